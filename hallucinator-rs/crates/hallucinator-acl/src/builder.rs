@@ -8,43 +8,69 @@ use std::io::BufReader;
 use std::path::Path;
 
 use futures_util::StreamExt;
+use hallucinator_text::{download, sqlite};
 use rusqlite::Connection;
 
 use crate::db::{self, InsertBatch};
 use crate::xml_parser;
-use crate::{AclError, BuildProgress};
+use crate::{AclError, BuildOptions, BuildProgress};
 
 /// GitHub API URL for the ACL Anthology tarball.
-const TARBALL_URL: &str = "https://api.github.com/repos/acl-org/acl-anthology/tarball/master";
+pub const TARBALL_URL: &str = "https://api.github.com/repos/acl-org/acl-anthology/tarball/master";
 
 /// GitHub API URL for the latest commit SHA on master.
-const COMMITS_URL: &str = "https://api.github.com/repos/acl-org/acl-anthology/commits/master";
+pub const COMMITS_URL: &str = "https://api.github.com/repos/acl-org/acl-anthology/commits/master";
+
+/// Default number of times a failed request is retried.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
 
 /// Batch size for database inserts.
 const BATCH_SIZE: usize = 10_000;
 
+/// Warnings reported individually per build; any beyond this are only
+/// counted, and summarized in one warning once parsing ends.
+const MAX_WARNINGS: u64 = 100;
+
 /// Build (or update) the offline ACL Anthology database.
 ///
 /// Downloads the GitHub tarball, extracts `data/xml/*.xml`, parses them,
 /// and builds the SQLite database. Uses commit SHA to skip if unchanged.
+///
+/// Connection failures, 5xx/429 responses and interrupted downloads are
+/// retried up to `options.max_retries` times with exponential backoff; each
+/// retry is reported as [`BuildProgress::Retrying`].
+///
+/// Before returning `Ok(true)` the WAL is checkpointed and the files fsynced
+/// (see [`sqlite::finalize`]), so a connection opened afterwards sees every record.
 pub async fn build(
     db_path: &Path,
+    options: BuildOptions,
     mut progress: impl FnMut(BuildProgress),
 ) -> Result<bool, AclError> {
-    let conn = Connection::open(db_path)?;
-    db::init_database(&conn)?;
-
-    // Check stored commit SHA for conditional update
-    let stored_sha = db::get_metadata(&conn, "commit_sha")?;
+    // Check stored commit SHA for conditional update. A database built with
+    // another `min_year` is rebuilt even if the repository hasn't moved.
+    let stored_sha = {
+        let conn = Connection::open(db_path)?;
+        db::init_database(&conn)?;
+        let same_min_year =
+            db::get_metadata(&conn, "min_year")? == options.min_year.map(|y| y.to_string());
+        if same_min_year {
+            db::get_metadata(&conn, "commit_sha")?
+        } else {
+            None
+        }
+    };
 
-    // Build HTTP client
-    let client = reqwest::Client::builder()
-        .user_agent("hallucinator-acl/0.1.0")
-        .build()
-        .map_err(|e| AclError::Download(e.to_string()))?;
+    let client = http_client(&options)?;
 
     // Check latest commit SHA
-    let current_sha = get_latest_commit_sha(&client).await?;
+    let mut attempt = 0;
+    let current_sha = loop {
+        match get_latest_commit_sha(&client, &options.commits_url).await {
+            Ok(sha) => break sha,
+            Err(e) => retry_or_fail(e, &mut attempt, &options, &mut progress).await?,
+        }
+    };
 
     if let Some(ref stored) = stored_sha
         && stored == &current_sha
@@ -52,58 +78,32 @@ pub async fn build(
         progress(BuildProgress::Complete {
             publications: 0,
             authors: 0,
+            issues: 0,
             skipped: true,
         });
         return Ok(false);
     }
 
-    // Download tarball
-    progress(BuildProgress::Downloading {
-        bytes_downloaded: 0,
-        total_bytes: None,
-    });
-
-    let response = client
-        .get(TARBALL_URL)
-        .send()
-        .await
-        .map_err(|e| AclError::Download(e.to_string()))?;
-
-    if !response.status().is_success() {
-        return Err(AclError::Download(format!(
-            "HTTP error: {}",
-            response.status()
-        )));
-    }
-
-    let total_bytes = response.content_length();
-
-    // Download to temp file
+    // Download tarball to temp file
     let db_dir = db_path.parent().unwrap_or(Path::new("."));
     let tmp_dir = tempfile::TempDir::new_in(db_dir).map_err(AclError::Io)?;
     let tarball_path = tmp_dir.path().join("acl-anthology.tar.gz");
 
-    {
-        let mut out = std::fs::File::create(&tarball_path)?;
-        let mut stream = response.bytes_stream();
-        let mut bytes_downloaded: u64 = 0;
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| AclError::Download(e.to_string()))?;
-            std::io::Write::write_all(&mut out, &chunk)?;
-            bytes_downloaded += chunk.len() as u64;
-
-            progress(BuildProgress::Downloading {
-                bytes_downloaded,
-                total_bytes,
-            });
+    let mut attempt = 0;
+    loop {
+        let result =
+            download_tarball(&client, &options.tarball_url, &tarball_path, &mut progress).await;
+        match result {
+            Ok(()) => break,
+            Err(e) => retry_or_fail(e, &mut attempt, &options, &mut progress).await?,
         }
-        std::io::Write::flush(&mut out)?;
     }
 
     // Extract XML files and parse into DB
     let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<BuildProgress>(64);
     let sha_for_meta = current_sha.clone();
+    let min_year = options.min_year;
+    let db_path = db_path.to_path_buf();
 
     let parse_handle = tokio::task::spawn_blocking(move || {
         let _tmp_dir = tmp_dir; // keep alive
@@ -147,13 +147,20 @@ pub async fn build(
 
         let files_total = xml_files.len() as u64;
 
-        // Parse XML files and insert into database
+        // Parse XML files into a fresh database that replaces the old one
+        // once finished, so nothing from the previous build survives.
+        let staged_db = sqlite::staging_path(&db_path);
+        sqlite::remove_database(&staged_db)?;
+        let conn = Connection::open(&staged_db)?;
+        db::init_database(&conn)?;
         db::begin_bulk_load(&conn)?;
 
         let mut batch = InsertBatch::new();
         let mut records_parsed: u64 = 0;
         let mut records_inserted: u64 = 0;
         let mut files_processed: u64 = 0;
+        let issues = std::cell::Cell::new(0u64);
+        let warnings = std::cell::Cell::new(0u64);
 
         for xml_path in &xml_files {
             let file = match std::fs::File::open(xml_path) {
@@ -161,47 +168,71 @@ pub async fn build(
                 Err(_) => continue,
             };
             let reader = BufReader::new(file);
+            let filename = xml_path.file_name().unwrap_or_default().to_string_lossy();
+            // `records` is how many records the anomaly cost.
+            let warn = |message: String, records: u64| {
+                issues.set(issues.get() + records);
+                warnings.set(warnings.get() + 1);
+                if warnings.get() <= MAX_WARNINGS {
+                    let _ = progress_tx.blocking_send(BuildProgress::Warning {
+                        message: format!("{}: {}", filename, message),
+                    });
+                }
+            };
 
-            xml_parser::parse_xml(reader, |paper| {
-                records_parsed += 1;
+            xml_parser::parse_xml(
+                reader,
+                |paper| {
+                    if let Some(min) = min_year
+                        && paper.year.is_none_or(|year| year < min)
+                    {
+                        return;
+                    }
+                    records_parsed += 1;
 
-                // Add authors
-                for author in &paper.authors {
-                    batch.authors.push(author.clone());
-                }
+                    // Add authors
+                    for author in &paper.authors {
+                        batch.authors.push(author.clone());
+                    }
 
-                // Add publication
-                batch.publications.push((
-                    paper.anthology_id.clone(),
-                    paper.title,
-                    paper.url,
-                    paper.doi,
-                ));
-
-                // Add relationships
-                for (pos, author) in paper.authors.iter().enumerate() {
-                    batch.publication_authors.push((
+                    // Add publication
+                    batch.publications.push((
                         paper.anthology_id.clone(),
-                        author.clone(),
-                        pos,
+                        paper.title,
+                        paper.url,
+                        paper.doi,
                     ));
-                }
 
-                if batch.len() >= BATCH_SIZE {
-                    records_inserted += batch.len() as u64;
-                    if let Err(_e) = db::insert_batch(&conn, &batch) {
-                        // Log error but continue
+                    // Add relationships
+                    for (pos, author) in paper.authors.iter().enumerate() {
+                        batch.publication_authors.push((
+                            paper.anthology_id.clone(),
+                            author.clone(),
+                            pos,
+                        ));
                     }
-                    batch.clear();
 
-                    let _ = progress_tx.blocking_send(BuildProgress::Parsing {
-                        records_parsed,
-                        records_inserted,
-                        files_processed,
-                        files_total,
-                    });
-                }
-            });
+                    if batch.len() >= BATCH_SIZE {
+                        match db::insert_batch(&conn, &batch) {
+                            Ok(()) => records_inserted += batch.len() as u64,
+                            // Report but continue
+                            Err(e) => warn(
+                                format!("failed to insert {} records: {}", batch.len(), e),
+                                batch.len() as u64,
+                            ),
+                        }
+                        batch.clear();
+
+                        let _ = progress_tx.blocking_send(BuildProgress::Parsing {
+                            records_parsed,
+                            records_inserted,
+                            files_processed,
+                            files_total,
+                        });
+                    }
+                },
+                |message| warn(message, 1),
+            );
 
             files_processed += 1;
 
@@ -215,6 +246,13 @@ pub async fn build(
             }
         }
 
+        let unreported = warnings.get().saturating_sub(MAX_WARNINGS);
+        if unreported > 0 {
+            let _ = progress_tx.blocking_send(BuildProgress::Warning {
+                message: format!("{} more warnings not shown", unreported),
+            });
+        }
+
         // Flush remaining
         if !batch.is_empty() {
             records_inserted += batch.len() as u64;
@@ -237,12 +275,19 @@ pub async fn build(
         db::set_metadata(&conn, "last_updated", &timestamp)?;
         db::set_metadata(&conn, "schema_version", "1")?;
         db::set_metadata(&conn, "commit_sha", &sha_for_meta)?;
+        match min_year {
+            Some(year) => db::set_metadata(&conn, "min_year", &year.to_string())?,
+            None => db::delete_metadata(&conn, "min_year")?,
+        }
 
         let (pubs, authors, _) = db::get_counts(&conn)?;
         db::set_metadata(&conn, "publication_count", &pubs.to_string())?;
         db::set_metadata(&conn, "author_count", &authors.to_string())?;
+        sqlite::finalize(&conn, &staged_db)?;
+        drop(conn);
+        sqlite::replace_database(&staged_db, &db_path)?;
 
-        Ok::<(i64, i64), AclError>((pubs, authors))
+        Ok::<(i64, i64, u64), AclError>((pubs, authors, issues.get()))
     });
 
     // Forward progress events
@@ -250,43 +295,153 @@ pub async fn build(
         progress(evt);
     }
 
-    let (pubs, authors) = parse_handle
+    let (pubs, authors, issues) = parse_handle
         .await
         .map_err(|e| AclError::Download(format!("parse task panicked: {}", e)))??;
 
     progress(BuildProgress::Complete {
         publications: pubs as u64,
         authors: authors as u64,
+        issues,
         skipped: false,
     });
 
     Ok(true)
 }
 
+/// The client for builds and remote checks: [`BuildOptions::client`], or
+/// the shared default.
+fn http_client(options: &BuildOptions) -> Result<reqwest::Client, AclError> {
+    match &options.client {
+        Some(client) => Ok(client.clone()),
+        None => hallucinator_text::download::default_client()
+            .map_err(|e| AclError::Download(e.to_string())),
+    }
+}
+
+/// Ask whether the latest commit at `options.commits_url` differs from
+/// `stored_sha`, the commit the database was built from. With no stored SHA
+/// any commit counts as an update.
+pub(crate) async fn remote_has_update(
+    options: &BuildOptions,
+    stored_sha: Option<&str>,
+) -> Result<bool, AclError> {
+    let current_sha = get_latest_commit_sha(&http_client(options)?, &options.commits_url)
+        .await
+        .map_err(|e| match e {
+            AttemptError::Transient(message) => AclError::Download(message),
+            AttemptError::Fatal(e) => e,
+        })?;
+    Ok(stored_sha != Some(current_sha.as_str()))
+}
+
+type AttemptError = download::AttemptError<AclError>;
+
+/// Classify a non-success HTTP status.
+fn status_error(status: reqwest::StatusCode, message: String) -> AttemptError {
+    AttemptError::from_status(status, message, AclError::Download)
+}
+
+/// Handle a failed request: if it was transient and retries remain, report
+/// [`BuildProgress::Retrying`] and sleep out the backoff; otherwise return
+/// the error that ends the build.
+async fn retry_or_fail(
+    err: AttemptError,
+    attempt: &mut u32,
+    options: &BuildOptions,
+    progress: &mut impl FnMut(BuildProgress),
+) -> Result<(), AclError> {
+    let max_retries = options.max_retries;
+    download::retry_or_fail(
+        err,
+        attempt,
+        max_retries,
+        options.retry_delay,
+        |attempt, error| {
+            progress(BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            })
+        },
+        AclError::Download,
+    )
+    .await
+}
+
 /// Get the latest commit SHA for the master branch.
-async fn get_latest_commit_sha(client: &reqwest::Client) -> Result<String, AclError> {
+async fn get_latest_commit_sha(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<String, AttemptError> {
     let response = client
-        .get(COMMITS_URL)
+        .get(url)
         .send()
         .await
-        .map_err(|e| AclError::Download(e.to_string()))?;
-
-    if !response.status().is_success() {
-        return Err(AclError::Download(format!(
-            "Failed to fetch commit info: HTTP {}",
-            response.status()
-        )));
+        .map_err(|e| AttemptError::Transient(e.to_string()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(status_error(
+            status,
+            format!("Failed to fetch commit info: HTTP {}", status),
+        ));
     }
 
     let body: serde_json::Value = response
         .json()
         .await
-        .map_err(|e| AclError::Download(e.to_string()))?;
+        .map_err(|e| AttemptError::Transient(e.to_string()))?;
 
     body["sha"]
         .as_str()
         .map(String::from)
-        .ok_or_else(|| AclError::Download("No SHA in commit response".into()))
+        .ok_or_else(|| AttemptError::Fatal(AclError::Download("No SHA in commit response".into())))
+}
+
+/// Stream the repository tarball to `tarball_path`, overwriting any partial
+/// download from an earlier attempt.
+async fn download_tarball(
+    client: &reqwest::Client,
+    url: &str,
+    tarball_path: &Path,
+    progress: &mut impl FnMut(BuildProgress),
+) -> Result<(), AttemptError> {
+    progress(BuildProgress::Downloading {
+        bytes_downloaded: 0,
+        total_bytes: None,
+    });
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| AttemptError::Transient(e.to_string()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(status_error(status, format!("HTTP error: {}", status)));
+    }
+
+    let total_bytes = response.content_length();
+
+    let mut out = std::fs::File::create(tarball_path)?;
+    let mut stream = response.bytes_stream();
+    let mut bytes_downloaded: u64 = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AttemptError::Transient(e.to_string()))?;
+        std::io::Write::write_all(&mut out, &chunk)?;
+        bytes_downloaded += chunk.len() as u64;
+
+        progress(BuildProgress::Downloading {
+            bytes_downloaded,
+            total_bytes,
+        });
+    }
+    std::io::Write::flush(&mut out)?;
+
+    Ok(())
 }
 
 /// Unix timestamp as a string (seconds since epoch).
@@ -297,3 +452,220 @@ fn now_unix_timestamp() -> String {
         .as_secs()
         .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    async fn spawn_http_stub(
+        responses: Vec<(&'static str, Vec<u8>)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        download::stub::spawn_http_stub("/commits/master", responses).await
+    }
+
+    #[tokio::test]
+    async fn test_commit_check_retries_after_server_error() {
+        let (commits_url, requests) = spawn_http_stub(vec![
+            ("502 Bad Gateway", Vec::new()),
+            ("200 OK", r#"{"sha":"abc123"}"#.into()),
+        ])
+        .await;
+
+        // Same SHA as the stub reports, so the build stops after the check.
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("acl.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            db::init_database(&conn).unwrap();
+            db::set_metadata(&conn, "commit_sha", "abc123").unwrap();
+        }
+
+        let options = BuildOptions {
+            commits_url,
+            max_retries: 2,
+            retry_delay: Duration::from_millis(10),
+            ..BuildOptions::default()
+        };
+        let mut retries = Vec::new();
+        let updated = build(&db_path, options, |evt| {
+            if let BuildProgress::Retrying { attempt, error, .. } = evt {
+                retries.push((attempt, error));
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(!updated);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert_eq!(retries.len(), 1);
+        assert_eq!(retries[0].0, 1);
+        assert!(retries[0].1.contains("502"), "{}", retries[0].1);
+    }
+
+    #[tokio::test]
+    async fn test_remote_staleness_compares_commit_sha() {
+        let (commits_url, requests) = spawn_http_stub(vec![
+            ("200 OK", r#"{"sha":"abc123"}"#.into()),
+            ("200 OK", r#"{"sha":"def456"}"#.into()),
+        ])
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("acl.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            db::init_database(&conn).unwrap();
+            db::set_metadata(&conn, "last_updated", "0").unwrap();
+            db::set_metadata(&conn, "commit_sha", "abc123").unwrap();
+        }
+        let db = crate::AclDatabase::open(&db_path).unwrap();
+        let options = BuildOptions {
+            commits_url,
+            ..BuildOptions::default()
+        };
+
+        let check = db.check_remote_staleness(30, &options).await.unwrap();
+        assert!(!check.is_stale);
+        assert_eq!(check.update_available, Some(false));
+
+        let check = db.check_remote_staleness(30, &options).await.unwrap();
+        assert!(check.is_stale);
+        assert_eq!(check.update_available, Some(true));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    /// Gzipped tarball laid out like the GitHub one, holding `files` as
+    /// `data/xml/<name>`.
+    fn anthology_tarball(files: &[(&str, &str)]) -> Vec<u8> {
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        let mut tar = tar::Builder::new(encoder);
+        for (name, xml) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(xml.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(
+                &mut header,
+                format!("acl-org-acl-anthology-abc123/data/xml/{}", name),
+                xml.as_bytes(),
+            )
+            .unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_min_year_excludes_older_volumes() {
+        let volume = |year: &str, title: &str| {
+            format!(
+                r#"<collection id="{year}.acl"><volume id="long">
+<meta><year>{year}</year></meta>
+<paper id="1"><title>{title}</title><author><first>Ada</first><last>{year}</last></author></paper>
+</volume></collection>"#
+            )
+        };
+        let (old, new) = (volume("2009", "Old Paper"), volume("2019", "New Paper"));
+        let tarball = anthology_tarball(&[("2009.acl.xml", &old), ("2019.acl.xml", &new)]);
+        let (url, _) = spawn_http_stub(vec![
+            ("200 OK", r#"{"sha":"abc123"}"#.into()),
+            ("200 OK", tarball.clone()),
+            ("200 OK", r#"{"sha":"abc123"}"#.into()),
+            ("200 OK", tarball),
+        ])
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("acl.db");
+        let options = BuildOptions {
+            tarball_url: url.clone(),
+            commits_url: url,
+            ..BuildOptions::default()
+        };
+        assert!(build(&db_path, options.clone(), |_| {}).await.unwrap());
+
+        // Same commit, but a new `min_year`: rebuilt, dropping older rows.
+        let options = BuildOptions {
+            min_year: Some(2015),
+            ..options
+        };
+        assert!(build(&db_path, options, |_| {}).await.unwrap());
+
+        let conn = Connection::open(&db_path).unwrap();
+        let titles: Vec<String> = conn
+            .prepare("SELECT title FROM publications")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert_eq!(titles, ["New Paper"]);
+        assert_eq!(db::get_counts(&conn).unwrap().1, 1);
+        assert_eq!(
+            db::get_metadata(&conn, "min_year").unwrap(),
+            Some("2015".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_warnings_are_capped_and_summarized() {
+        let untitled = MAX_WARNINGS + 5;
+        let papers: String = (1..=untitled)
+            .map(|id| format!(r#"<paper id="{id}"><title></title></paper>"#))
+            .collect();
+        let xml = format!(
+            r#"<collection id="2024.acl"><volume id="long">
+<meta><year>2024</year></meta>{papers}
+<paper id="0"><title>Kept Paper</title></paper>
+</volume></collection>"#
+        );
+        let tarball = anthology_tarball(&[("2024.acl.xml", &xml)]);
+        let (url, _) = spawn_http_stub(vec![
+            ("200 OK", r#"{"sha":"abc123"}"#.into()),
+            ("200 OK", tarball),
+        ])
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let options = BuildOptions {
+            tarball_url: url.clone(),
+            commits_url: url,
+            ..BuildOptions::default()
+        };
+        let mut warnings = Vec::new();
+        let mut issues = None;
+        build(&dir.path().join("acl.db"), options, |evt| match evt {
+            BuildProgress::Warning { message } => warnings.push(message),
+            BuildProgress::Complete { issues: n, .. } => issues = Some(n),
+            _ => {}
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(warnings.len() as u64, MAX_WARNINGS + 1);
+        assert!(warnings[0].ends_with("no title, skipped"), "{warnings:?}");
+        assert_eq!(warnings.last().unwrap(), "5 more warnings not shown");
+        assert_eq!(issues, Some(untitled));
+    }
+
+    #[tokio::test]
+    async fn test_commit_check_gives_up_after_max_retries() {
+        let (commits_url, requests) =
+            spawn_http_stub(vec![("503 Service Unavailable", Vec::new())]).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let options = BuildOptions {
+            commits_url,
+            max_retries: 2,
+            retry_delay: Duration::from_millis(10),
+            ..BuildOptions::default()
+        };
+        let err = build(&dir.path().join("acl.db"), options, |_| {})
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("gave up after 2 retries"), "{err}");
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+}
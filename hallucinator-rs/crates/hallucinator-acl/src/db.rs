@@ -171,6 +171,12 @@ pub fn set_metadata(conn: &Connection, key: &str, value: &str) -> Result<(), Acl
     Ok(())
 }
 
+/// Remove a metadata value, if present.
+pub fn delete_metadata(conn: &Connection, key: &str) -> Result<(), AclError> {
+    conn.execute("DELETE FROM metadata WHERE key = ?1", params![key])?;
+    Ok(())
+}
+
 /// Get counts of publications, authors, and relations.
 pub fn get_counts(conn: &Connection) -> Result<(i64, i64, i64), AclError> {
     let pubs: i64 = conn.query_row("SELECT COUNT(*) FROM publications", [], |row| row.get(0))?;
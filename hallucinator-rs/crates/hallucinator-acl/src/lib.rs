@@ -10,11 +10,14 @@ mod query;
 mod xml_parser;
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use rusqlite::Connection;
 use thiserror::Error;
 
-pub use query::DEFAULT_THRESHOLD;
+pub use builder::{COMMITS_URL, DEFAULT_MAX_RETRIES, TARBALL_URL};
+pub use hallucinator_text::TitleScorer;
+pub use query::{DEFAULT_THRESHOLD, QueryGuard};
 
 #[derive(Error, Debug)]
 pub enum AclError {
@@ -30,6 +33,7 @@ pub enum AclError {
 
 /// A publication record from the offline ACL Anthology database.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AclRecord {
     pub title: String,
     pub authors: Vec<String>,
@@ -38,6 +42,7 @@ pub struct AclRecord {
 
 /// Query result with fuzzy match score.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AclQueryResult {
     pub record: AclRecord,
     pub score: f64,
@@ -51,6 +56,8 @@ pub struct DatabaseInfo {
     pub publication_count: Option<String>,
     pub author_count: Option<String>,
     pub commit_sha: Option<String>,
+    /// Earliest publication year kept by the last build, if it was filtered.
+    pub min_year: Option<String>,
 }
 
 /// Progress events emitted during database building.
@@ -69,26 +76,83 @@ pub enum BuildProgress {
         files_processed: u64,
         files_total: u64,
     },
+    /// A request to GitHub failed with a transient error (connection
+    /// failure, 5xx/429 response, interrupted stream); it is repeated after a
+    /// backoff delay.
+    Retrying {
+        /// 1-based retry number.
+        attempt: u32,
+        max_retries: u32,
+        error: String,
+    },
+    /// A non-fatal anomaly, e.g. a paper skipped for lacking a title, a
+    /// file whose XML is malformed past some point, or a batch that failed
+    /// to insert. Only the first hundred are reported individually, followed
+    /// by one warning counting the rest.
+    Warning {
+        message: String,
+    },
     RebuildingIndex,
     Complete {
         publications: u64,
         authors: u64,
+        /// How many records were skipped or failed to insert; a file whose
+        /// XML is malformed counts once.
+        issues: u64,
         skipped: bool,
     },
 }
 
+/// Options for [`build_database_with_options`].
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// URL of the repository tarball.
+    pub tarball_url: String,
+    /// URL of the GitHub API commit endpoint used to detect changes.
+    pub commits_url: String,
+    /// How many times a failed request or interrupted download is retried
+    /// before the build gives up. `0` disables retries.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each subsequent one.
+    pub retry_delay: Duration,
+    /// Skip papers from volumes before this year (and volumes without a
+    /// year) while parsing, for a smaller database.
+    pub min_year: Option<u32>,
+    /// HTTP client for the download and the remote update check, e.g. the
+    /// run's client carrying its proxy and CA settings. `None` uses
+    /// [`hallucinator_text::download::default_client`].
+    pub client: Option<reqwest::Client>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            tarball_url: TARBALL_URL.to_string(),
+            commits_url: COMMITS_URL.to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: Duration::from_secs(1),
+            min_year: None,
+            client: None,
+        }
+    }
+}
+
 /// Result of a staleness check.
 #[derive(Debug, Clone)]
 pub struct StalenessCheck {
     pub is_stale: bool,
     pub age_days: Option<u64>,
     pub build_date: Option<String>,
+    /// Whether the upstream source changed since this build, when it was
+    /// asked (see `check_remote_staleness`); `None` for a local-only check.
+    pub update_available: Option<bool>,
 }
 
 /// Handle to an opened offline ACL Anthology database.
 pub struct AclDatabase {
     conn: Connection,
     path: PathBuf,
+    guard: QueryGuard,
 }
 
 impl AclDatabase {
@@ -109,12 +173,13 @@ impl AclDatabase {
         Ok(Self {
             conn,
             path: path.to_path_buf(),
+            guard: QueryGuard::default(),
         })
     }
 
     /// Query for a title, returning the best fuzzy match above the default threshold.
     pub fn query(&self, title: &str) -> Result<Option<AclQueryResult>, AclError> {
-        query::query_fts(&self.conn, title, DEFAULT_THRESHOLD)
+        query::query_fts(&self.conn, title, DEFAULT_THRESHOLD, &self.guard)
     }
 
     /// Query with a custom similarity threshold.
@@ -123,7 +188,34 @@ impl AclDatabase {
         title: &str,
         threshold: f64,
     ) -> Result<Option<AclQueryResult>, AclError> {
-        query::query_fts(&self.conn, title, threshold)
+        query::query_fts(&self.conn, title, threshold, &self.guard)
+    }
+
+    /// Query with a custom threshold, scoring candidates with `scorer`.
+    pub fn query_with_scorer(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+    ) -> Result<Option<AclQueryResult>, AclError> {
+        self.query_guarded(title, threshold, scorer, &self.guard)
+    }
+
+    /// Like [`query_with_scorer`](Self::query_with_scorer), applying `guard`
+    /// instead of the one set with [`set_query_guard`](Self::set_query_guard).
+    pub fn query_guarded(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+        guard: &QueryGuard,
+    ) -> Result<Option<AclQueryResult>, AclError> {
+        query::query_fts_scored(&self.conn, title, threshold, guard, scorer)
+    }
+
+    /// Replace the short/generic query guard used by [`query`](Self::query).
+    pub fn set_query_guard(&mut self, guard: QueryGuard) {
+        self.guard = guard;
     }
 
     /// Get database metadata/info.
@@ -134,6 +226,7 @@ impl AclDatabase {
             publication_count: db::get_metadata(&self.conn, "publication_count")?,
             author_count: db::get_metadata(&self.conn, "author_count")?,
             commit_sha: db::get_metadata(&self.conn, "commit_sha")?,
+            min_year: db::get_metadata(&self.conn, "min_year")?,
         })
     }
 
@@ -156,9 +249,27 @@ impl AclDatabase {
             is_stale,
             age_days,
             build_date,
+            update_available: None,
         })
     }
 
+    /// Like [`check_staleness`](Self::check_staleness), but also asks
+    /// `options.commits_url` whether the repository moved past the commit
+    /// this database was built from. An old database is only reported stale
+    /// if an update is actually available.
+    pub async fn check_remote_staleness(
+        &self,
+        threshold_days: u64,
+        options: &BuildOptions,
+    ) -> Result<StalenessCheck, AclError> {
+        let mut check = self.check_staleness(threshold_days)?;
+        let stored_sha = db::get_metadata(&self.conn, "commit_sha")?;
+        let update_available = builder::remote_has_update(options, stored_sha.as_deref()).await?;
+        check.is_stale &= update_available;
+        check.update_available = Some(update_available);
+        Ok(check)
+    }
+
     /// Convenience: check staleness with the default 30-day threshold.
     pub fn is_stale(&self) -> Result<bool, AclError> {
         Ok(self.check_staleness(30)?.is_stale)
@@ -175,9 +286,46 @@ impl AclDatabase {
 /// Downloads the GitHub tarball, extracts XML files, parses them, and builds
 /// an FTS5-indexed SQLite database. Uses commit SHA for conditional updates.
 /// Returns `false` if the remote hasn't changed since the last build.
+///
+/// A successful build is durable: the WAL is checkpointed and the files are
+/// fsynced before this returns, so the database can be reopened (or handed
+/// to [`AclDatabase::open`] in another process) right away.
 pub async fn build_database(
     db_path: &Path,
     progress: impl FnMut(BuildProgress),
 ) -> Result<bool, AclError> {
-    builder::build(db_path, progress).await
+    builder::build(db_path, BuildOptions::default(), progress).await
+}
+
+/// Build or update the offline ACL database with full [`BuildOptions`].
+pub async fn build_database_with_options(
+    db_path: &Path,
+    options: BuildOptions,
+    progress: impl FnMut(BuildProgress),
+) -> Result<bool, AclError> {
+    builder::build(db_path, options, progress).await
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_query_result_round_trip() {
+        let result = AclQueryResult {
+            record: AclRecord {
+                title: "Attention Is All You Need".into(),
+                authors: vec!["Ashish Vaswani".into(), "Noam Shazeer".into()],
+                url: Some("https://example.org/paper".into()),
+            },
+            score: 0.97,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"title\":\"Attention Is All You Need\""));
+        let back: AclQueryResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.record.title, result.record.title);
+        assert_eq!(back.record.authors, result.record.authors);
+        assert_eq!(back.record.url, result.record.url);
+        assert_eq!(back.score, result.score);
+    }
 }
@@ -2,7 +2,10 @@
 
 use once_cell::sync::Lazy;
 use regex::Regex;
-use rusqlite::{Connection, params};
+use rusqlite::Connection;
+
+use hallucinator_text::fts::{self, FtsLookup};
+use hallucinator_text::{TitleScorer, normalize_title};
 
 use crate::db;
 use crate::{AclError, AclQueryResult, AclRecord};
@@ -10,20 +13,18 @@ use crate::{AclError, AclQueryResult, AclRecord};
 /// Default similarity threshold for fuzzy title matching.
 pub const DEFAULT_THRESHOLD: f64 = 0.95;
 
-/// Normalize a title for comparison: lowercase alphanumeric only.
-fn normalize_title(title: &str) -> String {
-    static NON_ALNUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
-    let lowered = title.to_lowercase();
-    NON_ALNUM.replace_all(&lowered, "").to_string()
-}
+/// Maximum number of FTS5 candidates fetched for fuzzy re-ranking.
+const MAX_CANDIDATES: usize = 50;
+
+pub use hallucinator_text::QueryGuard;
 
 /// Extract meaningful query words for FTS5 MATCH (4+ chars, no stop words).
 ///
 /// Handles digits (`L2`, `3D`), hyphens (`Machine-Learning`), and apostrophes (`What's`).
 /// Also strips BibTeX braces (`{BERT}` → `BERT`).
 fn get_query_words(title: &str) -> Vec<String> {
-    // Strip BibTeX capitalization braces
-    let title = title.replace(['{', '}'], "");
+    // Strip BibTeX capitalization braces and fold diacritics
+    let title = hallucinator_text::fold_diacritics(title).replace(['{', '}'], "");
 
     static WORD_RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"[a-zA-Z0-9]+(?:['\u{2019}\u{2018}\-][a-zA-Z0-9]+)*").unwrap());
@@ -48,53 +49,58 @@ fn get_query_words(title: &str) -> Vec<String> {
 }
 
 /// Query the FTS5 index for a title, returning the best match above the threshold.
+///
+/// Short or generic queries are skipped or tightened according to `guard`.
 pub fn query_fts(
     conn: &Connection,
     title: &str,
     threshold: f64,
+    guard: &QueryGuard,
+) -> Result<Option<AclQueryResult>, AclError> {
+    query_fts_scored(conn, title, threshold, guard, TitleScorer::default())
+}
+
+/// Like [`query_fts`], ranking candidates with `scorer`; `threshold` applies
+/// to its score.
+pub fn query_fts_scored(
+    conn: &Connection,
+    title: &str,
+    threshold: f64,
+    guard: &QueryGuard,
+    scorer: TitleScorer,
 ) -> Result<Option<AclQueryResult>, AclError> {
     let words = get_query_words(title);
-    if words.is_empty() {
+    let Some((threshold, limit)) = guard.limits(words.len(), threshold, MAX_CANDIDATES) else {
         return Ok(None);
-    }
-
-    let fts_query = words.join(" ");
+    };
 
-    let mut stmt = conn.prepare_cached(
-        "SELECT p.anthology_id, p.title, p.url FROM publications p \
-         WHERE p.id IN (SELECT rowid FROM publications_fts WHERE title MATCH ?1) \
-         LIMIT 50",
-    )?;
+    let mut candidates = fts_candidates(conn, FtsLookup::Keywords, &words.join(" "), limit)?;
 
-    let candidates: Vec<(String, String, Option<String>)> = stmt
-        .query_map(params![fts_query], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, Option<String>>(2)?,
-            ))
-        })?
-        .filter_map(|r| r.ok())
-        .collect();
+    // Nothing shares the keywords verbatim: loosen to prefixes before giving up
+    if candidates.is_empty() && guard.allows_loose_fallback(words.len()) {
+        let fts_query = fts::prefix_query(&words);
+        if !fts_query.is_empty() {
+            candidates = fts_candidates(conn, FtsLookup::Ranked, &fts_query, limit)?;
+        }
+    }
 
     if candidates.is_empty() {
         return Ok(None);
     }
 
-    let norm_query = normalize_title(title);
-    if norm_query.is_empty() {
+    if normalize_title(title).is_empty() {
         return Ok(None);
     }
 
     let mut best_match: Option<(f64, String, String, Option<String>)> = None;
+    let cited = scorer.prepare(title);
 
     for (anthology_id, candidate_title, url) in &candidates {
-        let norm_candidate = normalize_title(candidate_title);
-        if norm_candidate.is_empty() {
+        if normalize_title(candidate_title).is_empty() {
             continue;
         }
 
-        let score = rapidfuzz::fuzz::ratio(norm_query.chars(), norm_candidate.chars());
+        let score = cited.score(candidate_title);
 
         if score >= threshold
             && best_match
@@ -126,6 +132,29 @@ pub fn query_fts(
     }
 }
 
+/// Run an FTS5 query and return up to `limit` `(anthology_id, title, url)` candidates.
+fn fts_candidates(
+    conn: &Connection,
+    lookup: FtsLookup,
+    fts_query: &str,
+    limit: usize,
+) -> Result<Vec<(String, String, Option<String>)>, AclError> {
+    Ok(fts::fts_candidates(
+        conn,
+        "p.anthology_id, p.title, p.url",
+        lookup,
+        fts_query,
+        limit,
+        |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        },
+    )?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +198,19 @@ mod tests {
         assert_eq!(normalize_title("Hello, World! 123"), "helloworld123");
     }
 
+    #[test]
+    fn test_query_fts_diacritics() {
+        let conn = setup_db_with_data();
+        let result = query_fts(
+            &conn,
+            "Attention Pätterns in Tränsformer Models",
+            DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
+        )
+        .unwrap();
+        assert!(result.is_some());
+    }
+
     #[test]
     fn test_get_query_words() {
         let words = get_query_words("Attention Patterns in Transformer Models");
@@ -205,6 +247,7 @@ mod tests {
             &conn,
             "Attention Patterns in Transformer Models",
             DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
         )
         .unwrap();
         assert!(result.is_some());
@@ -213,6 +256,37 @@ mod tests {
         assert_eq!(result.record.authors.len(), 2);
     }
 
+    #[test]
+    fn test_query_fts_token_set_rescues_reordered_title() {
+        let conn = setup_db_with_data();
+        let reordered = "Transformer Models: Attention Patterns";
+        let guard = QueryGuard::default();
+
+        let ratio = query_fts_scored(
+            &conn,
+            reordered,
+            DEFAULT_THRESHOLD,
+            &guard,
+            TitleScorer::Ratio,
+        )
+        .unwrap();
+        assert!(ratio.is_none());
+
+        let result = query_fts_scored(
+            &conn,
+            reordered,
+            DEFAULT_THRESHOLD,
+            &guard,
+            TitleScorer::TokenSet,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            result.record.title,
+            "Attention Patterns in Transformer Models"
+        );
+    }
+
     #[test]
     fn test_query_fts_no_match() {
         let conn = setup_db_with_data();
@@ -220,6 +294,7 @@ mod tests {
             &conn,
             "Completely Unrelated Paper About Marine Biology",
             DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
         )
         .unwrap();
         assert!(result.is_none());
@@ -228,7 +303,45 @@ mod tests {
     #[test]
     fn test_query_fts_empty() {
         let conn = setup_db_with_data();
-        let result = query_fts(&conn, "", DEFAULT_THRESHOLD).unwrap();
+        let result = query_fts(&conn, "", DEFAULT_THRESHOLD, &QueryGuard::default()).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_fts_stopwords_only_skips_index() {
+        // No schema at all: any FTS5 query would error, so a `None` here
+        // proves the guard returned before touching the database.
+        let conn = Connection::open_in_memory().unwrap();
+        let result = query_fts(
+            &conn,
+            "About Their Other Through",
+            DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
+        )
+        .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_fts_prefix_fallback() {
+        let conn = setup_db_with_data();
+        // "Transfromer" is not an indexed token, so the AND query finds nothing
+        let title = "Attention Patterns in Transfromer Models";
+
+        let guard = QueryGuard {
+            loose_fallback: true,
+            ..QueryGuard::default()
+        };
+        let result = query_fts(&conn, title, DEFAULT_THRESHOLD, &guard)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            result.record.title,
+            "Attention Patterns in Transformer Models"
+        );
+        assert!(result.score >= DEFAULT_THRESHOLD);
+
+        let result = query_fts(&conn, title, DEFAULT_THRESHOLD, &QueryGuard::default()).unwrap();
         assert!(result.is_none());
     }
 }
@@ -4,6 +4,7 @@
 //! ```xml
 //! <collection id="2024.acl">
 //!   <volume id="long" type="proceedings">
+//!     <meta><year>2024</year></meta>
 //!     <paper id="1">
 //!       <title>Some <fixed-case>BERT</fixed-case> Paper</title>
 //!       <author><first>Alice</first><last>Smith</last></author>
@@ -26,10 +27,19 @@ pub struct AclPaper {
     pub authors: Vec<String>,
     pub doi: Option<String>,
     pub url: Option<String>,
+    /// Publication year, from the enclosing volume's `<meta>`.
+    pub year: Option<u32>,
 }
 
-/// Parse an ACL Anthology XML file, calling `on_paper` for each paper found.
-pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
+/// Parse an ACL Anthology XML file, calling `on_paper` for each paper found
+/// and `on_issue` with a description of each non-fatal anomaly: a paper
+/// skipped for lacking a title, or malformed XML (the rest of the file is
+/// skipped).
+pub fn parse_xml<R: BufRead>(
+    reader: R,
+    mut on_paper: impl FnMut(AclPaper),
+    mut on_issue: impl FnMut(String),
+) {
     let mut xml_reader = Reader::from_reader(reader);
     // Do NOT trim_text — it strips whitespace around inline markup (e.g.
     // <fixed-case>, <tex-math>) within <title> elements, merging words.
@@ -40,6 +50,8 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
     // Track collection/volume IDs for constructing anthology_id
     let mut collection_id = String::new();
     let mut volume_id = String::new();
+    let mut volume_year: Option<u32> = None;
+    let mut year_text = String::new();
 
     // Current paper state
     let mut in_paper = false;
@@ -59,6 +71,7 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
     let mut in_last = false;
     let mut in_doi = false;
     let mut in_url = false;
+    let mut in_year = false;
     let mut title_depth: u32 = 0;
 
     loop {
@@ -80,6 +93,11 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
                                 volume_id = String::from_utf8_lossy(&attr.value).to_string();
                             }
                         }
+                        volume_year = None;
+                    }
+                    "year" if !in_paper => {
+                        in_year = true;
+                        year_text.clear();
                     }
                     "paper" => {
                         for attr in e.attributes().flatten() {
@@ -148,6 +166,8 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
                     doi_text.push_str(&e.unescape().unwrap_or_default());
                 } else if in_url {
                     url_text.push_str(&e.unescape().unwrap_or_default());
+                } else if in_year {
+                    year_text.push_str(&e.unescape().unwrap_or_default());
                 }
             }
             Ok(Event::End(ref e)) => {
@@ -186,6 +206,10 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
                     "url" => {
                         in_url = false;
                     }
+                    "year" if in_year => {
+                        in_year = false;
+                        volume_year = year_text.trim().parse().ok();
+                    }
                     "paper" if in_paper => {
                         in_paper = false;
 
@@ -196,6 +220,10 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
 
                         let title = title_text.trim().to_string();
                         if title.is_empty() {
+                            on_issue(format!(
+                                "{}-{}.{}: no title, skipped",
+                                collection_id, volume_id, paper_id
+                            ));
                             continue;
                         }
 
@@ -221,13 +249,21 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_paper: impl FnMut(AclPaper)) {
                             authors: authors.clone(),
                             doi,
                             url: Some(url),
+                            year: volume_year,
                         });
                     }
                     _ => {}
                 }
             }
             Ok(Event::Eof) => break,
-            Err(_) => break,
+            Err(e) => {
+                on_issue(format!(
+                    "malformed XML near byte {}: {}; rest of file skipped",
+                    xml_reader.buffer_position(),
+                    e
+                ));
+                break;
+            }
             _ => {}
         }
         buf.clear();
@@ -244,6 +280,10 @@ mod tests {
         let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
 <collection id="2024.acl">
   <volume id="long" type="proceedings">
+    <meta>
+      <booktitle>Proceedings of ACL 2024</booktitle>
+      <year>2024</year>
+    </meta>
     <paper id="1">
       <title>Attention Patterns in <fixed-case>BERT</fixed-case> Models</title>
       <author><first>Alice</first><last>Smith</last></author>
@@ -255,7 +295,7 @@ mod tests {
 </collection>"#;
 
         let mut papers = Vec::new();
-        parse_xml(Cursor::new(xml), |paper| papers.push(paper));
+        parse_xml(Cursor::new(xml), |paper| papers.push(paper), |_| {});
 
         assert_eq!(papers.len(), 1);
         assert_eq!(papers[0].anthology_id, "2024.acl-long.1");
@@ -269,6 +309,7 @@ mod tests {
             papers[0].url,
             Some("https://aclanthology.org/2024.acl-long.1".to_string())
         );
+        assert_eq!(papers[0].year, Some(2024));
     }
 
     #[test]
@@ -288,7 +329,7 @@ mod tests {
 </collection>"#;
 
         let mut papers = Vec::new();
-        parse_xml(Cursor::new(xml), |paper| papers.push(paper));
+        parse_xml(Cursor::new(xml), |paper| papers.push(paper), |_| {});
 
         assert_eq!(papers.len(), 1);
         assert_eq!(papers[0].title, "Real Paper Title");
@@ -313,7 +354,7 @@ mod tests {
 </collection>"#;
 
         let mut papers = Vec::new();
-        parse_xml(Cursor::new(xml), |paper| papers.push(paper));
+        parse_xml(Cursor::new(xml), |paper| papers.push(paper), |_| {});
 
         assert_eq!(papers.len(), 2);
         assert_eq!(papers[0].title, "First Paper");
@@ -333,9 +374,41 @@ mod tests {
 </collection>"#;
 
         let mut papers = Vec::new();
-        parse_xml(Cursor::new(xml), |paper| papers.push(paper));
+        parse_xml(Cursor::new(xml), |paper| papers.push(paper), |_| {});
 
         assert_eq!(papers.len(), 1);
         assert_eq!(papers[0].title, "Understanding x^2 in Deep NLP");
     }
+
+    #[test]
+    fn test_issues_reported_for_malformed_entries() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<collection id="2024.acl">
+  <volume id="long">
+    <paper id="1">
+      <author><first>No</first><last>Title</last></author>
+    </paper>
+    <paper id="2">
+      <title>Kept Paper</title>
+    </paper>
+    <paper id="3">
+      <title>Broken</titel>
+    </paper>
+  </volume>
+</collection>"#;
+
+        let mut papers = Vec::new();
+        let mut issues = Vec::new();
+        parse_xml(
+            Cursor::new(xml),
+            |paper| papers.push(paper),
+            |issue| issues.push(issue),
+        );
+
+        assert_eq!(papers.len(), 1);
+        assert_eq!(papers[0].title, "Kept Paper");
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert_eq!(issues[0], "2024.acl-long.1: no title, skipped");
+        assert!(issues[1].starts_with("malformed XML"), "{issues:?}");
+    }
 }
@@ -4,7 +4,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use thiserror::Error;
 
-use hallucinator_core::{ExtractionResult, Reference, SkipStats};
+use hallucinator_core::{ExtractionResult, RefKind, Reference, SkipStats, classify_citation};
 
 #[derive(Error, Debug)]
 pub enum BblError {
@@ -21,10 +21,47 @@ pub enum BblError {
 /// Parses `\bibitem` entries and extracts structured fields from
 /// `\bibinfo{field}{value}` markup (ACM-Reference-Format style).
 pub fn extract_references_from_bbl(path: &Path) -> Result<ExtractionResult, BblError> {
-    let content = std::fs::read_to_string(path)?;
+    let content = read_bibliography_file(path)?;
     extract_references_from_bbl_str(&content)
 }
 
+/// Read a .bbl/.bib file, falling back to Windows-1252 when it is not UTF-8.
+///
+/// Older TeX setups still produce Latin-1 bibliographies; rather than failing
+/// with an "invalid UTF-8" IO error, decode them byte-for-byte and carry on.
+fn read_bibliography_file(path: &Path) -> Result<String, BblError> {
+    let bytes = std::fs::read(path)?;
+    match String::from_utf8(bytes) {
+        Ok(content) => Ok(content),
+        Err(e) => {
+            tracing::warn!(
+                path = %path.display(),
+                "file is not valid UTF-8, decoding as Latin-1/Windows-1252"
+            );
+            Ok(decode_windows_1252(e.as_bytes()))
+        }
+    }
+}
+
+/// Decode Windows-1252 bytes (a superset of Latin-1's printable range).
+///
+/// 0x80–0x9F map to the cp1252 punctuation block; the five bytes it leaves
+/// undefined fall back to the corresponding C1 control, as in Latin-1.
+fn decode_windows_1252(bytes: &[u8]) -> String {
+    const HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž',
+        '\u{8F}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}',
+        'ž', 'Ÿ',
+    ];
+    bytes
+        .iter()
+        .map(|&b| match b {
+            0x80..=0x9F => HIGH[(b - 0x80) as usize],
+            _ => b as char,
+        })
+        .collect()
+}
+
 /// Parse .bbl content from a string (useful for testing).
 pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult, BblError> {
     let entries = segment_bibitem_entries(content);
@@ -44,6 +81,8 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
         // Build raw citation for display (collapse whitespace) — needed for both skipped and normal refs
         static WS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
         let raw_citation = WS_RE.replace_all(entry.trim(), " ").to_string();
+        let kind = classify_citation(&raw_citation);
+        let cite_key = extract_cite_key(entry);
 
         // Extract title
         let title = extract_title(entry).map(|t| strip_latex(&t));
@@ -61,6 +100,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    kind,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -74,6 +117,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("short_title".to_string()),
+                    kind,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -87,6 +134,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    kind,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -103,6 +154,11 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
             // Still include (tracked only)
         }
 
+        // The enclosing book or proceedings, unless it is what we took as the title
+        let container_title = extract_bibinfo(entry, "booktitle")
+            .map(|b| strip_latex(&b))
+            .filter(|b| !b.is_empty() && *b != title);
+
         // Skip URL-only entries (non-academic URLs without a real title)
         if is_url_only_entry(entry) {
             stats.url_only += 1;
@@ -114,6 +170,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                 arxiv_id: None,
                 original_number: raw_idx + 1,
                 skip_reason: Some("url_only".to_string()),
+                kind,
+                container_title,
+                cite_key,
+                cited_in_text: None,
             });
             continue;
         }
@@ -130,6 +190,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
             arxiv_id,
             original_number: raw_idx + 1,
             skip_reason: None,
+            kind,
+            container_title,
+            cite_key,
+            cited_in_text: None,
         });
     }
 
@@ -144,7 +208,7 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
 /// Uses the `biblatex` crate for robust parsing with LaTeX accent decoding
 /// and structured field extraction.
 pub fn extract_references_from_bib(path: &Path) -> Result<ExtractionResult, BblError> {
-    let content = std::fs::read_to_string(path)?;
+    let content = read_bibliography_file(path)?;
     extract_references_from_bib_str(&content)
 }
 
@@ -220,6 +284,8 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
     let mut references = Vec::new();
 
     for (raw_idx, entry) in entries.iter().enumerate() {
+        let cite_key = Some(entry.key.clone()).filter(|k| !k.is_empty());
+
         // Extract title (convert chunks → string, then strip residual LaTeX)
         let title = entry
             .title()
@@ -240,6 +306,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    kind: RefKind::Unknown,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -253,6 +323,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("short_title".to_string()),
+                    kind: RefKind::Unknown,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -266,6 +340,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     arxiv_id: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    kind: RefKind::Unknown,
+                    container_title: None,
+                    cite_key,
+                    cited_in_text: None,
                 });
                 continue;
             }
@@ -307,10 +385,12 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
         {
             raw_parts.push(journal);
         }
-        if let Some(booktitle) = entry.get("booktitle").map(chunks_to_string)
-            && !booktitle.is_empty()
-        {
-            raw_parts.push(booktitle);
+        let container_title = entry
+            .get("booktitle")
+            .map(chunks_to_string)
+            .filter(|b| !b.is_empty());
+        if let Some(booktitle) = &container_title {
+            raw_parts.push(booktitle.clone());
         }
         if let Some(year) = entry.get("year").map(chunks_to_string)
             && !year.is_empty()
@@ -318,6 +398,7 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
             raw_parts.push(year);
         }
         let raw_citation = raw_parts.join(". ");
+        let kind = classify_citation(&raw_citation);
 
         references.push(Reference {
             raw_citation,
@@ -327,6 +408,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
             arxiv_id,
             original_number: raw_idx + 1,
             skip_reason: None,
+            kind,
+            container_title,
+            cite_key,
+            cited_in_text: None,
         });
     }
 
@@ -435,6 +520,34 @@ fn segment_bibitem_entries(content: &str) -> Vec<String> {
     entries
 }
 
+/// Extract the cite key from `\bibitem[label]{key}`.
+///
+/// The optional label may itself contain braces (natbib writes
+/// `\bibitem[{Doe et~al.(2023)}]{doe2023}`), so it is skipped by bracket
+/// depth rather than up to the first `]`.
+fn extract_cite_key(entry: &str) -> Option<String> {
+    let rest = entry.strip_prefix("\\bibitem")?.trim_start();
+    let rest = match rest.strip_prefix('[') {
+        Some(label) => {
+            let mut depth = 0i32;
+            let (end, _) = label.char_indices().find(|&(_, c)| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    ']' if depth == 0 => return true,
+                    _ => {}
+                }
+                false
+            })?;
+            label[end + 1..].trim_start()
+        }
+        None => rest,
+    };
+    let key = extract_balanced_braces(rest.strip_prefix('{')?)?;
+    let key = key.trim();
+    (!key.is_empty()).then(|| key.to_string())
+}
+
 /// Extract title from a bibitem entry.
 ///
 /// Tries in order:
@@ -718,6 +831,66 @@ Second entry content.
         assert!(entries[1].contains("Second entry"));
     }
 
+    #[test]
+    fn test_extract_cite_key() {
+        assert_eq!(
+            extract_cite_key("\\bibitem[Author1(2020)]{key1}\nFirst entry."),
+            Some("key1".into())
+        );
+        assert_eq!(
+            extract_cite_key("\\bibitem{plain:2019}\nEntry."),
+            Some("plain:2019".into())
+        );
+        // natbib labels nest braces and may contain `]` inside them
+        assert_eq!(
+            extract_cite_key("\\bibitem[{Doe et~al.(2023{]})}]\n  {doe2023} Entry."),
+            Some("doe2023".into())
+        );
+        assert_eq!(extract_cite_key("\\bibitem[Broken(2020)] Entry."), None);
+    }
+
+    #[test]
+    fn test_cite_key_kept_on_bbl_references() {
+        let bbl = r#"\begin{thebibliography}{2}
+\bibitem[Doe(2023)]{doe2023}
+J.~Doe.
+\newblock \bibinfo{title}{A Very Important Research Paper Title}.
+\bibitem{short1}
+\bibinfo{title}{Too short}.
+\end{thebibliography}"#;
+        let result = extract_references_from_bbl_str(bbl).unwrap();
+        let keys: Vec<_> = result
+            .references
+            .iter()
+            .map(|r| r.cite_key.as_deref())
+            .collect();
+        // Skipped references keep their key too.
+        assert_eq!(keys, [Some("doe2023"), Some("short1")]);
+        assert_eq!(
+            result.references[1].skip_reason.as_deref(),
+            Some("short_title")
+        );
+    }
+
+    #[test]
+    fn test_bbl_container_title_from_booktitle() {
+        let bbl = r#"\begin{thebibliography}{2}
+\bibitem{chapter}
+J.~Doe.
+\newblock \bibinfo{title}{A Chapter in an Edited Collection}.
+\newblock In \bibinfo{booktitle}{\emph{The Handbook of Things}}.
+\bibitem{book}
+\bibinfo{booktitle}{The Khrushchev Era and the Limits of Reform}.
+\end{thebibliography}"#;
+        let result = extract_references_from_bbl_str(bbl).unwrap();
+        assert_eq!(
+            result.references[0].container_title.as_deref(),
+            Some("The Handbook of Things")
+        );
+        // A booktitle used as the title is not its own container
+        assert_eq!(result.references[1].container_title, None);
+    }
+
     #[test]
     fn test_extract_authors() {
         let entry = r#"\bibfield{author}{\bibinfo{person}{Pantelis Agathangelou},
@@ -899,6 +1072,7 @@ Second entry content.
         assert!(r.authors[1].contains("Jane"));
         assert!(r.authors[1].contains("Smith"));
         assert_eq!(r.doi.as_deref(), Some("10.1234/test.2023"));
+        assert_eq!(r.cite_key.as_deref(), Some("doe2023"));
     }
 
     #[test]
@@ -915,6 +1089,10 @@ Second entry content.
         assert_eq!(result.references.len(), 1);
 
         let r = &result.references[0];
+        assert_eq!(
+            r.container_title.as_deref(),
+            Some("International Conference on Machine Learning")
+        );
         // biblatex should decode LaTeX accents
         let jegou = r.authors.iter().find(|a| a.contains("gou"));
         assert!(jegou.is_some(), "Should find Jégou author: {:?}", r.authors);
@@ -985,6 +1163,34 @@ Second entry content.
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_decode_windows_1252() {
+        assert_eq!(decode_windows_1252(b"caf\xe9"), "café");
+        assert_eq!(
+            decode_windows_1252(b"\x93quoted\x94 \x96 \x80"),
+            "“quoted” – €"
+        );
+    }
+
+    #[test]
+    fn test_bib_latin1_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(
+            &mut file,
+            b"@article{doe2020,\n  title = {Learning R\xe9sum\xe9 Representations for Job Matching},\n  author = {Doe, Ren\xe9 and Smith, Jane},\n  year = {2020}\n}\n",
+        )
+        .unwrap();
+
+        let result = extract_references_from_bib(file.path()).unwrap();
+        assert_eq!(result.references.len(), 1);
+        let r = &result.references[0];
+        assert_eq!(
+            r.title.as_deref(),
+            Some("Learning Résumé Representations for Job Matching")
+        );
+        assert!(r.authors.iter().any(|a| a.contains("René")));
+    }
+
     #[test]
     fn test_bib_integration_sample_file() {
         let path = Path::new(env!("CARGO_MANIFEST_DIR"))
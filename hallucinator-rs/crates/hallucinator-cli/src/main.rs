@@ -7,7 +7,7 @@ use tokio_util::sync::CancellationToken;
 
 mod output;
 
-use output::ColorMode;
+use output::{ColorMode, RawNumbers, ShowAuthors};
 
 /// Hallucinated Reference Detector - Detect fabricated references in academic PDFs
 #[derive(Parser, Debug)]
@@ -21,6 +21,12 @@ struct Cli {
     #[arg(long, global = true)]
     log: Option<PathBuf>,
 
+    /// Print counts and byte sizes as plain integers (no digit grouping or
+    /// KiB/MiB units) in progress and summary output. Also enabled by setting
+    /// HALLUCINATOR_RAW_NUMBERS=1
+    #[arg(long, global = true)]
+    raw_numbers: bool,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -30,8 +36,15 @@ struct Cli {
 enum Command {
     /// Check a PDF, .bbl, or .bib file for hallucinated references
     Check {
-        /// Path to the PDF, .bbl, or .bib file to check
-        file_path: PathBuf,
+        /// Path to the PDF, .bbl, or .bib file to check, or `-` to read plain
+        /// reference text from stdin (separate references with blank lines)
+        #[arg(required_unless_present = "input_list")]
+        file_path: Option<PathBuf>,
+
+        /// Text file listing one PDF/.bbl/.bib path per line; each is checked
+        /// like a file in an archive, with an aggregate summary at the end
+        #[arg(long, conflicts_with_all = ["file_path", "dry_run", "dump"])]
+        input_list: Option<PathBuf>,
 
         /// Disable colored output
         #[arg(long)]
@@ -61,6 +74,11 @@ enum Command {
         #[arg(long)]
         openalex_offline: Option<PathBuf>,
 
+        /// When an offline database is over 30 days old, ask its upstream
+        /// whether it changed before warning (one request per database)
+        #[arg(long)]
+        check_db_updates: bool,
+
         /// Comma-separated list of databases to disable
         #[arg(long, value_delimiter = ',')]
         disable_dbs: Vec<String>,
@@ -69,6 +87,31 @@ enum Command {
         #[arg(long)]
         check_openalex_authors: bool,
 
+        /// Verify a reference whose DOI resolves to the cited title without
+        /// searching any other database by title
+        #[arg(long)]
+        trust_resolved_doi: bool,
+
+        /// Also search other databases by title for references with a DOI,
+        /// and flag any whose DOI resolves to a different paper
+        #[arg(long)]
+        cross_validate_doi: bool,
+
+        /// Verify a cited preprint that matches its published version (or
+        /// vice versa) even when the author lists differ
+        #[arg(long)]
+        accept_preprint_versions: bool,
+
+        /// Only check that each cited paper exists: a title match is
+        /// verified whatever its authors, and no author mismatches are reported
+        #[arg(long)]
+        existence_only: bool,
+
+        /// Comma-separated list of databases whose match settles a reference
+        /// without waiting for (or cross-checking against) the others
+        #[arg(long, value_delimiter = ',')]
+        trusted_verifiers: Vec<String>,
+
         /// Number of concurrent reference checks (default: 4)
         #[arg(long)]
         num_workers: Option<usize>,
@@ -77,19 +120,103 @@ enum Command {
         #[arg(long)]
         max_rate_limit_retries: Option<u32>,
 
+        /// Re-check references left unverified by a database timeout or
+        /// error up to N more times (default: 0)
+        #[arg(long, value_name = "N")]
+        retry_passes: Option<u32>,
+
+        /// Fewest online databases that must answer (not time out, error or
+        /// be rate limited) for a not-found result to be reported as a
+        /// confident negative; offline databases don't count (default: 1)
+        #[arg(long, value_name = "N")]
+        min_databases: Option<usize>,
+
+        /// Title similarity threshold (0.0-1.0) for offline database matches
+        /// (default: each database's built-in threshold)
+        #[arg(long, value_parser = parse_threshold)]
+        threshold: Option<f64>,
+
+        /// Similarity an offline match needs when the cited title has only
+        /// one or two distinctive words (default: 0.97)
+        #[arg(long, value_parser = parse_threshold, value_name = "X")]
+        short_query_threshold: Option<f64>,
+
+        /// When no offline title shares a cited title's keywords, retry with
+        /// a looser lookup (word prefixes, or OpenAlex title trigrams) to
+        /// catch typos and OCR errors. Slower on not-found references
+        #[arg(long)]
+        loose_title_fallback: bool,
+
+        /// How offline databases score candidate titles against the cited one
+        #[arg(long, value_enum, default_value_t = Scorer::Ratio)]
+        title_scorer: Scorer,
+
+        /// Most references to check per document (default: 2000, 0 = no limit)
+        #[arg(long)]
+        max_references: Option<usize>,
+
+        /// What to do with a document over --max-references
+        #[arg(long, value_enum, default_value_t = OverLimit::Truncate)]
+        on_too_many_references: OverLimit,
+
+        /// Seconds to wait for a PDF's text before giving up on the file
+        /// (default: 120, 0 = no limit)
+        #[arg(long, value_name = "SECS")]
+        extraction_timeout: Option<u64>,
+
+        /// Scan each PDF's body for in-text citations and report references
+        /// that are listed but never cited
+        #[arg(long)]
+        check_citations: bool,
+
+        /// Read short references set two-up within a PDF text block column
+        /// by column instead of row by row
+        #[arg(long)]
+        pdf_columns: bool,
+
+        /// TOML/JSON file mapping reference titles or fingerprints to a known
+        /// DOI or URL; matching references verify without any database query
+        #[arg(long)]
+        overrides: Option<PathBuf>,
+
         /// Dry run: extract and print references without querying databases
         #[arg(long)]
         dry_run: bool,
 
+        /// Output format for --dry-run
+        #[arg(long, value_enum, default_value_t = DryRunFormat::Human, requires = "dry_run")]
+        format: DryRunFormat,
+
+        /// Dry run that prints only one extracted field per reference next to
+        /// its raw citation (implies --dry-run)
+        #[arg(long, value_enum)]
+        dump: Option<DumpField>,
+
         /// Enable SearxNG web search fallback for unverified citations.
         /// Uses SEARXNG_URL env var or defaults to http://localhost:8080
         #[arg(long)]
         searxng: bool,
 
+        /// Send all database queries through this proxy (http://host:port).
+        /// Without it, the config file's [network] http_proxy or
+        /// HTTP_PROXY/HTTPS_PROXY/NO_PROXY apply
+        #[arg(long, value_name = "URL")]
+        http_proxy: Option<String>,
+
+        /// PEM file of extra CA certificates to trust, e.g. for a
+        /// TLS-intercepting proxy
+        #[arg(long, value_name = "PATH")]
+        ca_cert: Option<PathBuf>,
+
         /// Path to persistent query cache database (SQLite)
         #[arg(long)]
         cache_path: Option<PathBuf>,
 
+        /// Most query results kept in the cache; the least recently used are
+        /// evicted beyond this (default: unbounded)
+        #[arg(long)]
+        cache_max_entries: Option<usize>,
+
         /// Clear the query cache and exit
         #[arg(long)]
         clear_cache: bool,
@@ -98,21 +225,88 @@ enum Command {
         #[arg(long)]
         clear_not_found: bool,
 
+        /// Print how many entries of each type the cache holds and how old
+        /// they are, then exit
+        #[arg(long, conflicts_with_all = ["clear_cache", "clear_not_found"])]
+        cache_stats: bool,
+
         /// Export results as JSON to this path (compatible with hallucinator-tui --load)
         #[arg(long)]
         json: Option<PathBuf>,
+
+        /// Write NotFound/AuthorMismatch references to this path as BibTeX
+        /// entries, ready to send to authors or re-check
+        #[arg(long)]
+        export_unverified: Option<PathBuf>,
+
+        /// Append a one-line JSON summary (counts and elapsed time) per
+        /// checked file to this path, for tracking results over time
+        #[arg(long, value_name = "PATH")]
+        metrics_file: Option<PathBuf>,
+
+        /// Fail (non-zero exit) when more than this fraction of a document's
+        /// references were skipped during extraction (default: 0.1)
+        #[arg(
+            long,
+            value_name = "FRACTION",
+            num_args = 0..=1,
+            default_missing_value = "0.1",
+            value_parser = parse_fraction
+        )]
+        strict_extraction: Option<f64>,
+
+        /// Most files of an archive or --input-list to check at once
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = DEFAULT_MAX_CONCURRENT_FILES,
+            value_parser = parse_file_limit
+        )]
+        max_concurrent_files: usize,
+
+        /// Check every file of an archive or input list, even ones identical
+        /// to a file already extracted or listed
+        #[arg(long)]
+        keep_duplicates: bool,
+
+        /// After each verified reference, list the authors of the record it
+        /// matched, for comparing against the cited authors
+        #[arg(long)]
+        show_authors: bool,
     },
 
     /// Download and build the offline DBLP database
     UpdateDblp {
         /// Path to store the DBLP SQLite database
         path: PathBuf,
+
+        /// Times to retry a failed connection or interrupted download
+        #[arg(long, default_value_t = hallucinator_dblp::DEFAULT_MAX_RETRIES)]
+        max_retries: u32,
+
+        /// Only index publications from this year or later (e.g. 2015)
+        #[arg(long)]
+        min_year: Option<u32>,
+
+        #[command(flatten)]
+        network: NetworkArgs,
     },
 
     /// Download and build the offline ACL Anthology database
     UpdateAcl {
         /// Path to store the ACL SQLite database
         path: PathBuf,
+
+        /// Times to retry a failed request or interrupted download
+        #[arg(long, default_value_t = hallucinator_acl::DEFAULT_MAX_RETRIES)]
+        max_retries: u32,
+
+        /// Only index papers from this year or later (e.g. 2015)
+        #[arg(long)]
+        min_year: Option<u32>,
+
+        #[command(flatten)]
+        network: NetworkArgs,
     },
 
     /// Download and build the offline OpenAlex Tantivy index
@@ -121,12 +315,37 @@ enum Command {
         path: PathBuf,
 
         /// Only download S3 partitions newer than this date (YYYY-MM-DD)
-        #[arg(long)]
+        #[arg(long, value_parser = parse_date)]
         since: Option<String>,
 
         /// Only index works published in this year or later (e.g. 2020)
         #[arg(long)]
         min_year: Option<u32>,
+
+        /// Also index title trigrams for fuzzier candidate retrieval
+        /// (roughly doubles index size; only applies to a new index)
+        #[arg(long)]
+        title_ngrams: bool,
+
+        #[command(flatten)]
+        network: NetworkArgs,
+    },
+
+    /// Compare two saved result JSON files and report changed references
+    Diff {
+        /// Results from the earlier run
+        old: PathBuf,
+
+        /// Results from the later run
+        new: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = DiffFormat::Human)]
+        format: DiffFormat,
+
+        /// Disable colored output
+        #[arg(long)]
+        no_color: bool,
     },
 }
 
@@ -180,16 +399,68 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
+    let numbers = RawNumbers::new(
+        cli.raw_numbers,
+        std::env::var("HALLUCINATOR_RAW_NUMBERS").ok().as_deref(),
+    );
+
     match cli.command {
-        Command::UpdateDblp { path } => update_dblp(&path).await,
-        Command::UpdateAcl { path } => update_acl(&path).await,
+        Command::UpdateDblp {
+            path,
+            max_retries,
+            min_year,
+            network,
+        } => {
+            update_dblp(
+                &path,
+                max_retries,
+                min_year,
+                network.client(&file_config)?,
+                numbers,
+            )
+            .await
+        }
+        Command::UpdateAcl {
+            path,
+            max_retries,
+            min_year,
+            network,
+        } => {
+            update_acl(
+                &path,
+                max_retries,
+                min_year,
+                network.client(&file_config)?,
+                numbers,
+            )
+            .await
+        }
         Command::UpdateOpenalex {
             path,
             since,
             min_year,
-        } => update_openalex(&path, since.as_deref(), min_year).await,
+            title_ngrams,
+            network,
+        } => {
+            update_openalex(
+                &path,
+                since.as_deref(),
+                min_year,
+                title_ngrams,
+                network.client(&file_config)?,
+                numbers,
+            )
+            .await
+        }
+        Command::Diff {
+            old,
+            new,
+            format,
+            no_color,
+        } => diff(&old, &new, format, no_color),
         Command::Check {
             file_path,
+            input_list,
             no_color,
             openalex_key,
             s2_api_key,
@@ -197,18 +468,48 @@ async fn main() -> anyhow::Result<()> {
             dblp_offline,
             acl_offline,
             openalex_offline,
+            check_db_updates,
             disable_dbs,
             check_openalex_authors,
+            trust_resolved_doi,
+            cross_validate_doi,
+            accept_preprint_versions,
+            existence_only,
+            trusted_verifiers,
             num_workers,
             max_rate_limit_retries,
+            retry_passes,
+            min_databases,
+            threshold,
+            short_query_threshold,
+            loose_title_fallback,
+            title_scorer,
+            max_references,
+            on_too_many_references,
+            extraction_timeout,
+            check_citations,
+            pdf_columns,
+            overrides,
             dry_run,
+            format,
+            dump,
             searxng,
+            http_proxy,
+            ca_cert,
             cache_path,
+            cache_max_entries,
             clear_cache,
             clear_not_found,
+            cache_stats,
             json,
+            export_unverified,
+            metrics_file,
+            strict_extraction,
+            max_concurrent_files,
+            keep_duplicates,
+            show_authors,
         } => {
-            if clear_cache || clear_not_found {
+            if clear_cache || clear_not_found || cache_stats {
                 let path = cache_path
                     .or_else(|| {
                         std::env::var("HALLUCINATOR_CACHE_PATH")
@@ -223,6 +524,22 @@ async fn main() -> anyhow::Result<()> {
                             .map(PathBuf::from)
                     });
                 return match path {
+                    Some(p) if p.exists() && cache_stats => {
+                        let stats = hallucinator_core::QueryCache::read_stats(&p)
+                            .map_err(|e| anyhow::anyhow!("{}", e))?;
+                        let now = std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .unwrap_or_default()
+                            .as_secs();
+                        output::print_cache_stats(
+                            &mut std::io::stdout(),
+                            &p,
+                            &stats,
+                            now,
+                            numbers,
+                        )?;
+                        Ok(())
+                    }
                     Some(p) if p.exists() => {
                         let cache = hallucinator_core::QueryCache::open(
                             &p,
@@ -254,11 +571,19 @@ async fn main() -> anyhow::Result<()> {
                     }
                 };
             }
-            if dry_run {
-                dry_run_check(file_path, no_color, output).await
+            if file_path.as_deref().is_some_and(is_stdin_path) && (dump.is_some() || dry_run) {
+                anyhow::bail!("--dump and --dry-run need a file path, not stdin");
+            }
+            if let Some(field) = dump {
+                let file_path = file_path.expect("clap requires FILE_PATH without --input-list");
+                dump_check(file_path, field, pdf_columns, no_color, output)
+            } else if dry_run {
+                let file_path = file_path.expect("clap requires FILE_PATH without --input-list");
+                dry_run_check(file_path, format, pdf_columns, no_color, output).await
             } else {
                 check(
                     file_path,
+                    input_list,
                     no_color,
                     openalex_key,
                     s2_api_key,
@@ -266,15 +591,42 @@ async fn main() -> anyhow::Result<()> {
                     dblp_offline,
                     acl_offline,
                     openalex_offline,
+                    check_db_updates,
                     disable_dbs,
                     check_openalex_authors,
+                    trust_resolved_doi,
+                    cross_validate_doi,
+                    accept_preprint_versions,
+                    existence_only,
+                    trusted_verifiers,
                     num_workers,
                     max_rate_limit_retries,
+                    retry_passes,
+                    min_databases,
+                    threshold,
+                    short_query_threshold,
+                    loose_title_fallback,
+                    title_scorer,
+                    max_references,
+                    on_too_many_references,
+                    extraction_timeout,
+                    check_citations,
+                    pdf_columns,
+                    overrides,
                     searxng,
+                    http_proxy,
+                    ca_cert,
                     cache_path,
+                    cache_max_entries,
                     file_config,
                     config_source,
                     json,
+                    export_unverified,
+                    metrics_file,
+                    strict_extraction,
+                    max_concurrent_files,
+                    keep_duplicates,
+                    show_authors,
                 )
                 .await
             }
@@ -282,6 +634,188 @@ async fn main() -> anyhow::Result<()> {
     }
 }
 
+/// Proxy and CA settings for the `update-*` downloads.
+#[derive(clap::Args, Debug)]
+struct NetworkArgs {
+    /// Download through this proxy (http://host:port). Without it, the
+    /// config file's [network] http_proxy or HTTP_PROXY/HTTPS_PROXY/NO_PROXY
+    /// apply
+    #[arg(long, value_name = "URL")]
+    http_proxy: Option<String>,
+
+    /// PEM file of extra CA certificates to trust, e.g. for a
+    /// TLS-intercepting proxy
+    #[arg(long, value_name = "PATH")]
+    ca_cert: Option<PathBuf>,
+}
+
+impl NetworkArgs {
+    /// The download client, with these flags over the config file's
+    /// `[network]` section. Fails on an invalid proxy URL or certificate.
+    fn client(
+        self,
+        file_config: &hallucinator_core::config_file::ConfigFile,
+    ) -> anyhow::Result<reqwest::Client> {
+        let (http_proxy, extra_ca_cert) =
+            network_settings(self.http_proxy, self.ca_cert, file_config);
+        Ok(hallucinator_core::http::build_client_for(
+            &hallucinator_core::Config {
+                http_proxy,
+                extra_ca_cert,
+                ..Default::default()
+            },
+        )?)
+    }
+}
+
+/// `--http-proxy` and `--ca-cert`, falling back to the config file's
+/// `[network]` section.
+fn network_settings(
+    http_proxy: Option<String>,
+    ca_cert: Option<PathBuf>,
+    file_config: &hallucinator_core::config_file::ConfigFile,
+) -> (Option<String>, Option<PathBuf>) {
+    let network = file_config.network.as_ref();
+    (
+        http_proxy.or_else(|| {
+            network
+                .and_then(|n| n.http_proxy.clone())
+                .filter(|p| !p.is_empty())
+        }),
+        ca_cert.or_else(|| {
+            network
+                .and_then(|n| n.extra_ca_cert.as_ref())
+                .filter(|p| !p.is_empty())
+                .map(PathBuf::from)
+        }),
+    )
+}
+
+/// Field printed by `check --dump`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum DumpField {
+    /// Extracted author list per reference
+    Authors,
+}
+
+/// Output format for `check --dry-run`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum DryRunFormat {
+    /// Readable listing of each extracted reference
+    Human,
+    /// The extraction result (references, including skipped ones with their
+    /// reason, and skip counts) as JSON
+    Json,
+}
+
+/// Output format for `diff`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum DiffFormat {
+    /// Readable summary grouped by kind of change
+    Human,
+    /// JSON array of changes
+    Json,
+}
+
+/// Action for `check --on-too-many-references`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum OverLimit {
+    /// Check only the first --max-references references
+    Truncate,
+    /// Skip the document with an error
+    Abort,
+}
+
+impl From<OverLimit> for hallucinator_core::ReferenceLimitAction {
+    fn from(action: OverLimit) -> Self {
+        match action {
+            OverLimit::Truncate => Self::Truncate,
+            OverLimit::Abort => Self::Abort,
+        }
+    }
+}
+
+/// Scorer for `check --title-scorer`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum Scorer {
+    /// Character similarity of the whole titles; strict about word order
+    Ratio,
+    /// Like ratio, after sorting each title's words
+    TokenSort,
+    /// Compare shared words; a title contained in the other matches fully
+    TokenSet,
+}
+
+impl From<Scorer> for hallucinator_core::TitleScorer {
+    fn from(scorer: Scorer) -> Self {
+        match scorer {
+            Scorer::Ratio => Self::Ratio,
+            Scorer::TokenSort => Self::TokenSort,
+            Scorer::TokenSet => Self::TokenSet,
+        }
+    }
+}
+
+/// Parse and range-check a `--threshold` value.
+fn parse_threshold(s: &str) -> Result<f64, String> {
+    let value: f64 = s.parse().map_err(|_| format!("'{}' is not a number", s))?;
+    check_threshold(value)
+}
+
+/// Parse an `--since` date, rejecting anything that isn't a real YYYY-MM-DD date.
+fn parse_date(s: &str) -> Result<String, String> {
+    if hallucinator_openalex::is_valid_date(s) {
+        Ok(s.to_string())
+    } else {
+        Err(format!("'{}' is not a valid YYYY-MM-DD date", s))
+    }
+}
+
+/// Range-check a similarity threshold.
+fn check_threshold(value: f64) -> Result<f64, String> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "threshold must be between 0.0 and 1.0, got {}",
+            value
+        ))
+    }
+}
+
+/// Parse and range-check a `--strict-extraction` fraction.
+fn parse_fraction(s: &str) -> Result<f64, String> {
+    let value: f64 = s.parse().map_err(|_| format!("'{}' is not a number", s))?;
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "fraction must be between 0.0 and 1.0, got {}",
+            value
+        ))
+    }
+}
+
+/// Files checked at once by default in archive and `--input-list` runs.
+const DEFAULT_MAX_CONCURRENT_FILES: usize = 2;
+
+/// Parse a `--max-concurrent-files` count, which must be at least 1.
+fn parse_file_limit(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("must check at least 1 file at a time".to_string()),
+        Ok(n) => Ok(n),
+        Err(_) => Err(format!("'{}' is not a whole number", s)),
+    }
+}
+
+/// Whether `skip_stats` exceeds the `--strict-extraction` limit, if one is set.
+fn fails_strict_extraction(
+    skip_stats: &hallucinator_core::SkipStats,
+    strict_extraction: Option<f64>,
+) -> Option<f64> {
+    strict_extraction.filter(|&max| skip_stats.skipped_fraction() > max)
+}
+
 /// Saved reference metadata for --json export (captured before check_references consumes refs).
 struct RefMeta {
     original_number: usize,
@@ -337,7 +871,7 @@ fn build_report_data(
     // Compute stats from the non-skipped results
     let mut stats = hallucinator_core::CheckStats {
         total: ref_meta.len(),
-        skipped: skip_stats.url_only + skip_stats.short_title + skip_stats.no_title,
+        skipped: skip_stats.skipped(),
         ..Default::default()
     };
     for result in results_vec.iter().flatten() {
@@ -358,9 +892,27 @@ fn build_report_data(
     ((), report_refs, results_vec, stats)
 }
 
+/// How long to wait for an offline database's upstream to say whether it
+/// changed before warning about its age anyway.
+const REMOTE_STALENESS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Whether an old offline database is still stale once its upstream is
+/// asked. An upstream that errors or doesn't answer in time counts as
+/// changed, so the age warning still shows.
+async fn still_stale<T, E>(
+    remote_check: impl std::future::Future<Output = Result<T, E>>,
+    is_stale: impl FnOnce(&T) -> bool,
+) -> bool {
+    match tokio::time::timeout(REMOTE_STALENESS_TIMEOUT, remote_check).await {
+        Ok(Ok(check)) => is_stale(&check),
+        _ => true,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn check(
-    file_path: PathBuf,
+    file_path: Option<PathBuf>,
+    input_list: Option<PathBuf>,
     no_color: bool,
     openalex_key: Option<String>,
     s2_api_key: Option<String>,
@@ -368,15 +920,42 @@ async fn check(
     dblp_offline: Option<PathBuf>,
     acl_offline: Option<PathBuf>,
     openalex_offline: Option<PathBuf>,
+    check_db_updates: bool,
     disable_dbs: Vec<String>,
     check_openalex_authors: bool,
+    trust_resolved_doi: bool,
+    cross_validate_doi: bool,
+    accept_preprint_versions: bool,
+    existence_only: bool,
+    trusted_verifiers: Vec<String>,
     num_workers: Option<usize>,
     max_rate_limit_retries: Option<u32>,
+    retry_passes: Option<u32>,
+    min_databases: Option<usize>,
+    threshold: Option<f64>,
+    short_query_threshold: Option<f64>,
+    loose_title_fallback: bool,
+    title_scorer: Scorer,
+    max_references: Option<usize>,
+    on_too_many_references: OverLimit,
+    extraction_timeout: Option<u64>,
+    check_citations: bool,
+    pdf_columns: bool,
+    overrides_path: Option<PathBuf>,
     searxng: bool,
+    http_proxy: Option<String>,
+    ca_cert: Option<PathBuf>,
     cache_path: Option<PathBuf>,
+    cache_max_entries: Option<usize>,
     file_config: hallucinator_core::config_file::ConfigFile,
     config_source: Option<PathBuf>,
     json_output: Option<PathBuf>,
+    export_unverified: Option<PathBuf>,
+    metrics_file: Option<PathBuf>,
+    strict_extraction: Option<f64>,
+    max_concurrent_files: usize,
+    keep_duplicates: bool,
+    show_authors: bool,
 ) -> anyhow::Result<()> {
     // Print config file source
     match &config_source {
@@ -484,6 +1063,7 @@ async fn check(
     // Determine color mode and output writer
     let use_color = !no_color && output.is_none();
     let color = ColorMode(use_color);
+    let show_authors = ShowAuthors(show_authors);
 
     let mut writer: Box<dyn Write> = if let Some(ref output_path) = output {
         Box::new(std::fs::File::create(output_path)?)
@@ -491,6 +1071,27 @@ async fn check(
         Box::new(std::io::stdout())
     };
 
+    let crossref_mailto: Option<String> = std::env::var("CROSSREF_MAILTO")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .or_else(|| {
+            file_config
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.crossref_mailto.clone())
+        });
+
+    // One client for the whole run, shared by the update checks below and
+    // every file in an archive. A bad proxy URL or certificate file fails
+    // here rather than per query.
+    let (http_proxy, ca_cert) = network_settings(http_proxy, ca_cert, &file_config);
+    let http_client = hallucinator_core::http::build_client_for(&hallucinator_core::Config {
+        crossref_mailto: crossref_mailto.clone(),
+        http_proxy: http_proxy.clone(),
+        extra_ca_cert: ca_cert.clone(),
+        ..Default::default()
+    })?;
+
     // Open offline DBLP database if configured
     let dblp_offline_db = if let Some(ref path) = dblp_offline_path {
         if !path.exists() {
@@ -505,6 +1106,18 @@ async fn check(
         // Check staleness
         if let Ok(staleness) = db.check_staleness(30)
             && staleness.is_stale
+            && (!check_db_updates
+                || still_stale(
+                    db.check_remote_staleness(
+                        30,
+                        &hallucinator_dblp::BuildOptions {
+                            client: Some(http_client.clone()),
+                            ..Default::default()
+                        },
+                    ),
+                    |s| s.is_stale,
+                )
+                .await)
         {
             let msg = if let Some(days) = staleness.age_days {
                 format!(
@@ -545,6 +1158,18 @@ async fn check(
 
         if let Ok(staleness) = db.check_staleness(30)
             && staleness.is_stale
+            && (!check_db_updates
+                || still_stale(
+                    db.check_remote_staleness(
+                        30,
+                        &hallucinator_acl::BuildOptions {
+                            client: Some(http_client.clone()),
+                            ..Default::default()
+                        },
+                    ),
+                    |s| s.is_stale,
+                )
+                .await)
         {
             let msg = if let Some(days) = staleness.age_days {
                 format!(
@@ -586,6 +1211,18 @@ async fn check(
 
         if let Ok(staleness) = db.check_staleness(30)
             && staleness.is_stale
+            && (!check_db_updates
+                || still_stale(
+                    db.check_remote_staleness(
+                        30,
+                        &hallucinator_openalex::BuildOptions {
+                            client: Some(http_client.clone()),
+                            ..Default::default()
+                        },
+                    ),
+                    |s| s.is_stale,
+                )
+                .await)
         {
             let msg = if let Some(days) = staleness.age_days {
                 format!(
@@ -613,19 +1250,33 @@ async fn check(
         None
     };
 
-    if !file_path.exists() {
+    // Read --input-list up front so an unreadable list fails before any setup.
+    let listed = match &input_list {
+        Some(list_path) => Some(
+            hallucinator_ingest::read_input_list(list_path, !keep_duplicates).map_err(|e| {
+                anyhow::anyhow!("Cannot read input list {}: {}", list_path.display(), e)
+            })?,
+        ),
+        None => None,
+    };
+    let file_path = file_path.unwrap_or_default();
+    let from_stdin = is_stdin_path(&file_path);
+    if listed.is_none() && !from_stdin && !file_path.exists() {
         anyhow::bail!("File not found: {}", file_path.display());
     }
 
-    let crossref_mailto: Option<String> = std::env::var("CROSSREF_MAILTO")
-        .ok()
-        .filter(|s| !s.is_empty())
-        .or_else(|| {
-            file_config
-                .api_keys
-                .as_ref()
-                .and_then(|a| a.crossref_mailto.clone())
-        });
+    let known_db = |name: &str| {
+        hallucinator_core::db::DATABASE_NAMES
+            .iter()
+            .any(|db| db.eq_ignore_ascii_case(name))
+    };
+    if let Some(name) = trusted_verifiers.iter().find(|name| !known_db(name)) {
+        anyhow::bail!(
+            "Unknown database in --trusted-verifiers: {} (expected one of: {})",
+            name,
+            hallucinator_core::db::DATABASE_NAMES.join(", ")
+        );
+    }
 
     // Merge disable_dbs: CLI flags + config file disabled list
     let disable_dbs = if disable_dbs.is_empty() {
@@ -650,6 +1301,40 @@ async fn check(
                 .and_then(|c| c.max_rate_limit_retries)
         })
         .unwrap_or(3);
+    let retry_passes = retry_passes
+        .or_else(|| {
+            file_config
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.retry_passes)
+        })
+        .unwrap_or(0);
+    let min_databases_queried = min_databases
+        .or_else(|| {
+            file_config
+                .databases
+                .as_ref()
+                .and_then(|d| d.min_databases_queried)
+        })
+        .unwrap_or(hallucinator_core::DEFAULT_MIN_DATABASES_QUERIED);
+    let threshold = match threshold {
+        Some(t) => Some(t),
+        None => file_config
+            .databases
+            .as_ref()
+            .and_then(|d| d.match_threshold)
+            .map(check_threshold)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("[databases] match_threshold: {}", e))?,
+    };
+    let thresholds = file_config
+        .databases
+        .as_ref()
+        .and_then(|d| d.thresholds.clone())
+        .unwrap_or_default();
+    for (db, t) in &thresholds {
+        check_threshold(*t).map_err(|e| anyhow::anyhow!("[databases.thresholds] {}: {}", db, e))?;
+    }
     let rate_limiters = std::sync::Arc::new(hallucinator_core::RateLimiters::new(
         crossref_mailto.is_some(),
         s2_api_key.is_some(),
@@ -668,10 +1353,27 @@ async fn check(
                 .and_then(|d| d.cache_path.as_ref())
                 .map(PathBuf::from)
         });
+    let cache_max_entries = cache_max_entries.or_else(|| {
+        file_config
+            .databases
+            .as_ref()
+            .and_then(|d| d.cache_max_entries)
+    });
     let positive_ttl = hallucinator_core::DEFAULT_POSITIVE_TTL.as_secs();
     let negative_ttl = hallucinator_core::DEFAULT_NEGATIVE_TTL.as_secs();
-    let query_cache =
-        hallucinator_core::build_query_cache(cache_path.as_deref(), positive_ttl, negative_ttl);
+    let query_cache = hallucinator_core::build_query_cache(
+        cache_path.as_deref(),
+        positive_ttl,
+        negative_ttl,
+        cache_max_entries,
+    );
+
+    // Fail fast on a bad override file rather than warning once per paper.
+    let overrides = overrides_path
+        .as_deref()
+        .map(hallucinator_core::overrides::Overrides::load)
+        .transpose()?
+        .map(Arc::new);
 
     let config = hallucinator_core::Config {
         openalex_key: openalex_key.clone(),
@@ -689,36 +1391,141 @@ async fn check(
         check_openalex_authors,
         crossref_mailto,
         max_rate_limit_retries,
+        retry_passes,
+        min_databases_queried,
         rate_limiters,
         searxng_url,
         query_cache: Some(query_cache),
         cache_path,
         cache_positive_ttl_secs: positive_ttl,
         cache_negative_ttl_secs: negative_ttl,
+        near_miss_margin: hallucinator_core::DEFAULT_NEAR_MISS_MARGIN,
+        match_threshold: threshold,
+        thresholds,
+        title_scorer: title_scorer.into(),
+        query_guard: {
+            let mut guard = hallucinator_core::QueryGuard::default();
+            if let Some(floor) = short_query_threshold {
+                guard.short_query_threshold = floor;
+            }
+            guard.loose_fallback = loose_title_fallback;
+            guard
+        },
+        http_client: Some(http_client),
+        http_proxy,
+        extra_ca_cert: ca_cert,
+        max_references: match max_references {
+            Some(0) => None,
+            Some(n) => Some(n),
+            None => Some(hallucinator_core::DEFAULT_MAX_REFERENCES),
+        },
+        max_references_action: on_too_many_references.into(),
+        extraction_timeout: match extraction_timeout {
+            Some(0) => None,
+            Some(secs) => Some(std::time::Duration::from_secs(secs)),
+            None => Some(hallucinator_core::DEFAULT_EXTRACTION_TIMEOUT),
+        },
+        check_in_text_citations: check_citations
+            || file_config
+                .databases
+                .as_ref()
+                .and_then(|d| d.check_citations)
+                .unwrap_or(false),
+        pdf_column_detection: pdf_columns,
+        overrides_path,
+        overrides,
+        trust_resolved_doi,
+        cross_validate_doi,
+        accept_preprint_versions,
+        trusted_verifiers,
+        mode: if existence_only
+            || file_config
+                .databases
+                .as_ref()
+                .and_then(|d| d.existence_only)
+                .unwrap_or(false)
+        {
+            hallucinator_core::VerificationMode::ExistenceOnly
+        } else {
+            hallucinator_core::VerificationMode::Full
+        },
     };
 
+    // Handle input lists: check each listed file independently
+    if let (Some(list_path), Some(listed)) = (input_list, listed) {
+        return run_list_check(
+            &list_path,
+            listed,
+            config,
+            output,
+            color,
+            show_authors,
+            json_output,
+            export_unverified,
+            metrics_file,
+            strict_extraction,
+            max_concurrent_files,
+        )
+        .await;
+    }
+
     // Handle archives: extract each file and run check on each independently
     if hallucinator_ingest::is_archive_path(&file_path) {
-        return run_archive_check(&file_path, config, output, color, json_output).await;
+        return run_archive_check(
+            &file_path,
+            config,
+            output,
+            color,
+            show_authors,
+            json_output,
+            export_unverified,
+            metrics_file,
+            strict_extraction,
+            max_concurrent_files,
+            keep_duplicates,
+        )
+        .await;
     }
 
-    // Single file: extract then check
-    let extraction = hallucinator_ingest::extract_references(&file_path)
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    // Single file (or pasted text on stdin): extract then check
+    let started = std::time::Instant::now();
+    let (mut extraction, title) = if from_stdin {
+        (extract_from_reader(std::io::stdin().lock())?, None)
+    } else {
+        hallucinator_ingest::extract_references_and_title(
+            &file_path,
+            &hallucinator_ingest::ExtractOptions::from(&config),
+        )
+        .map_err(|e| anyhow::anyhow!("{}", e))?
+    };
+    config.enforce_reference_limit(&mut extraction)?;
 
-    let file_name = file_path
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| file_path.display().to_string());
+    let file_name = if from_stdin {
+        "<stdin>".to_string()
+    } else {
+        file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_path.display().to_string())
+    };
+    let label = hallucinator_reporting::paper_label(&file_name, title.as_deref());
 
     output::print_extraction_summary(
         &mut writer,
-        &file_name,
+        &label,
         extraction.references.len(),
         &extraction.skip_stats,
         color,
     )?;
 
+    if let Some(max) = fails_strict_extraction(&extraction.skip_stats, strict_extraction) {
+        output::print_skip_breakdown(&mut writer, &extraction.skip_stats, max, color)?;
+        anyhow::bail!(
+            "{}: too many references skipped during extraction (--strict-extraction)",
+            file_name
+        );
+    }
+
     if extraction.references.is_empty() {
         writeln!(writer, "No references to check.")?;
         return Ok(());
@@ -736,7 +1543,7 @@ async fn check(
         let pw = Arc::clone(&progress_writer);
         move |event: hallucinator_core::ProgressEvent| {
             if let Ok(mut w) = pw.lock() {
-                let _ = output::print_progress(&mut *w, &event, progress_color);
+                let _ = output::print_progress(&mut *w, &event, progress_color, show_authors);
                 let _ = w.flush();
             }
         }
@@ -764,10 +1571,12 @@ async fn check(
             skip_reason: r.skip_reason.clone(),
         })
         .collect();
+    let shared_dois = hallucinator_core::doi::find_shared_dois(&extraction.references);
+    let titles: Vec<String> = ref_meta.iter().map(|m| m.title.clone()).collect();
 
     let results =
         hallucinator_core::check_references(extraction.references, config, progress_cb, cancel)
-            .await;
+            .await?;
 
     // Print final report
     writeln!(writer)?;
@@ -775,48 +1584,87 @@ async fn check(
     output::print_hallucination_report(&mut writer, &results, openalex_key.is_some(), color)?;
 
     output::print_doi_issues(&mut writer, &results, color)?;
+    output::print_shared_dois(&mut writer, &shared_dois, &titles, color)?;
     output::print_retraction_warnings(&mut writer, &results, color)?;
+    output::print_published_versions(&mut writer, &results, color)?;
+    output::print_version_matches(&mut writer, &results, color)?;
+    output::print_uncited_references(&mut writer, &results, color)?;
     output::print_summary(&mut writer, &results, &skip_stats, color)?;
 
-    // --json export
-    if let Some(json_path) = json_output {
-        let (_, report_refs, results_vec, stats) =
-            build_report_data(&file_name, &results, &ref_meta, &skip_stats);
-        let paper = hallucinator_reporting::ReportPaper {
+    let (_, report_refs, results_vec, stats) =
+        build_report_data(&file_name, &results, &ref_meta, &skip_stats);
+    if let Some(path) = &metrics_file {
+        write_metrics(path, &file_name, &stats, started.elapsed());
+    }
+
+    // --json / --export-unverified export
+    if json_output.is_some() || export_unverified.is_some() {
+        let papers = [hallucinator_reporting::ReportPaper {
             filename: &file_name,
+            title: title.as_deref(),
             stats: &stats,
             results: &results_vec,
             verdict: None,
-        };
+        }];
         let ref_slices: &[&[hallucinator_reporting::ReportRef]] = &[&report_refs];
-        hallucinator_reporting::export_results(
-            &[paper],
-            ref_slices,
-            hallucinator_reporting::ExportFormat::Json,
-            &json_path,
-            false,
-        )
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
-        eprintln!("Results saved to {}", json_path.display());
+        if let Some(json_path) = json_output {
+            hallucinator_reporting::export_results(
+                &papers,
+                ref_slices,
+                hallucinator_reporting::ExportFormat::Json,
+                &json_path,
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            eprintln!("Results saved to {}", json_path.display());
+        }
+        if let Some(bib_path) = export_unverified {
+            hallucinator_reporting::export_results(
+                &papers,
+                ref_slices,
+                hallucinator_reporting::ExportFormat::Bibtex,
+                &bib_path,
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            eprintln!("Unverified references saved to {}", bib_path.display());
+        }
     }
 
     Ok(())
 }
 
+/// Whether a `check` path is `-`, meaning "read reference text from stdin".
+fn is_stdin_path(path: &std::path::Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Read pasted reference text (`check -`) and extract references from it.
+fn extract_from_reader(
+    mut reader: impl std::io::Read,
+) -> anyhow::Result<hallucinator_ingest::ExtractionResult> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    hallucinator_ingest::extract_references_from_text(&text).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
 /// Process all extractable files inside an archive, printing a per-file report for each.
+#[allow(clippy::too_many_arguments)]
 async fn run_archive_check(
     archive_path: &std::path::Path,
     config: hallucinator_core::Config,
     output: Option<PathBuf>,
     color: ColorMode,
+    show_authors: ShowAuthors,
     json_output: Option<PathBuf>,
+    export_unverified: Option<PathBuf>,
+    metrics_file: Option<PathBuf>,
+    strict_extraction: Option<f64>,
+    max_concurrent_files: usize,
+    keep_duplicates: bool,
 ) -> anyhow::Result<()> {
-    use hallucinator_ingest::archive::{ArchiveItem, extract_archive_streaming};
-
-    let mut writer: Box<dyn Write> = if let Some(ref output_path) = output {
-        Box::new(std::fs::File::create(output_path)?)
-    } else {
-        Box::new(std::io::stdout())
+    use hallucinator_ingest::archive::{
+        ArchiveItem, ArchiveOptions, extract_archive_streaming_with_options,
     };
 
     let archive_name = archive_path
@@ -824,136 +1672,47 @@ async fn run_archive_check(
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| archive_path.display().to_string());
 
-    writeln!(writer, "Archive: {}", archive_name)?;
-    writeln!(writer)?;
+    let collect_reports = json_output.is_some() || export_unverified.is_some();
+    let mut batch = BatchCheck::new(
+        config,
+        output,
+        color,
+        show_authors,
+        collect_reports,
+        metrics_file,
+        strict_extraction,
+        max_concurrent_files,
+    )?;
+    writeln!(batch.writer, "Archive: {}", archive_name)?;
+    writeln!(batch.writer)?;
 
     let temp_dir = tempfile::tempdir()?;
     let (tx, rx) = std::sync::mpsc::channel::<ArchiveItem>();
 
     let archive_path = archive_path.to_path_buf();
     let dir = temp_dir.path().to_path_buf();
-    let extract_handle =
-        std::thread::spawn(move || extract_archive_streaming(&archive_path, &dir, 0, &tx));
-
-    let mut file_count = 0usize;
-    let config = Arc::new(config);
-
-    // Accumulator for --json export
-    struct PerFileData {
-        filename: String,
-        report_refs: Vec<hallucinator_reporting::ReportRef>,
-        results_vec: Vec<Option<hallucinator_core::ValidationResult>>,
-        stats: hallucinator_core::CheckStats,
-    }
-    let mut json_data: Vec<PerFileData> = Vec::new();
+    let options = ArchiveOptions {
+        dedup: !keep_duplicates,
+        ..ArchiveOptions::default()
+    };
+    let extract_handle = std::thread::spawn(move || {
+        extract_archive_streaming_with_options(&archive_path, &dir, &options, &tx)
+    });
 
     for item in rx {
         match item {
             ArchiveItem::Warning(msg) => {
-                writeln!(writer, "Warning: {}", msg)?;
+                batch.message(format!("Warning: {}\n", msg)).await?;
             }
             ArchiveItem::Pdf(extracted) => {
-                file_count += 1;
-
-                // Print a header separator for each file
-                writeln!(writer, "─── {} ───", extracted.filename)?;
-                writeln!(writer)?;
-
-                let path = extracted.path.clone();
-                let extraction = match hallucinator_ingest::extract_references(&path) {
-                    Ok(e) => e,
-                    Err(e) => {
-                        writeln!(writer, "  Error: {}", e)?;
-                        writeln!(writer)?;
-                        continue;
-                    }
-                };
-
-                output::print_extraction_summary(
-                    &mut writer,
-                    &extracted.filename,
-                    extraction.references.len(),
-                    &extraction.skip_stats,
-                    color,
-                )?;
-
-                if extraction.references.is_empty() {
-                    writeln!(writer, "No references to check.")?;
-                    writeln!(writer)?;
-                    continue;
-                }
-
-                let progress_writer: Arc<Mutex<Box<dyn Write + Send>>> = if output.is_some() {
-                    Arc::new(Mutex::new(Box::new(std::io::stderr())))
-                } else {
-                    Arc::new(Mutex::new(Box::new(std::io::stdout())))
-                };
-                let progress_color = color;
-                let progress_cb = {
-                    let pw = Arc::clone(&progress_writer);
-                    move |event: hallucinator_core::ProgressEvent| {
-                        if let Ok(mut w) = pw.lock() {
-                            let _ = output::print_progress(&mut *w, &event, progress_color);
-                            let _ = w.flush();
-                        }
-                    }
-                };
-
-                let cancel = CancellationToken::new();
-                let cancel_clone = cancel.clone();
-                tokio::spawn(async move {
-                    if tokio::signal::ctrl_c().await.is_ok() {
-                        cancel_clone.cancel();
-                    }
-                });
-
-                let skip_stats = extraction.skip_stats.clone();
-                let config_clone = Arc::clone(&config);
-
-                // Save ref metadata for --json export
-                let ref_meta: Vec<RefMeta> = extraction
-                    .references
-                    .iter()
-                    .map(|r| RefMeta {
-                        original_number: r.original_number,
-                        title: r.title.clone().unwrap_or_default(),
-                        skip_reason: r.skip_reason.clone(),
-                    })
-                    .collect();
-
-                let refs = extraction.references;
-
-                let results = hallucinator_core::check_references(
-                    refs,
-                    (*config_clone).clone(),
-                    progress_cb,
-                    cancel,
-                )
-                .await;
-
-                writeln!(writer)?;
-                // Use the first openalex key for the report (openalex_key is in config)
-                let has_openalex = config_clone.openalex_key.is_some();
-                output::print_hallucination_report(&mut writer, &results, has_openalex, color)?;
-                output::print_doi_issues(&mut writer, &results, color)?;
-                output::print_retraction_warnings(&mut writer, &results, color)?;
-                output::print_summary(&mut writer, &results, &skip_stats, color)?;
-                writeln!(writer)?;
-
-                // Accumulate for --json export
-                if json_output.is_some() {
-                    let (_, report_refs, results_vec, stats) =
-                        build_report_data(&extracted.filename, &results, &ref_meta, &skip_stats);
-                    json_data.push(PerFileData {
-                        filename: extracted.filename.clone(),
-                        report_refs,
-                        results_vec,
-                        stats,
-                    });
-                }
+                batch
+                    .check_file(&extracted.filename, &extracted.path)
+                    .await?;
             }
             ArchiveItem::Done { total } => {
-                writeln!(writer, "Processed {} file(s) from archive.", total)?;
+                batch
+                    .message(format!("Processed {} file(s) from archive.\n", total))
+                    .await?;
             }
         }
     }
@@ -963,39 +1722,503 @@ async fn run_archive_check(
         .map_err(|_| anyhow::anyhow!("Archive extraction thread panicked"))?
         .map_err(|e| anyhow::anyhow!("Archive extraction failed: {}", e))?;
 
-    if file_count == 0 {
-        writeln!(writer, "No processable files found in archive.")?;
+    // The extracted files live in `temp_dir`, so every check must finish first.
+    batch.finish(json_output, export_unverified).await
+}
+
+/// Process every file named in an `--input-list`, printing a per-file report
+/// for each. Entries that don't exist were already turned into warnings.
+#[allow(clippy::too_many_arguments)]
+async fn run_list_check(
+    list_path: &std::path::Path,
+    listed: hallucinator_ingest::InputList,
+    config: hallucinator_core::Config,
+    output: Option<PathBuf>,
+    color: ColorMode,
+    show_authors: ShowAuthors,
+    json_output: Option<PathBuf>,
+    export_unverified: Option<PathBuf>,
+    metrics_file: Option<PathBuf>,
+    strict_extraction: Option<f64>,
+    max_concurrent_files: usize,
+) -> anyhow::Result<()> {
+    let collect_reports = json_output.is_some() || export_unverified.is_some();
+    // Warnings also go to stderr when the report itself goes to a file.
+    let to_file = output.is_some();
+    let mut batch = BatchCheck::new(
+        config,
+        output,
+        color,
+        show_authors,
+        collect_reports,
+        metrics_file,
+        strict_extraction,
+        max_concurrent_files,
+    )?;
+    writeln!(batch.writer, "Input list: {}", list_path.display())?;
+    writeln!(batch.writer)?;
+
+    for msg in &listed.warnings {
+        writeln!(batch.writer, "Warning: {}", msg)?;
+        if to_file {
+            eprintln!("Warning: {}", msg);
+        }
+    }
+    if !listed.warnings.is_empty() {
+        writeln!(batch.writer)?;
+    }
+    for file in &listed.files {
+        batch.check_file(&file.filename, &file.path).await?;
+    }
+    batch
+        .message(format!(
+            "Processed {} file(s) from list.\n",
+            listed.files.len()
+        ))
+        .await?;
+
+    batch.finish(json_output, export_unverified).await
+}
+
+/// Runs futures on the runtime, handing their outputs back in the order they
+/// were queued. At most `limit` tasks are running or holding an output that
+/// hasn't been taken yet, so a slow file at the front caps how many finished
+/// reports wait behind it; callers take outputs while [`is_full`] before
+/// spawning more.
+///
+/// [`is_full`]: BoundedTasks::is_full
+struct BoundedTasks<T> {
+    semaphore: Arc<tokio::sync::Semaphore>,
+    queue: std::collections::VecDeque<QueuedTask<T>>,
+}
+
+enum QueuedTask<T> {
+    Done(T),
+    /// The permit is released once the output is taken.
+    Running(
+        tokio::task::JoinHandle<T>,
+        tokio::sync::OwnedSemaphorePermit,
+    ),
+}
+
+impl<T: Send + 'static> BoundedTasks<T> {
+    fn new(limit: usize) -> Self {
+        Self {
+            semaphore: Arc::new(tokio::sync::Semaphore::new(limit.max(1))),
+            queue: std::collections::VecDeque::new(),
+        }
+    }
+
+    /// Whether every slot is taken by a running task or an untaken output.
+    fn is_full(&self) -> bool {
+        self.semaphore.available_permits() == 0
+    }
+
+    /// Wait for a free slot, then start `task`.
+    async fn spawn(&mut self, task: impl Future<Output = T> + Send + 'static) {
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        self.queue
+            .push_back(QueuedTask::Running(tokio::spawn(task), permit));
+    }
+
+    /// Queue an output that needs no work, keeping its place in the order.
+    fn push_done(&mut self, value: T) {
+        self.queue.push_back(QueuedTask::Done(value));
+    }
+
+    /// Take the oldest queued output. Without `wait`, returns `None` as soon
+    /// as the oldest task is still running.
+    async fn pop_front(&mut self, wait: bool) -> anyhow::Result<Option<T>> {
+        let ready = match self.queue.front() {
+            None => false,
+            Some(QueuedTask::Done(_)) => true,
+            Some(QueuedTask::Running(handle, _)) => wait || handle.is_finished(),
+        };
+        if !ready {
+            return Ok(None);
+        }
+        match self.queue.pop_front() {
+            Some(QueuedTask::Done(value)) => Ok(Some(value)),
+            Some(QueuedTask::Running(handle, _permit)) => handle
+                .await
+                .map(Some)
+                .map_err(|e| anyhow::anyhow!("File check task failed: {}", e)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Per-file report data kept for --json / --export-unverified export.
+struct PerFileData {
+    filename: String,
+    title: Option<String>,
+    report_refs: Vec<hallucinator_reporting::ReportRef>,
+    results_vec: Vec<Option<hallucinator_core::ValidationResult>>,
+    stats: hallucinator_core::CheckStats,
+}
+
+/// What checking one file produced. The report text is buffered so files
+/// checked concurrently still print one after another, in input order.
+#[derive(Default)]
+struct FileReport {
+    text: Vec<u8>,
+    /// Stats for the corpus summary; `None` if the file wasn't checked.
+    stats: Option<(String, hallucinator_core::CheckStats)>,
+    /// Time spent extracting and checking the file.
+    elapsed: std::time::Duration,
+    /// Only filled when an export was requested.
+    data: Option<PerFileData>,
+    /// Set when the file exceeded `--strict-extraction`.
+    strict_failure: Option<String>,
+}
+
+/// Settings shared by every file check in a batch.
+struct FileCheckOptions {
+    config: Arc<hallucinator_core::Config>,
+    progress_to_stderr: bool,
+    color: ColorMode,
+    show_authors: ShowAuthors,
+    collect_reports: bool,
+    strict_extraction: Option<f64>,
+}
+
+/// Shared state for multi-file runs (archives and `--input-list`): checks up
+/// to `--max-concurrent-files` files at once, prints their reports in input
+/// order, and prints the corpus summary and exports at the end.
+struct BatchCheck {
+    writer: Box<dyn Write>,
+    options: Arc<FileCheckOptions>,
+    tasks: BoundedTasks<anyhow::Result<FileReport>>,
+    /// `--metrics-file`, appended to as each file's report is written.
+    metrics_file: Option<PathBuf>,
+    file_count: usize,
+    json_data: Vec<PerFileData>,
+    /// Per-file stats for the corpus summary
+    corpus_stats: Vec<(String, hallucinator_core::CheckStats)>,
+    /// Files that exceeded the `--strict-extraction` limit (which are not
+    /// checked).
+    strict_failures: Vec<String>,
+}
+
+impl BatchCheck {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        config: hallucinator_core::Config,
+        output: Option<PathBuf>,
+        color: ColorMode,
+        show_authors: ShowAuthors,
+        collect_reports: bool,
+        metrics_file: Option<PathBuf>,
+        strict_extraction: Option<f64>,
+        max_concurrent_files: usize,
+    ) -> anyhow::Result<Self> {
+        let writer: Box<dyn Write> = if let Some(ref output_path) = output {
+            Box::new(std::fs::File::create(output_path)?)
+        } else {
+            Box::new(std::io::stdout())
+        };
+        Ok(Self {
+            writer,
+            options: Arc::new(FileCheckOptions {
+                config: Arc::new(config),
+                progress_to_stderr: output.is_some(),
+                color,
+                show_authors,
+                collect_reports,
+                strict_extraction,
+            }),
+            tasks: BoundedTasks::new(max_concurrent_files),
+            metrics_file,
+            file_count: 0,
+            json_data: Vec::new(),
+            corpus_stats: Vec::new(),
+            strict_failures: Vec::new(),
+        })
+    }
+
+    /// Start checking one file once a slot is free, printing any reports
+    /// that are ready by then.
+    async fn check_file(&mut self, filename: &str, path: &std::path::Path) -> anyhow::Result<()> {
+        self.file_count += 1;
+        // Slots free up as reports are taken, oldest first
+        while self.tasks.is_full() {
+            if let Some(report) = self.tasks.pop_front(true).await? {
+                self.write_report(report?)?;
+            }
+        }
+        let options = Arc::clone(&self.options);
+        let filename = filename.to_string();
+        let path = path.to_path_buf();
+        self.tasks
+            .spawn(async move { check_one_file(&options, &filename, &path).await })
+            .await;
+        self.write_reports(false).await
+    }
+
+    /// Print `text` after the reports of every file queued so far.
+    async fn message(&mut self, text: String) -> anyhow::Result<()> {
+        self.tasks.push_done(Ok(FileReport {
+            text: text.into_bytes(),
+            ..FileReport::default()
+        }));
+        self.write_reports(false).await
     }
 
-    // --json export for archive
-    if let Some(json_path) = json_output {
-        let report_papers: Vec<hallucinator_reporting::ReportPaper<'_>> = json_data
+    /// Print queued reports in order, stopping at the first file still being
+    /// checked unless `wait` is set.
+    async fn write_reports(&mut self, wait: bool) -> anyhow::Result<()> {
+        while let Some(report) = self.tasks.pop_front(wait).await? {
+            self.write_report(report?)?;
+        }
+        Ok(())
+    }
+
+    /// Print one file's report and keep its stats and export data.
+    fn write_report(&mut self, report: FileReport) -> anyhow::Result<()> {
+        self.writer.write_all(&report.text)?;
+        self.writer.flush()?;
+        if let Some((filename, stats)) = report.stats {
+            if let Some(path) = &self.metrics_file {
+                write_metrics(path, &filename, &stats, report.elapsed);
+            }
+            self.corpus_stats.push((filename, stats));
+        }
+        if let Some(data) = report.data {
+            self.json_data.push(data);
+        }
+        if let Some(filename) = report.strict_failure {
+            self.strict_failures.push(filename);
+        }
+        Ok(())
+    }
+
+    /// Wait for the remaining checks, then print the corpus summary and
+    /// write any requested exports.
+    async fn finish(
+        mut self,
+        json_output: Option<PathBuf>,
+        export_unverified: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        self.write_reports(true).await?;
+        if self.file_count == 0 {
+            writeln!(self.writer, "No processable files found.")?;
+        } else if !self.corpus_stats.is_empty() {
+            let summary = output::aggregate_corpus(&self.corpus_stats);
+            output::print_corpus_summary(&mut self.writer, &summary, self.options.color)?;
+        }
+
+        let report_papers: Vec<hallucinator_reporting::ReportPaper<'_>> = self
+            .json_data
             .iter()
             .map(|d| hallucinator_reporting::ReportPaper {
                 filename: &d.filename,
+                title: d.title.as_deref(),
                 stats: &d.stats,
                 results: &d.results_vec,
                 verdict: None,
             })
             .collect();
-        let ref_slices: Vec<&[hallucinator_reporting::ReportRef]> =
-            json_data.iter().map(|d| d.report_refs.as_slice()).collect();
-        hallucinator_reporting::export_results(
-            &report_papers,
-            &ref_slices,
-            hallucinator_reporting::ExportFormat::Json,
-            &json_path,
-            false,
-        )
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
-        eprintln!("Results saved to {}", json_path.display());
+        let ref_slices: Vec<&[hallucinator_reporting::ReportRef]> = self
+            .json_data
+            .iter()
+            .map(|d| d.report_refs.as_slice())
+            .collect();
+        if let Some(json_path) = json_output {
+            hallucinator_reporting::export_results(
+                &report_papers,
+                &ref_slices,
+                hallucinator_reporting::ExportFormat::Json,
+                &json_path,
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            eprintln!("Results saved to {}", json_path.display());
+        }
+        if let Some(bib_path) = export_unverified {
+            hallucinator_reporting::export_results(
+                &report_papers,
+                &ref_slices,
+                hallucinator_reporting::ExportFormat::Bibtex,
+                &bib_path,
+                false,
+            )
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+            eprintln!("Unverified references saved to {}", bib_path.display());
+        }
+
+        if !self.strict_failures.is_empty() {
+            anyhow::bail!(
+                "{} file(s) had too many references skipped during extraction (--strict-extraction): {}",
+                self.strict_failures.len(),
+                self.strict_failures.join(", ")
+            );
+        }
+        Ok(())
     }
+}
 
-    Ok(())
+/// Append a `--metrics-file` record. A failed write is only a warning, so
+/// the report and exports still come out.
+fn write_metrics(
+    path: &std::path::Path,
+    filename: &str,
+    stats: &hallucinator_core::CheckStats,
+    elapsed: std::time::Duration,
+) {
+    if let Err(e) = hallucinator_reporting::append_metrics(path, filename, stats, elapsed) {
+        eprintln!("Warning: cannot write metrics to {}: {}", path.display(), e);
+    }
+}
+
+/// Extract and check one file of a batch. Extraction errors are reported
+/// inline and don't stop the batch.
+async fn check_one_file(
+    options: &FileCheckOptions,
+    filename: &str,
+    path: &std::path::Path,
+) -> anyhow::Result<FileReport> {
+    let started = std::time::Instant::now();
+    let mut report = FileReport::default();
+    let writer = &mut report.text;
+    let color = options.color;
+    let show_authors = options.show_authors;
+
+    // Extraction blocks, so it runs off the runtime's worker threads
+    let extract_options = hallucinator_ingest::ExtractOptions::from(options.config.as_ref());
+    let owned_path = path.to_path_buf();
+    let extracted = tokio::task::spawn_blocking(move || {
+        hallucinator_ingest::extract_references_and_title(&owned_path, &extract_options)
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("Extraction task failed: {}", e))?;
+
+    // Print a header separator for each file, labelled by its title if it has one
+    let title = extracted.as_ref().ok().and_then(|(_, title)| title.clone());
+    writeln!(
+        writer,
+        "─── {} ───",
+        hallucinator_reporting::paper_label(filename, title.as_deref())
+    )?;
+    writeln!(writer)?;
+
+    let mut extraction = match extracted {
+        Ok((e, _)) => e,
+        Err(e) => {
+            writeln!(writer, "  Error: {}", e)?;
+            writeln!(writer)?;
+            return Ok(report);
+        }
+    };
+    if let Err(e) = options.config.enforce_reference_limit(&mut extraction) {
+        writeln!(writer, "  Error: {}", e)?;
+        writeln!(writer)?;
+        return Ok(report);
+    }
+
+    output::print_extraction_summary(
+        writer,
+        filename,
+        extraction.references.len(),
+        &extraction.skip_stats,
+        color,
+    )?;
+
+    if let Some(max) = fails_strict_extraction(&extraction.skip_stats, options.strict_extraction) {
+        output::print_skip_breakdown(writer, &extraction.skip_stats, max, color)?;
+        report.strict_failure = Some(filename.to_string());
+        return Ok(report);
+    }
+
+    if extraction.references.is_empty() {
+        writeln!(writer, "No references to check.")?;
+        writeln!(writer)?;
+        return Ok(report);
+    }
+
+    let progress_writer: Arc<Mutex<Box<dyn Write + Send>>> = if options.progress_to_stderr {
+        Arc::new(Mutex::new(Box::new(std::io::stderr())))
+    } else {
+        Arc::new(Mutex::new(Box::new(std::io::stdout())))
+    };
+    let progress_color = color;
+    let progress_cb = {
+        let pw = Arc::clone(&progress_writer);
+        move |event: hallucinator_core::ProgressEvent| {
+            if let Ok(mut w) = pw.lock() {
+                let _ = output::print_progress(&mut *w, &event, progress_color, show_authors);
+                let _ = w.flush();
+            }
+        }
+    };
+
+    let cancel = CancellationToken::new();
+    let cancel_clone = cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            cancel_clone.cancel();
+        }
+    });
+
+    let skip_stats = extraction.skip_stats.clone();
+    let config_clone = Arc::clone(&options.config);
+
+    // Save ref metadata for --json export
+    let ref_meta: Vec<RefMeta> = extraction
+        .references
+        .iter()
+        .map(|r| RefMeta {
+            original_number: r.original_number,
+            title: r.title.clone().unwrap_or_default(),
+            skip_reason: r.skip_reason.clone(),
+        })
+        .collect();
+    let shared_dois = hallucinator_core::doi::find_shared_dois(&extraction.references);
+    let titles: Vec<String> = ref_meta.iter().map(|m| m.title.clone()).collect();
+
+    let refs = extraction.references;
+
+    let results =
+        hallucinator_core::check_references(refs, (*config_clone).clone(), progress_cb, cancel)
+            .await?;
+
+    writeln!(writer)?;
+    // Use the first openalex key for the report (openalex_key is in config)
+    let has_openalex = config_clone.openalex_key.is_some();
+    output::print_hallucination_report(writer, &results, has_openalex, color)?;
+    output::print_doi_issues(writer, &results, color)?;
+    output::print_shared_dois(writer, &shared_dois, &titles, color)?;
+    output::print_retraction_warnings(writer, &results, color)?;
+    output::print_published_versions(writer, &results, color)?;
+    output::print_version_matches(writer, &results, color)?;
+    output::print_uncited_references(writer, &results, color)?;
+    output::print_summary(writer, &results, &skip_stats, color)?;
+    writeln!(writer)?;
+
+    let (_, report_refs, results_vec, stats) =
+        build_report_data(filename, &results, &ref_meta, &skip_stats);
+    report.stats = Some((filename.to_string(), stats.clone()));
+    report.elapsed = started.elapsed();
+
+    // Keep for --json / --export-unverified export
+    if options.collect_reports {
+        report.data = Some(PerFileData {
+            filename: filename.to_string(),
+            title,
+            report_refs,
+            results_vec,
+            stats,
+        });
+    }
+    Ok(report)
 }
 
 async fn dry_run_check(
     file_path: PathBuf,
+    format: DryRunFormat,
+    pdf_columns: bool,
     no_color: bool,
     output: Option<PathBuf>,
 ) -> anyhow::Result<()> {
@@ -1011,11 +2234,29 @@ async fn dry_run_check(
         anyhow::bail!("File not found: {}", file_path.display());
     }
 
+    if let DryRunFormat::Json = format {
+        let options = hallucinator_ingest::ExtractOptions {
+            pdf_column_detection: pdf_columns,
+            ..Default::default()
+        };
+        let extraction = hallucinator_ingest::extract_references_with_options(&file_path, &options)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        writeln!(writer, "{}", extraction_to_json(&extraction)?)?;
+        return Ok(());
+    }
+
     let file_name = file_path
         .file_name()
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| file_path.display().to_string());
 
+    // Read a `.gz` input from a decompressed copy, as `check` does.
+    let decompressed = hallucinator_ingest::decompress_if_gzipped(&file_path)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let file_path = decompressed
+        .as_ref()
+        .map_or(file_path.as_path(), |temp| temp.path());
+
     let is_bbl = file_path
         .extension()
         .map(|e| e.eq_ignore_ascii_case("bbl"))
@@ -1026,22 +2267,29 @@ async fn dry_run_check(
         .unwrap_or(false);
 
     if is_bbl || is_bib {
-        dry_run_bbl(&file_path, &file_name, use_color, &mut writer)
+        dry_run_bbl(file_path, &file_name, use_color, &mut writer)
     } else {
-        dry_run_pdf(&file_path, &file_name, use_color, &mut writer)
+        dry_run_pdf(file_path, &file_name, pdf_columns, use_color, &mut writer)
     }
 }
 
+/// Pretty-printed JSON for `check --dry-run --format json`.
+fn extraction_to_json(extraction: &hallucinator_core::ExtractionResult) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(extraction)?)
+}
+
 fn dry_run_pdf(
     file_path: &std::path::Path,
     file_name: &str,
+    pdf_columns: bool,
     use_color: bool,
     writer: &mut Box<dyn Write>,
 ) -> anyhow::Result<()> {
     use owo_colors::OwoColorize;
 
     use hallucinator_core::PdfBackend as _;
-    let text = hallucinator_pdf_mupdf::MupdfBackend
+    let text = hallucinator_pdf_mupdf::MupdfBackend::default()
+        .column_detection(pdf_columns)
         .extract_text(file_path)
         .map_err(|e| anyhow::anyhow!("{}", e))?;
     let ref_section = hallucinator_parsing::section::find_references_section(&text)
@@ -1242,20 +2490,127 @@ fn dry_run_bbl(
     Ok(())
 }
 
-async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
-    use indicatif::{HumanBytes, HumanCount, MultiProgress, ProgressBar, ProgressStyle};
+/// Extract references without querying databases and print only the chosen
+/// field alongside each raw citation.
+fn dump_check(
+    file_path: PathBuf,
+    field: DumpField,
+    pdf_columns: bool,
+    no_color: bool,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let color = ColorMode(!no_color && output.is_none());
+
+    let mut writer: Box<dyn Write> = if let Some(ref output_path) = output {
+        Box::new(std::fs::File::create(output_path)?)
+    } else {
+        Box::new(std::io::stdout())
+    };
+
+    if !file_path.exists() {
+        anyhow::bail!("File not found: {}", file_path.display());
+    }
+
+    let file_name = file_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_path.display().to_string());
+
+    let decompressed = hallucinator_ingest::decompress_if_gzipped(&file_path)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let file_path = decompressed
+        .as_ref()
+        .map_or(file_path.as_path(), |temp| temp.path());
+
+    let extension = file_path
+        .extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase());
+    let entries: Vec<(String, Vec<String>)> = match extension.as_deref() {
+        Some(ext @ ("bbl" | "bib")) => {
+            let extraction = if ext == "bib" {
+                hallucinator_bbl::extract_references_from_bib(file_path)
+                    .map_err(|e| anyhow::anyhow!("BIB extraction failed: {}", e))?
+            } else {
+                hallucinator_bbl::extract_references_from_bbl(file_path)
+                    .map_err(|e| anyhow::anyhow!("BBL extraction failed: {}", e))?
+            };
+            extraction
+                .references
+                .into_iter()
+                .map(|r| (r.raw_citation, r.authors))
+                .collect()
+        }
+        _ => {
+            use hallucinator_core::PdfBackend as _;
+            let text = hallucinator_pdf_mupdf::MupdfBackend::default()
+                .column_detection(pdf_columns)
+                .extract_text(file_path)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            let ref_section = hallucinator_parsing::section::find_references_section(&text)
+                .ok_or_else(|| anyhow::anyhow!("No references section found"))?;
+            hallucinator_parsing::section::segment_references(&ref_section)
+                .into_iter()
+                .map(|raw| {
+                    let authors =
+                        hallucinator_parsing::authors::extract_authors_from_reference(&raw);
+                    (raw, authors)
+                })
+                .collect()
+        }
+    };
+
+    match field {
+        DumpField::Authors => output::print_author_dump(&mut writer, &file_name, &entries, color)?,
+    }
+    Ok(())
+}
+
+/// Compare two saved runs by reference fingerprint.
+fn diff(
+    old_path: &std::path::Path,
+    new_path: &std::path::Path,
+    format: DiffFormat,
+    no_color: bool,
+) -> anyhow::Result<()> {
+    let load =
+        |path: &std::path::Path| -> anyhow::Result<Vec<hallucinator_core::run::PaperRecord>> {
+            let json = std::fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("Cannot read {}: {}", path.display(), e))?;
+            hallucinator_core::run::parse_run(&json)
+                .map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))
+        };
+    let changes = hallucinator_core::run::diff_runs(&load(old_path)?, &load(new_path)?);
+
+    let mut stdout = std::io::stdout();
+    match format {
+        DiffFormat::Json => writeln!(stdout, "{}", hallucinator_core::run::diff_to_json(&changes))?,
+        DiffFormat::Human => output::print_run_diff(&mut stdout, &changes, ColorMode(!no_color))?,
+    }
+    Ok(())
+}
+
+async fn update_dblp(
+    db_path: &PathBuf,
+    max_retries: u32,
+    min_year: Option<u32>,
+    client: reqwest::Client,
+    numbers: RawNumbers,
+) -> anyhow::Result<()> {
+    use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
     use std::time::{Duration, Instant};
 
     let multi = MultiProgress::new();
 
-    let dl_bar_style = ProgressStyle::with_template(
+    let dl_bar_style = ProgressStyle::with_template(&numbers.template(
         "{spinner:.cyan} {msg} [{bar:40.cyan/dim}] {bytes}/{total_bytes} ({bytes_per_sec}, eta {eta})",
-    )
+    ))
     .unwrap()
     .progress_chars("=> ");
 
-    let dl_unknown_style =
-        ProgressStyle::with_template("{spinner:.cyan} {msg} {bytes} ({bytes_per_sec})").unwrap();
+    let dl_unknown_style = ProgressStyle::with_template(
+        &numbers.template("{spinner:.cyan} {msg} {bytes} ({bytes_per_sec})"),
+    )
+    .unwrap();
 
     let parse_bar_style = ProgressStyle::with_template(
         "{spinner:.green} [{elapsed_precise}] {msg} [{bar:40.green/dim}] {percent}% (eta {eta})",
@@ -1282,112 +2637,152 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
     let build_start = Instant::now();
     let parse_start = std::cell::Cell::new(None::<Instant>);
 
-    let updated = hallucinator_dblp::build_database(db_path, |event| match event {
-        hallucinator_dblp::BuildProgress::Downloading {
-            bytes_downloaded,
-            total_bytes,
-            ..
-        } => {
-            if let Some(total) = total_bytes {
-                if dl_bar.length() == Some(0) {
-                    dl_bar.set_length(total);
-                    dl_bar.set_style(dl_bar_style.clone());
-                }
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading dblp.xml.gz");
-                if bytes_downloaded >= total && !dl_bar.is_finished() {
-                    dl_bar.finish_with_message(format!(
-                        "Downloaded {} in {:.0?}",
-                        HumanBytes(total),
-                        dl_bar.elapsed()
-                    ));
+    if let Some(min_year) = min_year {
+        eprintln!("Only indexing publications from {min_year} or later");
+    }
+
+    let options = hallucinator_dblp::BuildOptions {
+        max_retries,
+        min_year,
+        client: Some(client),
+        ..Default::default()
+    };
+    let updated =
+        hallucinator_dblp::build_database_with_options(db_path, options, |event| match event {
+            hallucinator_dblp::BuildProgress::Downloading {
+                bytes_downloaded,
+                total_bytes,
+                ..
+            } => {
+                if let Some(total) = total_bytes {
+                    if dl_bar.length() == Some(0) {
+                        dl_bar.set_length(total);
+                        dl_bar.set_style(dl_bar_style.clone());
+                    }
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading dblp.xml.gz");
+                    if bytes_downloaded >= total && !dl_bar.is_finished() {
+                        dl_bar.finish_with_message(format!(
+                            "Downloaded {} in {:.0?}",
+                            numbers.bytes(total),
+                            dl_bar.elapsed()
+                        ));
+                    }
+                } else {
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading dblp.xml.gz");
                 }
-            } else {
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading dblp.xml.gz");
             }
-        }
-        hallucinator_dblp::BuildProgress::Parsing {
-            records_inserted,
-            bytes_read,
-            bytes_total,
-        } => {
-            if !dl_bar.is_finished() {
+            hallucinator_dblp::BuildProgress::ReusingDownload { bytes } => {
                 dl_bar.finish_with_message(format!(
-                    "Downloaded {} in {:.0?}",
-                    HumanBytes(dl_bar.position()),
-                    dl_bar.elapsed()
+                    "Reusing downloaded {} (ETag unchanged)",
+                    numbers.bytes(bytes)
                 ));
             }
-            if parse_start.get().is_none() {
-                parse_start.set(Some(Instant::now()));
-                parse_bar.reset_elapsed();
-                parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-                parse_bar.enable_steady_tick(Duration::from_millis(120));
+            hallucinator_dblp::BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            } => {
+                dl_bar.println(format!(
+                    "Download failed ({}); retrying ({}/{})...",
+                    error, attempt, max_retries
+                ));
+                dl_bar.set_message("Reconnecting to dblp.org...");
             }
-            // Switch to progress bar style on first event with a known total
-            if bytes_total > 0 && parse_bar.length() == Some(0) {
-                parse_bar.set_length(bytes_total);
-                parse_bar.set_style(parse_bar_style.clone());
+            hallucinator_dblp::BuildProgress::Warning { message } => {
+                parse_bar.println(format!("Warning: {}", message));
             }
-            parse_bar.set_position(bytes_read);
-            let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
-            let per_sec = if elapsed > 0.0 {
-                records_inserted as f64 / elapsed
-            } else {
-                0.0
-            };
-            parse_bar.set_message(format!(
-                "{} publications ({}/s)",
-                HumanCount(records_inserted),
-                HumanCount(per_sec as u64),
-            ));
-        }
-        hallucinator_dblp::BuildProgress::RebuildingIndex => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!(
-                    "Downloaded {} in {:.0?}",
-                    HumanBytes(dl_bar.position()),
-                    dl_bar.elapsed()
+            hallucinator_dblp::BuildProgress::Parsing {
+                records_inserted,
+                queue_depth,
+                bytes_read,
+                bytes_total,
+                ..
+            } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!(
+                        "Downloaded {} in {:.0?}",
+                        numbers.bytes(dl_bar.position()),
+                        dl_bar.elapsed()
+                    ));
+                }
+                if parse_start.get().is_none() {
+                    parse_start.set(Some(Instant::now()));
+                    parse_bar.reset_elapsed();
+                    parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                    parse_bar.enable_steady_tick(Duration::from_millis(120));
+                }
+                // Switch to progress bar style on first event with a known total
+                if bytes_total > 0 && parse_bar.length() == Some(0) {
+                    parse_bar.set_length(bytes_total);
+                    parse_bar.set_style(parse_bar_style.clone());
+                }
+                parse_bar.set_position(bytes_read);
+                let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
+                let per_sec = if elapsed > 0.0 {
+                    records_inserted as f64 / elapsed
+                } else {
+                    0.0
+                };
+                parse_bar.set_message(format!(
+                    "{} publications ({}/s, {} queued)",
+                    numbers.count(records_inserted),
+                    numbers.count(per_sec as u64),
+                    numbers.count(queue_depth),
                 ));
             }
-            if !parse_bar.is_finished() {
-                let elapsed = parse_start.get().map(|s| s.elapsed());
-                parse_bar.finish_with_message(format!(
-                    "Inserted publications in {:.0?}",
-                    elapsed.unwrap_or_default()
-                ));
+            hallucinator_dblp::BuildProgress::RebuildingIndex => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!(
+                        "Downloaded {} in {:.0?}",
+                        numbers.bytes(dl_bar.position()),
+                        dl_bar.elapsed()
+                    ));
+                }
+                if !parse_bar.is_finished() {
+                    let elapsed = parse_start.get().map(|s| s.elapsed());
+                    parse_bar.finish_with_message(format!(
+                        "Inserted publications in {:.0?}",
+                        elapsed.unwrap_or_default()
+                    ));
+                }
+                finalize_bar.reset_elapsed();
+                finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                finalize_bar.enable_steady_tick(Duration::from_millis(120));
+                finalize_bar.set_message("Rebuilding FTS search index...");
             }
-            finalize_bar.reset_elapsed();
-            finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-            finalize_bar.enable_steady_tick(Duration::from_millis(120));
-            finalize_bar.set_message("Rebuilding FTS search index...");
-        }
-        hallucinator_dblp::BuildProgress::Compacting => {
-            finalize_bar.set_message("Compacting database (VACUUM)...");
-        }
-        hallucinator_dblp::BuildProgress::Complete {
-            publications,
-            authors,
-            skipped,
-        } => {
-            if !parse_bar.is_finished() {
-                parse_bar.finish_and_clear();
+            hallucinator_dblp::BuildProgress::Compacting => {
+                finalize_bar.set_message("Compacting database (VACUUM)...");
             }
-            if skipped {
-                finalize_bar
-                    .finish_with_message("Database is already up to date (304 Not Modified)");
-            } else {
-                finalize_bar.finish_with_message(format!(
-                    "Indexed {} publications, {} authors (total {:.0?})",
-                    HumanCount(publications),
-                    HumanCount(authors),
-                    build_start.elapsed()
-                ));
+            hallucinator_dblp::BuildProgress::Complete {
+                publications,
+                authors,
+                issues,
+                skipped,
+            } => {
+                if !parse_bar.is_finished() {
+                    parse_bar.finish_and_clear();
+                }
+                if skipped {
+                    finalize_bar
+                        .finish_with_message("Database is already up to date (304 Not Modified)");
+                } else {
+                    let mut summary = output::indexed_summary(
+                        numbers,
+                        publications,
+                        Some(authors),
+                        build_start.elapsed(),
+                    );
+                    if issues > 0 {
+                        summary
+                            .push_str(&format!("; {} records had issues", numbers.count(issues)));
+                    }
+                    finalize_bar.finish_with_message(summary);
+                }
             }
-        }
-    })
-    .await?;
+        })
+        .await?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1399,20 +2794,28 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn update_acl(db_path: &PathBuf) -> anyhow::Result<()> {
-    use indicatif::{HumanCount, MultiProgress, ProgressBar, ProgressStyle};
+async fn update_acl(
+    db_path: &PathBuf,
+    max_retries: u32,
+    min_year: Option<u32>,
+    client: reqwest::Client,
+    numbers: RawNumbers,
+) -> anyhow::Result<()> {
+    use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
     use std::time::{Duration, Instant};
 
     let multi = MultiProgress::new();
 
-    let dl_bar_style = ProgressStyle::with_template(
+    let dl_bar_style = ProgressStyle::with_template(&numbers.template(
         "{spinner:.cyan} {msg} [{bar:40.cyan/dim}] {bytes}/{total_bytes} ({bytes_per_sec}, eta {eta})",
-    )
+    ))
     .unwrap()
     .progress_chars("=> ");
 
-    let dl_unknown_style =
-        ProgressStyle::with_template("{spinner:.cyan} {msg} {bytes} ({bytes_per_sec})").unwrap();
+    let dl_unknown_style = ProgressStyle::with_template(
+        &numbers.template("{spinner:.cyan} {msg} {bytes} ({bytes_per_sec})"),
+    )
+    .unwrap();
 
     let parse_bar_style = ProgressStyle::with_template(
         "{spinner:.green} [{elapsed_precise}] {msg} [{bar:40.green/dim}] {percent}% (eta {eta})",
@@ -1439,107 +2842,138 @@ async fn update_acl(db_path: &PathBuf) -> anyhow::Result<()> {
     let build_start = Instant::now();
     let parse_start = std::cell::Cell::new(None::<Instant>);
 
-    let updated = hallucinator_acl::build_database(db_path, |event| match event {
-        hallucinator_acl::BuildProgress::Downloading {
-            bytes_downloaded,
-            total_bytes,
-        } => {
-            if let Some(total) = total_bytes {
-                if dl_bar.length() == Some(0) {
-                    dl_bar.set_length(total);
-                    dl_bar.set_style(dl_bar_style.clone());
+    if let Some(min_year) = min_year {
+        eprintln!("Only indexing papers from {min_year} or later");
+    }
+
+    let options = hallucinator_acl::BuildOptions {
+        max_retries,
+        min_year,
+        client: Some(client),
+        ..Default::default()
+    };
+    let updated =
+        hallucinator_acl::build_database_with_options(db_path, options, |event| match event {
+            hallucinator_acl::BuildProgress::Downloading {
+                bytes_downloaded,
+                total_bytes,
+            } => {
+                if let Some(total) = total_bytes {
+                    if dl_bar.length() == Some(0) {
+                        dl_bar.set_length(total);
+                        dl_bar.set_style(dl_bar_style.clone());
+                    }
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading acl-anthology.tar.gz");
+                } else {
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading acl-anthology.tar.gz");
                 }
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading acl-anthology.tar.gz");
-            } else {
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading acl-anthology.tar.gz");
             }
-        }
-        hallucinator_acl::BuildProgress::Extracting { files_extracted } => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
-            }
-            if parse_bar.is_hidden() {
-                parse_bar.reset_elapsed();
-                parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-                parse_bar.enable_steady_tick(Duration::from_millis(120));
+            hallucinator_acl::BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            } => {
+                dl_bar.println(format!(
+                    "Download failed ({}); retrying ({}/{})...",
+                    error, attempt, max_retries
+                ));
+                dl_bar.set_message("Reconnecting to GitHub...");
             }
-            parse_bar.set_message(format!("Extracting XML files... ({})", files_extracted));
-        }
-        hallucinator_acl::BuildProgress::Parsing {
-            records_parsed,
-            records_inserted,
-            files_processed,
-            files_total,
-        } => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+            hallucinator_acl::BuildProgress::Warning { message } => {
+                parse_bar.println(format!("Warning: {}", message));
             }
-            if parse_start.get().is_none() {
-                parse_start.set(Some(Instant::now()));
+            hallucinator_acl::BuildProgress::Extracting { files_extracted } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
                 if parse_bar.is_hidden() {
                     parse_bar.reset_elapsed();
                     parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
                     parse_bar.enable_steady_tick(Duration::from_millis(120));
                 }
+                parse_bar.set_message(format!("Extracting XML files... ({})", files_extracted));
             }
-            if files_total > 0 && parse_bar.length() == Some(0) {
-                parse_bar.set_length(files_total);
-                parse_bar.set_style(parse_bar_style.clone());
-            }
-            parse_bar.set_position(files_processed);
-            let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
-            let per_sec = if elapsed > 0.0 {
-                records_inserted as f64 / elapsed
-            } else {
-                0.0
-            };
-            parse_bar.set_message(format!(
-                "{} parsed, {} inserted ({}/s)",
-                HumanCount(records_parsed),
-                HumanCount(records_inserted),
-                HumanCount(per_sec as u64),
-            ));
-        }
-        hallucinator_acl::BuildProgress::RebuildingIndex => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
-            }
-            if !parse_bar.is_finished() {
-                let elapsed = parse_start.get().map(|s| s.elapsed());
-                parse_bar.finish_with_message(format!(
-                    "Inserted publications in {:.0?}",
-                    elapsed.unwrap_or_default()
+            hallucinator_acl::BuildProgress::Parsing {
+                records_parsed,
+                records_inserted,
+                files_processed,
+                files_total,
+            } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
+                if parse_start.get().is_none() {
+                    parse_start.set(Some(Instant::now()));
+                    if parse_bar.is_hidden() {
+                        parse_bar.reset_elapsed();
+                        parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                        parse_bar.enable_steady_tick(Duration::from_millis(120));
+                    }
+                }
+                if files_total > 0 && parse_bar.length() == Some(0) {
+                    parse_bar.set_length(files_total);
+                    parse_bar.set_style(parse_bar_style.clone());
+                }
+                parse_bar.set_position(files_processed);
+                let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
+                let per_sec = if elapsed > 0.0 {
+                    records_inserted as f64 / elapsed
+                } else {
+                    0.0
+                };
+                parse_bar.set_message(format!(
+                    "{} parsed, {} inserted ({}/s)",
+                    numbers.count(records_parsed),
+                    numbers.count(records_inserted),
+                    numbers.count(per_sec as u64),
                 ));
             }
-            finalize_bar.reset_elapsed();
-            finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-            finalize_bar.enable_steady_tick(Duration::from_millis(120));
-            finalize_bar.set_message("Rebuilding FTS search index...");
-        }
-        hallucinator_acl::BuildProgress::Complete {
-            publications,
-            authors,
-            skipped,
-        } => {
-            if !parse_bar.is_finished() {
-                parse_bar.finish_and_clear();
+            hallucinator_acl::BuildProgress::RebuildingIndex => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
+                if !parse_bar.is_finished() {
+                    let elapsed = parse_start.get().map(|s| s.elapsed());
+                    parse_bar.finish_with_message(format!(
+                        "Inserted publications in {:.0?}",
+                        elapsed.unwrap_or_default()
+                    ));
+                }
+                finalize_bar.reset_elapsed();
+                finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                finalize_bar.enable_steady_tick(Duration::from_millis(120));
+                finalize_bar.set_message("Rebuilding FTS search index...");
             }
-            if skipped {
-                finalize_bar
-                    .finish_with_message("Database is already up to date (same commit SHA)");
-            } else {
-                finalize_bar.finish_with_message(format!(
-                    "Indexed {} publications, {} authors (total {:.0?})",
-                    HumanCount(publications),
-                    HumanCount(authors),
-                    build_start.elapsed()
-                ));
+            hallucinator_acl::BuildProgress::Complete {
+                publications,
+                authors,
+                issues,
+                skipped,
+            } => {
+                if !parse_bar.is_finished() {
+                    parse_bar.finish_and_clear();
+                }
+                if skipped {
+                    finalize_bar
+                        .finish_with_message("Database is already up to date (same commit SHA)");
+                } else {
+                    let mut summary = output::indexed_summary(
+                        numbers,
+                        publications,
+                        Some(authors),
+                        build_start.elapsed(),
+                    );
+                    if issues > 0 {
+                        summary
+                            .push_str(&format!("; {} records had issues", numbers.count(issues)));
+                    }
+                    finalize_bar.finish_with_message(summary);
+                }
             }
-        }
-    })
-    .await?;
+        })
+        .await?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1555,8 +2989,11 @@ async fn update_openalex(
     db_path: &PathBuf,
     since: Option<&str>,
     min_year: Option<u32>,
+    title_ngrams: bool,
+    client: reqwest::Client,
+    numbers: RawNumbers,
 ) -> anyhow::Result<()> {
-    use indicatif::{HumanBytes, HumanCount, MultiProgress, ProgressBar, ProgressStyle};
+    use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
     use std::collections::HashMap;
     use std::time::{Duration, Instant};
 
@@ -1569,6 +3006,9 @@ async fn update_openalex(
     if let Some(min_year) = min_year {
         eprintln!("Only indexing works published in {min_year} or later");
     }
+    if title_ngrams {
+        eprintln!("Indexing title trigrams (larger index, fuzzier candidate retrieval)");
+    }
 
     let multi = MultiProgress::new();
 
@@ -1590,11 +3030,15 @@ async fn update_openalex(
 
     let build_start = Instant::now();
 
-    let updated = hallucinator_openalex::build_database_filtered(
-        db_path,
-        since,
+    let options = hallucinator_openalex::BuildOptions {
+        since: since.map(String::from),
         min_year,
-        |event| match event {
+        title_ngrams,
+        secondary_types: Vec::new(),
+        client: Some(client),
+    };
+    let updated =
+        hallucinator_openalex::build_database_with_options(db_path, options, |event| match event {
             hallucinator_openalex::BuildProgress::ListingPartitions { message } => {
                 bar.set_message(message);
             }
@@ -1615,7 +3059,11 @@ async fn update_openalex(
                 bytes_downloaded,
             } => {
                 if let Some(s) = file_spinners.get(&filename) {
-                    s.set_message(format!("{} ({})", filename, HumanBytes(bytes_downloaded)));
+                    s.set_message(format!(
+                        "{} ({})",
+                        filename,
+                        numbers.bytes(bytes_downloaded)
+                    ));
                 }
             }
             hallucinator_openalex::BuildProgress::Downloading {
@@ -1633,7 +3081,7 @@ async fn update_openalex(
                 let speed = if elapsed > 0.5 {
                     format!(
                         " ({}/s)",
-                        HumanBytes((bytes_downloaded as f64 / elapsed) as u64)
+                        numbers.bytes((bytes_downloaded as f64 / elapsed) as u64)
                     )
                 } else {
                     String::new()
@@ -1641,20 +3089,25 @@ async fn update_openalex(
                 let rate = if elapsed > 0.5 && records_indexed > 0 {
                     format!(
                         ", {} records ({}/s)",
-                        HumanCount(records_indexed),
-                        HumanCount((records_indexed as f64 / elapsed) as u64)
+                        numbers.count(records_indexed),
+                        numbers.count((records_indexed as f64 / elapsed) as u64)
                     )
                 } else if records_indexed > 0 {
-                    format!(", {} records", HumanCount(records_indexed))
+                    format!(", {} records", numbers.count(records_indexed))
                 } else {
                     String::new()
                 };
-                bar.set_message(format!("{}{}{}", HumanBytes(bytes_downloaded), speed, rate));
+                bar.set_message(format!(
+                    "{}{}{}",
+                    numbers.bytes(bytes_downloaded),
+                    speed,
+                    rate
+                ));
             }
             hallucinator_openalex::BuildProgress::Committing { records_indexed } => {
                 bar.set_message(format!(
                     "Committing... {} records",
-                    HumanCount(records_indexed)
+                    numbers.count(records_indexed)
                 ));
             }
             hallucinator_openalex::BuildProgress::FileSkipped { filename, error } => {
@@ -1665,11 +3118,20 @@ async fn update_openalex(
                     eprintln!("Warning: skipped {} ({error})", filename);
                 });
             }
-            hallucinator_openalex::BuildProgress::Merging => {
+            hallucinator_openalex::BuildProgress::Merging { segments_before } => {
                 for (_, s) in file_spinners.drain() {
                     s.finish_and_clear();
                 }
-                bar.set_message("Merging index segments...");
+                bar.set_message(format!("Merging {} index segments...", segments_before));
+            }
+            hallucinator_openalex::BuildProgress::Merged {
+                segments_before,
+                segments_after,
+            } => {
+                bar.set_message(format!(
+                    "Merged index segments ({} -> {})",
+                    segments_before, segments_after
+                ));
             }
             hallucinator_openalex::BuildProgress::Complete {
                 publications,
@@ -1685,17 +3147,15 @@ async fn update_openalex(
                         format!(" ({} files failed)", failed_files.len())
                     };
                     bar.finish_with_message(format!(
-                        "Indexed {} publications (total {:.0?}){}",
-                        HumanCount(publications),
-                        build_start.elapsed(),
+                        "{}{}",
+                        output::indexed_summary(numbers, publications, None, build_start.elapsed()),
                         warn,
                     ));
                 }
             }
-        },
-    )
-    .await
-    .map_err(|e| anyhow::anyhow!("{}", e))?;
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1706,3 +3166,142 @@ async fn update_openalex(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_run_json_includes_skipped_references() {
+        let dir = tempfile::tempdir().unwrap();
+        let bib = dir.path().join("refs.bib");
+        std::fs::write(
+            &bib,
+            "@article{kept,\n  title = {Attention Is All You Need},\n  author = {Ashish Vaswani and Noam Shazeer},\n  year = {2017},\n}\n\
+             @misc{short,\n  title = {Tech Report},\n  author = {Jane Doe},\n  year = {2020},\n}\n",
+        )
+        .unwrap();
+
+        let extraction = hallucinator_ingest::extract_references(&bib).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&extraction_to_json(&extraction).unwrap()).unwrap();
+
+        let references = json["references"].as_array().unwrap();
+        let reasons: Vec<_> = references
+            .iter()
+            .map(|r| (r["title"].as_str().unwrap(), r["skip_reason"].as_str()))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                ("Attention Is All You Need", None),
+                ("Tech Report", Some("short_title"))
+            ]
+        );
+        assert_eq!(json["skip_stats"]["short_title"], 1);
+        assert_eq!(json["skip_stats"]["total_raw"], 2);
+    }
+
+    #[test]
+    fn test_strict_extraction_fails_on_high_skip_ratio() {
+        let dir = tempfile::tempdir().unwrap();
+        let bib = dir.path().join("refs.bib");
+        std::fs::write(
+            &bib,
+            "@article{kept,\n  title = {Attention Is All You Need},\n  author = {Ashish Vaswani and Noam Shazeer},\n  year = {2017},\n}\n\
+             @misc{short1,\n  title = {Tech Report},\n  author = {Jane Doe},\n  year = {2020},\n}\n\
+             @misc{short2,\n  title = {Slides},\n  author = {John Roe},\n  year = {2021},\n}\n",
+        )
+        .unwrap();
+
+        let extraction = hallucinator_ingest::extract_references(&bib).unwrap();
+        let stats = &extraction.skip_stats;
+        assert_eq!(stats.skipped(), 2);
+        assert_eq!(fails_strict_extraction(stats, Some(0.1)), Some(0.1));
+        assert_eq!(fails_strict_extraction(stats, Some(0.7)), None);
+        assert_eq!(fails_strict_extraction(stats, None), None);
+
+        let mut out = Vec::new();
+        output::print_skip_breakdown(&mut out, stats, 0.1, ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("skipped 2 of 3 references (66.7%"));
+        assert!(out.contains("Short title: 2"));
+    }
+
+    #[test]
+    fn test_stdin_references() {
+        assert!(is_stdin_path(std::path::Path::new("-")));
+        assert!(!is_stdin_path(std::path::Path::new("paper.pdf")));
+
+        let pasted = "\
+A. Vaswani, N. Shazeer, N. Parmar, J. Uszkoreit, L. Jones, A. N. Gomez, L. Kaiser, and I. Polosukhin, \"Attention is all you need,\" in Advances in Neural Information Processing Systems, 2017.
+
+K. He, X. Zhang, S. Ren, and J. Sun, \"Deep residual learning for image recognition,\" in Proc. IEEE CVPR, 2016.
+";
+        let extraction = extract_from_reader(std::io::Cursor::new(pasted)).unwrap();
+        let titles: Vec<_> = extraction
+            .references
+            .iter()
+            .filter_map(|r| r.title.as_deref())
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "Attention is all you need",
+                "Deep residual learning for image recognition"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_max_concurrent_files_must_be_positive() {
+        assert_eq!(parse_file_limit("3"), Ok(3));
+        assert!(parse_file_limit("0").is_err());
+        assert!(parse_file_limit("-1").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bounded_tasks_cap_concurrency_and_keep_order() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let mut tasks = BoundedTasks::new(3);
+        let mut order = Vec::new();
+        let mut peak_queued = 0;
+        for i in 0..10u64 {
+            while tasks.is_full() {
+                order.push(tasks.pop_front(true).await.unwrap().unwrap());
+            }
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            tasks
+                .spawn(async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    // Later tasks finish first, so order comes from the queue.
+                    tokio::time::sleep(std::time::Duration::from_millis(2 * (10 - i))).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    i
+                })
+                .await;
+            if i == 4 {
+                tasks.push_done(100);
+            }
+            let running_or_waiting = tasks
+                .queue
+                .iter()
+                .filter(|task| matches!(task, QueuedTask::Running(..)))
+                .count();
+            peak_queued = peak_queued.max(running_or_waiting);
+        }
+
+        while let Some(i) = tasks.pop_front(true).await.unwrap() {
+            order.push(i);
+        }
+        assert_eq!(order, [0, 1, 2, 3, 4, 100, 5, 6, 7, 8, 9]);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        // Finished outputs stuck behind the slow first task keep their slots
+        assert_eq!(peak_queued, 3);
+    }
+}
@@ -1,6 +1,10 @@
 use std::io::Write;
 
-use hallucinator_core::{ProgressEvent, SkipStats, Status, ValidationResult};
+use hallucinator_core::doi::SharedDoi;
+use hallucinator_core::run::ReferenceChange;
+use hallucinator_core::{
+    CacheStats, CheckStats, ProgressEvent, RunStats, SkipStats, Status, ValidationResult,
+};
 use owo_colors::OwoColorize;
 
 /// Whether to use colored output.
@@ -13,6 +17,81 @@ impl ColorMode {
     }
 }
 
+/// Whether each VERIFIED progress line is followed by the authors of the
+/// matched record (`check --show-authors`).
+#[derive(Debug, Clone, Copy)]
+pub struct ShowAuthors(pub bool);
+
+/// Most found authors listed under a verified reference before the rest are
+/// summarized as "+N more".
+const MAX_SHOWN_AUTHORS: usize = 6;
+
+/// Whether counts and byte sizes in progress/summary output are printed as
+/// plain integers instead of human-friendly `1,234,567` / `1.18 MiB`.
+#[derive(Debug, Clone, Copy)]
+pub struct RawNumbers(pub bool);
+
+impl RawNumbers {
+    /// Enabled by `--raw-numbers`, or by `HALLUCINATOR_RAW_NUMBERS` set to
+    /// anything but an empty string, `0`, `false`, `no` or `off`.
+    pub fn new(flag: bool, env: Option<&str>) -> Self {
+        let env = env.is_some_and(|v| {
+            !matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "" | "0" | "false" | "no" | "off"
+            )
+        });
+        Self(flag || env)
+    }
+
+    pub fn count(&self, n: u64) -> String {
+        if self.0 {
+            n.to_string()
+        } else {
+            indicatif::HumanCount(n).to_string()
+        }
+    }
+
+    pub fn bytes(&self, n: u64) -> String {
+        if self.0 {
+            format!("{} B", n)
+        } else {
+            indicatif::HumanBytes(n).to_string()
+        }
+    }
+
+    /// Rewrite a progress-bar template's human-formatted byte placeholders
+    /// into plain positions when raw numbers are enabled.
+    pub fn template(&self, template: &str) -> String {
+        if self.0 {
+            template
+                .replace("{bytes_per_sec}", "{per_sec}")
+                .replace("{total_bytes}", "{len} B")
+                .replace("{bytes}", "{pos} B")
+        } else {
+            template.to_string()
+        }
+    }
+}
+
+/// Final line of a database build: "Indexed N publications[, M authors] (total T)".
+pub fn indexed_summary(
+    numbers: RawNumbers,
+    publications: u64,
+    authors: Option<u64>,
+    elapsed: std::time::Duration,
+) -> String {
+    let authors = authors
+        .map(|a| format!(", {} authors", numbers.count(a)))
+        .unwrap_or_default();
+    format!(
+        "Indexed {} publications{} (total {:.0?})",
+        numbers.count(publications),
+        authors,
+        elapsed
+    )
+}
+
 /// Print the extraction summary after PDF parsing.
 pub fn print_extraction_summary(
     w: &mut dyn Write,
@@ -24,35 +103,86 @@ pub fn print_extraction_summary(
     writeln!(w, "Extracting references from {}...", pdf_name)?;
     writeln!(w, "Found {} references to check", total_refs)?;
 
-    let skipped = skip_stats.url_only + skip_stats.short_title;
+    let skipped = skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder;
     if skipped > 0 {
+        let mut msg = format!(
+            "(Skipped {} URLs, {} short titles",
+            skip_stats.url_only, skip_stats.short_title
+        );
+        if skip_stats.placeholder > 0 {
+            msg.push_str(&format!(
+                ", {} placeholder/incomplete citations",
+                skip_stats.placeholder
+            ));
+        }
+        msg.push(')');
         if color.enabled() {
-            writeln!(
-                w,
-                "{}",
-                format!(
-                    "(Skipped {} URLs, {} short titles)",
-                    skip_stats.url_only, skip_stats.short_title
-                )
-                .dimmed()
-            )?;
+            writeln!(w, "{}", msg.dimmed())?;
         } else {
-            writeln!(
-                w,
-                "(Skipped {} URLs, {} short titles)",
-                skip_stats.url_only, skip_stats.short_title
-            )?;
+            writeln!(w, "{}", msg)?;
+        }
+    }
+    if skip_stats.truncated > 0 {
+        let msg = format!(
+            "Warning: too many references; only the first {} of {} will be checked (see --max-references)",
+            total_refs,
+            total_refs + skip_stats.truncated
+        );
+        if color.enabled() {
+            writeln!(w, "{}", msg.yellow())?;
+        } else {
+            writeln!(w, "{}", msg)?;
         }
     }
     writeln!(w)?;
     Ok(())
 }
 
+/// Print why a document failed `--strict-extraction`: how many references
+/// were skipped against the allowed fraction, and for which reasons.
+pub fn print_skip_breakdown(
+    w: &mut dyn Write,
+    skip_stats: &SkipStats,
+    max_fraction: f64,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let msg = format!(
+        "Extraction skipped {} of {} references ({:.1}%, --strict-extraction allows {:.1}%):",
+        skip_stats.skipped(),
+        skip_stats.total_raw,
+        skip_stats.skipped_fraction() * 100.0,
+        max_fraction * 100.0
+    );
+    if color.enabled() {
+        writeln!(w, "{}", msg.red())?;
+    } else {
+        writeln!(w, "{}", msg)?;
+    }
+    for (label, count) in [
+        ("URL only", skip_stats.url_only),
+        ("Short title", skip_stats.short_title),
+        ("Placeholder", skip_stats.placeholder),
+        ("No title", skip_stats.no_title),
+    ] {
+        writeln!(w, "  {:<12} {}", format!("{}:", label), count)?;
+    }
+    if skip_stats.no_authors > 0 {
+        writeln!(
+            w,
+            "  ({} more had no authors; they are checked by title only)",
+            skip_stats.no_authors
+        )?;
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
 /// Print a real-time progress event.
 pub fn print_progress(
     w: &mut dyn Write,
     event: &ProgressEvent,
     color: ColorMode,
+    show_authors: ShowAuthors,
 ) -> std::io::Result<()> {
     match event {
         ProgressEvent::Checking {
@@ -80,7 +210,13 @@ pub fn print_progress(
             let idx = index + 1;
             match result.status {
                 Status::Verified => {
-                    let source = result.source.as_deref().unwrap_or("unknown");
+                    let mut source = result.source.as_deref().unwrap_or("unknown").to_string();
+                    if result.verified_by_count > 1 {
+                        source.push_str(&format!(
+                            ", verified by {} sources",
+                            result.verified_by_count
+                        ));
+                    }
                     if color.enabled() {
                         writeln!(
                             w,
@@ -93,6 +229,14 @@ pub fn print_progress(
                     } else {
                         writeln!(w, "[{}/{}] -> VERIFIED ({})", idx, total, source)?;
                     }
+                    if show_authors.0 && !result.found_authors.is_empty() {
+                        let authors = found_authors_line(&result.found_authors);
+                        if color.enabled() {
+                            writeln!(w, "    {} {}", "Authors:".dimmed(), authors.magenta())?;
+                        } else {
+                            writeln!(w, "    Authors: {}", authors)?;
+                        }
+                    }
                 }
                 Status::AuthorMismatch => {
                     let source = result.source.as_deref().unwrap_or("unknown");
@@ -133,7 +277,9 @@ pub fn print_progress(
                 count
             )?;
         }
-        ProgressEvent::Retrying { .. }
+        ProgressEvent::Extracted { .. }
+        | ProgressEvent::Retrying { .. }
+        | ProgressEvent::DatabaseQueryStarted { .. }
         | ProgressEvent::DatabaseQueryComplete { .. }
         | ProgressEvent::RateLimitWait { .. }
         | ProgressEvent::RateLimitRetry { .. } => {
@@ -143,6 +289,16 @@ pub fn print_progress(
     Ok(())
 }
 
+/// The first [`MAX_SHOWN_AUTHORS`] found authors, comma-separated, with a
+/// count of the rest.
+fn found_authors_line(authors: &[String]) -> String {
+    let shown = authors[..authors.len().min(MAX_SHOWN_AUTHORS)].join(", ");
+    match authors.len().saturating_sub(MAX_SHOWN_AUTHORS) {
+        0 => shown,
+        rest => format!("{}, +{} more", shown, rest),
+    }
+}
+
 /// Print the detailed hallucination/mismatch report for all problematic references.
 pub fn print_hallucination_report(
     w: &mut dyn Write,
@@ -191,6 +347,7 @@ fn print_not_found_block(
         writeln!(w, "  {}", result.title)?;
     }
     writeln!(w)?;
+    print_cite_key(w, result, color)?;
 
     if color.enabled() {
         writeln!(w, "{} Reference not found in any database", "Status:".red())?;
@@ -208,6 +365,14 @@ fn print_not_found_block(
     } else {
         writeln!(w, "{}", dbs)?;
     }
+    if result.insufficient_coverage {
+        let caveat = hallucinator_reporting::coverage_caveat(result);
+        if color.enabled() {
+            writeln!(w, "{} {}", "Caveat:".yellow(), caveat)?;
+        } else {
+            writeln!(w, "Caveat: {}", caveat)?;
+        }
+    }
 
     writeln!(w)?;
     let dash_sep = "-".repeat(60);
@@ -220,6 +385,24 @@ fn print_not_found_block(
     Ok(())
 }
 
+/// Print the LaTeX cite key, when the reference came from a BBL/BIB file.
+fn print_cite_key(
+    w: &mut dyn Write,
+    result: &ValidationResult,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let Some(key) = &result.cite_key else {
+        return Ok(());
+    };
+    if color.enabled() {
+        writeln!(w, "{}:", "Cite key".bold())?;
+    } else {
+        writeln!(w, "Cite key:")?;
+    }
+    writeln!(w, "  {}", key)?;
+    writeln!(w)
+}
+
 fn print_author_mismatch_block(
     w: &mut dyn Write,
     result: &ValidationResult,
@@ -248,6 +431,7 @@ fn print_author_mismatch_block(
         writeln!(w, "  {}", result.title)?;
     }
     writeln!(w)?;
+    print_cite_key(w, result, color)?;
 
     if color.enabled() {
         writeln!(
@@ -321,7 +505,7 @@ pub fn print_doi_issues(
 ) -> std::io::Result<()> {
     let issues: Vec<_> = results
         .iter()
-        .filter(|r| r.doi_info.as_ref().is_some_and(|d| !d.valid))
+        .filter_map(|r| doi_issue(r).map(|(doi, issue)| (r, doi, issue)))
         .collect();
 
     if issues.is_empty() {
@@ -344,18 +528,92 @@ pub fn print_doi_issues(
         writeln!(w, "{}", sep)?;
     }
 
-    for result in issues {
-        let doi_info = result.doi_info.as_ref().unwrap();
+    for (result, doi, issue) in issues {
         let short_title = truncate(&result.title, 70);
         writeln!(w)?;
         if color.enabled() {
             writeln!(w, "{} {}", "Reference:".bold(), short_title)?;
-            writeln!(w, "{} {}", "DOI:".bold(), doi_info.doi)?;
-            writeln!(w, "{} DOI does not resolve", "Issue:".red())?;
+            writeln!(w, "{} {}", "DOI:".bold(), doi)?;
+            writeln!(w, "{} {}", "Issue:".red(), issue)?;
         } else {
             writeln!(w, "Reference: {}", short_title)?;
-            writeln!(w, "DOI: {}", doi_info.doi)?;
-            writeln!(w, "Issue: DOI does not resolve")?;
+            writeln!(w, "DOI: {}", doi)?;
+            writeln!(w, "Issue: {}", issue)?;
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// The reference's DOI and what is wrong with it, if anything: a DOI that
+/// doesn't resolve to the cited title, or one naming a different paper than
+/// the title search matched (see [`DoiConflict`](hallucinator_core::DoiConflict)).
+fn doi_issue(result: &ValidationResult) -> Option<(&str, String)> {
+    if let Some(doi_info) = &result.doi_info
+        && (!doi_info.valid || doi_info.title_mismatch)
+    {
+        let issue = if doi_info.well_formed && !doi_info.registered {
+            "DOI is not registered (well-formed, but the resolver has no record of it)".to_string()
+        } else if doi_info.valid {
+            format!(
+                "DOI resolves to a different title: {}",
+                truncate(doi_info.title.as_deref().unwrap_or(""), 70)
+            )
+        } else if !doi_info.well_formed {
+            "DOI is malformed".to_string()
+        } else {
+            "DOI does not resolve".to_string()
+        };
+        return Some((&doi_info.doi, issue));
+    }
+    let conflict = result.doi_conflict.as_ref()?;
+    Some((
+        &conflict.doi,
+        format!(
+            "DOI resolves to \"{}\", but {} matched \"{}\"",
+            truncate(&conflict.doi_title, 70),
+            conflict.matched_source,
+            truncate(&conflict.matched_title, 70)
+        ),
+    ))
+}
+
+/// Print DOIs cited by several references under different titles.
+///
+/// `titles[i]` is the title of reference `i`, matching
+/// [`SharedDoi::ref_indices`].
+pub fn print_shared_dois(
+    w: &mut dyn Write,
+    shared: &[SharedDoi],
+    titles: &[String],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    if shared.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().red())?;
+        writeln!(w, "{}", "DOI REUSED FOR DIFFERENT TITLES".bold().red())?;
+        writeln!(w, "{}", sep.bold().red())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "DOI REUSED FOR DIFFERENT TITLES")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for group in shared {
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "{} {}", "DOI:".bold(), group.doi)?;
+        } else {
+            writeln!(w, "DOI: {}", group.doi)?;
+        }
+        for &i in &group.ref_indices {
+            let title = titles.get(i).map(String::as_str).unwrap_or("");
+            writeln!(w, "  [{}] {}", i + 1, truncate(title, 70))?;
         }
     }
     writeln!(w)?;
@@ -421,6 +679,140 @@ pub fn print_retraction_warnings(
     Ok(())
 }
 
+/// Print arXiv preprints whose published version has a DOI.
+pub fn print_published_versions(
+    w: &mut dyn Write,
+    results: &[ValidationResult],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let published: Vec<_> = results
+        .iter()
+        .filter_map(|r| {
+            let ax = r.arxiv_info.as_ref()?;
+            Some((r, ax, ax.published_doi.as_deref()?))
+        })
+        .collect();
+
+    if published.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().cyan())?;
+        writeln!(w, "{}", "PREPRINTS WITH A PUBLISHED VERSION".bold().cyan())?;
+        writeln!(w, "{}", sep.bold().cyan())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "PREPRINTS WITH A PUBLISHED VERSION")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for (result, ax, doi) in published {
+        let short_title = truncate(&result.title, 70);
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "{} {}", "Reference:".bold(), short_title)?;
+            writeln!(w, "{} {}", "arXiv:".bold(), ax.arxiv_id)?;
+            writeln!(w, "{} {}", "Published as:".cyan(), doi)?;
+        } else {
+            writeln!(w, "Reference: {}", short_title)?;
+            writeln!(w, "arXiv: {}", ax.arxiv_id)?;
+            writeln!(w, "Published as: {}", doi)?;
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Print references verified against another version of the cited work
+/// (see `--accept-preprint-versions`).
+pub fn print_version_matches(
+    w: &mut dyn Write,
+    results: &[ValidationResult],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let matches: Vec<_> = results
+        .iter()
+        .filter_map(|r| Some((r, r.version_relation?)))
+        .collect();
+
+    if matches.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().cyan())?;
+        writeln!(w, "{}", "VERIFIED AGAINST ANOTHER VERSION".bold().cyan())?;
+        writeln!(w, "{}", sep.bold().cyan())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "VERIFIED AGAINST ANOTHER VERSION")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for (result, relation) in matches {
+        let short_title = truncate(&result.title, 70);
+        let matched = format!(
+            "{} ({})",
+            relation.description(),
+            result.source.as_deref().unwrap_or("unknown")
+        );
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "{} {}", "Reference:".bold(), short_title)?;
+            writeln!(w, "{} {}", "Matched:".cyan(), matched)?;
+        } else {
+            writeln!(w, "Reference: {}", short_title)?;
+            writeln!(w, "Matched: {}", matched)?;
+        }
+        if !result.found_authors.is_empty() {
+            writeln!(w, "Authors (DB): {}", result.found_authors.join(", "))?;
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Print references the document lists but never cites (see
+/// `--check-citations`).
+pub fn print_uncited_references(
+    w: &mut dyn Write,
+    results: &[ValidationResult],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let uncited: Vec<_> = results
+        .iter()
+        .filter(|r| r.cited_in_text == Some(false))
+        .collect();
+
+    if uncited.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().yellow())?;
+        writeln!(w, "{}", "LISTED BUT NEVER CITED".bold().yellow())?;
+        writeln!(w, "{}", sep.bold().yellow())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "LISTED BUT NEVER CITED")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    writeln!(w)?;
+    for result in &uncited {
+        writeln!(w, "- {}", truncate(&result.title, 70))?;
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
 /// Print the final summary.
 pub fn print_summary(
     w: &mut dyn Write,
@@ -457,14 +849,21 @@ pub fn print_summary(
         writeln!(w, "{}", sep)?;
     }
 
-    let total_skipped = skip_stats.url_only + skip_stats.short_title;
+    let total_skipped = skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder;
     writeln!(w, "  Total references found: {}", skip_stats.total_raw)?;
     writeln!(w, "  References analyzed: {}", results.len())?;
     if total_skipped > 0 {
-        let msg = format!(
-            "Skipped: {} (URLs: {}, short titles: {})",
+        let mut msg = format!(
+            "Skipped: {} (URLs: {}, short titles: {}",
             total_skipped, skip_stats.url_only, skip_stats.short_title
         );
+        if skip_stats.placeholder > 0 {
+            msg.push_str(&format!(
+                ", {} placeholder/incomplete citations",
+                skip_stats.placeholder
+            ));
+        }
+        msg.push(')');
         if color.enabled() {
             writeln!(w, "  {}", msg.dimmed())?;
         } else {
@@ -482,6 +881,17 @@ pub fn print_summary(
             writeln!(w, "  {}", msg)?;
         }
     }
+    if skip_stats.truncated > 0 {
+        let msg = format!(
+            "Truncated: {} references beyond --max-references were not checked",
+            skip_stats.truncated
+        );
+        if color.enabled() {
+            writeln!(w, "  {}", msg.yellow())?;
+        } else {
+            writeln!(w, "  {}", msg)?;
+        }
+    }
     writeln!(w)?;
 
     if color.enabled() {
@@ -520,7 +930,11 @@ pub fn print_summary(
     let dois_found = results.iter().filter(|r| r.doi_info.is_some()).count();
     let dois_valid = results
         .iter()
-        .filter(|r| r.doi_info.as_ref().is_some_and(|d| d.valid))
+        .filter(|r| {
+            r.doi_info
+                .as_ref()
+                .is_some_and(|d| d.valid && !d.title_mismatch)
+        })
         .count();
     if dois_found > 0 && dois_valid > 0 {
         let msg = format!("DOIs validated: {}/{}", dois_valid, dois_found);
@@ -536,6 +950,270 @@ pub fn print_summary(
     Ok(())
 }
 
+/// Number of papers listed by problematic percentage in the corpus summary.
+const CORPUS_WORST_PAPERS: usize = 5;
+
+/// Totals across every file checked in one archive run.
+#[derive(Debug, Default)]
+pub struct CorpusSummary {
+    pub papers: usize,
+    pub totals: CheckStats,
+    /// Papers with any problems, highest problematic percentage first.
+    pub worst: Vec<(String, f64)>,
+}
+
+/// Sum per-file stats and rank papers by problematic percentage.
+pub fn aggregate_corpus(files: &[(String, CheckStats)]) -> CorpusSummary {
+    let mut totals = CheckStats::default();
+    for (_, s) in files {
+        totals.total += s.total;
+        totals.verified += s.verified;
+        totals.not_found += s.not_found;
+        totals.author_mismatch += s.author_mismatch;
+        totals.retracted += s.retracted;
+        totals.skipped += s.skipped;
+    }
+
+    let mut worst: Vec<(String, f64)> = files
+        .iter()
+        .map(|(name, s)| (name.clone(), RunStats::from_check_stats(s).problematic_pct))
+        .filter(|(_, pct)| *pct > 0.0)
+        .collect();
+    worst.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    worst.truncate(CORPUS_WORST_PAPERS);
+
+    CorpusSummary {
+        papers: files.len(),
+        totals,
+        worst,
+    }
+}
+
+/// Print the aggregate summary at the end of an archive run.
+pub fn print_corpus_summary(
+    w: &mut dyn Write,
+    summary: &CorpusSummary,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let t = &summary.totals;
+    let checked = t.total.saturating_sub(t.skipped);
+    let pct = |n: usize| {
+        if checked == 0 {
+            0.0
+        } else {
+            n as f64 / checked as f64 * 100.0
+        }
+    };
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold())?;
+        writeln!(w, "{}", "CORPUS SUMMARY".bold())?;
+        writeln!(w, "{}", sep.bold())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "CORPUS SUMMARY")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    writeln!(w, "  Papers checked: {}", summary.papers)?;
+    writeln!(w, "  References: {} ({} skipped)", t.total, t.skipped)?;
+    writeln!(w, "  Verified: {} ({:.1}%)", t.verified, pct(t.verified))?;
+    writeln!(w, "  Not found: {} ({:.1}%)", t.not_found, pct(t.not_found))?;
+    writeln!(
+        w,
+        "  Author mismatches: {} ({:.1}%)",
+        t.author_mismatch,
+        pct(t.author_mismatch)
+    )?;
+    writeln!(w, "  Retractions: {}", t.retracted)?;
+
+    if !summary.worst.is_empty() {
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "  {}", "Most problematic papers:".bold())?;
+        } else {
+            writeln!(w, "  Most problematic papers:")?;
+        }
+        for (name, p) in &summary.worst {
+            let line = format!("    {:>5.1}%  {}", p, name);
+            if color.enabled() {
+                writeln!(w, "{}", line.red())?;
+            } else {
+                writeln!(w, "{}", line)?;
+            }
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Print each reference's extracted author list under its raw citation, for
+/// spotting author-extraction failures without running a full check.
+pub fn print_author_dump(
+    w: &mut dyn Write,
+    file_name: &str,
+    entries: &[(String, Vec<String>)],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    if color.enabled() {
+        writeln!(
+            w,
+            "{} {} ({} references)\n",
+            "AUTHOR DUMP:".bold().cyan(),
+            file_name.bold(),
+            entries.len()
+        )?;
+    } else {
+        writeln!(
+            w,
+            "AUTHOR DUMP: {} ({} references)\n",
+            file_name,
+            entries.len()
+        )?;
+    }
+
+    let mut empty = 0;
+    for (i, (raw, authors)) in entries.iter().enumerate() {
+        let raw_display = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+        if color.enabled() {
+            writeln!(
+                w,
+                "{} {}",
+                format!("[{}]", i + 1).bold().yellow(),
+                raw_display.dimmed()
+            )?;
+        } else {
+            writeln!(w, "[{}] {}", i + 1, raw_display)?;
+        }
+        if authors.is_empty() {
+            empty += 1;
+            if color.enabled() {
+                writeln!(w, "    Authors: {}", "(none)".red())?;
+            } else {
+                writeln!(w, "    Authors: (none)")?;
+            }
+        } else {
+            writeln!(w, "    Authors: {}", authors.join("; "))?;
+        }
+        writeln!(w)?;
+    }
+
+    writeln!(
+        w,
+        "Total: {} references, {} with no authors extracted",
+        entries.len(),
+        empty
+    )?;
+    Ok(())
+}
+
+/// Print `--cache-stats`: entry counts by type, then how old the entries are.
+/// `now` is the current Unix time in seconds.
+pub fn print_cache_stats(
+    w: &mut dyn Write,
+    path: &std::path::Path,
+    stats: &CacheStats,
+    now: u64,
+    numbers: RawNumbers,
+) -> std::io::Result<()> {
+    let count = |n: usize| numbers.count(n as u64);
+    let ago = |epoch: Option<u64>| match epoch {
+        Some(epoch) => {
+            let age = std::time::Duration::from_secs(now.saturating_sub(epoch));
+            format!("{} ago", indicatif::HumanDuration(age))
+        }
+        None => "-".to_string(),
+    };
+
+    writeln!(w, "Cache: {}", path.display())?;
+    writeln!(w, "  Found:      {}", count(stats.found))?;
+    writeln!(w, "  Not found:  {}", count(stats.not_found))?;
+    writeln!(w, "  DOI:        {}", count(stats.doi))?;
+    writeln!(w, "  Total:      {}", count(stats.total()))?;
+    writeln!(w, "  Oldest:     {}", ago(stats.oldest))?;
+    writeln!(w, "  Newest:     {}", ago(stats.newest))?;
+    writeln!(
+        w,
+        "  Age:        <1h {}, <1d {}, <1w {}, older {}",
+        count(stats.under_hour),
+        count(stats.under_day),
+        count(stats.under_week),
+        count(stats.older)
+    )?;
+    Ok(())
+}
+
+/// Print the result of `diff`: status changes, then added and removed references.
+pub fn print_run_diff(
+    w: &mut dyn Write,
+    changes: &[ReferenceChange],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    if changes.is_empty() {
+        writeln!(w, "No reference changes.")?;
+        return Ok(());
+    }
+
+    let changed: Vec<_> = changes
+        .iter()
+        .filter_map(|c| match c {
+            ReferenceChange::StatusChanged {
+                title, old, new, ..
+            } => Some((title, format!("{} -> {}", old.as_str(), new.as_str()))),
+            _ => None,
+        })
+        .collect();
+    let added: Vec<_> = changes
+        .iter()
+        .filter_map(|c| match c {
+            ReferenceChange::Added { title, status, .. } => {
+                Some((title, status.as_str().to_string()))
+            }
+            _ => None,
+        })
+        .collect();
+    let removed: Vec<_> = changes
+        .iter()
+        .filter_map(|c| match c {
+            ReferenceChange::Removed { title, status, .. } => {
+                Some((title, status.as_str().to_string()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    for (heading, entries) in [
+        ("Status changed", changed),
+        ("Added", added),
+        ("Removed", removed),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        if color.enabled() {
+            writeln!(w, "{} ({})", heading.bold(), entries.len())?;
+        } else {
+            writeln!(w, "{} ({})", heading, entries.len())?;
+        }
+        for (title, status) in entries {
+            let title = if title.is_empty() {
+                "(untitled)"
+            } else {
+                title.as_str()
+            };
+            if color.enabled() {
+                writeln!(w, "  {} {}", format!("[{}]", status).yellow(), title)?;
+            } else {
+                writeln!(w, "  [{}] {}", status, title)?;
+            }
+        }
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
 fn truncate(s: &str, max: usize) -> String {
     if s.len() > max {
         format!("{}...", &s[..max])
@@ -543,3 +1221,351 @@ fn truncate(s: &str, max: usize) -> String {
         s.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_numbers_env_is_boolean() {
+        assert!(!RawNumbers::new(false, None).0);
+        assert!(!RawNumbers::new(false, Some("0")).0);
+        assert!(!RawNumbers::new(false, Some("false")).0);
+        assert!(!RawNumbers::new(false, Some("")).0);
+        assert!(RawNumbers::new(false, Some("1")).0);
+        assert!(RawNumbers::new(false, Some("yes")).0);
+        assert!(RawNumbers::new(true, Some("0")).0);
+    }
+
+    #[test]
+    fn test_indexed_summary_raw_numbers() {
+        let elapsed = std::time::Duration::from_secs(90);
+        let human = indexed_summary(RawNumbers(false), 1_234_567, Some(89_012), elapsed);
+        assert!(human.contains("1,234,567 publications"), "{}", human);
+        assert!(human.contains("89,012 authors"), "{}", human);
+
+        let raw = indexed_summary(RawNumbers(true), 1_234_567, Some(89_012), elapsed);
+        assert!(raw.contains("1234567 publications"), "{}", raw);
+        assert!(raw.contains("89012 authors"), "{}", raw);
+        assert!(!raw.contains("1,"), "{}", raw);
+
+        assert_eq!(RawNumbers(true).bytes(1_048_576), "1048576 B");
+        assert_eq!(
+            RawNumbers(true).template("{bytes}/{total_bytes} ({bytes_per_sec})"),
+            "{pos} B/{len} B ({per_sec})"
+        );
+    }
+
+    fn stats(total: usize, verified: usize, not_found: usize, skipped: usize) -> CheckStats {
+        CheckStats {
+            total,
+            verified,
+            not_found,
+            skipped,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cache_stats_reflect_seeded_cache() {
+        let cache = hallucinator_core::QueryCache::default();
+        let found = hallucinator_core::db::DbQueryResult::found("Paper", vec![], None);
+        cache.insert("Paper", "CrossRef", &found);
+        cache.insert("Paper", "DBLP", &found);
+        cache.insert(
+            "Missing",
+            "CrossRef",
+            &hallucinator_core::db::DbQueryResult::not_found(),
+        );
+        let stats = cache.stats();
+        let now = stats.newest.unwrap();
+
+        let mut out = Vec::new();
+        print_cache_stats(
+            &mut out,
+            std::path::Path::new("cache.db"),
+            &stats,
+            now,
+            RawNumbers(true),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Found:      2"), "{}", out);
+        assert!(out.contains("Not found:  1"), "{}", out);
+        assert!(out.contains("DOI:        0"), "{}", out);
+        assert!(out.contains("Total:      3"), "{}", out);
+        assert!(out.contains("<1h 3, <1d 0, <1w 0, older 0"), "{}", out);
+    }
+
+    #[test]
+    fn test_aggregate_corpus() {
+        let files = vec![
+            ("a.pdf".to_string(), stats(12, 10, 0, 2)),
+            ("b.pdf".to_string(), stats(20, 15, 5, 0)),
+            (
+                "c.pdf".to_string(),
+                CheckStats {
+                    retracted: 1,
+                    author_mismatch: 1,
+                    ..stats(10, 9, 0, 0)
+                },
+            ),
+            ("d.pdf".to_string(), stats(3, 0, 0, 3)),
+        ];
+        let summary = aggregate_corpus(&files);
+
+        assert_eq!(summary.papers, 4);
+        assert_eq!(summary.totals.total, 45);
+        assert_eq!(summary.totals.verified, 34);
+        assert_eq!(summary.totals.not_found, 5);
+        assert_eq!(summary.totals.author_mismatch, 1);
+        assert_eq!(summary.totals.retracted, 1);
+        assert_eq!(summary.totals.skipped, 5);
+        // Clean papers and papers with nothing checked are not listed.
+        assert_eq!(
+            summary.worst,
+            vec![("b.pdf".to_string(), 25.0), ("c.pdf".to_string(), 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_corpus_caps_worst_list() {
+        let files: Vec<_> = (0..8)
+            .map(|i| (format!("p{}.pdf", i), stats(10, 10 - i, i, 0)))
+            .collect();
+        let summary = aggregate_corpus(&files);
+        let names: Vec<_> = summary.worst.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["p7.pdf", "p6.pdf", "p5.pdf", "p4.pdf", "p3.pdf"]);
+    }
+
+    #[test]
+    fn test_author_dump_shape() {
+        let entries = vec![
+            (
+                "A. Vaswani, N. Shazeer, and N. Parmar.\n  Attention is all you need. 2017."
+                    .to_string(),
+                vec![
+                    "A. Vaswani".to_string(),
+                    "N. Shazeer".to_string(),
+                    "N. Parmar".to_string(),
+                ],
+            ),
+            ("Untitled web page, 2020.".to_string(), vec![]),
+        ];
+        let mut out = Vec::new();
+        print_author_dump(&mut out, "paper.pdf", &entries, ColorMode(false)).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "AUTHOR DUMP: paper.pdf (2 references)\n\n\
+             [1] A. Vaswani, N. Shazeer, and N. Parmar. Attention is all you need. 2017.\n\
+             \x20   Authors: A. Vaswani; N. Shazeer; N. Parmar\n\n\
+             [2] Untitled web page, 2020.\n\
+             \x20   Authors: (none)\n\n\
+             Total: 2 references, 1 with no authors extracted\n"
+        );
+    }
+
+    fn verified(found_authors: &[&str]) -> ValidationResult {
+        ValidationResult {
+            title: "Deep Residual Learning for Image Recognition".into(),
+            raw_citation: String::new(),
+            ref_authors: vec!["K. He".into()],
+            status: Status::Verified,
+            source: Some("DBLP".into()),
+            found_authors: found_authors.iter().map(|a| a.to_string()).collect(),
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            near_miss: None,
+            author_match_detail: vec![],
+            cite_key: None,
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 1,
+            insufficient_coverage: false,
+            cited_in_text: None,
+        }
+    }
+
+    fn progress_output(result: ValidationResult, show_authors: bool) -> String {
+        let event = ProgressEvent::Result {
+            index: 0,
+            total: 1,
+            result: Box::new(result),
+        };
+        let mut out = Vec::new();
+        print_progress(
+            &mut out,
+            &event,
+            ColorMode(false),
+            ShowAuthors(show_authors),
+        )
+        .unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_verified_progress_lists_found_authors() {
+        let result = verified(&["Kaiming He", "Xiangyu Zhang", "Shaoqing Ren", "Jian Sun"]);
+        assert_eq!(
+            progress_output(result.clone(), true),
+            "[1/1] -> VERIFIED (DBLP)\n    Authors: Kaiming He, Xiangyu Zhang, Shaoqing Ren, Jian Sun\n"
+        );
+        assert_eq!(progress_output(result, false), "[1/1] -> VERIFIED (DBLP)\n");
+    }
+
+    #[test]
+    fn test_long_found_author_list_is_truncated() {
+        let names: Vec<String> = (1..=9).map(|i| format!("Author {}", i)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let out = progress_output(verified(&names), true);
+        assert!(out.ends_with("Author 5, Author 6, +3 more\n"), "{}", out);
+        assert!(!out.contains("Author 7"), "{}", out);
+    }
+
+    #[test]
+    fn test_not_found_block_shows_coverage_caveat() {
+        let mut result = verified(&[]);
+        result.status = Status::NotFound;
+        result.source = None;
+        result.failed_dbs = vec!["CrossRef".into()];
+
+        let mut out = Vec::new();
+        print_hallucination_report(
+            &mut out,
+            std::slice::from_ref(&result),
+            false,
+            ColorMode(false),
+        )
+        .unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("Caveat"));
+
+        result.insufficient_coverage = true;
+        let mut out = Vec::new();
+        print_hallucination_report(&mut out, &[result], false, ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.contains(
+                "Caveat: too few online databases answered (CrossRef timed out or failed)"
+            ),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn test_uncited_references_section() {
+        let mut cited = verified(&[]);
+        cited.cited_in_text = Some(true);
+        let unchecked = verified(&[]);
+        let mut uncited = verified(&[]);
+        uncited.title = "Towards Evaluating the Robustness of Neural Networks".into();
+        uncited.cited_in_text = Some(false);
+
+        let mut out = Vec::new();
+        print_uncited_references(&mut out, &[cited.clone(), unchecked], ColorMode(false)).unwrap();
+        assert!(out.is_empty());
+
+        let mut out = Vec::new();
+        print_uncited_references(&mut out, &[cited, uncited], ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("LISTED BUT NEVER CITED"), "{}", out);
+        assert!(
+            out.contains("\n- Towards Evaluating the Robustness of Neural Networks\n"),
+            "{}",
+            out
+        );
+        assert!(!out.contains("Deep Residual"), "{}", out);
+    }
+
+    #[test]
+    fn test_doi_conflict_output() {
+        let mut result = verified(&["Kaiming He"]);
+        result.doi_conflict = Some(hallucinator_core::DoiConflict {
+            doi: "10.1000/xyz".into(),
+            doi_title: "Attention Is All You Need".into(),
+            matched_source: "DBLP".into(),
+            matched_title: "Deep Residual Learning for Image Recognition".into(),
+        });
+        let mut out = Vec::new();
+        print_doi_issues(&mut out, &[result], ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "DOI: 10.1000/xyz\nIssue: DOI resolves to \"Attention Is All You Need\", but DBLP matched \"Deep Residual Learning for Image Recognition\"\n"
+        ), "{}", out);
+    }
+
+    #[test]
+    fn test_version_matches_output() {
+        let mut result = verified(&["Kaiming He", "Jian Sun"]);
+        result.version_relation = Some(hallucinator_core::VersionRelation::PublishedVersion);
+        let mut out = Vec::new();
+        print_version_matches(&mut out, &[verified(&[]), result], ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(
+            out.contains(
+                "Reference: Deep Residual Learning for Image Recognition\n\
+             Matched: published version of the cited preprint (DBLP)\n\
+             Authors (DB): Kaiming He, Jian Sun\n"
+            ),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn test_shared_dois_output() {
+        let shared = vec![SharedDoi {
+            doi: "10.1109/cvpr.2016.90".into(),
+            ref_indices: vec![0, 2],
+        }];
+        let titles = vec![
+            "Real Paper".to_string(),
+            "Other".into(),
+            "Fake Paper".into(),
+        ];
+        let mut out = Vec::new();
+        print_shared_dois(&mut out, &shared, &titles, ColorMode(false)).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("DOI: 10.1109/cvpr.2016.90\n  [1] Real Paper\n  [3] Fake Paper\n"));
+
+        let mut out = Vec::new();
+        print_shared_dois(&mut out, &[], &titles, ColorMode(false)).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_run_diff_output() {
+        use hallucinator_core::run::RecordStatus;
+
+        let changes = vec![
+            ReferenceChange::StatusChanged {
+                fingerprint: "bbbb".into(),
+                title: "Fixed Paper".into(),
+                old: RecordStatus::NotFound,
+                new: RecordStatus::Verified,
+            },
+            ReferenceChange::Removed {
+                fingerprint: "aaaa".into(),
+                title: "Dropped Paper".into(),
+                status: RecordStatus::AuthorMismatch,
+            },
+        ];
+        let mut out = Vec::new();
+        print_run_diff(&mut out, &changes, ColorMode(false)).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Status changed (1)\n\
+             \x20 [not_found -> verified] Fixed Paper\n\n\
+             Removed (1)\n\
+             \x20 [author_mismatch] Dropped Paper\n\n"
+        );
+
+        let mut out = Vec::new();
+        print_run_diff(&mut out, &[], ColorMode(false)).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "No reference changes.\n");
+    }
+}
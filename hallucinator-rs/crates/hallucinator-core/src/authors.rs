@@ -26,20 +26,7 @@ pub fn validate_authors(ref_authors: &[String], found_authors: &[String]) -> boo
         return false;
     }
 
-    let ref_clean: Vec<&str> = ref_authors
-        .iter()
-        .map(|a| a.trim())
-        .filter(|a| !a.is_empty())
-        .collect();
-
-    // Determine if ref authors are last-name-only
-    let last_name_only_count = ref_clean
-        .iter()
-        .filter(|a| !has_first_name_or_initial(a))
-        .count();
-    let ref_are_last_name_only = last_name_only_count > ref_clean.len() / 2;
-
-    if ref_are_last_name_only {
+    if ref_are_last_name_only(ref_authors) {
         let ref_surnames: Vec<String> = ref_authors
             .iter()
             .filter_map(|a| {
@@ -56,18 +43,9 @@ pub fn validate_authors(ref_authors: &[String], found_authors: &[String]) -> boo
             })
             .collect();
 
-        for rn in &ref_surnames {
-            for fn_ in &found_surnames {
-                if rn == fn_ {
-                    return true;
-                }
-                // Check if one surname ends with the other
-                if fn_.ends_with(rn.as_str()) || rn.ends_with(fn_.as_str()) {
-                    return true;
-                }
-            }
-        }
-        false
+        ref_surnames
+            .iter()
+            .any(|rn| found_surnames.iter().any(|fn_| surnames_match(rn, fn_)))
     } else {
         let ref_set: HashSet<String> = ref_authors.iter().map(|a| normalize_author(a)).collect();
         let found_set: HashSet<String> =
@@ -76,6 +54,81 @@ pub fn validate_authors(ref_authors: &[String], found_authors: &[String]) -> boo
     }
 }
 
+/// Pair each author in `ref_authors` with whether it appears in `found_authors`.
+///
+/// Uses the same mode as [`validate_authors`], so the result has at least one
+/// `true` exactly when `validate_authors` accepts. Empty if either list is.
+pub fn author_match_detail(
+    ref_authors: &[String],
+    found_authors: &[String],
+) -> Vec<(String, bool)> {
+    if ref_authors.is_empty() || found_authors.is_empty() {
+        return vec![];
+    }
+
+    if ref_are_last_name_only(ref_authors) {
+        let found_surnames: Vec<String> = found_authors
+            .iter()
+            .map(|a| get_last_name(a))
+            .filter(|s| !s.is_empty())
+            .collect();
+        ref_authors
+            .iter()
+            .map(|a| {
+                let rn = get_last_name(a);
+                let found =
+                    !rn.is_empty() && found_surnames.iter().any(|fn_| surnames_match(&rn, fn_));
+                (a.clone(), found)
+            })
+            .collect()
+    } else {
+        let found_set: HashSet<String> =
+            found_authors.iter().map(|a| normalize_author(a)).collect();
+        ref_authors
+            .iter()
+            .map(|a| (a.clone(), found_set.contains(&normalize_author(a))))
+            .collect()
+    }
+}
+
+/// Whether any surname in `ref_authors` matches one in `found_authors`,
+/// ignoring first names and initials entirely.
+///
+/// Looser than [`validate_authors`]: `Bill Freeman` and `William T. Freeman`
+/// overlap here but not there.
+pub(crate) fn surnames_overlap(ref_authors: &[String], found_authors: &[String]) -> bool {
+    let found_surnames: Vec<String> = found_authors
+        .iter()
+        .map(|a| get_last_name(a))
+        .filter(|s| !s.is_empty())
+        .collect();
+    ref_authors
+        .iter()
+        .map(|a| get_last_name(a))
+        .filter(|s| !s.is_empty())
+        .any(|rn| found_surnames.iter().any(|fn_| surnames_match(&rn, fn_)))
+}
+
+/// Whether most of `ref_authors` are bare surnames (no first name or initial).
+fn ref_are_last_name_only(ref_authors: &[String]) -> bool {
+    let ref_clean: Vec<&str> = ref_authors
+        .iter()
+        .map(|a| a.trim())
+        .filter(|a| !a.is_empty())
+        .collect();
+    let last_name_only_count = ref_clean
+        .iter()
+        .filter(|a| !has_first_name_or_initial(a))
+        .count();
+    last_name_only_count > ref_clean.len() / 2
+}
+
+/// Lowercased surnames match if equal or if one ends with the other
+/// (multi-word surnames cited by their last word).
+fn surnames_match(a: &str, b: &str) -> bool {
+    a == b || a.ends_with(b) || b.ends_with(a)
+}
+
 /// Extract surname from name parts, handling multi-word surnames and suffixes.
 fn get_surname_from_parts(parts: &[&str]) -> String {
     if parts.is_empty() {
@@ -237,6 +290,16 @@ mod tests {
         assert!(!validate_authors(&s(&["John Smith"]), &s(&["Bob Brown"]),));
     }
 
+    #[test]
+    fn test_surnames_overlap_ignores_first_names() {
+        let cited = s(&["Bill Freeman", "Egon Pasztor"]);
+        let found = s(&["William T. Freeman", "Owen T. Carmichael"]);
+        assert!(!validate_authors(&cited, &found));
+        assert!(surnames_overlap(&cited, &found));
+        assert!(!surnames_overlap(&s(&["Bill Freeman"]), &s(&["Bob Brown"])));
+        assert!(!surnames_overlap(&cited, &[]));
+    }
+
     #[test]
     fn test_validate_authors_last_name_only() {
         // Last-name-only mode
@@ -287,4 +350,33 @@ mod tests {
         assert!(!validate_authors(&[], &s(&["Smith"])));
         assert!(!validate_authors(&s(&["Smith"]), &[]));
     }
+
+    #[test]
+    fn test_author_match_detail_partial() {
+        let detail = author_match_detail(
+            &s(&["John Smith", "Alice Jones", "Carol White"]),
+            &s(&["John Smith", "Bob Brown", "C. White"]),
+        );
+        assert_eq!(
+            detail,
+            vec![
+                ("John Smith".to_string(), true),
+                ("Alice Jones".to_string(), false),
+                ("Carol White".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_author_match_detail_last_name_only() {
+        let detail = author_match_detail(
+            &s(&["Bavel", "Jones"]),
+            &s(&["Jay Van Bavel", "Alice Brown"]),
+        );
+        assert_eq!(
+            detail,
+            vec![("Bavel".to_string(), true), ("Jones".to_string(), false)]
+        );
+        assert!(author_match_detail(&s(&["Smith"]), &[]).is_empty());
+    }
 }
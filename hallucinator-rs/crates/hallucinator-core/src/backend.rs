@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -8,8 +9,14 @@ pub enum BackendError {
     OpenError(String),
     #[error("failed to extract text: {0}")]
     ExtractionError(String),
+    /// The PDF needs a password the backend wasn't given (the path).
+    #[error("{0}: PDF is encrypted/password-protected")]
+    Encrypted(String),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+    /// Text extraction did not finish within the per-file deadline.
+    #[error("text extraction timed out after {}s", .0.as_secs_f64())]
+    Timeout(Duration),
 }
 
 /// Trait for PDF text extraction backends.
@@ -17,7 +24,23 @@ pub enum BackendError {
 /// Implementors provide the low-level text extraction step; the parsing
 /// pipeline (section detection, reference segmentation, title/author extraction)
 /// lives in [`hallucinator_parsing::ReferenceExtractor`].
+///
+/// Built-in implementations are `hallucinator_pdf_mupdf::MupdfBackend`
+/// (AGPL) and `hallucinator_pdf_extract::PdfExtractBackend` (MIT);
+/// `hallucinator-ingest` picks one at compile time.
 pub trait PdfBackend: Send + Sync {
     /// Extract the full text content of a PDF file.
+    ///
+    /// The parser depends on the text's shape, so every backend must:
+    /// - emit pages in order, in reading order within each page;
+    /// - end every visual text line with `\n` (never join lines into
+    ///   paragraphs) and leave a blank line or more between pages;
+    /// - keep hyphenation and ligatures as printed (the parser repairs them);
+    /// - fail with [`BackendError::OpenError`] when the file can't be read or
+    ///   isn't a PDF, [`BackendError::Encrypted`] when it can't be decrypted,
+    ///   and [`BackendError::ExtractionError`] when its content can't be
+    ///   decoded.
+    ///
+    /// Calls may block for a long time and may run on any thread.
     fn extract_text(&self, path: &Path) -> Result<String, BackendError>;
 }
@@ -11,6 +11,16 @@
 //! minor variations (diacritics, HTML entities, Greek letters) produce the same
 //! key. Only successful results are cached; transient errors (timeouts, network
 //! failures) are never cached.
+//!
+//! DOI resolutions are cached separately via [`get_doi`](QueryCache::get_doi) /
+//! [`insert_doi`](QueryCache::insert_doi), keyed by the
+//! [normalized DOI](crate::doi::normalize_doi) and kept for the positive TTL.
+//!
+//! Expired entries are dropped in bulk by
+//! [`prune_expired`](QueryCache::prune_expired), which also runs on
+//! [`open`](QueryCache::open). A cache built
+//! [`with_max_entries`](QueryCache::with_max_entries) additionally evicts its
+//! least-recently-used query results once a tier grows past the cap.
 
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
@@ -21,6 +31,7 @@ use dashmap::DashMap;
 use rusqlite::{Connection, OpenFlags, params};
 
 use crate::db::DbQueryResult;
+use crate::doi::{DoiValidation, normalize_doi};
 use crate::matching::normalize_title;
 use crate::retraction::RetractionResult;
 
@@ -30,6 +41,14 @@ pub const DEFAULT_POSITIVE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60)
 /// Default time-to-live for negative (not found) cache entries: 24 hours.
 pub const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
+/// Hits on a capped persistent cache are written to disk in batches of this
+/// many (and before every eviction), not one SQLite write per hit.
+const TOUCH_BATCH: usize = 256;
+
+/// A tier over its cap is evicted down to `max - max / EVICTION_SLACK`, so
+/// the eviction scan runs once per batch of inserts instead of on each one.
+const EVICTION_SLACK: usize = 10;
+
 /// Cache key: normalized title + database name.
 #[derive(Hash, Eq, PartialEq, Clone, Debug)]
 struct CacheKey {
@@ -52,14 +71,75 @@ enum CachedResult {
 }
 
 /// A timestamped cache entry (L1 only — uses monotonic `Instant`).
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 struct CacheEntry {
     result: CachedResult,
     inserted_at: Instant,
-    /// Wall-clock timestamp stored for L2 round-trips (written but not
-    /// actively read back from L1 — SQLite uses it on promotion).
-    #[allow(dead_code)]
+    /// Wall-clock timestamp, for L2 round-trips and [`QueryCache::stats`].
     inserted_epoch: u64,
+    /// [`QueryCache::access_clock`] tick of the last insert or hit.
+    last_used: AtomicU64,
+}
+
+impl CacheEntry {
+    fn is_expired(&self, positive_ttl: Duration, negative_ttl: Duration) -> bool {
+        let ttl = match &self.result {
+            CachedResult::Found { .. } => positive_ttl,
+            CachedResult::NotFound => negative_ttl,
+        };
+        self.inserted_at.elapsed() > ttl
+    }
+}
+
+/// A timestamped DOI resolution (L1 only).
+#[derive(Clone, Debug)]
+struct DoiCacheEntry {
+    validation: DoiValidation,
+    inserted_at: Instant,
+}
+
+/// Entry counts and ages of a [`QueryCache`], from [`QueryCache::stats`].
+///
+/// Counts the persistent tier when there is one, otherwise the in-memory one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Query results that found the paper.
+    pub found: usize,
+    /// Query results that did not.
+    pub not_found: usize,
+    /// DOI resolutions.
+    pub doi: usize,
+    /// Insertion time (Unix seconds) of the oldest entry of any type.
+    pub oldest: Option<u64>,
+    /// Insertion time (Unix seconds) of the newest entry of any type.
+    pub newest: Option<u64>,
+    /// Entries inserted within the last hour.
+    pub under_hour: usize,
+    /// Entries between an hour and a day old.
+    pub under_day: usize,
+    /// Entries between a day and a week old.
+    pub under_week: usize,
+    /// Entries a week old or more.
+    pub older: usize,
+}
+
+impl CacheStats {
+    /// Total entries of every type.
+    pub fn total(&self) -> usize {
+        self.found + self.not_found + self.doi
+    }
+
+    /// Count an entry inserted at `epoch` into the age brackets as of `now`.
+    fn record_age(&mut self, epoch: u64, now: u64) {
+        self.oldest = Some(self.oldest.map_or(epoch, |o| o.min(epoch)));
+        self.newest = Some(self.newest.map_or(epoch, |n| n.max(epoch)));
+        match now.saturating_sub(epoch) {
+            age if age < 60 * 60 => self.under_hour += 1,
+            age if age < 24 * 60 * 60 => self.under_day += 1,
+            age if age < 7 * 24 * 60 * 60 => self.under_week += 1,
+            _ => self.older += 1,
+        }
+    }
 }
 
 /// Open a SQLite connection with WAL mode and standard pragmas.
@@ -80,6 +160,36 @@ fn open_sqlite(path: &Path, read_only: bool) -> Result<Connection, rusqlite::Err
     Ok(conn)
 }
 
+/// Counts and ages of every query result and DOI resolution in the cache
+/// database behind `conn`.
+fn sqlite_stats(conn: &Connection) -> CacheStats {
+    let now = now_epoch();
+    let mut stats = CacheStats::default();
+    if let Ok(mut stmt) = conn.prepare("SELECT found, inserted_at FROM query_cache")
+        && let Ok(rows) = stmt.query_map([], |row| {
+            Ok((row.get::<_, i32>(0)? != 0, row.get::<_, u64>(1)?))
+        })
+    {
+        for (found, epoch) in rows.flatten() {
+            if found {
+                stats.found += 1;
+            } else {
+                stats.not_found += 1;
+            }
+            stats.record_age(epoch, now);
+        }
+    }
+    if let Ok(mut stmt) = conn.prepare("SELECT inserted_at FROM doi_cache")
+        && let Ok(rows) = stmt.query_map([], |row| row.get::<_, u64>(0))
+    {
+        for epoch in rows.flatten() {
+            stats.doi += 1;
+            stats.record_age(epoch, now);
+        }
+    }
+    stats
+}
+
 /// SQLite writer connection (L2 writes: insert, clear, evict).
 struct SqliteWriter {
     conn: Connection,
@@ -98,6 +208,7 @@ impl SqliteWriter {
                  paper_url        TEXT,
                  inserted_at      INTEGER NOT NULL,
                  retraction_json  TEXT,
+                 last_used        INTEGER NOT NULL DEFAULT 0,
                  PRIMARY KEY (normalized_title, db_name)
              );",
         )?;
@@ -105,12 +216,27 @@ impl SqliteWriter {
         // ALTER TABLE ADD COLUMN is a no-op if the column already exists (SQLite
         // returns "duplicate column name" error which we silently ignore).
         let _ = conn.execute_batch("ALTER TABLE query_cache ADD COLUMN retraction_json TEXT");
+        let _ = conn.execute_batch(
+            "ALTER TABLE query_cache ADD COLUMN last_used INTEGER NOT NULL DEFAULT 0",
+        );
+        conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS query_cache_last_used ON query_cache (last_used);",
+        )?;
         conn.execute_batch(
             "CREATE TABLE IF NOT EXISTS fp_overrides (
                  normalized_title TEXT PRIMARY KEY,
                  fp_reason        TEXT NOT NULL
              );",
         )?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS doi_cache (
+                 normalized_doi TEXT PRIMARY KEY,
+                 valid          INTEGER NOT NULL,
+                 title          TEXT,
+                 authors        TEXT,
+                 inserted_at    INTEGER NOT NULL
+             );",
+        )?;
         Ok(Self { conn })
     }
 
@@ -122,6 +248,7 @@ impl SqliteWriter {
         db_name: &str,
         result: &CachedResult,
         epoch: u64,
+        tick: u64,
     ) -> Option<bool> {
         // Check what (if anything) is being replaced
         let previous: Option<bool> = self
@@ -156,8 +283,8 @@ impl SqliteWriter {
 
         let _ = self.conn.execute(
             "INSERT OR REPLACE INTO query_cache
-                 (normalized_title, db_name, found, found_title, authors, paper_url, inserted_at, retraction_json)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                 (normalized_title, db_name, found, found_title, authors, paper_url, inserted_at, retraction_json, last_used)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 norm_title,
                 db_name,
@@ -166,7 +293,8 @@ impl SqliteWriter {
                 authors_json,
                 paper_url,
                 epoch,
-                retraction_json
+                retraction_json,
+                tick
             ],
         );
 
@@ -175,6 +303,7 @@ impl SqliteWriter {
 
     fn clear(&self) {
         let _ = self.conn.execute("DELETE FROM query_cache", []);
+        let _ = self.conn.execute("DELETE FROM doi_cache", []);
         // Reclaim disk space — without VACUUM the deleted pages stay as free pages.
         let _ = self.conn.execute_batch("VACUUM");
     }
@@ -190,15 +319,92 @@ impl SqliteWriter {
         deleted
     }
 
-    fn evict_expired(&self, positive_ttl: Duration, negative_ttl: Duration) {
+    /// Delete every query result and DOI resolution past its TTL. Returns
+    /// the number of rows removed.
+    fn evict_expired(&self, positive_ttl: Duration, negative_ttl: Duration) -> usize {
         let now = now_epoch();
         let pos_cutoff = now.saturating_sub(positive_ttl.as_secs());
         let neg_cutoff = now.saturating_sub(negative_ttl.as_secs());
+        let queries = self
+            .conn
+            .execute(
+                "DELETE FROM query_cache WHERE
+                     (found = 1 AND inserted_at < ?1) OR
+                     (found = 0 AND inserted_at < ?2)",
+                params![pos_cutoff, neg_cutoff],
+            )
+            .unwrap_or(0);
+        let dois = self
+            .conn
+            .execute(
+                "DELETE FROM doi_cache WHERE inserted_at < ?1",
+                params![pos_cutoff],
+            )
+            .unwrap_or(0);
+        queries + dois
+    }
+
+    /// Delete the `count` least-recently-used query results. Returns how
+    /// many (found, not_found) rows were removed.
+    fn evict_lru(&self, count: usize) -> (usize, usize) {
+        let mut removed = (0, 0);
+        if let Ok(mut stmt) = self.conn.prepare(
+            "DELETE FROM query_cache WHERE rowid IN
+                 (SELECT rowid FROM query_cache ORDER BY last_used LIMIT ?1)
+             RETURNING found",
+        ) && let Ok(rows) = stmt.query_map(params![count as i64], |row| row.get::<_, i32>(0))
+        {
+            for found in rows.flatten() {
+                if found != 0 {
+                    removed.0 += 1;
+                } else {
+                    removed.1 += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Record reads of query results for LRU eviction, in one transaction.
+    fn touch_all(&self, touches: &[(String, String, u64)]) {
+        let Ok(tx) = self.conn.unchecked_transaction() else {
+            return;
+        };
+        if let Ok(mut stmt) = tx.prepare_cached(
+            "UPDATE query_cache SET last_used = MAX(last_used, ?3)
+             WHERE normalized_title = ?1 AND db_name = ?2",
+        ) {
+            for (norm_title, db_name, tick) in touches {
+                let _ = stmt.execute(params![norm_title, db_name, tick]);
+            }
+        }
+        let _ = tx.commit();
+    }
+
+    /// Highest `last_used` tick on disk, so a reopened cache keeps counting
+    /// from where the last one stopped.
+    fn max_last_used(&self) -> u64 {
+        self.conn
+            .query_row(
+                "SELECT COALESCE(MAX(last_used), 0) FROM query_cache",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap_or(0)
+    }
+
+    fn insert_doi(&self, norm_doi: &str, validation: &DoiValidation, epoch: u64) {
+        let authors_json = serde_json::to_string(&validation.authors).unwrap_or_default();
         let _ = self.conn.execute(
-            "DELETE FROM query_cache WHERE
-                 (found = 1 AND inserted_at < ?1) OR
-                 (found = 0 AND inserted_at < ?2)",
-            params![pos_cutoff, neg_cutoff],
+            "INSERT OR REPLACE INTO doi_cache (normalized_doi, valid, title, authors, inserted_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                norm_doi,
+                validation.valid as i32,
+                validation.title,
+                authors_json,
+                epoch
+            ],
         );
     }
 
@@ -218,6 +424,11 @@ impl SqliteWriter {
         );
     }
 
+    /// Counts and ages of every stored query result and DOI resolution.
+    fn stats(&self) -> CacheStats {
+        sqlite_stats(&self.conn)
+    }
+
     /// Count of (found, not_found) entries in the SQLite table.
     fn counts_by_type(&self) -> (usize, usize) {
         let found: usize = self
@@ -301,6 +512,39 @@ impl ReadPool {
         result
     }
 
+    fn get_doi(&self, norm_doi: &str, ttl: Duration) -> Option<(DoiValidation, u64)> {
+        let conn = self.acquire()?;
+        let row = conn
+            .query_row(
+                "SELECT valid, title, authors, inserted_at FROM doi_cache WHERE normalized_doi = ?1",
+                params![norm_doi],
+                |row| {
+                    let valid: i32 = row.get(0)?;
+                    let title: Option<String> = row.get(1)?;
+                    let authors_json: Option<String> = row.get(2)?;
+                    let inserted_at: u64 = row.get(3)?;
+                    Ok((valid, title, authors_json, inserted_at))
+                },
+            )
+            .ok();
+        self.release(conn);
+
+        let (valid, title, authors_json, inserted_at) = row?;
+        let age = Duration::from_secs(now_epoch().saturating_sub(inserted_at));
+        if age > ttl {
+            return None;
+        }
+        let validation = DoiValidation {
+            valid: valid != 0,
+            title,
+            authors: authors_json
+                .and_then(|j| serde_json::from_str(&j).ok())
+                .unwrap_or_default(),
+            error: (valid == 0).then(|| "DOI not found".to_string()),
+        };
+        Some((validation, inserted_at))
+    }
+
     fn query(
         conn: &Connection,
         norm_title: &str,
@@ -379,6 +623,8 @@ fn now_epoch() -> u64 {
 ///     writes go through a single [`SqliteWriter`] behind a [`Mutex`].
 pub struct QueryCache {
     entries: DashMap<CacheKey, CacheEntry>,
+    /// DOI resolutions keyed by normalized DOI.
+    doi_entries: DashMap<String, DoiCacheEntry>,
     /// Writer connection for inserts, clears, eviction (serialized).
     sqlite_writer: Option<Mutex<SqliteWriter>>,
     /// Pool of read-only connections for concurrent L2 lookups.
@@ -396,6 +642,12 @@ pub struct QueryCache {
     l1_not_found_count: AtomicU64,
     l2_found_count: AtomicU64,
     l2_not_found_count: AtomicU64,
+    /// Most query results kept per tier; `None` leaves the cache unbounded.
+    max_entries: Option<usize>,
+    /// Logical clock ordering inserts and hits for LRU eviction.
+    access_clock: AtomicU64,
+    /// Hits not yet written to disk, as (normalized title, DB, tick).
+    pending_touches: Mutex<Vec<(String, String, u64)>>,
 }
 
 impl Default for QueryCache {
@@ -404,11 +656,22 @@ impl Default for QueryCache {
     }
 }
 
+impl Drop for QueryCache {
+    fn drop(&mut self) {
+        if let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            self.flush_touches(&store);
+        }
+    }
+}
+
 impl QueryCache {
     /// Create an in-memory-only cache with custom TTLs (no disk persistence).
     pub fn new(positive_ttl: Duration, negative_ttl: Duration) -> Self {
         Self {
             entries: DashMap::new(),
+            doi_entries: DashMap::new(),
             sqlite_writer: None,
             read_pool: None,
             positive_ttl,
@@ -421,12 +684,15 @@ impl QueryCache {
             l1_not_found_count: AtomicU64::new(0),
             l2_found_count: AtomicU64::new(0),
             l2_not_found_count: AtomicU64::new(0),
+            max_entries: None,
+            access_clock: AtomicU64::new(0),
+            pending_touches: Mutex::new(Vec::new()),
         }
     }
 
     /// Open a persistent cache backed by a SQLite database at `path`.
     ///
-    /// On startup, expired entries are evicted from SQLite. The L1 DashMap
+    /// On startup, expired entries are pruned from SQLite. The L1 DashMap
     /// starts empty and is populated lazily as entries are accessed.
     pub fn open(
         path: &Path,
@@ -435,10 +701,15 @@ impl QueryCache {
     ) -> Result<Self, String> {
         let writer = SqliteWriter::open(path)
             .map_err(|e| format!("Failed to open cache database at {}: {}", path.display(), e))?;
-        writer.evict_expired(positive_ttl, negative_ttl);
+        let pruned = writer.evict_expired(positive_ttl, negative_ttl);
+        if pruned > 0 {
+            tracing::debug!(pruned, path = %path.display(), "pruned expired cache entries");
+        }
         let (l2_found, l2_nf) = writer.counts_by_type();
+        let last_used = writer.max_last_used();
         Ok(Self {
             entries: DashMap::new(),
+            doi_entries: DashMap::new(),
             sqlite_writer: Some(Mutex::new(writer)),
             read_pool: Some(ReadPool::new(path)),
             positive_ttl,
@@ -451,9 +722,175 @@ impl QueryCache {
             l1_not_found_count: AtomicU64::new(0),
             l2_found_count: AtomicU64::new(l2_found as u64),
             l2_not_found_count: AtomicU64::new(l2_nf as u64),
+            max_entries: None,
+            access_clock: AtomicU64::new(last_used),
+            pending_touches: Mutex::new(Vec::new()),
         })
     }
 
+    /// Cap each tier at `max_entries` query results, evicting the
+    /// least-recently-used ones (on disk too) whenever an insert goes over.
+    ///
+    /// With a cap set, hits also record their recency on disk. Those writes
+    /// are batched, so recency from the last few hits before the process
+    /// exits may only be flushed when the cache is dropped.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self.enforce_max_entries();
+        self
+    }
+
+    /// Entry counts and ages of the cache database at `path`, read without
+    /// changing it: unlike [`open`](Self::open), nothing is created, migrated
+    /// or pruned, so entries past their TTL are still counted.
+    pub fn read_stats(path: &Path) -> Result<CacheStats, String> {
+        let conn = open_sqlite(path, true)
+            .map_err(|e| format!("Failed to open cache database at {}: {}", path.display(), e))?;
+        Ok(sqlite_stats(&conn))
+    }
+
+    /// The cap set by [`with_max_entries`](Self::with_max_entries), if any.
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Remove every query result and DOI resolution past its TTL from both
+    /// tiers in one pass.
+    ///
+    /// Returns the total number of entries removed across both tiers.
+    pub fn prune_expired(&self) -> usize {
+        let (positive_ttl, negative_ttl) = (self.positive_ttl, self.negative_ttl);
+        let mut l1_removed = 0usize;
+        self.entries.retain(|_, entry| {
+            if entry.is_expired(positive_ttl, negative_ttl) {
+                l1_removed += 1;
+                match entry.result {
+                    CachedResult::Found { .. } => {
+                        self.l1_found_count.fetch_sub(1, Ordering::Relaxed)
+                    }
+                    CachedResult::NotFound => {
+                        self.l1_not_found_count.fetch_sub(1, Ordering::Relaxed)
+                    }
+                };
+                false
+            } else {
+                true
+            }
+        });
+        self.doi_entries.retain(|_, entry| {
+            if entry.inserted_at.elapsed() > positive_ttl {
+                l1_removed += 1;
+                false
+            } else {
+                true
+            }
+        });
+
+        let l2_removed = if let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            let removed = store.evict_expired(positive_ttl, negative_ttl);
+            self.store_l2_counts(&store);
+            removed
+        } else {
+            0
+        };
+
+        l1_removed + l2_removed
+    }
+
+    /// Next tick of the LRU clock.
+    fn tick(&self) -> u64 {
+        self.access_clock.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Queue a hit for the on-disk LRU order, writing the queue out once it
+    /// fills; only capped caches track recency there.
+    fn touch_l2(&self, norm_title: &str, db_name: &str, tick: u64) {
+        if self.max_entries.is_none() || self.sqlite_writer.is_none() {
+            return;
+        }
+        let queued = match self.pending_touches.lock() {
+            Ok(mut pending) => {
+                pending.push((norm_title.to_string(), db_name.to_string(), tick));
+                pending.len()
+            }
+            Err(_) => return,
+        };
+        if queued >= TOUCH_BATCH
+            && let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            self.flush_touches(&store);
+        }
+    }
+
+    /// Write queued hits to disk. Called with the writer locked, so an
+    /// eviction never runs between taking the queue and writing it.
+    fn flush_touches(&self, store: &SqliteWriter) {
+        let touches = match self.pending_touches.lock() {
+            Ok(mut pending) => std::mem::take(&mut *pending),
+            Err(_) => return,
+        };
+        if !touches.is_empty() {
+            store.touch_all(&touches);
+        }
+    }
+
+    fn store_l2_counts(&self, store: &SqliteWriter) {
+        let (found, not_found) = store.counts_by_type();
+        self.l2_found_count.store(found as u64, Ordering::Relaxed);
+        self.l2_not_found_count
+            .store(not_found as u64, Ordering::Relaxed);
+    }
+
+    /// Evict least-recently-used query results from any tier over the cap,
+    /// down to [`EVICTION_SLACK`] below it. Tier sizes come from the
+    /// running counters, so a tier under the cap costs nothing to check.
+    fn enforce_max_entries(&self) {
+        let Some(max) = self.max_entries else {
+            return;
+        };
+        let target = max - max / EVICTION_SLACK;
+
+        let (l1_found, l1_not_found) = self.l1_counts();
+        if l1_found + l1_not_found > max {
+            let mut by_age: Vec<(u64, CacheKey)> = self
+                .entries
+                .iter()
+                .map(|e| (e.last_used.load(Ordering::Relaxed), e.key().clone()))
+                .collect();
+            let excess = by_age.len().saturating_sub(target);
+            if excess > 0 && excess < by_age.len() {
+                by_age.select_nth_unstable_by_key(excess, |(last_used, _)| *last_used);
+            }
+            for (_, key) in by_age.into_iter().take(excess) {
+                if let Some((_, entry)) = self.entries.remove(&key) {
+                    match entry.result {
+                        CachedResult::Found { .. } => {
+                            self.l1_found_count.fetch_sub(1, Ordering::Relaxed)
+                        }
+                        CachedResult::NotFound => {
+                            self.l1_not_found_count.fetch_sub(1, Ordering::Relaxed)
+                        }
+                    };
+                }
+            }
+        }
+
+        if self.disk_len() > max
+            && let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            self.flush_touches(&store);
+            let (found, not_found) = store.evict_lru(self.disk_len().saturating_sub(target));
+            self.l2_found_count
+                .fetch_sub(found as u64, Ordering::Relaxed);
+            self.l2_not_found_count
+                .fetch_sub(not_found as u64, Ordering::Relaxed);
+        }
+    }
+
     /// Look up a cached result for the given title and database.
     ///
     /// Returns `Some(result)` on cache hit (within TTL), `None` on miss.
@@ -468,11 +905,7 @@ impl QueryCache {
 
         // L1 check
         if let Some(entry) = self.entries.get(&key) {
-            let ttl = match &entry.result {
-                CachedResult::Found { .. } => self.positive_ttl,
-                CachedResult::NotFound => self.negative_ttl,
-            };
-            if entry.inserted_at.elapsed() > ttl {
+            if entry.is_expired(self.positive_ttl, self.negative_ttl) {
                 let is_found = matches!(entry.result, CachedResult::Found { .. });
                 drop(entry);
                 self.entries.remove(&key);
@@ -484,6 +917,9 @@ impl QueryCache {
                 }
                 // Fall through to L2
             } else {
+                let tick = self.tick();
+                entry.last_used.store(tick, Ordering::Relaxed);
+                self.touch_l2(&norm, db_name, tick);
                 self.hits.fetch_add(1, Ordering::Relaxed);
                 self.record_lookup(start);
                 tracing::trace!(db = db_name, title, "cache L1 hit");
@@ -498,6 +934,8 @@ impl QueryCache {
         {
             // Promote to L1
             tracing::trace!(db = db_name, title, "cache L2 hit, promoting to L1");
+            let tick = self.tick();
+            self.touch_l2(&norm, db_name, tick);
             let query_result = cached_to_query_result(&result);
             match &result {
                 CachedResult::Found { .. } => {
@@ -513,8 +951,10 @@ impl QueryCache {
                     result,
                     inserted_at: epoch_to_instant(epoch),
                     inserted_epoch: epoch,
+                    last_used: AtomicU64::new(tick),
                 },
             );
+            self.enforce_max_entries();
             self.hits.fetch_add(1, Ordering::Relaxed);
             self.record_lookup(start);
             return Some(query_result);
@@ -566,6 +1006,7 @@ impl QueryCache {
 
         let now_is_found = matches!(cached, CachedResult::Found { .. });
         let epoch = now_epoch();
+        let tick = self.tick();
 
         // L1 — DashMap::insert returns the old value if the key existed
         let old_l1 = self.entries.insert(
@@ -574,6 +1015,7 @@ impl QueryCache {
                 result: cached.clone(),
                 inserted_at: Instant::now(),
                 inserted_epoch: epoch,
+                last_used: AtomicU64::new(tick),
             },
         );
 
@@ -595,7 +1037,7 @@ impl QueryCache {
         if let Some(ref sqlite_mutex) = self.sqlite_writer
             && let Ok(store) = sqlite_mutex.lock()
         {
-            let previous = store.insert(&norm, db_name, &cached, epoch);
+            let previous = store.insert(&norm, db_name, &cached, epoch, tick);
 
             // Adjust L2 counters: decrement old type, increment new type
             match previous {
@@ -613,6 +1055,8 @@ impl QueryCache {
                 self.l2_not_found_count.fetch_add(1, Ordering::Relaxed);
             }
         }
+
+        self.enforce_max_entries();
     }
 
     /// Remove all not-found entries from L1 (in-memory) and L2 (SQLite).
@@ -647,6 +1091,10 @@ impl QueryCache {
     /// Remove all entries from both L1 and L2.
     pub fn clear(&self) {
         self.entries.clear();
+        self.doi_entries.clear();
+        if let Ok(mut pending) = self.pending_touches.lock() {
+            pending.clear();
+        }
         self.l1_found_count.store(0, Ordering::Relaxed);
         self.l1_not_found_count.store(0, Ordering::Relaxed);
         if let Some(ref sqlite_mutex) = self.sqlite_writer
@@ -718,6 +1166,33 @@ impl QueryCache {
         )
     }
 
+    /// Entry counts by type and age, for diagnosing cache behavior.
+    pub fn stats(&self) -> CacheStats {
+        if let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            return store.stats();
+        }
+
+        let now = now_epoch();
+        let mut stats = CacheStats::default();
+        for entry in self.entries.iter() {
+            match entry.result {
+                CachedResult::Found { .. } => stats.found += 1,
+                CachedResult::NotFound => stats.not_found += 1,
+            }
+            stats.record_age(entry.inserted_epoch, now);
+        }
+        for entry in self.doi_entries.iter() {
+            stats.doi += 1;
+            stats.record_age(
+                now.saturating_sub(entry.inserted_at.elapsed().as_secs()),
+                now,
+            );
+        }
+        stats
+    }
+
     /// The positive (found) TTL.
     pub fn positive_ttl(&self) -> Duration {
         self.positive_ttl
@@ -728,6 +1203,67 @@ impl QueryCache {
         self.negative_ttl
     }
 
+    // ── DOI resolution methods ──────────────────────────────────────
+
+    /// Look up a cached DOI resolution.
+    ///
+    /// Returns `Some(validation)` if the DOI was resolved within the positive
+    /// TTL, `None` otherwise. The DOI is normalized before lookup.
+    pub fn get_doi(&self, doi: &str) -> Option<DoiValidation> {
+        let norm = normalize_doi(doi);
+
+        // L1 check
+        if let Some(entry) = self.doi_entries.get(&norm) {
+            if entry.inserted_at.elapsed() <= self.positive_ttl {
+                tracing::trace!(doi, "DOI cache L1 hit");
+                return Some(entry.validation.clone());
+            }
+            drop(entry);
+            self.doi_entries.remove(&norm);
+        }
+
+        // L2 check, promoting to L1 on hit
+        if let Some(ref pool) = self.read_pool
+            && let Some((validation, epoch)) = pool.get_doi(&norm, self.positive_ttl)
+        {
+            tracing::trace!(doi, "DOI cache L2 hit, promoting to L1");
+            self.doi_entries.insert(
+                norm,
+                DoiCacheEntry {
+                    validation: validation.clone(),
+                    inserted_at: epoch_to_instant(epoch),
+                },
+            );
+            return Some(validation);
+        }
+
+        tracing::trace!(doi, "DOI cache miss");
+        None
+    }
+
+    /// Insert a DOI resolution into the cache (write-through to L1 and L2).
+    ///
+    /// Only definitive resolutions — resolved metadata or a 404 — should be
+    /// passed here; transient lookup failures must not be cached.
+    pub fn insert_doi(&self, doi: &str, validation: &DoiValidation) {
+        let norm = normalize_doi(doi);
+        tracing::trace!(doi, valid = validation.valid, "DOI cache insert");
+        let epoch = now_epoch();
+
+        if let Some(ref sqlite_mutex) = self.sqlite_writer
+            && let Ok(store) = sqlite_mutex.lock()
+        {
+            store.insert_doi(&norm, validation, epoch);
+        }
+        self.doi_entries.insert(
+            norm,
+            DoiCacheEntry {
+                validation: validation.clone(),
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
     // ── FP override methods ─────────────────────────────────────────
 
     /// Store or remove a false-positive override for a reference title.
@@ -772,6 +1308,9 @@ fn cached_to_query_result(cached: &CachedResult) -> DbQueryResult {
             authors: authors.clone(),
             paper_url: url.clone(),
             retraction: retraction.clone(),
+            near_miss: None,
+            doi_mismatch_title: None,
+            doi_unregistered: false,
         },
         CachedResult::NotFound => DbQueryResult::not_found(),
     }
@@ -1033,6 +1572,209 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn prune_expired_removes_expired_entries() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        // Not-found entries expire after 1s; found ones are kept for a day.
+        let cache =
+            QueryCache::open(&path, Duration::from_secs(86400), Duration::from_secs(1)).unwrap();
+        cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
+        cache.insert("Missing", "DB", &DbQueryResult::not_found());
+        assert_eq!(cache.prune_expired(), 0);
+
+        std::thread::sleep(Duration::from_secs(2));
+
+        // One not-found entry in each tier
+        assert_eq!(cache.prune_expired(), 2);
+        assert_eq!(cache.l1_counts(), (1, 0));
+        assert_eq!(cache.l2_counts(), (1, 0));
+        assert!(cache.get("Paper", "DB").is_some());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn max_entries_evicts_least_recently_used() {
+        let cache = QueryCache::default().with_max_entries(2);
+        cache.insert(
+            "Paper A",
+            "DB",
+            &DbQueryResult::found("Paper A", vec![], None),
+        );
+        cache.insert(
+            "Paper B",
+            "DB",
+            &DbQueryResult::found("Paper B", vec![], None),
+        );
+        // Touch A so B becomes the least recently used
+        assert!(cache.get("Paper A", "DB").is_some());
+        cache.insert(
+            "Paper C",
+            "DB",
+            &DbQueryResult::found("Paper C", vec![], None),
+        );
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.l1_counts(), (2, 0));
+        assert!(cache.get("Paper A", "DB").is_some());
+        assert!(cache.get("Paper B", "DB").is_none());
+        assert!(cache.get("Paper C", "DB").is_some());
+    }
+
+    #[test]
+    fn max_entries_evicts_below_the_cap_in_batches() {
+        let cache = QueryCache::default().with_max_entries(20);
+        for i in 0..20 {
+            let title = format!("Paper {i}");
+            cache.insert(&title, "DB", &DbQueryResult::found(&title, vec![], None));
+        }
+        assert_eq!(cache.len(), 20);
+
+        // Going over evicts down to 18, so the next insert evicts nothing
+        cache.insert("Paper 20", "DB", &DbQueryResult::not_found());
+        assert_eq!(cache.len(), 18);
+        assert!(cache.get("Paper 0", "DB").is_none());
+        assert!(cache.get("Paper 2", "DB").is_none());
+        assert!(cache.get("Paper 3", "DB").is_some());
+        cache.insert("Paper 21", "DB", &DbQueryResult::not_found());
+        assert_eq!(cache.len(), 19);
+        assert_eq!(cache.l1_counts(), (17, 2));
+    }
+
+    #[test]
+    fn sqlite_max_entries_evicts_least_recently_used() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL)
+                .unwrap()
+                .with_max_entries(2);
+            cache.insert(
+                "Paper A",
+                "DB",
+                &DbQueryResult::found("Paper A", vec![], None),
+            );
+            cache.insert("Paper B", "DB", &DbQueryResult::not_found());
+            assert!(cache.get("Paper A", "DB").is_some());
+            cache.insert(
+                "Paper C",
+                "DB",
+                &DbQueryResult::found("Paper C", vec![], None),
+            );
+            assert_eq!(cache.l2_counts(), (2, 0));
+        }
+
+        // Lowering the cap on reopen evicts down to it, oldest first
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL)
+            .unwrap()
+            .with_max_entries(1);
+        assert_eq!(cache.disk_len(), 1);
+        assert!(cache.get("Paper A", "DB").is_none());
+        assert!(cache.get("Paper B", "DB").is_none());
+        assert!(cache.get("Paper C", "DB").is_some());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn sqlite_hits_reach_disk_when_cache_is_dropped() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL)
+                .unwrap()
+                .with_max_entries(2);
+            cache.insert(
+                "Paper A",
+                "DB",
+                &DbQueryResult::found("Paper A", vec![], None),
+            );
+            cache.insert(
+                "Paper B",
+                "DB",
+                &DbQueryResult::found("Paper B", vec![], None),
+            );
+            // Queued, not yet written
+            assert!(cache.get("Paper A", "DB").is_some());
+        }
+
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL)
+            .unwrap()
+            .with_max_entries(1);
+        assert!(cache.get("Paper A", "DB").is_some());
+        assert!(cache.get("Paper B", "DB").is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn stats_reflect_seeded_cache() {
+        let cache = QueryCache::default();
+        assert_eq!(cache.stats(), CacheStats::default());
+
+        cache.insert(
+            "Paper A",
+            "CrossRef",
+            &DbQueryResult::found("Paper A", vec![], None),
+        );
+        cache.insert(
+            "Paper A",
+            "DBLP",
+            &DbQueryResult::found("Paper A", vec![], None),
+        );
+        cache.insert("Missing", "CrossRef", &DbQueryResult::not_found());
+        cache.insert_doi(
+            "10.1234/abc",
+            &DoiValidation {
+                valid: true,
+                title: Some("Paper A".into()),
+                authors: vec![],
+                error: None,
+            },
+        );
+
+        let stats = cache.stats();
+        assert_eq!((stats.found, stats.not_found, stats.doi), (2, 1, 1));
+        assert_eq!(stats.total(), 4);
+        assert_eq!(stats.under_hour, 4);
+        assert!(stats.oldest.is_some() && stats.oldest <= stats.newest);
+    }
+
+    #[test]
+    fn sqlite_stats_count_persisted_entries() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let cache =
+                QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+            cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
+            cache.insert("Missing", "DB", &DbQueryResult::not_found());
+        }
+
+        // A reopened cache has an empty L1 but still reports what is on disk
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let stats = cache.stats();
+        assert_eq!((stats.found, stats.not_found, stats.doi), (1, 1, 0));
+        assert_eq!(stats.under_hour, 2);
+        assert_eq!(QueryCache::read_stats(&path).unwrap(), stats);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn read_stats_does_not_create_a_cache() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(QueryCache::read_stats(&path).is_err());
+        assert!(!path.exists());
+    }
+
     // ── Two-tier interaction tests ────────────────────────────────────
 
     #[test]
@@ -1593,6 +2335,54 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    // ── DOI resolution tests ────────────────────────────────────────
+
+    #[test]
+    fn doi_resolution_persists_across_restart() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let cache =
+                QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+            let validation = DoiValidation {
+                valid: true,
+                title: Some("Deep Residual Learning".into()),
+                authors: vec!["Kaiming He".into()],
+                error: None,
+            };
+            cache.insert_doi("10.1109/CVPR.2016.90", &validation);
+        }
+
+        // Reopen — lookup by a differently-formatted DOI hits L2
+        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cached = cache2
+            .get_doi("https://doi.org/10.1109/cvpr.2016.90")
+            .unwrap();
+        assert!(cached.valid);
+        assert_eq!(cached.title.as_deref(), Some("Deep Residual Learning"));
+        assert_eq!(cached.authors, vec!["Kaiming He"]);
+
+        cache2.clear();
+        assert!(cache2.get_doi("10.1109/CVPR.2016.90").is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn doi_resolution_expires_with_positive_ttl() {
+        let cache = QueryCache::new(Duration::from_millis(1), Duration::from_secs(3600));
+        let validation = DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI not found".into()),
+        };
+        cache.insert_doi("10.1234/missing", &validation);
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(cache.get_doi("10.1234/missing").is_none());
+    }
+
     #[test]
     fn fp_override_uses_normalized_title() {
         let path = temp_cache_path();
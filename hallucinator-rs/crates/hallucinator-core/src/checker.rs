@@ -1,14 +1,14 @@
 use crate::db::DatabaseBackend;
-use crate::db::DbQueryResult;
 use crate::db::searxng::Searxng;
-use crate::doi::{DoiMatchResult, DoiValidation, check_doi_match, validate_doi};
+use crate::doi::{DoiMatchResult, check_doi_match, resolve_doi};
 use crate::orchestrator::query_all_databases;
 use crate::pool::{RefJob, ValidationPool};
 use crate::retraction::check_retraction;
 use crate::{
-    Config, DbResult, DbStatus, DoiInfo, ProgressEvent, Reference, RetractionInfo, Status,
-    ValidationResult,
+    Config, CoreError, DbResult, DbStatus, DoiInfo, ProgressEvent, Reference, RetractionInfo,
+    Status, ValidationResult,
 };
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -18,23 +18,44 @@ use tokio_util::sync::CancellationToken;
 /// Creates an internal ValidationPool with `num_workers` workers.
 /// Submits all refs, collects results via oneshot channels.
 /// Progress events are emitted via the callback. Cancellation is supported.
+/// References left unverified by a backend failure are then re-checked for
+/// up to `config.retry_passes` passes (see [`retry_failed_references`]).
+///
+/// Fails before checking anything if the HTTP client can't be built from
+/// `config` (an invalid proxy URL or CA certificate file).
 pub async fn check_references(
     refs: Vec<Reference>,
     config: Config,
     progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
     cancel: CancellationToken,
-) -> Vec<ValidationResult> {
+) -> Result<Vec<ValidationResult>, CoreError> {
+    // Build the client once so every worker and backend shares its pool.
+    let mut config = config;
+    if config.http_client.is_none() {
+        config.http_client = Some(config.http_client()?);
+    }
+
+    progress(ProgressEvent::Extracted {
+        references: refs.clone(),
+    });
     let total = refs.len();
     if total == 0 {
-        return vec![];
+        return Ok(vec![]);
     }
 
     let num_workers = config.num_workers.max(1);
+    if config.overrides.is_none() {
+        config.overrides = config.overrides();
+    }
     let config = Arc::new(config);
     let progress = Arc::new(progress);
 
+    // Resolve references with a DOI or arXiv ID in bulk where a backend
+    // supports it; the pool's per-reference queries then hit the cache
+    crate::orchestrator::prefetch_batched(&refs, &config, &config.http_client()?).await;
+
     // Create the pool
-    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers);
+    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers)?;
 
     // Submit all refs and collect oneshot receivers
     let mut receivers = Vec::with_capacity(total);
@@ -66,7 +87,148 @@ pub async fn check_references(
 
     pool.shutdown().await;
 
-    results.into_iter().flatten().collect()
+    retry_failed_references(&refs, &mut results, &config, progress.as_ref(), &cancel).await?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Whether `result` is worth retrying: a backend timed out or errored and
+/// no other backend verified the reference.
+fn needs_retry(result: &ValidationResult) -> bool {
+    result.status != Status::Verified && !result.failed_dbs.is_empty()
+}
+
+/// Re-check references whose result [`needs_retry`], for up to
+/// `config.retry_passes` passes. Each pass emits [`ProgressEvent::RetryPass`],
+/// then a [`ProgressEvent::Retrying`] and a [`ProgressEvent::Result`] per
+/// reference, and queries only the backends that failed last time.
+///
+/// `results` is indexed like `refs`; `None` slots (e.g. after cancellation)
+/// are left alone. Fails only if the HTTP client can't be built from `config`.
+pub async fn retry_failed_references(
+    refs: &[Reference],
+    results: &mut [Option<ValidationResult>],
+    config: &Config,
+    progress: &(dyn Fn(ProgressEvent) + Send + Sync),
+    cancel: &CancellationToken,
+) -> Result<(), CoreError> {
+    if config.retry_passes == 0 || !results.iter().flatten().any(needs_retry) {
+        return Ok(());
+    }
+    let client = config.http_client()?;
+    let config = Arc::new(config.clone());
+    let retry = |reference: Reference, failed_dbs: Vec<String>| {
+        let config = Arc::clone(&config);
+        let client = client.clone();
+        async move {
+            check_single_reference_retry(&reference, &config, &client, &failed_dbs, None).await
+        }
+    };
+    run_retry_passes(refs, results, &config, progress, cancel, retry).await;
+    Ok(())
+}
+
+/// The pass loop behind [`retry_failed_references`], with the re-check
+/// itself supplied by `retry` (given the reference and its failed backends).
+async fn run_retry_passes<F, Fut>(
+    refs: &[Reference],
+    results: &mut [Option<ValidationResult>],
+    config: &Config,
+    progress: &(dyn Fn(ProgressEvent) + Send + Sync),
+    cancel: &CancellationToken,
+    retry: F,
+) where
+    F: Fn(Reference, Vec<String>) -> Fut,
+    Fut: Future<Output = ValidationResult> + Send + 'static,
+{
+    let total = refs.len();
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(config.num_workers.max(1)));
+
+    for _ in 0..config.retry_passes {
+        let pending: Vec<usize> = results
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.as_ref().is_some_and(needs_retry))
+            .map(|(i, _)| i)
+            .collect();
+        if pending.is_empty() || cancel.is_cancelled() {
+            break;
+        }
+        progress(ProgressEvent::RetryPass {
+            count: pending.len(),
+        });
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for i in pending {
+            let Some(reference) = refs.get(i) else {
+                continue;
+            };
+            let failed_dbs = results[i]
+                .as_ref()
+                .map(|r| r.failed_dbs.clone())
+                .unwrap_or_default();
+            progress(ProgressEvent::Retrying {
+                index: i,
+                total,
+                title: reference.title.clone().unwrap_or_default(),
+                failed_dbs: failed_dbs.clone(),
+            });
+            let check = retry(reference.clone(), failed_dbs);
+            let semaphore = Arc::clone(&semaphore);
+            let cancel = cancel.clone();
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await.ok()?;
+                tokio::select! {
+                    _ = cancel.cancelled() => None,
+                    result = check => Some((i, result)),
+                }
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            let Ok(Some((i, retried))) = joined else {
+                continue;
+            };
+            let Some(previous) = results[i].take() else {
+                continue;
+            };
+            let mut merged = merge_retry(previous, retried);
+            merged.fill_insufficient_coverage(config);
+            progress(ProgressEvent::Result {
+                index: i,
+                total,
+                result: Box::new(merged.clone()),
+            });
+            results[i] = Some(merged);
+        }
+    }
+}
+
+/// Fold a retry (which only queried the failed backends) into the previous
+/// result. The retry's status is adopted when it verified the reference or
+/// the previous result found nothing; the DOI, arXiv and retraction details
+/// of the first pass are kept.
+fn merge_retry(mut previous: ValidationResult, retried: ValidationResult) -> ValidationResult {
+    previous
+        .db_results
+        .retain(|r| !retried.db_results.iter().any(|n| n.db_name == r.db_name));
+    previous.db_results.extend(retried.db_results);
+    previous.failed_dbs = retried.failed_dbs;
+
+    if retried.status == Status::Verified || previous.status == Status::NotFound {
+        previous.near_miss = match retried.status {
+            Status::NotFound => retried.near_miss.or(previous.near_miss),
+            _ => None,
+        };
+        previous.status = retried.status;
+        previous.source = retried.source;
+        previous.found_authors = retried.found_authors;
+        previous.paper_url = retried.paper_url;
+        previous.version_relation = retried.version_relation;
+        previous.fill_author_match_detail();
+    }
+    previous.fill_verified_by_count();
+    previous
 }
 
 /// Check a single reference against all databases.
@@ -77,77 +239,33 @@ pub async fn check_single_reference(
     longer_timeout: bool,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
 ) -> ValidationResult {
+    if let Some(result) = config
+        .overrides
+        .as_ref()
+        .and_then(|o| o.result_for(reference))
+    {
+        if let Some(cb) = on_db_complete {
+            result.db_results.iter().cloned().for_each(cb);
+        }
+        return result;
+    }
+
     let title = reference.title.as_deref().unwrap_or("");
     let timeout = Duration::from_secs(config.db_timeout_secs);
 
     // Step 1: Validate DOI if present (with cache support)
     let mut doi_info = None;
     if let Some(ref doi) = reference.doi {
-        // Check cache first
-        let cached = config
-            .query_cache
-            .as_ref()
-            .and_then(|cache| cache.get(title, "DOI"));
-
-        let (doi_result, match_result) = if let Some(ref cached_result) = cached {
-            if cached_result.is_found() {
-                // Cache hit with Found — reconstruct DOI validation and match
-                let doi_val = DoiValidation {
-                    valid: true,
-                    title: cached_result.found_title.clone(),
-                    authors: cached_result.authors.clone(),
-                    error: None,
-                };
-                let match_res = check_doi_match(&doi_val, title, &reference.authors);
-                (doi_val, match_res)
-            } else {
-                // Cache hit with NotFound — skip DOI validation entirely,
-                // fall through to DB search by jumping past DOI block
-                (
-                    DoiValidation {
-                        valid: false,
-                        title: None,
-                        authors: vec![],
-                        error: Some("Cached as not found".into()),
-                    },
-                    DoiMatchResult::Invalid {
-                        error: "Cached as not found".into(),
-                    },
-                )
-            }
-        } else {
-            // Cache miss — call doi.org
-            let doi_val = validate_doi(doi, client, timeout).await;
-            let match_res = check_doi_match(&doi_val, title, &reference.authors);
-
-            // Cache the result
-            if let Some(ref cache) = config.query_cache {
-                let cache_entry = match &match_res {
-                    DoiMatchResult::Verified {
-                        doi_title,
-                        doi_authors,
-                    }
-                    | DoiMatchResult::AuthorMismatch {
-                        doi_title,
-                        doi_authors,
-                    } => DbQueryResult::found(
-                        doi_title.clone(),
-                        doi_authors.clone(),
-                        Some(format!("https://doi.org/{}", doi)),
-                    ),
-                    _ => DbQueryResult::not_found(),
-                };
-                cache.insert(title, "DOI", &cache_entry);
-            }
-
-            (doi_val, match_res)
-        };
+        // The resolution is cached by DOI, keeping whether it was registered
+        // and which title it named
+        let doi_result = resolve_doi(doi, client, timeout, config.query_cache.as_deref()).await;
+        let match_result = check_doi_match(
+            &doi_result,
+            title,
+            config.authors_to_compare(&reference.authors),
+        );
 
-        doi_info = Some(DoiInfo {
-            doi: doi.clone(),
-            valid: doi_result.valid,
-            title: doi_result.title.clone(),
-        });
+        doi_info = Some(DoiInfo::from_validation(doi, &doi_result, title));
 
         match match_result {
             DoiMatchResult::Verified {
@@ -167,9 +285,11 @@ pub async fn check_single_reference(
                     None
                 };
 
-                return ValidationResult {
+                let mut result = ValidationResult {
                     title: title.to_string(),
                     raw_citation: reference.raw_citation.clone(),
+                    cite_key: reference.cite_key.clone(),
+                    cited_in_text: reference.cited_in_text,
                     ref_authors: reference.authors.clone(),
                     status: Status::Verified,
                     source: Some("DOI".into()),
@@ -187,15 +307,26 @@ pub async fn check_single_reference(
                     doi_info,
                     arxiv_info: None,
                     retraction_info,
+                    near_miss: None,
+                    author_match_detail: vec![],
+                    doi_conflict: None,
+                    version_relation: None,
+                    verified_by_count: 0,
+                    insufficient_coverage: false,
                 };
+                result.fill_author_match_detail();
+                result.fill_verified_by_count();
+                return result;
             }
             DoiMatchResult::AuthorMismatch {
                 doi_title: _,
                 doi_authors,
             } => {
-                return ValidationResult {
+                let mut result = ValidationResult {
                     title: title.to_string(),
                     raw_citation: reference.raw_citation.clone(),
+                    cite_key: reference.cite_key.clone(),
+                    cited_in_text: reference.cited_in_text,
                     ref_authors: reference.authors.clone(),
                     status: Status::AuthorMismatch,
                     source: Some("DOI".into()),
@@ -213,7 +344,16 @@ pub async fn check_single_reference(
                     doi_info,
                     arxiv_info: None,
                     retraction_info: None,
+                    near_miss: None,
+                    author_match_detail: vec![],
+                    doi_conflict: None,
+                    version_relation: None,
+                    verified_by_count: 0,
+                    insufficient_coverage: false,
                 };
+                result.fill_author_match_detail();
+                result.fill_verified_by_count();
+                return result;
             }
             _ => {
                 // DOI invalid or title mismatch — fall through to DB search
@@ -225,6 +365,7 @@ pub async fn check_single_reference(
     let mut db_result = query_all_databases(
         title,
         &reference.authors,
+        reference.kind,
         config,
         client,
         longer_timeout,
@@ -346,9 +487,21 @@ pub async fn check_single_reference(
         None
     };
 
-    ValidationResult {
+    // Step 4: Keep the closest offline candidate only for references that
+    // stayed NotFound after every fallback.
+    let near_miss = if db_result.status == Status::NotFound {
+        db_result.near_miss
+    } else {
+        None
+    };
+
+    let matched = (db_result.status == Status::Verified)
+        .then(|| (db_result.source.clone(), db_result.found_title.clone()));
+    let mut result = ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
+        cite_key: reference.cite_key.clone(),
+        cited_in_text: reference.cited_in_text,
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
@@ -357,9 +510,31 @@ pub async fn check_single_reference(
         failed_dbs: db_result.failed_dbs,
         db_results: db_result.db_results,
         doi_info,
-        arxiv_info: None, // TODO(#124): implement arXiv ID validation
+        arxiv_info: crate::pool::lookup_reference_arxiv_id(reference, config, client).await,
         retraction_info,
+        near_miss,
+        author_match_detail: vec![],
+        doi_conflict: None,
+        version_relation: None,
+        verified_by_count: 0,
+        insufficient_coverage: false,
+    };
+    if let Some((Some(source), found_title)) = matched {
+        crate::pool::cross_validate_doi(
+            reference,
+            &mut result,
+            &source,
+            found_title.as_deref(),
+            config,
+            client,
+        )
+        .await;
     }
+    crate::pool::accept_preprint_version(reference, &mut result, config);
+    result.fill_author_match_detail();
+    result.fill_verified_by_count();
+    result.fill_insufficient_coverage(config);
+    result
 }
 
 /// Retry a reference check targeting only the previously failed databases.
@@ -375,6 +550,7 @@ pub async fn check_single_reference_retry(
     let db_result = query_all_databases(
         title,
         &reference.authors,
+        reference.kind,
         config,
         client,
         true, // longer timeout for retries
@@ -383,9 +559,17 @@ pub async fn check_single_reference_retry(
     )
     .await;
 
-    ValidationResult {
+    let near_miss = if db_result.status == Status::NotFound {
+        db_result.near_miss
+    } else {
+        None
+    };
+
+    let mut result = ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
+        cite_key: reference.cite_key.clone(),
+        cited_in_text: reference.cited_in_text,
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
@@ -396,5 +580,272 @@ pub async fn check_single_reference_retry(
         doi_info: None,
         arxiv_info: None,
         retraction_info: None,
+        near_miss,
+        author_match_detail: vec![],
+        doi_conflict: None,
+        version_relation: None,
+        verified_by_count: 0,
+        insufficient_coverage: false,
+    };
+    crate::pool::accept_preprint_version(reference, &mut result, config);
+    result.fill_author_match_detail();
+    result.fill_verified_by_count();
+    result.fill_insufficient_coverage(config);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RefKind;
+    use crate::db::mock::{MockDb, MockResponse};
+    use std::sync::Mutex;
+
+    const TITLE: &str = "Attention Is All You Need";
+
+    fn reference() -> Reference {
+        Reference {
+            raw_citation: TITLE.into(),
+            title: Some(TITLE.into()),
+            authors: vec!["Ashish Vaswani".into()],
+            doi: None,
+            arxiv_id: None,
+            original_number: 1,
+            skip_reason: None,
+            kind: RefKind::Unknown,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    /// Query `db` once and turn its answer into a result.
+    async fn check_with(db: &MockDb) -> ValidationResult {
+        let response = db
+            .query(TITLE, &reqwest::Client::new(), Duration::from_secs(1))
+            .await;
+        let (status, found_authors, failed_dbs, db_status) = match response {
+            Ok(found) if found.is_found() => {
+                (Status::Verified, found.authors, vec![], DbStatus::Match)
+            }
+            Ok(_) => (Status::NotFound, vec![], vec![], DbStatus::NoMatch),
+            Err(_) => (
+                Status::NotFound,
+                vec![],
+                vec!["Mock".into()],
+                DbStatus::Error,
+            ),
+        };
+        let mut result = ValidationResult {
+            title: TITLE.into(),
+            raw_citation: TITLE.into(),
+            ref_authors: vec!["Ashish Vaswani".into()],
+            status: status.clone(),
+            source: (status == Status::Verified).then(|| "Mock".into()),
+            found_authors,
+            paper_url: None,
+            failed_dbs,
+            db_results: vec![DbResult {
+                db_name: "Mock".into(),
+                status: db_status,
+                elapsed: None,
+                found_authors: vec![],
+                paper_url: None,
+                error_message: None,
+            }],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            near_miss: None,
+            author_match_detail: vec![],
+            cite_key: None,
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 0,
+            insufficient_coverage: false,
+            cited_in_text: None,
+        };
+        result.fill_verified_by_count();
+        result
+    }
+
+    /// Run the main pass and `passes` retry passes against a backend that
+    /// errors twice, then finds the paper.
+    async fn run(passes: u32) -> (ValidationResult, usize, Vec<usize>) {
+        let db = Arc::new(MockDb::with_sequence(
+            "Mock",
+            vec![
+                MockResponse::Error("timed out".into()),
+                MockResponse::Error("timed out".into()),
+                MockResponse::Found {
+                    title: TITLE.into(),
+                    authors: vec!["Ashish Vaswani".into()],
+                    url: None,
+                },
+            ],
+        ));
+        let refs = vec![reference()];
+        let mut results = vec![Some(check_with(&db).await)];
+
+        let retry_passes = Mutex::new(Vec::new());
+        let progress = |event: ProgressEvent| {
+            if let ProgressEvent::RetryPass { count } = event {
+                retry_passes.lock().unwrap().push(count);
+            }
+        };
+        let retry = |_: Reference, failed_dbs: Vec<String>| {
+            assert_eq!(failed_dbs, ["Mock"]);
+            let db = Arc::clone(&db);
+            async move { check_with(&db).await }
+        };
+        let config = Config {
+            retry_passes: passes,
+            num_workers: 1,
+            ..Config::default()
+        };
+        run_retry_passes(
+            &refs,
+            &mut results,
+            &config,
+            &progress,
+            &CancellationToken::new(),
+            retry,
+        )
+        .await;
+
+        let passes_run = retry_passes.into_inner().unwrap();
+        (results.remove(0).unwrap(), db.call_count(), passes_run)
+    }
+
+    #[tokio::test]
+    async fn transient_failure_is_retried_until_it_succeeds() {
+        let (result, calls, passes) = run(3).await;
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.source.as_deref(), Some("Mock"));
+        assert!(result.failed_dbs.is_empty());
+        assert_eq!(result.verified_by_count, 1);
+        // The third pass has nothing left to retry.
+        assert_eq!(calls, 3);
+        assert_eq!(passes, [1, 1]);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_configured_passes() {
+        let (result, calls, passes) = run(1).await;
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(result.failed_dbs, ["Mock"]);
+        // The only backend never answered.
+        assert!(result.insufficient_coverage);
+        assert_eq!(calls, 2);
+        assert_eq!(passes, [1]);
+
+        let (result, calls, passes) = run(0).await;
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(calls, 1);
+        assert!(passes.is_empty());
+    }
+
+    /// Open an offline DBLP database at `path` holding only [`TITLE`].
+    fn dblp_fixture(path: &std::path::Path) -> hallucinator_dblp::DblpDatabase {
+        use hallucinator_dblp::db::{
+            SCHEMA_VERSION, init_database, insert_or_get_author, insert_or_get_publication,
+            rebuild_fts_index, set_metadata,
+        };
+
+        let conn = rusqlite::Connection::open(path).unwrap();
+        init_database(&conn).unwrap();
+        let author_id = insert_or_get_author(&conn, "Ashish Vaswani").unwrap();
+        let pub_id =
+            insert_or_get_publication(&conn, "conf/nips/VaswaniSPUJGKP17", TITLE, None).unwrap();
+        conn.execute(
+            "INSERT INTO publication_authors (pub_id, author_id) VALUES (?1, ?2)",
+            (pub_id, author_id),
+        )
+        .unwrap();
+        rebuild_fts_index(&conn).unwrap();
+        set_metadata(&conn, "schema_version", SCHEMA_VERSION).unwrap();
+        drop(conn);
+        hallucinator_dblp::DblpDatabase::open(path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn pool_failure_is_retried_against_the_failed_backend() {
+        // In the main pass, the only backend is a DBLP that times out.
+        let flaky = Arc::new(MockDb::new("DBLP", MockResponse::Error("timed out".into())));
+        let config = Config {
+            query_cache: None,
+            retry_passes: 1,
+            ..Config::default()
+        };
+        let pool = ValidationPool::with_backends(
+            Arc::new(config.clone()),
+            CancellationToken::new(),
+            1,
+            vec![flaky.clone()],
+        )
+        .unwrap();
+        let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+        pool.submit(RefJob {
+            reference: reference(),
+            result_tx,
+            ref_index: 0,
+            total: 1,
+            progress: Arc::new(|_| {}),
+        })
+        .await;
+        let mut results = vec![Some(result_rx.await.unwrap())];
+        pool.shutdown().await;
+        assert_eq!(results[0].as_ref().unwrap().failed_dbs, ["DBLP"]);
+
+        // The retry queries only DBLP, which is now served offline.
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            dblp_offline_db: Some(Arc::new(Mutex::new(dblp_fixture(
+                &dir.path().join("dblp.db"),
+            )))),
+            ..config
+        };
+        let events = Mutex::new(Vec::new());
+        let progress = |event: ProgressEvent| events.lock().unwrap().push(event);
+        retry_failed_references(
+            &[reference()],
+            &mut results,
+            &config,
+            &progress,
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+        let result = results.remove(0).unwrap();
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.source.as_deref(), Some("DBLP"));
+        assert!(result.failed_dbs.is_empty());
+        assert_eq!(flaky.call_count(), 1);
+        let events = events.into_inner().unwrap();
+        assert!(matches!(
+            events.as_slice(),
+            [
+                ProgressEvent::RetryPass { count: 1 },
+                ProgressEvent::Retrying { index: 0, .. },
+                ProgressEvent::Result { index: 0, .. },
+            ]
+        ));
+    }
+
+    #[tokio::test]
+    async fn merge_keeps_first_pass_match_when_retry_finds_nothing() {
+        let failing = MockDb::new("Mock", MockResponse::Error("timed out".into()));
+        let mut previous = check_with(&failing).await;
+        previous.status = Status::AuthorMismatch;
+        previous.source = Some("DBLP".into());
+        let retried = check_with(&MockDb::new("Mock", MockResponse::NotFound)).await;
+
+        let merged = merge_retry(previous, retried);
+        assert_eq!(merged.status, Status::AuthorMismatch);
+        assert_eq!(merged.source.as_deref(), Some("DBLP"));
+        assert!(merged.failed_dbs.is_empty());
+        assert_eq!(merged.db_results.len(), 1);
+        assert_eq!(merged.db_results[0].status, DbStatus::NoMatch);
     }
 }
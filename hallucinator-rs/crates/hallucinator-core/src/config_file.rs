@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -9,6 +10,7 @@ pub struct ConfigFile {
     pub api_keys: Option<ApiKeysConfig>,
     pub databases: Option<DatabasesConfig>,
     pub concurrency: Option<ConcurrencyConfig>,
+    pub network: Option<NetworkConfig>,
     pub display: Option<DisplayConfig>,
 }
 
@@ -25,8 +27,22 @@ pub struct DatabasesConfig {
     pub acl_offline_path: Option<String>,
     pub openalex_offline_path: Option<String>,
     pub cache_path: Option<String>,
+    /// Most query results kept in the cache; unbounded when unset.
+    pub cache_max_entries: Option<usize>,
     pub searxng_url: Option<String>,
     pub disabled: Option<Vec<String>>,
+    pub min_databases_queried: Option<usize>,
+    /// Similarity threshold (0.0-1.0) for all offline DB title matches.
+    pub match_threshold: Option<f64>,
+    /// Per-database thresholds keyed by DB name, e.g. `DBLP = 0.92` under
+    /// `[databases.thresholds]`. These win over `match_threshold`.
+    pub thresholds: Option<HashMap<String, f64>>,
+    /// Scan each document's body for in-text citations and flag references
+    /// that are listed but never cited.
+    pub check_citations: Option<bool>,
+    /// Only check that each cited paper exists, without comparing authors
+    /// ([`VerificationMode::ExistenceOnly`](crate::VerificationMode::ExistenceOnly)).
+    pub existence_only: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -35,13 +51,25 @@ pub struct ConcurrencyConfig {
     pub db_timeout_secs: Option<u64>,
     pub db_timeout_short_secs: Option<u64>,
     pub max_rate_limit_retries: Option<u32>,
+    pub retry_passes: Option<u32>,
     pub max_archive_size_mb: Option<u32>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Proxy for every database query and offline database download, e.g.
+    /// `http://host:port`.
+    pub http_proxy: Option<String>,
+    /// PEM file of extra CA certificates to trust.
+    pub extra_ca_cert: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DisplayConfig {
     pub theme: Option<String>,
     pub fps: Option<u32>,
+    /// Skip the animated startup banner.
+    pub no_banner: Option<bool>,
 }
 
 /// Platform config directory path: `<config_dir>/hallucinator/config.toml`.
@@ -127,6 +155,11 @@ pub fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|d| d.cache_path.clone())
                 .or_else(|| base.databases.as_ref().and_then(|d| d.cache_path.clone())),
+            cache_max_entries: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.cache_max_entries)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.cache_max_entries)),
             searxng_url: overlay
                 .databases
                 .as_ref()
@@ -137,6 +170,35 @@ pub fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|d| d.disabled.clone())
                 .or_else(|| base.databases.as_ref().and_then(|d| d.disabled.clone())),
+            min_databases_queried: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.min_databases_queried)
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.min_databases_queried)
+                }),
+            match_threshold: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.match_threshold)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.match_threshold)),
+            thresholds: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.thresholds.clone())
+                .or_else(|| base.databases.as_ref().and_then(|d| d.thresholds.clone())),
+            check_citations: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.check_citations)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.check_citations)),
+            existence_only: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.existence_only)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.existence_only)),
         }),
         concurrency: Some(ConcurrencyConfig {
             num_workers: overlay
@@ -167,6 +229,11 @@ pub fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .as_ref()
                         .and_then(|c| c.max_rate_limit_retries)
                 }),
+            retry_passes: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.retry_passes)
+                .or_else(|| base.concurrency.as_ref().and_then(|c| c.retry_passes)),
             max_archive_size_mb: overlay
                 .concurrency
                 .as_ref()
@@ -177,6 +244,18 @@ pub fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .and_then(|c| c.max_archive_size_mb)
                 }),
         }),
+        network: Some(NetworkConfig {
+            http_proxy: overlay
+                .network
+                .as_ref()
+                .and_then(|n| n.http_proxy.clone())
+                .or_else(|| base.network.as_ref().and_then(|n| n.http_proxy.clone())),
+            extra_ca_cert: overlay
+                .network
+                .as_ref()
+                .and_then(|n| n.extra_ca_cert.clone())
+                .or_else(|| base.network.as_ref().and_then(|n| n.extra_ca_cert.clone())),
+        }),
         display: Some(DisplayConfig {
             theme: overlay
                 .display
@@ -188,6 +267,11 @@ pub fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|d| d.fps)
                 .or_else(|| base.display.as_ref().and_then(|d| d.fps)),
+            no_banner: overlay
+                .display
+                .as_ref()
+                .and_then(|d| d.no_banner)
+                .or_else(|| base.display.as_ref().and_then(|d| d.no_banner)),
         }),
     }
 }
@@ -233,6 +317,38 @@ mod tests {
         assert!(parsed.databases.unwrap().cache_path.is_none());
     }
 
+    #[test]
+    fn thresholds_deserialize() {
+        let toml_str =
+            "[databases]\nmatch_threshold = 0.85\n\n[databases.thresholds]\nDBLP = 0.92\n";
+        let databases = toml::from_str::<ConfigFile>(toml_str)
+            .unwrap()
+            .databases
+            .unwrap();
+        assert_eq!(databases.match_threshold, Some(0.85));
+        assert_eq!(databases.thresholds.unwrap()["DBLP"], 0.92);
+    }
+
+    #[test]
+    fn cache_max_entries_deserializes_and_merges() {
+        let base: ConfigFile = toml::from_str("[databases]\ncache_max_entries = 5000\n").unwrap();
+        let merged = merge(base, ConfigFile::default());
+        assert_eq!(merged.databases.unwrap().cache_max_entries, Some(5000));
+    }
+
+    #[test]
+    fn network_deserializes_and_merges() {
+        let base: ConfigFile = toml::from_str(
+            "[network]\nhttp_proxy = \"http://proxy:3128\"\nextra_ca_cert = \"/etc/ca.pem\"\n",
+        )
+        .unwrap();
+        let overlay: ConfigFile =
+            toml::from_str("[network]\nhttp_proxy = \"http://other:8080\"\n").unwrap();
+        let network = merge(base, overlay).network.unwrap();
+        assert_eq!(network.http_proxy.as_deref(), Some("http://other:8080"));
+        assert_eq!(network.extra_ca_cert.as_deref(), Some("/etc/ca.pem"));
+    }
+
     #[test]
     fn merge_cache_path_overlay_wins() {
         let base = ConfigFile {
@@ -1,4 +1,8 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use super::{
+    BatchLookup, BatchQueryResult, DatabaseBackend, DbQueryError, DbQueryResult,
+    query_offline_titles,
+};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
 use std::future::Future;
@@ -11,6 +15,43 @@ pub struct AclAnthology;
 /// Offline ACL Anthology backend backed by a local SQLite database with FTS5.
 pub struct AclOffline {
     pub db: Arc<Mutex<hallucinator_acl::AclDatabase>>,
+    /// How far below the match threshold a candidate may score and still be
+    /// reported as a near miss.
+    pub near_miss_margin: f64,
+    /// Similarity threshold a candidate must reach to count as a match.
+    pub threshold: f64,
+    /// How candidate titles are scored against the cited one.
+    pub scorer: hallucinator_text::TitleScorer,
+    /// Skips or tightens short and generic titles.
+    pub guard: hallucinator_text::QueryGuard,
+}
+
+impl AclOffline {
+    /// Query `titles` with this backend's threshold, scorer and guard.
+    async fn query_titles(&self, titles: Vec<String>) -> Vec<Result<DbQueryResult, DbQueryError>> {
+        let scorer = self.scorer;
+        let guard = self.guard;
+        query_offline_titles(
+            &self.db,
+            titles,
+            self.threshold,
+            self.near_miss_margin,
+            move |db, title, threshold| {
+                db.query_guarded(title, threshold, scorer, &guard)
+                    .map(|result| {
+                        result.map(|qr| {
+                            let found = DbQueryResult::found(
+                                qr.record.title,
+                                qr.record.authors,
+                                qr.record.url,
+                            );
+                            (qr.score, found)
+                        })
+                    })
+            },
+        )
+        .await
+    }
 }
 
 impl DatabaseBackend for AclOffline {
@@ -28,28 +69,27 @@ impl DatabaseBackend for AclOffline {
         _client: &'a reqwest::Client,
         _timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
-        let db = Arc::clone(&self.db);
-        let title = title.to_string();
         Box::pin(async move {
-            let result = tokio::task::spawn_blocking(move || {
-                let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
-                db.query(&title)
-                    .map_err(|e| DbQueryError::Other(e.to_string()))
-            })
-            .await
-            .map_err(|e| DbQueryError::Other(e.to_string()))??;
-
-            match result {
-                Some(qr) if !qr.record.authors.is_empty() => Ok(DbQueryResult::found(
-                    qr.record.title,
-                    qr.record.authors,
-                    qr.record.url,
-                )),
-                // Skip results with empty authors - let other DBs verify
-                _ => Ok(DbQueryResult::not_found()),
-            }
+            let mut results = self.query_titles(vec![title.to_string()]).await;
+            results
+                .pop()
+                .unwrap_or_else(|| Ok(DbQueryResult::not_found()))
         })
     }
+
+    fn supports_batch(&self) -> bool {
+        true
+    }
+
+    fn query_batch<'a>(
+        &'a self,
+        lookups: &'a [BatchLookup<'a>],
+        _client: &'a reqwest::Client,
+        _timeout: Duration,
+    ) -> BatchQueryResult<'a> {
+        let titles = lookups.iter().map(|l| l.title.to_string()).collect();
+        Box::pin(async move { self.query_titles(titles).await })
+    }
 }
 
 impl DatabaseBackend for AclAnthology {
@@ -72,7 +112,7 @@ impl DatabaseBackend for AclAnthology {
             let resp = client
                 .get(&url)
                 .timeout(timeout)
-                .send()
+                .send_logged("ACL Anthology")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -1,6 +1,10 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::ArxivInfo;
+use crate::cache::QueryCache;
+use crate::http::SendLogged;
 use crate::matching::titles_match;
-use crate::text_utils::get_query_words;
+use crate::rate_limit::check_rate_limit_response;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -19,8 +23,7 @@ impl DatabaseBackend for Arxiv {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
             let url = format!(
                 "http://export.arxiv.org/api/query?search_query=all:{}&start=0&max_results=5",
                 urlencoding::encode(&query)
@@ -29,7 +32,7 @@ impl DatabaseBackend for Arxiv {
             let resp = client
                 .get(&url)
                 .timeout(timeout)
-                .send()
+                .send_logged("arXiv")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -101,12 +104,10 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
                     }
                 }
             }
-            Ok(Event::Empty(ref e)) => {
-                if e.local_name().as_ref() == b"link" && in_entry {
-                    for attr in e.attributes().flatten() {
-                        if attr.key.as_ref() == b"href" && current_link.is_empty() {
-                            current_link = String::from_utf8_lossy(&attr.value).to_string();
-                        }
+            Ok(Event::Empty(ref e)) if e.local_name().as_ref() == b"link" && in_entry => {
+                for attr in e.attributes().flatten() {
+                    if attr.key.as_ref() == b"href" && current_link.is_empty() {
+                        current_link = String::from_utf8_lossy(&attr.value).to_string();
                     }
                 }
             }
@@ -162,3 +163,212 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
 
     Ok(DbQueryResult::not_found())
 }
+
+/// Query-cache database name under which arXiv ID lookups are stored, keyed
+/// by the ID.
+const ID_LOOKUP_CACHE: &str = "arXiv ID";
+
+/// A cached lookup keeps the published DOI as a doi.org URL in `paper_url`.
+const DOI_URL_PREFIX: &str = "https://doi.org/";
+
+/// Look up an arXiv ID directly and describe it as an [`ArxivInfo`].
+///
+/// The ID is valid if arXiv returns an entry for it; `published_doi` carries
+/// the DOI of the published version when the authors registered one. Errors
+/// are transient failures (network, HTTP, 429, malformed XML), where validity
+/// is unknown.
+pub(crate) async fn lookup_arxiv_id(
+    arxiv_id: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+) -> Result<ArxivInfo, DbQueryError> {
+    let url = format!(
+        "http://export.arxiv.org/api/query?id_list={}",
+        urlencoding::encode(arxiv_id)
+    );
+    let resp = client
+        .get(&url)
+        .timeout(timeout)
+        .send_logged("arXiv")
+        .await
+        .map_err(|e| DbQueryError::Other(e.to_string()))?;
+    check_rate_limit_response(&resp)?;
+    if !resp.status().is_success() {
+        return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+    }
+    let body = resp
+        .text()
+        .await
+        .map_err(|e| DbQueryError::Other(e.to_string()))?;
+    parse_arxiv_id_response(&body, arxiv_id)
+        .ok_or_else(|| DbQueryError::Other("malformed arXiv response".into()))
+}
+
+/// A previous lookup of `arxiv_id` from the query cache.
+pub(crate) fn cached_id_lookup(cache: &QueryCache, arxiv_id: &str) -> Option<ArxivInfo> {
+    let cached = cache.get(arxiv_id, ID_LOOKUP_CACHE)?;
+    Some(ArxivInfo {
+        arxiv_id: arxiv_id.to_string(),
+        valid: cached.is_found(),
+        title: cached.found_title.filter(|t| !t.is_empty()),
+        published_doi: cached
+            .paper_url
+            .and_then(|url| url.strip_prefix(DOI_URL_PREFIX).map(String::from)),
+    })
+}
+
+/// Store a definitive lookup in the query cache. An invalid ID is cached as
+/// not-found, under the shorter negative TTL.
+pub(crate) fn cache_id_lookup(cache: &QueryCache, info: &ArxivInfo) {
+    let result = if info.valid {
+        DbQueryResult::found(
+            info.title.clone().unwrap_or_default(),
+            vec![],
+            info.published_doi
+                .as_ref()
+                .map(|doi| format!("{DOI_URL_PREFIX}{doi}")),
+        )
+    } else {
+        DbQueryResult::not_found()
+    };
+    cache.insert(&info.arxiv_id, ID_LOOKUP_CACHE, &result);
+}
+
+/// Parse an arXiv `id_list` response into an [`ArxivInfo`].
+///
+/// arXiv reports unknown or malformed IDs either as an empty feed or as an
+/// entry whose `<id>` points at `arxiv.org/api/errors`; both yield an invalid
+/// result. The published DOI comes from `<arxiv:doi>`.
+fn parse_arxiv_id_response(xml: &str, arxiv_id: &str) -> Option<ArxivInfo> {
+    use quick_xml::Reader;
+    use quick_xml::events::Event;
+
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+
+    let mut in_entry = false;
+    let mut field: Option<&'static str> = None;
+    let mut entry_id = String::new();
+    let mut title = String::new();
+    let mut doi = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => match (e.name().as_ref(), in_entry) {
+                (b"entry", _) => in_entry = true,
+                (b"id", true) => field = Some("id"),
+                (b"title", true) => field = Some("title"),
+                (b"arxiv:doi", true) => field = Some("doi"),
+                _ => {}
+            },
+            Ok(Event::Text(ref e)) => {
+                let text = e.unescape().unwrap_or_default();
+                match field {
+                    Some("id") => entry_id.push_str(&text),
+                    Some("title") => title.push_str(&text),
+                    Some("doi") => doi.push_str(&text),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(ref e)) => match e.name().as_ref() {
+                // Only the first entry is relevant for a single-ID query
+                b"entry" => break,
+                b"id" | b"title" | b"arxiv:doi" => field = None,
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(_) => return None,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let valid = in_entry && !entry_id.contains("arxiv.org/api/errors");
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    let doi = doi.trim();
+    Some(ArxivInfo {
+        arxiv_id: arxiv_id.to_string(),
+        valid,
+        title: (valid && !title.is_empty()).then_some(title),
+        published_doi: (valid && !doi.is_empty()).then(|| doi.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_id_response_with_published_doi() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:arxiv="http://arxiv.org/schemas/atom">
+  <title type="html">ArXiv Query: id_list=1512.03385</title>
+  <entry>
+    <id>http://arxiv.org/abs/1512.03385v1</id>
+    <title>Deep Residual Learning for Image
+      Recognition</title>
+    <author><name>Kaiming He</name></author>
+    <arxiv:doi>10.1109/CVPR.2016.90</arxiv:doi>
+    <arxiv:journal_ref>CVPR 2016</arxiv:journal_ref>
+  </entry>
+</feed>"#;
+        let info = parse_arxiv_id_response(xml, "1512.03385").unwrap();
+        assert!(info.valid);
+        assert_eq!(
+            info.title.as_deref(),
+            Some("Deep Residual Learning for Image Recognition")
+        );
+        assert_eq!(info.published_doi.as_deref(), Some("10.1109/CVPR.2016.90"));
+    }
+
+    #[test]
+    fn test_id_lookup_cache_round_trip() {
+        let cache = QueryCache::new(Duration::from_secs(60), Duration::from_secs(60));
+        let published = ArxivInfo {
+            arxiv_id: "1512.03385".into(),
+            valid: true,
+            title: Some("Deep Residual Learning for Image Recognition".into()),
+            published_doi: Some("10.1109/CVPR.2016.90".into()),
+        };
+        let invalid = ArxivInfo {
+            arxiv_id: "9999.99999".into(),
+            valid: false,
+            title: None,
+            published_doi: None,
+        };
+        assert!(cached_id_lookup(&cache, "1512.03385").is_none());
+        cache_id_lookup(&cache, &published);
+        cache_id_lookup(&cache, &invalid);
+        assert_eq!(cached_id_lookup(&cache, "1512.03385"), Some(published));
+        assert_eq!(cached_id_lookup(&cache, "9999.99999"), Some(invalid));
+    }
+
+    #[test]
+    fn test_parse_id_response_without_doi() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>http://arxiv.org/abs/2301.00001v2</id>
+    <title>Some Preprint</title>
+  </entry>
+</feed>"#;
+        let info = parse_arxiv_id_response(xml, "2301.00001").unwrap();
+        assert!(info.valid);
+        assert!(info.published_doi.is_none());
+    }
+
+    #[test]
+    fn test_parse_id_response_error_entry_is_invalid() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>http://arxiv.org/api/errors#incorrect_id_format_for_9999.99999</id>
+    <title>Error</title>
+  </entry>
+</feed>"#;
+        let info = parse_arxiv_id_response(xml, "9999.99999").unwrap();
+        assert!(!info.valid);
+        assert!(info.title.is_none());
+
+        let empty = r#"<feed xmlns="http://www.w3.org/2005/Atom"></feed>"#;
+        assert!(!parse_arxiv_id_response(empty, "2301.99999").unwrap().valid);
+    }
+}
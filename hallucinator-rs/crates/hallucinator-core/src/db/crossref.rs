@@ -1,8 +1,9 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
 use crate::retraction::extract_retraction_from_item;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -23,25 +24,20 @@ impl DatabaseBackend for CrossRef {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
             let mut url = format!(
                 "https://api.crossref.org/works?query.title={}&rows=5",
                 urlencoding::encode(&query)
             );
 
-            let user_agent = if let Some(ref email) = self.mailto {
+            if let Some(ref email) = self.mailto {
                 url.push_str(&format!("&mailto={}", urlencoding::encode(email)));
-                format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email)
-            } else {
-                "Academic Reference Parser".to_string()
-            };
+            }
 
             let resp = client
                 .get(&url)
-                .header("User-Agent", user_agent)
                 .timeout(timeout)
-                .send()
+                .send_logged("CrossRef")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -98,6 +94,9 @@ impl DatabaseBackend for CrossRef {
                         authors,
                         paper_url,
                         retraction: Some(retraction),
+                        near_miss: None,
+                        doi_mismatch_title: None,
+                        doi_unregistered: false,
                     });
                 }
             }
@@ -1,7 +1,11 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use super::{
+    BatchLookup, BatchQueryResult, DatabaseBackend, DbQueryError, DbQueryResult,
+    query_offline_titles,
+};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
@@ -30,6 +34,47 @@ pub struct DblpOnline;
 /// Offline DBLP backend backed by a local SQLite database with FTS5.
 pub struct DblpOffline {
     pub db: Arc<Mutex<hallucinator_dblp::DblpDatabase>>,
+    /// How far below the match threshold a candidate may score and still be
+    /// reported as a near miss.
+    pub near_miss_margin: f64,
+    /// Similarity threshold a candidate must reach to count as a match.
+    pub threshold: f64,
+    /// How candidate titles are scored against the cited one.
+    pub scorer: hallucinator_text::TitleScorer,
+    /// Skips or tightens short and generic titles.
+    pub guard: hallucinator_text::QueryGuard,
+}
+
+impl DblpOffline {
+    /// Query `titles` with this backend's threshold, scorer and guard.
+    async fn query_titles(&self, titles: Vec<String>) -> Vec<Result<DbQueryResult, DbQueryError>> {
+        let scorer = self.scorer;
+        let guard = self.guard;
+        query_offline_titles(
+            &self.db,
+            titles,
+            self.threshold,
+            self.near_miss_margin,
+            move |db, title, threshold| {
+                db.query_guarded(title, threshold, scorer, &guard)
+                    .map(|result| {
+                        result.map(|qr| {
+                            let found = DbQueryResult::found(
+                                qr.record.title,
+                                qr.record
+                                    .authors
+                                    .into_iter()
+                                    .map(|a| strip_dblp_suffix(&a))
+                                    .collect(),
+                                qr.record.url,
+                            );
+                            (qr.score, found)
+                        })
+                    })
+            },
+        )
+        .await
+    }
 }
 
 impl DatabaseBackend for DblpOffline {
@@ -47,32 +92,27 @@ impl DatabaseBackend for DblpOffline {
         _client: &'a reqwest::Client,
         _timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
-        let db = Arc::clone(&self.db);
-        let title = title.to_string();
         Box::pin(async move {
-            let result = tokio::task::spawn_blocking(move || {
-                let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
-                db.query(&title)
-                    .map_err(|e| DbQueryError::Other(e.to_string()))
-            })
-            .await
-            .map_err(|e| DbQueryError::Other(e.to_string()))??;
-
-            match result {
-                Some(qr) if !qr.record.authors.is_empty() => Ok(DbQueryResult::found(
-                    qr.record.title,
-                    qr.record
-                        .authors
-                        .into_iter()
-                        .map(|a| strip_dblp_suffix(&a))
-                        .collect(),
-                    qr.record.url,
-                )),
-                // Skip results with empty authors - let other DBs verify
-                _ => Ok(DbQueryResult::not_found()),
-            }
+            let mut results = self.query_titles(vec![title.to_string()]).await;
+            results
+                .pop()
+                .unwrap_or_else(|| Ok(DbQueryResult::not_found()))
         })
     }
+
+    fn supports_batch(&self) -> bool {
+        true
+    }
+
+    fn query_batch<'a>(
+        &'a self,
+        lookups: &'a [BatchLookup<'a>],
+        _client: &'a reqwest::Client,
+        _timeout: Duration,
+    ) -> BatchQueryResult<'a> {
+        let titles = lookups.iter().map(|l| l.title.to_string()).collect();
+        Box::pin(async move { self.query_titles(titles).await })
+    }
 }
 
 impl DatabaseBackend for DblpOnline {
@@ -87,8 +127,7 @@ impl DatabaseBackend for DblpOnline {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
             let url = format!(
                 "https://dblp.org/search/publ/api?q={}&format=json",
                 urlencoding::encode(&query)
@@ -97,7 +136,7 @@ impl DatabaseBackend for DblpOnline {
             let resp = client
                 .get(&url)
                 .timeout(timeout)
-                .send()
+                .send_logged("DBLP")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
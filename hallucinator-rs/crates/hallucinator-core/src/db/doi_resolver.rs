@@ -2,15 +2,22 @@
 
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::doi::{DoiMatchResult, check_doi_match, validate_doi};
+use crate::cache::QueryCache;
+use crate::doi::{DoiMatchResult, check_doi_match, doi_title_mismatch, resolve_doi};
 use crate::rate_limit::DbQueryError;
 
 use super::{DbQueryResult, DoiQueryResult};
 
 /// A database backend that resolves DOIs via doi.org metadata.
-pub struct DoiResolver;
+///
+/// Resolutions are looked up in (and written to) `cache` by normalized DOI
+/// before going to the network.
+pub struct DoiResolver {
+    pub cache: Option<Arc<QueryCache>>,
+}
 
 impl super::DatabaseBackend for DoiResolver {
     fn name(&self) -> &str {
@@ -21,6 +28,10 @@ impl super::DatabaseBackend for DoiResolver {
         true
     }
 
+    fn sends_id_lookups(&self) -> bool {
+        true
+    }
+
     /// Title-based search is not supported — always returns not-found.
     fn query<'a>(
         &'a self,
@@ -40,7 +51,7 @@ impl super::DatabaseBackend for DoiResolver {
         timeout: Duration,
     ) -> DoiQueryResult<'a> {
         Box::pin(async move {
-            let doi_result = validate_doi(doi, client, timeout).await;
+            let doi_result = resolve_doi(doi, client, timeout, self.cache.as_deref()).await;
             let match_result = check_doi_match(&doi_result, title, authors);
 
             match match_result {
@@ -62,10 +73,18 @@ impl super::DatabaseBackend for DoiResolver {
                         Some(url),
                     )))
                 }
-                DoiMatchResult::TitleMismatch { .. } | DoiMatchResult::Invalid { .. } => {
-                    // DOI didn't match or was invalid — return not-found
-                    Some(Ok(DbQueryResult::not_found()))
+                DoiMatchResult::TitleMismatch { .. } => {
+                    // DOI resolves but not to this title — not-found, flagging
+                    // the resolved title if it is a different paper entirely
+                    Some(Ok(DbQueryResult {
+                        doi_mismatch_title: doi_title_mismatch(&doi_result, title),
+                        ..DbQueryResult::not_found()
+                    }))
                 }
+                DoiMatchResult::Invalid { .. } => Some(Ok(DbQueryResult {
+                    doi_unregistered: doi_result.is_unregistered(doi),
+                    ..DbQueryResult::not_found()
+                })),
             }
         })
     }
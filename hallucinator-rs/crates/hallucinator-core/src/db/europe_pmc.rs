@@ -1,4 +1,5 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
 use once_cell::sync::Lazy;
@@ -37,9 +38,8 @@ impl DatabaseBackend for EuropePmc {
                     ("format", "json"),
                     ("pageSize", "15"),
                 ])
-                .header("User-Agent", "Academic Reference Parser")
                 .timeout(timeout)
-                .send()
+                .send_logged("Europe PMC")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -6,12 +6,11 @@ use std::sync::Mutex;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
-use super::{DatabaseBackend, DbQueryResult};
+use super::{BatchLookup, BatchQueryResult, DatabaseBackend, DbQueryResult, DoiQueryResult};
 use crate::rate_limit::DbQueryError;
 
 /// A configurable mock response for [`MockDb`].
 #[derive(Clone, Debug)]
-#[allow(dead_code)]
 pub enum MockResponse {
     /// Simulate a successful match.
     Found {
@@ -32,8 +31,12 @@ pub enum MockResponse {
 /// Supports:
 /// - A fixed response (used for every call), **or**
 /// - A sequence of responses (one per call, cycling the last if exhausted).
-/// - Optional per-call latency.
+/// - Optional per-call (and per-batch) latency.
 /// - Call counting via [`call_count()`](MockDb::call_count).
+/// - Optional ID lookups that send a (counted) request and fall through to
+///   the title search.
+/// - An optional batch endpoint answering every lookup with the fixed
+///   response, counted by [`batch_call_count()`](MockDb::batch_call_count).
 pub struct MockDb {
     name: &'static str,
     /// If `Some`, each call pops the next response (last is repeated if exhausted).
@@ -42,6 +45,9 @@ pub struct MockDb {
     fallback: MockResponse,
     delay: Option<Duration>,
     call_count: AtomicUsize,
+    id_lookups: bool,
+    batch_size: usize,
+    batch_call_count: AtomicUsize,
 }
 
 impl MockDb {
@@ -53,11 +59,13 @@ impl MockDb {
             fallback: response,
             delay: None,
             call_count: AtomicUsize::new(0),
+            id_lookups: false,
+            batch_size: 0,
+            batch_call_count: AtomicUsize::new(0),
         }
     }
 
     /// Create a mock that returns responses in order, repeating the last one.
-    #[allow(dead_code)]
     pub fn with_sequence(name: &'static str, mut responses: Vec<MockResponse>) -> Self {
         assert!(
             !responses.is_empty(),
@@ -72,21 +80,43 @@ impl MockDb {
             fallback,
             delay: None,
             call_count: AtomicUsize::new(0),
+            id_lookups: false,
+            batch_size: 0,
+            batch_call_count: AtomicUsize::new(0),
         }
     }
 
+    /// Send DOI and arXiv ID lookups, each counted as a call, that find
+    /// nothing.
+    pub fn with_id_lookups(mut self) -> Self {
+        self.id_lookups = true;
+        self
+    }
+
     /// Set simulated network latency per call.
-    #[allow(dead_code)]
     pub fn with_delay(mut self, delay: Duration) -> Self {
         self.delay = Some(delay);
         self
     }
 
-    /// How many times `query()` has been called.
+    /// Accept batch lookups of up to `size` references.
+    pub fn with_batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size;
+        self
+    }
+
+    /// How many times `query()` (or, with
+    /// [`with_id_lookups()`](MockDb::with_id_lookups), an ID lookup) has
+    /// been called.
     pub fn call_count(&self) -> usize {
         self.call_count.load(Ordering::SeqCst)
     }
 
+    /// How many times `query_batch()` has been called.
+    pub fn batch_call_count(&self) -> usize {
+        self.batch_call_count.load(Ordering::SeqCst)
+    }
+
     fn next_response(&self) -> MockResponse {
         let mut seq = self.responses.lock().unwrap();
         if let Some(resp) = seq.pop() {
@@ -131,4 +161,73 @@ impl DatabaseBackend for MockDb {
             }
         })
     }
+
+    fn sends_id_lookups(&self) -> bool {
+        self.id_lookups
+    }
+
+    fn query_doi<'a>(
+        &'a self,
+        _doi: &'a str,
+        _title: &'a str,
+        _authors: &'a [String],
+        _client: &'a reqwest::Client,
+        _timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        if self.id_lookups {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+        }
+        Box::pin(async { None })
+    }
+
+    fn query_arxiv_id<'a>(
+        &'a self,
+        _arxiv_id: &'a str,
+        _title: &'a str,
+        _client: &'a reqwest::Client,
+        _timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        if self.id_lookups {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+        }
+        Box::pin(async { None })
+    }
+
+    fn supports_batch(&self) -> bool {
+        self.batch_size > 0
+    }
+
+    fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    fn query_batch<'a>(
+        &'a self,
+        lookups: &'a [BatchLookup<'a>],
+        _client: &'a reqwest::Client,
+        _timeout: Duration,
+    ) -> BatchQueryResult<'a> {
+        self.batch_call_count.fetch_add(1, Ordering::SeqCst);
+        let response = self.fallback.clone();
+        let delay = self.delay;
+
+        Box::pin(async move {
+            if let Some(d) = delay {
+                tokio::time::sleep(d).await;
+            }
+            let result = match response {
+                MockResponse::Found {
+                    title,
+                    authors,
+                    url,
+                } => Ok(DbQueryResult::found(title, authors, url)),
+                MockResponse::NotFound => Ok(DbQueryResult::not_found()),
+                MockResponse::RateLimited { retry_after } => {
+                    Err(DbQueryError::RateLimited { retry_after })
+                }
+                MockResponse::Error(msg) => Err(DbQueryError::Other(msg)),
+            };
+            vec![result; lookups.len()]
+        })
+    }
 }
@@ -19,6 +19,7 @@ pub(crate) mod mock;
 
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 
 pub use crate::rate_limit::DbQueryError;
 
@@ -27,12 +28,20 @@ pub use crate::rate_limit::DbQueryError;
 /// `found_title == None` means the paper was not found in this database.
 /// The optional `retraction` field is populated only by CrossRef (which has
 /// retraction metadata in its response); all other backends leave it `None`.
+/// The optional `near_miss` field is populated only by offline backends when
+/// the best candidate scored just below the match threshold. The optional
+/// `doi_mismatch_title` is set only by the DOI backend when the DOI resolves
+/// to a paper with a significantly different title, and `doi_unregistered`
+/// only when the resolver has no record of the DOI at all.
 #[derive(Debug, Clone, Default)]
 pub struct DbQueryResult {
     pub found_title: Option<String>,
     pub authors: Vec<String>,
     pub paper_url: Option<String>,
     pub retraction: Option<crate::retraction::RetractionResult>,
+    pub near_miss: Option<(String, f64)>,
+    pub doi_mismatch_title: Option<String>,
+    pub doi_unregistered: bool,
 }
 
 impl DbQueryResult {
@@ -43,6 +52,17 @@ impl DbQueryResult {
             authors,
             paper_url: url,
             retraction: None,
+            near_miss: None,
+            doi_mismatch_title: None,
+            doi_unregistered: false,
+        }
+    }
+
+    /// Construct a "not found" result that remembers the closest candidate.
+    pub fn near_miss(title: impl Into<String>, score: f64) -> Self {
+        Self {
+            near_miss: Some((title.into(), score)),
+            ..Self::default()
         }
     }
 
@@ -57,10 +77,146 @@ impl DbQueryResult {
     }
 }
 
+/// Lowest score an offline backend should ask for so that candidates within
+/// `margin` below `threshold` come back as near misses.
+pub(crate) fn near_miss_floor(threshold: f64, margin: f64) -> f64 {
+    (threshold - margin.max(0.0)).max(0.0)
+}
+
+/// Run an offline `query` down to the near-miss floor.
+///
+/// The offline databases only try their fallback lookups (top keywords,
+/// prefixes, trigrams) when no candidate reaches the threshold they are
+/// given, so a near miss at the floor would hide a real match those lookups
+/// find. Such a near miss is re-checked at `threshold` before it is kept.
+pub(crate) fn query_to_near_miss_floor<R, E>(
+    threshold: f64,
+    margin: f64,
+    query: impl Fn(f64) -> Result<Option<R>, E>,
+    score: impl Fn(&R) -> f64,
+) -> Result<Option<R>, E> {
+    let result = query(near_miss_floor(threshold, margin))?;
+    match &result {
+        Some(candidate) if score(candidate) < threshold => Ok(query(threshold)?.or(result)),
+        _ => Ok(result),
+    }
+}
+
+/// Classify an offline candidate scored against `threshold`.
+///
+/// Scores at or above `threshold` keep `found`; scores within `margin` below it
+/// become a near miss; anything lower is plain not-found.
+pub(crate) fn classify_offline_match(
+    found: DbQueryResult,
+    score: f64,
+    threshold: f64,
+    margin: f64,
+) -> DbQueryResult {
+    if score >= threshold {
+        found
+    } else if score >= near_miss_floor(threshold, margin) {
+        DbQueryResult::near_miss(found.found_title.unwrap_or_default(), score)
+    } else {
+        DbQueryResult::not_found()
+    }
+}
+
 /// Result type for `query_doi`: `None` means the backend doesn't handle DOI queries.
 pub type DoiQueryResult<'a> =
     Pin<Box<dyn Future<Output = Option<Result<DbQueryResult, DbQueryError>>> + Send + 'a>>;
 
+/// Result type for `query_batch`: one result per lookup, in order.
+pub type BatchQueryResult<'a> =
+    Pin<Box<dyn Future<Output = Vec<Result<DbQueryResult, DbQueryError>>> + Send + 'a>>;
+
+/// A reference to resolve in [`DatabaseBackend::query_batch`]: its title and
+/// whichever identifiers it carries.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchLookup<'a> {
+    pub title: &'a str,
+    pub doi: Option<&'a str>,
+    pub arxiv_id: Option<&'a str>,
+}
+
+/// How many titles an offline backend queries per hold of its database lock,
+/// so a long batch doesn't keep the per-reference checks waiting.
+const OFFLINE_LOCK_CHUNK: usize = 16;
+
+/// Query `titles` against an offline database on a blocking thread, taking
+/// the lock once per [`OFFLINE_LOCK_CHUNK`] titles.
+///
+/// `query(db, title, threshold)` returns the best candidate at or above
+/// `threshold` as `(score, found result)`; it is run down to the near-miss
+/// floor and classified with [`classify_offline_candidate`].
+pub(crate) async fn query_offline_titles<D, E>(
+    db: &Arc<Mutex<D>>,
+    titles: Vec<String>,
+    threshold: f64,
+    margin: f64,
+    query: impl Fn(&D, &str, f64) -> Result<Option<(f64, DbQueryResult)>, E> + Send + 'static,
+) -> Vec<Result<DbQueryResult, DbQueryError>>
+where
+    D: Send + 'static,
+    E: std::fmt::Display,
+{
+    let db = Arc::clone(db);
+    let count = titles.len();
+    let joined = tokio::task::spawn_blocking(move || {
+        let mut results = Vec::with_capacity(titles.len());
+        for chunk in titles.chunks(OFFLINE_LOCK_CHUNK) {
+            let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
+            results.extend(chunk.iter().map(|title| {
+                query_to_near_miss_floor(
+                    threshold,
+                    margin,
+                    |floor| query(&db, title, floor),
+                    |(score, _)| *score,
+                )
+                .map(|candidate| classify_offline_candidate(candidate, threshold, margin))
+                .map_err(|e| DbQueryError::Other(e.to_string()))
+            }));
+        }
+        Ok(results)
+    })
+    .await
+    .map_err(|e| DbQueryError::Other(e.to_string()));
+
+    match joined {
+        Ok(Ok(results)) => results,
+        Ok(Err(e)) | Err(e) => vec![Err(e); count],
+    }
+}
+
+/// Turn an offline database's best candidate into a match, near miss or
+/// not-found.
+pub(crate) fn classify_offline_candidate(
+    candidate: Option<(f64, DbQueryResult)>,
+    threshold: f64,
+    margin: f64,
+) -> DbQueryResult {
+    match candidate {
+        Some((score, found)) if score < threshold || !found.authors.is_empty() => {
+            classify_offline_match(found, score, threshold, margin)
+        }
+        // Skip results with empty authors - let other DBs verify
+        _ => DbQueryResult::not_found(),
+    }
+}
+
+/// Names of the backends a check can query, as [`DatabaseBackend::name`]
+/// reports them and `disabled_dbs`/`trusted_verifiers` refer to them.
+pub const DATABASE_NAMES: &[&str] = &[
+    "CrossRef",
+    "arXiv",
+    "DBLP",
+    "Semantic Scholar",
+    "ACL Anthology",
+    "Europe PMC",
+    "PubMed",
+    "DOI",
+    "OpenAlex",
+];
+
 /// A database backend that can search for papers by title.
 pub trait DatabaseBackend: Send + Sync {
     /// The canonical name of this database (e.g., "CrossRef", "arXiv").
@@ -85,6 +241,40 @@ pub trait DatabaseBackend: Send + Sync {
         timeout: std::time::Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>>;
 
+    /// Whether [`query_batch`](Self::query_batch) does better than one
+    /// [`query`](Self::query) per reference, e.g. a bulk endpoint or fewer
+    /// locks on an offline database. Only such backends are worth batching.
+    fn supports_batch(&self) -> bool {
+        false
+    }
+
+    /// Most lookups [`query_batch`](Self::query_batch) takes per call;
+    /// callers split larger sets into chunks of this size.
+    fn batch_size(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Query the database for several references, returning one result per
+    /// lookup in order.
+    ///
+    /// A bulk endpoint may resolve lookups by identifier, so a not-found
+    /// entry doesn't rule out a title match. The default runs
+    /// [`query`](Self::query) for each title in turn.
+    fn query_batch<'a>(
+        &'a self,
+        lookups: &'a [BatchLookup<'a>],
+        client: &'a reqwest::Client,
+        timeout: std::time::Duration,
+    ) -> BatchQueryResult<'a> {
+        Box::pin(async move {
+            let mut results = Vec::with_capacity(lookups.len());
+            for lookup in lookups {
+                results.push(self.query(lookup.title, client, timeout).await);
+            }
+            results
+        })
+    }
+
     /// Query the database using a DOI.
     ///
     /// Returns `None` if this backend doesn't support DOI queries (default).
@@ -99,4 +289,164 @@ pub trait DatabaseBackend: Send + Sync {
     ) -> DoiQueryResult<'a> {
         Box::pin(async { None })
     }
+
+    /// Whether [`query_doi`](Self::query_doi) and
+    /// [`query_arxiv_id`](Self::query_arxiv_id) send requests of their own.
+    /// Each such request takes its own rate-limit permit; the defaults answer
+    /// `None` without one.
+    fn sends_id_lookups(&self) -> bool {
+        false
+    }
+
+    /// Query the database using an arXiv ID (e.g. `1706.03762v2`).
+    ///
+    /// Like [`query_doi`](Self::query_doi): `None` (the default) falls back
+    /// to the title search.
+    fn query_arxiv_id<'a>(
+        &'a self,
+        _arxiv_id: &'a str,
+        _title: &'a str,
+        _client: &'a reqwest::Client,
+        _timeout: std::time::Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async { None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::mock::{MockDb, MockResponse};
+    use std::time::Duration;
+
+    fn candidate() -> DbQueryResult {
+        DbQueryResult::found(
+            "Attention Is All You Need",
+            vec!["Ashish Vaswani".into()],
+            None,
+        )
+    }
+
+    #[test]
+    fn near_miss_within_margin() {
+        let result = classify_offline_match(candidate(), 0.82, 0.85, 0.05);
+        assert!(!result.is_found());
+        let (title, score) = result.near_miss.expect("near miss");
+        assert_eq!(title, "Attention Is All You Need");
+        assert_eq!(score, 0.82);
+    }
+
+    #[test]
+    fn near_miss_outside_margin_is_not_found() {
+        let result = classify_offline_match(candidate(), 0.78, 0.85, 0.05);
+        assert!(!result.is_found());
+        assert!(result.near_miss.is_none());
+    }
+
+    #[test]
+    fn match_above_threshold_is_found() {
+        let result = classify_offline_match(candidate(), 0.91, 0.85, 0.05);
+        assert!(result.is_found());
+        assert!(result.near_miss.is_none());
+    }
+
+    fn responses() -> Vec<MockResponse> {
+        vec![
+            MockResponse::Found {
+                title: "Attention Is All You Need".into(),
+                authors: vec!["Ashish Vaswani".into()],
+                url: None,
+            },
+            MockResponse::NotFound,
+            MockResponse::Error("boom".into()),
+        ]
+    }
+
+    /// `(found title, error message)` of a query, for comparison.
+    fn summary(result: &Result<DbQueryResult, DbQueryError>) -> (Option<String>, Option<String>) {
+        match result {
+            Ok(r) => (r.found_title.clone(), None),
+            Err(e) => (None, Some(e.to_string())),
+        }
+    }
+
+    /// A backend that keeps the default `query_batch`, querying through a mock.
+    struct Sequential(MockDb);
+
+    impl DatabaseBackend for Sequential {
+        fn name(&self) -> &str {
+            self.0.name()
+        }
+
+        fn query<'a>(
+            &'a self,
+            title: &'a str,
+            client: &'a reqwest::Client,
+            timeout: Duration,
+        ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>>
+        {
+            self.0.query(title, client, timeout)
+        }
+    }
+
+    #[tokio::test]
+    async fn default_query_batch_matches_sequential_queries() {
+        let client = reqwest::Client::new();
+        let timeout = Duration::from_secs(1);
+        let titles = ["First", "Second", "Third"];
+        let lookups: Vec<BatchLookup> = titles
+            .iter()
+            .map(|title| BatchLookup {
+                title,
+                doi: None,
+                arxiv_id: None,
+            })
+            .collect();
+
+        let batched = Sequential(MockDb::with_sequence("Mock", responses()));
+        assert!(!batched.supports_batch());
+        let batch_results = batched.query_batch(&lookups, &client, timeout).await;
+
+        let sequential = MockDb::with_sequence("Mock", responses());
+        let mut sequential_results = Vec::new();
+        for title in titles {
+            sequential_results.push(sequential.query(title, &client, timeout).await);
+        }
+
+        assert_eq!(batch_results.len(), titles.len());
+        assert_eq!(
+            batch_results.iter().map(summary).collect::<Vec<_>>(),
+            sequential_results.iter().map(summary).collect::<Vec<_>>()
+        );
+        assert_eq!(batched.0.call_count(), titles.len());
+    }
+
+    #[test]
+    fn near_miss_floor_clamps() {
+        assert_eq!(near_miss_floor(0.9, 0.0), 0.9);
+        assert_eq!(near_miss_floor(0.9, -1.0), 0.9);
+        assert_eq!(near_miss_floor(0.05, 0.1), 0.0);
+    }
+
+    #[test]
+    fn near_miss_does_not_hide_fallback_match() {
+        // The keyword lookup finds a near miss at the floor; only the
+        // fallback run at the real threshold finds the cited paper.
+        let query = |threshold: f64| -> Result<_, ()> {
+            Ok(Some(if threshold < 0.85 {
+                ("Attention Is Not All You Need", 0.82)
+            } else {
+                ("Attention Is All You Need", 0.97)
+            }))
+        };
+        let result = query_to_near_miss_floor(0.85, 0.05, query, |c| c.1).unwrap();
+        assert_eq!(result, Some(("Attention Is All You Need", 0.97)));
+
+        // Nothing better at the threshold: the near miss is kept.
+        let query = |threshold: f64| -> Result<_, ()> {
+            Ok((threshold < 0.85).then_some(("Closest", 0.82)))
+        };
+        let result = query_to_near_miss_floor(0.85, 0.05, query, |c| c.1).unwrap();
+        assert_eq!(result, Some(("Closest", 0.82)));
+    }
 }
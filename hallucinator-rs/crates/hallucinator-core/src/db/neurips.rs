@@ -1,4 +1,5 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use std::future::Future;
 use std::pin::Pin;
@@ -30,7 +31,7 @@ impl DatabaseBackend for NeurIPS {
                 let resp = client
                     .get(&url)
                     .timeout(timeout)
-                    .send()
+                    .send_logged("NeurIPS")
                     .await
                     .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -54,7 +55,12 @@ impl DatabaseBackend for NeurIPS {
                     let paper_url = format!("https://papers.nips.cc{}", href);
 
                     // Fetch author page
-                    let authors = match client.get(&paper_url).timeout(timeout).send().await {
+                    let authors = match client
+                        .get(&paper_url)
+                        .timeout(timeout)
+                        .send_logged("NeurIPS")
+                        .await
+                    {
                         Ok(resp) if resp.status().is_success() => {
                             let body = resp.text().await.unwrap_or_default();
                             tokio::task::spawn_blocking(move || parse_neurips_authors(&body))
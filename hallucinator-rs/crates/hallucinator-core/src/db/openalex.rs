@@ -1,7 +1,8 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -22,8 +23,7 @@ impl DatabaseBackend for OpenAlex {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
             let url = format!(
                 "https://api.openalex.org/works?filter=title.search:{}&api_key={}",
                 urlencoding::encode(&query),
@@ -32,9 +32,8 @@ impl DatabaseBackend for OpenAlex {
 
             let resp = client
                 .get(&url)
-                .header("User-Agent", "Academic Reference Parser")
                 .timeout(timeout)
-                .send()
+                .send_logged("OpenAlex")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -1,4 +1,6 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use super::{
+    DatabaseBackend, DbQueryError, DbQueryResult, classify_offline_match, query_to_near_miss_floor,
+};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
@@ -7,6 +9,15 @@ use std::time::Duration;
 /// Offline OpenAlex backend backed by a local Tantivy index.
 pub struct OpenAlexOffline {
     pub db: Arc<Mutex<hallucinator_openalex::OpenAlexDatabase>>,
+    /// How far below the match threshold a candidate may score and still be
+    /// reported as a near miss.
+    pub near_miss_margin: f64,
+    /// Similarity threshold a candidate must reach to count as a match.
+    pub threshold: f64,
+    /// How candidate titles are scored against the cited one.
+    pub scorer: hallucinator_text::TitleScorer,
+    /// Skips or tightens short and generic titles.
+    pub guard: hallucinator_text::QueryGuard,
 }
 
 impl DatabaseBackend for OpenAlexOffline {
@@ -26,21 +37,30 @@ impl DatabaseBackend for OpenAlexOffline {
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         let db = Arc::clone(&self.db);
         let title = title.to_string();
+        let threshold = self.threshold;
+        let margin = self.near_miss_margin;
+        let scorer = self.scorer;
+        let guard = self.guard;
         Box::pin(async move {
             let result = tokio::task::spawn_blocking(move || {
                 let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
-                db.query(&title)
-                    .map_err(|e| DbQueryError::Other(e.to_string()))
+                query_to_near_miss_floor(
+                    threshold,
+                    margin,
+                    |floor| db.query_guarded(&title, floor, scorer, &guard),
+                    |qr| qr.score,
+                )
+                .map_err(|e| DbQueryError::Other(e.to_string()))
             })
             .await
             .map_err(|e| DbQueryError::Other(e.to_string()))??;
 
             match result {
-                Some(qr) => Ok(DbQueryResult::found(
-                    qr.record.title,
-                    qr.record.authors,
-                    qr.record.url,
-                )),
+                Some(qr) => {
+                    let found =
+                        DbQueryResult::found(qr.record.title, qr.record.authors, qr.record.url);
+                    Ok(classify_offline_match(found, qr.score, threshold, margin))
+                }
                 None => Ok(DbQueryResult::not_found()),
             }
         })
@@ -1,7 +1,8 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -20,8 +21,7 @@ impl DatabaseBackend for PubMed {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
 
             // Step 1: Search for matching articles
             let search_url = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/esearch.fcgi";
@@ -35,9 +35,8 @@ impl DatabaseBackend for PubMed {
                     ("retmode", "json"),
                     ("retmax", "10"),
                 ])
-                .header("User-Agent", "Academic Reference Parser")
                 .timeout(timeout)
-                .send()
+                .send_logged("PubMed")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -70,9 +69,8 @@ impl DatabaseBackend for PubMed {
             let resp = client
                 .get(fetch_url)
                 .query(&[("db", "pubmed"), ("id", ids.as_str()), ("retmode", "json")])
-                .header("User-Agent", "Academic Reference Parser")
                 .timeout(timeout)
-                .send()
+                .send_logged("PubMed")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
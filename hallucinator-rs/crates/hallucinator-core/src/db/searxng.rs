@@ -8,6 +8,7 @@
 //! it cannot verify authors - it only confirms the paper exists on the web.
 
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::normalize_title;
 use std::future::Future;
 use std::pin::Pin;
@@ -27,13 +28,13 @@ impl Searxng {
 
     /// Check if SearxNG is reachable. Returns Ok(()) if reachable, Err with message otherwise.
     pub async fn check_connectivity(&self) -> Result<(), String> {
-        let client = reqwest::Client::new();
+        let client = crate::http::build_client(None);
         let url = format!("{}/", self.base_url.trim_end_matches('/'));
 
         match client
             .get(&url)
             .timeout(std::time::Duration::from_secs(3))
-            .send()
+            .send_logged("Web Search")
             .await
         {
             Ok(resp) if resp.status().is_success() || resp.status().is_redirection() => Ok(()),
@@ -125,7 +126,12 @@ impl DatabaseBackend for Searxng {
             );
 
             // If SearxNG is not running, silently return "not found" instead of erroring
-            let resp = match client.get(&url).timeout(timeout).send().await {
+            let resp = match client
+                .get(&url)
+                .timeout(timeout)
+                .send_logged("Web Search")
+                .await
+            {
                 Ok(r) => r,
                 Err(_) => {
                     // Connection refused, timeout, etc. - SearxNG not available
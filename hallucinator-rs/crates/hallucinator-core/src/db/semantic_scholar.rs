@@ -1,15 +1,117 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use super::{
+    BatchLookup, BatchQueryResult, DatabaseBackend, DbQueryError, DbQueryResult, DoiQueryResult,
+};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
+const API_BASE: &str = "https://api.semanticscholar.org/graph/v1/paper";
+
+/// Most IDs the `/paper/batch` endpoint accepts per request.
+const BATCH_SIZE: usize = 500;
+
 pub struct SemanticScholar {
     pub api_key: Option<String>,
 }
 
+impl SemanticScholar {
+    fn authorize(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.api_key {
+            Some(ref key) => req.header("x-api-key", key),
+            None => req,
+        }
+    }
+
+    fn get(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        timeout: Duration,
+    ) -> reqwest::RequestBuilder {
+        self.authorize(client.get(url).timeout(timeout))
+    }
+
+    /// Exact lookup of a paper by an external ID such as `DOI:10.1145/...` or
+    /// `arXiv:1706.03762`, costing one request instead of a title search.
+    ///
+    /// `None` (fall back to the title search) when S2 has no such paper or
+    /// its record isn't the cited `title`.
+    async fn lookup(
+        &self,
+        paper_id: &str,
+        title: &str,
+        client: &reqwest::Client,
+        timeout: Duration,
+    ) -> Option<Result<DbQueryResult, DbQueryError>> {
+        // Keep the `/` of DOIs and the `:` of the ID prefix readable in the path
+        let paper_id = urlencoding::encode(paper_id)
+            .replace("%2F", "/")
+            .replace("%3A", ":");
+        let url = format!("{API_BASE}/{paper_id}?fields=title,authors,url");
+
+        let resp = match self
+            .get(client, &url, timeout)
+            .send_logged("Semantic Scholar")
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => return Some(Err(DbQueryError::Other(e.to_string()))),
+        };
+        if let Err(e) = check_rate_limit_response(&resp) {
+            return Some(Err(e));
+        }
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return None;
+        }
+        if !resp.status().is_success() {
+            return Some(Err(DbQueryError::Other(format!("HTTP {}", resp.status()))));
+        }
+
+        let data: serde_json::Value = match resp.json().await {
+            Ok(data) => data,
+            Err(e) => return Some(Err(DbQueryError::Other(e.to_string()))),
+        };
+        parse_paper(&data, title).map(Ok)
+    }
+
+    /// Resolve `lookups` by DOI or arXiv ID with one `/paper/batch` request:
+    /// `None` where S2 has no paper matching the lookup.
+    async fn query_ids(
+        &self,
+        lookups: &[BatchLookup<'_>],
+        client: &reqwest::Client,
+        timeout: Duration,
+    ) -> Result<Vec<Option<DbQueryResult>>, DbQueryError> {
+        let ids: Vec<String> = lookups.iter().map(batch_id).collect();
+        let url = format!("{API_BASE}/batch?fields=title,authors,url");
+        let req = client
+            .post(&url)
+            .timeout(timeout)
+            .json(&serde_json::json!({ "ids": ids }));
+
+        let resp = self
+            .authorize(req)
+            .send_logged("Semantic Scholar")
+            .await
+            .map_err(|e| DbQueryError::Other(e.to_string()))?;
+
+        check_rate_limit_response(&resp)?;
+        if !resp.status().is_success() {
+            return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+        }
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| DbQueryError::Other(e.to_string()))?;
+        Ok(parse_batch_response(&data, lookups))
+    }
+}
+
 impl DatabaseBackend for SemanticScholar {
     fn name(&self) -> &str {
         "Semantic Scholar"
@@ -22,24 +124,15 @@ impl DatabaseBackend for SemanticScholar {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
             let url = format!(
-                "https://api.semanticscholar.org/graph/v1/paper/search?query={}&limit=10&fields=title,authors,url",
+                "{API_BASE}/search?query={}&limit=10&fields=title,authors,url",
                 urlencoding::encode(&query)
             );
 
-            let mut req = client
-                .get(&url)
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout);
-
-            if let Some(ref key) = self.api_key {
-                req = req.header("x-api-key", key);
-            }
-
-            let resp = req
-                .send()
+            let resp = self
+                .get(client, &url, timeout)
+                .send_logged("Semantic Scholar")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -54,31 +147,254 @@ impl DatabaseBackend for SemanticScholar {
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
             let results = data["data"].as_array().cloned().unwrap_or_default();
 
-            for item in results {
-                let found_title = item["title"].as_str().unwrap_or("");
-                if !found_title.is_empty() && titles_match(title, found_title) {
-                    let authors: Vec<String> = item["authors"]
-                        .as_array()
-                        .map(|arr| {
-                            arr.iter()
-                                .filter_map(|a| a["name"].as_str().map(String::from))
-                                .collect()
-                        })
-                        .unwrap_or_default();
-
-                    // Skip results with empty authors - let other DBs verify
-                    // Semantic Scholar sometimes returns title matches without author data
-                    if authors.is_empty() {
-                        continue;
-                    }
-
-                    let paper_url = item["url"].as_str().map(String::from);
-
-                    return Ok(DbQueryResult::found(found_title, authors, paper_url));
-                }
+            Ok(results
+                .iter()
+                .find_map(|item| parse_paper(item, title))
+                .unwrap_or_else(DbQueryResult::not_found))
+        })
+    }
+
+    fn sends_id_lookups(&self) -> bool {
+        true
+    }
+
+    fn query_doi<'a>(
+        &'a self,
+        doi: &'a str,
+        title: &'a str,
+        _authors: &'a [String],
+        client: &'a reqwest::Client,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move { self.lookup(&doi_id(doi), title, client, timeout).await })
+    }
+
+    fn query_arxiv_id<'a>(
+        &'a self,
+        arxiv_id: &'a str,
+        title: &'a str,
+        client: &'a reqwest::Client,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move {
+            self.lookup(&arxiv_paper_id(arxiv_id), title, client, timeout)
+                .await
+        })
+    }
+
+    fn supports_batch(&self) -> bool {
+        true
+    }
+
+    fn batch_size(&self) -> usize {
+        BATCH_SIZE
+    }
+
+    fn query_batch<'a>(
+        &'a self,
+        lookups: &'a [BatchLookup<'a>],
+        client: &'a reqwest::Client,
+        timeout: Duration,
+    ) -> BatchQueryResult<'a> {
+        Box::pin(async move {
+            match self.query_ids(lookups, client, timeout).await {
+                Ok(results) => results
+                    .into_iter()
+                    .map(|r| Ok(r.unwrap_or_else(DbQueryResult::not_found)))
+                    .collect(),
+                Err(e) => vec![Err(e); lookups.len()],
             }
+        })
+    }
+}
+
+/// The found result for an S2 paper object, if its title matches `title`.
+///
+/// Papers without author data are skipped so other DBs can verify them:
+/// Semantic Scholar sometimes returns title matches with no authors.
+fn parse_paper(item: &serde_json::Value, title: &str) -> Option<DbQueryResult> {
+    let found_title = item["title"].as_str().unwrap_or("");
+    if found_title.is_empty() || !titles_match(title, found_title) {
+        return None;
+    }
+    let authors: Vec<String> = item["authors"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| a["name"].as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    if authors.is_empty() {
+        return None;
+    }
+    let paper_url = item["url"].as_str().map(String::from);
+    Some(DbQueryResult::found(found_title, authors, paper_url))
+}
 
-            Ok(DbQueryResult::not_found())
+/// Match each lookup against the paper at the same position of a
+/// `/paper/batch` response, which holds `null` for IDs S2 doesn't know.
+fn parse_batch_response(
+    data: &serde_json::Value,
+    lookups: &[BatchLookup<'_>],
+) -> Vec<Option<DbQueryResult>> {
+    let papers = data.as_array().map(Vec::as_slice).unwrap_or_default();
+    lookups
+        .iter()
+        .enumerate()
+        .map(|(i, lookup)| {
+            papers
+                .get(i)
+                .and_then(|item| parse_paper(item, lookup.title))
         })
+        .collect()
+}
+
+fn doi_id(doi: &str) -> String {
+    format!("DOI:{doi}")
+}
+
+fn arxiv_paper_id(arxiv_id: &str) -> String {
+    format!("arXiv:{}", strip_arxiv_version(arxiv_id))
+}
+
+/// The S2 ID for a batch lookup, preferring the DOI. A lookup with neither
+/// identifier gets an empty ID, which S2 answers with `null`.
+fn batch_id(lookup: &BatchLookup<'_>) -> String {
+    match (lookup.doi, lookup.arxiv_id) {
+        (Some(doi), _) => doi_id(doi),
+        (None, Some(arxiv_id)) => arxiv_paper_id(arxiv_id),
+        (None, None) => String::new(),
+    }
+}
+
+/// `1706.03762v5` → `1706.03762`; S2 indexes arXiv papers without versions.
+fn strip_arxiv_version(arxiv_id: &str) -> &str {
+    match arxiv_id.rfind('v') {
+        Some(i)
+            if i + 1 < arxiv_id.len() && arxiv_id[i + 1..].bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            &arxiv_id[..i]
+        }
+        _ => arxiv_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Recorded response of `/paper/arXiv:1706.03762?fields=title,authors,url`.
+    const ARXIV_LOOKUP: &str = r#"{
+  "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+  "url": "https://www.semanticscholar.org/paper/204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+  "title": "Attention is All you Need",
+  "authors": [
+    {"authorId": "40348417", "name": "Ashish Vaswani"},
+    {"authorId": "1846258", "name": "Noam M. Shazeer"},
+    {"authorId": "3877127", "name": "Niki Parmar"},
+    {"authorId": "39328010", "name": "Jakob Uszkoreit"},
+    {"authorId": "145024664", "name": "Llion Jones"},
+    {"authorId": "19177000", "name": "Aidan N. Gomez"},
+    {"authorId": "40527594", "name": "Lukasz Kaiser"},
+    {"authorId": "3443442", "name": "Illia Polosukhin"}
+  ]
+}"#;
+
+    #[test]
+    fn test_parse_arxiv_lookup() {
+        let data: serde_json::Value = serde_json::from_str(ARXIV_LOOKUP).unwrap();
+        let result = parse_paper(&data, "Attention Is All You Need").unwrap();
+        assert_eq!(
+            result.found_title.as_deref(),
+            Some("Attention is All you Need")
+        );
+        assert_eq!(result.authors.len(), 8);
+        assert_eq!(result.authors[0], "Ashish Vaswani");
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://www.semanticscholar.org/paper/204e3073870fae3d05bcbc2f6a8e263d9b72e776")
+        );
+    }
+
+    #[test]
+    fn test_parse_lookup_of_different_paper() {
+        let data: serde_json::Value = serde_json::from_str(ARXIV_LOOKUP).unwrap();
+        assert!(parse_paper(&data, "Deep Residual Learning for Image Recognition").is_none());
+    }
+
+    #[test]
+    fn test_parse_paper_without_authors() {
+        let data = serde_json::json!({"title": "Attention is All you Need", "authors": []});
+        assert!(parse_paper(&data, "Attention Is All You Need").is_none());
+    }
+
+    /// Recorded response of `/paper/batch` for `DOI:10.1109/CVPR.2016.90`,
+    /// `arXiv:9999.99999` (unknown) and `arXiv:1706.03762`.
+    const BATCH_RESPONSE: &str = r#"[
+  {
+    "paperId": "2c03df8b48bf3fa39054345bafabfeff15bfd11d",
+    "url": "https://www.semanticscholar.org/paper/2c03df8b48bf3fa39054345bafabfeff15bfd11d",
+    "title": "Deep Residual Learning for Image Recognition",
+    "authors": [
+      {"authorId": "39353098", "name": "Kaiming He"},
+      {"authorId": "1771551", "name": "X. Zhang"},
+      {"authorId": "3080683", "name": "Shaoqing Ren"},
+      {"authorId": "2153632494", "name": "Jian Sun"}
+    ]
+  },
+  null,
+  {
+    "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+    "url": "https://www.semanticscholar.org/paper/204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+    "title": "Attention is All you Need",
+    "authors": [{"authorId": "40348417", "name": "Ashish Vaswani"}]
+  }
+]"#;
+
+    #[test]
+    fn test_parse_batch_response() {
+        let lookups = [
+            BatchLookup {
+                title: "Deep Residual Learning for Image Recognition",
+                doi: Some("10.1109/CVPR.2016.90"),
+                arxiv_id: None,
+            },
+            BatchLookup {
+                title: "A Paper That Does Not Exist",
+                doi: None,
+                arxiv_id: Some("9999.99999"),
+            },
+            BatchLookup {
+                title: "Some Other Title Entirely",
+                doi: None,
+                arxiv_id: Some("1706.03762v5"),
+            },
+        ];
+        assert_eq!(
+            lookups.iter().map(batch_id).collect::<Vec<_>>(),
+            [
+                "DOI:10.1109/CVPR.2016.90",
+                "arXiv:9999.99999",
+                "arXiv:1706.03762"
+            ]
+        );
+
+        let data: serde_json::Value = serde_json::from_str(BATCH_RESPONSE).unwrap();
+        let results = parse_batch_response(&data, &lookups);
+        assert_eq!(results.len(), 3);
+        let first = results[0].as_ref().unwrap();
+        assert_eq!(first.authors[0], "Kaiming He");
+        assert!(results[1].is_none());
+        // The ID resolved, but to a paper other than the one cited
+        assert!(results[2].is_none());
+    }
+
+    #[test]
+    fn test_strip_arxiv_version() {
+        assert_eq!(strip_arxiv_version("1706.03762v5"), "1706.03762");
+        assert_eq!(strip_arxiv_version("1706.03762"), "1706.03762");
+        assert_eq!(strip_arxiv_version("hep-th/9901001v2"), "hep-th/9901001");
+        assert_eq!(strip_arxiv_version("solv-int/9901001"), "solv-int/9901001");
     }
 }
@@ -1,7 +1,8 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use crate::rate_limit::check_rate_limit_response;
-use crate::text_utils::get_query_words;
+use crate::text_utils::normalize_query_title;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -20,8 +21,7 @@ impl DatabaseBackend for Ssrn {
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
+            let query = normalize_query_title(title);
 
             let resp = client
                 .get("https://papers.ssrn.com/sol3/results.cfm")
@@ -29,7 +29,7 @@ impl DatabaseBackend for Ssrn {
                 .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
                 .header("Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8")
                 .timeout(timeout)
-                .send()
+                .send_logged("SSRN")
                 .await
                 .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
@@ -1,5 +1,11 @@
+use crate::Reference;
 use crate::authors::validate_authors;
+use crate::cache::QueryCache;
+use crate::http::SendLogged;
 use crate::matching::normalize_title;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::future::Future;
 use std::time::Duration;
 
 /// Result of DOI validation.
@@ -11,6 +17,30 @@ pub struct DoiValidation {
     pub error: Option<String>,
 }
 
+impl DoiValidation {
+    /// `doi` is well-formed and the resolver answered definitively that it
+    /// has no record of it, as opposed to a lookup that failed (timeout,
+    /// server error). A malformed DOI is never reported as unregistered: it
+    /// is simply not a DOI.
+    pub fn is_unregistered(&self, doi: &str) -> bool {
+        self.is_not_found() && is_well_formed_doi(doi)
+    }
+
+    /// The resolver has no record of the DOI.
+    fn is_not_found(&self) -> bool {
+        !self.valid && self.error.as_deref() == Some(DOI_NOT_FOUND)
+    }
+}
+
+/// `DoiValidation::error` for a DOI the resolver has no record of.
+const DOI_NOT_FOUND: &str = "DOI not found";
+
+const DOI_RESOLVER_URL: &str = "https://doi.org";
+
+/// Normalized-title similarity below which a resolved DOI is considered to
+/// point at a different paper than the one cited.
+pub const DOI_TITLE_MISMATCH_THRESHOLD: f64 = 0.7;
+
 /// Result of checking DOI match against a reference.
 #[derive(Debug, Clone)]
 pub enum DoiMatchResult {
@@ -33,6 +63,16 @@ pub enum DoiMatchResult {
 
 /// Validate a DOI by querying doi.org for metadata.
 pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration) -> DoiValidation {
+    validate_doi_at(DOI_RESOLVER_URL, doi, client, timeout).await
+}
+
+/// [`validate_doi`] against the resolver at `base_url`.
+async fn validate_doi_at(
+    base_url: &str,
+    doi: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+) -> DoiValidation {
     if doi.is_empty() {
         return DoiValidation {
             valid: false,
@@ -42,13 +82,12 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
         };
     }
 
-    let url = format!("https://doi.org/{}", doi);
+    let url = format!("{}/{}", base_url, doi);
     let result = client
         .get(&url)
         .header("Accept", "application/vnd.citationstyles.csl+json")
-        .header("User-Agent", "HallucinatedReferenceChecker/1.0")
         .timeout(timeout)
-        .send()
+        .send_logged("DOI")
         .await;
 
     match result {
@@ -99,7 +138,7 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
                     valid: false,
                     title: None,
                     authors: vec![],
-                    error: Some("DOI not found".into()),
+                    error: Some(DOI_NOT_FOUND.into()),
                 }
             } else {
                 DoiValidation {
@@ -119,6 +158,80 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
     }
 }
 
+impl crate::DoiInfo {
+    /// Summarize the resolution of `doi`, cited under `ref_title`.
+    pub fn from_validation(doi: &str, validation: &DoiValidation, ref_title: &str) -> Self {
+        Self {
+            doi: doi.to_string(),
+            valid: validation.valid,
+            title: validation.title.clone(),
+            title_mismatch: doi_title_mismatch(validation, ref_title).is_some(),
+            well_formed: is_well_formed_doi(doi),
+            registered: !validation.is_unregistered(doi),
+        }
+    }
+}
+
+/// Whether `doi` has the shape of a DOI (`10.<registrant>/<suffix>`), after
+/// stripping any resolver prefix. Says nothing about whether it is registered.
+pub fn is_well_formed_doi(doi: &str) -> bool {
+    static DOI_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^10\.\d{4,9}(\.\d+)*/\S+$").unwrap());
+    DOI_RE.is_match(&normalize_doi(doi))
+}
+
+/// Normalize a DOI for use as a cache key: trimmed, lowercased, with any
+/// `https://doi.org/` or `doi:` prefix removed. DOIs are case-insensitive.
+pub fn normalize_doi(doi: &str) -> String {
+    let lower = doi.trim().to_lowercase();
+    let stripped = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "http://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| lower.strip_prefix(prefix))
+    .unwrap_or(&lower);
+    stripped.trim().to_string()
+}
+
+/// Validate a DOI, consulting `cache` before hitting doi.org.
+pub async fn resolve_doi(
+    doi: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+    cache: Option<&QueryCache>,
+) -> DoiValidation {
+    resolve_doi_with(doi, cache, || validate_doi(doi, client, timeout)).await
+}
+
+/// Cache-aware DOI resolution with a pluggable fetch.
+///
+/// Returns the cached resolution if present; otherwise awaits `fetch` and
+/// caches the outcome when it is definitive (resolved, or not found). Transient
+/// failures are returned but never cached.
+pub(crate) async fn resolve_doi_with<F, Fut>(
+    doi: &str,
+    cache: Option<&QueryCache>,
+    fetch: F,
+) -> DoiValidation
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = DoiValidation>,
+{
+    if let Some(cached) = cache.and_then(|c| c.get_doi(doi)) {
+        return cached;
+    }
+    let result = fetch().await;
+    if let Some(cache) = cache
+        && (result.valid || result.is_not_found())
+    {
+        cache.insert_doi(doi, &result);
+    }
+    result
+}
+
 /// Check if DOI metadata matches the reference title and authors.
 pub fn check_doi_match(
     doi_result: &DoiValidation,
@@ -191,3 +304,353 @@ pub fn check_doi_match(
         }
     }
 }
+
+/// If the DOI resolved to a title that diverges significantly from the cited
+/// title, return the resolved title.
+///
+/// Stricter than [`check_doi_match`]'s `TitleMismatch`, which also fires on
+/// small differences (typos, truncated subtitles) that only mean the DOI
+/// can't verify the reference on its own.
+pub fn doi_title_mismatch(doi_result: &DoiValidation, ref_title: &str) -> Option<String> {
+    if !doi_result.valid {
+        return None;
+    }
+    let doi_title = doi_result.title.as_deref()?;
+    resolved_title_diverges(ref_title, doi_title).then(|| doi_title.to_string())
+}
+
+/// Whether `resolved` (a title a DOI resolved to) names a different paper
+/// than the `cited` one: similarity below [`DOI_TITLE_MISMATCH_THRESHOLD`],
+/// neither title a prefix of the other, and `resolved` (when 20+ characters)
+/// not contained in `cited`, which often carries venue text after the title.
+/// Empty titles never diverge.
+fn resolved_title_diverges(cited: &str, resolved: &str) -> bool {
+    let cited_norm = normalize_title(cited);
+    let resolved_norm = normalize_title(resolved);
+    if cited_norm.is_empty() || resolved_norm.is_empty() {
+        return false;
+    }
+    let ratio = rapidfuzz::fuzz::ratio(cited_norm.chars(), resolved_norm.chars());
+    let overlaps = cited_norm.starts_with(&resolved_norm)
+        || resolved_norm.starts_with(&cited_norm)
+        || (resolved_norm.len() >= 20 && cited_norm.contains(&resolved_norm));
+    ratio < DOI_TITLE_MISMATCH_THRESHOLD && !overlaps
+}
+
+/// One DOI cited by several references whose titles name different papers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedDoi {
+    /// Normalized DOI (see [`normalize_doi`]).
+    pub doi: String,
+    /// Indices into the reference list, in document order.
+    pub ref_indices: Vec<usize>,
+}
+
+/// Document-level pass flagging DOIs reused across references with
+/// dissimilar titles — a real DOI pasted onto invented papers is a common
+/// pattern in fabricated bibliographies. Repeats of the same paper (e.g. a
+/// duplicated entry) are not flagged.
+pub fn find_shared_dois(refs: &[Reference]) -> Vec<SharedDoi> {
+    let mut groups: Vec<SharedDoi> = Vec::new();
+    for (i, reference) in refs.iter().enumerate() {
+        let Some(doi) = reference.doi.as_deref().map(normalize_doi) else {
+            continue;
+        };
+        if doi.is_empty() {
+            continue;
+        }
+        match groups.iter_mut().find(|g| g.doi == doi) {
+            Some(group) => group.ref_indices.push(i),
+            None => groups.push(SharedDoi {
+                doi,
+                ref_indices: vec![i],
+            }),
+        }
+    }
+
+    // Two cited titles are the same paper if either could be the other with
+    // venue text appended, so check the containment rule both ways round.
+    let title = |i: usize| refs[i].title.as_deref().unwrap_or("");
+    let diverge = |a: &str, b: &str| resolved_title_diverges(a, b) && resolved_title_diverges(b, a);
+    groups.retain(|g| {
+        g.ref_indices.iter().enumerate().any(|(n, &a)| {
+            g.ref_indices[n + 1..]
+                .iter()
+                .any(|&b| diverge(title(a), title(b)))
+        })
+    });
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(title: &str) -> DoiValidation {
+        DoiValidation {
+            valid: true,
+            title: Some(title.to_string()),
+            authors: vec!["Kaiming He".into()],
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_doi_resolving_to_different_title_is_mismatch() {
+        let doi_result = resolved("Deep Residual Learning for Image Recognition");
+        let cited = "Attention Is All You Need";
+
+        assert!(matches!(
+            check_doi_match(&doi_result, cited, &[]),
+            DoiMatchResult::TitleMismatch { .. }
+        ));
+        assert_eq!(
+            doi_title_mismatch(&doi_result, cited).as_deref(),
+            Some("Deep Residual Learning for Image Recognition")
+        );
+    }
+
+    #[test]
+    fn test_doi_title_minor_differences_are_not_mismatch() {
+        let doi_result = resolved("Deep Residual Learning for Image Recognition");
+        assert!(
+            doi_title_mismatch(&doi_result, "Deep residual learning for image recognition")
+                .is_none()
+        );
+        assert!(
+            doi_title_mismatch(&doi_result, "Deep Residual Learning for Image Recogniton")
+                .is_none()
+        );
+        assert!(
+            doi_title_mismatch(
+                &doi_result,
+                "Deep Residual Learning for Image Recognition: Extended Version"
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_resolved_title_containing_cited_is_mismatch() {
+        // A cited title that only appears inside a longer resolved one names
+        // a different paper; the reverse (venue text after the cited title)
+        // does not, as above.
+        let doi_result = resolved(
+            "Revisiting Deep Residual Learning for Image Recognition under Distribution Shift and Label Noise",
+        );
+        assert!(
+            doi_title_mismatch(&doi_result, "Deep Residual Learning for Image Recognition")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_invalid_doi_is_not_mismatch() {
+        let doi_result = DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI not found".into()),
+        };
+        assert!(doi_title_mismatch(&doi_result, "Attention Is All You Need").is_none());
+    }
+
+    fn cited(title: &str, doi: &str) -> Reference {
+        Reference {
+            raw_citation: title.to_string(),
+            title: Some(title.to_string()),
+            authors: vec![],
+            doi: Some(doi.to_string()),
+            arxiv_id: None,
+            original_number: 1,
+            skip_reason: None,
+            kind: crate::RefKind::Unknown,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    #[test]
+    fn test_shared_doi_with_different_titles_is_flagged() {
+        let refs = vec![
+            cited(
+                "Deep Residual Learning for Image Recognition",
+                "10.1109/CVPR.2016.90",
+            ),
+            cited("Attention Is All You Need", "10.5555/3295222.3295349"),
+            cited(
+                "Quantum Gradient Methods for Federated Graph Pruning",
+                "https://doi.org/10.1109/cvpr.2016.90",
+            ),
+        ];
+        assert_eq!(
+            find_shared_dois(&refs),
+            vec![SharedDoi {
+                doi: "10.1109/cvpr.2016.90".into(),
+                ref_indices: vec![0, 2],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_shared_doi_with_same_title_is_not_flagged() {
+        let refs = vec![
+            cited("Attention Is All You Need", "10.5555/3295222.3295349"),
+            cited("Attention is all you need.", "10.5555/3295222.3295349"),
+            cited(
+                "Deep Residual Learning for Image Recognition",
+                "10.1109/CVPR.2016.90",
+            ),
+            cited(
+                "In CVPR 2016: Deep Residual Learning for Image Recognition",
+                "10.1109/CVPR.2016.90",
+            ),
+        ];
+        assert!(find_shared_dois(&refs).is_empty());
+    }
+
+    #[test]
+    fn test_is_well_formed_doi() {
+        assert!(is_well_formed_doi("10.1109/CVPR.2016.90"));
+        assert!(is_well_formed_doi("https://doi.org/10.1000.10/xyz"));
+        assert!(!is_well_formed_doi("10.12/short-registrant"));
+        assert!(!is_well_formed_doi("doi.org/not-a-doi"));
+        assert!(!is_well_formed_doi("10.1109/"));
+    }
+
+    /// Answer one request on a local port with `404 Not Found`.
+    async fn serve_not_found() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 1024];
+            while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut chunk).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                buf.extend_from_slice(&chunk[..n]);
+            }
+            socket
+                .write_all(
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_well_formed_unresolved_doi_is_unregistered() {
+        let base_url = serve_not_found().await;
+        let doi = "10.1145/3576915.9999999";
+        let validation = validate_doi_at(
+            &base_url,
+            doi,
+            &reqwest::Client::new(),
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(!validation.valid);
+        assert!(validation.is_unregistered(doi));
+
+        let info = crate::DoiInfo::from_validation(doi, &validation, "A Plausible Paper");
+        assert!(!info.valid);
+        assert!(info.well_formed);
+        assert!(!info.registered);
+    }
+
+    #[test]
+    fn test_failed_lookup_is_not_unregistered() {
+        let failed = DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI lookup failed: HTTP 503 Service Unavailable".into()),
+        };
+        assert!(!failed.is_unregistered("10.1234/abcd"));
+        assert!(crate::DoiInfo::from_validation("10.1234/abcd", &failed, "T").registered);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_doi_is_not_unregistered() {
+        let base_url = serve_not_found().await;
+        let doi = "10.12/short-registrant";
+        let validation = validate_doi_at(
+            &base_url,
+            doi,
+            &reqwest::Client::new(),
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(!validation.valid);
+        assert!(!validation.is_unregistered(doi));
+
+        let info = crate::DoiInfo::from_validation(doi, &validation, "A Plausible Paper");
+        assert!(!info.well_formed);
+        assert!(info.registered);
+    }
+
+    #[test]
+    fn test_normalize_doi() {
+        assert_eq!(
+            normalize_doi("10.1109/CVPR.2016.90"),
+            "10.1109/cvpr.2016.90"
+        );
+        assert_eq!(
+            normalize_doi(" https://doi.org/10.1109/CVPR.2016.90 "),
+            "10.1109/cvpr.2016.90"
+        );
+        assert_eq!(
+            normalize_doi("doi:10.1109/cvpr.2016.90"),
+            "10.1109/cvpr.2016.90"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_second_resolution_served_from_cache() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let cache = QueryCache::default();
+        let calls = AtomicUsize::new(0);
+        let mock_fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            resolved("Deep Residual Learning for Image Recognition")
+        };
+
+        let first = resolve_doi_with("10.1109/CVPR.2016.90", Some(&cache), mock_fetch).await;
+        let second = resolve_doi_with(
+            "https://doi.org/10.1109/cvpr.2016.90",
+            Some(&cache),
+            mock_fetch,
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(second.valid);
+        assert_eq!(second.title, first.title);
+        assert_eq!(second.authors, first.authors);
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_not_cached() {
+        let cache = QueryCache::default();
+        let failed = || async {
+            DoiValidation {
+                valid: false,
+                title: None,
+                authors: vec![],
+                error: Some("DOI lookup failed: timeout".into()),
+            }
+        };
+
+        resolve_doi_with("10.1234/abcd", Some(&cache), failed).await;
+        assert!(cache.get_doi("10.1234/abcd").is_none());
+    }
+}
@@ -0,0 +1,375 @@
+//! Heuristic classification of a reference's publication kind from its raw
+//! citation text.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// The kind of publication a reference points to.
+///
+/// Inferred from surface signals in the raw citation (see [`classify_citation`]);
+/// `Unknown` when no signal is strong enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RefKind {
+    Journal,
+    Conference,
+    Preprint,
+    Book,
+    Web,
+    #[default]
+    Unknown,
+}
+
+impl RefKind {
+    /// Lowercase name used in exports and logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefKind::Journal => "journal",
+            RefKind::Conference => "conference",
+            RefKind::Preprint => "preprint",
+            RefKind::Book => "book",
+            RefKind::Web => "web",
+            RefKind::Unknown => "unknown",
+        }
+    }
+
+    /// Databases most likely to hold this kind of publication. They are
+    /// queried (and their cached answers consulted) before the others, so a
+    /// match is attributed to the natural source.
+    pub fn preferred_dbs(&self) -> &'static [&'static str] {
+        match self {
+            RefKind::Journal => &["CrossRef", "Europe PMC", "PubMed", "OpenAlex"],
+            RefKind::Conference => &["DBLP", "ACL Anthology", "Semantic Scholar"],
+            RefKind::Preprint => &["arXiv", "SSRN"],
+            RefKind::Book => &["CrossRef", "OpenAlex"],
+            RefKind::Web | RefKind::Unknown => &[],
+        }
+    }
+
+    /// Sort key ordering `db_name` for this kind: preferred databases first.
+    pub(crate) fn db_rank(&self, db_name: &str) -> usize {
+        usize::from(!self.preferred_dbs().contains(&db_name))
+    }
+}
+
+impl std::fmt::Display for RefKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How the record a database matched relates to the cited work when they
+/// are two versions of the same paper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum VersionRelation {
+    /// A preprint was cited; the match is its published version.
+    PublishedVersion,
+    /// A published paper was cited; the match is its preprint.
+    PreprintVersion,
+}
+
+impl VersionRelation {
+    /// Lowercase name used in exports and logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionRelation::PublishedVersion => "published_version",
+            VersionRelation::PreprintVersion => "preprint_version",
+        }
+    }
+
+    /// What the matched record is, for reports.
+    pub fn description(&self) -> &'static str {
+        match self {
+            VersionRelation::PublishedVersion => "published version of the cited preprint",
+            VersionRelation::PreprintVersion => "preprint of the cited published paper",
+        }
+    }
+
+    /// Parse a name produced by [`as_str`](Self::as_str).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "published_version" => Some(VersionRelation::PublishedVersion),
+            "preprint_version" => Some(VersionRelation::PreprintVersion),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for VersionRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Databases whose records are preprints rather than published papers.
+const PREPRINT_SOURCES: &[&str] = &["arXiv", "SSRN"];
+
+/// How a record matched by `source` relates to `reference`, if exactly one
+/// of them is a preprint. A reference counts as a preprint when it carries an
+/// arXiv ID or was classified as [`RefKind::Preprint`]; the matched record
+/// when it comes from a preprint server, or its `paper_url` points at one
+/// (a CrossRef or Semantic Scholar record of an arXiv paper).
+pub(crate) fn version_relation(
+    reference: &crate::Reference,
+    source: &str,
+    paper_url: Option<&str>,
+) -> Option<VersionRelation> {
+    let cited_preprint = reference.arxiv_id.is_some() || reference.kind == RefKind::Preprint;
+    let matched_preprint = PREPRINT_SOURCES.contains(&source)
+        || paper_url.is_some_and(|url| PREPRINT_URL_RE.is_match(url));
+    match (cited_preprint, matched_preprint) {
+        (true, false) => Some(VersionRelation::PublishedVersion),
+        (false, true) => Some(VersionRelation::PreprintVersion),
+        _ => None,
+    }
+}
+
+static PREPRINT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\barxiv\b|\bbiorxiv\b|\bmedrxiv\b|\bssrn\b|\bcorr\s+abs/|\bpreprint\b|\b10\.48550/|\b10\.1101/",
+    )
+    .unwrap()
+});
+
+/// Preprint server hosts and DOI prefixes (arXiv, bioRxiv/medRxiv, SSRN).
+static PREPRINT_URL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\barxiv\.org/|\b(?:bio|med)rxiv\.org/|\bssrn\.com/|\b10\.48550/|\b10\.1101/|\b10\.2139/ssrn")
+        .unwrap()
+});
+
+static BOOK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bisbn(?:-1[03])?\b|\buniversity\s+press\b|\bpublishers?\b|\bpublishing\b|\b\d+(?:st|nd|rd|th)\s+ed(?:ition|\.)|\b10\.1007/978-",
+    )
+    .unwrap()
+});
+
+static CONFERENCE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bin\s+proc(?:eedings|\.)|\bconference\b|\bsymposium\b|\bworkshop\b|\bin\s+advances\s+in\b",
+    )
+    .unwrap()
+});
+
+static JOURNAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bjournal\b|\btransactions\s+on\b|\bletters\b|\bvol\.\s*\d+|\bvolume\s+\d+|\b\d+\s*\(\d+\)\s*[:,]",
+    )
+    .unwrap()
+});
+
+static URL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)https?://\S+|\bwww\.\S+").unwrap());
+
+/// URLs that identify scholarly records rather than web pages.
+static SCHOLARLY_URL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?:doi\.org|arxiv\.org|dl\.acm\.org|ieeexplore)").unwrap());
+
+static WEB_MARKER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\baccessed\b|\bretrieved\b|\[online\]|\bonline:").unwrap());
+
+/// Classify a reference's publication kind from its raw citation text.
+///
+/// Signals are checked from most to least specific: preprint markers (arXiv,
+/// bioRxiv, preprint DOI prefixes), book markers (ISBN, publisher, edition),
+/// conference markers ("In Proceedings", "Conference", ...), journal markers
+/// ("Journal of", "vol. N", `N(M):`), and finally a non-scholarly URL with
+/// no other signal or an "Accessed"/"[Online]" marker.
+pub fn classify_citation(raw: &str) -> RefKind {
+    if PREPRINT_RE.is_match(raw) {
+        return RefKind::Preprint;
+    }
+    if BOOK_RE.is_match(raw) {
+        return RefKind::Book;
+    }
+    if CONFERENCE_RE.is_match(raw) {
+        return RefKind::Conference;
+    }
+    if JOURNAL_RE.is_match(raw) {
+        return RefKind::Journal;
+    }
+    let has_web_url = URL_RE
+        .find_iter(raw)
+        .any(|m| !SCHOLARLY_URL_RE.is_match(m.as_str()));
+    if has_web_url || WEB_MARKER_RE.is_match(raw) {
+        return RefKind::Web;
+    }
+    RefKind::Unknown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preprint() {
+        assert_eq!(
+            classify_citation(
+                "A. Vaswani et al. Attention is all you need. arXiv preprint arXiv:1706.03762, 2017."
+            ),
+            RefKind::Preprint
+        );
+        assert_eq!(
+            classify_citation("J. Doe. Cell atlas of the fly brain. bioRxiv, 2021."),
+            RefKind::Preprint
+        );
+        assert_eq!(
+            classify_citation("J. Doe. Some paper. doi:10.48550/arXiv.2101.00001"),
+            RefKind::Preprint
+        );
+    }
+
+    #[test]
+    fn test_book() {
+        assert_eq!(
+            classify_citation(
+                "C. M. Bishop. Pattern Recognition and Machine Learning. Springer, 2006. ISBN 978-0-387-31073-2."
+            ),
+            RefKind::Book
+        );
+        assert_eq!(
+            classify_citation(
+                "R. S. Sutton and A. G. Barto. Reinforcement Learning: An Introduction, 2nd edition. MIT Press, 2018."
+            ),
+            RefKind::Book
+        );
+        assert_eq!(
+            classify_citation(
+                "I. Goodfellow, Y. Bengio, and A. Courville. Deep Learning. Cambridge University Press, 2016."
+            ),
+            RefKind::Book
+        );
+    }
+
+    #[test]
+    fn test_conference() {
+        assert_eq!(
+            classify_citation(
+                "K. He, X. Zhang, S. Ren, and J. Sun. Deep residual learning for image recognition. In Proceedings of the IEEE Conference on Computer Vision and Pattern Recognition, 2016."
+            ),
+            RefKind::Conference
+        );
+        assert_eq!(
+            classify_citation(
+                "N. Carlini and D. Wagner. Towards evaluating the robustness of neural networks. In 2017 IEEE Symposium on Security and Privacy, 2017."
+            ),
+            RefKind::Conference
+        );
+    }
+
+    #[test]
+    fn test_journal() {
+        assert_eq!(
+            classify_citation(
+                "Y. LeCun, Y. Bengio, and G. Hinton. Deep learning. Nature, 521(7553):436-444, 2015."
+            ),
+            RefKind::Journal
+        );
+        assert_eq!(
+            classify_citation(
+                "D. Silver et al. Mastering the game of Go. Journal of Machine Learning Research, vol. 17, 2016."
+            ),
+            RefKind::Journal
+        );
+    }
+
+    #[test]
+    fn test_web() {
+        assert_eq!(
+            classify_citation(
+                "OpenAI. GPT-4 system card. https://openai.com/research/gpt-4, 2023."
+            ),
+            RefKind::Web
+        );
+        assert_eq!(
+            classify_citation(
+                "Python Software Foundation. Python documentation. Accessed 2024-01-10."
+            ),
+            RefKind::Web
+        );
+    }
+
+    #[test]
+    fn test_doi_url_is_not_web() {
+        assert_eq!(
+            classify_citation("J. Doe. A study of things. https://doi.org/10.1234/abcd"),
+            RefKind::Unknown
+        );
+    }
+
+    #[test]
+    fn test_unknown() {
+        assert_eq!(
+            classify_citation("J. Doe. A study of things, 2020."),
+            RefKind::Unknown
+        );
+    }
+
+    #[test]
+    fn test_preferred_dbs_sort_first() {
+        let mut dbs = ["OpenAlex", "DBLP", "arXiv", "CrossRef"];
+        dbs.sort_by_key(|db| RefKind::Preprint.db_rank(db));
+        assert_eq!(dbs, ["arXiv", "OpenAlex", "DBLP", "CrossRef"]);
+        dbs.sort_by_key(|db| RefKind::Conference.db_rank(db));
+        assert_eq!(dbs[0], "DBLP");
+
+        let before = dbs;
+        dbs.sort_by_key(|db| RefKind::Unknown.db_rank(db));
+        assert_eq!(dbs, before);
+    }
+
+    fn reference(raw: &str, arxiv_id: Option<&str>) -> crate::Reference {
+        crate::Reference {
+            raw_citation: raw.into(),
+            title: Some("Learning Low-Level Vision".into()),
+            authors: vec![],
+            doi: None,
+            arxiv_id: arxiv_id.map(Into::into),
+            original_number: 1,
+            skip_reason: None,
+            kind: classify_citation(raw),
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    #[test]
+    fn test_version_relation() {
+        let preprint = reference("Learning Low-Level Vision. arXiv preprint, 2000.", None);
+        let published = reference("Learning Low-Level Vision. IJCV 40(1):25-47, 2000.", None);
+        let by_id = reference("Learning Low-Level Vision, 2000.", Some("0001.00001"));
+        assert_eq!(
+            version_relation(&preprint, "DBLP", None),
+            Some(VersionRelation::PublishedVersion)
+        );
+        assert_eq!(
+            version_relation(&by_id, "CrossRef", None),
+            Some(VersionRelation::PublishedVersion)
+        );
+        assert_eq!(
+            version_relation(&published, "arXiv", None),
+            Some(VersionRelation::PreprintVersion)
+        );
+        assert_eq!(version_relation(&preprint, "arXiv", None), None);
+        assert_eq!(version_relation(&published, "DBLP", None), None);
+
+        // A general database's record of a preprint
+        let arxiv_doi = Some("https://doi.org/10.48550/arXiv.0001.00001");
+        assert_eq!(
+            version_relation(&published, "CrossRef", arxiv_doi),
+            Some(VersionRelation::PreprintVersion)
+        );
+        assert_eq!(version_relation(&preprint, "CrossRef", arxiv_doi), None);
+        assert_eq!(
+            version_relation(
+                &published,
+                "Semantic Scholar",
+                Some("https://arxiv.org/abs/0001.00001")
+            ),
+            Some(VersionRelation::PreprintVersion)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -11,22 +12,47 @@ pub mod checker;
 pub mod config_file;
 pub mod db;
 pub mod doi;
+pub mod http;
+pub mod kind;
 pub mod matching;
 pub mod orchestrator;
+pub mod overrides;
 pub mod pool;
 pub mod rate_limit;
 pub mod retraction;
+pub mod run;
 pub mod text_utils;
 
 // Re-export for convenience
 pub use backend::{BackendError, PdfBackend};
-pub use cache::{DEFAULT_NEGATIVE_TTL, DEFAULT_POSITIVE_TTL, QueryCache};
-pub use orchestrator::{DbSearchResult, query_all_databases};
+pub use cache::{CacheStats, DEFAULT_NEGATIVE_TTL, DEFAULT_POSITIVE_TTL, QueryCache};
+pub use doi::{SharedDoi, find_shared_dois};
+pub use hallucinator_text::{QueryGuard, TitleScorer};
+pub use kind::{RefKind, VersionRelation, classify_citation};
+pub use orchestrator::{DbSearchResult, prefetch_batched, query_all_databases};
 pub use rate_limit::{DbQueryError, RateLimitedResult, RateLimiters};
-pub use text_utils::{extract_arxiv_id, extract_doi, get_query_words};
+pub use run::{PaperRecord, RecordStatus, ReferenceRecord, RunStats, parse_run, run_to_json};
+pub use text_utils::{extract_arxiv_id, extract_doi, get_query_words, normalize_query_title};
+
+/// Default margin below an offline DB's threshold within which the best
+/// candidate is reported as a near miss.
+pub const DEFAULT_NEAR_MISS_MARGIN: f64 = 0.05;
+
+/// Default fewest online backends that must answer before a NotFound is a
+/// confident negative (see [`Config::min_databases_queried`]).
+pub const DEFAULT_MIN_DATABASES_QUERIED: usize = 1;
+
+/// Default cap on references checked per document. Real bibliographies stay
+/// well below this; a malformed PDF can segment into tens of thousands.
+pub const DEFAULT_MAX_REFERENCES: usize = 2000;
+
+/// Default per-file deadline for PDF text extraction. Text layers come out in
+/// seconds; a malformed PDF can keep MuPDF spinning far longer.
+pub const DEFAULT_EXTRACTION_TIMEOUT: Duration = Duration::from_secs(120);
 
 /// A parsed reference extracted from a document.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reference {
     pub raw_citation: String,
     pub title: Option<String>,
@@ -37,27 +63,205 @@ pub struct Reference {
     pub original_number: usize,
     /// If set, this reference was skipped during extraction (e.g. "url_only", "short_title").
     pub skip_reason: Option<String>,
+    /// Publication kind inferred from the raw citation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: RefKind,
+    /// Enclosing book title for chapters in an edited collection
+    /// ("Chapter. In: Editor (ed.), Book Title"); for .bib/.bbl input, the
+    /// entry's `booktitle`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub container_title: Option<String>,
+    /// LaTeX cite key of the source entry (`\bibitem{key}`, `@article{key,`),
+    /// for mapping results back to the document. `None` for PDF references.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cite_key: Option<String>,
+    /// Whether the document body cites this reference, when
+    /// [`Config::check_in_text_citations`] asked extraction to look; `None`
+    /// when it wasn't checked or the citation style wasn't recognized.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cited_in_text: Option<bool>,
+}
+
+impl Reference {
+    /// A checkable reference with the given title, for callers building
+    /// references themselves rather than extracting them from a document.
+    ///
+    /// The raw citation defaults to the title and the reference is numbered
+    /// 1; chain the setters below to fill in the rest.
+    ///
+    /// ```no_run
+    /// use hallucinator_core::{Config, Reference, Status, check_references};
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let refs = vec![
+    ///     Reference::new("Attention Is All You Need")
+    ///         .authors(["Ashish Vaswani", "Noam Shazeer"])
+    ///         .arxiv_id("1706.03762"),
+    ///     Reference::new("Deep Residual Learning for Image Recognition")
+    ///         .authors(["Kaiming He"])
+    ///         .doi("10.1109/CVPR.2016.90")
+    ///         .original_number(2),
+    /// ];
+    /// let results = check_references(refs, Config::default(), |_| {}, CancellationToken::new())
+    ///     .await
+    ///     .expect("HTTP client");
+    /// for result in &results {
+    ///     if result.status != Status::Verified {
+    ///         println!("could not verify: {}", result.title);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn new(title: impl Into<String>) -> Self {
+        let title = title.into();
+        Self {
+            raw_citation: title.clone(),
+            title: Some(title),
+            authors: vec![],
+            doi: None,
+            arxiv_id: None,
+            original_number: 1,
+            skip_reason: None,
+            kind: RefKind::Unknown,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    /// Set the author names, as cited.
+    pub fn authors(mut self, authors: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.authors = authors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the DOI, which is resolved alongside the title search.
+    pub fn doi(mut self, doi: impl Into<String>) -> Self {
+        self.doi = Some(doi.into());
+        self
+    }
+
+    /// Set the arXiv identifier (e.g. `1706.03762`).
+    pub fn arxiv_id(mut self, arxiv_id: impl Into<String>) -> Self {
+        self.arxiv_id = Some(arxiv_id.into());
+        self
+    }
+
+    /// Set the full citation text shown in reports, in place of the title.
+    pub fn raw_citation(mut self, raw_citation: impl Into<String>) -> Self {
+        self.raw_citation = raw_citation.into();
+        self
+    }
+
+    /// Set the 1-based position in the caller's reference list.
+    pub fn original_number(mut self, original_number: usize) -> Self {
+        self.original_number = original_number;
+        self
+    }
+
+    /// Stable cross-run identity; see [`matching::reference_fingerprint`].
+    pub fn fingerprint(&self) -> String {
+        matching::reference_fingerprint(self.title.as_deref().unwrap_or(""), &self.authors)
+    }
 }
 
 /// Statistics about references that were skipped during extraction.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SkipStats {
     pub url_only: usize,
     pub short_title: usize,
+    /// Unfilled placeholders such as "[CITATION NEEDED]" or "Author et al., Year".
+    pub placeholder: usize,
     pub no_title: usize,
     pub no_authors: usize,
     pub total_raw: usize,
+    /// References dropped because the document exceeded
+    /// [`Config::max_references`]. They are not in `references` at all.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub truncated: usize,
+}
+
+impl SkipStats {
+    /// References extracted but not checked: URL-only, short-title,
+    /// placeholder and untitled entries. `no_authors` references are still
+    /// checked and `truncated` ones were never extracted, so neither counts.
+    pub fn skipped(&self) -> usize {
+        self.url_only + self.short_title + self.placeholder + self.no_title
+    }
+
+    /// [`skipped`](Self::skipped) as a fraction of
+    /// [`total_raw`](Self::total_raw); `0.0` when nothing was found.
+    pub fn skipped_fraction(&self) -> f64 {
+        if self.total_raw == 0 {
+            0.0
+        } else {
+            self.skipped() as f64 / self.total_raw as f64
+        }
+    }
 }
 
 /// Result of extracting references from a document.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtractionResult {
     pub references: Vec<Reference>,
     pub skip_stats: SkipStats,
 }
 
+impl ExtractionResult {
+    /// Concatenate several extraction results (e.g. the chapters of a book
+    /// extracted separately) into one.
+    ///
+    /// References keep their order and are renumbered `1..=n` so
+    /// `original_number` stays unique; skip statistics are summed.
+    pub fn merge(others: Vec<ExtractionResult>) -> ExtractionResult {
+        Self::merge_with(others, false)
+    }
+
+    /// Like [`merge`](Self::merge), but keeps only the first reference with
+    /// a given [`fingerprint`](Reference::fingerprint), so a paper cited in
+    /// several parts is checked once. Untitled references are never dropped.
+    pub fn merge_deduplicated(others: Vec<ExtractionResult>) -> ExtractionResult {
+        Self::merge_with(others, true)
+    }
+
+    fn merge_with(others: Vec<ExtractionResult>, dedup: bool) -> ExtractionResult {
+        let mut references = Vec::new();
+        let mut skip_stats = SkipStats::default();
+        let mut seen = HashSet::new();
+        for result in others {
+            let stats = result.skip_stats;
+            skip_stats.url_only += stats.url_only;
+            skip_stats.short_title += stats.short_title;
+            skip_stats.placeholder += stats.placeholder;
+            skip_stats.no_title += stats.no_title;
+            skip_stats.no_authors += stats.no_authors;
+            skip_stats.total_raw += stats.total_raw;
+            skip_stats.truncated += stats.truncated;
+
+            references.extend(result.references.into_iter().filter(|r| {
+                !dedup
+                    || r.title.as_deref().is_none_or(str::is_empty)
+                    || seen.insert(r.fingerprint())
+            }));
+        }
+        for (i, reference) in references.iter_mut().enumerate() {
+            reference.original_number = i + 1;
+        }
+        ExtractionResult {
+            references,
+            skip_stats,
+        }
+    }
+}
+
 /// Status of a single database query within an orchestrator run.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DbStatus {
     Match,
     NoMatch,
@@ -71,15 +275,40 @@ pub enum DbStatus {
 
 /// Result from querying a single database backend.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DbResult {
     pub db_name: String,
     pub status: DbStatus,
+    /// Serialized as whole milliseconds (`elapsed_ms`), as in the run schema.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "elapsed_ms", with = "duration_ms", default)
+    )]
     pub elapsed: Option<Duration>,
     pub found_authors: Vec<String>,
     pub paper_url: Option<String>,
     pub error_message: Option<String>,
 }
 
+/// `Option<Duration>` as optional whole milliseconds.
+#[cfg(feature = "serde")]
+mod duration_ms {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match d {
+            Some(d) => s.serialize_some(&(d.as_millis() as u64)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(d)?.map(Duration::from_millis))
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum CoreError {
     #[error("HTTP request error: {0}")]
@@ -92,10 +321,42 @@ pub enum CoreError {
     OpenAlex(#[from] hallucinator_openalex::OpenAlexError),
     #[error("validation error: {0}")]
     Validation(String),
+    #[error("document has {found} references, more than the limit of {limit}")]
+    TooManyReferences { found: usize, limit: usize },
+    #[error("override file error: {0}")]
+    Overrides(String),
+    #[error("HTTP client setup error: {0}")]
+    HttpClient(String),
+}
+
+/// What to do when a document yields more than [`Config::max_references`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReferenceLimitAction {
+    /// Keep the first `max_references` and record the rest in
+    /// [`SkipStats::truncated`].
+    #[default]
+    Truncate,
+    /// Refuse the document with [`CoreError::TooManyReferences`].
+    Abort,
+}
+
+/// How much of a reference [`Config::mode`] asks the checker to verify.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VerificationMode {
+    /// Match the title, then compare the cited authors with the matched
+    /// record's; disagreeing authors give [`Status::AuthorMismatch`].
+    #[default]
+    Full,
+    /// Only check that the paper exists: a title match is
+    /// [`Status::Verified`] whatever its authors, so
+    /// [`Status::AuthorMismatch`] is never produced.
+    ExistenceOnly,
 }
 
 /// The validation status of a reference.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Status {
     Verified,
     NotFound,
@@ -103,23 +364,62 @@ pub enum Status {
 }
 
 /// Information about a DOI lookup.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoiInfo {
     pub doi: String,
+    /// The DOI resolved to metadata.
     pub valid: bool,
     pub title: Option<String>,
+    /// The DOI resolves, but to a paper whose title diverges from the cited
+    /// one (`title` holds the resolved title). A common sign of a fabricated
+    /// citation pairing a real DOI with the wrong paper.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title_mismatch: bool,
+    /// The DOI has the `10.<registrant>/<suffix>` shape.
+    #[cfg_attr(feature = "serde", serde(default = "default_true"))]
+    pub well_formed: bool,
+    /// `false` when the resolver reported no record of the DOI. A failed
+    /// lookup (timeout, server error) leaves this `true`. A well-formed but
+    /// unregistered DOI is a strong sign of an invented citation.
+    #[cfg_attr(feature = "serde", serde(default = "default_true"))]
+    pub registered: bool,
+}
+
+#[cfg(feature = "serde")]
+fn default_true() -> bool {
+    true
+}
+
+/// A DOI and a title search that name different papers, recorded with
+/// [`Config::cross_validate_doi`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DoiConflict {
+    pub doi: String,
+    /// Title the DOI resolves to.
+    pub doi_title: String,
+    /// Database whose title search matched the reference.
+    pub matched_source: String,
+    /// Title of the record that title search matched.
+    pub matched_title: String,
 }
 
 /// Information about an arXiv lookup.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArxivInfo {
     pub arxiv_id: String,
     pub valid: bool,
     pub title: Option<String>,
+    /// DOI of the published version of the preprint, as registered on arXiv.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub published_doi: Option<String>,
 }
 
 /// Information about a retraction check.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RetractionInfo {
     pub is_retracted: bool,
     pub retraction_doi: Option<String>,
@@ -128,6 +428,7 @@ pub struct RetractionInfo {
 
 /// The result of validating a single reference.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationResult {
     pub title: String,
     pub raw_citation: String,
@@ -141,11 +442,117 @@ pub struct ValidationResult {
     pub doi_info: Option<DoiInfo>,
     pub arxiv_info: Option<ArxivInfo>,
     pub retraction_info: Option<RetractionInfo>,
+    /// Closest offline candidate `(title, score)` when the reference was not
+    /// found but narrowly missed the match threshold.
+    pub near_miss: Option<(String, f64)>,
+    /// Each reference author paired with whether it was found in the matched
+    /// record's author list; empty when no record with authors was matched.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub author_match_detail: Vec<(String, bool)>,
+    /// The checked reference's [`Reference::cite_key`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cite_key: Option<String>,
+    /// Set when [`Config::cross_validate_doi`] found the DOI and the title
+    /// search pointing at different papers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub doi_conflict: Option<DoiConflict>,
+    /// Set when [`Config::accept_preprint_versions`] verified the reference
+    /// against another version (preprint or published) of the cited work.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub version_relation: Option<VersionRelation>,
+    /// How many backends independently matched the reference: the distinct
+    /// [`db_results`](Self::db_results) entries with [`DbStatus::Match`].
+    ///
+    /// A lower bound, not a consensus count: once one backend verifies the
+    /// reference, queries still queued on the others are skipped, so only
+    /// those already in flight can add to it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub verified_by_count: usize,
+    /// Set on a NotFound result when fewer than
+    /// [`Config::min_databases_queried`] online backends answered (the rest
+    /// timed out, errored, were rate limited, skipped or disabled), so the
+    /// negative rests on too little evidence to be trusted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub insufficient_coverage: bool,
+    /// The checked reference's [`Reference::cited_in_text`]; `Some(false)`
+    /// marks a reference that is listed but never cited.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cited_in_text: Option<bool>,
+}
+
+impl ValidationResult {
+    /// Fingerprint of the checked reference, equal to [`Reference::fingerprint`]
+    /// for the reference it came from.
+    pub fn fingerprint(&self) -> String {
+        matching::reference_fingerprint(&self.title, &self.ref_authors)
+    }
+
+    /// Recompute [`author_match_detail`](Self::author_match_detail) from
+    /// `ref_authors` and `found_authors`.
+    pub(crate) fn fill_author_match_detail(&mut self) {
+        self.author_match_detail =
+            authors::author_match_detail(&self.ref_authors, &self.found_authors);
+    }
+
+    /// Recompute [`verified_by_count`](Self::verified_by_count) from
+    /// `db_results`.
+    pub(crate) fn fill_verified_by_count(&mut self) {
+        self.verified_by_count = verified_by_count(&self.db_results);
+    }
+
+    /// How many distinct backends in [`db_results`](Self::db_results)
+    /// answered the query: matched, found no match, or matched with
+    /// different authors.
+    pub fn databases_answered(&self) -> usize {
+        self.answered_db_names().len()
+    }
+
+    fn answered_db_names(&self) -> HashSet<&str> {
+        self.db_results
+            .iter()
+            .filter(|r| {
+                matches!(
+                    r.status,
+                    DbStatus::Match | DbStatus::NoMatch | DbStatus::AuthorMismatch
+                )
+            })
+            .map(|r| r.db_name.as_str())
+            .collect()
+    }
+
+    /// Recompute [`insufficient_coverage`](Self::insufficient_coverage)
+    /// against `config.min_databases_queried`. Answers from offline
+    /// databases don't count: they say nothing about whether the online
+    /// backends could be reached.
+    pub(crate) fn fill_insufficient_coverage(&mut self, config: &Config) {
+        let online_answered = self
+            .answered_db_names()
+            .into_iter()
+            .filter(|name| !config.is_offline_db(name))
+            .count();
+        self.insufficient_coverage =
+            self.status == Status::NotFound && online_answered < config.min_databases_queried;
+    }
+}
+
+/// Number of distinct backends in `db_results` that matched.
+pub(crate) fn verified_by_count(db_results: &[DbResult]) -> usize {
+    db_results
+        .iter()
+        .filter(|r| r.status == DbStatus::Match)
+        .map(|r| r.db_name.as_str())
+        .collect::<HashSet<_>>()
+        .len()
 }
 
 /// Progress events emitted during validation.
 #[derive(Debug, Clone)]
 pub enum ProgressEvent {
+    /// The references about to be checked, sent once before any other event
+    /// so a UI can list them as pending while validation runs.
+    Extracted {
+        references: Vec<Reference>,
+    },
     Checking {
         index: usize,
         total: usize,
@@ -173,6 +580,15 @@ pub enum ProgressEvent {
         title: String,
         failed_dbs: Vec<String>,
     },
+    /// A backend is about to be queried for a reference. The matching
+    /// `DatabaseQueryComplete` (same `ref_index` and `db_name`) follows when
+    /// the query returns; backends skipped without a query only report
+    /// completion.
+    DatabaseQueryStarted {
+        paper_index: usize,
+        ref_index: usize,
+        db_name: String,
+    },
     DatabaseQueryComplete {
         paper_index: usize,
         ref_index: usize,
@@ -194,6 +610,7 @@ pub enum ProgressEvent {
 
 /// Summary statistics for a complete check run.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CheckStats {
     pub total: usize,
     pub verified: usize,
@@ -221,6 +638,17 @@ pub struct Config {
     pub check_openalex_authors: bool,
     pub crossref_mailto: Option<String>,
     pub max_rate_limit_retries: u32,
+    /// How many times [`checker::check_references`] re-checks references
+    /// that ended unverified because a backend timed out or errored, each
+    /// pass querying only the backends that failed. Default: 0.
+    pub retry_passes: u32,
+    /// Fewest online backends that must answer a reference's query (match
+    /// or no match, rather than time out, error, hit a rate limit or be
+    /// skipped) for NotFound to be a confident negative; offline databases
+    /// don't count. With fewer, the result is flagged
+    /// [`insufficient_coverage`](ValidationResult::insufficient_coverage).
+    /// Default: [`DEFAULT_MIN_DATABASES_QUERIED`].
+    pub min_databases_queried: usize,
     pub rate_limiters: Arc<RateLimiters>,
     /// SearxNG base URL for web search fallback (e.g., "http://localhost:8080").
     /// If set, SearxNG will be queried as a fallback when a reference is not found
@@ -234,6 +662,148 @@ pub struct Config {
     pub cache_positive_ttl_secs: u64,
     /// TTL in seconds for negative (not-found) cache entries. Default: 24 hours.
     pub cache_negative_ttl_secs: u64,
+    /// How far below an offline DB's match threshold the best candidate may
+    /// score and still be reported as a near miss. Default: 0.05.
+    pub near_miss_margin: f64,
+    /// Global similarity threshold for offline DB title matching (e.g. from
+    /// `--threshold`). `None` keeps each database's own `DEFAULT_THRESHOLD`.
+    pub match_threshold: Option<f64>,
+    /// Per-database similarity thresholds keyed by DB name (e.g. "DBLP").
+    /// An explicit entry here wins over `match_threshold`.
+    pub thresholds: HashMap<String, f64>,
+    /// How offline DBs score candidate titles against the cited one; the
+    /// thresholds above apply to its score. Default: [`TitleScorer::Ratio`].
+    pub title_scorer: TitleScorer,
+    /// How the offline DBs treat short and generic titles: queries with too
+    /// few distinctive words are skipped, and those with only a couple must
+    /// reach [`QueryGuard::short_query_threshold`] (default 0.97) whatever
+    /// the configured threshold.
+    pub query_guard: QueryGuard,
+    /// HTTP client shared by every remote query in the run. Clones share one
+    /// connection pool, so set this once when checking several papers with
+    /// the same config. `None` lets [`checker::check_references`] build one.
+    pub http_client: Option<reqwest::Client>,
+    /// Proxy URL (`http://host:port` or `https://…`, optionally with
+    /// `user:pass@`) for every remote query. Without one, the standard
+    /// `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY` environment variables apply.
+    pub http_proxy: Option<String>,
+    /// PEM file of extra CA certificates to trust alongside the built-in
+    /// roots, e.g. for a TLS-intercepting corporate proxy.
+    pub extra_ca_cert: Option<PathBuf>,
+    /// Most references to check per document; `None` disables the guard.
+    /// Default: [`DEFAULT_MAX_REFERENCES`].
+    pub max_references: Option<usize>,
+    /// Whether an over-long reference list is truncated or rejected.
+    pub max_references_action: ReferenceLimitAction,
+    /// Per-file deadline for PDF text extraction; `None` waits indefinitely.
+    /// Default: [`DEFAULT_EXTRACTION_TIMEOUT`].
+    pub extraction_timeout: Option<Duration>,
+    /// Scan the document body for in-text citation markers during
+    /// extraction, filling [`Reference::cited_in_text`]. Only PDF inputs
+    /// have a body to scan. Default: false.
+    pub check_in_text_citations: bool,
+    /// Detect short references set two-up within a PDF text block and read
+    /// them column by column (MuPDF backend only). Default: false.
+    pub pdf_column_detection: bool,
+    /// Title/fingerprint → DOI override file (see [`overrides`]).
+    pub overrides_path: Option<PathBuf>,
+    /// Overrides loaded from `overrides_path`, shared across the run. `None`
+    /// lets [`checker::check_references`] load them from the path.
+    pub overrides: Option<Arc<overrides::Overrides>>,
+    /// Verify a reference as soon as its DOI resolves to the cited title
+    /// (and authors, when both list them), without title-searching any other
+    /// database. Default: false.
+    pub trust_resolved_doi: bool,
+    /// For references with a DOI, run both the DOI lookup and the title
+    /// search, even when one of them settles the reference, and record a
+    /// [`DoiConflict`] when they name different papers. A reference the DOI
+    /// verifies is still title-searched, by one database only. Takes precedence
+    /// over [`trust_resolved_doi`](Config::trust_resolved_doi). Default: false.
+    pub cross_validate_doi: bool,
+    /// Verify a title match whose authors disagree when one side is a
+    /// preprint and the other a published record (the cited arXiv paper
+    /// matched its conference version, or vice versa), as long as the author
+    /// surnames still overlap. The result records the [`VersionRelation`].
+    /// Default: false.
+    pub accept_preprint_versions: bool,
+    /// Backends (e.g. "DBLP") whose match settles a reference outright: once
+    /// one of them verifies it, queries still queued or in flight on the
+    /// other backends are cancelled and the trusted backend is reported as
+    /// the source, and the DOI cross-check of
+    /// [`cross_validate_doi`](Config::cross_validate_doi) is skipped.
+    /// Default: empty.
+    pub trusted_verifiers: Vec<String>,
+    /// Whether matches are checked for authors as well as titles.
+    /// Default: [`VerificationMode::Full`].
+    pub mode: VerificationMode,
+}
+
+impl Config {
+    /// Apply the [`max_references`](Config::max_references) guard to a fresh
+    /// extraction, truncating it or returning an error per
+    /// [`max_references_action`](Config::max_references_action).
+    pub fn enforce_reference_limit(
+        &self,
+        extraction: &mut ExtractionResult,
+    ) -> Result<(), CoreError> {
+        let found = extraction.references.len();
+        let Some(limit) = self.max_references.filter(|&limit| found > limit) else {
+            return Ok(());
+        };
+        match self.max_references_action {
+            ReferenceLimitAction::Abort => Err(CoreError::TooManyReferences { found, limit }),
+            ReferenceLimitAction::Truncate => {
+                tracing::warn!(
+                    found,
+                    limit,
+                    "too many references, checking only the first {}",
+                    limit
+                );
+                extraction.references.truncate(limit);
+                extraction.skip_stats.truncated += found - limit;
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether `db_name` is answered from a local database rather than
+    /// queried online.
+    pub(crate) fn is_offline_db(&self, db_name: &str) -> bool {
+        match db_name {
+            "DBLP" => self.dblp_offline_db.is_some(),
+            "ACL Anthology" => self.acl_offline_db.is_some(),
+            "OpenAlex" => self.openalex_offline_db.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Effective offline match threshold for `db_name`: an explicit per-DB
+    /// entry, then the global override, then the database's `default`.
+    pub fn threshold_for(&self, db_name: &str, default: f64) -> f64 {
+        self.thresholds
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(db_name))
+            .map(|(_, t)| *t)
+            .or(self.match_threshold)
+            .unwrap_or(default)
+    }
+
+    /// Whether `db_name` is one of the [`trusted_verifiers`](Config::trusted_verifiers).
+    pub fn is_trusted_verifier(&self, db_name: &str) -> bool {
+        self.trusted_verifiers
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(db_name))
+    }
+
+    /// The cited authors to compare against a database's match: `authors`,
+    /// or none in [`VerificationMode::ExistenceOnly`]. Every author check
+    /// treats an empty list as agreeing with any record.
+    pub(crate) fn authors_to_compare<'a>(&self, authors: &'a [String]) -> &'a [String] {
+        match self.mode {
+            VerificationMode::Full => authors,
+            VerificationMode::ExistenceOnly => &[],
+        }
+    }
 }
 
 impl std::fmt::Debug for Config {
@@ -266,6 +836,8 @@ impl std::fmt::Debug for Config {
                 &self.crossref_mailto.as_ref().map(|_| "***"),
             )
             .field("max_rate_limit_retries", &self.max_rate_limit_retries)
+            .field("retry_passes", &self.retry_passes)
+            .field("min_databases_queried", &self.min_databases_queried)
             .field("searxng_url", &self.searxng_url)
             .field(
                 "query_cache",
@@ -274,6 +846,35 @@ impl std::fmt::Debug for Config {
             .field("cache_path", &self.cache_path)
             .field("cache_positive_ttl_secs", &self.cache_positive_ttl_secs)
             .field("cache_negative_ttl_secs", &self.cache_negative_ttl_secs)
+            .field("near_miss_margin", &self.near_miss_margin)
+            .field("match_threshold", &self.match_threshold)
+            .field("thresholds", &self.thresholds)
+            .field("title_scorer", &self.title_scorer)
+            .field("query_guard", &self.query_guard)
+            .field(
+                "http_client",
+                &self.http_client.as_ref().map(|_| "<shared>"),
+            )
+            .field("http_proxy", &self.http_proxy.as_ref().map(|_| "***"))
+            .field("extra_ca_cert", &self.extra_ca_cert)
+            .field("max_references", &self.max_references)
+            .field("max_references_action", &self.max_references_action)
+            .field("extraction_timeout", &self.extraction_timeout)
+            .field("check_in_text_citations", &self.check_in_text_citations)
+            .field("pdf_column_detection", &self.pdf_column_detection)
+            .field("overrides_path", &self.overrides_path)
+            .field(
+                "overrides",
+                &self
+                    .overrides
+                    .as_ref()
+                    .map(|o| format!("<{} entries>", o.len())),
+            )
+            .field("trust_resolved_doi", &self.trust_resolved_doi)
+            .field("cross_validate_doi", &self.cross_validate_doi)
+            .field("accept_preprint_versions", &self.accept_preprint_versions)
+            .field("trusted_verifiers", &self.trusted_verifiers)
+            .field("mode", &self.mode)
             .finish()
     }
 }
@@ -296,12 +897,34 @@ impl Default for Config {
             check_openalex_authors: false,
             crossref_mailto: None,
             max_rate_limit_retries: 3,
+            retry_passes: 0,
+            min_databases_queried: DEFAULT_MIN_DATABASES_QUERIED,
             rate_limiters: Arc::new(RateLimiters::default()),
             searxng_url: None,
+            http_client: None,
+            http_proxy: None,
+            extra_ca_cert: None,
             query_cache: Some(Arc::new(QueryCache::default())),
             cache_path: None,
             cache_positive_ttl_secs: DEFAULT_POSITIVE_TTL.as_secs(),
             cache_negative_ttl_secs: DEFAULT_NEGATIVE_TTL.as_secs(),
+            near_miss_margin: DEFAULT_NEAR_MISS_MARGIN,
+            match_threshold: None,
+            thresholds: HashMap::new(),
+            title_scorer: TitleScorer::default(),
+            query_guard: QueryGuard::default(),
+            max_references: Some(DEFAULT_MAX_REFERENCES),
+            max_references_action: ReferenceLimitAction::Truncate,
+            extraction_timeout: Some(DEFAULT_EXTRACTION_TIMEOUT),
+            check_in_text_citations: false,
+            pdf_column_detection: false,
+            overrides_path: None,
+            overrides: None,
+            trust_resolved_doi: false,
+            cross_validate_doi: false,
+            accept_preprint_versions: false,
+            trusted_verifiers: vec![],
+            mode: VerificationMode::Full,
         }
     }
 }
@@ -309,14 +932,20 @@ impl Default for Config {
 /// Build a [`QueryCache`] from configuration.
 ///
 /// If `cache_path` is set, opens a persistent SQLite-backed cache.
-/// Otherwise, returns an in-memory-only cache.
+/// Otherwise, returns an in-memory-only cache. `max_entries` caps it with
+/// [`QueryCache::with_max_entries`].
 pub fn build_query_cache(
     cache_path: Option<&std::path::Path>,
     positive_ttl_secs: u64,
     negative_ttl_secs: u64,
+    max_entries: Option<usize>,
 ) -> Arc<QueryCache> {
     let positive_ttl = Duration::from_secs(positive_ttl_secs);
     let negative_ttl = Duration::from_secs(negative_ttl_secs);
+    let cap = |cache: QueryCache| match max_entries {
+        Some(max) => cache.with_max_entries(max),
+        None => cache,
+    };
     if let Some(path) = cache_path {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
@@ -325,30 +954,293 @@ pub fn build_query_cache(
         match QueryCache::open(path, positive_ttl, negative_ttl) {
             Ok(cache) => {
                 tracing::info!(path = %path.display(), "opened persistent cache");
-                return Arc::new(cache);
+                return Arc::new(cap(cache));
             }
             Err(e) => {
                 tracing::warn!(path = %path.display(), error = %e, "failed to open cache, falling back to in-memory");
             }
         }
     }
-    Arc::new(QueryCache::new(positive_ttl, negative_ttl))
+    Arc::new(cap(QueryCache::new(positive_ttl, negative_ttl)))
 }
 
 /// Check a list of references against academic databases.
 ///
 /// Validates each reference concurrently, querying multiple databases in parallel.
 /// Progress events are emitted via the callback. The operation can be cancelled
-/// via the CancellationToken.
+/// via the CancellationToken. Fails with [`CoreError::HttpClient`] if the
+/// configured proxy or CA certificate is invalid.
 pub async fn check_references(
     refs: Vec<Reference>,
     config: Config,
     progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
     cancel: CancellationToken,
-) -> Vec<ValidationResult> {
+) -> Result<Vec<ValidationResult>, CoreError> {
     checker::check_references(refs, config, progress, cancel).await
 }
 
+#[cfg(test)]
+mod reference_builder_tests {
+    use super::*;
+
+    #[test]
+    fn builder_fills_defaults_and_setters() {
+        let reference = Reference::new("Attention Is All You Need");
+        assert_eq!(
+            reference.title.as_deref(),
+            Some("Attention Is All You Need")
+        );
+        assert_eq!(reference.raw_citation, "Attention Is All You Need");
+        assert_eq!(reference.original_number, 1);
+        assert!(reference.authors.is_empty());
+        assert!(reference.skip_reason.is_none());
+
+        let reference = reference
+            .authors(["Ashish Vaswani", "Noam Shazeer"])
+            .doi("10.5555/3295222.3295349")
+            .arxiv_id("1706.03762")
+            .raw_citation("[3] A. Vaswani et al. Attention Is All You Need. NeurIPS 2017.")
+            .original_number(3);
+        assert_eq!(reference.authors, ["Ashish Vaswani", "Noam Shazeer"]);
+        assert_eq!(reference.doi.as_deref(), Some("10.5555/3295222.3295349"));
+        assert_eq!(reference.arxiv_id.as_deref(), Some("1706.03762"));
+        assert!(reference.raw_citation.starts_with("[3] A. Vaswani"));
+        assert_eq!(reference.original_number, 3);
+    }
+}
+
+#[cfg(test)]
+mod skip_stats_tests {
+    use super::*;
+
+    #[test]
+    fn skipped_fraction_counts_unchecked_references() {
+        let stats = SkipStats {
+            url_only: 1,
+            short_title: 2,
+            placeholder: 1,
+            no_title: 1,
+            no_authors: 3,
+            total_raw: 20,
+            truncated: 4,
+        };
+        assert_eq!(stats.skipped(), 5);
+        assert_eq!(stats.skipped_fraction(), 0.25);
+        assert_eq!(SkipStats::default().skipped_fraction(), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod merge_tests {
+    use super::*;
+
+    fn extraction(titles: &[&str], stats: SkipStats) -> ExtractionResult {
+        let references = titles
+            .iter()
+            .enumerate()
+            .map(|(i, title)| {
+                Reference::new(*title)
+                    .authors(["Ada Lovelace"])
+                    .original_number(i + 1)
+            })
+            .collect();
+        ExtractionResult {
+            references,
+            skip_stats: stats,
+        }
+    }
+
+    fn titles(result: &ExtractionResult) -> Vec<(usize, &str)> {
+        result
+            .references
+            .iter()
+            .map(|r| (r.original_number, r.title.as_deref().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn merges_renumbers_and_dedups_overlap() {
+        let parts = || {
+            vec![
+                extraction(
+                    &["Notes on the Analytical Engine", "Sketch of the Engine"],
+                    SkipStats {
+                        url_only: 1,
+                        total_raw: 3,
+                        ..Default::default()
+                    },
+                ),
+                extraction(
+                    // Same paper as above, differently cased and punctuated.
+                    &["Sketch of the engine.", "A Third Paper"],
+                    SkipStats {
+                        no_authors: 2,
+                        total_raw: 2,
+                        ..Default::default()
+                    },
+                ),
+            ]
+        };
+
+        let merged = ExtractionResult::merge(parts());
+        assert_eq!(
+            titles(&merged),
+            [
+                (1, "Notes on the Analytical Engine"),
+                (2, "Sketch of the Engine"),
+                (3, "Sketch of the engine."),
+                (4, "A Third Paper"),
+            ]
+        );
+        assert_eq!(merged.skip_stats.url_only, 1);
+        assert_eq!(merged.skip_stats.no_authors, 2);
+        assert_eq!(merged.skip_stats.total_raw, 5);
+
+        let merged = ExtractionResult::merge_deduplicated(parts());
+        assert_eq!(
+            titles(&merged),
+            [
+                (1, "Notes on the Analytical Engine"),
+                (2, "Sketch of the Engine"),
+                (3, "A Third Paper"),
+            ]
+        );
+        assert_eq!(merged.skip_stats.total_raw, 5);
+    }
+
+    #[test]
+    fn merging_nothing_is_empty() {
+        let merged = ExtractionResult::merge(vec![]);
+        assert!(merged.references.is_empty());
+        assert_eq!(merged.skip_stats.total_raw, 0);
+    }
+}
+
+#[cfg(test)]
+mod verified_by_count_tests {
+    use super::*;
+
+    fn db(name: &str, status: DbStatus) -> DbResult {
+        DbResult {
+            db_name: name.into(),
+            status,
+            elapsed: None,
+            found_authors: vec![],
+            paper_url: None,
+            error_message: None,
+        }
+    }
+
+    #[test]
+    fn counts_only_matching_backends() {
+        let results = [
+            db("DBLP", DbStatus::Match),
+            db("CrossRef", DbStatus::Match),
+            db("arXiv", DbStatus::NoMatch),
+            db("OpenAlex", DbStatus::AuthorMismatch),
+            db("Semantic Scholar", DbStatus::Timeout),
+            db("PubMed", DbStatus::RateLimited),
+            db("Europe PMC", DbStatus::Error),
+            db("ACL Anthology", DbStatus::Skipped),
+            // The same backend matching twice (e.g. on retry) counts once.
+            db("DBLP", DbStatus::Match),
+        ];
+        assert_eq!(verified_by_count(&results), 2);
+        assert_eq!(verified_by_count(&results[2..8]), 0);
+        assert_eq!(verified_by_count(&[]), 0);
+    }
+}
+
+#[cfg(test)]
+mod reference_limit_tests {
+    use super::*;
+
+    fn extraction(n: usize) -> ExtractionResult {
+        let references = (1..=n)
+            .map(|i| Reference {
+                raw_citation: format!("Author {i}. Paper number {i}. 2020."),
+                title: Some(format!("Paper number {i}")),
+                authors: vec![format!("Author {i}")],
+                doi: None,
+                arxiv_id: None,
+                original_number: i,
+                skip_reason: None,
+                kind: RefKind::default(),
+                container_title: None,
+                cite_key: None,
+                cited_in_text: None,
+            })
+            .collect();
+        ExtractionResult {
+            references,
+            skip_stats: SkipStats {
+                total_raw: n,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn truncates_to_configured_limit() {
+        let config = Config {
+            max_references: Some(1000),
+            ..Config::default()
+        };
+        let mut result = extraction(3000);
+        config.enforce_reference_limit(&mut result).unwrap();
+        assert_eq!(result.references.len(), 1000);
+        assert_eq!(result.references.last().unwrap().original_number, 1000);
+        assert_eq!(result.skip_stats.truncated, 2000);
+        assert_eq!(result.skip_stats.total_raw, 3000);
+    }
+
+    #[test]
+    fn default_limit_applies() {
+        let mut result = extraction(3000);
+        Config::default()
+            .enforce_reference_limit(&mut result)
+            .unwrap();
+        assert_eq!(result.references.len(), DEFAULT_MAX_REFERENCES);
+    }
+
+    #[test]
+    fn abort_returns_error_and_leaves_result_intact() {
+        let config = Config {
+            max_references: Some(1000),
+            max_references_action: ReferenceLimitAction::Abort,
+            ..Config::default()
+        };
+        let mut result = extraction(3000);
+        let err = config.enforce_reference_limit(&mut result).unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::TooManyReferences {
+                found: 3000,
+                limit: 1000
+            }
+        ));
+        assert_eq!(result.references.len(), 3000);
+    }
+
+    #[test]
+    fn within_limit_or_disabled_is_untouched() {
+        let mut result = extraction(50);
+        Config::default()
+            .enforce_reference_limit(&mut result)
+            .unwrap();
+        assert_eq!(result.references.len(), 50);
+        assert_eq!(result.skip_stats.truncated, 0);
+
+        let config = Config {
+            max_references: None,
+            ..Config::default()
+        };
+        let mut result = extraction(3000);
+        config.enforce_reference_limit(&mut result).unwrap();
+        assert_eq!(result.references.len(), 3000);
+    }
+}
+
 #[cfg(test)]
 mod build_cache_tests {
     use super::*;
@@ -373,6 +1265,7 @@ mod build_cache_tests {
             None,
             DEFAULT_POSITIVE_TTL.as_secs(),
             DEFAULT_NEGATIVE_TTL.as_secs(),
+            None,
         );
         assert!(!cache.has_persistence());
     }
@@ -386,6 +1279,7 @@ mod build_cache_tests {
             Some(&path),
             DEFAULT_POSITIVE_TTL.as_secs(),
             DEFAULT_NEGATIVE_TTL.as_secs(),
+            None,
         );
         assert!(cache.has_persistence());
 
@@ -414,6 +1308,7 @@ mod build_cache_tests {
             Some(&path),
             DEFAULT_POSITIVE_TTL.as_secs(),
             DEFAULT_NEGATIVE_TTL.as_secs(),
+            None,
         );
         assert!(cache.has_persistence());
         assert!(path.parent().unwrap().exists());
@@ -421,3 +1316,142 @@ mod build_cache_tests {
         let _ = std::fs::remove_file(&path);
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+
+    /// Serialize, deserialize, and serialize again; the two encodings must match.
+    fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> serde_json::Value {
+        let json = serde_json::to_value(value).unwrap();
+        let back: T = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+        json
+    }
+
+    fn sample_reference() -> Reference {
+        Reference {
+            raw_citation: "A. Vaswani et al. Attention is all you need. In NeurIPS, 2017.".into(),
+            title: Some("Attention is all you need".into()),
+            authors: vec!["A. Vaswani".into()],
+            doi: None,
+            arxiv_id: Some("1706.03762".into()),
+            original_number: 3,
+            skip_reason: None,
+            kind: RefKind::Conference,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    fn sample_db_result() -> DbResult {
+        DbResult {
+            db_name: "CrossRef".into(),
+            status: DbStatus::RateLimited,
+            elapsed: Some(Duration::from_millis(250)),
+            found_authors: vec![],
+            paper_url: None,
+            error_message: Some("429".into()),
+        }
+    }
+
+    #[test]
+    fn test_reference_round_trip() {
+        let json = round_trip(&sample_reference());
+        assert_eq!(json["kind"], "conference");
+        assert_eq!(json["original_number"], 3);
+    }
+
+    #[test]
+    fn test_reference_from_older_run_deserializes() {
+        let mut json = serde_json::to_value(sample_reference()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("kind");
+        fields.remove("container_title");
+        let reference: Reference = serde_json::from_value(json).unwrap();
+        assert_eq!(reference.kind, RefKind::Unknown);
+        assert_eq!(reference.container_title, None);
+    }
+
+    #[test]
+    fn test_extraction_result_round_trip() {
+        let result = ExtractionResult {
+            references: vec![sample_reference()],
+            skip_stats: SkipStats {
+                url_only: 1,
+                placeholder: 2,
+                total_raw: 4,
+                ..Default::default()
+            },
+        };
+        let json = round_trip(&result);
+        assert_eq!(json["skip_stats"]["placeholder"], 2);
+        round_trip(&result.skip_stats);
+    }
+
+    #[test]
+    fn test_db_result_round_trip() {
+        let json = round_trip(&sample_db_result());
+        assert_eq!(json["status"], "rate_limited");
+        assert_eq!(json["elapsed_ms"], 250);
+        assert!(json.get("elapsed").is_none());
+    }
+
+    #[test]
+    fn test_validation_result_round_trip() {
+        let result = ValidationResult {
+            title: "Attention is all you need".into(),
+            raw_citation: "A. Vaswani et al. Attention is all you need.".into(),
+            cite_key: None,
+            ref_authors: vec!["A. Vaswani".into()],
+            status: Status::AuthorMismatch,
+            source: Some("DBLP".into()),
+            found_authors: vec!["Ashish Vaswani".into()],
+            paper_url: Some("https://dblp.org/rec/x".into()),
+            failed_dbs: vec!["CrossRef".into()],
+            db_results: vec![sample_db_result()],
+            doi_info: None,
+            arxiv_info: Some(ArxivInfo {
+                arxiv_id: "1706.03762".into(),
+                valid: true,
+                title: None,
+                published_doi: None,
+            }),
+            retraction_info: None,
+            near_miss: Some(("Attention is not all you need".into(), 0.91)),
+            author_match_detail: vec![],
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 0,
+            insufficient_coverage: false,
+            cited_in_text: None,
+        };
+        let json = round_trip(&result);
+        assert_eq!(json["status"], "author_mismatch");
+        assert_eq!(json["near_miss"][1], 0.91);
+    }
+
+    #[test]
+    fn test_check_stats_round_trip() {
+        let stats = CheckStats {
+            total: 10,
+            verified: 7,
+            not_found: 2,
+            author_mismatch: 1,
+            retracted: 1,
+            skipped: 0,
+        };
+        let json = round_trip(&stats);
+        assert_eq!(json["not_found"], 2);
+    }
+
+    #[test]
+    fn test_enum_names() {
+        assert_eq!(round_trip(&Status::NotFound), "not_found");
+        assert_eq!(round_trip(&DbStatus::NoMatch), "no_match");
+        assert_eq!(round_trip(&RefKind::Preprint), "preprint");
+    }
+}
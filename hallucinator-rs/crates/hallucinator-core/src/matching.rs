@@ -210,6 +210,23 @@ pub fn normalize_title(title: &str) -> String {
     NON_ALNUM.replace_all(&normalized, "").to_lowercase()
 }
 
+/// Strip a leading English article ("The", "A", "An") from a title.
+///
+/// Only used as a scoring fallback: real titles often differ from database
+/// records solely by a dropped or added article.
+fn strip_leading_article(title: &str) -> &str {
+    let trimmed = title.trim_start();
+    for article in ["the ", "a ", "an "] {
+        if trimmed.len() > article.len()
+            && trimmed.is_char_boundary(article.len())
+            && trimmed[..article.len()].eq_ignore_ascii_case(article)
+        {
+            return trimmed[article.len()..].trim_start();
+        }
+    }
+    trimmed
+}
+
 /// Check if two titles match using fuzzy comparison (95% threshold).
 ///
 /// Includes conservative prefix matching: if a shorter title is a prefix of a
@@ -230,6 +247,17 @@ pub fn titles_match(title_a: &str, title_b: &str) -> bool {
         return true;
     }
 
+    // Retry ignoring a leading article on either side ("The X" vs "X")
+    let bare_a = normalize_title(strip_leading_article(title_a));
+    let bare_b = normalize_title(strip_leading_article(title_b));
+    if (bare_a != norm_a || bare_b != norm_b)
+        && !bare_a.is_empty()
+        && !bare_b.is_empty()
+        && rapidfuzz::fuzz::ratio(bare_a.chars(), bare_b.chars()) >= 0.95
+    {
+        return true;
+    }
+
     // Conservative prefix matching with subtitle awareness
     let (shorter, longer) = if norm_a.len() <= norm_b.len() {
         (&norm_a, &norm_b)
@@ -271,10 +299,61 @@ pub fn titles_match(title_a: &str, title_b: &str) -> bool {
     true
 }
 
+/// Stable identity for a reference across runs and caches.
+///
+/// Hashes the normalized title together with the first author's surname
+/// (the part before a comma in "Last, First", otherwise the last word), so
+/// whitespace, case, punctuation and diacritic differences don't change it.
+/// Uses 64-bit FNV-1a rather than `DefaultHasher`, whose output is not
+/// guaranteed stable across Rust releases. Returned as 16 hex digits.
+pub fn reference_fingerprint(title: &str, authors: &[String]) -> String {
+    let surname = authors
+        .first()
+        .map(|a| match a.split_once(',') {
+            Some((last, _)) => last,
+            None => a.split_whitespace().last().unwrap_or(""),
+        })
+        .unwrap_or("");
+    let key = format!("{}|{}", normalize_title(title), normalize_title(surname));
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in key.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // =========================================================================
+    // Fingerprints
+    // =========================================================================
+
+    #[test]
+    fn test_fingerprint_ignores_title_whitespace_and_case() {
+        let authors = vec!["Ashish Vaswani".to_string()];
+        let a = reference_fingerprint("Attention Is All You Need", &authors);
+        let b = reference_fingerprint("  attention is\nall  you NEED ", &authors);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 16);
+    }
+
+    #[test]
+    fn test_fingerprint_uses_first_author_surname() {
+        let title = "Attention Is All You Need";
+        let a = reference_fingerprint(title, &["A. Vaswani".into(), "N. Shazeer".into()]);
+        let b = reference_fingerprint(title, &["Vaswani, Ashish".into()]);
+        let c = reference_fingerprint(title, &["N. Shazeer".into()]);
+        assert_eq!(a, b);
+        // Pinned so fingerprints in saved runs stay comparable
+        assert_eq!(a, "d9cf36f8188f6e28");
+        assert_ne!(a, c);
+        assert_ne!(a, reference_fingerprint(title, &[]));
+    }
+
     // =========================================================================
     // Basic normalization
     // =========================================================================
@@ -319,6 +398,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_titles_match_ignores_leading_article() {
+        // Short titles score below 0.95 on the article alone
+        assert!(titles_match("The Case for RAID", "Case for RAID"));
+        assert!(titles_match("An Empirical Study", "Empirical Study"));
+        assert!(!titles_match("The Other Side", "Another Side"));
+    }
+
+    #[test]
+    fn test_strip_leading_article() {
+        assert_eq!(strip_leading_article("The Case for X"), "Case for X");
+        assert_eq!(
+            strip_leading_article("an Empirical Study"),
+            "Empirical Study"
+        );
+        assert_eq!(strip_leading_article("Theory of Games"), "Theory of Games");
+        assert_eq!(strip_leading_article("A"), "A");
+    }
+
     #[test]
     fn test_titles_match_empty() {
         assert!(!titles_match("", "Something"));
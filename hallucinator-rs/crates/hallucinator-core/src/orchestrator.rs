@@ -1,7 +1,7 @@
 use crate::authors::validate_authors;
-use crate::db::DatabaseBackend;
+use crate::db::{BatchLookup, DatabaseBackend};
 use crate::rate_limit;
-use crate::{Config, DbResult, DbStatus, Status};
+use crate::{Config, DbResult, DbStatus, RefKind, Reference, Status};
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
@@ -13,32 +13,43 @@ pub struct DbSearchResult {
     pub source: Option<String>,
     pub found_authors: Vec<String>,
     pub paper_url: Option<String>,
+    /// Title of the matched record, when `source` found one.
+    pub found_title: Option<String>,
     pub failed_dbs: Vec<String>,
     pub db_results: Vec<DbResult>,
     pub retraction: Option<crate::retraction::RetractionResult>,
+    /// Best offline near miss `(title, score)` seen while searching.
+    pub near_miss: Option<(String, f64)>,
 }
 
 /// Query all databases for a single reference (local first, then remote).
 ///
 /// This is a convenience wrapper that calls [`query_local_databases`] followed by
 /// [`query_remote_databases`]. For the pool's split architecture, use those
-/// functions directly.
+/// functions directly. `ref_authors` are ignored in
+/// [`VerificationMode::ExistenceOnly`](crate::VerificationMode::ExistenceOnly).
+/// Databases preferred for `kind` are consulted first.
+#[allow(clippy::too_many_arguments)]
 pub async fn query_all_databases(
     title: &str,
     ref_authors: &[String],
+    kind: RefKind,
     config: &Config,
     client: &reqwest::Client,
     longer_timeout: bool,
     only_dbs: Option<&[String]>,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
 ) -> DbSearchResult {
+    let ref_authors = config.authors_to_compare(ref_authors);
     let local_result = query_local_databases(
         title,
         ref_authors,
+        kind,
         config,
         client,
         longer_timeout,
         only_dbs,
+        None,
         on_db_complete,
     )
     .await;
@@ -50,6 +61,7 @@ pub async fn query_all_databases(
     query_remote_databases(
         title,
         ref_authors,
+        kind,
         config,
         client,
         longer_timeout,
@@ -64,13 +76,19 @@ pub async fn query_all_databases(
 ///
 /// Returns immediately (<1ms). If a local DB matches, the result has
 /// `status == Verified` and remaining DBs are marked Skipped.
+///
+/// `on_db_start` is called with each DB's name just before it is queried.
+/// Databases preferred for `kind` go first.
+#[allow(clippy::too_many_arguments)]
 pub async fn query_local_databases(
     title: &str,
     ref_authors: &[String],
+    kind: RefKind,
     config: &Config,
     client: &reqwest::Client,
     longer_timeout: bool,
     only_dbs: Option<&[String]>,
+    on_db_start: Option<&(dyn Fn(&str) + Send + Sync)>,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
 ) -> DbSearchResult {
     let timeout = compute_timeout(config, longer_timeout);
@@ -84,8 +102,9 @@ pub async fn query_local_databases(
         return empty_result();
     }
 
-    let (local_dbs, remote_dbs): (Vec<_>, Vec<_>) =
+    let (mut local_dbs, remote_dbs): (Vec<_>, Vec<_>) =
         all_databases.into_iter().partition(|db| db.is_local());
+    local_dbs.sort_by_key(|db| kind.db_rank(db.name()));
 
     // All DB names for Skipped tracking on early exit
     let all_db_names: HashSet<String> = local_dbs
@@ -102,9 +121,13 @@ pub async fn query_local_databases(
     let mut failed_dbs = Vec::new();
     let mut db_results: Vec<DbResult> = Vec::new();
     let mut completed_db_names: HashSet<String> = HashSet::new();
+    let mut near_miss: Option<(String, f64)> = None;
 
     for db in &local_dbs {
         let name = db.name().to_string();
+        if let Some(cb) = on_db_start {
+            cb(&name);
+        }
         let rl_result = rate_limit::query_with_retry(
             db.as_ref(),
             title,
@@ -117,6 +140,9 @@ pub async fn query_local_databases(
         .await;
         let elapsed = rl_result.elapsed;
         completed_db_names.insert(name.clone());
+        if let Ok(ref qr) = rl_result.result {
+            keep_best_near_miss(&mut near_miss, qr.near_miss.as_ref());
+        }
 
         match process_query_result(
             name,
@@ -149,6 +175,7 @@ pub async fn query_local_databases(
     // No local match — return partial result for remote phase to continue from
     if let Some(mut mismatch) = first_mismatch {
         mismatch.db_results = db_results;
+        mismatch.near_miss = near_miss;
         return mismatch;
     }
 
@@ -157,20 +184,24 @@ pub async fn query_local_databases(
         source: None,
         found_authors: vec![],
         paper_url: None,
+        found_title: None,
         failed_dbs,
         db_results,
         retraction: None,
+        near_miss,
     }
 }
 
 /// Query only remote/online databases concurrently, continuing from local results.
 ///
 /// The `local_result` carries any db_results, failed_dbs, and first_mismatch from
-/// the local phase. Remote results are merged in.
+/// the local phase. Remote results are merged in. Cached answers from databases
+/// preferred for `kind` are consulted first.
 #[allow(clippy::too_many_arguments)]
 pub async fn query_remote_databases(
     title: &str,
     ref_authors: &[String],
+    kind: RefKind,
     config: &Config,
     client: &reqwest::Client,
     longer_timeout: bool,
@@ -186,8 +217,9 @@ pub async fn query_remote_databases(
         .map(Arc::from)
         .collect();
 
-    let (local_dbs, remote_dbs): (Vec<_>, Vec<_>) =
+    let (local_dbs, mut remote_dbs): (Vec<_>, Vec<_>) =
         all_databases.into_iter().partition(|db| db.is_local());
+    remote_dbs.sort_by_key(|db| kind.db_rank(db.name()));
 
     // All DB names for Skipped tracking
     let all_db_names: HashSet<String> = local_dbs
@@ -210,6 +242,7 @@ pub async fn query_remote_databases(
         } else {
             None
         };
+    let near_miss = local_result.near_miss;
     let mut failed_dbs = local_result.failed_dbs;
     let mut db_results = local_result.db_results;
     let mut completed_db_names: HashSet<String> =
@@ -225,9 +258,11 @@ pub async fn query_remote_databases(
             source: None,
             found_authors: vec![],
             paper_url: None,
+            found_title: None,
             failed_dbs,
             db_results,
             retraction: None,
+            near_miss,
         };
     }
 
@@ -342,12 +377,100 @@ pub async fn query_remote_databases(
         source: None,
         found_authors: vec![],
         paper_url: None,
+        found_title: None,
         failed_dbs,
         db_results,
         retraction: None,
+        near_miss,
     }
 }
 
+/// Resolve the references that carry a DOI or arXiv ID in bulk against every
+/// enabled online backend that supports batching (Semantic Scholar), one
+/// request per [`batch_size`](DatabaseBackend::batch_size) references, all
+/// sent concurrently within each backend's rate limit.
+///
+/// Matches go into the query cache, where the per-reference checks that
+/// follow find them without a request of their own; anything the batch
+/// didn't match is queried individually as usual. Does nothing without a
+/// query cache.
+pub async fn prefetch_batched(refs: &[Reference], config: &Config, client: &reqwest::Client) {
+    let databases: Vec<Arc<dyn DatabaseBackend>> = build_database_list(config, None)
+        .into_iter()
+        .map(Arc::from)
+        .collect();
+    prefetch_batched_with(&databases, refs, config, client).await;
+}
+
+async fn prefetch_batched_with(
+    databases: &[Arc<dyn DatabaseBackend>],
+    refs: &[Reference],
+    config: &Config,
+    client: &reqwest::Client,
+) {
+    let Some(cache) = config.query_cache.as_ref() else {
+        return;
+    };
+    let timeout = compute_timeout(config, false);
+
+    // One task per batch request, across all backends; each backend's
+    // limiter still spaces its own requests.
+    let mut join_set = tokio::task::JoinSet::new();
+    for db in databases
+        .iter()
+        .filter(|db| db.supports_batch() && !db.is_local())
+    {
+        let mut seen = HashSet::new();
+        let lookups: Vec<(String, Option<String>, Option<String>)> = refs
+            .iter()
+            .filter(|r| r.doi.is_some() || r.arxiv_id.is_some())
+            .filter_map(|r| {
+                let title = r.title.as_deref().filter(|t| !t.is_empty())?;
+                Some((title, r))
+            })
+            .filter(|(title, _)| seen.insert(*title))
+            .filter(|(title, _)| cache.get(title, db.name()).is_none())
+            .map(|(title, r)| (title.to_string(), r.doi.clone(), r.arxiv_id.clone()))
+            .collect();
+
+        for chunk in lookups.chunks(db.batch_size()) {
+            let db = Arc::clone(db);
+            let chunk = chunk.to_vec();
+            let client = client.clone();
+            let cache = Arc::clone(cache);
+            let rate_limiters = config.rate_limiters.clone();
+            join_set.spawn(async move {
+                let lookups: Vec<BatchLookup> = chunk
+                    .iter()
+                    .map(|(title, doi, arxiv_id)| BatchLookup {
+                        title,
+                        doi: doi.as_deref(),
+                        arxiv_id: arxiv_id.as_deref(),
+                    })
+                    .collect();
+                if let Some(limiter) = rate_limiters.get(db.name()) {
+                    limiter.acquire().await;
+                }
+                let results = db.query_batch(&lookups, &client, timeout).await;
+                let mut error = None;
+                for (lookup, result) in lookups.iter().zip(results) {
+                    match result {
+                        Ok(result) if result.is_found() => {
+                            cache.insert(lookup.title, db.name(), &result);
+                        }
+                        Ok(_) => {}
+                        Err(e) => error = Some(e),
+                    }
+                }
+                if let Some(e) = error {
+                    tracing::warn!(db = db.name(), error = %e, "batch lookup failed");
+                }
+            });
+        }
+    }
+    join_set.join_all().await;
+}
+
 // ── Helpers ───────────────────────────────────────────────────────────────
 
 fn compute_timeout(config: &Config, longer: bool) -> Duration {
@@ -364,9 +487,20 @@ fn empty_result() -> DbSearchResult {
         source: None,
         found_authors: vec![],
         paper_url: None,
+        found_title: None,
         failed_dbs: vec![],
         db_results: vec![],
         retraction: None,
+        near_miss: None,
+    }
+}
+
+/// Replace `best` with `candidate` when it scores higher.
+fn keep_best_near_miss(best: &mut Option<(String, f64)>, candidate: Option<&(String, f64)>) {
+    if let Some(candidate) = candidate
+        && best.as_ref().is_none_or(|(_, score)| candidate.1 > *score)
+    {
+        *best = Some(candidate.clone());
     }
 }
 
@@ -414,9 +548,11 @@ fn process_query_result(
                     source: Some(name),
                     found_authors,
                     paper_url,
+                    found_title: qr.found_title.clone(),
                     failed_dbs: vec![],
                     db_results: vec![], // caller fills this in
                     retraction,
+                    near_miss: None,
                 });
             } else {
                 let db_result = DbResult {
@@ -438,9 +574,11 @@ fn process_query_result(
                         source: Some(name),
                         found_authors,
                         paper_url,
+                        found_title: qr.found_title.clone(),
                         failed_dbs: vec![],
                         db_results: vec![],
                         retraction,
+                        near_miss: None,
                     });
                 }
             }
@@ -539,6 +677,10 @@ pub(crate) fn build_database_list(
         if let Some(ref db) = config.dblp_offline_db {
             databases.push(Box::new(dblp::DblpOffline {
                 db: std::sync::Arc::clone(db),
+                near_miss_margin: config.near_miss_margin,
+                threshold: config.threshold_for("DBLP", hallucinator_dblp::DEFAULT_THRESHOLD),
+                scorer: config.title_scorer,
+                guard: config.query_guard,
             }));
         } else {
             databases.push(Box::new(dblp::DblpOnline));
@@ -553,6 +695,11 @@ pub(crate) fn build_database_list(
         if let Some(ref db) = config.acl_offline_db {
             databases.push(Box::new(acl::AclOffline {
                 db: std::sync::Arc::clone(db),
+                near_miss_margin: config.near_miss_margin,
+                threshold: config
+                    .threshold_for("ACL Anthology", hallucinator_acl::DEFAULT_THRESHOLD),
+                scorer: config.title_scorer,
+                guard: config.query_guard,
             }));
         } else {
             databases.push(Box::new(acl::AclAnthology));
@@ -565,12 +712,19 @@ pub(crate) fn build_database_list(
         databases.push(Box::new(pubmed::PubMed));
     }
     if should_include("DOI") {
-        databases.push(Box::new(doi_resolver::DoiResolver));
+        databases.push(Box::new(doi_resolver::DoiResolver {
+            cache: config.query_cache.clone(),
+        }));
     }
     if should_include("OpenAlex") {
         if let Some(ref db) = config.openalex_offline_db {
             databases.push(Box::new(openalex_offline::OpenAlexOffline {
                 db: std::sync::Arc::clone(db),
+                near_miss_margin: config.near_miss_margin,
+                threshold: config
+                    .threshold_for("OpenAlex", hallucinator_openalex::DEFAULT_THRESHOLD),
+                scorer: config.title_scorer,
+                guard: config.query_guard,
             }));
         } else if let Some(ref key) = config.openalex_key {
             databases.insert(
@@ -626,6 +780,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn database_names_cover_every_backend() {
+        let config = Config {
+            openalex_key: Some("key".into()),
+            ..Config::default()
+        };
+        let dbs = build_database_list(&config, None);
+        let mut names: Vec<&str> = dbs.iter().map(|db| db.name()).collect();
+        names.sort_unstable();
+        let mut expected = crate::db::DATABASE_NAMES.to_vec();
+        expected.sort_unstable();
+        assert_eq!(names, expected);
+    }
+
     #[test]
     fn disabled_dbs_excluded() {
         let config = Config {
@@ -646,6 +814,19 @@ mod tests {
         assert_eq!(dbs[0].name(), "arXiv");
     }
 
+    #[test]
+    fn per_db_threshold_wins_over_global() {
+        let mut config = Config {
+            match_threshold: Some(0.80),
+            ..Config::default()
+        };
+        assert_eq!(config.threshold_for("DBLP", 0.90), 0.80);
+        config.thresholds.insert("dblp".into(), 0.97);
+        assert_eq!(config.threshold_for("DBLP", 0.90), 0.97);
+        assert_eq!(config.threshold_for("ACL Anthology", 0.95), 0.80);
+        assert_eq!(Config::default().threshold_for("DBLP", 0.90), 0.90);
+    }
+
     #[test]
     fn openalex_requires_key() {
         let config = Config::default();
@@ -661,12 +842,117 @@ mod tests {
         assert_eq!(dbs[0].name(), "OpenAlex");
     }
 
+    fn reference(title: &str, doi: Option<&str>) -> Reference {
+        Reference {
+            raw_citation: title.into(),
+            title: Some(title.into()),
+            authors: vec![],
+            doi: doi.map(Into::into),
+            arxiv_id: None,
+            original_number: 1,
+            skip_reason: None,
+            kind: crate::RefKind::Unknown,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    fn batch_mock(batch_size: usize) -> Arc<MockDb> {
+        Arc::new(
+            MockDb::new(
+                "Mock",
+                MockResponse::Found {
+                    title: "Found".into(),
+                    authors: vec!["Smith".into()],
+                    url: None,
+                },
+            )
+            .with_batch_size(batch_size),
+        )
+    }
+
+    #[tokio::test]
+    async fn prefetch_batches_refs_with_identifiers() {
+        let config = config_all_disabled();
+        let cache = config.query_cache.clone().unwrap();
+        let mock = batch_mock(500);
+        let dbs: Vec<Arc<dyn DatabaseBackend>> = vec![mock.clone()];
+        let mut refs: Vec<Reference> = (0..5)
+            .map(|i| reference(&format!("Paper number {i}"), Some(&format!("10.1000/{i}"))))
+            .collect();
+        refs.push(reference("Paper without identifier", None));
+
+        prefetch_batched_with(&dbs, &refs, &config, &reqwest::Client::new()).await;
+
+        assert_eq!(mock.batch_call_count(), 1);
+        assert_eq!(mock.call_count(), 0);
+        for i in 0..5 {
+            let cached = cache.get(&format!("Paper number {i}"), "Mock");
+            assert!(cached.is_some_and(|r| r.is_found()));
+        }
+        assert!(cache.get("Paper without identifier", "Mock").is_none());
+    }
+
+    #[tokio::test]
+    async fn prefetch_splits_into_batch_size_chunks() {
+        let config = config_all_disabled();
+        let mock = batch_mock(2);
+        let dbs: Vec<Arc<dyn DatabaseBackend>> = vec![mock.clone()];
+        let refs: Vec<Reference> = (0..5)
+            .map(|i| reference(&format!("Paper number {i}"), Some(&format!("10.1000/{i}"))))
+            .collect();
+
+        prefetch_batched_with(&dbs, &refs, &config, &reqwest::Client::new()).await;
+        assert_eq!(mock.batch_call_count(), 3);
+
+        // Everything is cached now, so a second pass sends nothing
+        prefetch_batched_with(&dbs, &refs, &config, &reqwest::Client::new()).await;
+        assert_eq!(mock.batch_call_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn prefetch_sends_batches_concurrently() {
+        let config = config_all_disabled();
+        let delay = Duration::from_millis(200);
+        let slow = |name| {
+            let mock = MockDb::new(
+                name,
+                MockResponse::Found {
+                    title: "Found".into(),
+                    authors: vec!["Smith".into()],
+                    url: None,
+                },
+            );
+            Arc::new(mock.with_batch_size(2).with_delay(delay))
+        };
+        let (first, second) = (slow("First"), slow("Second"));
+        let dbs: Vec<Arc<dyn DatabaseBackend>> = vec![first.clone(), second.clone()];
+        let refs: Vec<Reference> = (0..6)
+            .map(|i| reference(&format!("Paper number {i}"), Some(&format!("10.1000/{i}"))))
+            .collect();
+
+        let start = std::time::Instant::now();
+        prefetch_batched_with(&dbs, &refs, &config, &reqwest::Client::new()).await;
+        assert_eq!(first.batch_call_count() + second.batch_call_count(), 6);
+        assert!(start.elapsed() < delay * 2, "{:?}", start.elapsed());
+    }
+
     #[tokio::test]
     async fn empty_db_list_returns_not_found() {
         let config = config_all_disabled();
         let client = reqwest::Client::new();
-        let result =
-            query_all_databases("Some Title", &[], &config, &client, false, None, None).await;
+        let result = query_all_databases(
+            "Some Title",
+            &[],
+            RefKind::Unknown,
+            &config,
+            &client,
+            false,
+            None,
+            None,
+        )
+        .await;
         assert_eq!(result.status, Status::NotFound);
         assert!(result.db_results.is_empty());
     }
@@ -739,12 +1025,25 @@ mod tests {
             source: None,
             found_authors: vec![],
             paper_url: None,
+            found_title: None,
             failed_dbs,
             db_results,
             retraction: None,
+            near_miss: None,
         }
     }
 
+    #[test]
+    fn keep_best_near_miss_prefers_higher_score() {
+        let mut best = None;
+        keep_best_near_miss(&mut best, Some(&("A".into(), 0.82)));
+        keep_best_near_miss(&mut best, None);
+        keep_best_near_miss(&mut best, Some(&("B".into(), 0.80)));
+        assert_eq!(best, Some(("A".to_string(), 0.82)));
+        keep_best_near_miss(&mut best, Some(&("C".into(), 0.86)));
+        assert_eq!(best, Some(("C".to_string(), 0.86)));
+    }
+
     #[tokio::test]
     async fn single_match_returns_verified() {
         let mock: Arc<dyn DatabaseBackend> = Arc::new(MockDb::new(
@@ -0,0 +1,221 @@
+//! User-supplied title → DOI/URL overrides.
+//!
+//! Some references never match (odd formatting, venues no backend indexes)
+//! even though the user knows exactly which paper they are. An override file
+//! asserts "this reference is this paper"; a hit short-circuits the check to
+//! `Verified` with source [`OVERRIDE_SOURCE`] before any backend is queried.
+//!
+//! The file is a flat map from a reference title or a
+//! [`Reference::fingerprint`] to a DOI or URL, as TOML or (with a `.json`
+//! extension) JSON:
+//!
+//! ```toml
+//! "Attention Is All You Need" = "10.5555/3295222.3295349"
+//! "3f2a9c0d1b7e4a55" = "https://example.org/tech-report.pdf"
+//! ```
+//!
+//! Title keys are normalized like every other title comparison, so case,
+//! punctuation and diacritics don't matter.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::matching::normalize_title;
+use crate::{Config, CoreError, DbResult, DbStatus, Reference, Status, ValidationResult};
+
+/// `source` (and DB name) reported for references verified by an override.
+pub const OVERRIDE_SOURCE: &str = "manual override";
+
+/// Loaded override map, keyed by normalized title or fingerprint.
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    entries: HashMap<String, String>,
+}
+
+impl Overrides {
+    /// Read an override file: JSON if the extension is `.json`, TOML otherwise.
+    pub fn load(path: &Path) -> Result<Self, CoreError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| CoreError::Overrides(format!("{}: {}", path.display(), e)))?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let raw: HashMap<String, String> = if is_json {
+            serde_json::from_str(&content).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(&content).map_err(|e| e.to_string())
+        }
+        .map_err(|e| CoreError::Overrides(format!("{}: {}", path.display(), e)))?;
+        Ok(Self::from_entries(raw))
+    }
+
+    /// Build from `(title or fingerprint, DOI or URL)` pairs.
+    pub fn from_entries(entries: impl IntoIterator<Item = (String, String)>) -> Self {
+        let entries = entries
+            .into_iter()
+            .filter_map(|(key, target)| {
+                let key = normalize_title(&key);
+                let target = target.trim().to_string();
+                (!key.is_empty() && !target.is_empty()).then_some((key, target))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The DOI or URL asserted for `reference`, matched by fingerprint first
+    /// and then by title.
+    pub fn lookup(&self, reference: &Reference) -> Option<&str> {
+        self.entries
+            .get(&reference.fingerprint())
+            .or_else(|| {
+                let title = normalize_title(reference.title.as_deref().unwrap_or(""));
+                self.entries.get(&title)
+            })
+            .map(String::as_str)
+    }
+
+    /// A `Verified` result for `reference` if an override covers it.
+    pub fn result_for(&self, reference: &Reference) -> Option<ValidationResult> {
+        let target = self.lookup(reference)?;
+        let paper_url = if target.starts_with("http://") || target.starts_with("https://") {
+            target.to_string()
+        } else {
+            format!("https://doi.org/{}", target)
+        };
+        Some(ValidationResult {
+            title: reference.title.clone().unwrap_or_default(),
+            raw_citation: reference.raw_citation.clone(),
+            cite_key: reference.cite_key.clone(),
+            cited_in_text: reference.cited_in_text,
+            ref_authors: reference.authors.clone(),
+            status: Status::Verified,
+            source: Some(OVERRIDE_SOURCE.into()),
+            found_authors: vec![],
+            paper_url: Some(paper_url.clone()),
+            failed_dbs: vec![],
+            db_results: vec![DbResult {
+                db_name: OVERRIDE_SOURCE.into(),
+                status: DbStatus::Match,
+                elapsed: None,
+                found_authors: vec![],
+                paper_url: Some(paper_url),
+                error_message: None,
+            }],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            near_miss: None,
+            author_match_detail: vec![],
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 0,
+            insufficient_coverage: false,
+        })
+    }
+}
+
+impl Config {
+    /// The run's overrides: the loaded ones if set, otherwise read from
+    /// `overrides_path`. A file that fails to load is logged and ignored.
+    pub fn overrides(&self) -> Option<Arc<Overrides>> {
+        if let Some(overrides) = &self.overrides {
+            return Some(overrides.clone());
+        }
+        let path = self.overrides_path.as_deref()?;
+        match Overrides::load(path) {
+            Ok(overrides) => Some(Arc::new(overrides)),
+            Err(e) => {
+                tracing::warn!("ignoring overrides: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RefKind;
+
+    fn reference(title: &str, authors: &[&str]) -> Reference {
+        Reference {
+            raw_citation: title.to_string(),
+            title: Some(title.to_string()),
+            authors: authors.iter().map(|a| a.to_string()).collect(),
+            doi: None,
+            arxiv_id: None,
+            original_number: 1,
+            skip_reason: None,
+            kind: RefKind::Unknown,
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    #[test]
+    fn test_load_toml_and_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml_path = dir.path().join("overrides.toml");
+        std::fs::write(
+            &toml_path,
+            "\"Attention Is All You Need\" = \"10.5555/3295222.3295349\"\n",
+        )
+        .unwrap();
+        let json_path = dir.path().join("overrides.json");
+        std::fs::write(
+            &json_path,
+            r#"{"Some Tech Report": "https://example.org/tr.pdf"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(Overrides::load(&toml_path).unwrap().len(), 1);
+        assert_eq!(Overrides::load(&json_path).unwrap().len(), 1);
+        assert!(matches!(
+            Overrides::load(&dir.path().join("missing.toml")),
+            Err(CoreError::Overrides(_))
+        ));
+    }
+
+    #[test]
+    fn test_lookup_by_title_and_fingerprint() {
+        let by_title = reference("attention is all you need!", &["A. Vaswani"]);
+        let by_fingerprint = reference("An Obscure Workshop Paper", &["J. Doe"]);
+        let overrides = Overrides::from_entries([
+            (
+                "Attention Is All You Need".to_string(),
+                "10.5555/3295222.3295349".to_string(),
+            ),
+            (
+                by_fingerprint.fingerprint(),
+                "https://example.org/paper".to_string(),
+            ),
+        ]);
+
+        let result = overrides.result_for(&by_title).unwrap();
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.source.as_deref(), Some(OVERRIDE_SOURCE));
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://doi.org/10.5555/3295222.3295349")
+        );
+        assert_eq!(
+            overrides.lookup(&by_fingerprint),
+            Some("https://example.org/paper")
+        );
+        assert!(
+            overrides
+                .lookup(&reference("Unrelated", &["A. Vaswani"]))
+                .is_none()
+        );
+    }
+}
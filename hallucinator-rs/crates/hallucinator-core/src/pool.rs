@@ -9,17 +9,24 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use tokio::sync::oneshot;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, oneshot};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
-use crate::authors::validate_authors;
+use crate::authors::{surnames_overlap, validate_authors};
 use crate::db::DatabaseBackend;
 use crate::db::searxng::Searxng;
+use crate::doi::{
+    DoiMatchResult, check_doi_match, doi_title_mismatch, is_well_formed_doi, resolve_doi,
+};
+use crate::kind::{RefKind, version_relation};
 use crate::orchestrator::{build_database_list, query_local_databases};
+use crate::overrides::Overrides;
 use crate::rate_limit::{self, DbQueryError, DoiContext};
+use crate::retraction::check_retraction;
 use crate::{
-    Config, DbResult, DbStatus, DoiInfo, ProgressEvent, Reference, Status, ValidationResult,
+    Config, CoreError, DbResult, DbStatus, DoiConflict, DoiInfo, ProgressEvent, Reference, Status,
+    ValidationResult,
 };
 
 // ── Public API (unchanged) ──────────────────────────────────────────────
@@ -34,6 +41,88 @@ pub struct RefJob {
     pub progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
 }
 
+/// Upper bound for [`ValidationPool::set_concurrency`]: the number of
+/// coordinator tasks spawned per pool, most of them idle at any time.
+pub const MAX_WORKERS: usize = 64;
+
+/// Adjustable cap on how many coordinators work on a reference at once.
+///
+/// Handles are cheap clones sharing one limit. Raising it takes effect
+/// immediately; lowering it never interrupts a reference in progress — the
+/// surplus permits are retired as their holders finish.
+#[derive(Clone)]
+pub struct WorkerLimit {
+    semaphore: Arc<Semaphore>,
+    state: Arc<Mutex<LimitState>>,
+}
+
+struct LimitState {
+    limit: usize,
+    /// Permits to retire instead of returning once their holder finishes.
+    owed: usize,
+}
+
+/// A coordinator's permit to process one reference.
+struct WorkerPermit {
+    permit: Option<OwnedSemaphorePermit>,
+    state: Arc<Mutex<LimitState>>,
+}
+
+impl Drop for WorkerPermit {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.owed > 0
+            && let Some(permit) = self.permit.take()
+        {
+            state.owed -= 1;
+            permit.forget();
+        }
+    }
+}
+
+impl WorkerLimit {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.clamp(1, MAX_WORKERS);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            state: Arc::new(Mutex::new(LimitState { limit, owed: 0 })),
+        }
+    }
+
+    /// The current limit.
+    pub fn get(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).limit
+    }
+
+    /// Change the limit, clamped to `1..=MAX_WORKERS`.
+    pub fn set(&self, limit: usize) {
+        let limit = limit.clamp(1, MAX_WORKERS);
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if limit > state.limit {
+            let raise = limit - state.limit;
+            let repaid = raise.min(state.owed);
+            state.owed -= repaid;
+            self.semaphore.add_permits(raise - repaid);
+        } else {
+            let cut = state.limit - limit;
+            let forgotten = self.semaphore.forget_permits(cut);
+            state.owed += cut - forgotten;
+        }
+        state.limit = limit;
+    }
+
+    async fn acquire(&self) -> WorkerPermit {
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("worker semaphore is never closed");
+        WorkerPermit {
+            permit: Some(permit),
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
 /// A pool of coordinator + drainer tasks that process reference validation jobs.
 ///
 /// Submit jobs via [`submit()`](ValidationPool::submit), receive results via
@@ -41,26 +130,45 @@ pub struct RefJob {
 pub struct ValidationPool {
     job_tx: async_channel::Sender<RefJob>,
     pool_handle: JoinHandle<()>,
+    worker_limit: WorkerLimit,
 }
 
 impl ValidationPool {
-    /// Create a new pool with `num_workers` coordinator tasks.
+    /// Create a new pool that works on up to `num_workers` references at once.
     ///
     /// One drainer task is spawned per enabled remote DB. Coordinators handle
     /// local DBs inline, then fan out to per-DB drainer queues (including DOI).
-    pub fn new(config: Arc<Config>, cancel: CancellationToken, num_workers: usize) -> Self {
-        let (job_tx, job_rx) = async_channel::unbounded::<RefJob>();
-        let client = reqwest::Client::builder()
-            .pool_max_idle_per_host(2)
-            .pool_idle_timeout(Duration::from_secs(30))
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
-
-        // Build database list and partition into local/remote
-        let all_dbs: Vec<Arc<dyn DatabaseBackend>> = build_database_list(&config, None)
+    /// The number of active coordinators can be changed while the pool runs
+    /// with [`set_concurrency()`](ValidationPool::set_concurrency).
+    ///
+    /// Fails if the HTTP client can't be built from `config` (see
+    /// [`Config::http_client`]).
+    pub fn new(
+        config: Arc<Config>,
+        cancel: CancellationToken,
+        num_workers: usize,
+    ) -> Result<Self, CoreError> {
+        let all_dbs = build_database_list(&config, None)
             .into_iter()
             .map(Arc::from)
             .collect();
+        Self::with_backends(config, cancel, num_workers, all_dbs)
+    }
+
+    /// Like [`new()`](ValidationPool::new), but querying `all_dbs` instead of
+    /// the databases `config` enables.
+    pub(crate) fn with_backends(
+        config: Arc<Config>,
+        cancel: CancellationToken,
+        num_workers: usize,
+        all_dbs: Vec<Arc<dyn DatabaseBackend>>,
+    ) -> Result<Self, CoreError> {
+        let (job_tx, job_rx) = async_channel::unbounded::<RefJob>();
+        let worker_limit = WorkerLimit::new(num_workers);
+        let client = config.http_client()?;
+        let overrides = config.overrides();
+
+        // Partition into local/remote
         let (local_dbs, remote_dbs): (Vec<_>, Vec<_>) =
             all_dbs.into_iter().partition(|db| db.is_local());
 
@@ -83,15 +191,18 @@ impl ValidationPool {
         let drainer_txs = Arc::new(drainer_txs);
 
         // Spawn coordinator tasks
+        let coord_limit = worker_limit.clone();
         let pool_handle = tokio::spawn(async move {
-            let mut coord_handles = Vec::with_capacity(num_workers.max(1));
+            let mut coord_handles = Vec::with_capacity(MAX_WORKERS);
 
-            for _ in 0..num_workers.max(1) {
+            for _ in 0..MAX_WORKERS {
                 coord_handles.push(tokio::spawn(coordinator_loop(
                     job_rx.clone(),
+                    coord_limit.clone(),
                     config.clone(),
                     client.clone(),
                     cancel.clone(),
+                    overrides.clone(),
                     local_dbs.clone(),
                     drainer_txs.clone(),
                 )));
@@ -114,10 +225,27 @@ impl ValidationPool {
             }
         });
 
-        Self {
+        Ok(Self {
             job_tx,
             pool_handle,
-        }
+            worker_limit,
+        })
+    }
+
+    /// How many references the pool currently works on at once.
+    pub fn concurrency(&self) -> usize {
+        self.worker_limit.get()
+    }
+
+    /// Change how many references the pool works on at once (clamped to
+    /// `1..=MAX_WORKERS`), e.g. to back off under sustained rate limiting.
+    pub fn set_concurrency(&self, workers: usize) {
+        self.worker_limit.set(workers);
+    }
+
+    /// A handle for adjusting concurrency from elsewhere (e.g. a UI task).
+    pub fn worker_limit(&self) -> WorkerLimit {
+        self.worker_limit.clone()
     }
 
     /// Get a cloneable sender for submitting jobs from multiple tasks.
@@ -155,6 +283,12 @@ struct RefCollector {
     remaining: AtomicUsize,
     /// Set to true when any drainer verifies. Other drainers check this to skip work.
     verified: AtomicBool,
+    /// Cancelled when one of [`Config::trusted_verifiers`] verifies; aborts
+    /// the other drainers' queued and in-flight queries for this ref.
+    trusted_match: CancellationToken,
+    /// Claimed by the one title-search drainer that still queries a verified
+    /// ref for [`Config::cross_validate_doi`] (see [`claims_cross_check`]).
+    cross_check_claimed: AtomicBool,
 
     /// Aggregation state (single Mutex, held briefly).
     state: Mutex<AggState>,
@@ -164,6 +298,10 @@ struct RefCollector {
 
     /// DB results from the local phase (carried forward for merging).
     local_result: crate::orchestrator::DbSearchResult,
+
+    /// The coordinator's [`WorkerLimit`] permit, released by
+    /// [`finalize_collector`] so the limit covers the remote queries too.
+    permit: Mutex<Option<WorkerPermit>>,
 }
 
 /// Mutable aggregation state protected by a Mutex.
@@ -174,12 +312,20 @@ struct AggState {
     db_results: Vec<DbResult>,
     /// Retraction info extracted inline from CrossRef response (if any).
     retraction: Option<crate::retraction::RetractionResult>,
+    /// Title the DOI resolved to, when it names a different paper.
+    doi_mismatch_title: Option<String>,
+    /// The resolver has no record of the reference's DOI.
+    doi_unregistered: bool,
+    /// First title-search (non-DOI) match `(source, found title)`, for
+    /// [`Config::cross_validate_doi`].
+    title_match: Option<(String, Option<String>)>,
 }
 
 struct VerifiedInfo {
     source: String,
     found_authors: Vec<String>,
     paper_url: Option<String>,
+    found_title: Option<String>,
 }
 
 struct MismatchInfo {
@@ -220,12 +366,20 @@ async fn drainer_loop(
             continue;
         }
 
-        // Skip if already verified by another drainer
-        if collector.verified.load(Ordering::Acquire) {
+        // Skip if already verified by another drainer, unless this drainer
+        // is the one title search cross-checking the ref's DOI
+        if collector.verified.load(Ordering::Acquire)
+            && !claims_cross_check(collector, requires_doi)
+        {
             tracing::debug!(db = db.name(), title = %collector.title, "skipping: already verified");
             skip_and_decrement(collector, db.name()).await;
             continue;
         }
+        if collector.trusted_match.is_cancelled() {
+            tracing::debug!(db = db.name(), title = %collector.title, "skipping: trusted verifier matched");
+            skip_and_decrement(collector, db.name()).await;
+            continue;
+        }
 
         // DOI-requiring backends skip refs without a DOI
         if requires_doi && collector.reference.doi.is_none() {
@@ -234,29 +388,61 @@ async fn drainer_loop(
             continue;
         }
 
-        // Build DOI context if this ref has a DOI (used by DOI backend)
-        let doi_ctx = collector.reference.doi.as_deref().map(|doi| DoiContext {
-            doi,
-            authors: &collector.reference.authors,
+        // Build identifier context if this ref has a DOI or arXiv ID (used
+        // by the DOI backend and Semantic Scholar's exact lookups)
+        let reference = &collector.reference;
+        let doi_ctx =
+            (reference.doi.is_some() || reference.arxiv_id.is_some()).then(|| DoiContext {
+                doi: reference.doi.as_deref(),
+                arxiv_id: reference.arxiv_id.as_deref(),
+                authors: collector.config.authors_to_compare(&reference.authors),
+            });
+
+        (collector.progress)(ProgressEvent::DatabaseQueryStarted {
+            paper_index: 0,
+            ref_index: collector.ref_index,
+            db_name: db.name().to_string(),
         });
 
-        // Query (includes cache check + governor acquire + HTTP call)
-        let rl_result = rate_limit::query_with_rate_limit(
-            db.as_ref(),
-            &collector.title,
-            &client,
-            timeout,
-            &rate_limiters,
-            cache.as_deref(),
-            doi_ctx.as_ref(),
-        )
-        .await;
+        // Query (includes cache check + governor acquire + HTTP call),
+        // abandoned if a trusted verifier settles the ref meanwhile
+        let rl_result = tokio::select! {
+            rl_result = rate_limit::query_with_rate_limit(
+                db.as_ref(),
+                &collector.title,
+                &client,
+                timeout,
+                &rate_limiters,
+                cache.as_deref(),
+                doi_ctx.as_ref(),
+            ) => rl_result,
+            _ = collector.trusted_match.cancelled() => {
+                tracing::debug!(db = db.name(), title = %collector.title, "cancelled: trusted verifier matched");
+                skip_and_decrement(collector, db.name()).await;
+                continue;
+            }
+        };
 
         // Process result and decrement remaining
         report_result(collector, db.name(), rl_result).await;
     }
 }
 
+/// Whether a drainer should still query a reference another drainer has
+/// verified: with [`Config::cross_validate_doi`], a DOI reference that no
+/// title search has matched yet gets exactly one, from the first title-search
+/// drainer to ask.
+fn claims_cross_check(collector: &RefCollector, requires_doi: bool) -> bool {
+    if !collector.config.cross_validate_doi || collector.reference.doi.is_none() || requires_doi {
+        return false;
+    }
+    let title_matched = {
+        let state = collector.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.title_match.is_some()
+    };
+    !title_matched && !collector.cross_check_claimed.swap(true, Ordering::AcqRel)
+}
+
 /// Emit a Skipped event and decrement the collector's remaining counter.
 async fn skip_and_decrement(collector: &RefCollector, db_name: &str) {
     (collector.progress)(ProgressEvent::DatabaseQueryComplete {
@@ -298,7 +484,9 @@ async fn report_result(
         Ok(ref qr) if qr.is_found() => {
             let found_authors = &qr.authors;
             let paper_url = &qr.paper_url;
-            let ref_authors = &collector.reference.authors;
+            let ref_authors = collector
+                .config
+                .authors_to_compare(&collector.reference.authors);
             if ref_authors.is_empty() || validate_authors(ref_authors, found_authors) {
                 // Verified — set flag so other drainers can skip
                 collector.verified.store(true, Ordering::Release);
@@ -320,13 +508,24 @@ async fn report_result(
                     paper_url: paper_url.clone(),
                     error_message: None,
                 });
-                if state.verified_info.is_none() {
+                // The first trusted verifier to match becomes the source,
+                // even over an earlier untrusted match, and stops the rest
+                let trusted = collector.config.is_trusted_verifier(db_name)
+                    && !collector.trusted_match.is_cancelled();
+                if trusted {
+                    collector.trusted_match.cancel();
+                }
+                if state.verified_info.is_none() || trusted {
                     state.verified_info = Some(VerifiedInfo {
                         source: db_name.to_string(),
                         found_authors: found_authors.clone(),
                         paper_url: paper_url.clone(),
+                        found_title: qr.found_title.clone(),
                     });
                 }
+                if db_name != "DOI" && state.title_match.is_none() {
+                    state.title_match = Some((db_name.to_string(), qr.found_title.clone()));
+                }
                 // Capture inline retraction info (populated by CrossRef)
                 if let Some(ref retraction) = qr.retraction
                     && retraction.retracted
@@ -364,7 +563,7 @@ async fn report_result(
                 }
             }
         }
-        Ok(_) => {
+        Ok(ref qr) => {
             (collector.progress)(ProgressEvent::DatabaseQueryComplete {
                 paper_index: 0,
                 ref_index: collector.ref_index,
@@ -374,6 +573,10 @@ async fn report_result(
             });
 
             let mut state = collector.state.lock().unwrap_or_else(|e| e.into_inner());
+            if qr.doi_mismatch_title.is_some() {
+                state.doi_mismatch_title = qr.doi_mismatch_title.clone();
+            }
+            state.doi_unregistered |= qr.doi_unregistered;
             state.db_results.push(DbResult {
                 db_name: db_name.to_string(),
                 status: DbStatus::NoMatch,
@@ -471,6 +674,11 @@ async fn finalize_collector(collector: &RefCollector) {
                 let searxng = Searxng::new(searxng_url.clone());
                 let timeout = Duration::from_secs(collector.config.db_timeout_secs);
 
+                (collector.progress)(ProgressEvent::DatabaseQueryStarted {
+                    paper_index: 0,
+                    ref_index: collector.ref_index,
+                    db_name: "Web Search".to_string(),
+                });
                 let start = std::time::Instant::now();
                 let searxng_result = searxng
                     .query(&collector.title, &collector.client, timeout)
@@ -529,15 +737,27 @@ async fn finalize_collector(collector: &RefCollector) {
     let mut all_failed_dbs = collector.local_result.failed_dbs.clone();
     all_failed_dbs.extend(remote_failed_dbs);
 
-    // Build doi_info from reference DOI + DOI drainer result
+    // Build doi_info from reference DOI + DOI drainer result. A DOI that
+    // resolves to a different paper is valid but flagged.
+    let (doi_mismatch_title, doi_unregistered, title_match) = {
+        let mut state = collector.state.lock().unwrap_or_else(|e| e.into_inner());
+        (
+            state.doi_mismatch_title.take(),
+            state.doi_unregistered,
+            state.title_match.take(),
+        )
+    };
     let doi_info = collector.reference.doi.as_ref().map(|doi| {
         let valid = all_db_results.iter().any(|r| {
             r.db_name == "DOI" && matches!(r.status, DbStatus::Match | DbStatus::AuthorMismatch)
         });
         DoiInfo {
             doi: doi.clone(),
-            valid,
-            title: None,
+            valid: valid || doi_mismatch_title.is_some(),
+            title_mismatch: doi_mismatch_title.is_some(),
+            title: doi_mismatch_title,
+            well_formed: is_well_formed_doi(doi),
+            registered: !doi_unregistered,
         }
     });
 
@@ -558,9 +778,17 @@ async fn finalize_collector(collector: &RefCollector) {
         None
     };
 
-    let result = ValidationResult {
+    let near_miss = if status == Status::NotFound {
+        collector.local_result.near_miss.clone()
+    } else {
+        None
+    };
+
+    let mut result = ValidationResult {
         title: collector.title.clone(),
         raw_citation: collector.reference.raw_citation.clone(),
+        cite_key: collector.reference.cite_key.clone(),
+        cited_in_text: collector.reference.cited_in_text,
         ref_authors: collector.reference.authors.clone(),
         status,
         source,
@@ -569,17 +797,39 @@ async fn finalize_collector(collector: &RefCollector) {
         failed_dbs: all_failed_dbs,
         db_results: all_db_results,
         doi_info,
-        arxiv_info: None, // TODO(#124): implement arXiv ID validation
+        arxiv_info: None,
         retraction_info,
+        near_miss,
+        author_match_detail: vec![],
+        doi_conflict: None,
+        version_relation: None,
+        verified_by_count: 0,
+        insufficient_coverage: false,
     };
+    if let Some((source, found_title)) = &title_match {
+        cross_validate_doi(
+            &collector.reference,
+            &mut result,
+            source,
+            found_title.as_deref(),
+            &collector.config,
+            &collector.client,
+        )
+        .await;
+    }
+    accept_preprint_version(&collector.reference, &mut result, &collector.config);
 
     emit_final_events(
         collector.progress.as_ref(),
-        &result,
+        &mut result,
+        &collector.reference,
+        &collector.config,
+        &collector.client,
         collector.ref_index,
         collector.total,
         &collector.title,
-    );
+    )
+    .await;
 
     let tx = collector
         .result_tx
@@ -589,6 +839,67 @@ async fn finalize_collector(collector: &RefCollector) {
     if let Some(tx) = tx {
         let _ = tx.send(result);
     }
+    drop(
+        collector
+            .permit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take(),
+    );
+}
+
+/// Validate the reference's arXiv ID (if any) unless arXiv is disabled,
+/// recording the published DOI when arXiv lists one.
+///
+/// Lookups are answered from the query cache when possible and otherwise
+/// wait on arXiv's rate limiter, like its title searches.
+pub(crate) async fn lookup_reference_arxiv_id(
+    reference: &Reference,
+    config: &Config,
+    client: &reqwest::Client,
+) -> Option<crate::ArxivInfo> {
+    let arxiv_id = reference.arxiv_id.as_deref()?;
+    if config
+        .disabled_dbs
+        .iter()
+        .any(|d| d.eq_ignore_ascii_case("arXiv"))
+    {
+        return None;
+    }
+    let cache = config.query_cache.as_deref();
+    if let Some(info) = cache.and_then(|c| crate::db::arxiv::cached_id_lookup(c, arxiv_id)) {
+        return Some(info);
+    }
+
+    let limiter = config.rate_limiters.get("arXiv");
+    if let Some(lim) = limiter {
+        lim.acquire().await;
+    }
+    let timeout = Duration::from_secs(config.db_timeout_secs);
+    let result = crate::db::arxiv::lookup_arxiv_id(arxiv_id, client, timeout).await;
+    if let Some(lim) = limiter {
+        if let Err(DbQueryError::RateLimited { retry_after }) = result {
+            lim.start_backoff(retry_after.unwrap_or(Duration::from_secs(2)).min(timeout));
+        }
+        rate_limit::record_outcome(
+            "arXiv",
+            lim,
+            matches!(result, Err(DbQueryError::RateLimited { .. })),
+        );
+    }
+
+    match result {
+        Ok(info) => {
+            if let Some(c) = cache {
+                crate::db::arxiv::cache_id_lookup(c, &info);
+            }
+            Some(info)
+        }
+        Err(e) => {
+            tracing::debug!(arxiv_id, error = %e, "arXiv ID lookup failed");
+            None
+        }
+    }
 }
 
 // ── Cache pre-check ─────────────────────────────────────────────────────
@@ -613,16 +924,27 @@ struct CachePreCheck {
 /// sets `verified`, causing other drainers to skip before checking their
 /// own cache entries — preventing those entries from ever being populated.
 ///
+/// Databases preferred for `kind` are checked (and later dispatched) first,
+/// so the first cached match comes from the reference's natural source.
+///
 /// Does NOT emit progress events — the caller is responsible for emitting
 /// Skipped events for cache-hit DBs to decrement in-flight counters.
+#[allow(clippy::too_many_arguments)]
 fn pre_check_remote_cache(
     cache: Option<&crate::cache::QueryCache>,
     title: &str,
     ref_authors: &[String],
+    kind: RefKind,
     drainer_txs: &[(String, bool, async_channel::Sender<DrainerJob>)],
     check_openalex_authors: bool,
     has_doi: bool,
 ) -> CachePreCheck {
+    // Skip DOI-requiring backends for refs without a DOI
+    let mut order: Vec<usize> = (0..drainer_txs.len())
+        .filter(|&i| has_doi || !drainer_txs[i].1)
+        .collect();
+    order.sort_by_key(|&i| kind.db_rank(&drainer_txs[i].0));
+
     let cache = match cache {
         Some(c) => c,
         None => {
@@ -630,9 +952,7 @@ fn pre_check_remote_cache(
                 db_results: vec![],
                 verified_info: None,
                 first_mismatch: None,
-                miss_indices: (0..drainer_txs.len())
-                    .filter(|&i| has_doi || !drainer_txs[i].1)
-                    .collect(),
+                miss_indices: order,
                 retraction: None,
             };
         }
@@ -644,9 +964,12 @@ fn pre_check_remote_cache(
     let mut miss_indices = Vec::new();
     let mut retraction: Option<crate::retraction::RetractionResult> = None;
 
-    for (i, (db_name, requires_doi, _)) in drainer_txs.iter().enumerate() {
-        // Skip DOI-requiring backends for refs without a DOI
-        if *requires_doi && !has_doi {
+    for i in order {
+        let (db_name, requires_doi, _) = &drainer_txs[i];
+        // DOI-keyed backends are cached by DOI, not title; their drainer
+        // reads that cache
+        if *requires_doi {
+            miss_indices.push(i);
             continue;
         }
         match cache.get(title, db_name) {
@@ -673,6 +996,7 @@ fn pre_check_remote_cache(
                             source: db_name.clone(),
                             found_authors: qr.authors,
                             paper_url: qr.paper_url,
+                            found_title: qr.found_title,
                         });
                     }
                 } else {
@@ -724,18 +1048,45 @@ fn pre_check_remote_cache(
     }
 }
 
+/// `DoiInfo` for a reference settled without querying the DOI backend: its
+/// cached resolution if there is one, else a DOI not known to resolve.
+fn cached_doi_info(doi: &str, title: &str, cache: Option<&crate::cache::QueryCache>) -> DoiInfo {
+    match cache.and_then(|c| c.get_doi(doi)) {
+        Some(validation) => DoiInfo::from_validation(doi, &validation, title),
+        None => DoiInfo {
+            doi: doi.to_string(),
+            valid: false,
+            title: None,
+            title_mismatch: false,
+            well_formed: is_well_formed_doi(doi),
+            registered: true,
+        },
+    }
+}
+
 // ── Coordinator ─────────────────────────────────────────────────────────
 
 /// Coordinator loop: pick a ref, run local DBs inline, fan out to drainers.
+///
+/// A coordinator takes a [`WorkerLimit`] permit before each ref and keeps it
+/// until the ref is finalized, so at most `limit` refs are in progress at
+/// once. A ref handed to the drainers takes the permit with it.
+#[allow(clippy::too_many_arguments)]
 async fn coordinator_loop(
     job_rx: async_channel::Receiver<RefJob>,
+    worker_limit: WorkerLimit,
     config: Arc<Config>,
     client: reqwest::Client,
     cancel: CancellationToken,
-    _local_dbs: Vec<Arc<dyn DatabaseBackend>>,
+    overrides: Option<Arc<Overrides>>,
+    local_dbs: Vec<Arc<dyn DatabaseBackend>>,
     drainer_txs: Arc<Vec<(String, bool, async_channel::Sender<DrainerJob>)>>,
 ) {
-    while let Ok(job) = job_rx.recv().await {
+    loop {
+        let permit = worker_limit.acquire().await;
+        let Ok(job) = job_rx.recv().await else {
+            break;
+        };
         if cancel.is_cancelled() {
             break;
         }
@@ -757,15 +1108,69 @@ async fn coordinator_loop(
             title: title.clone(),
         });
 
-        // --- Local DB phase (inline, <1ms) ---
+        let db_start_cb = make_db_start_callback(progress.clone(), ref_index);
         let db_complete_cb = make_db_callback(progress.clone(), ref_index);
+
+        // --- Manual override: the user vouches for this ref, query nothing ---
+        if let Some(mut result) = overrides.as_ref().and_then(|o| o.result_for(&reference)) {
+            for db_result in &result.db_results {
+                db_complete_cb(db_result.clone());
+            }
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
+            let _ = result_tx.send(result);
+            continue;
+        }
+
+        // --- Trusted DOI: a DOI resolving to the cited paper settles it ---
+        if config.trust_resolved_doi
+            && !config.cross_validate_doi
+            && let Some(mut result) = trusted_doi_result(
+                &reference,
+                &title,
+                &config,
+                &client,
+                &local_dbs,
+                &drainer_txs,
+                &db_start_cb,
+                &db_complete_cb,
+            )
+            .await
+        {
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
+            let _ = result_tx.send(result);
+            continue;
+        }
+
+        // --- Local DB phase (inline, <1ms) ---
         let local_result = query_local_databases(
             &title,
-            &reference.authors,
+            config.authors_to_compare(&reference.authors),
+            reference.kind,
             &config,
             &client,
             false,
             None,
+            Some(&db_start_cb),
             Some(&db_complete_cb),
         )
         .await;
@@ -773,8 +1178,29 @@ async fn coordinator_loop(
         if local_result.status == Status::Verified {
             // query_local_databases already emitted Skipped for remaining DBs
             // (including remote) via the on_db_complete callback
-            let result = build_validation_result(&reference, &title, local_result, None);
-            emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
+            let source = local_result.source.clone().unwrap_or_default();
+            let found_title = local_result.found_title.clone();
+            let mut result = build_validation_result(&reference, &title, local_result, None);
+            cross_validate_doi(
+                &reference,
+                &mut result,
+                &source,
+                found_title.as_deref(),
+                &config,
+                &client,
+            )
+            .await;
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
             let _ = result_tx.send(result);
             continue;
         }
@@ -782,11 +1208,16 @@ async fn coordinator_loop(
         // --- Fan out to drainer queues ---
         if drainer_txs.is_empty() {
             // No remote DBs enabled — try SearxNG fallback if configured
-            let result = if local_result.status == Status::NotFound {
+            let mut result = if local_result.status == Status::NotFound {
                 if let Some(ref searxng_url) = config.searxng_url {
                     let searxng = Searxng::new(searxng_url.clone());
                     let timeout = Duration::from_secs(config.db_timeout_secs);
 
+                    progress(ProgressEvent::DatabaseQueryStarted {
+                        paper_index: 0,
+                        ref_index,
+                        db_name: "Web Search".to_string(),
+                    });
                     let start = std::time::Instant::now();
                     let searxng_result = searxng.query(&title, &client, timeout).await;
                     let elapsed = start.elapsed();
@@ -815,6 +1246,8 @@ async fn coordinator_loop(
                         ValidationResult {
                             title: title.clone(),
                             raw_citation: reference.raw_citation.clone(),
+                            cite_key: reference.cite_key.clone(),
+                            cited_in_text: reference.cited_in_text,
                             ref_authors: reference.authors.clone(),
                             status: Status::Verified,
                             source: Some("Web Search".into()),
@@ -823,8 +1256,14 @@ async fn coordinator_loop(
                             failed_dbs: local_result.failed_dbs.clone(),
                             db_results,
                             doi_info: None,
-                            arxiv_info: None, // TODO(#124): implement arXiv ID validation
+                            arxiv_info: None,
                             retraction_info: None,
+                            near_miss: None,
+                            author_match_detail: vec![],
+                            doi_conflict: None,
+                            version_relation: None,
+                            verified_by_count: 0,
+                            insufficient_coverage: false,
                         }
                     } else {
                         progress(ProgressEvent::DatabaseQueryComplete {
@@ -842,7 +1281,18 @@ async fn coordinator_loop(
             } else {
                 build_validation_result(&reference, &title, local_result, None)
             };
-            emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
+            accept_preprint_version(&reference, &mut result, &config);
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
             let _ = result_tx.send(result);
             continue;
         }
@@ -855,7 +1305,8 @@ async fn coordinator_loop(
         let pre = pre_check_remote_cache(
             config.query_cache.as_deref(),
             &title,
-            &reference.authors,
+            config.authors_to_compare(&reference.authors),
+            reference.kind,
             &drainer_txs,
             config.check_openalex_authors,
             reference.doi.is_some(),
@@ -916,21 +1367,17 @@ async fn coordinator_loop(
                 }
             });
 
-            let doi_info = reference.doi.as_ref().map(|doi| {
-                let valid = all_db_results.iter().any(|r| {
-                    r.db_name == "DOI"
-                        && matches!(r.status, DbStatus::Match | DbStatus::AuthorMismatch)
-                });
-                DoiInfo {
-                    doi: doi.clone(),
-                    valid,
-                    title: None,
-                }
-            });
+            let doi_info = reference
+                .doi
+                .as_ref()
+                .map(|doi| cached_doi_info(doi, &title, config.query_cache.as_deref()));
 
-            let result = ValidationResult {
+            let matched_source = verified.source.clone();
+            let mut result = ValidationResult {
                 title: title.clone(),
                 raw_citation: reference.raw_citation.clone(),
+                cite_key: reference.cite_key.clone(),
+                cited_in_text: reference.cited_in_text,
                 ref_authors: reference.authors.clone(),
                 status: Status::Verified,
                 source: Some(verified.source),
@@ -939,11 +1386,36 @@ async fn coordinator_loop(
                 failed_dbs: local_result.failed_dbs,
                 db_results: all_db_results,
                 doi_info,
-                arxiv_info: None, // TODO(#124): implement arXiv ID validation
+                arxiv_info: None,
                 retraction_info,
+                near_miss: None,
+                author_match_detail: vec![],
+                doi_conflict: None,
+                version_relation: None,
+                verified_by_count: 0,
+                insufficient_coverage: false,
             };
-
-            emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
+            cross_validate_doi(
+                &reference,
+                &mut result,
+                &matched_source,
+                verified.found_title.as_deref(),
+                &config,
+                &client,
+            )
+            .await;
+
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
             let _ = result_tx.send(result);
             continue;
         }
@@ -975,10 +1447,17 @@ async fn coordinator_loop(
             } else {
                 (Status::NotFound, None, vec![], None)
             };
+            let near_miss = if status == Status::NotFound {
+                local_result.near_miss
+            } else {
+                None
+            };
 
-            let result = ValidationResult {
+            let mut result = ValidationResult {
                 title: title.clone(),
                 raw_citation: reference.raw_citation.clone(),
+                cite_key: reference.cite_key.clone(),
+                cited_in_text: reference.cited_in_text,
                 ref_authors: reference.authors.clone(),
                 status,
                 source,
@@ -986,16 +1465,32 @@ async fn coordinator_loop(
                 paper_url,
                 failed_dbs: local_result.failed_dbs,
                 db_results: all_db_results,
-                doi_info: reference.doi.as_ref().map(|doi| DoiInfo {
-                    doi: doi.clone(),
-                    valid: false,
-                    title: None,
-                }),
-                arxiv_info: None, // TODO(#124): implement arXiv ID validation
+                doi_info: reference
+                    .doi
+                    .as_ref()
+                    .map(|doi| cached_doi_info(doi, &title, config.query_cache.as_deref())),
+                arxiv_info: None,
                 retraction_info: None,
+                near_miss,
+                author_match_detail: vec![],
+                doi_conflict: None,
+                version_relation: None,
+                verified_by_count: 0,
+                insufficient_coverage: false,
             };
-
-            emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
+            accept_preprint_version(&reference, &mut result, &config);
+
+            emit_final_events(
+                progress.as_ref(),
+                &mut result,
+                &reference,
+                &config,
+                &client,
+                ref_index,
+                total,
+                &title,
+            )
+            .await;
             let _ = result_tx.send(result);
             continue;
         }
@@ -1023,15 +1518,21 @@ async fn coordinator_loop(
             client: client.clone(),
             remaining: AtomicUsize::new(pre.miss_indices.len()),
             verified: AtomicBool::new(false),
+            trusted_match: CancellationToken::new(),
+            cross_check_claimed: AtomicBool::new(false),
             state: Mutex::new(AggState {
                 verified_info: None,
                 first_mismatch,
                 failed_dbs: vec![],
                 db_results: pre.db_results,
                 retraction: pre.retraction,
+                doi_mismatch_title: None,
+                doi_unregistered: false,
+                title_match: None,
             }),
             result_tx: Mutex::new(Some(result_tx)),
             local_result,
+            permit: Mutex::new(Some(permit)),
         });
 
         for &i in &pre.miss_indices {
@@ -1044,6 +1545,191 @@ async fn coordinator_loop(
 
 // ── Helpers ─────────────────────────────────────────────────────────────
 
+/// With [`Config::trust_resolved_doi`], resolve the reference's DOI up front
+/// and, if it matches the cited title and authors, verify via "DOI" with every
+/// other database skipped, then check CrossRef for a retraction of the DOI.
+/// `None` sends the reference down the normal path; DOI's query is then
+/// reported (started and completed) by its drainer, which finds a definitive
+/// resolution in the cache.
+#[allow(clippy::too_many_arguments)]
+async fn trusted_doi_result(
+    reference: &Reference,
+    title: &str,
+    config: &Config,
+    client: &reqwest::Client,
+    local_dbs: &[Arc<dyn DatabaseBackend>],
+    drainer_txs: &[(String, bool, async_channel::Sender<DrainerJob>)],
+    on_db_start: &(dyn Fn(&str) + Send + Sync),
+    on_db_complete: &(dyn Fn(DbResult) + Send + Sync),
+) -> Option<ValidationResult> {
+    let doi = reference.doi.as_deref()?;
+    if !drainer_txs.iter().any(|(name, _, _)| name == "DOI") {
+        return None;
+    }
+
+    let start = std::time::Instant::now();
+    let timeout = Duration::from_secs(config.db_timeout_secs);
+    let resolved = resolve_doi(doi, client, timeout, config.query_cache.as_deref()).await;
+    let DoiMatchResult::Verified { doi_authors, .. } = check_doi_match(
+        &resolved,
+        title,
+        config.authors_to_compare(&reference.authors),
+    ) else {
+        return None;
+    };
+    let elapsed = start.elapsed();
+
+    let paper_url = format!("https://doi.org/{}", doi);
+    let mut db_results = vec![DbResult {
+        db_name: "DOI".into(),
+        status: DbStatus::Match,
+        elapsed: Some(elapsed),
+        found_authors: doi_authors.clone(),
+        paper_url: Some(paper_url.clone()),
+        error_message: None,
+    }];
+    let skipped = local_dbs
+        .iter()
+        .map(|db| db.name())
+        .chain(drainer_txs.iter().map(|(name, _, _)| name.as_str()))
+        .filter(|name| *name != "DOI");
+    for db_name in skipped {
+        db_results.push(DbResult {
+            db_name: db_name.to_string(),
+            status: DbStatus::Skipped,
+            elapsed: None,
+            found_authors: vec![],
+            paper_url: None,
+            error_message: None,
+        });
+    }
+    on_db_start("DOI");
+    for db_result in &db_results {
+        on_db_complete(db_result.clone());
+    }
+
+    let retraction =
+        check_retraction(doi, client, timeout, config.crossref_mailto.as_deref()).await;
+    let retraction_info = if retraction.retracted {
+        Some(crate::RetractionInfo {
+            is_retracted: true,
+            retraction_doi: retraction.retraction_doi,
+            retraction_source: retraction.retraction_type,
+        })
+    } else {
+        None
+    };
+
+    Some(ValidationResult {
+        title: title.to_string(),
+        raw_citation: reference.raw_citation.clone(),
+        cite_key: reference.cite_key.clone(),
+        cited_in_text: reference.cited_in_text,
+        ref_authors: reference.authors.clone(),
+        status: Status::Verified,
+        source: Some("DOI".into()),
+        found_authors: doi_authors,
+        paper_url: Some(paper_url),
+        failed_dbs: vec![],
+        db_results,
+        doi_info: Some(DoiInfo::from_validation(doi, &resolved, title)),
+        arxiv_info: None,
+        retraction_info,
+        near_miss: None,
+        author_match_detail: vec![],
+        doi_conflict: None,
+        version_relation: None,
+        verified_by_count: 0,
+        insufficient_coverage: false,
+    })
+}
+
+/// With [`Config::cross_validate_doi`], resolve the DOI of a reference that
+/// `matched_source`'s title search matched (to `matched_title`, or the cited
+/// title when the backend doesn't report one) and record a [`DoiConflict`] if
+/// the DOI names a different paper. Fills in `doi_info` when the DOI backend
+/// never ran for the reference. A match from one of the
+/// [`trusted_verifiers`](Config::trusted_verifiers) is taken as is.
+pub(crate) async fn cross_validate_doi(
+    reference: &Reference,
+    result: &mut ValidationResult,
+    matched_source: &str,
+    matched_title: Option<&str>,
+    config: &Config,
+    client: &reqwest::Client,
+) {
+    let Some(doi) = reference.doi.as_deref() else {
+        return;
+    };
+    if !config.cross_validate_doi
+        || matched_source == "DOI"
+        || config.is_trusted_verifier(matched_source)
+        || config
+            .disabled_dbs
+            .iter()
+            .any(|d| d.eq_ignore_ascii_case("DOI"))
+    {
+        return;
+    }
+
+    let timeout = Duration::from_secs(config.db_timeout_secs);
+    let resolved = resolve_doi(doi, client, timeout, config.query_cache.as_deref()).await;
+    if result.doi_info.is_none() {
+        result.doi_info = Some(DoiInfo::from_validation(doi, &resolved, &result.title));
+    }
+    let matched_title = matched_title.unwrap_or(&result.title).to_string();
+    if let Some(doi_title) = doi_title_mismatch(&resolved, &matched_title) {
+        result.doi_conflict = Some(DoiConflict {
+            doi: doi.to_string(),
+            doi_title,
+            matched_source: matched_source.to_string(),
+            matched_title,
+        });
+    }
+}
+
+/// With [`Config::accept_preprint_versions`], verify an author mismatch whose
+/// matched record is another version of the cited work (see
+/// [`version_relation`]) and whose authors share a surname with the cited
+/// ones, or list none.
+pub(crate) fn accept_preprint_version(
+    reference: &Reference,
+    result: &mut ValidationResult,
+    config: &Config,
+) {
+    if !config.accept_preprint_versions || result.status != Status::AuthorMismatch {
+        return;
+    }
+    let Some(relation) = result
+        .source
+        .as_deref()
+        .and_then(|source| version_relation(reference, source, result.paper_url.as_deref()))
+    else {
+        return;
+    };
+    if !result.found_authors.is_empty()
+        && !surnames_overlap(&result.ref_authors, &result.found_authors)
+    {
+        return;
+    }
+    result.status = Status::Verified;
+    result.version_relation = Some(relation);
+}
+
+/// Build per-ref callback announcing a DB query about to start.
+fn make_db_start_callback(
+    progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
+    ref_index: usize,
+) -> impl Fn(&str) + Send + Sync {
+    move |db_name: &str| {
+        progress(ProgressEvent::DatabaseQueryStarted {
+            paper_index: 0,
+            ref_index,
+            db_name: db_name.to_string(),
+        });
+    }
+}
+
 /// Build per-ref DB completion callback.
 fn make_db_callback(
     progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
@@ -1060,14 +1746,28 @@ fn make_db_callback(
     }
 }
 
-/// Emit Warning + Result progress events and log the final outcome.
-fn emit_final_events(
+/// Look up the reference's arXiv ID and fill in the per-author match detail,
+/// verified-by count and coverage flag, then emit Warning + Result progress
+/// events and log the final outcome.
+#[allow(clippy::too_many_arguments)]
+async fn emit_final_events(
     progress: &(dyn Fn(ProgressEvent) + Send + Sync),
-    result: &ValidationResult,
+    result: &mut ValidationResult,
+    reference: &Reference,
+    config: &Config,
+    client: &reqwest::Client,
     ref_index: usize,
     total: usize,
     title: &str,
 ) {
+    // A manual override queries nothing, arXiv included
+    if result.source.as_deref() != Some(crate::overrides::OVERRIDE_SOURCE) {
+        result.arxiv_info = lookup_reference_arxiv_id(reference, config, client).await;
+    }
+    result.fill_author_match_detail();
+    result.fill_verified_by_count();
+    result.fill_insufficient_coverage(config);
+
     let status_str = match result.status {
         Status::Verified => "Verified",
         Status::NotFound => "NotFound",
@@ -1116,9 +1816,16 @@ fn build_validation_result(
     db_result: crate::orchestrator::DbSearchResult,
     retraction_info: Option<crate::RetractionInfo>,
 ) -> ValidationResult {
+    let near_miss = if db_result.status == Status::NotFound {
+        db_result.near_miss
+    } else {
+        None
+    };
     ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
+        cite_key: reference.cite_key.clone(),
+        cited_in_text: reference.cited_in_text,
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
@@ -1127,7 +1834,276 @@ fn build_validation_result(
         failed_dbs: db_result.failed_dbs,
         db_results: db_result.db_results,
         doi_info: None,
-        arxiv_info: None, // TODO(#124): implement arXiv ID validation
+        arxiv_info: None,
         retraction_info,
+        near_miss,
+        author_match_detail: vec![],
+        doi_conflict: None,
+        version_relation: None,
+        verified_by_count: 0,
+        insufficient_coverage: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lowering_worker_limit_mid_run_caps_in_flight() {
+        let limit = WorkerLimit::new(8);
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak_before = Arc::new(AtomicUsize::new(0));
+        let peak_after = Arc::new(AtomicUsize::new(0));
+        let lowered = Arc::new(AtomicBool::new(false));
+
+        let mut tasks = Vec::new();
+        for _ in 0..40 {
+            let limit = limit.clone();
+            let in_flight = in_flight.clone();
+            let peak_before = peak_before.clone();
+            let peak_after = peak_after.clone();
+            let lowered = lowered.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = limit.acquire().await;
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                if lowered.load(Ordering::SeqCst) {
+                    peak_after.fetch_max(now, Ordering::SeqCst);
+                } else {
+                    peak_before.fetch_max(now, Ordering::SeqCst);
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+
+        // Let the first wave take all eight permits, then cut to two.
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        lowered.store(true, Ordering::SeqCst);
+        limit.set(2);
+        assert_eq!(limit.get(), 2);
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(peak_before.load(Ordering::SeqCst), 8);
+        assert!(peak_after.load(Ordering::SeqCst) <= 2);
+
+        // Every retired permit has been paid off; raising the limit again
+        // hands out exactly the difference.
+        assert_eq!(limit.semaphore.available_permits(), 2);
+        limit.set(5);
+        assert_eq!(limit.semaphore.available_permits(), 5);
+    }
+
+    #[test]
+    fn test_cache_pre_check_prefers_kind_source() {
+        let cache = crate::cache::QueryCache::new(Duration::from_secs(60), Duration::from_secs(60));
+        let title = "Learning Low-Level Vision";
+        let found = crate::db::DbQueryResult::found(title, vec!["William Freeman".into()], None);
+        cache.insert(title, "CrossRef", &found);
+        cache.insert(title, "arXiv", &found);
+
+        let drainer_txs: Vec<_> = ["CrossRef", "arXiv"]
+            .into_iter()
+            .map(|name| (name.to_string(), false, async_channel::unbounded().0))
+            .collect();
+        let source = |kind| {
+            pre_check_remote_cache(
+                Some(&cache),
+                title,
+                &["William Freeman".into()],
+                kind,
+                &drainer_txs,
+                false,
+                false,
+            )
+            .verified_info
+            .unwrap()
+            .source
+        };
+        assert_eq!(source(RefKind::Unknown), "CrossRef");
+        assert_eq!(source(RefKind::Preprint), "arXiv");
+    }
+
+    #[tokio::test]
+    async fn test_cross_validation_adds_one_title_search_per_ref() {
+        use crate::db::mock::{MockDb, MockResponse};
+
+        let found = MockResponse::Found {
+            title: "Found".into(),
+            authors: vec![],
+            url: None,
+        };
+        // "DOI" (throttled to 3/s) answers both refs before the slow title
+        // searches reach the second one.
+        let doi = Arc::new(MockDb::new("DOI", found.clone()).with_delay(Duration::from_millis(10)));
+        let title_dbs: Vec<Arc<MockDb>> = ["Title A", "Title B", "Title C"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(MockDb::new(name, found.clone()).with_delay(Duration::from_millis(600)))
+            })
+            .collect();
+        let mut all_dbs: Vec<Arc<dyn DatabaseBackend>> = vec![doi.clone()];
+        all_dbs.extend(
+            title_dbs
+                .iter()
+                .map(|db| db.clone() as Arc<dyn DatabaseBackend>),
+        );
+
+        let config = Config {
+            cross_validate_doi: true,
+            // Keeps the finalizing cross-check itself offline
+            disabled_dbs: vec!["DOI".into()],
+            ..Config::default()
+        };
+        let pool =
+            ValidationPool::with_backends(Arc::new(config), CancellationToken::new(), 2, all_dbs)
+                .unwrap();
+        let mut receivers = Vec::new();
+        for (i, title) in ["First Cited Paper Title", "Second Cited Paper Title"]
+            .into_iter()
+            .enumerate()
+        {
+            let (result_tx, result_rx) = oneshot::channel();
+            let mut reference = Reference::new(title);
+            reference.doi = Some(format!("10.1000/{}", i));
+            pool.submit(RefJob {
+                reference,
+                result_tx,
+                ref_index: i,
+                total: 2,
+                progress: Arc::new(|_| {}),
+            })
+            .await;
+            receivers.push(result_rx);
+        }
+        for rx in receivers {
+            assert_eq!(rx.await.unwrap().status, Status::Verified);
+        }
+        pool.shutdown().await;
+
+        // All three title searches were already in flight for the first ref;
+        // the second, verified by then, gets exactly one.
+        assert_eq!(doi.call_count(), 2);
+        let title_queries: usize = title_dbs.iter().map(|db| db.call_count()).sum();
+        assert_eq!(title_queries, 4);
+    }
+
+    #[tokio::test]
+    async fn test_trusted_match_cancels_in_flight_queries() {
+        use crate::db::mock::{MockDb, MockResponse};
+
+        let found = MockResponse::Found {
+            title: "Found".into(),
+            authors: vec![],
+            url: None,
+        };
+        let slow_delay = Duration::from_secs(5);
+        let slow = Arc::new(MockDb::new("Slow", found.clone()).with_delay(slow_delay));
+        let trusted = Arc::new(MockDb::new("Trusted", found).with_delay(Duration::from_millis(50)));
+        let all_dbs: Vec<Arc<dyn DatabaseBackend>> = vec![slow.clone(), trusted.clone()];
+
+        let config = Config {
+            trusted_verifiers: vec!["trusted".into()],
+            ..Config::default()
+        };
+        let pool =
+            ValidationPool::with_backends(Arc::new(config), CancellationToken::new(), 1, all_dbs)
+                .unwrap();
+        let (result_tx, result_rx) = oneshot::channel();
+        let start = std::time::Instant::now();
+        pool.submit(RefJob {
+            reference: Reference::new("A Cited Paper With A Long Title"),
+            result_tx,
+            ref_index: 0,
+            total: 1,
+            progress: Arc::new(|_| {}),
+        })
+        .await;
+        let result = result_rx.await.unwrap();
+        pool.shutdown().await;
+
+        // The slow query was already in flight when the trusted match came in
+        assert!(start.elapsed() < slow_delay, "{:?}", start.elapsed());
+        assert_eq!(slow.call_count(), 1);
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.source.as_deref(), Some("Trusted"));
+        let slow_status = result
+            .db_results
+            .iter()
+            .find(|r| r.db_name == "Slow")
+            .map(|r| r.status.clone());
+        assert_eq!(slow_status, Some(DbStatus::Skipped));
+    }
+
+    #[tokio::test]
+    async fn test_local_verified_reference_gets_arxiv_published_doi() {
+        use hallucinator_dblp::db::{
+            SCHEMA_VERSION, init_database, insert_or_get_author, insert_or_get_publication,
+            rebuild_fts_index, set_metadata,
+        };
+
+        let title = "Deep Residual Learning for Image Recognition";
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dblp.db");
+        {
+            let conn = rusqlite::Connection::open(&path).unwrap();
+            init_database(&conn).unwrap();
+            let pub_id =
+                insert_or_get_publication(&conn, "conf/cvpr/HeZRS16", title, None).unwrap();
+            let author_id = insert_or_get_author(&conn, "Kaiming He").unwrap();
+            conn.execute(
+                "INSERT INTO publication_authors (pub_id, author_id) VALUES (?1, ?2)",
+                (pub_id, author_id),
+            )
+            .unwrap();
+            rebuild_fts_index(&conn).unwrap();
+            set_metadata(&conn, "schema_version", SCHEMA_VERSION).unwrap();
+        }
+
+        // The arXiv ID lookup is answered from the cache, so nothing is sent
+        let cache = Arc::new(crate::QueryCache::default());
+        crate::db::arxiv::cache_id_lookup(
+            &cache,
+            &crate::ArxivInfo {
+                arxiv_id: "1512.03385".into(),
+                valid: true,
+                title: Some(title.into()),
+                published_doi: Some("10.1109/CVPR.2016.90".into()),
+            },
+        );
+        let db = hallucinator_dblp::DblpDatabase::open(&path).unwrap();
+        let config = Config {
+            disabled_dbs: [
+                "CrossRef",
+                "Semantic Scholar",
+                "ACL Anthology",
+                "Europe PMC",
+                "PubMed",
+                "OpenAlex",
+                "DOI",
+            ]
+            .map(String::from)
+            .to_vec(),
+            dblp_offline_db: Some(Arc::new(std::sync::Mutex::new(db))),
+            query_cache: Some(cache),
+            ..Config::default()
+        };
+
+        let reference = Reference::new(title)
+            .authors(["Kaiming He"])
+            .arxiv_id("1512.03385");
+        let results =
+            crate::check_references(vec![reference], config, |_| {}, CancellationToken::new())
+                .await
+                .unwrap();
+
+        assert_eq!(results[0].source.as_deref(), Some("DBLP"));
+        let arxiv_info = results[0].arxiv_info.as_ref().unwrap();
+        assert_eq!(
+            arxiv_info.published_doi.as_deref(),
+            Some("10.1109/CVPR.2016.90")
+        );
     }
 }
@@ -1,10 +1,11 @@
 //! Per-database rate limiting with adaptive governor instances.
 //!
 //! Each DB query waits for its governor permit via `until_ready()`, which
-//! spaces requests at the configured rate. On 429, the governor is slowed
-//! and the error is returned immediately.
+//! spaces requests at the configured rate. Every query outcome is recorded;
+//! when 429s make up too much of a backend's recent traffic the governor is
+//! slowed, and a long enough run of successes speeds it back up.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::{Duration, Instant};
@@ -49,18 +50,66 @@ impl From<String> for DbQueryError {
     }
 }
 
+/// Number of recent outcomes per backend the 429 share is computed over.
+const OUTCOME_WINDOW: usize = 10;
+
+/// Share of 429s among recent outcomes at which the rate is halved.
+const TIGHTEN_RATIO: f64 = 0.3;
+
+/// Outcomes needed before the 429 share is trusted, so one stray 429 (or the
+/// first response after a rate change) can't halve the rate on its own.
+const MIN_OUTCOMES: usize = 3;
+
+/// Consecutive successes after which a slowed rate is doubled again.
+const LOOSEN_AFTER_SUCCESSES: u32 = 20;
+
+/// Largest slowdown factor.
+const MAX_FACTOR: u32 = 16;
+
 /// Per-DB rate limiter with adaptive rate adjustment via ArcSwap.
 ///
-/// When a 429 is received, the governor is atomically swapped to a slower rate.
-/// After a cooldown period (60s) with no 429s, the original rate is restored.
+/// Tracks the backend's recent successes and 429s. When 429s reach
+/// [`TIGHTEN_RATIO`] of the last [`OUTCOME_WINDOW`] responses the governor is
+/// atomically swapped to half the rate (down to 1/[`MAX_FACTOR`]); after
+/// [`LOOSEN_AFTER_SUCCESSES`] successes in a row it doubles again, one step
+/// at a time, back to the base rate.
 pub struct AdaptiveDbLimiter {
     limiter: ArcSwap<DirectLimiter>,
     /// Base period between allowed requests.
     base_period: Duration,
     /// Current slowdown factor (1 = normal, 2 = half rate, etc.).
     current_factor: AtomicU32,
-    /// Timestamp of the last 429 response.
-    last_429: std::sync::Mutex<Option<Instant>>,
+    outcomes: std::sync::Mutex<Outcomes>,
+    /// When the latest 429 backoff (a `Retry-After` sleep) ends.
+    backoff_until: std::sync::Mutex<Option<Instant>>,
+}
+
+/// Recent query outcomes for one backend.
+#[derive(Default)]
+struct Outcomes {
+    /// `true` for a 429, oldest first; at most [`OUTCOME_WINDOW`] entries.
+    recent: VecDeque<bool>,
+    /// Successes since the last 429 or rate change.
+    success_streak: u32,
+}
+
+impl Outcomes {
+    fn record(&mut self, rate_limited: bool) {
+        if self.recent.len() == OUTCOME_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(rate_limited);
+        if rate_limited {
+            self.success_streak = 0;
+        } else {
+            self.success_streak += 1;
+        }
+    }
+
+    fn rate_limited_share(&self) -> f64 {
+        let limited = self.recent.iter().filter(|&&l| l).count();
+        limited as f64 / self.recent.len().max(1) as f64
+    }
 }
 
 impl AdaptiveDbLimiter {
@@ -72,7 +121,8 @@ impl AdaptiveDbLimiter {
             limiter: ArcSwap::from(limiter),
             base_period: period,
             current_factor: AtomicU32::new(1),
-            last_429: std::sync::Mutex::new(None),
+            outcomes: std::sync::Mutex::new(Outcomes::default()),
+            backoff_until: std::sync::Mutex::new(None),
         }
     }
 
@@ -87,48 +137,68 @@ impl AdaptiveDbLimiter {
     /// Blocks the calling future until a token is available. This naturally
     /// spaces requests at the configured rate across all concurrent callers.
     pub async fn acquire(&self) {
-        self.try_decay();
         let limiter = self.limiter.load();
         limiter.until_ready().await;
     }
 
-    /// Called when a 429 is received. Doubles the slowdown factor and swaps the governor.
-    pub fn on_rate_limited(&self) {
-        if let Ok(mut last) = self.last_429.lock() {
-            *last = Some(Instant::now());
+    /// Record a 429. Halves the rate if 429s now make up too much of the
+    /// recent traffic, returning the new slowdown factor.
+    pub fn on_rate_limited(&self) -> Option<u32> {
+        let mut outcomes = self.outcomes.lock().unwrap_or_else(|e| e.into_inner());
+        outcomes.record(true);
+        let factor = self.current_factor.load(Ordering::SeqCst);
+        if factor >= MAX_FACTOR
+            || outcomes.recent.len() < MIN_OUTCOMES
+            || outcomes.rate_limited_share() < TIGHTEN_RATIO
+        {
+            return None;
         }
+        // Judge the new rate on fresh evidence.
+        *outcomes = Outcomes::default();
+        Some(self.set_factor(factor * 2))
+    }
 
-        // Double factor, cap at 16x slowdown
-        let _ = self
-            .current_factor
-            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |f| {
-                Some((f * 2).min(16))
-            });
-
+    /// Record a successful (non-429) response. Doubles a slowed rate after a
+    /// sustained run of successes, returning the new slowdown factor.
+    pub fn on_success(&self) -> Option<u32> {
+        let mut outcomes = self.outcomes.lock().unwrap_or_else(|e| e.into_inner());
+        outcomes.record(false);
         let factor = self.current_factor.load(Ordering::SeqCst);
-        if let Some(scaled) = self.base_period.checked_mul(factor)
-            && let Some(quota) = Quota::with_period(scaled)
-        {
-            let new_limiter = Arc::new(DirectLimiter::direct(quota));
-            self.limiter.store(new_limiter);
+        if factor <= 1 || outcomes.success_streak < LOOSEN_AFTER_SUCCESSES {
+            return None;
+        }
+        *outcomes = Outcomes::default();
+        Some(self.set_factor(factor / 2))
+    }
+
+    /// Note that a query is sleeping `wait` before retrying a 429.
+    pub fn start_backoff(&self, wait: Duration) {
+        let until = Instant::now() + wait;
+        let mut backoff = self.backoff_until.lock().unwrap_or_else(|e| e.into_inner());
+        if backoff.is_none_or(|current| current < until) {
+            *backoff = Some(until);
         }
     }
 
-    /// If 30s have passed since the last 429, restore the original rate.
-    fn try_decay(&self) {
-        let should_restore = self
-            .last_429
-            .lock()
-            .ok()
-            .and_then(|last| last.map(|t| t.elapsed().as_secs() >= 30))
-            .unwrap_or(false);
-
-        if should_restore && self.current_factor.load(Ordering::SeqCst) > 1 {
-            self.current_factor.store(1, Ordering::SeqCst);
-            let quota = Quota::with_period(self.base_period).expect("base period valid");
-            let limiter = Arc::new(DirectLimiter::direct(quota));
-            self.limiter.store(limiter);
+    /// Time left until the latest 429 backoff ends, or `None` if the backend
+    /// isn't backing off.
+    pub fn backoff_remaining(&self) -> Option<Duration> {
+        let backoff = self.backoff_until.lock().unwrap_or_else(|e| e.into_inner());
+        backoff
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Swap in a governor running at `base_period * factor`.
+    fn set_factor(&self, factor: u32) -> u32 {
+        let factor = factor.clamp(1, MAX_FACTOR);
+        self.current_factor.store(factor, Ordering::SeqCst);
+        if let Some(scaled) = self.base_period.checked_mul(factor)
+            && let Some(quota) = Quota::with_period(scaled)
+        {
+            self.limiter.store(Arc::new(DirectLimiter::direct(quota)));
         }
+        factor
     }
 }
 
@@ -203,6 +273,11 @@ impl RateLimiters {
             .map(|l| l.current_factor.load(Ordering::Relaxed))
             .unwrap_or(1)
     }
+
+    /// Time left in a database's current 429 backoff, if it is in one.
+    pub fn backoff_remaining(&self, db_name: &str) -> Option<Duration> {
+        self.limiters.get(db_name)?.backoff_remaining()
+    }
 }
 
 /// Check if an HTTP response is a 429 and extract Retry-After if present.
@@ -238,7 +313,8 @@ pub fn parse_retry_after(value: &str) -> Option<Duration> {
 /// Query a database with proactive governor rate limiting.
 ///
 /// 1. Acquires the per-DB governor (waits if needed)
-/// 2. Calls `db.query()` (or `db.query_doi()` if `doi_context` is provided)
+/// 2. Calls `db.query()` (or `db.query_doi()` / `db.query_arxiv_id()` if
+///    `doi_context` carries the identifier)
 /// 3. On 429: adapts governor to slower rate and returns error immediately
 ///    (the pool-level retry queue will re-check failed DBs later)
 /// 4. On other errors or success: returns immediately
@@ -250,27 +326,53 @@ pub struct RateLimitedResult {
     pub elapsed: Duration,
 }
 
-/// Context for DOI-based queries, passed to backends that support `query_doi`.
+/// Context for identifier-based queries, passed to backends that support
+/// `query_doi` or `query_arxiv_id`.
 pub struct DoiContext<'a> {
-    pub doi: &'a str,
+    pub doi: Option<&'a str>,
+    pub arxiv_id: Option<&'a str>,
     pub authors: &'a [String],
 }
 
-/// Execute the appropriate query for a backend, trying `query_doi` first if context is provided.
+/// Execute the appropriate query for a backend, trying `query_doi` and then
+/// `query_arxiv_id` first if context is provided.
+///
+/// The caller holds a `limiter` permit for the first request; each further
+/// one (an ID lookup that fell through, see
+/// [`DatabaseBackend::sends_id_lookups`]) waits for its own.
 async fn execute_query(
     db: &dyn DatabaseBackend,
     title: &str,
     client: &reqwest::Client,
     timeout: Duration,
     doi_context: Option<&DoiContext<'_>>,
+    limiter: Option<&AdaptiveDbLimiter>,
 ) -> Result<DbQueryResult, DbQueryError> {
-    if let Some(ctx) = doi_context
-        && let Some(result) = db
-            .query_doi(ctx.doi, title, ctx.authors, client, timeout)
-            .await
-    {
-        return result;
+    let mut permit_spent = false;
+    let mut next_request = async |sends: bool| {
+        if !sends {
+            return;
+        }
+        if permit_spent && let Some(lim) = limiter {
+            lim.acquire().await;
+        }
+        permit_spent = true;
+    };
+    if let Some(ctx) = doi_context {
+        if let Some(doi) = ctx.doi {
+            next_request(db.sends_id_lookups()).await;
+            if let Some(result) = db.query_doi(doi, title, ctx.authors, client, timeout).await {
+                return result;
+            }
+        }
+        if let Some(arxiv_id) = ctx.arxiv_id {
+            next_request(db.sends_id_lookups()).await;
+            if let Some(result) = db.query_arxiv_id(arxiv_id, title, client, timeout).await {
+                return result;
+            }
+        }
     }
+    next_request(true).await;
     db.query(title, client, timeout).await
 }
 
@@ -284,8 +386,10 @@ pub async fn query_with_rate_limit(
     doi_context: Option<&DoiContext<'_>>,
 ) -> RateLimitedResult {
     // Check cache before making any network request or waiting on the governor.
-    // Skip cache for local/offline backends — they have their own SQLite DBs.
-    let use_cache = !db.is_local();
+    // Skip cache for local/offline backends — they have their own SQLite DBs —
+    // and for DOI-keyed backends, whose resolutions are cached by DOI: a title
+    // entry would drop whether the DOI was registered or named another paper.
+    let use_cache = !db.is_local() && !db.requires_doi();
     if use_cache
         && let Some(c) = cache
         && let Some(cached_result) = c.get(title, db.name())
@@ -313,12 +417,14 @@ pub async fn query_with_rate_limit(
     tracing::debug!(db = db.name(), title, "query start");
     let start = Instant::now();
 
-    let result = match execute_query(db, title, client, timeout, doi_context).await {
+    let first = execute_query(db, title, client, timeout, doi_context, limiter).await;
+    let first_attempt_ok = first.is_ok();
+    let result = match first {
         Ok(result) => Ok(result),
         Err(DbQueryError::RateLimited { retry_after }) => {
             // Adapt governor to slower rate so subsequent requests are throttled
             if let Some(lim) = limiter {
-                lim.on_rate_limited();
+                record_outcome(db.name(), lim, true);
             }
 
             // Honor Retry-After: sleep then retry once instead of bailing.
@@ -331,6 +437,9 @@ pub async fn query_with_rate_limit(
                 wait_secs = wait.as_secs_f64(),
                 "429 rate limited, retrying"
             );
+            if let Some(lim) = limiter {
+                lim.start_backoff(wait);
+            }
             tokio::time::sleep(wait).await;
 
             // Re-acquire governor token after sleeping
@@ -339,10 +448,20 @@ pub async fn query_with_rate_limit(
             }
 
             // Single retry — if still 429, give up
-            execute_query(db, title, client, timeout, doi_context).await
+            let retried = execute_query(db, title, client, timeout, doi_context, limiter).await;
+            if let Some(lim) = limiter {
+                let rate_limited = matches!(retried, Err(DbQueryError::RateLimited { .. }));
+                record_outcome(db.name(), lim, rate_limited);
+            }
+            retried
         }
         Err(other) => Err(other),
     };
+    if let Some(lim) = limiter
+        && first_attempt_ok
+    {
+        record_outcome(db.name(), lim, false);
+    }
 
     // Cache successful results (found or not-found); never cache errors.
     // Skip cache for local/offline backends.
@@ -365,6 +484,34 @@ pub async fn query_with_rate_limit(
     RateLimitedResult { result, elapsed }
 }
 
+/// Feed one response into the backend's adaptive limiter, logging any
+/// resulting rate change.
+pub(crate) fn record_outcome(db_name: &str, limiter: &AdaptiveDbLimiter, rate_limited: bool) {
+    let changed = if rate_limited {
+        limiter.on_rate_limited()
+    } else {
+        limiter.on_success()
+    };
+    if let Some(factor) = changed {
+        let period = limiter.base_period * factor;
+        if rate_limited {
+            tracing::warn!(
+                db = db_name,
+                factor,
+                period_ms = period.as_millis() as u64,
+                "sustained 429s, slowing request rate"
+            );
+        } else {
+            tracing::info!(
+                db = db_name,
+                factor,
+                period_ms = period.as_millis() as u64,
+                "requests succeeding again, raising request rate"
+            );
+        }
+    }
+}
+
 /// Legacy wrapper: calls [`query_with_rate_limit`] (ignores `max_retries`).
 ///
 /// Kept for API compatibility; inline retry has been replaced by
@@ -462,18 +609,22 @@ mod tests {
     }
 
     #[test]
-    fn on_rate_limited_doubles() {
+    fn repeated_429s_double() {
         let limiter = AdaptiveDbLimiter::per_second(10);
-        limiter.on_rate_limited();
+        assert_eq!(limiter.on_rate_limited(), None);
+        assert_eq!(limiter.on_rate_limited(), None);
+        assert_eq!(limiter.on_rate_limited(), Some(2));
         assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 2);
-        limiter.on_rate_limited();
+        for _ in 0..MIN_OUTCOMES {
+            limiter.on_rate_limited();
+        }
         assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 4);
     }
 
     #[test]
     fn factor_caps_at_16() {
         let limiter = AdaptiveDbLimiter::per_second(10);
-        for _ in 0..10 {
+        for _ in 0..30 {
             limiter.on_rate_limited();
         }
         assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 16);
@@ -486,22 +637,50 @@ mod tests {
         limiter.acquire().await;
     }
 
-    #[tokio::test]
-    async fn decay_restores_after_30s() {
+    #[test]
+    fn occasional_429_among_successes_keeps_rate() {
         let limiter = AdaptiveDbLimiter::per_second(10);
-        limiter.on_rate_limited();
-        limiter.on_rate_limited();
-        assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 4);
+        for _ in 0..9 {
+            assert_eq!(limiter.on_success(), None);
+        }
+        assert_eq!(limiter.on_rate_limited(), None);
+        assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 1);
+    }
 
-        // Manually backdate last_429 to 31 seconds ago
-        {
-            let mut last = limiter.last_429.lock().unwrap();
-            *last = Some(Instant::now() - Duration::from_secs(31));
+    #[test]
+    fn burst_of_429s_tightens_then_successes_loosen() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        for _ in 0..OUTCOME_WINDOW {
+            limiter.on_success();
         }
+        // 429s push out successes until they reach 30% of the window.
+        assert_eq!(limiter.on_rate_limited(), None);
+        assert_eq!(limiter.on_rate_limited(), None);
+        assert_eq!(limiter.on_rate_limited(), Some(2));
 
-        // acquire() calls try_decay() internally
-        limiter.acquire().await;
-        assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 1);
+        for _ in 0..LOOSEN_AFTER_SUCCESSES - 1 {
+            assert_eq!(limiter.on_success(), None);
+        }
+        assert_eq!(limiter.on_success(), Some(1));
+        // Already at the base rate: nothing left to loosen.
+        for _ in 0..LOOSEN_AFTER_SUCCESSES {
+            assert_eq!(limiter.on_success(), None);
+        }
+    }
+
+    #[test]
+    fn backoff_keeps_the_latest_deadline() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        assert_eq!(limiter.backoff_remaining(), None);
+
+        limiter.start_backoff(Duration::from_secs(30));
+        limiter.start_backoff(Duration::from_secs(5));
+        let left = limiter.backoff_remaining().unwrap();
+        assert!(left > Duration::from_secs(25) && left <= Duration::from_secs(30));
+
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        limiter.start_backoff(Duration::ZERO);
+        assert_eq!(limiter.backoff_remaining(), None);
     }
 
     // ── RateLimiters ───────────────────────────────────────────────────
@@ -808,4 +987,57 @@ mod tests {
         assert!(rl_result.result.is_err());
         assert!(cache.is_empty()); // errors not cached
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limited_burst_slows_backend() {
+        // Named after a backend that has a limiter in `RateLimiters`.
+        let db = MockDb::new("CrossRef", MockResponse::RateLimited { retry_after: None });
+        let client = reqwest::Client::new();
+        let limiters = RateLimiters::new(false, false);
+        assert_eq!(limiters.backoff_factor("CrossRef"), 1);
+
+        for _ in 0..3 {
+            query_with_rate_limit(
+                &db,
+                "A Paper",
+                &client,
+                Duration::from_secs(10),
+                &limiters,
+                None,
+                None,
+            )
+            .await;
+        }
+        assert!(limiters.backoff_factor("CrossRef") > 1);
+    }
+
+    #[tokio::test]
+    async fn each_id_lookup_request_takes_a_permit() {
+        // DOI lookup, arXiv lookup and title search: three requests
+        let db = MockDb::new("TestDB", MockResponse::NotFound).with_id_lookups();
+        let client = reqwest::Client::new();
+        let period = Duration::from_millis(100);
+        let limiters = RateLimiters {
+            limiters: HashMap::from([("TestDB", AdaptiveDbLimiter::new(period))]),
+        };
+        let ctx = DoiContext {
+            doi: Some("10.1234/test"),
+            arxiv_id: Some("1706.03762"),
+            authors: &[],
+        };
+
+        let start = Instant::now();
+        query_with_rate_limit(
+            &db,
+            "A Paper",
+            &client,
+            Duration::from_secs(10),
+            &limiters,
+            None,
+            Some(&ctx),
+        )
+        .await;
+        assert_eq!(db.call_count(), 3);
+        assert!(start.elapsed() >= period * 2, "{:?}", start.elapsed());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::http::SendLogged;
 use crate::matching::titles_match;
 use std::time::Duration;
 
@@ -70,18 +71,15 @@ pub async fn check_retraction(
         return RetractionResult::default();
     }
 
-    let user_agent = match mailto {
-        Some(email) => format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email),
-        None => "HallucinatedReferenceChecker/1.0 (mailto:hallucination-checker@example.com)"
-            .to_string(),
-    };
+    let mut url = format!("https://api.crossref.org/works/{}", doi);
+    if let Some(email) = mailto {
+        url.push_str(&format!("?mailto={}", urlencoding::encode(email)));
+    }
 
-    let url = format!("https://api.crossref.org/works/{}", doi);
     let resp = match client
         .get(&url)
-        .header("User-Agent", &user_agent)
         .timeout(timeout)
-        .send()
+        .send_logged("Retraction")
         .await
     {
         Ok(r) => r,
@@ -128,22 +126,18 @@ pub async fn check_retraction_by_title(
         return RetractionResult::default();
     }
 
-    let url = format!(
+    let mut url = format!(
         "https://api.crossref.org/works?query.title={}&filter=has-update:true&rows=5",
         urlencoding::encode(title)
     );
-
-    let user_agent = match mailto {
-        Some(email) => format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email),
-        None => "HallucinatedReferenceChecker/1.0 (mailto:hallucination-checker@example.com)"
-            .to_string(),
-    };
+    if let Some(email) = mailto {
+        url.push_str(&format!("&mailto={}", urlencoding::encode(email)));
+    }
 
     let resp = match client
         .get(&url)
-        .header("User-Agent", &user_agent)
         .timeout(timeout)
-        .send()
+        .send_logged("Retraction")
         .await
     {
         Ok(r) => r,
@@ -0,0 +1,824 @@
+//! Canonical serde schema for a completed run: papers → references →
+//! validation results.
+//!
+//! This is the JSON shape written by the TUI's auto-save and JSON export,
+//! read back by `--load`, and sent as the `run` field of the web server's
+//! `complete` event, so results are portable between tools. A run serializes
+//! as a JSON array of [`PaperRecord`]s; [`parse_run`] also accepts a single
+//! paper object.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ArxivInfo, CheckStats, DbResult, DbStatus, DoiConflict, DoiInfo, RetractionInfo, Status,
+    ValidationResult, VersionRelation,
+};
+
+/// One checked paper and its references.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaperRecord {
+    pub filename: String,
+    /// The document's own title, if one was extracted.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// User verdict: `"safe"`, `"questionable"`, or null.
+    #[serde(default)]
+    pub verdict: Option<String>,
+    #[serde(default)]
+    pub stats: Option<RunStats>,
+    pub references: Vec<ReferenceRecord>,
+}
+
+/// Per-paper counts. Consumers may recompute them from `references`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunStats {
+    pub total: usize,
+    pub verified: usize,
+    pub not_found: usize,
+    pub author_mismatch: usize,
+    pub retracted: usize,
+    pub skipped: usize,
+    pub problematic_pct: f64,
+}
+
+/// Status of a reference in a saved run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStatus {
+    Verified,
+    NotFound,
+    AuthorMismatch,
+    Skipped,
+    /// Not yet checked (or an unrecognized status from another tool).
+    #[serde(other)]
+    Pending,
+}
+
+impl RecordStatus {
+    /// The serialized name, e.g. `"not_found"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RecordStatus::Verified => "verified",
+            RecordStatus::NotFound => "not_found",
+            RecordStatus::AuthorMismatch => "author_mismatch",
+            RecordStatus::Skipped => "skipped",
+            RecordStatus::Pending => "pending",
+        }
+    }
+
+    /// The validation status, if this reference has a result.
+    pub fn to_status(self) -> Option<Status> {
+        match self {
+            RecordStatus::Verified => Some(Status::Verified),
+            RecordStatus::NotFound => Some(Status::NotFound),
+            RecordStatus::AuthorMismatch => Some(Status::AuthorMismatch),
+            RecordStatus::Skipped | RecordStatus::Pending => None,
+        }
+    }
+}
+
+impl From<&Status> for RecordStatus {
+    fn from(status: &Status) -> Self {
+        match status {
+            Status::Verified => RecordStatus::Verified,
+            Status::NotFound => RecordStatus::NotFound,
+            Status::AuthorMismatch => RecordStatus::AuthorMismatch,
+        }
+    }
+}
+
+/// One reference and, when checked, its validation result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceRecord {
+    /// 0-based position in the paper's reference list.
+    pub index: usize,
+    /// 1-based reference number from the document (before skip filtering).
+    #[serde(default)]
+    pub original_number: Option<usize>,
+    /// Stable cross-run identity (see [`crate::Reference::fingerprint`]);
+    /// absent in older saves.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub raw_citation: String,
+    /// LaTeX cite key (see [`crate::Reference::cite_key`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cite_key: Option<String>,
+    pub status: RecordStatus,
+    /// Status after applying `fp_reason` (a false positive counts as verified).
+    #[serde(default)]
+    pub effective_status: Option<RecordStatus>,
+    /// Why extraction skipped this reference (e.g. `"url_only"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+    /// User false-positive override (e.g. `"known_good"`).
+    #[serde(default)]
+    pub fp_reason: Option<String>,
+    /// Legacy flag from older saves; superseded by `fp_reason`.
+    #[serde(default, skip_serializing)]
+    pub marked_safe: Option<bool>,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub ref_authors: Vec<String>,
+    #[serde(default)]
+    pub found_authors: Vec<String>,
+    #[serde(default)]
+    pub paper_url: Option<String>,
+    #[serde(default)]
+    pub failed_dbs: Vec<String>,
+    /// Legacy flag from older saves; superseded by `retraction_info`.
+    #[serde(default, skip_serializing)]
+    pub retracted: Option<bool>,
+    #[serde(default)]
+    pub doi_info: Option<DoiInfoRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi_conflict: Option<DoiConflictRecord>,
+    /// `published_version` or `preprint_version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_relation: Option<String>,
+    #[serde(default)]
+    pub arxiv_info: Option<ArxivInfoRecord>,
+    #[serde(default)]
+    pub retraction_info: Option<RetractionInfoRecord>,
+    #[serde(default)]
+    pub near_miss: Option<NearMissRecord>,
+    /// See [`ValidationResult::insufficient_coverage`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub insufficient_coverage: bool,
+    /// See [`ValidationResult::cited_in_text`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cited_in_text: Option<bool>,
+    #[serde(default)]
+    pub db_results: Vec<DbResultRecord>,
+}
+
+/// Closest offline candidate for a not-found reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NearMissRecord {
+    pub title: String,
+    pub score: f64,
+}
+
+/// Outcome of querying one database for a reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DbResultRecord {
+    pub db: String,
+    /// `match`, `no_match`, `author_mismatch`, `timeout`, `rate_limited`,
+    /// `error`, or `skipped`.
+    pub status: String,
+    #[serde(default)]
+    pub elapsed_ms: Option<u64>,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// DOI lookup for a reference (see [`DoiInfo`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DoiInfoRecord {
+    pub doi: String,
+    pub valid: bool,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub title_mismatch: bool,
+    #[serde(default = "default_true")]
+    pub well_formed: bool,
+    #[serde(default = "default_true")]
+    pub registered: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// DOI and title search disagreement (see [`DoiConflict`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DoiConflictRecord {
+    pub doi: String,
+    pub doi_title: String,
+    pub matched_source: String,
+    pub matched_title: String,
+}
+
+/// arXiv lookup for a reference (see [`ArxivInfo`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArxivInfoRecord {
+    pub arxiv_id: String,
+    pub valid: bool,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub published_doi: Option<String>,
+}
+
+/// Retraction check for a reference (see [`RetractionInfo`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetractionInfoRecord {
+    pub is_retracted: bool,
+    pub retraction_doi: Option<String>,
+    pub retraction_source: Option<String>,
+}
+
+impl From<&DoiInfo> for DoiInfoRecord {
+    fn from(info: &DoiInfo) -> Self {
+        DoiInfoRecord {
+            doi: info.doi.clone(),
+            valid: info.valid,
+            title: info.title.clone(),
+            title_mismatch: info.title_mismatch,
+            well_formed: info.well_formed,
+            registered: info.registered,
+        }
+    }
+}
+
+impl From<&DoiInfoRecord> for DoiInfo {
+    fn from(record: &DoiInfoRecord) -> Self {
+        DoiInfo {
+            doi: record.doi.clone(),
+            valid: record.valid,
+            title: record.title.clone(),
+            title_mismatch: record.title_mismatch,
+            well_formed: record.well_formed,
+            registered: record.registered,
+        }
+    }
+}
+
+impl From<&DoiConflict> for DoiConflictRecord {
+    fn from(conflict: &DoiConflict) -> Self {
+        DoiConflictRecord {
+            doi: conflict.doi.clone(),
+            doi_title: conflict.doi_title.clone(),
+            matched_source: conflict.matched_source.clone(),
+            matched_title: conflict.matched_title.clone(),
+        }
+    }
+}
+
+impl From<&DoiConflictRecord> for DoiConflict {
+    fn from(record: &DoiConflictRecord) -> Self {
+        DoiConflict {
+            doi: record.doi.clone(),
+            doi_title: record.doi_title.clone(),
+            matched_source: record.matched_source.clone(),
+            matched_title: record.matched_title.clone(),
+        }
+    }
+}
+
+impl From<&ArxivInfo> for ArxivInfoRecord {
+    fn from(info: &ArxivInfo) -> Self {
+        ArxivInfoRecord {
+            arxiv_id: info.arxiv_id.clone(),
+            valid: info.valid,
+            title: info.title.clone(),
+            published_doi: info.published_doi.clone(),
+        }
+    }
+}
+
+impl From<&ArxivInfoRecord> for ArxivInfo {
+    fn from(record: &ArxivInfoRecord) -> Self {
+        ArxivInfo {
+            arxiv_id: record.arxiv_id.clone(),
+            valid: record.valid,
+            title: record.title.clone(),
+            published_doi: record.published_doi.clone(),
+        }
+    }
+}
+
+impl From<&RetractionInfo> for RetractionInfoRecord {
+    fn from(info: &RetractionInfo) -> Self {
+        RetractionInfoRecord {
+            is_retracted: info.is_retracted,
+            retraction_doi: info.retraction_doi.clone(),
+            retraction_source: info.retraction_source.clone(),
+        }
+    }
+}
+
+impl From<&RetractionInfoRecord> for RetractionInfo {
+    fn from(record: &RetractionInfoRecord) -> Self {
+        RetractionInfo {
+            is_retracted: record.is_retracted,
+            retraction_doi: record.retraction_doi.clone(),
+            retraction_source: record.retraction_source.clone(),
+        }
+    }
+}
+
+/// JSON key for a database query status.
+pub fn db_status_str(status: &DbStatus) -> &'static str {
+    match status {
+        DbStatus::Match => "match",
+        DbStatus::NoMatch => "no_match",
+        DbStatus::AuthorMismatch => "author_mismatch",
+        DbStatus::Timeout => "timeout",
+        DbStatus::RateLimited => "rate_limited",
+        DbStatus::Error => "error",
+        DbStatus::Skipped => "skipped",
+    }
+}
+
+/// Parse a database query status key; unknown keys map to `Error`.
+pub fn parse_db_status(s: &str) -> DbStatus {
+    match s {
+        "match" => DbStatus::Match,
+        "no_match" => DbStatus::NoMatch,
+        "author_mismatch" => DbStatus::AuthorMismatch,
+        "timeout" => DbStatus::Timeout,
+        "rate_limited" => DbStatus::RateLimited,
+        "skipped" => DbStatus::Skipped,
+        _ => DbStatus::Error,
+    }
+}
+
+impl From<&DbResult> for DbResultRecord {
+    fn from(db: &DbResult) -> Self {
+        DbResultRecord {
+            db: db.db_name.clone(),
+            status: db_status_str(&db.status).to_string(),
+            elapsed_ms: db.elapsed.map(|d| d.as_millis() as u64),
+            authors: db.found_authors.clone(),
+            url: db.paper_url.clone(),
+        }
+    }
+}
+
+impl ReferenceRecord {
+    /// Record a checked reference.
+    pub fn from_result(index: usize, original_number: usize, result: &ValidationResult) -> Self {
+        let status = RecordStatus::from(&result.status);
+        ReferenceRecord {
+            index,
+            original_number: Some(original_number),
+            fingerprint: Some(result.fingerprint()),
+            title: result.title.clone(),
+            raw_citation: result.raw_citation.clone(),
+            cite_key: result.cite_key.clone(),
+            status,
+            effective_status: Some(status),
+            skip_reason: None,
+            fp_reason: None,
+            marked_safe: None,
+            source: result.source.clone(),
+            ref_authors: result.ref_authors.clone(),
+            found_authors: result.found_authors.clone(),
+            paper_url: result.paper_url.clone(),
+            failed_dbs: result.failed_dbs.clone(),
+            retracted: None,
+            doi_info: result.doi_info.as_ref().map(DoiInfoRecord::from),
+            doi_conflict: result.doi_conflict.as_ref().map(DoiConflictRecord::from),
+            version_relation: result.version_relation.map(|v| v.as_str().to_string()),
+            arxiv_info: result.arxiv_info.as_ref().map(ArxivInfoRecord::from),
+            retraction_info: result
+                .retraction_info
+                .as_ref()
+                .map(RetractionInfoRecord::from),
+            near_miss: result
+                .near_miss
+                .as_ref()
+                .map(|(title, score)| NearMissRecord {
+                    title: title.clone(),
+                    score: *score,
+                }),
+            insufficient_coverage: result.insufficient_coverage,
+            cited_in_text: result.cited_in_text,
+            db_results: result.db_results.iter().map(DbResultRecord::from).collect(),
+        }
+    }
+
+    /// Record a reference without a result (`Skipped` or `Pending`).
+    pub fn unchecked(
+        index: usize,
+        original_number: usize,
+        title: &str,
+        raw_citation: &str,
+        status: RecordStatus,
+    ) -> Self {
+        ReferenceRecord {
+            index,
+            original_number: Some(original_number),
+            fingerprint: None,
+            title: title.to_string(),
+            raw_citation: raw_citation.to_string(),
+            cite_key: None,
+            status,
+            effective_status: Some(status),
+            skip_reason: None,
+            fp_reason: None,
+            marked_safe: None,
+            source: None,
+            ref_authors: Vec::new(),
+            found_authors: Vec::new(),
+            paper_url: None,
+            failed_dbs: Vec::new(),
+            retracted: None,
+            doi_info: None,
+            doi_conflict: None,
+            version_relation: None,
+            arxiv_info: None,
+            retraction_info: None,
+            near_miss: None,
+            insufficient_coverage: false,
+            cited_in_text: None,
+            db_results: Vec::new(),
+        }
+    }
+
+    /// The stored fingerprint, or one computed from the title and authors for
+    /// older saves that predate the field.
+    pub fn resolved_fingerprint(&self) -> String {
+        self.fingerprint.clone().unwrap_or_else(|| {
+            crate::matching::reference_fingerprint(&self.title, &self.ref_authors)
+        })
+    }
+
+    /// Rebuild the validation result, or `None` for skipped/pending references.
+    pub fn to_result(&self) -> Option<ValidationResult> {
+        let status = self.status.to_status()?;
+        let retraction_info = self
+            .retraction_info
+            .as_ref()
+            .map(RetractionInfo::from)
+            .or_else(|| {
+                (self.retracted == Some(true)).then_some(RetractionInfo {
+                    is_retracted: true,
+                    retraction_doi: None,
+                    retraction_source: None,
+                })
+            });
+        let db_results: Vec<DbResult> = self
+            .db_results
+            .iter()
+            .map(|db| DbResult {
+                db_name: db.db.clone(),
+                status: parse_db_status(&db.status),
+                elapsed: db.elapsed_ms.map(Duration::from_millis),
+                found_authors: db.authors.clone(),
+                paper_url: db.url.clone(),
+                error_message: None,
+            })
+            .collect();
+        Some(ValidationResult {
+            title: self.title.clone(),
+            raw_citation: self.raw_citation.clone(),
+            cite_key: self.cite_key.clone(),
+            ref_authors: self.ref_authors.clone(),
+            status,
+            source: self.source.clone().filter(|s| !s.is_empty()),
+            found_authors: self.found_authors.clone(),
+            paper_url: self.paper_url.clone(),
+            failed_dbs: self.failed_dbs.clone(),
+            verified_by_count: crate::verified_by_count(&db_results),
+            insufficient_coverage: self.insufficient_coverage,
+            cited_in_text: self.cited_in_text,
+            db_results,
+            doi_info: self.doi_info.as_ref().map(DoiInfo::from),
+            arxiv_info: self.arxiv_info.as_ref().map(ArxivInfo::from),
+            retraction_info,
+            near_miss: self
+                .near_miss
+                .as_ref()
+                .map(|nm| (nm.title.clone(), nm.score)),
+            author_match_detail: crate::authors::author_match_detail(
+                &self.ref_authors,
+                &self.found_authors,
+            ),
+            doi_conflict: self.doi_conflict.as_ref().map(DoiConflict::from),
+            version_relation: self
+                .version_relation
+                .as_deref()
+                .and_then(VersionRelation::parse),
+        })
+    }
+}
+
+impl RunStats {
+    /// Counts from check stats, with the problematic share of checked references.
+    pub fn from_check_stats(stats: &CheckStats) -> Self {
+        let checked = stats.total.saturating_sub(stats.skipped);
+        let problematic = stats.not_found + stats.author_mismatch + stats.retracted;
+        RunStats {
+            total: stats.total,
+            verified: stats.verified,
+            not_found: stats.not_found,
+            author_mismatch: stats.author_mismatch,
+            retracted: stats.retracted,
+            skipped: stats.skipped,
+            problematic_pct: if checked == 0 {
+                0.0
+            } else {
+                problematic as f64 / checked as f64 * 100.0
+            },
+        }
+    }
+}
+
+impl PaperRecord {
+    /// Record a paper from its validation results, in check order.
+    pub fn from_results(filename: &str, results: &[ValidationResult], skipped: usize) -> Self {
+        let mut stats = CheckStats {
+            total: results.len() + skipped,
+            skipped,
+            ..Default::default()
+        };
+        for r in results {
+            match r.status {
+                Status::Verified => stats.verified += 1,
+                Status::NotFound => stats.not_found += 1,
+                Status::AuthorMismatch => stats.author_mismatch += 1,
+            }
+            if r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted) {
+                stats.retracted += 1;
+            }
+        }
+        PaperRecord {
+            filename: filename.to_string(),
+            title: None,
+            verdict: None,
+            stats: Some(RunStats::from_check_stats(&stats)),
+            references: results
+                .iter()
+                .enumerate()
+                .map(|(i, r)| ReferenceRecord::from_result(i, i + 1, r))
+                .collect(),
+        }
+    }
+}
+
+/// How one reference differs between two runs, keyed by fingerprint.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ReferenceChange {
+    /// Present in both runs with a different status.
+    StatusChanged {
+        fingerprint: String,
+        title: String,
+        old: RecordStatus,
+        new: RecordStatus,
+    },
+    /// Only in the new run.
+    Added {
+        fingerprint: String,
+        title: String,
+        status: RecordStatus,
+    },
+    /// Only in the old run.
+    Removed {
+        fingerprint: String,
+        title: String,
+        status: RecordStatus,
+    },
+}
+
+/// Compare two runs reference-by-reference.
+///
+/// References from all papers in a run are pooled, since drafts of the same
+/// document usually differ in filename. Changed and added references follow
+/// the new run's order, removed ones the old run's. When a fingerprint repeats
+/// within a run (the same work cited twice), its first occurrence is used.
+pub fn diff_runs(old: &[PaperRecord], new: &[PaperRecord]) -> Vec<ReferenceChange> {
+    fn index(run: &[PaperRecord]) -> (Vec<String>, HashMap<String, &ReferenceRecord>) {
+        let mut order = Vec::new();
+        let mut by_fingerprint = HashMap::new();
+        for reference in run.iter().flat_map(|p| &p.references) {
+            let fingerprint = reference.resolved_fingerprint();
+            if let Entry::Vacant(entry) = by_fingerprint.entry(fingerprint) {
+                order.push(entry.key().clone());
+                entry.insert(reference);
+            }
+        }
+        (order, by_fingerprint)
+    }
+
+    let (old_order, old_refs) = index(old);
+    let (new_order, new_refs) = index(new);
+
+    let mut changes = Vec::new();
+    for fingerprint in new_order {
+        let new_ref = new_refs[&fingerprint];
+        match old_refs.get(&fingerprint) {
+            Some(old_ref) if old_ref.status != new_ref.status => {
+                changes.push(ReferenceChange::StatusChanged {
+                    title: new_ref.title.clone(),
+                    old: old_ref.status,
+                    new: new_ref.status,
+                    fingerprint,
+                });
+            }
+            Some(_) => {}
+            None => changes.push(ReferenceChange::Added {
+                title: new_ref.title.clone(),
+                status: new_ref.status,
+                fingerprint,
+            }),
+        }
+    }
+    for fingerprint in old_order {
+        if !new_refs.contains_key(&fingerprint) {
+            let old_ref = old_refs[&fingerprint];
+            changes.push(ReferenceChange::Removed {
+                title: old_ref.title.clone(),
+                status: old_ref.status,
+                fingerprint,
+            });
+        }
+    }
+    changes
+}
+
+/// Serialize a run diff as pretty-printed JSON.
+pub fn diff_to_json(changes: &[ReferenceChange]) -> String {
+    serde_json::to_string_pretty(changes).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Serialize a run as pretty-printed JSON.
+pub fn run_to_json(papers: &[PaperRecord]) -> String {
+    serde_json::to_string_pretty(papers).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Parse a saved run: a JSON array of papers, or a single paper object.
+pub fn parse_run(json: &str) -> Result<Vec<PaperRecord>, String> {
+    if let Ok(papers) = serde_json::from_str::<Vec<PaperRecord>>(json) {
+        return Ok(papers);
+    }
+    serde_json::from_str::<PaperRecord>(json)
+        .map(|paper| vec![paper])
+        .map_err(|e| format!("Invalid run JSON: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result() -> ValidationResult {
+        ValidationResult {
+            title: "Attention Is All You Need".into(),
+            raw_citation: "A. Vaswani et al. Attention is all you need. NeurIPS 2017.".into(),
+            cite_key: None,
+            ref_authors: vec!["Ashish Vaswani".into()],
+            status: Status::AuthorMismatch,
+            source: Some("DBLP".into()),
+            found_authors: vec!["Ashish Vaswani".into(), "Noam Shazeer".into()],
+            paper_url: Some("https://dblp.org/rec/x".into()),
+            failed_dbs: vec!["OpenAlex".into()],
+            db_results: vec![DbResult {
+                db_name: "DBLP".into(),
+                status: DbStatus::RateLimited,
+                elapsed: Some(Duration::from_millis(120)),
+                found_authors: vec!["Ashish Vaswani".into()],
+                paper_url: None,
+                error_message: None,
+            }],
+            doi_info: Some(DoiInfo {
+                doi: "10.1234/abc".into(),
+                valid: true,
+                title: Some("A Different Paper".into()),
+                title_mismatch: true,
+                well_formed: true,
+                registered: true,
+            }),
+            arxiv_info: Some(ArxivInfo {
+                arxiv_id: "1706.03762".into(),
+                valid: false,
+                title: Some("Attention".into()),
+                published_doi: Some("10.5555/3295222.3295349".into()),
+            }),
+            retraction_info: Some(RetractionInfo {
+                is_retracted: true,
+                retraction_doi: Some("10.1234/retract".into()),
+                retraction_source: None,
+            }),
+            near_miss: Some(("Attention Is All You Need!".into(), 0.93)),
+            author_match_detail: vec![],
+            doi_conflict: None,
+            version_relation: Some(VersionRelation::PublishedVersion),
+            verified_by_count: 0,
+            insufficient_coverage: false,
+            cited_in_text: None,
+        }
+    }
+
+    #[test]
+    fn test_run_round_trip() {
+        let mut paper = PaperRecord::from_results("paper.pdf", &[sample_result()], 1);
+        paper.verdict = Some("questionable".into());
+        paper.references[0].fp_reason = Some("known_good".into());
+        paper.references.push(ReferenceRecord {
+            skip_reason: Some("url_only".into()),
+            ..ReferenceRecord::unchecked(1, 2, "", "https://example.com", RecordStatus::Skipped)
+        });
+
+        let json = run_to_json(std::slice::from_ref(&paper));
+        let parsed = parse_run(&json).unwrap();
+        assert_eq!(parsed, vec![paper]);
+
+        let stats = parsed[0].stats.as_ref().unwrap();
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.author_mismatch, 1);
+        assert_eq!(stats.retracted, 1);
+
+        let result = parsed[0].references[0].to_result().unwrap();
+        assert_eq!(result.status, Status::AuthorMismatch);
+        assert_eq!(result.db_results[0].status, DbStatus::RateLimited);
+        assert_eq!(
+            result.db_results[0].elapsed,
+            Some(Duration::from_millis(120))
+        );
+        assert_eq!(result.near_miss.as_ref().unwrap().1, 0.93);
+        assert_eq!(result.doi_info, sample_result().doi_info);
+        assert_eq!(
+            result.version_relation,
+            Some(VersionRelation::PublishedVersion)
+        );
+        assert!(parsed[0].references[1].to_result().is_none());
+    }
+
+    #[test]
+    fn test_parse_single_object_and_legacy_fields() {
+        let json = r#"{
+            "filename": "old.pdf",
+            "references": [
+                {"index": 0, "title": "T", "status": "not_found", "retracted": true, "marked_safe": true},
+                {"index": 1, "status": "checking"}
+            ]
+        }"#;
+        let papers = parse_run(json).unwrap();
+        assert_eq!(papers.len(), 1);
+        let refs = &papers[0].references;
+        assert_eq!(refs[0].marked_safe, Some(true));
+        let result = refs[0].to_result().unwrap();
+        assert!(result.retraction_info.unwrap().is_retracted);
+        assert_eq!(refs[1].status, RecordStatus::Pending);
+    }
+
+    #[test]
+    fn test_diff_runs() {
+        // Draft 1: two references, one not found; the third has no stored
+        // fingerprint (older save).
+        let old = parse_run(
+            r#"[{"filename": "draft1.pdf", "references": [
+                {"index": 0, "fingerprint": "aaaa", "title": "Stable Paper", "status": "verified"},
+                {"index": 1, "fingerprint": "bbbb", "title": "Fixed Paper", "status": "not_found"},
+                {"index": 2, "title": "Dropped Paper", "status": "author_mismatch",
+                 "ref_authors": ["Jane Doe"]}
+            ]}]"#,
+        )
+        .unwrap();
+        // Draft 2: the citation was fixed, one was dropped, one was added.
+        let new = parse_run(
+            r#"[{"filename": "draft2.pdf", "references": [
+                {"index": 0, "fingerprint": "cccc", "title": "New Paper", "status": "not_found"},
+                {"index": 1, "fingerprint": "bbbb", "title": "Fixed Paper", "status": "verified"},
+                {"index": 2, "fingerprint": "aaaa", "title": "Stable Paper", "status": "verified"}
+            ]}]"#,
+        )
+        .unwrap();
+
+        let changes = diff_runs(&old, &new);
+        let dropped = crate::matching::reference_fingerprint("Dropped Paper", &["Jane Doe".into()]);
+        assert_eq!(
+            changes,
+            vec![
+                ReferenceChange::Added {
+                    fingerprint: "cccc".into(),
+                    title: "New Paper".into(),
+                    status: RecordStatus::NotFound,
+                },
+                ReferenceChange::StatusChanged {
+                    fingerprint: "bbbb".into(),
+                    title: "Fixed Paper".into(),
+                    old: RecordStatus::NotFound,
+                    new: RecordStatus::Verified,
+                },
+                ReferenceChange::Removed {
+                    fingerprint: dropped,
+                    title: "Dropped Paper".into(),
+                    status: RecordStatus::AuthorMismatch,
+                },
+            ]
+        );
+
+        let json = diff_to_json(&changes);
+        assert!(json.contains(r#""change": "status_changed""#));
+        assert!(json.contains(r#""old": "not_found""#));
+        assert!(diff_runs(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_run("{\"nope\": 1}").is_err());
+        assert!(parse_run("not json").is_err());
+    }
+}
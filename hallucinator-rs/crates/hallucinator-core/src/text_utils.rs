@@ -161,10 +161,21 @@ static STOP_WORDS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     .collect()
 });
 
+/// Number of title words [`normalize_query_title`] keeps.
+pub const QUERY_WORD_COUNT: usize = 6;
+
 /// Extract `n` significant words from a title for building search queries.
 ///
-/// Skips stop words and very short words, but keeps short alphanumeric
-/// terms like "L2", "3D", "AI", "5G".
+/// BibTeX braces are dropped (`{BERT}` → `BERT`), then the title is split
+/// into runs of ASCII letters and digits; apostrophes and hyphens inside a
+/// word (`What's`, `Pre-training`) and a trailing `?`/`!` are kept, all other
+/// punctuation separates words. Stop words and words under three characters
+/// are skipped, but short alphanumeric terms like "L2", "3D", "5G" are kept.
+/// If fewer than three significant words remain, the first `n` words are
+/// returned unfiltered.
+///
+/// Non-ASCII letters also separate words, so "Lösung" yields `L`, `sung`;
+/// [`normalize_query_title`] folds diacritics first.
 pub fn get_query_words(title: &str, n: usize) -> Vec<String> {
     // Strip BibTeX capitalization braces: {BERT} → BERT, {M}ixup → Mixup
     let title = title.replace(['{', '}'], "");
@@ -188,6 +199,17 @@ pub fn get_query_words(title: &str, n: usize) -> Vec<String> {
     }
 }
 
+/// The title search string the online databases are queried with.
+///
+/// Folds diacritics (`"Lösung"` → `"Losung"`), then joins the first
+/// [`QUERY_WORD_COUNT`] words picked by [`get_query_words`] with spaces.
+/// Every online backend builds its title query with this function, so it
+/// shows exactly what is searched for a reference.
+pub fn normalize_query_title(title: &str) -> String {
+    let folded = hallucinator_text::fold_diacritics(title);
+    get_query_words(&folded, QUERY_WORD_COUNT).join(" ")
+}
+
 fn is_significant(w: &str) -> bool {
     // Strip trailing ?! before checking (e.g., "important?" → "important")
     let w = w.trim_end_matches(['?', '!']);
@@ -396,4 +418,39 @@ mod tests {
         let words = get_query_words("{COVID}-19 Detection with Deep Learning", 6);
         assert!(words.contains(&"COVID-19".to_string()));
     }
+
+    #[test]
+    fn test_normalize_query_title_punctuation() {
+        assert_eq!(
+            normalize_query_title(
+                "{BERT}: Pre-training of Deep Bidirectional Transformers, for (Language) Understanding."
+            ),
+            "BERT Pre-training Deep Bidirectional Transformers Language"
+        );
+        assert_eq!(
+            normalize_query_title("What's Next for NLP? A Survey"),
+            "What's Next NLP? Survey"
+        );
+    }
+
+    #[test]
+    fn test_normalize_query_title_folds_diacritics() {
+        assert_eq!(
+            normalize_query_title("Über die Lösbarkeit von Gödels Sätzen"),
+            "Uber die Losbarkeit von Godels Satzen"
+        );
+        assert_eq!(
+            normalize_query_title("Rényi Divergence and Kullback–Leibler Straße"),
+            "Renyi Divergence Kullback Leibler Strasse"
+        );
+    }
+
+    #[test]
+    fn test_normalize_query_title_matches_query_words() {
+        let title = "Attention Is All You Need in L2 Regularized 3D Models";
+        assert_eq!(
+            normalize_query_title(title),
+            get_query_words(title, QUERY_WORD_COUNT).join(" ")
+        );
+    }
 }
@@ -0,0 +1,103 @@
+//! A DOI's resolution is cached by DOI, so a rerun against the same cache
+//! reports the DOI exactly as the first run did.
+//!
+//! The title search runs against a tiny on-disk DBLP database and the DOI is
+//! pre-resolved in the query cache, so no HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use hallucinator_core::doi::DoiValidation;
+use hallucinator_core::{Config, QueryCache, Reference, Status, ValidationResult};
+
+use common::{build_dblp, check, dblp_config};
+
+const CITED: &str = "Quantum Entanglement of Distributed Hash Tables";
+const DOI: &str = "10.1145/9999999.9999999";
+
+/// A DBLP database holding one paper unrelated to the checked reference.
+fn build_fixture(path: &Path) {
+    build_dblp(
+        path,
+        &[(
+            "conf/cvpr/HeZRS16",
+            "Deep Residual Learning for Image Recognition",
+            &["Kaiming He"],
+        )],
+    );
+}
+
+/// Check `reference` twice, each run reading the cache the one before wrote.
+async fn check_twice(
+    path: &Path,
+    cache: Arc<QueryCache>,
+    reference: Reference,
+) -> [ValidationResult; 2] {
+    let config = || {
+        let mut config = Config {
+            query_cache: Some(cache.clone()),
+            ..dblp_config(path)
+        };
+        config.disabled_dbs.retain(|db| db != "DOI");
+        config
+    };
+    [
+        check(reference.clone(), config()).await,
+        check(reference, config()).await,
+    ]
+}
+
+#[tokio::test]
+async fn test_unregistered_doi_survives_a_rerun() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+    let cache = Arc::new(QueryCache::default());
+    cache.insert_doi(
+        DOI,
+        &DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI not found".into()),
+        },
+    );
+
+    let reference = Reference::new(CITED).authors(["Jane Doe"]).doi(DOI);
+    for result in check_twice(&path, cache, reference).await {
+        assert_eq!(result.status, Status::NotFound);
+        let doi_info = result.doi_info.unwrap();
+        assert!(!doi_info.valid);
+        assert!(!doi_info.registered);
+    }
+}
+
+#[tokio::test]
+async fn test_doi_title_mismatch_survives_a_rerun() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+    let cache = Arc::new(QueryCache::default());
+    cache.insert_doi(
+        DOI,
+        &DoiValidation {
+            valid: true,
+            title: Some("Deep Learning with Differential Privacy".into()),
+            authors: vec!["Martín Abadi".into()],
+            error: None,
+        },
+    );
+
+    let reference = Reference::new(CITED).authors(["Jane Doe"]).doi(DOI);
+    for result in check_twice(&path, cache, reference).await {
+        assert_eq!(result.status, Status::NotFound);
+        let doi_info = result.doi_info.unwrap();
+        assert!(doi_info.title_mismatch);
+        assert_eq!(
+            doi_info.title.as_deref(),
+            Some("Deep Learning with Differential Privacy")
+        );
+    }
+}
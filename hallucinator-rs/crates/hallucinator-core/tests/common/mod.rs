@@ -0,0 +1,68 @@
+//! Fixtures for the tests that check references against a tiny on-disk DBLP
+//! database with every online backend disabled, so no HTTP requests are made.
+
+// Each test binary compiles its own copy and uses only part of it.
+#![allow(dead_code)]
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::{Config, Reference, ValidationResult, check_references};
+use hallucinator_dblp::db::{
+    SCHEMA_VERSION, init_database, insert_or_get_author, insert_or_get_publication,
+    rebuild_fts_index, set_metadata,
+};
+use tokio_util::sync::CancellationToken;
+
+/// Every backend that queries over the network, DOI resolution included.
+pub const ONLINE_DBS: [&str; 8] = [
+    "CrossRef",
+    "arXiv",
+    "Semantic Scholar",
+    "ACL Anthology",
+    "Europe PMC",
+    "PubMed",
+    "OpenAlex",
+    "DOI",
+];
+
+/// Build a DBLP database at `path` holding `papers`, each as
+/// `(key, title, authors)`.
+pub fn build_dblp(path: &Path, papers: &[(&str, &str, &[&str])]) {
+    let conn = rusqlite::Connection::open(path).unwrap();
+    init_database(&conn).unwrap();
+    for (key, title, authors) in papers {
+        let pub_id = insert_or_get_publication(&conn, key, title, None).unwrap();
+        for author in *authors {
+            let author_id = insert_or_get_author(&conn, author).unwrap();
+            conn.execute(
+                "INSERT INTO publication_authors (pub_id, author_id) VALUES (?1, ?2)",
+                (pub_id, author_id),
+            )
+            .unwrap();
+        }
+    }
+    rebuild_fts_index(&conn).unwrap();
+    set_metadata(&conn, "schema_version", SCHEMA_VERSION).unwrap();
+}
+
+/// A config that only consults the DBLP database at `path`, without a query
+/// cache.
+pub fn dblp_config(path: &Path) -> Config {
+    let db = hallucinator_dblp::DblpDatabase::open(path).unwrap();
+    Config {
+        disabled_dbs: ONLINE_DBS.map(String::from).to_vec(),
+        dblp_offline_db: Some(Arc::new(Mutex::new(db))),
+        query_cache: None,
+        ..Config::default()
+    }
+}
+
+/// Check a single reference and return its result.
+pub async fn check(reference: Reference, config: Config) -> ValidationResult {
+    let mut results = check_references(vec![reference], config, |_| {}, CancellationToken::new())
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    results.remove(0)
+}
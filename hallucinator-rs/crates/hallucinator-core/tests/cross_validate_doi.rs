@@ -0,0 +1,90 @@
+//! With `cross_validate_doi`, a reference verified by title search still has
+//! its DOI resolved, and a DOI naming a different paper is recorded as a
+//! conflict.
+//!
+//! The title search runs against a tiny on-disk DBLP database and the DOI is
+//! pre-resolved in the query cache, so no HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use hallucinator_core::doi::DoiValidation;
+use hallucinator_core::{Config, DoiConflict, QueryCache, Reference, Status, ValidationResult};
+
+use common::{build_dblp, dblp_config};
+
+const CITED: &str = "Deep Residual Learning for Image Recognition";
+const DOI: &str = "10.1145/3133956.3134093";
+const DOI_TITLE: &str = "Deep Learning with Differential Privacy";
+
+fn build_fixture(path: &Path) {
+    build_dblp(path, &[("conf/cvpr/HeZRS16", CITED, &["Kaiming He"])]);
+}
+
+fn config(path: &Path, cross_validate_doi: bool) -> Config {
+    let cache = Arc::new(QueryCache::default());
+    cache.insert_doi(
+        DOI,
+        &DoiValidation {
+            valid: true,
+            title: Some(DOI_TITLE.into()),
+            authors: vec!["Martín Abadi".into()],
+            error: None,
+        },
+    );
+    let mut config = Config {
+        query_cache: Some(cache),
+        cross_validate_doi,
+        ..dblp_config(path)
+    };
+    // The DOI resolves from the cache
+    config.disabled_dbs.retain(|db| db != "DOI");
+    config
+}
+
+async fn check(config: Config) -> ValidationResult {
+    let reference = Reference::new(CITED)
+        .authors(["Kaiming He"])
+        .doi(DOI)
+        .raw_citation(format!("[1] K. He. {CITED}. CVPR 2016. doi:{DOI}"));
+    common::check(reference, config).await
+}
+
+#[tokio::test]
+async fn test_doi_disagreeing_with_title_search_is_flagged() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(config(&path, true)).await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    assert_eq!(
+        result.doi_conflict,
+        Some(DoiConflict {
+            doi: DOI.into(),
+            doi_title: DOI_TITLE.into(),
+            matched_source: "DBLP".into(),
+            matched_title: CITED.into(),
+        })
+    );
+    let doi_info = result.doi_info.unwrap();
+    assert!(doi_info.title_mismatch);
+    assert_eq!(doi_info.title.as_deref(), Some(DOI_TITLE));
+}
+
+#[tokio::test]
+async fn test_no_conflict_recorded_without_cross_validation() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(config(&path, false)).await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    assert_eq!(result.verified_by_count, 1);
+    assert!(result.doi_conflict.is_none());
+    assert!(result.doi_info.is_none());
+}
@@ -0,0 +1,68 @@
+//! In `VerificationMode::ExistenceOnly`, a title match is verified whatever
+//! its authors, so a reference that would be an author mismatch is verified.
+//!
+//! Builds a tiny on-disk DBLP database and checks references against it
+//! with every online backend disabled, so no HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+
+use hallucinator_core::{Config, DbStatus, Reference, Status, VerificationMode};
+
+use common::{build_dblp, check, dblp_config};
+
+const TITLE: &str = "Attention Is All You Need";
+
+fn build_fixture(path: &Path) {
+    build_dblp(
+        path,
+        &[("conf/nips/VaswaniSPUJGKP17", TITLE, &["Ashish Vaswani"])],
+    );
+}
+
+fn config(path: &Path, mode: VerificationMode) -> Config {
+    Config {
+        mode,
+        ..dblp_config(path)
+    }
+}
+
+/// A citation of the fixture paper crediting the wrong author.
+fn misattributed() -> Reference {
+    Reference::new(TITLE)
+        .authors(["Jane Doe"])
+        .raw_citation(format!("[1] Jane Doe. {TITLE}. NeurIPS 2017."))
+}
+
+#[tokio::test]
+async fn test_author_mismatch_is_verified_in_existence_only_mode() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(
+        misattributed(),
+        config(&path, VerificationMode::ExistenceOnly),
+    )
+    .await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    assert_eq!(result.found_authors, ["Ashish Vaswani"]);
+    assert!(
+        result
+            .db_results
+            .iter()
+            .all(|r| r.status != DbStatus::AuthorMismatch)
+    );
+}
+
+#[tokio::test]
+async fn test_full_mode_still_flags_author_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(misattributed(), config(&path, VerificationMode::Full)).await;
+    assert_eq!(result.status, Status::AuthorMismatch);
+}
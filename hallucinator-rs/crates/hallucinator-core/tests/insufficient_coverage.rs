@@ -0,0 +1,92 @@
+//! A NotFound backed by fewer than `min_databases_queried` answering online
+//! backends is flagged `insufficient_coverage` instead of being reported as a
+//! confident negative.
+//!
+//! Online backends are made to fail by routing the shared HTTP client
+//! through a proxy on a closed local port, so every request errors at once
+//! and none leaves the machine.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::{Config, DbStatus, Reference, Status, ValidationResult};
+
+use common::build_dblp;
+
+/// An HTTP client whose every request fails to connect.
+fn unreachable_client() -> reqwest::Client {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{port}")).unwrap())
+        .build()
+        .unwrap()
+}
+
+fn config(min_databases_queried: usize) -> Config {
+    Config {
+        http_client: Some(unreachable_client()),
+        query_cache: None,
+        max_rate_limit_retries: 0,
+        min_databases_queried,
+        ..Config::default()
+    }
+}
+
+async fn check(config: Config) -> ValidationResult {
+    let reference =
+        Reference::new("Quantum Entanglement of Distributed Hash Tables").authors(["Jane Doe"]);
+    common::check(reference, config).await
+}
+
+#[tokio::test]
+async fn test_not_found_with_all_online_backends_failing_is_flagged() {
+    let result = check(config(1)).await;
+    assert_eq!(result.status, Status::NotFound);
+    assert!(!result.failed_dbs.is_empty());
+    assert!(
+        result
+            .db_results
+            .iter()
+            .all(|r| !matches!(r.status, DbStatus::Match | DbStatus::NoMatch))
+    );
+    assert_eq!(result.databases_answered(), 0);
+    assert!(result.insufficient_coverage);
+}
+
+#[tokio::test]
+async fn test_offline_answer_does_not_count_towards_coverage() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    // One paper unrelated to the checked reference
+    build_dblp(
+        &path,
+        &[(
+            "conf/cvpr/HeZRS16",
+            "Deep Residual Learning for Image Recognition",
+            &[],
+        )],
+    );
+    let with_dblp = |min| {
+        let db = hallucinator_dblp::DblpDatabase::open(&path).unwrap();
+        Config {
+            dblp_offline_db: Some(Arc::new(Mutex::new(db))),
+            ..config(min)
+        }
+    };
+
+    // Offline DBLP answers, but with every online backend failing the
+    // default minimum is still missed.
+    let result = check(with_dblp(1)).await;
+    assert_eq!(result.status, Status::NotFound);
+    assert_eq!(result.databases_answered(), 1);
+    assert!(result.insufficient_coverage);
+
+    let result = check(with_dblp(0)).await;
+    assert_eq!(result.status, Status::NotFound);
+    assert!(!result.insufficient_coverage);
+}
@@ -0,0 +1,156 @@
+//! Tests for the global/per-DB similarity threshold applied to offline DBs.
+//!
+//! Builds a tiny on-disk DBLP database and checks references against it with
+//! every online backend disabled, so no HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::db::dblp::DblpOffline;
+use hallucinator_core::db::{BatchLookup, DatabaseBackend};
+use hallucinator_core::{Config, Reference, Status, TitleScorer, check_references};
+use tokio_util::sync::CancellationToken;
+
+use common::{build_dblp, dblp_config};
+
+const EXACT: &str = "Attention is All you Need";
+/// Scores ~0.84 against "Deep Residual Learning for Image Recognition":
+/// below DBLP's default 0.90 threshold, above a loosened 0.80.
+const REWORDED: &str = "Deep Residual Learning for Image Classification";
+
+fn build_fixture(path: &Path) {
+    build_dblp(
+        path,
+        &[
+            ("conf/nips/VaswaniSPUJGKP17", EXACT, &["Ashish Vaswani"]),
+            (
+                "conf/cvpr/HeZRS16",
+                "Deep Residual Learning for Image Recognition",
+                &["Kaiming He"],
+            ),
+        ],
+    );
+}
+
+async fn statuses(config: Config) -> Vec<Status> {
+    let refs = vec![Reference::new(EXACT), Reference::new(REWORDED)];
+    check_references(refs, config, |_| {}, CancellationToken::new())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.status)
+        .collect()
+}
+
+#[tokio::test]
+async fn threshold_changes_which_references_verify() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let default = statuses(dblp_config(&path)).await;
+    assert_eq!(default, vec![Status::Verified, Status::NotFound]);
+
+    let loosened = Config {
+        match_threshold: Some(0.80),
+        ..dblp_config(&path)
+    };
+    assert_eq!(
+        statuses(loosened).await,
+        vec![Status::Verified, Status::Verified]
+    );
+}
+
+#[tokio::test]
+async fn per_db_threshold_overrides_global() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let mut config = Config {
+        match_threshold: Some(0.80),
+        ..dblp_config(&path)
+    };
+    config.thresholds.insert("DBLP".into(), 0.95);
+    assert_eq!(
+        statuses(config).await,
+        vec![Status::Verified, Status::NotFound]
+    );
+}
+
+#[tokio::test]
+async fn token_set_scorer_verifies_reordered_title() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+    let check = |config: Config| async move {
+        let refs = vec![Reference::new(
+            "Image Recognition with Deep Residual Learning",
+        )];
+        check_references(refs, config, |_| {}, CancellationToken::new())
+            .await
+            .unwrap()
+            .remove(0)
+            .status
+    };
+
+    assert_eq!(check(dblp_config(&path)).await, Status::NotFound);
+    let token_set = Config {
+        title_scorer: TitleScorer::TokenSet,
+        ..dblp_config(&path)
+    };
+    assert_eq!(check(token_set).await, Status::Verified);
+}
+
+#[tokio::test]
+async fn offline_batch_query_matches_single_queries() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let backend = DblpOffline {
+        db: Arc::new(Mutex::new(
+            hallucinator_dblp::DblpDatabase::open(&path).unwrap(),
+        )),
+        near_miss_margin: 0.0,
+        threshold: hallucinator_dblp::DEFAULT_THRESHOLD,
+        scorer: TitleScorer::default(),
+        guard: Default::default(),
+    };
+    assert!(backend.supports_batch());
+
+    let client = reqwest::Client::new();
+    let timeout = std::time::Duration::from_secs(1);
+    let titles = [EXACT, REWORDED, "An Entirely Unrelated Title"];
+    let lookups: Vec<BatchLookup> = titles
+        .iter()
+        .map(|title| BatchLookup {
+            title,
+            doi: None,
+            arxiv_id: None,
+        })
+        .collect();
+    let batched: Vec<Option<String>> = backend
+        .query_batch(&lookups, &client, timeout)
+        .await
+        .into_iter()
+        .map(|r| r.unwrap().found_title)
+        .collect();
+    let mut single = Vec::new();
+    for title in titles {
+        single.push(
+            backend
+                .query(title, &client, timeout)
+                .await
+                .unwrap()
+                .found_title,
+        );
+    }
+
+    assert_eq!(batched, single);
+    assert_eq!(batched[0].as_deref(), Some(EXACT));
+    assert!(batched[1].is_none());
+    assert!(batched[2].is_none());
+}
@@ -0,0 +1,62 @@
+//! A reference covered by a manual override verifies without querying any
+//! backend, even with every database enabled.
+
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::overrides::OVERRIDE_SOURCE;
+use hallucinator_core::{Config, ProgressEvent, RefKind, Reference, Status, check_references};
+use tokio_util::sync::CancellationToken;
+
+#[tokio::test]
+async fn test_overridden_reference_skips_backends() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("overrides.toml");
+    std::fs::write(
+        &path,
+        "\"An Internal Technical Report\" = \"10.1234/tr.2024.001\"\n",
+    )
+    .unwrap();
+
+    let config = Config {
+        overrides_path: Some(path),
+        query_cache: None,
+        ..Config::default()
+    };
+    let reference = Reference {
+        raw_citation: "[1] J. Doe. An internal technical report. 2024.".into(),
+        title: Some("An internal technical report".into()),
+        authors: vec!["J. Doe".into()],
+        doi: None,
+        arxiv_id: None,
+        original_number: 1,
+        skip_reason: None,
+        kind: RefKind::Unknown,
+        container_title: None,
+        cite_key: None,
+        cited_in_text: None,
+    };
+
+    let queried = Arc::new(Mutex::new(Vec::new()));
+    let sink = queried.clone();
+    let results = check_references(
+        vec![reference],
+        config,
+        move |event| {
+            if let ProgressEvent::DatabaseQueryComplete { db_name, .. } = event {
+                sink.lock().unwrap().push(db_name);
+            }
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].status, Status::Verified);
+    assert_eq!(results[0].source.as_deref(), Some(OVERRIDE_SOURCE));
+    assert_eq!(
+        results[0].paper_url.as_deref(),
+        Some("https://doi.org/10.1234/tr.2024.001")
+    );
+    assert_eq!(*queried.lock().unwrap(), vec![OVERRIDE_SOURCE.to_string()]);
+}
@@ -7,7 +7,9 @@
 use std::sync::{Arc, Mutex};
 
 use hallucinator_core::pool::{RefJob, ValidationPool};
-use hallucinator_core::{Config, ProgressEvent, RateLimiters, Reference, Status, ValidationResult};
+use hallucinator_core::{
+    Config, ProgressEvent, RateLimiters, RefKind, Reference, Status, ValidationResult,
+};
 use tokio_util::sync::CancellationToken;
 
 /// Build a Config with every real DB disabled (no HTTP calls).
@@ -39,6 +41,10 @@ fn dummy_ref(title: &str) -> Reference {
         arxiv_id: None,
         original_number: 1,
         skip_reason: None,
+        kind: RefKind::Unknown,
+        container_title: None,
+        cite_key: None,
+        cited_in_text: None,
     }
 }
 
@@ -46,7 +52,7 @@ fn dummy_ref(title: &str) -> Reference {
 async fn single_job_completes() {
     let config = Arc::new(config_no_network());
     let cancel = CancellationToken::new();
-    let pool = ValidationPool::new(config, cancel, 2);
+    let pool = ValidationPool::new(config, cancel, 2).unwrap();
 
     let (tx, rx) = tokio::sync::oneshot::channel();
     let job = RefJob {
@@ -69,7 +75,7 @@ async fn single_job_completes() {
 async fn multiple_jobs_all_collected() {
     let config = Arc::new(config_no_network());
     let cancel = CancellationToken::new();
-    let pool = ValidationPool::new(config, cancel, 2);
+    let pool = ValidationPool::new(config, cancel, 2).unwrap();
 
     let total = 5;
     let mut receivers = Vec::with_capacity(total);
@@ -104,7 +110,7 @@ async fn multiple_jobs_all_collected() {
 async fn cancellation_stops_pool() {
     let config = Arc::new(config_no_network());
     let cancel = CancellationToken::new();
-    let pool = ValidationPool::new(config, cancel.clone(), 2);
+    let pool = ValidationPool::new(config, cancel.clone(), 2).unwrap();
 
     // Cancel before submitting any jobs
     cancel.cancel();
@@ -132,7 +138,7 @@ async fn cancellation_stops_pool() {
 async fn shutdown_waits_for_completion() {
     let config = Arc::new(config_no_network());
     let cancel = CancellationToken::new();
-    let pool = ValidationPool::new(config, cancel, 2);
+    let pool = ValidationPool::new(config, cancel, 2).unwrap();
 
     let total = 3;
     let mut receivers = Vec::with_capacity(total);
@@ -166,7 +172,7 @@ async fn shutdown_waits_for_completion() {
 async fn progress_events_emitted() {
     let config = Arc::new(config_no_network());
     let cancel = CancellationToken::new();
-    let pool = ValidationPool::new(config, cancel, 1);
+    let pool = ValidationPool::new(config, cancel, 1).unwrap();
 
     let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
     let events_clone = events.clone();
@@ -205,3 +211,232 @@ async fn progress_events_emitted() {
         "should emit Result event, got: {collected:?}"
     );
 }
+
+/// Local HTTP server standing in for SearxNG: answers every request with an
+/// empty result list and records each request's User-Agent.
+async fn spawn_searxng_stub() -> (String, Arc<Mutex<Vec<String>>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let agents: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let seen = agents.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            let seen = seen.clone();
+            tokio::spawn(async move {
+                // Serve keep-alive requests until the client closes.
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 1024];
+                loop {
+                    while let Some(end) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&buf[..end]).to_string();
+                        buf.drain(..end + 4);
+                        let agent = head
+                            .lines()
+                            .find_map(|l| {
+                                let (k, v) = l.split_once(':')?;
+                                k.eq_ignore_ascii_case("user-agent")
+                                    .then(|| v.trim().to_string())
+                            })
+                            .unwrap_or_default();
+                        seen.lock().unwrap().push(agent);
+                        let body = r#"{"results":[]}"#;
+                        let resp = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                            body.len(),
+                            body
+                        );
+                        if socket.write_all(resp.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                    match socket.read(&mut chunk).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                    }
+                }
+            });
+        }
+    });
+    (url, agents)
+}
+
+#[tokio::test]
+async fn shared_client_used_for_every_reference() {
+    let (searxng_url, agents) = spawn_searxng_stub().await;
+    // A client with a distinctive User-Agent: any request made through a
+    // freshly built client would carry the default one instead.
+    let client = reqwest::Client::builder()
+        .user_agent("shared-run-client")
+        .build()
+        .unwrap();
+    let mut config = Config {
+        searxng_url: Some(searxng_url),
+        http_client: Some(client),
+        ..config_no_network()
+    };
+    // With no remote DB left, each reference goes straight to web search.
+    config.disabled_dbs.push("DOI".into());
+
+    // Two runs with the same config, as when checking each file of an archive.
+    for run in 0..2 {
+        let refs = (0..3)
+            .map(|i| dummy_ref(&format!("Run {run} paper number {i}")))
+            .collect();
+        let results = hallucinator_core::checker::check_references(
+            refs,
+            config.clone(),
+            |_| {},
+            CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(results.len(), 3);
+    }
+
+    let agents = agents.lock().unwrap();
+    assert_eq!(agents.len(), 6, "one web search per reference");
+    assert!(
+        agents.iter().all(|a| a == "shared-run-client"),
+        "every request should use the run's client: {:?}",
+        agents
+    );
+}
+
+#[tokio::test]
+async fn extracted_event_precedes_results() {
+    let events: Arc<Mutex<Vec<&'static str>>> = Arc::new(Mutex::new(Vec::new()));
+    let seen = events.clone();
+    let refs = vec![dummy_ref("First paper"), dummy_ref("Second paper")];
+    let results = hallucinator_core::checker::check_references(
+        refs,
+        config_no_network(),
+        move |event| {
+            let kind = match event {
+                ProgressEvent::Extracted { references } => {
+                    assert_eq!(references.len(), 2);
+                    assert_eq!(references[1].title.as_deref(), Some("Second paper"));
+                    "extracted"
+                }
+                ProgressEvent::Checking { .. } => "checking",
+                ProgressEvent::Result { .. } => "result",
+                _ => return,
+            };
+            seen.lock().unwrap().push(kind);
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    let events = events.lock().unwrap();
+    assert_eq!(events.first(), Some(&"extracted"), "{events:?}");
+    assert_eq!(events.iter().filter(|&&e| e == "extracted").count(), 1);
+    assert_eq!(events.iter().filter(|&&e| e == "result").count(), 2);
+}
+
+#[tokio::test]
+async fn db_query_started_precedes_complete() {
+    let (searxng_url, _) = spawn_searxng_stub().await;
+    let mut config = Config {
+        searxng_url: Some(searxng_url),
+        ..config_no_network()
+    };
+    config.disabled_dbs.push("DOI".into());
+
+    // (event kind, ref index, DB name)
+    type QueryEvent = (&'static str, usize, String);
+    let events: Arc<Mutex<Vec<QueryEvent>>> = Arc::new(Mutex::new(Vec::new()));
+    let seen = events.clone();
+    let refs = (0..2)
+        .map(|i| dummy_ref(&format!("Unindexed paper number {i}")))
+        .collect();
+    hallucinator_core::checker::check_references(
+        refs,
+        config,
+        move |event| match event {
+            ProgressEvent::DatabaseQueryStarted {
+                ref_index, db_name, ..
+            } => seen.lock().unwrap().push(("started", ref_index, db_name)),
+            ProgressEvent::DatabaseQueryComplete {
+                ref_index, db_name, ..
+            } => seen.lock().unwrap().push(("complete", ref_index, db_name)),
+            _ => {}
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+
+    let events = events.lock().unwrap();
+    for ref_index in 0..2 {
+        let position = |kind: &str| {
+            events
+                .iter()
+                .position(|(k, i, db)| *k == kind && *i == ref_index && db == "Web Search")
+        };
+        let started = position("started").expect("started event emitted");
+        let complete = position("complete").expect("complete event emitted");
+        assert!(
+            started < complete,
+            "ref {ref_index}: started should precede complete: {events:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn worker_permit_held_until_reference_finalized() {
+    // CrossRef is the only remote DB, reached through a proxy on a closed
+    // port: each reference is handed to its drainer and fails there.
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(format!("http://127.0.0.1:{port}")).unwrap())
+        .build()
+        .unwrap();
+    let mut config = Config {
+        http_client: Some(client),
+        query_cache: None,
+        max_rate_limit_retries: 0,
+        retry_passes: 0,
+        num_workers: 1,
+        ..config_no_network()
+    };
+    config.disabled_dbs.retain(|db| db != "CrossRef");
+    config.disabled_dbs.push("DOI".into());
+
+    // (event kind, ref index)
+    let events: Arc<Mutex<Vec<(&'static str, usize)>>> = Arc::new(Mutex::new(Vec::new()));
+    let seen = events.clone();
+    let refs = (0..3)
+        .map(|i| dummy_ref(&format!("Unindexed paper number {i}")))
+        .collect();
+    let results = hallucinator_core::checker::check_references(
+        refs,
+        config,
+        move |event| match event {
+            ProgressEvent::Checking { index, .. } => seen.lock().unwrap().push(("checking", index)),
+            ProgressEvent::Result { index, .. } => seen.lock().unwrap().push(("result", index)),
+            _ => {}
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 3);
+
+    // With one worker, no reference starts before the previous one's result.
+    let events = events.lock().unwrap();
+    let expected: Vec<_> = (0..3)
+        .flat_map(|i| [("checking", i), ("result", i)])
+        .collect();
+    assert_eq!(*events, expected);
+}
@@ -0,0 +1,89 @@
+//! With `accept_preprint_versions`, a cited arXiv preprint that matches its
+//! published version is verified even though the author lists disagree.
+//!
+//! Builds a tiny on-disk DBLP database holding the published record and
+//! checks references against it with every online backend disabled, so no
+//! HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+
+use hallucinator_core::{Config, RefKind, Reference, Status, VersionRelation};
+
+use common::{build_dblp, check, dblp_config};
+
+const TITLE: &str = "Learning Low-Level Vision";
+
+fn build_fixture(path: &Path) {
+    build_dblp(
+        path,
+        &[(
+            "journals/ijcv/FreemanPC00",
+            TITLE,
+            &[
+                "William T. Freeman",
+                "Egon C. Pasztor",
+                "Owen T. Carmichael",
+            ],
+        )],
+    );
+}
+
+fn config(path: &Path, accept_preprint_versions: bool) -> Config {
+    Config {
+        accept_preprint_versions,
+        ..dblp_config(path)
+    }
+}
+
+/// A preprint citation whose author is listed under a nickname, so the
+/// published record's authors don't match it.
+fn preprint(author: &str) -> Reference {
+    Reference {
+        kind: RefKind::Preprint,
+        ..Reference::new(TITLE)
+            .authors([author])
+            .arxiv_id("cs/0001001")
+            .raw_citation(format!(
+                "[1] {author}. {TITLE}. arXiv preprint cs/0001001, 2000."
+            ))
+    }
+}
+
+#[tokio::test]
+async fn test_preprint_matching_published_version_is_verified() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(preprint("Bill Freeman"), config(&path, true)).await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    assert_eq!(
+        result.version_relation,
+        Some(VersionRelation::PublishedVersion)
+    );
+}
+
+#[tokio::test]
+async fn test_preprint_version_needs_the_option() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(preprint("Bill Freeman"), config(&path, false)).await;
+    assert_eq!(result.status, Status::AuthorMismatch);
+    assert!(result.version_relation.is_none());
+}
+
+#[tokio::test]
+async fn test_preprint_with_unrelated_authors_stays_mismatched() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let result = check(preprint("Jane Doe"), config(&path, true)).await;
+    assert_eq!(result.status, Status::AuthorMismatch);
+    assert!(result.version_relation.is_none());
+}
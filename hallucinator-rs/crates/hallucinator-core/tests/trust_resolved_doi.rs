@@ -0,0 +1,84 @@
+//! With `trust_resolved_doi`, a reference whose DOI resolves to the cited
+//! paper verifies via DOI without a title query to any other backend.
+
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::doi::DoiValidation;
+use hallucinator_core::{
+    Config, DbStatus, ProgressEvent, QueryCache, RefKind, Reference, Status, check_references,
+};
+use tokio_util::sync::CancellationToken;
+
+#[tokio::test]
+async fn test_trusted_doi_skips_title_queries() {
+    // Pre-resolved so the DOI lookup itself stays off the network.
+    let cache = Arc::new(QueryCache::default());
+    cache.insert_doi(
+        "10.1145/3133956.3134093",
+        &DoiValidation {
+            valid: true,
+            title: Some("Deep Learning with Differential Privacy".into()),
+            authors: vec!["Martín Abadi".into(), "Andy Chu".into()],
+            error: None,
+        },
+    );
+
+    let config = Config {
+        query_cache: Some(cache),
+        trust_resolved_doi: true,
+        ..Config::default()
+    };
+    let reference = Reference {
+        raw_citation: "[1] M. Abadi, A. Chu. Deep learning with differential privacy. CCS 2016."
+            .into(),
+        title: Some("Deep learning with differential privacy".into()),
+        authors: vec!["M. Abadi".into(), "A. Chu".into()],
+        doi: Some("10.1145/3133956.3134093".into()),
+        arxiv_id: None,
+        original_number: 1,
+        skip_reason: None,
+        kind: RefKind::Unknown,
+        container_title: None,
+        cite_key: None,
+        cited_in_text: None,
+    };
+
+    let started = Arc::new(Mutex::new(Vec::new()));
+    let completed = Arc::new(Mutex::new(Vec::new()));
+    let (started_sink, completed_sink) = (started.clone(), completed.clone());
+    let results = check_references(
+        vec![reference],
+        config,
+        move |event| match event {
+            ProgressEvent::DatabaseQueryStarted { db_name, .. } => {
+                started_sink.lock().unwrap().push(db_name)
+            }
+            ProgressEvent::DatabaseQueryComplete {
+                db_name, status, ..
+            } => completed_sink.lock().unwrap().push((db_name, status)),
+            _ => {}
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].status, Status::Verified);
+    assert_eq!(results[0].source.as_deref(), Some("DOI"));
+    let doi_info = results[0].doi_info.as_ref().unwrap();
+    assert!(doi_info.valid);
+    assert_eq!(
+        doi_info.title.as_deref(),
+        Some("Deep Learning with Differential Privacy")
+    );
+    assert_eq!(*started.lock().unwrap(), vec!["DOI".to_string()]);
+    for (db_name, status) in completed.lock().unwrap().iter() {
+        let expected = if db_name == "DOI" {
+            DbStatus::Match
+        } else {
+            DbStatus::Skipped
+        };
+        assert_eq!(*status, expected, "{db_name}");
+    }
+}
@@ -0,0 +1,111 @@
+//! A match from one of `trusted_verifiers` settles a reference without any
+//! further lookups, even the DOI cross-check of `cross_validate_doi`.
+//!
+//! The title search runs against a tiny on-disk DBLP database and the DOI is
+//! pre-resolved in the query cache, so no HTTP requests are made.
+
+mod common;
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use hallucinator_core::doi::DoiValidation;
+use hallucinator_core::{
+    Config, DbStatus, ProgressEvent, QueryCache, Reference, Status, ValidationResult,
+    check_references,
+};
+use tokio_util::sync::CancellationToken;
+
+use common::{build_dblp, dblp_config};
+
+const CITED: &str = "Deep Residual Learning for Image Recognition";
+const DOI: &str = "10.1109/CVPR.2016.90";
+
+fn build_fixture(path: &Path) {
+    build_dblp(path, &[("conf/cvpr/HeZRS16", CITED, &["Kaiming He"])]);
+}
+
+fn config(path: &Path, trusted_verifiers: Vec<String>) -> Config {
+    let cache = Arc::new(QueryCache::default());
+    cache.insert_doi(
+        DOI,
+        &DoiValidation {
+            valid: true,
+            title: Some(CITED.into()),
+            authors: vec!["Kaiming He".into()],
+            error: None,
+        },
+    );
+    let mut config = Config {
+        query_cache: Some(cache),
+        cross_validate_doi: true,
+        trusted_verifiers,
+        ..dblp_config(path)
+    };
+    // The DOI resolves from the cache
+    config.disabled_dbs.retain(|db| db != "DOI");
+    config
+}
+
+/// Check the reference, returning its result and every DB that reported a
+/// query (anything but Skipped).
+async fn check(config: Config) -> (ValidationResult, Vec<String>) {
+    let reference = Reference::new(CITED)
+        .authors(["Kaiming He"])
+        .doi(DOI)
+        .raw_citation(format!("[1] K. He. {CITED}. CVPR 2016. doi:{DOI}"));
+    let queried = Arc::new(Mutex::new(Vec::new()));
+    let sink = queried.clone();
+    let mut results = check_references(
+        vec![reference],
+        config,
+        move |event| {
+            if let ProgressEvent::DatabaseQueryComplete {
+                db_name, status, ..
+            } = event
+                && status != DbStatus::Skipped
+            {
+                sink.lock().unwrap().push(db_name);
+            }
+        },
+        CancellationToken::new(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 1);
+    let queried = queried.lock().unwrap().clone();
+    (results.remove(0), queried)
+}
+
+#[tokio::test]
+async fn test_trusted_offline_match_skips_doi_lookup() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let (result, queried) = check(config(&path, vec!["dblp".into()])).await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    assert_eq!(queried, ["DBLP"]);
+    assert!(result.doi_info.is_none());
+    let doi = result
+        .db_results
+        .iter()
+        .find(|r| r.db_name == "DOI")
+        .unwrap();
+    assert_eq!(doi.status, DbStatus::Skipped);
+}
+
+#[tokio::test]
+async fn test_untrusted_match_still_cross_checks_doi() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("dblp.db");
+    build_fixture(&path);
+
+    let (result, _) = check(config(&path, vec!["ACL Anthology".into()])).await;
+    assert_eq!(result.status, Status::Verified);
+    assert_eq!(result.source.as_deref(), Some("DBLP"));
+    let doi_info = result.doi_info.unwrap();
+    assert!(doi_info.valid);
+    assert!(!doi_info.title_mismatch);
+}
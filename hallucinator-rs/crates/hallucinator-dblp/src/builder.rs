@@ -3,135 +3,145 @@
 //! Downloads `dblp.xml.gz` from dblp.org (~1 GB), parses it with a SAX-style
 //! XML parser, and builds a normalized SQLite database with FTS5 full-text search.
 
-use std::cell::Cell;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, Read, Write};
-use std::path::Path;
-use std::rc::Rc;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, mpsc};
 
 use futures_util::StreamExt;
+use hallucinator_text::{download, sqlite};
 use rusqlite::Connection;
 
 use crate::db;
 use crate::xml_parser;
-use crate::{BuildProgress, DblpError};
+use crate::{BuildOptions, BuildProgress, DblpError};
 
 /// Default DBLP XML dump URL (~1 GB compressed).
 pub const DEFAULT_DBLP_URL: &str = "https://dblp.uni-trier.de/xml/dblp.xml.gz";
 
+/// Default number of times a failed download is retried.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
 /// How many publication records to process before committing the transaction.
 /// Keeps WAL size reasonable while avoiding per-record fsync overhead.
 const COMMIT_INTERVAL: u64 = 50_000;
 
+/// How many parsed publications may queue between the XML parser thread and
+/// the SQLite inserter before the parser blocks.
+///
+/// This is the build's memory ceiling for in-flight records: a DBLP record is a
+/// key, a title and a handful of author names (well under 1 KB), so a full
+/// queue holds a few MB at most no matter how slow the disk is.
+const PARSE_QUEUE_CAPACITY: usize = 4096;
+
 /// Build (or update) the offline DBLP database by downloading from dblp.org.
 ///
-/// Phase 1: Downloads `dblp.xml.gz` to a temporary file with progress reporting.
+/// Phase 1: Downloads `dblp.xml.gz` to a staging file next to the database
+/// with progress reporting.
 /// Phase 2: Parses the XML and inserts into SQLite (runs in a blocking thread).
 ///
 /// Uses ETag/Last-Modified headers for conditional requests — if the remote
 /// file hasn't changed since the last build, returns `Ok(false)`.
+///
+/// The staged file and its ETag are removed only after a successful build.
+/// If a previous build died after downloading, the next one revalidates the
+/// staged ETag and, when the server answers 304, parses the staged file
+/// without downloading it again ([`BuildProgress::ReusingDownload`]).
+///
+/// Connection failures, 5xx/429 responses and interrupted streams are retried
+/// up to `options.max_retries` times with exponential backoff, restarting the
+/// download from scratch; each retry is reported as [`BuildProgress::Retrying`].
+///
+/// Before returning `Ok(true)` the WAL is checkpointed and the files fsynced
+/// (see [`sqlite::finalize`]), so a connection opened afterwards sees every record.
 pub async fn build(
     db_path: &Path,
+    options: BuildOptions,
     mut progress: impl FnMut(BuildProgress),
 ) -> Result<bool, DblpError> {
-    let conn = Connection::open(db_path)?;
-    db::init_database(&conn)?;
-
-    // Check stored ETag/Last-Modified for conditional request
-    let stored_etag = db::get_metadata(&conn, "etag")?;
-    let stored_last_modified = db::get_metadata(&conn, "last_modified")?;
-
-    // Build HTTP client
-    let client = reqwest::Client::builder()
-        .user_agent("hallucinator-dblp/0.1.0")
-        .build()
-        .map_err(|e| DblpError::Download(e.to_string()))?;
-
-    // Conditional GET
-    let mut request = client.get(DEFAULT_DBLP_URL);
-    if let Some(ref etag) = stored_etag {
-        request = request.header("If-None-Match", etag.as_str());
-    }
-    if let Some(ref lm) = stored_last_modified {
-        request = request.header("If-Modified-Since", lm.as_str());
-    }
-
-    let response = request
-        .send()
-        .await
-        .map_err(|e| DblpError::Download(e.to_string()))?;
-
-    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
-        progress(BuildProgress::Complete {
-            publications: 0,
-            authors: 0,
-            skipped: true,
-        });
-        return Ok(false);
-    }
-
-    if !response.status().is_success() {
-        return Err(DblpError::Download(format!(
-            "HTTP error: {}",
-            response.status()
-        )));
-    }
-
-    // Capture new ETag/Last-Modified from response
-    let new_etag = response
-        .headers()
-        .get("etag")
-        .and_then(|v| v.to_str().ok())
-        .map(String::from);
-    let new_last_modified = response
-        .headers()
-        .get("last-modified")
-        .and_then(|v| v.to_str().ok())
-        .map(String::from);
-
-    let total_bytes = response.content_length();
+    // Check stored ETag/Last-Modified for conditional request. A database
+    // from an older schema, or built with another `min_year`, is rebuilt
+    // even if the dump hasn't changed.
+    let (stored_etag, stored_last_modified) = {
+        let conn = Connection::open(db_path)?;
+        db::init_database(&conn)?;
+        let current_schema =
+            db::get_metadata(&conn, "schema_version")?.as_deref() == Some(db::SCHEMA_VERSION);
+        let same_min_year =
+            db::get_metadata(&conn, "min_year")? == options.min_year.map(|y| y.to_string());
+        if current_schema && same_min_year {
+            (
+                db::get_metadata(&conn, "etag")?,
+                db::get_metadata(&conn, "last_modified")?,
+            )
+        } else {
+            (None, None)
+        }
+    };
 
-    // Phase 1: Download .xml.gz to a temporary file
-    let db_dir = db_path.parent().unwrap_or(Path::new("."));
-    let tmp_dir = tempfile::TempDir::new_in(db_dir).map_err(DblpError::Io)?;
-    let gz_path = tmp_dir.path().join("dblp.xml.gz");
+    let client = http_client(&options)?;
+
+    // Phase 1: Download .xml.gz to a staging file next to the database. A
+    // completed download is kept with its ETag until the build succeeds, so a
+    // build that dies while parsing resumes from the file instead of
+    // downloading it again.
+    let (gz_path, etag_path) = staging_paths(db_path);
+    let staged_etag = read_staged_etag(&gz_path, &etag_path);
+    let (if_none_match, if_modified_since) = match &staged_etag {
+        Some(etag) => (Some(etag.as_str()), None),
+        None => (stored_etag.as_deref(), stored_last_modified.as_deref()),
+    };
 
-    progress(BuildProgress::Downloading {
-        bytes_downloaded: 0,
-        total_bytes,
-        bytes_decompressed: 0,
-    });
+    let mut attempt = 0;
+    let fetched = loop {
+        let result = download_attempt(
+            &client,
+            &options.url,
+            if_none_match,
+            if_modified_since,
+            &gz_path,
+            &etag_path,
+            &mut progress,
+        )
+        .await;
+        match result {
+            Ok(fetched) => break fetched,
+            Err(e) => retry_or_fail(e, &mut attempt, &options, &mut progress).await?,
+        }
+    };
 
-    {
-        let mut out = File::create(&gz_path)?;
-        let mut stream = response.bytes_stream();
-        let mut bytes_downloaded: u64 = 0;
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| DblpError::Download(e.to_string()))?;
-            out.write_all(&chunk)?;
-            bytes_downloaded += chunk.len() as u64;
-
-            progress(BuildProgress::Downloading {
-                bytes_downloaded,
-                total_bytes,
-                bytes_decompressed: 0,
+    let (new_etag, new_last_modified) = match (fetched, staged_etag) {
+        (Some(validators), _) => validators,
+        // 304 against the staged ETag: the staged file is still current.
+        (None, Some(etag)) => {
+            let bytes = std::fs::metadata(&gz_path)?.len();
+            progress(BuildProgress::ReusingDownload { bytes });
+            (Some(etag), None)
+        }
+        (None, None) => {
+            progress(BuildProgress::Complete {
+                publications: 0,
+                authors: 0,
+                issues: 0,
+                skipped: true,
             });
+            return Ok(false);
         }
-        out.flush()?;
-    }
+    };
 
     // Phase 2: Parse XML and insert into SQLite.
     // Runs in a blocking thread since XML parsing and SQLite writes are sync I/O.
     let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<BuildProgress>(64);
+    let min_year = options.min_year;
+    let db_path = db_path.to_path_buf();
 
     let parse_handle = tokio::task::spawn_blocking(move || {
-        let _tmp_dir = tmp_dir; // keep temp directory alive until parsing is done
-
+        let (staged_db, conn) = create_staged_database(&db_path)?;
         db::begin_bulk_load(&conn)?;
 
-        parse_and_insert(&conn, &gz_path, |evt| {
+        let issues = parse_and_insert(&conn, &gz_path, min_year, |evt| {
             let _ = progress_tx.blocking_send(evt);
         })?;
 
@@ -141,7 +151,8 @@ pub async fn build(
         // Update metadata
         let timestamp = now_unix_timestamp();
         db::set_metadata(&conn, "last_updated", &timestamp)?;
-        db::set_metadata(&conn, "schema_version", "3")?;
+        db::set_metadata(&conn, "schema_version", db::SCHEMA_VERSION)?;
+        store_min_year(&conn, min_year)?;
 
         if let Some(etag) = new_etag {
             db::set_metadata(&conn, "etag", &etag)?;
@@ -156,8 +167,15 @@ pub async fn build(
 
         let _ = progress_tx.blocking_send(BuildProgress::Compacting);
         db::vacuum(&conn)?;
+        sqlite::finalize(&conn, &staged_db)?;
+        drop(conn);
+        sqlite::replace_database(&staged_db, &db_path)?;
+
+        // The build is complete; the staged download is no longer needed.
+        let _ = std::fs::remove_file(&etag_path);
+        let _ = std::fs::remove_file(&gz_path);
 
-        Ok::<(i64, i64), DblpError>((pubs, authors))
+        Ok::<(i64, i64, u64), DblpError>((pubs, authors, issues))
     });
 
     // Forward progress events from the blocking task to the caller
@@ -165,37 +183,240 @@ pub async fn build(
         progress(evt);
     }
 
-    let (pubs, authors) = parse_handle
+    let (pubs, authors, issues) = parse_handle
         .await
         .map_err(|e| DblpError::Download(format!("parse task panicked: {}", e)))??;
 
     progress(BuildProgress::Complete {
         publications: pubs as u64,
         authors: authors as u64,
+        issues,
         skipped: false,
     });
 
     Ok(true)
 }
 
-/// Build the offline DBLP database from a local `.xml.gz` file.
+/// The client for builds and remote checks: [`BuildOptions::client`], or
+/// the shared default.
+fn http_client(options: &BuildOptions) -> Result<reqwest::Client, DblpError> {
+    match &options.client {
+        Some(client) => Ok(client.clone()),
+        None => hallucinator_text::download::default_client()
+            .map_err(|e| DblpError::Download(e.to_string())),
+    }
+}
+
+/// Ask whether the dump at `options.url` changed since the build that stored
+/// `etag` / `last_modified`, without downloading it.
+///
+/// Sends a conditional HEAD: 304 means unchanged. A server that ignores the
+/// condition is judged by comparing validators; with none stored, any dump
+/// counts as an update.
+pub(crate) async fn remote_has_update(
+    options: &BuildOptions,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+) -> Result<bool, DblpError> {
+    let mut request = http_client(options)?.head(&options.url);
+    if let Some(etag) = etag {
+        request = request.header("If-None-Match", etag);
+    }
+    if let Some(lm) = last_modified {
+        request = request.header("If-Modified-Since", lm);
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| DblpError::Download(e.to_string()))?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(false);
+    }
+    if !status.is_success() {
+        return Err(DblpError::Download(format!("HTTP error: {}", status)));
+    }
+
+    let header = |name: &str| response.headers().get(name).and_then(|v| v.to_str().ok());
+    Ok(
+        match (etag, header("etag"), last_modified, header("last-modified")) {
+            (Some(stored), Some(current), _, _) => stored != current,
+            (_, _, Some(stored), Some(current)) => stored != current,
+            _ => true,
+        },
+    )
+}
+
+type AttemptError = download::AttemptError<DblpError>;
+
+/// Handle a failed download attempt: if it was transient and retries remain,
+/// report [`BuildProgress::Retrying`] and sleep out the backoff; otherwise
+/// return the error that ends the build.
+async fn retry_or_fail(
+    err: AttemptError,
+    attempt: &mut u32,
+    options: &BuildOptions,
+    progress: &mut impl FnMut(BuildProgress),
+) -> Result<(), DblpError> {
+    let max_retries = options.max_retries;
+    download::retry_or_fail(
+        err,
+        attempt,
+        max_retries,
+        options.retry_delay,
+        |attempt, error| {
+            progress(BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            })
+        },
+        DblpError::Download,
+    )
+    .await
+}
+
+/// Create an empty database at the staging path for `db_path`, replacing
+/// any left behind by an interrupted build. The finished build replaces
+/// `db_path` wholesale, so nothing from the previous one survives.
+fn create_staged_database(db_path: &Path) -> Result<(PathBuf, Connection), DblpError> {
+    let staged = sqlite::staging_path(db_path);
+    sqlite::remove_database(&staged)?;
+    let conn = Connection::open(&staged)?;
+    db::init_database(&conn)?;
+    Ok((staged, conn))
+}
+
+/// Staging locations for the downloaded dump, next to the database:
+/// `<db>.download.xml.gz` and its ETag sidecar `<db>.download.xml.gz.etag`.
+fn staging_paths(db_path: &Path) -> (PathBuf, PathBuf) {
+    let mut gz_path = db_path.as_os_str().to_owned();
+    gz_path.push(".download.xml.gz");
+    let mut etag_path = gz_path.clone();
+    etag_path.push(".etag");
+    (gz_path.into(), etag_path.into())
+}
+
+/// ETag of a completely downloaded staged dump, if there is one.
+///
+/// The sidecar is only written once the download finishes, so a staged file
+/// without one is a partial download and is ignored.
+fn read_staged_etag(gz_path: &Path, etag_path: &Path) -> Option<String> {
+    if !gz_path.is_file() {
+        return None;
+    }
+    let etag = std::fs::read_to_string(etag_path).ok()?;
+    let etag = etag.trim();
+    (!etag.is_empty()).then(|| etag.to_string())
+}
+
+/// Make one conditional GET for the dump and stream the body to `gz_path`,
+/// recording the response's ETag in `etag_path` once the body is complete.
+///
+/// Returns `None` on 304 Not Modified, otherwise the response's
+/// `(ETag, Last-Modified)` to store once the build succeeds.
+async fn download_attempt(
+    client: &reqwest::Client,
+    url: &str,
+    if_none_match: Option<&str>,
+    if_modified_since: Option<&str>,
+    gz_path: &Path,
+    etag_path: &Path,
+    progress: &mut impl FnMut(BuildProgress),
+) -> Result<Option<(Option<String>, Option<String>)>, AttemptError> {
+    let mut request = client.get(url);
+    if let Some(etag) = if_none_match {
+        request = request.header("If-None-Match", etag);
+    }
+    if let Some(lm) = if_modified_since {
+        request = request.header("If-Modified-Since", lm);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| AttemptError::Transient(e.to_string()))?;
+
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+    if !status.is_success() {
+        return Err(AttemptError::from_status(
+            status,
+            format!("HTTP error: {}", status),
+            DblpError::Download,
+        ));
+    }
+
+    // Capture new ETag/Last-Modified from response
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from)
+    };
+    let validators = (header("etag"), header("last-modified"));
+
+    let total_bytes = response.content_length();
+    progress(BuildProgress::Downloading {
+        bytes_downloaded: 0,
+        total_bytes,
+        bytes_decompressed: 0,
+    });
+
+    // Invalidate any earlier staged download before overwriting it.
+    if let Err(e) = std::fs::remove_file(etag_path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        return Err(e.into());
+    }
+    let mut out = File::create(gz_path)?;
+    let mut stream = response.bytes_stream();
+    let mut bytes_downloaded: u64 = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AttemptError::Transient(e.to_string()))?;
+        out.write_all(&chunk)?;
+        bytes_downloaded += chunk.len() as u64;
+
+        progress(BuildProgress::Downloading {
+            bytes_downloaded,
+            total_bytes,
+            bytes_decompressed: 0,
+        });
+    }
+    out.flush()?;
+
+    if let Some(etag) = &validators.0 {
+        std::fs::write(etag_path, etag)?;
+    }
+
+    Ok(Some(validators))
+}
+
+/// Build the offline DBLP database from a local `.xml.gz` file, skipping
+/// publications before `min_year` when it is set.
 pub fn build_from_file(
     db_path: &Path,
     xml_gz_path: &Path,
+    min_year: Option<u32>,
     mut progress: impl FnMut(BuildProgress),
 ) -> Result<(), DblpError> {
-    let conn = Connection::open(db_path)?;
-    db::init_database(&conn)?;
+    let (staged_db, conn) = create_staged_database(db_path)?;
     db::begin_bulk_load(&conn)?;
 
-    parse_and_insert(&conn, xml_gz_path, &mut progress)?;
+    let issues = parse_and_insert(&conn, xml_gz_path, min_year, &mut progress)?;
 
     progress(BuildProgress::RebuildingIndex);
     db::rebuild_fts_index(&conn)?;
 
     let timestamp = now_unix_timestamp();
     db::set_metadata(&conn, "last_updated", &timestamp)?;
-    db::set_metadata(&conn, "schema_version", "3")?;
+    db::set_metadata(&conn, "schema_version", db::SCHEMA_VERSION)?;
+    store_min_year(&conn, min_year)?;
 
     let (pubs, authors, _) = db::get_counts(&conn)?;
     db::set_metadata(&conn, "publication_count", &pubs.to_string())?;
@@ -203,10 +424,14 @@ pub fn build_from_file(
 
     progress(BuildProgress::Compacting);
     db::vacuum(&conn)?;
+    sqlite::finalize(&conn, &staged_db)?;
+    drop(conn);
+    sqlite::replace_database(&staged_db, db_path)?;
 
     progress(BuildProgress::Complete {
         publications: pubs as u64,
         authors: authors as u64,
+        issues,
         skipped: false,
     });
 
@@ -216,36 +441,48 @@ pub fn build_from_file(
 /// Wrapper around a `Read` that tracks how many bytes have been consumed.
 struct CountingReader<R> {
     inner: R,
-    bytes_read: Rc<Cell<u64>>,
+    bytes_read: Arc<AtomicU64>,
 }
 
 impl<R: Read> Read for CountingReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         let n = self.inner.read(buf)?;
-        self.bytes_read.set(self.bytes_read.get() + n as u64);
+        self.bytes_read.fetch_add(n as u64, Ordering::Relaxed);
         Ok(n)
     }
 }
 
 /// Parse a `.xml.gz` file and insert publications into the database.
 ///
+/// The XML parser runs on its own thread and hands records to the inserter
+/// (this thread) through [`parse_pipeline`], so peak memory is bounded by
+/// `PARSE_QUEUE_CAPACITY` regardless of how far SQLite falls behind.
+///
 /// All inserts run inside an explicit transaction (committed every `COMMIT_INTERVAL`
 /// records) so individual writes don't trigger per-statement fsync. ID resolution
 /// uses `RETURNING` for a single round-trip and a HashMap cache for repeats.
+///
+/// Publications before `min_year`, or without a year when it is set, are
+/// dropped by the parser and never inserted.
+///
+/// Once everything is inserted, `<crossref>` links are resolved so papers
+/// inherit the venue and year of their proceedings where they lack their own.
+///
+/// Non-fatal parse anomalies are reported as [`BuildProgress::Warning`];
+/// returns how many there were.
 fn parse_and_insert(
     conn: &Connection,
     gz_path: &Path,
+    min_year: Option<u32>,
     mut progress: impl FnMut(BuildProgress),
-) -> Result<(), DblpError> {
+) -> Result<u64, DblpError> {
     let file = File::open(gz_path)?;
     let file_size = file.metadata().map(|m| m.len()).unwrap_or(0);
 
-    let bytes_read_counter = Rc::new(Cell::new(0u64));
-    let bytes_read_for_progress = Rc::clone(&bytes_read_counter);
-
+    let bytes_read = Arc::new(AtomicU64::new(0));
     let counting = CountingReader {
         inner: file,
-        bytes_read: bytes_read_counter,
+        bytes_read: Arc::clone(&bytes_read),
     };
     let decoder = flate2::read::GzDecoder::new(counting);
     let reader = BufReader::with_capacity(1024 * 1024, decoder);
@@ -255,98 +492,184 @@ fn parse_and_insert(
     // isn't worth the ~450MB of RAM it costs.
     let mut author_ids: HashMap<String, i64> = HashMap::new();
 
+    let records_parsed = AtomicU64::new(0);
     let mut records_inserted: u64 = 0;
-    let mut insert_error: Option<DblpError> = None;
+    let mut issues: u64 = 0;
+
+    db::begin_crossrefs(conn)?;
 
     // Start a long-running transaction — individual inserts are fast within a
     // transaction because SQLite only fsyncs on COMMIT, not per-statement.
     conn.execute_batch("BEGIN")?;
 
-    xml_parser::parse_xml(reader, |pub_record| {
-        if insert_error.is_some() {
-            return;
-        }
-
-        records_inserted += 1;
-
-        // Resolve author IDs (insert-or-get + cache)
-        let mut author_id_list = Vec::with_capacity(pub_record.authors.len());
-        for author in &pub_record.authors {
-            let aid = if let Some(&cached) = author_ids.get(author) {
-                cached
-            } else {
-                match db::insert_or_get_author(conn, author) {
-                    Ok(id) => {
-                        author_ids.insert(author.clone(), id);
-                        id
-                    }
-                    Err(e) => {
-                        insert_error = Some(e);
-                        return;
-                    }
+    let result = parse_pipeline(
+        reader,
+        PARSE_QUEUE_CAPACITY,
+        min_year,
+        &records_parsed,
+        |event| {
+            let pub_record = match event {
+                ParseEvent::Publication(pub_record) => pub_record,
+                ParseEvent::Issue(message) => {
+                    issues += 1;
+                    progress(BuildProgress::Warning { message });
+                    return Ok(());
                 }
             };
-            author_id_list.push(aid);
-        }
+            records_inserted += 1;
+
+            // Resolve author IDs (insert-or-get + cache)
+            let mut author_id_list = Vec::with_capacity(pub_record.authors.len());
+            for author in pub_record.authors {
+                let aid = match author_ids.get(&author) {
+                    Some(&cached) => cached,
+                    None => {
+                        let id = db::insert_or_get_author(conn, &author)?;
+                        author_ids.insert(author, id);
+                        id
+                    }
+                };
+                author_id_list.push(aid);
+            }
 
-        // Resolve publication ID (always hits SQLite — pubs rarely repeat)
-        let pub_id = match db::insert_or_get_publication(conn, &pub_record.key, &pub_record.title) {
-            Ok(id) => id,
-            Err(e) => {
-                insert_error = Some(e);
-                return;
+            // Resolve publication ID (always hits SQLite — pubs rarely repeat)
+            let pub_id = db::insert_or_get_publication_with_venue(
+                conn,
+                &pub_record.key,
+                &pub_record.title,
+                pub_record.year,
+                pub_record.venue.as_deref(),
+            )?;
+            if let Some(target) = &pub_record.crossref {
+                db::record_crossref(conn, pub_id, target)?;
             }
-        };
 
-        // Insert publication_authors directly (within the active transaction)
-        {
-            let pa_stmt = conn.prepare_cached(
+            // Insert publication_authors directly (within the active transaction)
+            let mut pa_stmt = conn.prepare_cached(
                 "INSERT OR IGNORE INTO publication_authors (pub_id, author_id) VALUES (?1, ?2)",
-            );
-            let mut pa_stmt = match pa_stmt {
-                Ok(s) => s,
-                Err(e) => {
-                    insert_error = Some(e.into());
-                    return;
-                }
-            };
+            )?;
             for aid in author_id_list {
-                if let Err(e) = pa_stmt.execute(rusqlite::params![pub_id, aid]) {
-                    insert_error = Some(e.into());
-                    return;
-                }
+                pa_stmt.execute(rusqlite::params![pub_id, aid])?;
             }
-        }
 
-        // Periodic commit to keep WAL size reasonable + report progress
-        if records_inserted.is_multiple_of(COMMIT_INTERVAL) {
-            if let Err(e) = conn.execute_batch("COMMIT; BEGIN") {
-                insert_error = Some(e.into());
-                return;
+            // Periodic commit to keep WAL size reasonable + report progress
+            if records_inserted.is_multiple_of(COMMIT_INTERVAL) {
+                conn.execute_batch("COMMIT; BEGIN")?;
+                let parsed = records_parsed.load(Ordering::Relaxed);
+                progress(BuildProgress::Parsing {
+                    records_inserted,
+                    records_parsed: parsed,
+                    queue_depth: parsed.saturating_sub(records_inserted),
+                    bytes_read: bytes_read.load(Ordering::Relaxed),
+                    bytes_total: file_size,
+                });
             }
-            progress(BuildProgress::Parsing {
-                records_inserted,
-                bytes_read: bytes_read_for_progress.get(),
-                bytes_total: file_size,
-            });
-        }
-    });
+            Ok(())
+        },
+    );
 
-    if let Some(err) = insert_error {
+    if let Err(err) = result {
         let _ = conn.execute_batch("ROLLBACK");
         return Err(err);
     }
 
     // Final commit
     conn.execute_batch("COMMIT")?;
+    db::resolve_crossrefs(conn)?;
 
     progress(BuildProgress::Parsing {
         records_inserted,
+        records_parsed: records_inserted,
+        queue_depth: 0,
         bytes_read: file_size, // done
         bytes_total: file_size,
     });
 
-    Ok(())
+    Ok(issues)
+}
+
+/// What the XML parser thread hands to the inserter.
+enum ParseEvent {
+    Publication(xml_parser::Publication),
+    /// A non-fatal anomaly in the dump (see [`xml_parser::parse_xml_with_issues`]).
+    Issue(String),
+}
+
+/// Run the XML parser on a worker thread and feed each publication (and
+/// parse issue) to `insert` on the calling thread.
+///
+/// The two sides are joined by a bounded channel of `capacity` records: when
+/// `insert` is slower than the parser (e.g. SQLite on a slow disk), the parser
+/// blocks instead of buffering ahead. `records_parsed` is bumped by the parser
+/// after each handoff, so `records_parsed - records_inserted` approximates the
+/// current queue depth (at most `capacity + 1`, counting the record in flight).
+///
+/// Publications before `min_year` are dropped on the parser thread, so they
+/// neither occupy the queue nor count as parsed.
+///
+/// If `insert` fails, the channel is closed and the parser runs to the end of
+/// the input without emitting further records; the first error is returned.
+fn parse_pipeline<R: BufRead + Send>(
+    reader: R,
+    capacity: usize,
+    min_year: Option<u32>,
+    records_parsed: &AtomicU64,
+    mut insert: impl FnMut(ParseEvent) -> Result<(), DblpError>,
+) -> Result<(), DblpError> {
+    let (tx, rx) = mpsc::sync_channel::<ParseEvent>(capacity);
+
+    std::thread::scope(|scope| {
+        let parser = scope.spawn(move || {
+            // Set once the inserter hangs up; later events are dropped.
+            let closed = std::cell::Cell::new(false);
+            let send = |event| {
+                if !closed.get() && tx.send(event).is_err() {
+                    closed.set(true);
+                }
+                !closed.get()
+            };
+            xml_parser::parse_xml_with_issues(
+                reader,
+                |pub_record| {
+                    if let Some(min) = min_year
+                        && pub_record.year.is_none_or(|year| year < min)
+                    {
+                        return;
+                    }
+                    if send(ParseEvent::Publication(pub_record)) {
+                        records_parsed.fetch_add(1, Ordering::Relaxed);
+                    }
+                },
+                |message| {
+                    send(ParseEvent::Issue(message));
+                },
+            );
+        });
+
+        let mut result = Ok(());
+        for event in rx.iter() {
+            if let Err(e) = insert(event) {
+                result = Err(e);
+                break;
+            }
+        }
+        // Unblock the parser if we bailed out early.
+        drop(rx);
+
+        parser
+            .join()
+            .map_err(|_| DblpError::Parse("XML parser thread panicked".into()))?;
+        result
+    })
+}
+
+/// Record the year filter a build applied, clearing one left by an earlier
+/// filtered build.
+fn store_min_year(conn: &Connection, min_year: Option<u32>) -> Result<(), DblpError> {
+    match min_year {
+        Some(year) => db::set_metadata(conn, "min_year", &year.to_string()),
+        None => db::delete_metadata(conn, "min_year"),
+    }
 }
 
 /// Unix timestamp as a string (seconds since epoch).
@@ -362,6 +685,7 @@ fn now_unix_timestamp() -> String {
 mod tests {
     use super::*;
     use std::io::Write;
+    use std::sync::Mutex;
 
     /// Create a minimal .xml.gz file for testing.
     fn create_test_xml_gz() -> Vec<u8> {
@@ -397,7 +721,7 @@ mod tests {
         std::fs::write(&xml_gz_path, &gz_data).unwrap();
 
         let mut progress_events = Vec::new();
-        build_from_file(&db_path, &xml_gz_path, |evt| {
+        build_from_file(&db_path, &xml_gz_path, None, |evt| {
             progress_events.push(format!("{:?}", evt));
         })
         .unwrap();
@@ -411,7 +735,7 @@ mod tests {
 
         // Verify metadata
         let schema = db::get_metadata(&conn, "schema_version").unwrap();
-        assert_eq!(schema, Some("3".into()));
+        assert_eq!(schema.as_deref(), Some(db::SCHEMA_VERSION));
 
         let last_updated = db::get_metadata(&conn, "last_updated").unwrap();
         assert!(last_updated.is_some());
@@ -436,6 +760,361 @@ mod tests {
         assert!(progress_events.iter().any(|e| e.contains("Compacting")));
     }
 
+    #[test]
+    fn test_build_from_file_reports_malformed_entries() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let xml = r#"<dblp>
+<article key="a"><title>Good Paper</title><year>2020</year></article>
+<article key="b"><author>Nobody</author></article>
+<article key="c"><title>Odd Year Paper</title><year>MMXX</year></article>
+</dblp>"#;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(xml.as_bytes()).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let xml_gz_path = dir.path().join("test.xml.gz");
+        std::fs::write(&xml_gz_path, encoder.finish().unwrap()).unwrap();
+
+        let mut warnings = Vec::new();
+        let mut issues = None;
+        build_from_file(
+            &dir.path().join("test.db"),
+            &xml_gz_path,
+            None,
+            |evt| match evt {
+                BuildProgress::Warning { message } => warnings.push(message),
+                BuildProgress::Complete { issues: n, .. } => issues = Some(n),
+                _ => {}
+            },
+        )
+        .unwrap();
+
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings[0].starts_with("b: no title"), "{warnings:?}");
+        assert!(warnings[1].contains("MMXX"), "{warnings:?}");
+        assert_eq!(issues, Some(2));
+    }
+
+    #[test]
+    fn test_build_from_file_resolves_crossrefs() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        // The paper comes before its proceedings, as it may in the dump, and
+        // names neither a venue nor a year of its own.
+        let xml = r#"<dblp>
+<inproceedings key="conf/cvpr/HeZRS16"><author>Kaiming He</author>
+<title>Deep Residual Learning for Image Recognition.</title>
+<crossref>conf/cvpr/2016</crossref></inproceedings>
+<inproceedings key="conf/cvpr/Own16"><title>Own Venue Paper.</title>
+<booktitle>CVPR Workshops</booktitle><year>2015</year>
+<crossref>conf/cvpr/2016</crossref></inproceedings>
+<inproceedings key="conf/cvpr/Dangling"><title>Dangling Crossref.</title>
+<crossref>conf/missing/2016</crossref></inproceedings>
+<proceedings key="conf/cvpr/2016"><editor>Lourdes Agapito</editor>
+<title>2016 IEEE Conference on Computer Vision and Pattern Recognition, CVPR 2016.</title>
+<booktitle>CVPR</booktitle><year>2016</year></proceedings>
+</dblp>"#;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(xml.as_bytes()).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let xml_gz_path = dir.path().join("test.xml.gz");
+        let db_path = dir.path().join("test.db");
+        std::fs::write(&xml_gz_path, encoder.finish().unwrap()).unwrap();
+
+        build_from_file(&db_path, &xml_gz_path, None, |_| {}).unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        let venue_year = |key: &str| -> (Option<String>, Option<u32>) {
+            conn.query_row(
+                "SELECT venue, year FROM publications WHERE key = ?1",
+                [key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            venue_year("conf/cvpr/HeZRS16"),
+            (Some("CVPR".into()), Some(2016))
+        );
+        // A paper's own venue and year win over the proceedings'.
+        assert_eq!(
+            venue_year("conf/cvpr/Own16"),
+            (Some("CVPR Workshops".into()), Some(2015))
+        );
+        assert_eq!(venue_year("conf/cvpr/Dangling"), (None, None));
+
+        // The joined venue/year come back with query results.
+        drop(conn);
+        let db = crate::DblpDatabase::open(&db_path).unwrap();
+        let result = db
+            .query("Deep Residual Learning for Image Recognition")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record.venue.as_deref(), Some("CVPR"));
+        assert_eq!(result.record.year, Some(2016));
+    }
+
+    #[test]
+    fn test_build_from_file_min_year_excludes_older_records() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let xml = r#"<dblp>
+<article key="old"><author>Ada</author><title>Old Paper</title><year>2009</year></article>
+<article key="new"><author>Bob</author><title>New Paper</title><year>2019</year></article>
+<article key="edge"><title>Edge Paper</title><year>2015</year></article>
+<article key="undated"><title>Undated Paper</title></article>
+</dblp>"#;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(xml.as_bytes()).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let xml_gz_path = dir.path().join("test.xml.gz");
+        let db_path = dir.path().join("test.db");
+        std::fs::write(&xml_gz_path, encoder.finish().unwrap()).unwrap();
+
+        // Filtering an existing unfiltered database drops its older rows.
+        build_from_file(&db_path, &xml_gz_path, None, |_| {}).unwrap();
+        build_from_file(&db_path, &xml_gz_path, Some(2015), |_| {}).unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        let mut stmt = conn
+            .prepare("SELECT key FROM publications ORDER BY key")
+            .unwrap();
+        let keys: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .filter_map(|r| r.ok())
+            .collect();
+        assert_eq!(keys, ["edge", "new"]);
+        assert_eq!(db::get_counts(&conn).unwrap().1, 1); // Ada never inserted
+        assert_eq!(
+            db::get_metadata(&conn, "min_year").unwrap(),
+            Some("2015".into())
+        );
+
+        drop(stmt);
+        drop(conn);
+
+        // An unfiltered rebuild clears the recorded filter.
+        build_from_file(&db_path, &xml_gz_path, None, |_| {}).unwrap();
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(db::get_metadata(&conn, "min_year").unwrap(), None);
+        assert!(!sqlite::staging_path(&db_path).exists());
+    }
+
+    async fn spawn_http_stub(
+        responses: Vec<(&'static str, Vec<u8>)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        download::stub::spawn_http_stub("/dblp.xml.gz", responses).await
+    }
+
+    #[tokio::test]
+    async fn test_build_retries_after_server_error() {
+        let (url, requests) = spawn_http_stub(vec![
+            ("503 Service Unavailable", Vec::new()),
+            ("200 OK", create_test_xml_gz()),
+        ])
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let options = BuildOptions {
+            url,
+            max_retries: 2,
+            retry_delay: std::time::Duration::from_millis(10),
+            ..BuildOptions::default()
+        };
+
+        let mut retries = Vec::new();
+        let updated = build(&db_path, options, |evt| {
+            if let BuildProgress::Retrying { attempt, error, .. } = evt {
+                retries.push((attempt, error));
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(updated);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert_eq!(retries.len(), 1);
+        assert_eq!(retries[0].0, 1);
+        assert!(retries[0].1.contains("503"), "{}", retries[0].1);
+
+        let conn = Connection::open(&db_path).unwrap();
+        let (pubs, authors, _) = db::get_counts(&conn).unwrap();
+        assert_eq!((pubs, authors), (2, 3));
+
+        // A successful build cleans up its staged download.
+        let (gz_path, etag_path) = staging_paths(&db_path);
+        assert!(!gz_path.exists() && !etag_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_build_reuses_staged_download_with_matching_etag() {
+        // The server only ever answers 304, so a build that needed the body
+        // could not succeed.
+        let (url, requests) = spawn_http_stub(vec![("304 Not Modified", Vec::new())]).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        // A previous build downloaded the dump but never finished parsing.
+        let (gz_path, etag_path) = staging_paths(&db_path);
+        std::fs::write(&gz_path, create_test_xml_gz()).unwrap();
+        std::fs::write(&etag_path, "\"dump-v1\"").unwrap();
+
+        let options = BuildOptions {
+            url,
+            ..BuildOptions::default()
+        };
+        let mut reused = None;
+        let updated = build(&db_path, options, |evt| {
+            if let BuildProgress::ReusingDownload { bytes } = evt {
+                reused = Some(bytes);
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(updated);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(
+            requests[0].contains("if-none-match: \"dump-v1\""),
+            "{}",
+            requests[0]
+        );
+        assert_eq!(reused, Some(create_test_xml_gz().len() as u64));
+
+        let conn = Connection::open(&db_path).unwrap();
+        let (pubs, authors, _) = db::get_counts(&conn).unwrap();
+        assert_eq!((pubs, authors), (2, 3));
+        assert_eq!(
+            db::get_metadata(&conn, "etag").unwrap().as_deref(),
+            Some("\"dump-v1\"")
+        );
+        assert!(!gz_path.exists() && !etag_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_build_ignores_etag_when_min_year_changes() {
+        let (url, requests) = spawn_http_stub(vec![("200 OK", create_test_xml_gz())]).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let xml_gz_path = dir.path().join("test.xml.gz");
+        std::fs::write(&xml_gz_path, create_test_xml_gz()).unwrap();
+        build_from_file(&db_path, &xml_gz_path, None, |_| {}).unwrap();
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            db::set_metadata(&conn, "etag", "\"dump-v1\"").unwrap();
+        }
+
+        let options = BuildOptions {
+            url,
+            min_year: Some(2015),
+            ..BuildOptions::default()
+        };
+        assert!(build(&db_path, options, |_| {}).await.unwrap());
+        assert!(!requests.lock().unwrap()[0].contains("if-none-match"));
+
+        // Neither test record has a year, so the filter excludes both.
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(db::get_counts(&conn).unwrap().0, 0);
+        assert_eq!(
+            db::get_metadata(&conn, "min_year").unwrap(),
+            Some("2015".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remote_staleness_respects_not_modified() {
+        let (url, requests) = spawn_http_stub(vec![
+            ("304 Not Modified", Vec::new()),
+            ("200 OK", Vec::new()),
+        ])
+        .await;
+
+        // Built long ago, from the dump the server still has.
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            db::init_database(&conn).unwrap();
+            db::set_metadata(&conn, "schema_version", db::SCHEMA_VERSION).unwrap();
+            db::set_metadata(&conn, "last_updated", "0").unwrap();
+            db::set_metadata(&conn, "etag", "\"dump-v1\"").unwrap();
+        }
+        let db = crate::DblpDatabase::open(&db_path).unwrap();
+        assert!(db.check_staleness(30).unwrap().is_stale);
+
+        let options = BuildOptions {
+            url,
+            ..BuildOptions::default()
+        };
+        let check = db.check_remote_staleness(30, &options).await.unwrap();
+        assert!(!check.is_stale);
+        assert_eq!(check.update_available, Some(false));
+        {
+            let requests = requests.lock().unwrap();
+            assert!(requests[0].starts_with("head "), "{}", requests[0]);
+            assert!(
+                requests[0].contains("if-none-match: \"dump-v1\""),
+                "{}",
+                requests[0]
+            );
+        }
+
+        // A changed dump (no 304, no matching ETag) is worth rebuilding for.
+        let check = db.check_remote_staleness(30, &options).await.unwrap();
+        assert!(check.is_stale);
+        assert_eq!(check.update_available, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_build_ignores_staged_download_without_etag() {
+        let (url, requests) = spawn_http_stub(vec![("200 OK", create_test_xml_gz())]).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        // A download that was cut off: no ETag sidecar was written.
+        let (gz_path, _) = staging_paths(&db_path);
+        std::fs::write(&gz_path, b"truncated").unwrap();
+
+        let options = BuildOptions {
+            url,
+            ..BuildOptions::default()
+        };
+        assert!(build(&db_path, options, |_| {}).await.unwrap());
+
+        let requests = requests.lock().unwrap();
+        assert!(!requests[0].contains("if-none-match"), "{}", requests[0]);
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(db::get_counts(&conn).unwrap().0, 2);
+    }
+
+    #[tokio::test]
+    async fn test_build_does_not_retry_client_error() {
+        let (url, requests) = spawn_http_stub(vec![("404 Not Found", Vec::new())]).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let options = BuildOptions {
+            url,
+            max_retries: 2,
+            retry_delay: std::time::Duration::from_millis(10),
+            ..BuildOptions::default()
+        };
+
+        let result = build(&dir.path().join("test.db"), options, |_| {}).await;
+
+        assert!(matches!(result, Err(DblpError::Download(ref e)) if e.contains("404")));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_parse_and_insert() {
         let gz_data = create_test_xml_gz();
@@ -447,7 +1126,7 @@ mod tests {
         let conn = Connection::open_in_memory().unwrap();
         db::init_database(&conn).unwrap();
 
-        parse_and_insert(&conn, &xml_gz_path, |_| {}).unwrap();
+        parse_and_insert(&conn, &xml_gz_path, None, |_| {}).unwrap();
 
         let (pubs, authors, rels) = db::get_counts(&conn).unwrap();
         assert_eq!(pubs, 2);
@@ -467,6 +1146,62 @@ mod tests {
         assert_eq!(paper_authors, vec!["Ashish Vaswani", "Noam Shazeer"]);
     }
 
+    #[test]
+    fn test_pipeline_backpressure_bounds_queue() {
+        use std::time::Duration;
+
+        let mut xml = String::from("<?xml version=\"1.0\"?>\n<dblp>\n");
+        for i in 0..300 {
+            xml.push_str(&format!(
+                "<article key=\"test/{i}\"><author>A{i}</author><title>Paper {i}</title></article>\n"
+            ));
+        }
+        xml.push_str("</dblp>");
+
+        let capacity = 8;
+        let records_parsed = AtomicU64::new(0);
+        let mut received: u64 = 0;
+        let mut max_depth: u64 = 0;
+
+        parse_pipeline(xml.as_bytes(), capacity, None, &records_parsed, |_| {
+            received += 1;
+            // Simulate an inserter on a slow disk.
+            std::thread::sleep(Duration::from_micros(300));
+            let depth = records_parsed
+                .load(Ordering::Relaxed)
+                .saturating_sub(received);
+            max_depth = max_depth.max(depth);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(received, 300);
+        assert_eq!(records_parsed.load(Ordering::Relaxed), 300);
+        assert!(
+            max_depth <= capacity as u64 + 1,
+            "queue depth {max_depth} exceeded capacity {capacity}"
+        );
+    }
+
+    #[test]
+    fn test_pipeline_stops_on_insert_error() {
+        let xml = r#"<dblp>
+<article key="a"><title>One</title></article>
+<article key="b"><title>Two</title></article>
+<article key="c"><title>Three</title></article>
+</dblp>"#;
+
+        let records_parsed = AtomicU64::new(0);
+        let mut calls = 0;
+        let result = parse_pipeline(xml.as_bytes(), 1, None, &records_parsed, |_| {
+            calls += 1;
+            Err(DblpError::Parse("boom".into()))
+        });
+
+        assert!(matches!(result, Err(DblpError::Parse(_))));
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     fn test_parse_proceedings_with_editors() {
         use flate2::Compression;
@@ -491,7 +1226,7 @@ mod tests {
         let conn = Connection::open_in_memory().unwrap();
         db::init_database(&conn).unwrap();
 
-        parse_and_insert(&conn, &xml_gz_path, |_| {}).unwrap();
+        parse_and_insert(&conn, &xml_gz_path, None, |_| {}).unwrap();
 
         let (pubs, authors, rels) = db::get_counts(&conn).unwrap();
         assert_eq!(pubs, 1);
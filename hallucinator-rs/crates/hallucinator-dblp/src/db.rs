@@ -4,6 +4,14 @@ use rusqlite::{Connection, params};
 
 use crate::DblpError;
 
+/// Schema version written by a build. v4 added publication years, v5 venues
+/// (resolved through `<crossref>`).
+pub const SCHEMA_VERSION: &str = "5";
+
+/// Older schema versions that [`add_missing_columns`] upgrades in place.
+/// Anything older must be rebuilt.
+pub const UPGRADABLE_SCHEMA_VERSIONS: &[&str] = &["3", "4"];
+
 /// Initialize the database with the required schema.
 /// Sets WAL mode and NORMAL synchronous for performance.
 pub fn init_database(conn: &Connection) -> Result<(), DblpError> {
@@ -23,7 +31,9 @@ pub fn init_database(conn: &Connection) -> Result<(), DblpError> {
         CREATE TABLE IF NOT EXISTS publications (
             id INTEGER PRIMARY KEY,
             key TEXT UNIQUE NOT NULL,
-            title TEXT NOT NULL
+            title TEXT NOT NULL,
+            year INTEGER,
+            venue TEXT
         );
 
         CREATE TABLE IF NOT EXISTS publication_authors (
@@ -45,6 +55,28 @@ pub fn init_database(conn: &Connection) -> Result<(), DblpError> {
         "#,
     )?;
 
+    // Databases from older schemas are rebuilt in place, so add the columns
+    // the build now fills.
+    add_missing_columns(conn)
+}
+
+/// Add the `publications` columns introduced since schema v3 (`year` in v4,
+/// `venue` in v5) to a database that lacks them. Existing rows get NULL
+/// until the next build fills them in.
+pub fn add_missing_columns(conn: &Connection) -> Result<(), DblpError> {
+    for (column, ty) in [("year", "INTEGER"), ("venue", "TEXT")] {
+        let exists: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('publications') WHERE name = ?1",
+            [column],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            conn.execute_batch(&format!(
+                "ALTER TABLE publications ADD COLUMN {} {};",
+                column, ty
+            ))?;
+        }
+    }
     Ok(())
 }
 
@@ -107,15 +139,71 @@ pub fn insert_or_get_publication(
     conn: &Connection,
     key: &str,
     title: &str,
+    year: Option<u32>,
+) -> Result<i64, DblpError> {
+    insert_or_get_publication_with_venue(conn, key, title, year, None)
+}
+
+/// [`insert_or_get_publication`], also storing the publication's venue.
+pub fn insert_or_get_publication_with_venue(
+    conn: &Connection,
+    key: &str,
+    title: &str,
+    year: Option<u32>,
+    venue: Option<&str>,
 ) -> Result<i64, DblpError> {
     let mut stmt = conn.prepare_cached(
-        "INSERT INTO publications (key, title) VALUES (?1, ?2) \
-         ON CONFLICT(key) DO UPDATE SET title = excluded.title RETURNING id",
+        "INSERT INTO publications (key, title, year, venue) VALUES (?1, ?2, ?3, ?4) \
+         ON CONFLICT(key) DO UPDATE SET title = excluded.title, year = excluded.year, \
+         venue = excluded.venue \
+         RETURNING id",
     )?;
-    let id: i64 = stmt.query_row(params![key, title], |row| row.get(0))?;
+    let id: i64 = stmt.query_row(params![key, title, year, venue], |row| row.get(0))?;
     Ok(id)
 }
 
+/// Create the connection-local table that [`record_crossref`] fills during
+/// a build.
+pub fn begin_crossrefs(conn: &Connection) -> Result<(), DblpError> {
+    conn.execute_batch(
+        "CREATE TEMP TABLE IF NOT EXISTS crossrefs (\
+             pub_id INTEGER PRIMARY KEY, \
+             target TEXT NOT NULL\
+         );",
+    )?;
+    Ok(())
+}
+
+/// Remember that publication `pub_id` is part of the record keyed `target`
+/// (an inproceedings' `<crossref>` to its proceedings).
+pub fn record_crossref(conn: &Connection, pub_id: i64, target: &str) -> Result<(), DblpError> {
+    let mut stmt = conn
+        .prepare_cached("INSERT OR REPLACE INTO temp.crossrefs (pub_id, target) VALUES (?1, ?2)")?;
+    stmt.execute(params![pub_id, target])?;
+    Ok(())
+}
+
+/// Fill in the venue and year of publications recorded by
+/// [`record_crossref`] from the record they point to, keeping any the
+/// publication already has. Targets missing from the dump are ignored.
+/// Returns how many publications were updated.
+///
+/// Runs once every record is inserted, since a proceedings record may come
+/// after the papers that reference it in the dump.
+pub fn resolve_crossrefs(conn: &Connection) -> Result<usize, DblpError> {
+    let updated = conn.execute(
+        "UPDATE publications AS p \
+         SET venue = COALESCE(p.venue, parent.venue), year = COALESCE(p.year, parent.year) \
+         FROM temp.crossrefs c JOIN publications parent ON parent.key = c.target \
+         WHERE p.id = c.pub_id \
+           AND (p.venue IS NULL AND parent.venue IS NOT NULL \
+                OR p.year IS NULL AND parent.year IS NOT NULL)",
+        [],
+    )?;
+    conn.execute_batch("DROP TABLE temp.crossrefs;")?;
+    Ok(updated)
+}
+
 /// Rebuild the FTS5 index from the publications table.
 pub fn rebuild_fts_index(conn: &Connection) -> Result<(), DblpError> {
     conn.execute(
@@ -148,6 +236,12 @@ pub fn set_metadata(conn: &Connection, key: &str, value: &str) -> Result<(), Dbl
     Ok(())
 }
 
+/// Remove a metadata value, if present.
+pub fn delete_metadata(conn: &Connection, key: &str) -> Result<(), DblpError> {
+    conn.execute("DELETE FROM metadata WHERE key = ?1", params![key])?;
+    Ok(())
+}
+
 /// Get counts of publications, authors, and relations.
 pub fn get_counts(conn: &Connection) -> Result<(i64, i64, i64), DblpError> {
     let pubs: i64 = conn.query_row("SELECT COUNT(*) FROM publications", [], |row| row.get(0))?;
@@ -199,7 +293,7 @@ mod tests {
     fn test_insert_and_query_batch() {
         let conn = setup_db();
         let author_id = insert_or_get_author(&conn, "Alice Smith").unwrap();
-        let pub_id = insert_or_get_publication(&conn, "rec/1", "Test Paper Title").unwrap();
+        let pub_id = insert_or_get_publication(&conn, "rec/1", "Test Paper Title", None).unwrap();
 
         let mut batch = InsertBatch::new();
         batch.publication_authors.push((pub_id, author_id));
@@ -215,8 +309,8 @@ mod tests {
     fn test_upsert_updates_existing() {
         let conn = setup_db();
 
-        insert_or_get_publication(&conn, "rec/1", "Old Title").unwrap();
-        insert_or_get_publication(&conn, "rec/1", "New Title").unwrap();
+        insert_or_get_publication(&conn, "rec/1", "Old Title", None).unwrap();
+        insert_or_get_publication(&conn, "rec/1", "New Title", None).unwrap();
 
         let title: String = conn
             .query_row(
@@ -248,7 +342,7 @@ mod tests {
         let conn = setup_db();
         let alice_id = insert_or_get_author(&conn, "Alice").unwrap();
         let bob_id = insert_or_get_author(&conn, "Bob").unwrap();
-        let pub_id = insert_or_get_publication(&conn, "rec/1", "Paper").unwrap();
+        let pub_id = insert_or_get_publication(&conn, "rec/1", "Paper", None).unwrap();
 
         let mut batch = InsertBatch::new();
         batch.publication_authors.push((pub_id, alice_id));
@@ -274,8 +368,8 @@ mod tests {
     #[test]
     fn test_fts_rebuild_and_query() {
         let conn = setup_db();
-        insert_or_get_publication(&conn, "rec/1", "Attention is All you Need").unwrap();
-        insert_or_get_publication(&conn, "rec/2", "BERT Pre-training").unwrap();
+        insert_or_get_publication(&conn, "rec/1", "Attention is All you Need", None).unwrap();
+        insert_or_get_publication(&conn, "rec/2", "BERT Pre-training", None).unwrap();
         rebuild_fts_index(&conn).unwrap();
 
         // FTS query
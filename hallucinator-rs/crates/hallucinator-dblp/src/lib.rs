@@ -11,13 +11,15 @@ pub mod query;
 pub mod xml_parser;
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use rusqlite::Connection;
 use thiserror::Error;
 
 // Re-export for convenience
-pub use builder::DEFAULT_DBLP_URL;
-pub use query::DEFAULT_THRESHOLD;
+pub use builder::{DEFAULT_DBLP_URL, DEFAULT_MAX_RETRIES};
+pub use hallucinator_text::TitleScorer;
+pub use query::{DEFAULT_THRESHOLD, QueryGuard};
 
 #[derive(Error, Debug)]
 pub enum DblpError {
@@ -33,14 +35,20 @@ pub enum DblpError {
 
 /// A publication record from the offline DBLP database.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DblpRecord {
     pub title: String,
     pub authors: Vec<String>,
     pub url: Option<String>,
+    pub year: Option<u32>,
+    /// Booktitle or journal, taken from the proceedings record for papers
+    /// that only name it through `<crossref>`.
+    pub venue: Option<String>,
 }
 
 /// Query result with fuzzy match score.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DblpQueryResult {
     pub record: DblpRecord,
     pub score: f64,
@@ -55,6 +63,8 @@ pub struct DatabaseInfo {
     pub author_count: Option<String>,
     pub etag: Option<String>,
     pub last_modified: Option<String>,
+    /// Earliest publication year kept by the last build, if it was filtered.
+    pub min_year: Option<String>,
 }
 
 /// Progress events emitted during database building.
@@ -68,32 +78,95 @@ pub enum BuildProgress {
     Parsing {
         /// Publications inserted into the database.
         records_inserted: u64,
+        /// Publications handed off by the XML parser so far. Runs ahead of
+        /// `records_inserted` by at most the parse queue capacity.
+        records_parsed: u64,
+        /// Parsed publications waiting for the inserter. Sitting at capacity
+        /// means SQLite is the bottleneck; near zero means parsing is.
+        queue_depth: u64,
         /// Compressed bytes consumed from the .xml.gz file.
         bytes_read: u64,
         /// Total compressed file size (for ETA calculation).
         bytes_total: u64,
     },
+    /// A complete download staged by an earlier, unfinished build is still
+    /// current (the server confirmed its ETag) and is parsed instead of
+    /// downloading the dump again.
+    ReusingDownload {
+        bytes: u64,
+    },
+    /// A download attempt failed with a transient error (connection
+    /// failure, 5xx/429 response, interrupted stream); the download restarts
+    /// after a backoff delay.
+    Retrying {
+        /// 1-based retry number.
+        attempt: u32,
+        max_retries: u32,
+        error: String,
+    },
+    /// A non-fatal anomaly in the dump, e.g. a publication skipped for
+    /// lacking a title or indexed without its malformed year.
+    Warning {
+        message: String,
+    },
     RebuildingIndex,
     Compacting,
     Complete {
         publications: u64,
         authors: u64,
+        /// How many [`Warning`](Self::Warning)s the build reported.
+        issues: u64,
         skipped: bool,
     },
 }
 
+/// Options for [`build_database_with_options`].
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// URL of the `dblp.xml.gz` dump.
+    pub url: String,
+    /// How many times a failed connection or interrupted download is retried
+    /// before the build gives up. `0` disables retries.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each subsequent one.
+    pub retry_delay: Duration,
+    /// Skip publications before this year (and those without a parseable
+    /// year) while parsing, for a smaller database.
+    pub min_year: Option<u32>,
+    /// HTTP client for the download and the remote update check, e.g. the
+    /// run's client carrying its proxy and CA settings. `None` uses
+    /// [`hallucinator_text::download::default_client`].
+    pub client: Option<reqwest::Client>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_DBLP_URL.to_string(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: Duration::from_secs(1),
+            min_year: None,
+            client: None,
+        }
+    }
+}
+
 /// Result of a staleness check.
 #[derive(Debug, Clone)]
 pub struct StalenessCheck {
     pub is_stale: bool,
     pub age_days: Option<u64>,
     pub build_date: Option<String>,
+    /// Whether the upstream source changed since this build, when it was
+    /// asked (see `check_remote_staleness`); `None` for a local-only check.
+    pub update_available: Option<bool>,
 }
 
 /// Handle to an opened offline DBLP database.
 pub struct DblpDatabase {
     conn: Connection,
     path: PathBuf,
+    guard: QueryGuard,
 }
 
 impl DblpDatabase {
@@ -114,16 +187,22 @@ impl DblpDatabase {
             return Err(DblpError::Database(rusqlite::Error::QueryReturnedNoRows));
         }
 
-        // Check schema version — v3 uses integer IDs; older versions are incompatible
+        // Check schema version — see `db::SCHEMA_VERSION`. Recent older
+        // versions only lack columns, which are added empty; the stored version
+        // is left alone so the next build still rebuilds the database.
         let version = db::get_metadata(&conn, "schema_version")?;
         match version.as_deref() {
-            Some("3") => {}
+            Some(db::SCHEMA_VERSION) => {}
+            Some(v) if db::UPGRADABLE_SCHEMA_VERSIONS.contains(&v) => {
+                db::add_missing_columns(&conn)?;
+            }
             Some(v) => {
                 return Err(DblpError::Parse(format!(
-                    "DBLP database at {} has schema version {}, but version 3 is required. \
+                    "DBLP database at {} has schema version {}, but version {} is required. \
                      Please rebuild with 'hallucinator-tui update-dblp'.",
                     path.display(),
-                    v
+                    v,
+                    db::SCHEMA_VERSION
                 )));
             }
             None => {
@@ -138,12 +217,13 @@ impl DblpDatabase {
         Ok(Self {
             conn,
             path: path.to_path_buf(),
+            guard: QueryGuard::default(),
         })
     }
 
     /// Query for a title, returning the best fuzzy match above the default threshold.
     pub fn query(&self, title: &str) -> Result<Option<DblpQueryResult>, DblpError> {
-        query::query_fts(&self.conn, title, DEFAULT_THRESHOLD)
+        query::query_fts_guarded(&self.conn, title, DEFAULT_THRESHOLD, &self.guard)
     }
 
     /// Query with a custom similarity threshold.
@@ -152,7 +232,34 @@ impl DblpDatabase {
         title: &str,
         threshold: f64,
     ) -> Result<Option<DblpQueryResult>, DblpError> {
-        query::query_fts(&self.conn, title, threshold)
+        query::query_fts_guarded(&self.conn, title, threshold, &self.guard)
+    }
+
+    /// Query with a custom threshold, scoring candidates with `scorer`.
+    pub fn query_with_scorer(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+    ) -> Result<Option<DblpQueryResult>, DblpError> {
+        self.query_guarded(title, threshold, scorer, &self.guard)
+    }
+
+    /// Like [`query_with_scorer`](Self::query_with_scorer), applying `guard`
+    /// instead of the one set with [`set_query_guard`](Self::set_query_guard).
+    pub fn query_guarded(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+        guard: &QueryGuard,
+    ) -> Result<Option<DblpQueryResult>, DblpError> {
+        query::query_fts_scored(&self.conn, title, None, threshold, guard, scorer)
+    }
+
+    /// Replace the short/generic query guard used by [`query`](Self::query).
+    pub fn set_query_guard(&mut self, guard: QueryGuard) {
+        self.guard = guard;
     }
 
     /// Get database metadata/info.
@@ -164,6 +271,7 @@ impl DblpDatabase {
             author_count: db::get_metadata(&self.conn, "author_count")?,
             etag: db::get_metadata(&self.conn, "etag")?,
             last_modified: db::get_metadata(&self.conn, "last_modified")?,
+            min_year: db::get_metadata(&self.conn, "min_year")?,
         })
     }
 
@@ -186,9 +294,29 @@ impl DblpDatabase {
             is_stale,
             age_days,
             build_date,
+            update_available: None,
         })
     }
 
+    /// Like [`check_staleness`](Self::check_staleness), but also asks the
+    /// dump at `options.url` whether it changed since this build (a
+    /// conditional HEAD against the stored ETag/Last-Modified). An old
+    /// database is only reported stale if an update is actually available.
+    pub async fn check_remote_staleness(
+        &self,
+        threshold_days: u64,
+        options: &BuildOptions,
+    ) -> Result<StalenessCheck, DblpError> {
+        let mut check = self.check_staleness(threshold_days)?;
+        let etag = db::get_metadata(&self.conn, "etag")?;
+        let last_modified = db::get_metadata(&self.conn, "last_modified")?;
+        let update_available =
+            builder::remote_has_update(options, etag.as_deref(), last_modified.as_deref()).await?;
+        check.is_stale &= update_available;
+        check.update_available = Some(update_available);
+        Ok(check)
+    }
+
     /// Convenience: check staleness with the default 30-day threshold.
     pub fn is_stale(&self) -> Result<bool, DblpError> {
         Ok(self.check_staleness(30)?.is_stale)
@@ -204,11 +332,24 @@ impl DblpDatabase {
 ///
 /// Uses ETag/Last-Modified for conditional requests. Returns `false` if the
 /// remote file hasn't changed since the last build (no work done).
+///
+/// A successful build is durable: the WAL is checkpointed and the files are
+/// fsynced before this returns, so the database can be reopened (or handed
+/// to [`DblpDatabase::open`] in another process) right away.
 pub async fn build_database(
     db_path: &Path,
     progress: impl FnMut(BuildProgress),
 ) -> Result<bool, DblpError> {
-    builder::build(db_path, progress).await
+    builder::build(db_path, BuildOptions::default(), progress).await
+}
+
+/// Build or update the offline DBLP database with full [`BuildOptions`].
+pub async fn build_database_with_options(
+    db_path: &Path,
+    options: BuildOptions,
+    progress: impl FnMut(BuildProgress),
+) -> Result<bool, DblpError> {
+    builder::build(db_path, options, progress).await
 }
 
 /// Build the offline DBLP database from a local `.xml.gz` file.
@@ -217,5 +358,107 @@ pub fn build_database_from_file(
     xml_gz_path: &Path,
     progress: impl FnMut(BuildProgress),
 ) -> Result<(), DblpError> {
-    builder::build_from_file(db_path, xml_gz_path, progress)
+    builder::build_from_file(db_path, xml_gz_path, None, progress)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A database as a schema v3 build left it: no `year` or `venue` column.
+    fn build_v3(path: &Path) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE authors (id INTEGER PRIMARY KEY, name TEXT UNIQUE NOT NULL);
+             CREATE TABLE publications (id INTEGER PRIMARY KEY, key TEXT UNIQUE NOT NULL, title TEXT NOT NULL);
+             CREATE TABLE publication_authors (pub_id INTEGER NOT NULL, author_id INTEGER NOT NULL,
+                                               PRIMARY KEY (pub_id, author_id));
+             CREATE VIRTUAL TABLE publications_fts USING fts5(title, content='publications', content_rowid='id');
+             CREATE TABLE metadata (key TEXT PRIMARY KEY, value TEXT);
+             INSERT INTO publications (key, title) VALUES ('conf/nips/V17', 'Attention Is All You Need');
+             INSERT INTO metadata VALUES ('schema_version', '3');",
+        )
+        .unwrap();
+        db::rebuild_fts_index(&conn).unwrap();
+    }
+
+    /// A database as a schema v4 build left it: years but no `venue` column.
+    fn build_v4(path: &Path) {
+        build_v3(path);
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch(
+            "ALTER TABLE publications ADD COLUMN year INTEGER;
+             UPDATE publications SET year = 2017;
+             UPDATE metadata SET value = '4' WHERE key = 'schema_version';",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_open_upgrades_older_schema_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dblp.db");
+        build_v3(&path);
+
+        let db = DblpDatabase::open(&path).unwrap();
+        let result = db.query("Attention Is All You Need").unwrap().unwrap();
+        assert_eq!(result.record.year, None);
+        assert_eq!(result.record.venue, None);
+        // The next build still sees an old schema and rebuilds.
+        assert_eq!(db.info().unwrap().schema_version.as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn test_open_accepts_v4_schema_without_venues() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dblp.db");
+        build_v4(&path);
+
+        let db = DblpDatabase::open(&path).unwrap();
+        let result = db.query("Attention Is All You Need").unwrap().unwrap();
+        assert_eq!(result.record.year, Some(2017));
+        assert_eq!(result.record.venue, None);
+        assert_eq!(db.info().unwrap().schema_version.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn test_open_rejects_unknown_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dblp.db");
+        build_v3(&path);
+        let conn = Connection::open(&path).unwrap();
+        db::set_metadata(&conn, "schema_version", "2").unwrap();
+        drop(conn);
+
+        assert!(matches!(
+            DblpDatabase::open(&path),
+            Err(DblpError::Parse(_))
+        ));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_query_result_round_trip() {
+        let result = DblpQueryResult {
+            record: DblpRecord {
+                title: "Attention Is All You Need".into(),
+                authors: vec!["Ashish Vaswani".into(), "Noam Shazeer".into()],
+                url: Some("https://example.org/paper".into()),
+                year: Some(2017),
+                venue: Some("NeurIPS".into()),
+            },
+            score: 0.97,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"title\":\"Attention Is All You Need\""));
+        let back: DblpQueryResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.record.title, result.record.title);
+        assert_eq!(back.record.authors, result.record.authors);
+        assert_eq!(back.record.url, result.record.url);
+        assert_eq!(back.score, result.score);
+    }
 }
@@ -1,8 +1,9 @@
 //! FTS5 search and fuzzy matching for DBLP queries.
 
+use hallucinator_text::fts::{self, FtsLookup};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use rusqlite::{Connection, params};
+use rusqlite::Connection;
 
 use crate::db;
 use crate::{DblpError, DblpQueryResult, DblpRecord};
@@ -27,17 +28,23 @@ use crate::{DblpError, DblpQueryResult, DblpRecord};
 ///
 /// Note that the FTS5 query acts as a first gate — a fabricated title must share
 /// 3-6 distinctive keywords (AND query) with a real paper before fuzzy matching
-/// even runs. This significantly reduces the false negative surface.
+/// even runs. This significantly reduces the false negative surface. The
+/// [`QueryGuard::loose_fallback`] retry loosens that gate to shared word
+/// prefixes, but only when no title shares the keywords at all.
 pub const DEFAULT_THRESHOLD: f64 = 0.90;
 
-/// Normalize a title for comparison: lowercase alphanumeric only.
+/// Maximum number of FTS5 candidates fetched for fuzzy re-ranking.
+const MAX_CANDIDATES: usize = 50;
+
+pub use hallucinator_text::QueryGuard;
+
+/// Normalize a title for comparison: diacritics folded, lowercase alphanumeric only.
 ///
-/// This is a simplified inline version to avoid depending on hallucinator-core.
-pub fn normalize_title(title: &str) -> String {
-    static NON_ALNUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
-    let lowered = title.to_lowercase();
-    NON_ALNUM.replace_all(&lowered, "").to_string()
-}
+/// Shared with the ACL and OpenAlex crates via `hallucinator-text` so all
+/// offline backends score identically.
+pub use hallucinator_text::normalize_title;
+
+use hallucinator_text::TitleScorer;
 
 /// Strip LaTeX markup from a title string for FTS5 query extraction.
 ///
@@ -76,9 +83,10 @@ fn strip_latex_for_query(title: &str) -> String {
 /// Handles digits (`L2`, `3D`), hyphens (`Machine-Learning`), and apostrophes (`What's`).
 /// Also strips BibTeX braces (`{BERT}` → `BERT`) and LaTeX markup.
 pub fn get_query_words(title: &str) -> Vec<String> {
-    // Strip LaTeX markup and BibTeX capitalization braces
+    // Strip LaTeX markup and BibTeX capitalization braces, fold diacritics
+    // so "Lösung" yields "losung" rather than being split at the umlaut
     let title = strip_latex_for_query(title);
-    let title = title.replace(['{', '}'], "");
+    let title = hallucinator_text::fold_diacritics(&title).replace(['{', '}'], "");
 
     static WORD_RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"[a-zA-Z0-9]+(?:['\u{2019}\u{2018}\-][a-zA-Z0-9]+)*").unwrap());
@@ -148,62 +156,82 @@ pub fn get_query_words(title: &str) -> Vec<String> {
     scored.into_iter().map(|(_, _, lower)| lower).collect()
 }
 
-/// Run an FTS5 query and return the best fuzzy match above the threshold.
-fn fts_match(
+/// Columns read for each FTS5 candidate.
+const CANDIDATE_COLUMNS: &str = "p.id, p.key, p.title, p.year, p.venue";
+
+/// An FTS5 candidate row: `(id, key, title, year, venue)`.
+type Candidate = (i64, String, String, Option<u32>, Option<String>);
+
+/// Run an FTS5 query and return up to `limit` candidates.
+fn fts_candidates(
     conn: &Connection,
+    lookup: FtsLookup,
     fts_query: &str,
-    norm_query: &str,
-    threshold: f64,
-) -> Result<Option<DblpQueryResult>, DblpError> {
-    let mut stmt = conn.prepare_cached(
-        "SELECT p.id, p.key, p.title FROM publications p \
-         WHERE p.id IN (SELECT rowid FROM publications_fts WHERE title MATCH ?1) \
-         LIMIT 50",
-    )?;
-
-    let candidates: Vec<(i64, String, String)> = stmt
-        .query_map(params![fts_query], |row| {
+    limit: usize,
+) -> Result<Vec<Candidate>, DblpError> {
+    Ok(fts::fts_candidates(
+        conn,
+        CANDIDATE_COLUMNS,
+        lookup,
+        fts_query,
+        limit,
+        |row| {
             Ok((
                 row.get::<_, i64>(0)?,
                 row.get::<_, String>(1)?,
                 row.get::<_, String>(2)?,
+                row.get::<_, Option<u32>>(3)?,
+                row.get::<_, Option<String>>(4)?,
             ))
-        })?
-        .filter_map(|r| r.ok())
-        .collect();
-
-    if candidates.is_empty() {
-        return Ok(None);
-    }
+        },
+    )?)
+}
 
-    let mut best_match: Option<(f64, i64, String, String)> = None;
+/// Fuzzy-rank FTS5 candidates and return the best match above the threshold.
+///
+/// With a cited `year`, a candidate from that year beats any other candidate
+/// above the threshold, so a conference paper and its same-titled journal
+/// version resolve to the one actually cited.
+fn rank_candidates(
+    conn: &Connection,
+    candidates: &[Candidate],
+    title: &str,
+    threshold: f64,
+    year: Option<u32>,
+    scorer: TitleScorer,
+) -> Result<Option<DblpQueryResult>, DblpError> {
+    let mut best_match: Option<(bool, f64, &Candidate)> = None;
+    let cited = scorer.prepare(title);
 
-    for (id, key, candidate_title) in &candidates {
-        let norm_candidate = normalize_title(candidate_title);
-        if norm_candidate.is_empty() {
+    for candidate in candidates {
+        let (_, _, candidate_title, candidate_year, _) = candidate;
+        if normalize_title(candidate_title).is_empty() {
             continue;
         }
 
-        let score = rapidfuzz::fuzz::ratio(norm_query.chars(), norm_candidate.chars());
+        let score = cited.score(candidate_title);
 
+        let year_match = year.is_some() && *candidate_year == year;
         if score >= threshold
             && best_match
                 .as_ref()
-                .is_none_or(|(best, _, _, _)| score > *best)
+                .is_none_or(|&(best_year, best, _)| (year_match, score) > (best_year, best))
         {
-            best_match = Some((score, *id, key.clone(), candidate_title.clone()));
+            best_match = Some((year_match, score, candidate));
         }
     }
 
     match best_match {
-        Some((score, id, key, matched_title)) => {
-            let authors = db::get_authors_for_publication(conn, id)?;
+        Some((_, score, (id, key, matched_title, matched_year, venue))) => {
+            let authors = db::get_authors_for_publication(conn, *id)?;
             let url = format!("https://dblp.org/rec/{}", key);
             Ok(Some(DblpQueryResult {
                 record: DblpRecord {
-                    title: matched_title,
+                    title: matched_title.clone(),
                     authors,
                     url: Some(url),
+                    year: *matched_year,
+                    venue: venue.clone(),
                 },
                 score,
             }))
@@ -213,32 +241,81 @@ fn fts_match(
 }
 
 /// Query the FTS5 index for a title, returning the best match above the threshold.
+///
+/// Applies the default [`QueryGuard`].
 pub fn query_fts(
     conn: &Connection,
     title: &str,
     threshold: f64,
+) -> Result<Option<DblpQueryResult>, DblpError> {
+    query_fts_guarded(conn, title, threshold, &QueryGuard::default())
+}
+
+/// Query the FTS5 index for a title with an explicit [`QueryGuard`].
+pub fn query_fts_guarded(
+    conn: &Connection,
+    title: &str,
+    threshold: f64,
+    guard: &QueryGuard,
+) -> Result<Option<DblpQueryResult>, DblpError> {
+    query_fts_with_year(conn, title, None, threshold, guard)
+}
+
+/// Query the FTS5 index for a title, preferring matches from the cited `year`.
+///
+/// Without a year this is the same as [`query_fts_guarded`].
+pub fn query_fts_with_year(
+    conn: &Connection,
+    title: &str,
+    year: Option<u32>,
+    threshold: f64,
+    guard: &QueryGuard,
+) -> Result<Option<DblpQueryResult>, DblpError> {
+    query_fts_scored(conn, title, year, threshold, guard, TitleScorer::default())
+}
+
+/// Query the FTS5 index, ranking candidates with `scorer` instead of the
+/// default [`TitleScorer::Ratio`]. `threshold` applies to `scorer`'s score.
+pub fn query_fts_scored(
+    conn: &Connection,
+    title: &str,
+    year: Option<u32>,
+    threshold: f64,
+    guard: &QueryGuard,
+    scorer: TitleScorer,
 ) -> Result<Option<DblpQueryResult>, DblpError> {
     let words = get_query_words(title);
-    if words.is_empty() {
+    let Some((threshold, limit)) = guard.limits(words.len(), threshold, MAX_CANDIDATES) else {
         return Ok(None);
-    }
+    };
 
-    let norm_query = normalize_title(title);
-    if norm_query.is_empty() {
+    if normalize_title(title).is_empty() {
         return Ok(None);
     }
 
     // Primary query: all words joined with AND
-    let fts_query = words.join(" ");
-    let result = fts_match(conn, &fts_query, &norm_query, threshold)?;
-    if result.is_some() {
-        return Ok(result);
+    let candidates = fts_candidates(conn, FtsLookup::Keywords, &words.join(" "), limit)?;
+    if let Some(result) = rank_candidates(conn, &candidates, title, threshold, year, scorer)? {
+        return Ok(Some(result));
     }
+    let mut any_candidates = !candidates.is_empty();
 
     // Fallback: retry with top 3 words when primary query returned nothing
     if words.len() > 3 {
-        let fallback_query = words[..3].join(" ");
-        return fts_match(conn, &fallback_query, &norm_query, threshold);
+        let candidates = fts_candidates(conn, FtsLookup::Keywords, &words[..3].join(" "), limit)?;
+        if let Some(result) = rank_candidates(conn, &candidates, title, threshold, year, scorer)? {
+            return Ok(Some(result));
+        }
+        any_candidates |= !candidates.is_empty();
+    }
+
+    // Last resort: no title shares the keywords verbatim, so loosen to prefixes
+    if guard.allows_loose_fallback(words.len()) && !any_candidates {
+        let fts_query = fts::prefix_query(&words);
+        if !fts_query.is_empty() {
+            let candidates = fts_candidates(conn, FtsLookup::Ranked, &fts_query, limit)?;
+            return rank_candidates(conn, &candidates, title, threshold, year, scorer);
+        }
     }
 
     Ok(None)
@@ -262,12 +339,14 @@ mod tests {
             &conn,
             "conf/nips/VaswaniSPUJGKP17",
             "Attention is All you Need",
+            Some(2017),
         )
         .unwrap();
         insert_or_get_publication(
             &conn,
             "conf/naacl/DevlinCLT19",
             "BERT: Pre-training of Deep Bidirectional Transformers for Language Understanding",
+            Some(2019),
         )
         .unwrap();
 
@@ -286,6 +365,34 @@ mod tests {
         assert_eq!(normalize_title("  A--B  "), "ab");
     }
 
+    #[test]
+    fn test_normalize_title_diacritics_score() {
+        // The previous ASCII-only normalization dropped accented letters
+        // outright, so a correctly-cited title lost several points.
+        fn ascii_only(title: &str) -> String {
+            static NON_ALNUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
+            NON_ALNUM.replace_all(&title.to_lowercase(), "").to_string()
+        }
+        let cited = "Érdős–Rényi Übergänge in Gödels Sätzen";
+        let indexed = "Erdos-Renyi Ubergange in Godels Satzen";
+
+        let before = rapidfuzz::fuzz::ratio(ascii_only(cited).chars(), ascii_only(indexed).chars());
+        let after = rapidfuzz::fuzz::ratio(
+            normalize_title(cited).chars(),
+            normalize_title(indexed).chars(),
+        );
+        assert!(before < DEFAULT_THRESHOLD, "before = {before}");
+        assert_eq!(after, 1.0);
+    }
+
+    #[test]
+    fn test_get_query_words_diacritics() {
+        let words = get_query_words("Über die Lösbarkeit von Gödels Sätzen");
+        assert!(words.contains(&"uber".to_string()));
+        assert!(words.contains(&"losbarkeit".to_string()));
+        assert!(words.contains(&"godels".to_string()));
+    }
+
     #[test]
     fn test_get_query_words() {
         let words = get_query_words("Attention is All you Need");
@@ -334,6 +441,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_fts_token_set_rescues_reordered_title() {
+        let conn = setup_db_with_data();
+        let reordered =
+            "Deep Bidirectional Transformers for Language Understanding: BERT Pre-training";
+        let guard = QueryGuard::default();
+
+        let ratio = query_fts_scored(
+            &conn,
+            reordered,
+            None,
+            DEFAULT_THRESHOLD,
+            &guard,
+            TitleScorer::Ratio,
+        )
+        .unwrap();
+        assert!(ratio.is_none());
+
+        let result = query_fts_scored(
+            &conn,
+            reordered,
+            None,
+            DEFAULT_THRESHOLD,
+            &guard,
+            TitleScorer::TokenSet,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            result.record.title,
+            "BERT: Pre-training of Deep Bidirectional Transformers for Language Understanding"
+        );
+        assert!(result.score >= DEFAULT_THRESHOLD);
+    }
+
     #[test]
     fn test_query_fts_no_match() {
         let conn = setup_db_with_data();
@@ -352,4 +494,80 @@ mod tests {
         let result = query_fts(&conn, "", DEFAULT_THRESHOLD).unwrap();
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_query_fts_stopwords_only_skips_index() {
+        // No schema at all: any FTS5 query would error, so a `None` here
+        // proves the guard returned before touching the database.
+        let conn = Connection::open_in_memory().unwrap();
+        let result = query_fts(&conn, "About Their Other Through", DEFAULT_THRESHOLD).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_guard_min_words() {
+        let conn = Connection::open_in_memory().unwrap();
+        let guard = QueryGuard {
+            min_query_words: 3,
+            ..QueryGuard::default()
+        };
+        let result = query_fts_guarded(&conn, "Deep Learning", DEFAULT_THRESHOLD, &guard).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_fts_prefix_fallback() {
+        let conn = setup_db_with_data();
+        // Typos in the leading keywords make every AND query miss
+        let title =
+            "BRET: Pre-trainng of Deep Bidirectional Transformers for Language Understanding";
+
+        let guard = QueryGuard {
+            loose_fallback: true,
+            ..QueryGuard::default()
+        };
+        let result = query_fts_guarded(&conn, title, DEFAULT_THRESHOLD, &guard)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            result.record.title,
+            "BERT: Pre-training of Deep Bidirectional Transformers for Language Understanding"
+        );
+        assert!(result.score >= DEFAULT_THRESHOLD);
+
+        let result = query_fts(&conn, title, DEFAULT_THRESHOLD).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_fts_with_year_prefers_cited_year() {
+        let conn = Connection::open_in_memory().unwrap();
+        init_database(&conn).unwrap();
+        let title = "Mastering the Game of Go without Human Knowledge";
+        insert_or_get_publication(&conn, "conf/test/Go16", title, Some(2016)).unwrap();
+        insert_or_get_publication(&conn, "journals/test/Go17", title, Some(2017)).unwrap();
+        rebuild_fts_index(&conn).unwrap();
+
+        let guard = QueryGuard::default();
+        for (year, key) in [(2016, "conf/test/Go16"), (2017, "journals/test/Go17")] {
+            let result = query_fts_with_year(&conn, title, Some(year), DEFAULT_THRESHOLD, &guard)
+                .unwrap()
+                .unwrap();
+            assert_eq!(result.record.year, Some(year));
+            assert_eq!(
+                result.record.url,
+                Some(format!("https://dblp.org/rec/{key}"))
+            );
+        }
+
+        // No year (or an unknown one): still the best title match
+        let result = query_fts_with_year(&conn, title, None, DEFAULT_THRESHOLD, &guard)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record.title, title);
+        let result = query_fts_with_year(&conn, title, Some(1999), DEFAULT_THRESHOLD, &guard)
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.record.title, title);
+    }
 }
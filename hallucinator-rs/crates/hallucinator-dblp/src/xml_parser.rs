@@ -1,7 +1,7 @@
 //! XML parser for DBLP XML dump format.
 //!
 //! Parses the DBLP XML dump (`dblp.xml.gz`) using SAX-style event processing,
-//! extracting publications with their titles, authors, and URLs.
+//! extracting publications with their titles, authors, URLs, and venues.
 
 use std::io::BufRead;
 
@@ -19,6 +19,12 @@ pub struct Publication {
     pub authors: Vec<String>,
     /// First electronic edition URL (from `<ee>` element)
     pub url: Option<String>,
+    /// Publication year (from `<year>` element)
+    pub year: Option<u32>,
+    /// Venue (from `<booktitle>`, or `<journal>` for articles)
+    pub venue: Option<String>,
+    /// Key of the proceedings/book this record is part of (from `<crossref>`)
+    pub crossref: Option<String>,
 }
 
 /// DBLP XML element types that represent publications.
@@ -41,6 +47,9 @@ enum Field {
     Title,
     Author,
     Url,
+    Year,
+    Venue,
+    Crossref,
 }
 
 impl Field {
@@ -49,6 +58,9 @@ impl Field {
             Field::Title => b"title",
             Field::Author => b"author",
             Field::Url => b"ee",
+            Field::Year => b"year",
+            Field::Venue => b"booktitle",
+            Field::Crossref => b"crossref",
         }
     }
 }
@@ -56,10 +68,23 @@ impl Field {
 /// Parse a DBLP XML dump, calling `on_pub` for each publication found.
 ///
 /// Handles the DBLP DTD structure where publication elements (article,
-/// inproceedings, etc.) contain `<title>`, `<author>`/`<editor>`, and `<ee>`
-/// child elements. Title elements may contain inline formatting sub-elements
-/// (`<i>`, `<sub>`, `<sup>`, `<tt>`) whose text content is accumulated.
-pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
+/// inproceedings, etc.) contain `<title>`, `<author>`/`<editor>`, `<ee>`,
+/// `<year>`, `<booktitle>`/`<journal>`, and `<crossref>` child elements. Title elements may contain inline formatting
+/// sub-elements (`<i>`, `<sub>`, `<sup>`, `<tt>`) whose text content is
+/// accumulated.
+pub fn parse_xml<R: BufRead>(reader: R, on_pub: impl FnMut(Publication)) {
+    parse_xml_with_issues(reader, on_pub, |_| {});
+}
+
+/// [`parse_xml`], also calling `on_issue` with a description of each
+/// non-fatal anomaly: a publication skipped for lacking a title, a year that
+/// isn't a number (the publication is kept without one), or malformed XML
+/// that the parser skipped over.
+pub fn parse_xml_with_issues<R: BufRead>(
+    reader: R,
+    mut on_pub: impl FnMut(Publication),
+    mut on_issue: impl FnMut(String),
+) {
     let mut xml = Reader::from_reader(reader);
     xml.config_mut().trim_text(false);
 
@@ -71,10 +96,15 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
     let mut current_title = String::new();
     let mut current_authors: Vec<String> = Vec::new();
     let mut current_url: Option<String> = None;
+    let mut current_year: Option<u32> = None;
+    let mut current_venue: Option<String> = None;
+    let mut current_crossref: Option<String> = None;
     let mut reading: Option<Field> = None;
     let mut text_buf = String::new();
     // Track if we're reading an <editor> (treat same as author)
     let mut reading_editor = false;
+    // Track if we're reading a <journal> (treat same as booktitle)
+    let mut reading_journal = false;
 
     loop {
         match xml.read_event_into(&mut buf) {
@@ -88,8 +118,12 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                     current_title.clear();
                     current_authors.clear();
                     current_url = None;
+                    current_year = None;
+                    current_venue = None;
+                    current_crossref = None;
                     reading = None;
                     reading_editor = false;
+                    reading_journal = false;
 
                     for attr in e.attributes().flatten() {
                         if attr.key.as_ref() == b"key" {
@@ -116,6 +150,19 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                             reading = Some(Field::Url);
                             text_buf.clear();
                         }
+                        b"year" => {
+                            reading = Some(Field::Year);
+                            text_buf.clear();
+                        }
+                        b"booktitle" | b"journal" if current_venue.is_none() => {
+                            reading = Some(Field::Venue);
+                            reading_journal = name_ref == b"journal";
+                            text_buf.clear();
+                        }
+                        b"crossref" => {
+                            reading = Some(Field::Crossref);
+                            text_buf.clear();
+                        }
                         _ => {}
                     }
                 }
@@ -130,11 +177,9 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                 }
             }
 
-            Ok(Event::CData(ref e)) => {
-                if reading.is_some() {
-                    let text = String::from_utf8_lossy(e.as_ref());
-                    text_buf.push_str(&text);
-                }
+            Ok(Event::CData(ref e)) if reading.is_some() => {
+                let text = String::from_utf8_lossy(e.as_ref());
+                text_buf.push_str(&text);
             }
 
             Ok(Event::End(ref e)) => {
@@ -145,6 +190,8 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                 if let Some(ref field) = reading {
                     let target = if reading_editor {
                         b"editor" as &[u8]
+                    } else if reading_journal {
+                        b"journal"
                     } else {
                         field.element_name()
                     };
@@ -155,19 +202,31 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                             Some(Field::Title) => {
                                 current_title = text;
                             }
-                            Some(Field::Author) => {
-                                if !text.is_empty() {
-                                    current_authors.push(text);
-                                }
+                            Some(Field::Author) if !text.is_empty() => {
+                                current_authors.push(text);
                             }
-                            Some(Field::Url) => {
-                                if !text.is_empty() {
-                                    current_url = Some(text);
+                            Some(Field::Url) if !text.is_empty() => {
+                                current_url = Some(text);
+                            }
+                            Some(Field::Year) => {
+                                current_year = text.parse().ok();
+                                if current_year.is_none() {
+                                    on_issue(format!(
+                                        "{}: unparseable year {:?}, indexed without a year",
+                                        current_key, text
+                                    ));
                                 }
                             }
-                            None => {}
+                            Some(Field::Venue) if !text.is_empty() => {
+                                current_venue = Some(text);
+                            }
+                            Some(Field::Crossref) if !text.is_empty() => {
+                                current_crossref = Some(text);
+                            }
+                            _ => {}
                         }
                         reading_editor = false;
+                        reading_journal = false;
                     }
                 } else if in_pub && is_pub_element(name_ref) {
                     // End of publication element — emit if we got a title
@@ -177,15 +236,25 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                             title: std::mem::take(&mut current_title),
                             authors: std::mem::take(&mut current_authors),
                             url: current_url.take(),
+                            year: current_year.take(),
+                            venue: current_venue.take(),
+                            crossref: current_crossref.take(),
                         });
+                    } else {
+                        on_issue(format!("{}: no title, skipped", current_key));
                     }
                     in_pub = false;
                 }
             }
 
             Ok(Event::Eof) => break,
-            Err(_) => {
+            Err(e) => {
                 // Skip malformed elements, continue parsing
+                on_issue(format!(
+                    "malformed XML near byte {}: {}",
+                    xml.buffer_position(),
+                    e
+                ));
                 continue;
             }
             _ => {}
@@ -223,6 +292,7 @@ mod tests {
             pubs[0].url.as_deref(),
             Some("https://doi.org/10.1145/361604.361612")
         );
+        assert_eq!(pubs[0].year, Some(1974));
     }
 
     #[test]
@@ -249,6 +319,33 @@ mod tests {
         assert!(pubs[0].url.as_ref().unwrap().contains("neurips"));
     }
 
+    #[test]
+    fn test_parse_venue_and_crossref() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dblp>
+<inproceedings key="conf/cvpr/HeZRS16">
+  <author>Kaiming He</author>
+  <title>Deep Residual Learning for Image Recognition.</title>
+  <booktitle>CVPR</booktitle>
+  <crossref>conf/cvpr/2016</crossref>
+</inproceedings>
+<article key="journals/cacm/Knuth74">
+  <title>Computer Programming as an Art.</title>
+  <journal>Commun. ACM</journal>
+</article>
+</dblp>"#;
+
+        let mut pubs = Vec::new();
+        parse_xml(xml.as_bytes(), |p| pubs.push(p));
+
+        assert_eq!(pubs.len(), 2);
+        assert_eq!(pubs[0].venue.as_deref(), Some("CVPR"));
+        assert_eq!(pubs[0].crossref.as_deref(), Some("conf/cvpr/2016"));
+        assert_eq!(pubs[0].year, None);
+        assert_eq!(pubs[1].venue.as_deref(), Some("Commun. ACM"));
+        assert_eq!(pubs[1].crossref, None);
+    }
+
     #[test]
     fn test_parse_title_with_formatting() {
         let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -325,6 +422,33 @@ mod tests {
         assert_eq!(pubs[0].key, "test/withtitle");
     }
 
+    #[test]
+    fn test_issues_reported_for_malformed_entries() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dblp>
+<article key="test/notitle">
+  <author>No Title Author</author>
+</article>
+<article key="test/badyear">
+  <title>Odd Year</title>
+  <year>19x4</year>
+</article>
+</dblp>"#;
+
+        let mut pubs = Vec::new();
+        let mut issues = Vec::new();
+        parse_xml_with_issues(xml.as_bytes(), |p| pubs.push(p), |i| issues.push(i));
+
+        assert_eq!(pubs.len(), 1);
+        assert_eq!(pubs[0].year, None);
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert!(
+            issues[0].starts_with("test/notitle: no title"),
+            "{issues:?}"
+        );
+        assert!(issues[1].contains("test/badyear") && issues[1].contains("19x4"));
+    }
+
     #[test]
     fn test_parse_multiple_types() {
         let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -78,11 +78,11 @@ fn build_test_db(titles: &HashSet<String>) -> Connection {
 
     // Set schema version so DblpDatabase::open would accept it (not needed for
     // direct query_fts calls, but good hygiene).
-    db::set_metadata(&conn, "schema_version", "3").unwrap();
+    db::set_metadata(&conn, "schema_version", db::SCHEMA_VERSION).unwrap();
 
     for (i, title) in titles.iter().enumerate() {
         let key = format!("test/{}", i);
-        db::insert_or_get_publication(&conn, &key, title).unwrap();
+        db::insert_or_get_publication(&conn, &key, title, None).unwrap();
     }
 
     db::rebuild_fts_index(&conn).unwrap();
@@ -1,4 +1,6 @@
 use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
@@ -26,6 +28,74 @@ pub enum ArchiveItem {
     Done { total: usize },
 }
 
+/// Options for [`extract_archive_with_options`] and
+/// [`extract_archive_streaming_with_options`].
+#[derive(Debug, Clone)]
+pub struct ArchiveOptions {
+    /// Limit on total extracted bytes (0 = unlimited).
+    pub max_size: u64,
+    /// Skip files whose content is identical to one already extracted from
+    /// the same archive (e.g. `paper.pdf` and `paper (1).pdf`).
+    pub dedup: bool,
+}
+
+impl Default for ArchiveOptions {
+    fn default() -> Self {
+        Self {
+            max_size: 0,
+            dedup: true,
+        }
+    }
+}
+
+/// Remembers the content of the files extracted so far, keyed by hash.
+pub(crate) struct Dedup {
+    enabled: bool,
+    seen: HashMap<u64, Vec<(PathBuf, String)>>,
+}
+
+impl Dedup {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// If `data` duplicates a file extracted earlier, return a warning naming
+    /// both. Otherwise remember it as about to be written to `path`.
+    fn check(&mut self, data: &[u8], path: &Path, filename: &str) -> Option<String> {
+        self.duplicate_of(data, path, filename)
+            .map(|original| format!("Skipping {}: identical to {}", filename, original))
+    }
+
+    /// The name of an earlier file with the same content as `data`, which is
+    /// then skipped. Otherwise `data` is remembered as the content of `path`.
+    pub(crate) fn duplicate_of(
+        &mut self,
+        data: &[u8],
+        path: &Path,
+        filename: &str,
+    ) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        let candidates = self.seen.entry(hasher.finish()).or_default();
+        // Confirm the match byte for byte so a hash collision can't drop a file.
+        if let Some((_, original)) = candidates
+            .iter()
+            .find(|(p, _)| std::fs::read(p).is_ok_and(|prev| prev == data))
+        {
+            tracing::info!(filename, original, "skipping duplicate file");
+            return Some(original.clone());
+        }
+        candidates.push((path.to_path_buf(), filename.to_string()));
+        None
+    }
+}
+
 /// Returns true if the given path looks like a supported archive.
 pub fn is_archive_path(path: &Path) -> bool {
     let name = path
@@ -56,10 +126,26 @@ fn passes_magic_check(name: &str, data: &[u8]) -> bool {
 /// Supports ZIP and tar.gz archives. Type is detected by extension and magic bytes.
 /// `max_size` limits total extracted bytes (0 = unlimited). When the limit is reached,
 /// extraction stops and a warning is included in the result.
+///
+/// Every file is extracted, even if identical to another; use
+/// [`extract_archive_with_options`] to skip duplicates.
 pub fn extract_archive(
     archive_path: &Path,
     dir: &Path,
     max_size: u64,
+) -> Result<ExtractionResult, String> {
+    let options = ArchiveOptions {
+        max_size,
+        dedup: false,
+    };
+    extract_archive_with_options(archive_path, dir, &options)
+}
+
+/// Like [`extract_archive`], with full [`ArchiveOptions`].
+pub fn extract_archive_with_options(
+    archive_path: &Path,
+    dir: &Path,
+    options: &ArchiveOptions,
 ) -> Result<ExtractionResult, String> {
     let data = std::fs::read(archive_path)
         .map_err(|e| format!("Failed to read archive {}: {}", archive_path.display(), e))?;
@@ -71,10 +157,10 @@ pub fn extract_archive(
 
     // Detect by extension first, then fall back to magic bytes
     if name.ends_with(".zip") || data.starts_with(b"PK") {
-        extract_from_zip(&data, dir, max_size)
+        zip_batch(&data, dir, options)
     } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") || data.starts_with(&[0x1f, 0x8b])
     {
-        extract_from_tar_gz(&data, dir, max_size)
+        tar_gz_batch(&data, dir, options)
     } else {
         Err(format!(
             "Unsupported archive format: {}",
@@ -90,6 +176,20 @@ pub fn extract_from_zip(
     dir: &Path,
     max_size: u64,
 ) -> Result<ExtractionResult, String> {
+    let options = ArchiveOptions {
+        max_size,
+        dedup: false,
+    };
+    zip_batch(data, dir, &options)
+}
+
+fn zip_batch(
+    data: &[u8],
+    dir: &Path,
+    options: &ArchiveOptions,
+) -> Result<ExtractionResult, String> {
+    let max_size = options.max_size;
+    let mut dedup = Dedup::new(options.dedup);
     let cursor = std::io::Cursor::new(data);
     let mut archive =
         zip::ZipArchive::new(cursor).map_err(|e| format!("Failed to open ZIP: {}", e))?;
@@ -159,6 +259,10 @@ pub fn extract_from_zip(
         if !passes_magic_check(&name_str, &buf) {
             continue;
         }
+        if let Some(warning) = dedup.check(&buf, &out_path, &basename) {
+            warnings.push(warning);
+            continue;
+        }
 
         std::fs::write(&out_path, &buf)
             .map_err(|e| format!("Failed to write {}: {}", out_name, e))?;
@@ -177,12 +281,27 @@ pub fn extract_from_zip(
 }
 
 /// Extract PDF files from a tar.gz archive (batch).
-/// `max_size` limits total extracted bytes (0 = unlimited).
+/// `max_size` limits total extracted bytes (0 = unlimited). Duplicate files
+/// are skipped with a warning.
 pub fn extract_from_tar_gz(
     data: &[u8],
     dir: &Path,
     max_size: u64,
 ) -> Result<ExtractionResult, String> {
+    let options = ArchiveOptions {
+        max_size,
+        ..ArchiveOptions::default()
+    };
+    tar_gz_batch(data, dir, &options)
+}
+
+fn tar_gz_batch(
+    data: &[u8],
+    dir: &Path,
+    options: &ArchiveOptions,
+) -> Result<ExtractionResult, String> {
+    let max_size = options.max_size;
+    let mut dedup = Dedup::new(options.dedup);
     let gz = GzDecoder::new(data);
     let mut archive = Archive::new(gz);
 
@@ -257,6 +376,10 @@ pub fn extract_from_tar_gz(
         if !passes_magic_check(&name_str, &buf) {
             continue;
         }
+        if let Some(warning) = dedup.check(&buf, &out_path, &basename) {
+            warnings.push(warning);
+            continue;
+        }
 
         std::fs::write(&out_path, &buf)
             .map_err(|e| format!("Failed to write {}: {}", out_name, e))?;
@@ -279,11 +402,27 @@ pub fn extract_from_tar_gz(
 /// This is the streaming counterpart of [`extract_archive`]. Instead of collecting all
 /// files into a Vec, each extracted file is sent immediately via the channel so the caller
 /// can process them incrementally. On completion, sends `ArchiveItem::Done`.
+/// Files identical to one already sent are skipped with an
+/// [`ArchiveItem::Warning`].
 pub fn extract_archive_streaming(
     archive_path: &Path,
     dir: &Path,
     max_size: u64,
     tx: &mpsc::Sender<ArchiveItem>,
+) -> Result<(), String> {
+    let options = ArchiveOptions {
+        max_size,
+        ..ArchiveOptions::default()
+    };
+    extract_archive_streaming_with_options(archive_path, dir, &options, tx)
+}
+
+/// Like [`extract_archive_streaming`], with full [`ArchiveOptions`].
+pub fn extract_archive_streaming_with_options(
+    archive_path: &Path,
+    dir: &Path,
+    options: &ArchiveOptions,
+    tx: &mpsc::Sender<ArchiveItem>,
 ) -> Result<(), String> {
     let data = std::fs::read(archive_path)
         .map_err(|e| format!("Failed to read archive {}: {}", archive_path.display(), e))?;
@@ -294,10 +433,10 @@ pub fn extract_archive_streaming(
         .unwrap_or_default();
 
     if name.ends_with(".zip") || data.starts_with(b"PK") {
-        extract_from_zip_streaming(&data, dir, max_size, tx)
+        extract_from_zip_streaming(&data, dir, options, tx)
     } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") || data.starts_with(&[0x1f, 0x8b])
     {
-        extract_from_tar_gz_streaming(&data, dir, max_size, tx)
+        extract_from_tar_gz_streaming(&data, dir, options, tx)
     } else {
         Err(format!(
             "Unsupported archive format: {}",
@@ -310,9 +449,11 @@ pub fn extract_archive_streaming(
 fn extract_from_zip_streaming(
     data: &[u8],
     dir: &Path,
-    max_size: u64,
+    options: &ArchiveOptions,
     tx: &mpsc::Sender<ArchiveItem>,
 ) -> Result<(), String> {
+    let max_size = options.max_size;
+    let mut dedup = Dedup::new(options.dedup);
     let cursor = std::io::Cursor::new(data);
     let mut archive =
         zip::ZipArchive::new(cursor).map_err(|e| format!("Failed to open ZIP: {}", e))?;
@@ -375,6 +516,10 @@ fn extract_from_zip_streaming(
         if !passes_magic_check(&name_str, &buf) {
             continue;
         }
+        if let Some(warning) = dedup.check(&buf, &out_path, &basename) {
+            let _ = tx.send(ArchiveItem::Warning(warning));
+            continue;
+        }
 
         std::fs::write(&out_path, &buf)
             .map_err(|e| format!("Failed to write {}: {}", out_name, e))?;
@@ -403,9 +548,11 @@ fn extract_from_zip_streaming(
 fn extract_from_tar_gz_streaming(
     data: &[u8],
     dir: &Path,
-    max_size: u64,
+    options: &ArchiveOptions,
     tx: &mpsc::Sender<ArchiveItem>,
 ) -> Result<(), String> {
+    let max_size = options.max_size;
+    let mut dedup = Dedup::new(options.dedup);
     let gz = GzDecoder::new(data);
     let mut archive = Archive::new(gz);
 
@@ -472,6 +619,10 @@ fn extract_from_tar_gz_streaming(
         if !passes_magic_check(&name_str, &buf) {
             continue;
         }
+        if let Some(warning) = dedup.check(&buf, &out_path, &basename) {
+            let _ = tx.send(ArchiveItem::Warning(warning));
+            continue;
+        }
 
         std::fs::write(&out_path, &buf)
             .map_err(|e| format!("Failed to write {}: {}", out_name, e))?;
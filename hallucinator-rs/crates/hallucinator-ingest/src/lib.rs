@@ -1,22 +1,53 @@
+use std::fs::File;
+use std::io::Write;
 use std::path::Path;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
+use tempfile::NamedTempFile;
 use thiserror::Error;
 
+// PDF text extractor, chosen at compile time: MuPDF unless only the
+// non-AGPL `pdf-extract` feature is enabled.
+#[cfg(all(feature = "pdf-extract", not(feature = "pdf")))]
+use hallucinator_pdf_extract::PdfExtractBackend as PdfBackendImpl;
+#[cfg(feature = "pdf")]
+use hallucinator_pdf_mupdf::MupdfBackend as PdfBackendImpl;
+
 pub mod archive;
+pub mod list;
 
 // Re-export domain types for convenience
-pub use hallucinator_core::{ExtractionResult, Reference, SkipStats};
+pub use hallucinator_core::{DEFAULT_EXTRACTION_TIMEOUT, ExtractionResult, Reference, SkipStats};
 // Re-export archive API
-pub use archive::{ArchiveItem, ExtractedPdf, extract_archive_streaming, is_archive_path};
+pub use archive::{
+    ArchiveItem, ArchiveOptions, ExtractedPdf, extract_archive_streaming,
+    extract_archive_streaming_with_options, is_archive_path,
+};
+pub use list::{InputList, read_input_list};
 
 #[derive(Error, Debug)]
 pub enum IngestError {
     #[error("PDF extraction error: {0}")]
     Pdf(#[from] hallucinator_parsing::ParsingError),
+    /// The PDF was read, but no references could be located in it.
+    #[error("no references found: {0}")]
+    NoReferences(hallucinator_parsing::SectionMissing),
+    /// The PDF is encrypted and no working password was available.
+    #[error("{0}: PDF is encrypted/password-protected")]
+    Encrypted(String),
     #[error("BBL/BIB extraction error: {0}")]
     Bbl(#[from] hallucinator_bbl::BblError),
-    #[cfg(not(feature = "pdf"))]
-    #[error("PDF support not compiled in (enable the `pdf` feature of hallucinator-ingest)")]
+    /// A `.gz` input could not be decompressed.
+    #[error("failed to decompress {path}: {source}")]
+    Gzip {
+        path: String,
+        source: std::io::Error,
+    },
+    #[cfg(not(any(feature = "pdf", feature = "pdf-extract")))]
+    #[error(
+        "PDF support not compiled in (enable the `pdf` or `pdf-extract` feature of hallucinator-ingest)"
+    )]
     NoPdfSupport,
 }
 
@@ -25,28 +56,248 @@ pub enum IngestError {
 /// Dispatches to the appropriate parser based on file extension:
 /// - `.bbl` → BBL parser
 /// - `.bib` → BibTeX parser
-/// - anything else → PDF parser (requires `pdf` feature / mupdf)
+/// - anything else → PDF parser (requires the `pdf` feature for MuPDF, or
+///   `pdf-extract` for the non-AGPL backend; MuPDF wins if both are on)
+///
+/// A `.gz` wrapper (`paper.bib.gz`, `paper.pdf.gz`) is decompressed to a
+/// temporary file and dispatched on the inner extension.
+///
+/// PDF text extraction is abandoned after [`DEFAULT_EXTRACTION_TIMEOUT`]; see
+/// [`extract_references_with_timeout`].
 pub fn extract_references(path: &Path) -> Result<ExtractionResult, IngestError> {
-    let ext = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
+    extract_references_with_timeout(path, Some(DEFAULT_EXTRACTION_TIMEOUT))
+}
+
+/// Like [`extract_references`], but PDF text extraction fails with
+/// [`BackendError::Timeout`](hallucinator_core::BackendError::Timeout) once it
+/// runs past `timeout`, so one malformed PDF can't stall a batch. `None` waits
+/// indefinitely.
+pub fn extract_references_with_timeout(
+    path: &Path,
+    timeout: Option<Duration>,
+) -> Result<ExtractionResult, IngestError> {
+    let options = ExtractOptions {
+        timeout,
+        ..ExtractOptions::default()
+    };
+    extract_references_with_options(path, &options)
+}
+
+/// Options for [`extract_references_with_options`].
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    /// Deadline for PDF text extraction; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+    /// Fill [`Reference::cited_in_text`] from the citations in a PDF's body.
+    /// BBL and BIB files have no body, so their references stay unchecked.
+    pub check_in_text_citations: bool,
+    /// Read two-up reference grids within a PDF text block column by column
+    /// (MuPDF only; the `pdf-extract` backend ignores it).
+    pub pdf_column_detection: bool,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Some(DEFAULT_EXTRACTION_TIMEOUT),
+            check_in_text_citations: false,
+            pdf_column_detection: false,
+        }
+    }
+}
+
+impl From<&hallucinator_core::Config> for ExtractOptions {
+    fn from(config: &hallucinator_core::Config) -> Self {
+        Self {
+            timeout: config.extraction_timeout,
+            check_in_text_citations: config.check_in_text_citations,
+            pdf_column_detection: config.pdf_column_detection,
+        }
+    }
+}
+
+/// Like [`extract_references`], with full [`ExtractOptions`].
+pub fn extract_references_with_options(
+    path: &Path,
+    options: &ExtractOptions,
+) -> Result<ExtractionResult, IngestError> {
+    let ext = lowercase_extension(path);
+    if ext == "gz" {
+        return extract_gzipped(path, options);
+    }
 
     match ext.as_str() {
         "bbl" => hallucinator_bbl::extract_references_from_bbl(path).map_err(IngestError::Bbl),
         "bib" => hallucinator_bbl::extract_references_from_bib(path).map_err(IngestError::Bbl),
-        _ => extract_pdf(path),
+        _ => extract_pdf(path, options),
+    }
+}
+
+fn lowercase_extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Decompress a `.gz` input to a temporary file (removed on return) and
+/// extract from it by the inner extension.
+fn extract_gzipped(path: &Path, options: &ExtractOptions) -> Result<ExtractionResult, IngestError> {
+    let temp = decompress_gzipped(path)?;
+    extract_references_with_options(temp.path(), options)
+}
+
+/// For a `.gz` input (`paper.bib.gz`), a decompressed temporary copy named
+/// with the inner extension, deleted when dropped; `None` for any other
+/// input. Lets callers that read a file themselves, rather than through
+/// [`extract_references`], accept the same inputs.
+pub fn decompress_if_gzipped(path: &Path) -> Result<Option<NamedTempFile>, IngestError> {
+    if lowercase_extension(path) == "gz" {
+        decompress_gzipped(path).map(Some)
+    } else {
+        Ok(None)
     }
 }
 
+fn decompress_gzipped(path: &Path) -> Result<NamedTempFile, IngestError> {
+    let gzip_error = |source| IngestError::Gzip {
+        path: path.display().to_string(),
+        source,
+    };
+    let inner_ext = path
+        .file_stem()
+        .map(|stem| lowercase_extension(Path::new(stem)))
+        .unwrap_or_default();
+
+    let mut decoder = flate2::read::GzDecoder::new(File::open(path).map_err(gzip_error)?);
+    let mut temp = tempfile::Builder::new()
+        .prefix("hallucinator-")
+        .suffix(&format!(".{}", inner_ext))
+        .tempfile()
+        .map_err(gzip_error)?;
+    std::io::copy(&mut decoder, &mut temp).map_err(gzip_error)?;
+    temp.flush().map_err(gzip_error)?;
+    Ok(temp)
+}
+
+/// The title of the document at `path`, for labelling it in reports instead
+/// of its filename. Only PDFs read with the MuPDF backend have one; `None`
+/// for other inputs or when no plausible title is found.
+pub fn extract_document_title(path: &Path) -> Option<String> {
+    if is_titled(path) {
+        pdf_document_title(path)
+    } else {
+        None
+    }
+}
+
+/// Whether [`extract_document_title`] reads `path` at all: only PDFs carry
+/// a title.
+fn is_titled(path: &Path) -> bool {
+    !matches!(lowercase_extension(path).as_str(), "bbl" | "bib" | "gz")
+}
+
+/// [`extract_references_with_options`] and [`extract_document_title`] under
+/// one deadline: reading the title only gets whatever is left of
+/// `options.timeout` after the references were extracted, and gives up with
+/// `None` once it runs out, so a PDF that hangs can't stall a batch here
+/// either.
+pub fn extract_references_and_title(
+    path: &Path,
+    options: &ExtractOptions,
+) -> Result<(ExtractionResult, Option<String>), IngestError> {
+    let started = Instant::now();
+    let extraction = extract_references_with_options(path, options)?;
+    let title = match options.timeout {
+        Some(timeout) => document_title_within(path, timeout.saturating_sub(started.elapsed())),
+        None => extract_document_title(path),
+    };
+    Ok((extraction, title))
+}
+
+/// Like [`extract_document_title`], but `None` if it takes longer than
+/// `timeout`. The lookup runs on its own thread, which is left to finish in
+/// the background once abandoned.
+fn document_title_within(path: &Path, timeout: Duration) -> Option<String> {
+    if !is_titled(path) {
+        return None;
+    }
+    let (tx, rx) = mpsc::channel();
+    let path = path.to_path_buf();
+    std::thread::Builder::new()
+        .name("pdf-title".into())
+        .spawn(move || {
+            let _ = tx.send(extract_document_title(&path));
+        })
+        .ok()?;
+    rx.recv_timeout(timeout).ok().flatten()
+}
+
 #[cfg(feature = "pdf")]
-fn extract_pdf(path: &Path) -> Result<ExtractionResult, IngestError> {
-    let backend = hallucinator_pdf_mupdf::MupdfBackend;
-    hallucinator_parsing::extract_references(path, &backend).map_err(IngestError::Pdf)
+fn pdf_document_title(path: &Path) -> Option<String> {
+    PdfBackendImpl::default().extract_document_title(path)
 }
 
 #[cfg(not(feature = "pdf"))]
-fn extract_pdf(_path: &Path) -> Result<ExtractionResult, IngestError> {
+fn pdf_document_title(_path: &Path) -> Option<String> {
+    None
+}
+
+/// Extract references from plain text, such as a few citations pasted on stdin.
+///
+/// A "References" heading is optional: without one the whole text is treated
+/// as the reference list, not just the tail a PDF fallback would use.
+pub fn extract_references_from_text(text: &str) -> Result<ExtractionResult, IngestError> {
+    let config = hallucinator_parsing::ParsingConfigBuilder::new()
+        .fallback_fraction(0.0)
+        .build()
+        .expect("no custom patterns to compile");
+    hallucinator_parsing::ReferenceExtractor::with_config(config)
+        .extract_references_from_text(text)
+        .map_err(from_parsing_error)
+}
+
+fn from_parsing_error(e: hallucinator_parsing::ParsingError) -> IngestError {
+    match e {
+        hallucinator_parsing::ParsingError::NoReferencesSection(cause) => {
+            IngestError::NoReferences(cause)
+        }
+        hallucinator_parsing::ParsingError::Backend(
+            hallucinator_core::BackendError::Encrypted(path),
+        ) => IngestError::Encrypted(path),
+        e => IngestError::Pdf(e),
+    }
+}
+
+#[cfg(any(feature = "pdf", feature = "pdf-extract"))]
+fn extract_pdf(path: &Path, options: &ExtractOptions) -> Result<ExtractionResult, IngestError> {
+    let config = hallucinator_parsing::ParsingConfigBuilder::new()
+        .check_in_text_citations(options.check_in_text_citations)
+        .build()
+        .expect("no custom patterns to compile");
+    let extractor = hallucinator_parsing::ReferenceExtractor::with_config(config);
+    let backend = pdf_backend(options);
+    let result = match options.timeout {
+        Some(timeout) => extractor.extract_references_via_backend(
+            path,
+            &hallucinator_parsing::TimeoutBackend::new(backend, timeout),
+        ),
+        None => extractor.extract_references_via_backend(path, &backend),
+    };
+    result.map_err(from_parsing_error)
+}
+
+#[cfg(feature = "pdf")]
+fn pdf_backend(options: &ExtractOptions) -> PdfBackendImpl {
+    PdfBackendImpl::default().column_detection(options.pdf_column_detection)
+}
+
+#[cfg(all(feature = "pdf-extract", not(feature = "pdf")))]
+fn pdf_backend(_options: &ExtractOptions) -> PdfBackendImpl {
+    PdfBackendImpl
+}
+
+#[cfg(not(any(feature = "pdf", feature = "pdf-extract")))]
+fn extract_pdf(_path: &Path, _options: &ExtractOptions) -> Result<ExtractionResult, IngestError> {
     Err(IngestError::NoPdfSupport)
 }
@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use crate::archive::{Dedup, ExtractedPdf, is_archive_path};
+
+/// Files named in an input list, plus a warning for every entry that was skipped.
+pub struct InputList {
+    pub files: Vec<ExtractedPdf>,
+    pub warnings: Vec<String>,
+}
+
+/// Read a newline-separated list of PDF/BBL/BIB paths.
+///
+/// Blank lines and lines starting with `#` are ignored. Relative paths are
+/// taken relative to the current directory, as if typed on the command line.
+/// Entries that don't exist, aren't regular files, or are archives are
+/// skipped with a warning rather than failing the whole list; only an
+/// unreadable list file is an error. Each file's `filename` is the path as
+/// written, since listed files often share a basename.
+///
+/// With `dedup`, an entry whose content is identical to an earlier one (such
+/// as the same file listed twice) is skipped with a warning too.
+pub fn read_input_list(path: &Path, dedup: bool) -> std::io::Result<InputList> {
+    let content = std::fs::read_to_string(path)?;
+    let mut files = Vec::new();
+    let mut warnings = Vec::new();
+    let mut seen = Dedup::new(dedup);
+
+    for (lineno, line) in content.lines().enumerate() {
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+        let file_path = PathBuf::from(entry);
+        let problem = if !file_path.exists() {
+            Some("file not found".to_string())
+        } else if !file_path.is_file() {
+            Some("not a regular file".to_string())
+        } else if is_archive_path(&file_path) {
+            Some("archives can't be listed; check them directly".to_string())
+        } else if dedup {
+            // An unreadable file is left for extraction to report.
+            std::fs::read(&file_path)
+                .ok()
+                .and_then(|data| seen.duplicate_of(&data, &file_path, entry))
+                .map(|original| format!("identical to {}", original))
+        } else {
+            None
+        };
+        match problem {
+            Some(problem) => warnings.push(format!(
+                "{}:{}: skipping {}: {}",
+                path.display(),
+                lineno + 1,
+                entry,
+                problem
+            )),
+            None => files.push(ExtractedPdf {
+                path: file_path,
+                filename: entry.to_string(),
+            }),
+        }
+    }
+
+    Ok(InputList { files, warnings })
+}
@@ -0,0 +1,108 @@
+//! Files with identical content are only extracted once per archive.
+
+use std::io::Write;
+use std::path::Path;
+
+use hallucinator_ingest::archive::{
+    ArchiveItem, ArchiveOptions, extract_archive, extract_archive_streaming,
+    extract_archive_with_options,
+};
+
+const PAPER: &[u8] = b"%PDF-1.4\n% paper\n%%EOF\n";
+const OTHER: &[u8] = b"%PDF-1.4\n% another paper\n%%EOF\n";
+
+fn write_zip(path: &Path, files: &[(&str, &[u8])]) {
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for (name, data) in files {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(data).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+fn write_tar_gz(path: &Path, files: &[(&str, &[u8])]) {
+    let gz = flate2::write::GzEncoder::new(
+        std::fs::File::create(path).unwrap(),
+        flate2::Compression::default(),
+    );
+    let mut tar = tar::Builder::new(gz);
+    for (name, data) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, name, *data).unwrap();
+    }
+    tar.into_inner().unwrap().finish().unwrap();
+}
+
+const FILES: &[(&str, &[u8])] = &[
+    ("paper.pdf", PAPER),
+    ("paper (1).pdf", PAPER),
+    ("other.pdf", OTHER),
+];
+
+#[test]
+fn identical_files_are_extracted_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive = dir.path().join("batch.zip");
+    write_zip(&archive, FILES);
+    let out = tempfile::tempdir().unwrap();
+
+    let result =
+        extract_archive_with_options(&archive, out.path(), &ArchiveOptions::default()).unwrap();
+    let names: Vec<&str> = result.pdfs.iter().map(|p| p.filename.as_str()).collect();
+    assert_eq!(names, ["paper.pdf", "other.pdf"]);
+    assert_eq!(
+        result.warnings,
+        ["Skipping paper (1).pdf: identical to paper.pdf"]
+    );
+}
+
+#[test]
+fn dedup_can_be_disabled() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive = dir.path().join("batch.zip");
+    write_zip(&archive, FILES);
+    let out = tempfile::tempdir().unwrap();
+
+    let options = ArchiveOptions {
+        dedup: false,
+        ..ArchiveOptions::default()
+    };
+    let result = extract_archive_with_options(&archive, out.path(), &options).unwrap();
+    assert_eq!(result.pdfs.len(), 3);
+    assert!(result.warnings.is_empty());
+
+    // The plain API keeps extracting every file.
+    let out = tempfile::tempdir().unwrap();
+    let result = extract_archive(&archive, out.path(), 0).unwrap();
+    assert_eq!(result.pdfs.len(), 3);
+}
+
+#[test]
+fn streaming_skips_identical_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let archive = dir.path().join("batch.tar.gz");
+    write_tar_gz(&archive, FILES);
+    let out = tempfile::tempdir().unwrap();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    extract_archive_streaming(&archive, out.path(), 0, &tx).unwrap();
+    drop(tx);
+
+    let mut processed = Vec::new();
+    let mut warnings = Vec::new();
+    let mut done = None;
+    for item in rx {
+        match item {
+            ArchiveItem::Pdf(pdf) => processed.push(pdf.filename),
+            ArchiveItem::Warning(msg) => warnings.push(msg),
+            ArchiveItem::Done { total } => done = Some(total),
+        }
+    }
+    assert_eq!(processed, ["paper.pdf", "other.pdf"]);
+    assert_eq!(warnings, ["Skipping paper (1).pdf: identical to paper.pdf"]);
+    assert_eq!(done, Some(2));
+}
@@ -0,0 +1,171 @@
+//! Extraction accuracy guardrail.
+//!
+//! Runs the full ingest pipeline over labeled fixture sets and fails if
+//! reference recall or precision drops below the set's thresholds. Each set is
+//! a directory with a `manifest.json`:
+//!
+//! ```json
+//! {
+//!   "min_recall": 0.9,
+//!   "min_precision": 0.9,
+//!   "documents": [
+//!     { "file": "paper.pdf", "expected_count": 42, "titles": ["Known title", "..."] }
+//!   ]
+//! }
+//! ```
+//!
+//! - **Recall**: fraction of the listed `titles` found among the extracted
+//!   references (fuzzy title match, skipped references included).
+//! - **Precision**: of all extracted reference segments, the fraction that can
+//!   be real, i.e. `min(extracted, expected_count) / extracted` summed over
+//!   documents. Over-segmentation lowers it; under-segmentation shows up as
+//!   missed titles.
+//!
+//! Two sets are checked:
+//! - `tests/fixtures/accuracy/` — small .bbl/.bib set, always present.
+//! - `test-data/extraction-accuracy/` at the repo root — PDFs, which are too
+//!   large to commit; skipped when absent.
+//!
+//! Run with:
+//!   cargo test -p hallucinator-ingest --test extraction_accuracy -- --nocapture
+
+use std::path::{Path, PathBuf};
+
+use hallucinator_core::matching::titles_match;
+use serde_json::Value;
+
+struct DocScore {
+    file: String,
+    expected: usize,
+    extracted: usize,
+    titles_found: usize,
+    titles_total: usize,
+    error: Option<String>,
+}
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/accuracy")
+}
+
+fn test_data_dir() -> PathBuf {
+    // hallucinator-rs/crates/hallucinator-ingest -> ../../.. -> repo root
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(3)
+        .unwrap()
+        .join("test-data")
+        .join("extraction-accuracy")
+}
+
+fn score_document(dir: &Path, doc: &Value) -> DocScore {
+    let file = doc["file"].as_str().expect("document needs a \"file\"");
+    let expected = doc["expected_count"]
+        .as_u64()
+        .expect("document needs an \"expected_count\"") as usize;
+    let titles: Vec<&str> = doc["titles"]
+        .as_array()
+        .map(|a| a.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut score = DocScore {
+        file: file.to_string(),
+        expected,
+        extracted: 0,
+        titles_found: 0,
+        titles_total: titles.len(),
+        error: None,
+    };
+    match hallucinator_ingest::extract_references(&dir.join(file)) {
+        Ok(result) => {
+            score.extracted = result.skip_stats.total_raw;
+            score.titles_found = titles
+                .iter()
+                .filter(|known| {
+                    result
+                        .references
+                        .iter()
+                        .filter_map(|r| r.title.as_deref())
+                        .any(|t| titles_match(known, t))
+                })
+                .count();
+        }
+        Err(e) => score.error = Some(e.to_string()),
+    }
+    score
+}
+
+/// Score every document in `dir`'s manifest and assert the thresholds.
+fn check_fixture_set(dir: &Path) {
+    let manifest_path = dir.join("manifest.json");
+    let manifest: Value = serde_json::from_str(
+        &std::fs::read_to_string(&manifest_path)
+            .unwrap_or_else(|e| panic!("cannot read {}: {e}", manifest_path.display())),
+    )
+    .unwrap_or_else(|e| panic!("invalid {}: {e}", manifest_path.display()));
+    let min_recall = manifest["min_recall"].as_f64().unwrap_or(0.9);
+    let min_precision = manifest["min_precision"].as_f64().unwrap_or(0.9);
+    let docs = manifest["documents"]
+        .as_array()
+        .expect("manifest needs a \"documents\" array");
+
+    let scores: Vec<DocScore> = docs.iter().map(|d| score_document(dir, d)).collect();
+
+    println!("{}", dir.display());
+    println!(
+        "  {:<40} {:>8} {:>9} {:>7}",
+        "file", "expected", "extracted", "titles"
+    );
+    for s in &scores {
+        match &s.error {
+            Some(e) => println!("  {:<40} ERROR: {e}", s.file),
+            None => println!(
+                "  {:<40} {:>8} {:>9} {:>4}/{}",
+                s.file, s.expected, s.extracted, s.titles_found, s.titles_total
+            ),
+        }
+    }
+
+    let titles_total: usize = scores.iter().map(|s| s.titles_total).sum();
+    let titles_found: usize = scores.iter().map(|s| s.titles_found).sum();
+    let extracted: usize = scores.iter().map(|s| s.extracted).sum();
+    let plausible: usize = scores.iter().map(|s| s.extracted.min(s.expected)).sum();
+    let recall = titles_found as f64 / titles_total.max(1) as f64;
+    // A document that failed to extract counts as zero precision, not as absent
+    let precision = if extracted == 0 {
+        0.0
+    } else {
+        plausible as f64 / extracted as f64
+    };
+    println!(
+        "  recall {recall:.3} (min {min_recall}), precision {precision:.3} (min {min_precision})"
+    );
+
+    assert!(
+        recall >= min_recall,
+        "recall {recall:.3} fell below {min_recall} for {}",
+        dir.display()
+    );
+    assert!(
+        precision >= min_precision,
+        "precision {precision:.3} fell below {min_precision} for {}",
+        dir.display()
+    );
+}
+
+#[test]
+fn bundled_fixtures_meet_accuracy_thresholds() {
+    check_fixture_set(&fixture_dir());
+}
+
+#[test]
+fn test_data_meets_accuracy_thresholds() {
+    let dir = test_data_dir();
+    if !dir.join("manifest.json").exists() {
+        eprintln!(
+            "Skipping: no manifest.json in test-data directory {}",
+            dir.display()
+        );
+        return;
+    }
+    check_fixture_set(&dir);
+}
@@ -0,0 +1,39 @@
+//! `.gz`-wrapped inputs are decompressed and dispatched on the inner extension.
+
+use std::io::Write;
+use std::path::Path;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use hallucinator_ingest::{decompress_if_gzipped, extract_references};
+
+fn titles(path: &Path) -> Vec<String> {
+    extract_references(path)
+        .unwrap()
+        .references
+        .into_iter()
+        .filter_map(|r| r.title)
+        .collect()
+}
+
+#[test]
+fn gzipped_bib_extracts_like_plain_bib() {
+    let plain = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/accuracy/sample.bib");
+    let dir = tempfile::tempdir().unwrap();
+    let gzipped = dir.path().join("sample.BIB.gz");
+    let mut encoder = GzEncoder::new(
+        std::fs::File::create(&gzipped).unwrap(),
+        Compression::default(),
+    );
+    encoder.write_all(&std::fs::read(&plain).unwrap()).unwrap();
+    encoder.finish().unwrap();
+
+    let expected = titles(&plain);
+    assert!(expected.contains(&"Attention Is All You Need".to_string()));
+    assert_eq!(titles(&gzipped), expected);
+
+    let decompressed = decompress_if_gzipped(&gzipped).unwrap().unwrap();
+    assert!(decompressed.path().to_string_lossy().ends_with(".bib"));
+    assert_eq!(titles(decompressed.path()), expected);
+    assert!(decompress_if_gzipped(&plain).unwrap().is_none());
+}
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use hallucinator_ingest::{extract_references, read_input_list};
+
+fn write_bib(path: &Path, title: &str) {
+    std::fs::write(
+        path,
+        format!(
+            "@article{{key,\n  title = {{{}}},\n  author = {{Jane Doe and John Smith}},\n  year = {{2021}},\n}}\n",
+            title
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn input_list_skips_missing_and_keeps_listed_fixtures() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("a")).unwrap();
+    std::fs::create_dir(dir.path().join("b")).unwrap();
+    let first = dir.path().join("a/refs.bib");
+    let second = dir.path().join("b/refs.bib");
+    write_bib(&first, "A Survey of Entirely Real Results");
+    write_bib(&second, "Deep Learning for Plausible Citations");
+    let archive = dir.path().join("bundle.zip");
+    std::fs::write(&archive, b"PK").unwrap();
+    let missing = dir.path().join("gone.pdf");
+
+    let list = dir.path().join("inputs.txt");
+    std::fs::write(
+        &list,
+        format!(
+            "# papers to check\n{}\n\n  {}  \n{}\n{}\n{}\n",
+            first.display(),
+            second.display(),
+            missing.display(),
+            archive.display(),
+            dir.path().join("a").display(),
+        ),
+    )
+    .unwrap();
+
+    let listed = read_input_list(&list, true).unwrap();
+    let paths: Vec<_> = listed.files.iter().map(|f| f.path.clone()).collect();
+    assert_eq!(paths, vec![first.clone(), second.clone()]);
+    // Same basename in both directories: filenames keep the full path.
+    assert_ne!(listed.files[0].filename, listed.files[1].filename);
+
+    assert_eq!(listed.warnings.len(), 3);
+    assert!(listed.warnings[0].contains("file not found"));
+    assert!(listed.warnings[0].contains(":5:"));
+    assert!(listed.warnings[1].contains("archives"));
+    assert!(listed.warnings[2].contains("not a regular file"));
+
+    for file in &listed.files {
+        let extraction = extract_references(&file.path).unwrap();
+        assert_eq!(extraction.references.len(), 1);
+    }
+}
+
+#[test]
+fn unreadable_input_list_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(read_input_list(&dir.path().join("missing.txt"), true).is_err());
+}
+
+#[test]
+fn input_list_skips_duplicate_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("paper.bib");
+    let copy = dir.path().join("paper (1).bib");
+    write_bib(&first, "A Survey of Entirely Real Results");
+    std::fs::copy(&first, &copy).unwrap();
+
+    let list = dir.path().join("inputs.txt");
+    std::fs::write(
+        &list,
+        format!(
+            "{}\n{}\n{}\n",
+            first.display(),
+            copy.display(),
+            first.display()
+        ),
+    )
+    .unwrap();
+
+    let listed = read_input_list(&list, true).unwrap();
+    let paths: Vec<_> = listed.files.iter().map(|f| f.path.clone()).collect();
+    assert_eq!(paths, vec![first.clone()]);
+    assert_eq!(listed.warnings.len(), 2);
+    assert!(listed.warnings[0].contains(":2:"));
+    assert!(listed.warnings[0].contains("identical to"));
+
+    let listed = read_input_list(&list, false).unwrap();
+    assert_eq!(listed.files.len(), 3);
+    assert!(listed.warnings.is_empty());
+}
@@ -0,0 +1,38 @@
+//! Plain-text input, as read by `hallucinator-cli check -` from stdin.
+
+use hallucinator_ingest::{IngestError, extract_references_from_text};
+
+#[test]
+fn pasted_references_without_heading() {
+    // Short pastes have too few "[n]" labels to segment on, so references
+    // are separated by blank lines
+    let text = "\
+A. Vaswani, N. Shazeer, N. Parmar, J. Uszkoreit, L. Jones, A. N. Gomez, L. Kaiser, and I. Polosukhin, \"Attention is all you need,\" in Advances in Neural Information Processing Systems, 2017.
+
+K. He, X. Zhang, S. Ren, and J. Sun, \"Deep residual learning for image recognition,\"
+in Proc. IEEE CVPR, 2016, pp. 770-778.
+";
+    let result = extract_references_from_text(text).unwrap();
+    let titles: Vec<_> = result
+        .references
+        .iter()
+        .map(|r| r.title.as_deref().unwrap_or(""))
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            "Attention is all you need",
+            "Deep residual learning for image recognition"
+        ]
+    );
+    assert_eq!(result.references[1].authors.len(), 4);
+}
+
+#[test]
+fn prose_is_not_a_reference_list() {
+    let text = "Just some notes about the paper.\nNothing here looks like a citation at all.\n";
+    assert!(matches!(
+        extract_references_from_text(text),
+        Err(IngestError::NoReferences(_))
+    ));
+}
@@ -16,17 +16,9 @@ use tantivy::schema::*;
 use tantivy::{Index, IndexWriter};
 
 use crate::metadata::{self, IndexMetadata};
+use crate::query::{self, NGRAM_TOKENIZER, PRIMARY_TYPES, TITLE_NGRAM_FIELD, WORK_TYPE_FIELD};
 use crate::s3;
-use crate::{BuildProgress, OpenAlexError};
-
-/// Work types we index (skip datasets, components, etc.).
-const ALLOWED_TYPES: &[&str] = &[
-    "article",
-    "book-chapter",
-    "preprint",
-    "review",
-    "dissertation",
-];
+use crate::{BuildOptions, BuildProgress, OpenAlexError, RecordContext};
 
 /// Number of files to download and parse concurrently.
 const DOWNLOAD_CONCURRENCY: usize = 8;
@@ -34,12 +26,30 @@ const DOWNLOAD_CONCURRENCY: usize = 8;
 /// Number of retry attempts per file before skipping.
 const MAX_RETRIES: u32 = 3;
 
+/// Largest fraction of a file's records that may be malformed JSON. Below
+/// it the bad records are logged and skipped; above it the file is treated
+/// as corrupt, and retried or skipped as a whole.
+const MAX_MALFORMED_FRACTION: f64 = 0.1;
+
+/// Writer heap used for the post-build segment merge. The merge itself
+/// streams segment data, so this only needs to satisfy Tantivy's minimum.
+const MERGE_WRITER_HEAP: usize = 50_000_000;
+
+/// One parsed OpenAlex work, ready for indexing.
+#[derive(Debug, PartialEq)]
+struct Work {
+    openalex_id: u64,
+    title: String,
+    authors: Vec<String>,
+    work_type: String,
+}
+
 /// Result from downloading and parsing a single gz file.
 enum FileResult {
     Ok {
         partition_date: String,
         filename: String,
-        records: Vec<(u64, String, Vec<String>)>,
+        records: Vec<Work>,
     },
     /// All retries exhausted — skip this file.
     Failed { filename: String, error: String },
@@ -61,14 +71,18 @@ fn short_filename(key: &str) -> String {
 /// Returns `true` if new data was indexed, `false` if already up to date.
 pub async fn build(
     db_path: &Path,
-    since_override: Option<String>,
-    min_year: Option<u32>,
+    options: BuildOptions,
     mut progress: impl FnMut(BuildProgress),
 ) -> Result<bool, OpenAlexError> {
-    let client = reqwest::Client::builder()
-        .user_agent("hallucinator/openalex-offline (https://github.com/gianlucasb/hallucinator)")
-        .build()
-        .map_err(|e| OpenAlexError::Download(e.to_string()))?;
+    let client = http_client(&options)?;
+    let BuildOptions {
+        since: since_override,
+        min_year,
+        title_ngrams,
+        secondary_types,
+        client: _,
+    } = options;
+    let secondary_types = Arc::new(secondary_types);
 
     // Read existing metadata for incremental updates
     let existing_meta = if db_path.exists() {
@@ -155,16 +169,26 @@ pub async fn build(
     // Step 3: Open or create Tantivy index
     std::fs::create_dir_all(db_path)?;
 
-    let (index, schema) = open_or_create_index(db_path)?;
+    let (index, schema) = open_or_create_index(db_path, title_ngrams)?;
     let title_field = schema
         .get_field("title")
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
+    // Only present when the index was created with `title_ngrams`
+    let ngram_field = schema.get_field(TITLE_NGRAM_FIELD).ok();
     let authors_field = schema
         .get_field("authors")
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
     let id_field = schema
         .get_field("openalex_id")
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
+    // Absent from indexes created before works were tagged by type
+    let type_field = schema.get_field(WORK_TYPE_FIELD).ok();
+    if type_field.is_none() && !secondary_types.is_empty() {
+        return Err(OpenAlexError::Index(format!(
+            "{} has no work type tags; rebuild it from scratch to index secondary types",
+            db_path.display()
+        )));
+    }
 
     let writer: IndexWriter = index
         .writer(256_000_000) // 256MB heap
@@ -180,24 +204,37 @@ pub async fn build(
     // Spawn dedicated indexer task so Tantivy writes don't stall the
     // download futures (FuturesUnordered only polls children when the
     // main select! loop is free).
-    let (index_tx, index_rx) =
-        tokio::sync::mpsc::channel::<Vec<(u64, String, Vec<String>)>>(DOWNLOAD_CONCURRENCY * 2);
+    let (index_tx, index_rx) = tokio::sync::mpsc::channel::<Vec<Work>>(DOWNLOAD_CONCURRENCY * 2);
     let indexer_records = records_indexed.clone();
     let index_handle = tokio::task::spawn_blocking(move || -> Result<(), OpenAlexError> {
         let mut index_rx = index_rx;
         let mut writer = writer;
         let mut uncommitted: u64 = 0;
         while let Some(batch) = index_rx.blocking_recv() {
-            for (openalex_id, title, authors) in batch {
+            for Work {
+                openalex_id,
+                title,
+                authors,
+                work_type,
+            } in batch
+            {
                 let id_term = tantivy::Term::from_field_u64(id_field, openalex_id);
                 writer.delete_term(id_term);
                 let authors_str = authors.join("|");
+                let ngram_text = ngram_field.map(|_| query::normalize_title(&title));
+                let mut document = doc!(
+                    title_field => title,
+                    authors_field => authors_str,
+                    id_field => openalex_id,
+                );
+                if let (Some(field), Some(text)) = (ngram_field, ngram_text) {
+                    document.add_text(field, text);
+                }
+                if let Some(field) = type_field {
+                    document.add_text(field, work_type);
+                }
                 writer
-                    .add_document(doc!(
-                        title_field => title,
-                        authors_field => authors_str,
-                        id_field => openalex_id,
-                    ))
+                    .add_document(document)
                     .map_err(|e| OpenAlexError::Index(e.to_string()))?;
                 uncommitted += 1;
                 indexer_records.fetch_add(1, Ordering::Relaxed);
@@ -240,6 +277,7 @@ pub async fn build(
                 file.key,
                 partition_date,
                 min_year,
+                secondary_types.clone(),
                 live_bytes.clone(),
                 file_bytes,
             ));
@@ -302,6 +340,7 @@ pub async fn build(
                         file.key,
                         partition_date,
                         min_year,
+                        secondary_types.clone(),
                         live_bytes.clone(),
                         file_bytes,
                     ));
@@ -333,16 +372,29 @@ pub async fn build(
         }
     }
 
-    // Step 6: Signal indexer to finish, then wait for commit + merge
+    // Step 6: Signal indexer to finish, then wait for the final commit
     drop(index_tx);
     progress(BuildProgress::Committing {
         records_indexed: records_indexed.load(Ordering::Relaxed),
     });
-    progress(BuildProgress::Merging);
     index_handle
         .await
         .map_err(|e| OpenAlexError::Index(e.to_string()))??;
 
+    // Step 6b: Force-merge the segments left behind by the periodic commits
+    // (and by earlier incremental updates) so queries stay fast.
+    let segments_before = index.searchable_segment_ids()?.len();
+    progress(BuildProgress::Merging { segments_before });
+    let merge_index = index.clone();
+    let (segments_before, segments_after) =
+        tokio::task::spawn_blocking(move || optimize_index(&merge_index))
+            .await
+            .map_err(|e| OpenAlexError::Index(e.to_string()))??;
+    progress(BuildProgress::Merged {
+        segments_before,
+        segments_after,
+    });
+
     let total_records = records_indexed.load(Ordering::Relaxed);
 
     // Step 7: Write updated metadata
@@ -351,8 +403,18 @@ pub async fn build(
         .unwrap_or_default()
         .as_secs();
 
-    let total_in_index =
-        existing_meta.and_then(|m| m.publication_count).unwrap_or(0) + total_records;
+    let total_in_index = existing_meta
+        .as_ref()
+        .and_then(|m| m.publication_count)
+        .unwrap_or(0)
+        + total_records;
+    // Works of types indexed by earlier builds stay in the index.
+    let mut indexed_types = existing_meta.map(|m| m.secondary_types).unwrap_or_default();
+    for t in secondary_types.iter() {
+        if !indexed_types.contains(t) {
+            indexed_types.push(t.clone());
+        }
+    }
 
     metadata::write_metadata(
         db_path,
@@ -361,6 +423,7 @@ pub async fn build(
             build_date: Some(now.to_string()),
             publication_count: Some(total_in_index),
             last_sync_date: Some(newest_date),
+            secondary_types: indexed_types,
         },
     )?;
 
@@ -373,6 +436,28 @@ pub async fn build(
     Ok(true)
 }
 
+/// The client for builds and remote checks: [`BuildOptions::client`], or
+/// the shared default.
+fn http_client(options: &BuildOptions) -> Result<reqwest::Client, OpenAlexError> {
+    match &options.client {
+        Some(client) => Ok(client.clone()),
+        None => hallucinator_text::download::default_client()
+            .map_err(|e| OpenAlexError::Download(e.to_string())),
+    }
+}
+
+/// Ask whether S3 holds a date partition newer than `last_sync_date`, the
+/// newest one already indexed. With no sync date every partition is new.
+pub(crate) async fn remote_has_update(
+    options: &BuildOptions,
+    last_sync_date: Option<&str>,
+) -> Result<bool, OpenAlexError> {
+    let partitions = s3::list_date_partitions(&http_client(options)?).await?;
+    Ok(partitions
+        .iter()
+        .any(|p| last_sync_date.is_none_or(|since| p.date.as_str() > since)))
+}
+
 /// Create a boxed future that downloads and parses one S3 file.
 ///
 /// Retries up to [`MAX_RETRIES`] times with exponential backoff. If all
@@ -383,6 +468,7 @@ fn make_download_future(
     key: String,
     partition_date: String,
     min_year: Option<u32>,
+    secondary_types: Arc<Vec<String>>,
     total_bytes: Arc<AtomicU64>,
     file_bytes: Arc<AtomicU64>,
 ) -> Pin<Box<dyn Future<Output = FileResult> + Send>> {
@@ -396,7 +482,16 @@ fn make_download_future(
                 let backoff = Duration::from_secs(2u64.pow(attempt));
                 tokio::time::sleep(backoff).await;
             }
-            match download_and_parse(&client, &key, min_year, &total_bytes, &file_bytes).await {
+            match download_and_parse(
+                &client,
+                &key,
+                min_year,
+                &secondary_types,
+                &total_bytes,
+                &file_bytes,
+            )
+            .await
+            {
                 Ok(records) => {
                     return FileResult::Ok {
                         partition_date,
@@ -425,9 +520,10 @@ async fn download_and_parse(
     client: &reqwest::Client,
     key: &str,
     min_year: Option<u32>,
+    secondary_types: &[String],
     total_bytes: &AtomicU64,
     file_bytes: &AtomicU64,
-) -> Result<Vec<(u64, String, Vec<String>)>, OpenAlexError> {
+) -> Result<Vec<Work>, OpenAlexError> {
     let url = format!("{}/{}", s3::BUCKET_URL, key);
     let resp = client
         .get(&url)
@@ -454,12 +550,94 @@ async fn download_and_parse(
         gz_bytes.extend_from_slice(&chunk);
     }
 
-    // Decompress and parse JSON lines
-    let decoder = GzDecoder::new(gz_bytes.as_slice());
-    let buf_reader = BufReader::new(decoder);
+    parse_partition(&gz_bytes, &short_filename(key), min_year, secondary_types)
+}
+
+/// Decompress a gzipped partition file and parse its works.
+///
+/// The snapshot itself is JSON lines, but some mirrors and exports ship a
+/// single JSON array instead; the format is picked from the first
+/// non-whitespace byte. A non-empty file that yields no works is logged as a
+/// likely format mismatch.
+fn parse_partition(
+    gz_bytes: &[u8],
+    filename: &str,
+    min_year: Option<u32>,
+    secondary_types: &[String],
+) -> Result<Vec<Work>, OpenAlexError> {
+    let mut reader = BufReader::new(GzDecoder::new(gz_bytes));
+    let (records, counts) = if starts_with_array(&mut reader)? {
+        parse_json_array(reader, filename, min_year, secondary_types)?
+    } else {
+        parse_json_lines(reader, filename, min_year, secondary_types)?
+    };
+    // Works dropped by the year or type filters were still parsed
+    if counts.works == 0 && counts.read > 0 {
+        let total = counts.read;
+        tracing::warn!(
+            filename,
+            total,
+            "none of {total} records is an OpenAlex work; is the file in a supported format?"
+        );
+    }
+    Ok(records)
+}
+
+/// How many records a partition held, and how many of those are OpenAlex
+/// works whether or not the year and type filters keep them.
+#[derive(Debug, Default, Clone, Copy)]
+struct RecordCounts {
+    read: u64,
+    works: u64,
+}
+
+impl RecordCounts {
+    fn count(&mut self, value: &serde_json::Value) {
+        self.read += 1;
+        if value
+            .get("id")
+            .and_then(|id| id.as_str())
+            .and_then(extract_numeric_id)
+            .is_some()
+        {
+            self.works += 1;
+        }
+    }
+}
+
+/// Skip leading whitespace and report whether the content opens a JSON array.
+fn starts_with_array(reader: &mut impl BufRead) -> Result<bool, OpenAlexError> {
+    loop {
+        let buf = reader.fill_buf()?;
+        let Some(&first) = buf.iter().find(|b| !b.is_ascii_whitespace()) else {
+            if buf.is_empty() {
+                return Ok(false);
+            }
+            let len = buf.len();
+            reader.consume(len);
+            continue;
+        };
+        return Ok(first == b'[');
+    }
+}
+
+/// Parse one work per line, returning the works and the records counted.
+///
+/// Malformed records are logged with their position and skipped, unless
+/// more than [`MAX_MALFORMED_FRACTION`] of the file is malformed; the error
+/// then points at the first bad record.
+fn parse_json_lines(
+    reader: impl BufRead,
+    filename: &str,
+    min_year: Option<u32>,
+    secondary_types: &[String],
+) -> Result<(Vec<Work>, RecordCounts), OpenAlexError> {
     let mut records = Vec::new();
+    let mut counts = RecordCounts::default();
+    let mut malformed: u64 = 0;
+    let mut first_malformed: Option<RecordContext> = None;
 
-    for line_result in buf_reader.lines() {
+    for (index, line_result) in reader.lines().enumerate() {
         let line = match line_result {
             Ok(l) => l,
             Err(_) => continue,
@@ -467,48 +645,157 @@ async fn download_and_parse(
         if line.trim().is_empty() {
             continue;
         }
-        if let Some(record) = parse_work_json(&line, min_year) {
-            records.push(record);
+        match serde_json::from_str::<serde_json::Value>(&line) {
+            Ok(value) => {
+                counts.count(&value);
+                records.extend(work_from_json(&value, min_year, secondary_types));
+            }
+            Err(e) => {
+                malformed += 1;
+                let context = RecordContext::new(filename, index as u64 + 1, &line);
+                tracing::warn!(
+                    malformed,
+                    "skipping malformed OpenAlex record ({context}): {e}"
+                );
+                first_malformed.get_or_insert(context);
+            }
         }
     }
 
-    Ok(records)
+    let total = counts.read + malformed;
+    if malformed as f64 > total as f64 * MAX_MALFORMED_FRACTION {
+        return Err(OpenAlexError::Parse {
+            message: format!("{malformed} of {total} records are malformed JSON"),
+            record: first_malformed,
+        });
+    }
+    Ok((records, counts))
+}
+
+/// Parse a JSON array of works element by element, returning the works and
+/// the records counted. Unlike JSON lines, a syntax error anywhere
+/// fails the whole file.
+fn parse_json_array(
+    reader: impl BufRead,
+    filename: &str,
+    min_year: Option<u32>,
+    secondary_types: &[String],
+) -> Result<(Vec<Work>, RecordCounts), OpenAlexError> {
+    struct WorkArray<'a> {
+        min_year: Option<u32>,
+        secondary_types: &'a [String],
+    }
+
+    impl<'de> serde::de::Visitor<'de> for WorkArray<'_> {
+        type Value = (Vec<Work>, RecordCounts);
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("an array of OpenAlex works")
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut records = Vec::new();
+            let mut counts = RecordCounts::default();
+            while let Some(value) = seq.next_element::<serde_json::Value>()? {
+                counts.count(&value);
+                records.extend(work_from_json(&value, self.min_year, self.secondary_types));
+            }
+            Ok((records, counts))
+        }
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let parsed = serde::Deserializer::deserialize_seq(
+        &mut deserializer,
+        WorkArray {
+            min_year,
+            secondary_types,
+        },
+    )
+    .and_then(|parsed| deserializer.end().map(|()| parsed));
+    parsed.map_err(|e| OpenAlexError::Parse {
+        message: format!("{filename}: {e}"),
+        record: None,
+    })
+}
+
+/// Merge every searchable segment of `index` into a single segment.
+///
+/// Returns the segment counts `(before, after)`. A no-op when the index
+/// already has zero or one segment.
+pub(crate) fn optimize_index(index: &Index) -> Result<(usize, usize), OpenAlexError> {
+    let segment_ids = index.searchable_segment_ids()?;
+    let before = segment_ids.len();
+    if before <= 1 {
+        return Ok((before, before));
+    }
+
+    let mut writer: IndexWriter = index
+        .writer(MERGE_WRITER_HEAP)
+        .map_err(|e| OpenAlexError::Index(e.to_string()))?;
+    writer
+        .merge(&segment_ids)
+        .wait()
+        .map_err(|e| OpenAlexError::Index(e.to_string()))?;
+    writer
+        .wait_merging_threads()
+        .map_err(|e| OpenAlexError::Index(e.to_string()))?;
+
+    let after = index.searchable_segment_ids()?.len();
+    Ok((before, after))
 }
 
 /// Open an existing Tantivy index or create a new one with our schema.
-fn open_or_create_index(path: &Path) -> Result<(Index, Schema), OpenAlexError> {
+///
+/// `title_ngrams` only affects newly created indexes; an existing index keeps
+/// the schema it was created with.
+fn open_or_create_index(path: &Path, title_ngrams: bool) -> Result<(Index, Schema), OpenAlexError> {
     // Check if this is already a Tantivy index directory
     let meta_path = path.join("meta.json");
-    if meta_path.exists() {
-        let index = Index::open_in_dir(path)?;
-        let schema = index.schema();
-        return Ok((index, schema));
-    }
-
-    // Create new index with schema
-    let schema = build_schema();
-    let index = Index::create_in_dir(path, schema.clone())?;
+    let index = if meta_path.exists() {
+        Index::open_in_dir(path)?
+    } else {
+        Index::create_in_dir(path, build_schema(title_ngrams))?
+    };
+    query::register_tokenizers(&index)?;
+    let schema = index.schema();
     Ok((index, schema))
 }
 
-fn build_schema() -> Schema {
+pub(crate) fn build_schema(title_ngrams: bool) -> Schema {
     let mut schema_builder = Schema::builder();
     schema_builder.add_text_field("title", TEXT | STORED);
+    if title_ngrams {
+        let indexing = TextFieldIndexing::default()
+            .set_tokenizer(NGRAM_TOKENIZER)
+            .set_index_option(IndexRecordOption::WithFreqs);
+        schema_builder.add_text_field(
+            TITLE_NGRAM_FIELD,
+            TextOptions::default().set_indexing_options(indexing),
+        );
+    }
     schema_builder.add_text_field("authors", STORED);
     schema_builder.add_u64_field("openalex_id", INDEXED | STORED | FAST);
+    schema_builder.add_text_field(WORK_TYPE_FIELD, STRING | STORED);
     schema_builder.build()
 }
 
-/// Parse a single OpenAlex JSON line into (openalex_id, title, authors).
+/// Build a [`Work`] from a parsed OpenAlex record.
 ///
-/// Returns `None` if the work type is not in `ALLOWED_TYPES` or required
-/// fields are missing.
-fn parse_work_json(line: &str, min_year: Option<u32>) -> Option<(u64, String, Vec<String>)> {
-    let value: serde_json::Value = serde_json::from_str(line).ok()?;
-
+/// `None` if the work type is in neither `PRIMARY_TYPES` nor
+/// `secondary_types`, the year is before `min_year`, or required fields are
+/// missing.
+fn work_from_json(
+    value: &serde_json::Value,
+    min_year: Option<u32>,
+    secondary_types: &[String],
+) -> Option<Work> {
     // Filter by type
     let work_type = value.get("type")?.as_str()?;
-    if !ALLOWED_TYPES.contains(&work_type) {
+    if !PRIMARY_TYPES.contains(&work_type) && !secondary_types.iter().any(|t| t == work_type) {
         return None;
     }
 
@@ -546,7 +833,12 @@ fn parse_work_json(line: &str, min_year: Option<u32>) -> Option<(u64, String, Ve
         })
         .unwrap_or_default();
 
-    Some((openalex_id, title.to_string(), authors))
+    Some(Work {
+        openalex_id,
+        title: title.to_string(),
+        authors,
+        work_type: work_type.to_string(),
+    })
 }
 
 /// Extract numeric ID from OpenAlex URL: "https://openalex.org/W1234567" → 1234567
@@ -562,27 +854,186 @@ fn extract_numeric_id(id_str: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    /// Parse a single OpenAlex JSON line into a [`Work`].
+    fn parse_work_json(
+        line: &str,
+        min_year: Option<u32>,
+        secondary_types: &[String],
+    ) -> Result<Option<Work>, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(line)?;
+        Ok(work_from_json(&value, min_year, secondary_types))
+    }
+
     #[test]
     fn test_parse_work_json_article() {
         let json = r#"{"id":"https://openalex.org/W2741809807","display_name":"Attention is All you Need","type":"article","authorships":[{"author":{"display_name":"Ashish Vaswani"}},{"author":{"display_name":"Noam Shazeer"}}]}"#;
-        let result = parse_work_json(json, None);
-        assert!(result.is_some());
-        let (id, title, authors) = result.unwrap();
-        assert_eq!(id, 2741809807);
-        assert_eq!(title, "Attention is All you Need");
-        assert_eq!(authors, vec!["Ashish Vaswani", "Noam Shazeer"]);
+        let work = parse_work_json(json, None, &[]).unwrap().unwrap();
+        assert_eq!(work.openalex_id, 2741809807);
+        assert_eq!(work.title, "Attention is All you Need");
+        assert_eq!(work.authors, vec!["Ashish Vaswani", "Noam Shazeer"]);
+        assert_eq!(work.work_type, "article");
     }
 
     #[test]
     fn test_parse_work_json_filtered_type() {
         let json = r#"{"id":"https://openalex.org/W123","display_name":"Some Dataset","type":"dataset","authorships":[]}"#;
-        assert!(parse_work_json(json, None).is_none());
+        assert!(parse_work_json(json, None, &[]).unwrap().is_none());
+
+        let work = parse_work_json(json, None, &["dataset".to_string()])
+            .unwrap()
+            .unwrap();
+        assert_eq!(work.work_type, "dataset");
     }
 
     #[test]
     fn test_parse_work_json_missing_title() {
         let json = r#"{"id":"https://openalex.org/W123","type":"article","authorships":[]}"#;
-        assert!(parse_work_json(json, None).is_none());
+        assert!(parse_work_json(json, None, &[]).unwrap().is_none());
+    }
+
+    fn gzip_lines(lines: &[String]) -> Vec<u8> {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(lines.join("\n").as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn work_line(id: u64) -> String {
+        format!(
+            r#"{{"id":"https://openalex.org/W{id}","display_name":"Paper {id}","type":"article","authorships":[]}}"#
+        )
+    }
+
+    #[test]
+    fn test_parse_partition_skips_malformed_record() {
+        let mut lines: Vec<String> = (1..=10).map(work_line).collect();
+        lines.insert(
+            4,
+            r#"{"id":"https://openalex.org/W99","display_name":"Trunc"#.into(),
+        );
+        let gz = gzip_lines(&lines);
+
+        let works = parse_partition(&gz, "2026-01-15/part_003.gz", None, &[]).unwrap();
+        let ids: Vec<u64> = works.iter().map(|w| w.openalex_id).collect();
+        assert_eq!(ids, (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parse_partition_fails_when_mostly_malformed() {
+        let garbage = format!("{{\"display_name\":\"{}", "x".repeat(500));
+        let lines = vec![work_line(1), garbage, "not json".to_string()];
+        let gz = gzip_lines(&lines);
+
+        let err = parse_partition(&gz, "2026-01-15/part_003.gz", None, &[]).unwrap_err();
+        let OpenAlexError::Parse { message, record } = &err else {
+            panic!("expected a parse error, got {err:?}");
+        };
+        assert_eq!(message, "2 of 3 records are malformed JSON");
+        let record = record.as_ref().unwrap();
+        assert_eq!(record.filename, "2026-01-15/part_003.gz");
+        assert_eq!(record.line, 2);
+        assert!(record.snippet.starts_with(r#"{"display_name":"xxx"#));
+        assert_eq!(
+            record.snippet.chars().count(),
+            RecordContext::SNIPPET_CHARS + 1
+        );
+        assert!(err.to_string().contains("2026-01-15/part_003.gz line 2"));
+    }
+
+    fn gzip(content: &str) -> Vec<u8> {
+        gzip_lines(&[content.to_string()])
+    }
+
+    #[test]
+    fn test_parse_partition_json_array_matches_json_lines() {
+        let mut lines: Vec<String> = (1..=5).map(work_line).collect();
+        lines.push(
+            r#"{"id":"https://openalex.org/W6","display_name":"Some Dataset","type":"dataset","authorships":[]}"#
+                .into(),
+        );
+        let from_lines = parse_partition(&gzip_lines(&lines), "part_000.gz", None, &[]).unwrap();
+        let array = format!("  \n[\n{}\n]\n", lines.join(",\n"));
+        let from_array = parse_partition(&gzip(&array), "part_000.gz", None, &[]).unwrap();
+
+        assert_eq!(from_lines.len(), 5);
+        assert_eq!(from_array, from_lines);
+    }
+
+    #[test]
+    fn test_parse_partition_empty_array_and_file() {
+        assert!(
+            parse_partition(&gzip("[]"), "part_000.gz", None, &[])
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            parse_partition(&gzip(""), "part_000.gz", None, &[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_filtered_works_still_count_as_parsed() {
+        let dataset =
+            r#"{"id":"https://openalex.org/W6","display_name":"Some Dataset","type":"dataset"}"#;
+        let (works, counts) =
+            parse_json_lines(dataset.as_bytes(), "part_000.gz", None, &[]).unwrap();
+        assert!(works.is_empty());
+        assert_eq!((counts.read, counts.works), (1, 1));
+
+        let other = r#"{"doi":"10.1000/1","name":"Not a work"}"#;
+        let (_, counts) = parse_json_lines(other.as_bytes(), "part_000.gz", None, &[]).unwrap();
+        assert_eq!((counts.read, counts.works), (1, 0));
+    }
+
+    #[test]
+    fn test_parse_partition_truncated_array_fails() {
+        let array = format!("[{},{}", work_line(1), work_line(2));
+        let err = parse_partition(&gzip(&array), "part_000.gz", None, &[]).unwrap_err();
+        assert!(matches!(err, OpenAlexError::Parse { record: None, .. }));
+        assert!(err.to_string().contains("part_000.gz"));
+    }
+
+    #[test]
+    fn test_optimize_reduces_segment_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema = build_schema(false);
+        let index = Index::create_in_dir(dir.path(), schema.clone()).unwrap();
+        let title_field = schema.get_field("title").unwrap();
+        let authors_field = schema.get_field("authors").unwrap();
+        let id_field = schema.get_field("openalex_id").unwrap();
+        let type_field = schema.get_field(WORK_TYPE_FIELD).unwrap();
+
+        // One commit per document with merging disabled leaves one segment each.
+        let mut writer: IndexWriter = index.writer(15_000_000).unwrap();
+        writer.set_merge_policy(Box::new(tantivy::merge_policy::NoMergePolicy));
+        for (id, title) in [
+            (1u64, "Attention is All you Need"),
+            (2u64, "Deep Residual Learning for Image Recognition"),
+            (3u64, "Generative Adversarial Networks"),
+        ] {
+            writer
+                .add_document(doc!(
+                    title_field => title,
+                    authors_field => "",
+                    id_field => id,
+                    type_field => "article",
+                ))
+                .unwrap();
+            writer.commit().unwrap();
+        }
+        writer.wait_merging_threads().unwrap();
+        assert_eq!(index.searchable_segment_ids().unwrap().len(), 3);
+
+        let db = crate::OpenAlexDatabase::open(dir.path()).unwrap();
+        let stats = db.optimize().unwrap();
+        assert_eq!(stats.segments_before, 3);
+        assert_eq!(stats.segments_after, 1);
+
+        // The merged index is still queryable.
+        let result = db.query("Attention is All you Need").unwrap();
+        assert!(result.is_some());
     }
 
     #[test]
@@ -610,7 +1061,7 @@ mod tests {
                 t
             );
             assert!(
-                parse_work_json(&json, None).is_some(),
+                parse_work_json(&json, None, &[]).unwrap().is_some(),
                 "type {} should be allowed",
                 t
             );
@@ -621,7 +1072,7 @@ mod tests {
                 t
             );
             assert!(
-                parse_work_json(&json, None).is_none(),
+                parse_work_json(&json, None, &[]).unwrap().is_none(),
                 "type {} should be filtered",
                 t
             );
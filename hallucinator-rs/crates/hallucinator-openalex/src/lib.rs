@@ -16,7 +16,8 @@ use std::path::{Path, PathBuf};
 use tantivy::Index;
 use thiserror::Error;
 
-pub use query::DEFAULT_THRESHOLD;
+pub use hallucinator_text::TitleScorer;
+pub use query::{DEFAULT_THRESHOLD, QueryGuard};
 
 #[derive(Error, Debug)]
 pub enum OpenAlexError {
@@ -24,8 +25,15 @@ pub enum OpenAlexError {
     Index(String),
     #[error("download error: {0}")]
     Download(String),
-    #[error("parse error: {0}")]
-    Parse(String),
+    #[error(
+        "parse error: {message}{}",
+        .record.as_ref().map(|r| format!(" ({r})")).unwrap_or_default()
+    )]
+    Parse {
+        message: String,
+        /// The snapshot record that failed to parse, if any.
+        record: Option<RecordContext>,
+    },
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -36,8 +44,44 @@ impl From<tantivy::TantivyError> for OpenAlexError {
     }
 }
 
+/// Where in the OpenAlex snapshot a malformed record was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordContext {
+    /// Partition file, e.g. `2026-01-15/part_003.gz`.
+    pub filename: String,
+    /// 1-based line number within the decompressed file.
+    pub line: u64,
+    /// The start of the record, at most [`RecordContext::SNIPPET_CHARS`]
+    /// characters.
+    pub snippet: String,
+}
+
+impl RecordContext {
+    /// Longest record prefix kept in [`snippet`](Self::snippet).
+    pub const SNIPPET_CHARS: usize = 120;
+
+    pub(crate) fn new(filename: &str, line: u64, record: &str) -> Self {
+        let mut snippet: String = record.chars().take(Self::SNIPPET_CHARS).collect();
+        if snippet.len() < record.len() {
+            snippet.push('…');
+        }
+        Self {
+            filename: filename.to_string(),
+            line,
+            snippet,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} line {}: {}", self.filename, self.line, self.snippet)
+    }
+}
+
 /// A publication record from the offline OpenAlex database.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpenAlexRecord {
     pub title: String,
     pub authors: Vec<String>,
@@ -46,6 +90,7 @@ pub struct OpenAlexRecord {
 
 /// Query result with fuzzy match score.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpenAlexQueryResult {
     pub record: OpenAlexRecord,
     pub score: f64,
@@ -93,7 +138,15 @@ pub enum BuildProgress {
         filename: String,
         error: String,
     },
-    Merging,
+    /// Final commit is done; merging the index down to a single segment.
+    Merging {
+        segments_before: usize,
+    },
+    /// Segment merge finished.
+    Merged {
+        segments_before: usize,
+        segments_after: usize,
+    },
     Complete {
         publications: u64,
         skipped: bool,
@@ -101,12 +154,82 @@ pub enum BuildProgress {
     },
 }
 
+/// Options for [`build_database_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    /// Only download S3 partitions newer than this date (YYYY-MM-DD).
+    pub since: Option<String>,
+    /// Skip works published before this year.
+    pub min_year: Option<u32>,
+    /// Index character trigrams of each normalized title alongside the
+    /// word index. Queries whose whole-word lookups find nothing then fall
+    /// back to trigram candidate retrieval, which tolerates word-order,
+    /// plural/singular and hyphenation differences before rapidfuzz
+    /// re-ranking.
+    ///
+    /// Tradeoff: the trigram postings roughly double the on-disk index size
+    /// and lengthen the build. Only takes effect when creating a new index;
+    /// an existing index keeps its schema.
+    pub title_ngrams: bool,
+    /// Work types indexed in addition to the default academic types (e.g.
+    /// `dataset`). They are tagged with their type and kept out of
+    /// [`OpenAlexDatabase::query`]; only [`OpenAlexDatabase::query_type`]
+    /// naming the type matches them, so a dataset can't verify a paper of the
+    /// same name. Needs an index created with type tags (any index created
+    /// since they were introduced).
+    pub secondary_types: Vec<String>,
+    /// HTTP client for the download and the remote update check, e.g. the
+    /// run's client carrying its proxy and CA settings. `None` uses
+    /// [`hallucinator_text::download::default_client`].
+    pub client: Option<reqwest::Client>,
+}
+
+/// Whether `date` is a real calendar date written `YYYY-MM-DD`, the form
+/// [`BuildOptions::since`] expects. Partition dates are compared as strings,
+/// so anything else (e.g. `2024-13-45`) would filter arbitrarily.
+pub fn is_valid_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+    let number = |range: std::ops::Range<usize>| {
+        let digits = &date[range];
+        digits
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| digits.parse::<u32>().ok())
+            .flatten()
+    };
+    let (Some(year), Some(month), Some(day)) = (number(0..4), number(5..7), number(8..10)) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
 /// Result of a staleness check.
 #[derive(Debug, Clone)]
 pub struct StalenessCheck {
     pub is_stale: bool,
     pub age_days: Option<u64>,
     pub build_date: Option<String>,
+    /// Whether the upstream source changed since this build, when it was
+    /// asked (see `check_remote_staleness`); `None` for a local-only check.
+    pub update_available: Option<bool>,
+}
+
+/// Segment counts before and after an [`OpenAlexDatabase::optimize`] pass.
+#[derive(Debug, Clone, Copy)]
+pub struct OptimizeStats {
+    pub segments_before: usize,
+    pub segments_after: usize,
 }
 
 /// Handle to an opened offline OpenAlex database.
@@ -114,28 +237,45 @@ pub struct OpenAlexDatabase {
     index: Index,
     reader: tantivy::IndexReader,
     path: PathBuf,
+    guard: QueryGuard,
+    /// Whether the index holds secondary types that untyped queries must
+    /// filter out.
+    has_secondary_types: bool,
 }
 
 impl OpenAlexDatabase {
     /// Open an existing offline OpenAlex index directory.
     pub fn open(path: &Path) -> Result<Self, OpenAlexError> {
         let index = Index::open_in_dir(path)?;
+        query::register_tokenizers(&index)?;
         let reader = index
             .reader_builder()
             .reload_policy(tantivy::ReloadPolicy::OnCommitWithDelay)
             .try_into()
             .map_err(|e: tantivy::TantivyError| OpenAlexError::Index(e.to_string()))?;
+        let has_secondary_types = !metadata::read_metadata(path)?.secondary_types.is_empty();
 
         Ok(Self {
             index,
             reader,
             path: path.to_path_buf(),
+            guard: QueryGuard::default(),
+            has_secondary_types,
         })
     }
 
+    /// Types untyped queries are restricted to: the default ones when the
+    /// index also holds secondary types, otherwise no filter at all.
+    fn untyped_filter(&self) -> Option<&'static [&'static str]> {
+        self.has_secondary_types.then_some(query::PRIMARY_TYPES)
+    }
+
     /// Query for a title, returning the best fuzzy match above the default threshold.
+    ///
+    /// Only the default academic work types are searched; see
+    /// [`BuildOptions::secondary_types`].
     pub fn query(&self, title: &str) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
-        query::query_index(&self.index, &self.reader, title, DEFAULT_THRESHOLD)
+        self.query_with_threshold(title, DEFAULT_THRESHOLD)
     }
 
     /// Query with a custom similarity threshold.
@@ -144,7 +284,69 @@ impl OpenAlexDatabase {
         title: &str,
         threshold: f64,
     ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
-        query::query_index(&self.index, &self.reader, title, threshold)
+        query::query_index_typed(
+            &self.index,
+            &self.reader,
+            title,
+            threshold,
+            &self.guard,
+            self.untyped_filter(),
+        )
+    }
+
+    /// Query with a custom threshold, scoring candidates with `scorer`.
+    pub fn query_with_scorer(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+    ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+        self.query_guarded(title, threshold, scorer, &self.guard)
+    }
+
+    /// Like [`query_with_scorer`](Self::query_with_scorer), applying `guard`
+    /// instead of the one set with [`set_query_guard`](Self::set_query_guard).
+    pub fn query_guarded(
+        &self,
+        title: &str,
+        threshold: f64,
+        scorer: TitleScorer,
+        guard: &QueryGuard,
+    ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+        query::query_index_scored(
+            &self.index,
+            &self.reader,
+            title,
+            threshold,
+            guard,
+            self.untyped_filter(),
+            scorer,
+        )
+    }
+
+    /// Query with a custom threshold, matching only works of the given
+    /// OpenAlex `types` (e.g. `&["dataset"]`).
+    ///
+    /// Fails on an index built without type tags.
+    pub fn query_type(
+        &self,
+        title: &str,
+        types: &[&str],
+        threshold: f64,
+    ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+        query::query_index_typed(
+            &self.index,
+            &self.reader,
+            title,
+            threshold,
+            &self.guard,
+            Some(types),
+        )
+    }
+
+    /// Replace the short/generic query guard used by [`query`](Self::query).
+    pub fn set_query_guard(&mut self, guard: QueryGuard) {
+        self.guard = guard;
     }
 
     /// Get database metadata/info.
@@ -177,14 +379,47 @@ impl OpenAlexDatabase {
             is_stale,
             age_days,
             build_date: meta.build_date,
+            update_available: None,
         })
     }
 
+    /// Like [`check_staleness`](Self::check_staleness), but also lists the
+    /// OpenAlex S3 partitions to see whether any is newer than the last one
+    /// indexed, using `options.client`. An old index is only reported stale
+    /// if an update is actually available.
+    pub async fn check_remote_staleness(
+        &self,
+        threshold_days: u64,
+        options: &BuildOptions,
+    ) -> Result<StalenessCheck, OpenAlexError> {
+        let mut check = self.check_staleness(threshold_days)?;
+        let last_sync_date = metadata::read_metadata(&self.path)?.last_sync_date;
+        let update_available =
+            builder::remote_has_update(options, last_sync_date.as_deref()).await?;
+        check.is_stale &= update_available;
+        check.update_available = Some(update_available);
+        Ok(check)
+    }
+
     /// Convenience: check staleness with the default 30-day threshold.
     pub fn is_stale(&self) -> Result<bool, OpenAlexError> {
         Ok(self.check_staleness(30)?.is_stale)
     }
 
+    /// Merge all index segments into one.
+    ///
+    /// Incremental updates leave many small segments behind, which slows
+    /// queries over time. This forces a full merge and reloads the reader so
+    /// subsequent queries see the compacted index.
+    pub fn optimize(&self) -> Result<OptimizeStats, OpenAlexError> {
+        let (segments_before, segments_after) = builder::optimize_index(&self.index)?;
+        self.reader.reload()?;
+        Ok(OptimizeStats {
+            segments_before,
+            segments_after,
+        })
+    }
+
     /// Get the path to the index directory.
     pub fn path(&self) -> &Path {
         &self.path
@@ -201,7 +436,7 @@ pub async fn build_database(
     db_path: &Path,
     progress: impl FnMut(BuildProgress),
 ) -> Result<bool, OpenAlexError> {
-    builder::build(db_path, None, None, progress).await
+    builder::build(db_path, BuildOptions::default(), progress).await
 }
 
 /// Build or incrementally update the OpenAlex index with filtering options.
@@ -210,11 +445,70 @@ pub async fn build_database(
 ///   Useful for testing with a small slice of data.
 /// - `min_year`: skip works published before this year during indexing.
 ///   e.g. `Some(2020)` keeps only 2020+ publications.
+///
+/// To also index secondary work types such as datasets, set
+/// [`BuildOptions::secondary_types`] and use [`build_database_with_options`].
 pub async fn build_database_filtered(
     db_path: &Path,
     since: Option<&str>,
     min_year: Option<u32>,
     progress: impl FnMut(BuildProgress),
 ) -> Result<bool, OpenAlexError> {
-    builder::build(db_path, since.map(String::from), min_year, progress).await
+    let options = BuildOptions {
+        since: since.map(String::from),
+        min_year,
+        ..BuildOptions::default()
+    };
+    builder::build(db_path, options, progress).await
+}
+
+/// Build or incrementally update the OpenAlex index with full [`BuildOptions`].
+pub async fn build_database_with_options(
+    db_path: &Path,
+    options: BuildOptions,
+    progress: impl FnMut(BuildProgress),
+) -> Result<bool, OpenAlexError> {
+    builder::build(db_path, options, progress).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_date() {
+        assert!(is_valid_date("2024-06-30"));
+        assert!(is_valid_date("2024-02-29"));
+        assert!(!is_valid_date("2023-02-29"));
+        assert!(!is_valid_date("2024-13-45"));
+        assert!(!is_valid_date("2024-04-31"));
+        assert!(!is_valid_date("2024-00-10"));
+        assert!(!is_valid_date("2024/06/30"));
+        assert!(!is_valid_date("24-06-30"));
+        assert!(!is_valid_date("2024-6-300"));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_query_result_round_trip() {
+        let result = OpenAlexQueryResult {
+            record: OpenAlexRecord {
+                title: "Attention Is All You Need".into(),
+                authors: vec!["Ashish Vaswani".into(), "Noam Shazeer".into()],
+                url: Some("https://example.org/paper".into()),
+            },
+            score: 0.97,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"title\":\"Attention Is All You Need\""));
+        let back: OpenAlexQueryResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.record.title, result.record.title);
+        assert_eq!(back.record.authors, result.record.authors);
+        assert_eq!(back.record.url, result.record.url);
+        assert_eq!(back.score, result.score);
+    }
 }
@@ -21,6 +21,10 @@ pub struct IndexMetadata {
     pub publication_count: Option<u64>,
     /// The newest partition date synced (YYYY-MM-DD).
     pub last_sync_date: Option<String>,
+    /// Secondary work types (e.g. `dataset`) indexed alongside the default
+    /// ones; empty for indexes holding only the default types.
+    #[serde(default)]
+    pub secondary_types: Vec<String>,
 }
 
 impl Default for IndexMetadata {
@@ -30,6 +34,7 @@ impl Default for IndexMetadata {
             build_date: None,
             publication_count: None,
             last_sync_date: None,
+            secondary_types: Vec::new(),
         }
     }
 }
@@ -41,14 +46,19 @@ pub fn read_metadata(dir: &Path) -> Result<IndexMetadata, OpenAlexError> {
         return Ok(IndexMetadata::default());
     }
     let content = std::fs::read_to_string(&path)?;
-    serde_json::from_str(&content).map_err(|e| OpenAlexError::Parse(e.to_string()))
+    serde_json::from_str(&content).map_err(|e| OpenAlexError::Parse {
+        message: e.to_string(),
+        record: None,
+    })
 }
 
 /// Write metadata to the index directory.
 pub fn write_metadata(dir: &Path, meta: &IndexMetadata) -> Result<(), OpenAlexError> {
     let path = dir.join(METADATA_FILENAME);
-    let content =
-        serde_json::to_string_pretty(meta).map_err(|e| OpenAlexError::Parse(e.to_string()))?;
+    let content = serde_json::to_string_pretty(meta).map_err(|e| OpenAlexError::Parse {
+        message: e.to_string(),
+        record: None,
+    })?;
     std::fs::write(&path, content)?;
     Ok(())
 }
@@ -65,12 +75,14 @@ mod tests {
             build_date: Some("1700000000".to_string()),
             publication_count: Some(42),
             last_sync_date: Some("2025-01-15".to_string()),
+            secondary_types: vec!["dataset".to_string()],
         };
         write_metadata(dir.path(), &meta).unwrap();
         let loaded = read_metadata(dir.path()).unwrap();
         assert_eq!(loaded.schema_version, "1");
         assert_eq!(loaded.publication_count, Some(42));
         assert_eq!(loaded.last_sync_date.as_deref(), Some("2025-01-15"));
+        assert_eq!(loaded.secondary_types, ["dataset"]);
     }
 
     #[test]
@@ -80,5 +92,6 @@ mod tests {
         assert_eq!(meta.schema_version, "1");
         assert!(meta.build_date.is_none());
         assert!(meta.publication_count.is_none());
+        assert!(meta.secondary_types.is_empty());
     }
 }
@@ -3,28 +3,65 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use tantivy::collector::TopDocs;
-use tantivy::query::QueryParser;
+use tantivy::query::{BooleanQuery, Occur, Query, QueryParser, TermQuery};
 use tantivy::schema::*;
-use tantivy::{Index, IndexReader};
+use tantivy::tokenizer::NgramTokenizer;
+use tantivy::{DocAddress, Index, IndexReader, Searcher};
 
 use crate::{OpenAlexError, OpenAlexQueryResult, OpenAlexRecord};
 
 /// Default similarity threshold for fuzzy title matching (same as DBLP).
 pub const DEFAULT_THRESHOLD: f64 = 0.90;
 
-/// Normalize a title for comparison: lowercase alphanumeric only.
-pub fn normalize_title(title: &str) -> String {
-    static NON_ALNUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
-    let lowered = title.to_lowercase();
-    NON_ALNUM.replace_all(&lowered, "").to_string()
+/// Maximum number of Tantivy candidates fetched for fuzzy re-ranking.
+const MAX_CANDIDATES: usize = 50;
+
+/// Optional field holding character trigrams of the normalized title.
+pub(crate) const TITLE_NGRAM_FIELD: &str = "title_ngram";
+
+/// Raw OpenAlex work type (`article`, `dataset`, ...). Absent from indexes
+/// built before secondary types were supported; every work there is primary.
+pub(crate) const WORK_TYPE_FIELD: &str = "work_type";
+
+/// Work types indexed by default (skip datasets, components, etc.) and the
+/// only ones untyped queries match.
+pub(crate) const PRIMARY_TYPES: &[&str] = &[
+    "article",
+    "book-chapter",
+    "preprint",
+    "review",
+    "dissertation",
+];
+
+/// Tokenizer registered for [`TITLE_NGRAM_FIELD`].
+pub(crate) const NGRAM_TOKENIZER: &str = "title_trigram";
+
+/// Gram size for [`TITLE_NGRAM_FIELD`].
+const NGRAM_SIZE: usize = 3;
+
+/// Register the custom tokenizers used by the index schema.
+///
+/// Tantivy does not persist tokenizers, so this must run after every
+/// `Index::open_in_dir` / `Index::create_in_dir` before writing documents.
+pub(crate) fn register_tokenizers(index: &Index) -> Result<(), OpenAlexError> {
+    let tokenizer = NgramTokenizer::new(NGRAM_SIZE, NGRAM_SIZE, false)?;
+    index.tokenizers().register(NGRAM_TOKENIZER, tokenizer);
+    Ok(())
 }
 
+pub use hallucinator_text::QueryGuard;
+
+/// Normalize a title for comparison: diacritics folded, lowercase alphanumeric only.
+pub use hallucinator_text::normalize_title;
+
+use hallucinator_text::TitleScorer;
+
 /// Extract meaningful query words for Tantivy search (4+ chars, no stop words).
 ///
 /// Duplicates DBLP's `get_query_words` logic for consistency.
 pub fn get_query_words(title: &str) -> Vec<String> {
-    // Strip BibTeX capitalization braces
-    let title = title.replace(['{', '}'], "");
+    // Strip BibTeX capitalization braces and fold diacritics
+    let title = hallucinator_text::fold_diacritics(title).replace(['{', '}'], "");
 
     static WORD_RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"[a-zA-Z0-9]+(?:['\u{2019}\u{2018}\-][a-zA-Z0-9]+)*").unwrap());
@@ -87,24 +124,61 @@ pub fn get_query_words(title: &str) -> Vec<String> {
     scored.into_iter().map(|(_, _, lower)| lower).collect()
 }
 
-/// Query the Tantivy index for a title, returning the best fuzzy match above the threshold.
-pub fn query_index(
+/// Query the Tantivy index for a title, returning the best fuzzy match above
+/// the threshold among works of `types`, or among all works when `None`.
+///
+/// Short or generic queries are skipped or tightened according to `guard`.
+pub fn query_index_typed(
     index: &Index,
     reader: &IndexReader,
     title: &str,
     threshold: f64,
+    guard: &QueryGuard,
+    types: Option<&[&str]>,
 ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+    query_index_scored(
+        index,
+        reader,
+        title,
+        threshold,
+        guard,
+        types,
+        TitleScorer::default(),
+    )
+}
+
+/// [`query_index_typed`] ranking candidates with `scorer`; `threshold`
+/// applies to its score.
+pub fn query_index_scored(
+    index: &Index,
+    reader: &IndexReader,
+    title: &str,
+    threshold: f64,
+    guard: &QueryGuard,
+    types: Option<&[&str]>,
+    scorer: TitleScorer,
+) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+    let schema = index.schema();
+    let type_filter = match (schema.get_field(WORK_TYPE_FIELD), types) {
+        (Ok(field), Some(types)) => Some(type_filter(field, types)),
+        (_, None) => None,
+        (Err(_), Some(_)) => {
+            return Err(OpenAlexError::Index(
+                "index has no work type tags; rebuild it to query by type".into(),
+            ));
+        }
+    };
+
     let words = get_query_words(title);
-    if words.is_empty() {
+    let Some((threshold, limit)) = guard.limits(words.len(), threshold, MAX_CANDIDATES) else {
         return Ok(None);
-    }
+    };
 
     let norm_query = normalize_title(title);
     if norm_query.is_empty() {
         return Ok(None);
     }
 
-    let schema = index.schema();
     let title_field = schema
         .get_field("title")
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
@@ -117,9 +191,12 @@ pub fn query_index(
         reader,
         &query_parser,
         &query_str,
-        &norm_query,
+        type_filter.as_deref(),
+        title,
         threshold,
+        limit,
         &schema,
+        scorer,
     )?;
     if result.is_some() {
         return Ok(result);
@@ -128,38 +205,136 @@ pub fn query_index(
     // Fallback: top 3 words when primary returned nothing
     if words.len() > 3 {
         let fallback_str = words[..3].join(" AND ");
-        return tantivy_match(
+        let result = tantivy_match(
             reader,
             &query_parser,
             &fallback_str,
-            &norm_query,
+            type_filter.as_deref(),
+            title,
             threshold,
+            limit,
             &schema,
-        );
+            scorer,
+        )?;
+        if result.is_some() {
+            return Ok(result);
+        }
+    }
+
+    // Last resort: trigram candidates, when the index was built with them.
+    // Catches plural/singular, hyphenation and word-order differences that
+    // defeat the whole-word AND queries above.
+    if !guard.allows_loose_fallback(words.len()) {
+        return Ok(None);
+    }
+    if let Ok(ngram_field) = schema.get_field(TITLE_NGRAM_FIELD) {
+        let searcher = reader.searcher();
+        let top_docs = ngram_candidates(
+            &searcher,
+            ngram_field,
+            &norm_query,
+            type_filter.as_deref(),
+            limit,
+        )?;
+        return best_candidate(&searcher, top_docs, title, threshold, &schema, scorer);
     }
 
     Ok(None)
 }
 
+/// A query matching works whose type is any of `types`.
+fn type_filter(field: Field, types: &[&str]) -> Box<dyn Query> {
+    Box::new(BooleanQuery::new(
+        types
+            .iter()
+            .map(|t| {
+                let term =
+                    TermQuery::new(Term::from_field_text(field, t), IndexRecordOption::Basic);
+                (Occur::Should, Box::new(term) as Box<dyn Query>)
+            })
+            .collect(),
+    ))
+}
+
+/// `query`, further restricted by `filter` when there is one.
+fn filtered(query: Box<dyn Query>, filter: Option<&dyn Query>) -> Box<dyn Query> {
+    match filter {
+        Some(filter) => Box::new(BooleanQuery::new(vec![
+            (Occur::Must, query),
+            (Occur::Must, filter.box_clone()),
+        ])),
+        None => query,
+    }
+}
+
+/// Retrieve candidates sharing character trigrams with `norm_query`, ranked
+/// by BM25 over the matching grams.
+fn ngram_candidates(
+    searcher: &Searcher,
+    ngram_field: Field,
+    norm_query: &str,
+    type_filter: Option<&dyn Query>,
+    limit: usize,
+) -> Result<Vec<(f32, DocAddress)>, OpenAlexError> {
+    let chars: Vec<char> = norm_query.chars().collect();
+    let mut grams: Vec<String> = chars
+        .windows(NGRAM_SIZE)
+        .map(|w| w.iter().collect())
+        .collect();
+    grams.sort_unstable();
+    grams.dedup();
+    if grams.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let terms = grams
+        .iter()
+        .map(|g| Term::from_field_text(ngram_field, g))
+        .collect();
+    let query = filtered(
+        Box::new(BooleanQuery::new_multiterms_query(terms)),
+        type_filter,
+    );
+    searcher
+        .search(&query, &TopDocs::with_limit(limit))
+        .map_err(|e| OpenAlexError::Index(e.to_string()))
+}
+
 /// Run a Tantivy query and return the best fuzzy match above the threshold.
+#[allow(clippy::too_many_arguments)]
 fn tantivy_match(
     reader: &IndexReader,
     query_parser: &QueryParser,
     query_str: &str,
-    norm_query: &str,
+    type_filter: Option<&dyn Query>,
+    title: &str,
     threshold: f64,
+    limit: usize,
     schema: &Schema,
+    scorer: TitleScorer,
 ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
     let query = match query_parser.parse_query(query_str) {
-        Ok(q) => q,
+        Ok(q) => filtered(q, type_filter),
         Err(_) => return Ok(None),
     };
 
     let searcher = reader.searcher();
     let top_docs = searcher
-        .search(&query, &TopDocs::with_limit(50))
+        .search(&query, &TopDocs::with_limit(limit))
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
 
+    best_candidate(&searcher, top_docs, title, threshold, schema, scorer)
+}
+
+/// Re-rank Tantivy hits with `scorer` and return the best one above the threshold.
+fn best_candidate(
+    searcher: &Searcher,
+    top_docs: Vec<(f32, DocAddress)>,
+    title: &str,
+    threshold: f64,
+    schema: &Schema,
+    scorer: TitleScorer,
+) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
     if top_docs.is_empty() {
         return Ok(None);
     }
@@ -172,6 +347,7 @@ fn tantivy_match(
         .map_err(|e| OpenAlexError::Index(e.to_string()))?;
 
     let mut best_match: Option<(f64, String, Vec<String>)> = None;
+    let cited = scorer.prepare(title);
 
     for (_score, doc_address) in top_docs {
         let doc = searcher
@@ -184,12 +360,11 @@ fn tantivy_match(
             .unwrap_or("")
             .to_string();
 
-        let norm_candidate = normalize_title(&candidate_title);
-        if norm_candidate.is_empty() {
+        if normalize_title(&candidate_title).is_empty() {
             continue;
         }
 
-        let fuzzy_score = rapidfuzz::fuzz::ratio(norm_query.chars(), norm_candidate.chars());
+        let fuzzy_score = cited.score(&candidate_title);
 
         if fuzzy_score >= threshold
             && best_match
@@ -228,6 +403,16 @@ mod tests {
     use super::*;
     use tantivy::doc;
 
+    fn query_index(
+        index: &Index,
+        reader: &IndexReader,
+        title: &str,
+        threshold: f64,
+        guard: &QueryGuard,
+    ) -> Result<Option<OpenAlexQueryResult>, OpenAlexError> {
+        query_index_typed(index, reader, title, threshold, guard, None)
+    }
+
     fn build_test_index() -> (Index, IndexReader) {
         let mut schema_builder = Schema::builder();
         schema_builder.add_text_field("title", TEXT | STORED);
@@ -269,12 +454,227 @@ mod tests {
         (index, reader)
     }
 
+    fn build_ngram_test_index() -> (Index, IndexReader) {
+        let schema = crate::builder::build_schema(true);
+        let index = Index::create_in_ram(schema.clone());
+        register_tokenizers(&index).unwrap();
+        let mut writer = index.writer(15_000_000).unwrap();
+
+        let title_field = schema.get_field("title").unwrap();
+        let ngram_field = schema.get_field(TITLE_NGRAM_FIELD).unwrap();
+        let authors_field = schema.get_field("authors").unwrap();
+        let id_field = schema.get_field("openalex_id").unwrap();
+        let type_field = schema.get_field(WORK_TYPE_FIELD).unwrap();
+
+        for (id, title) in [
+            (1u64, "Attention is All you Need"),
+            (
+                2u64,
+                "BERT: Pre-training of Deep Bidirectional Transformers for Language Understanding",
+            ),
+            (3u64, "Deep Residual Learning for Image Recognition"),
+        ] {
+            writer
+                .add_document(doc!(
+                    title_field => title,
+                    ngram_field => normalize_title(title),
+                    authors_field => "",
+                    id_field => id,
+                    type_field => "article"
+                ))
+                .unwrap();
+        }
+        writer.commit().unwrap();
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(tantivy::ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+
+        (index, reader)
+    }
+
+    /// Word-reordered, singular/plural-shifted paraphrase of the BERT title.
+    /// Neither the full AND query nor the top-3-word fallback matches it.
+    const REORDERED_BERT: &str =
+        "Transformer Pretraining for Deep Bidirectional Language Understanding (BERT)";
+
+    #[test]
+    fn test_ngram_candidates_word_reordered() {
+        let (index, reader) = build_ngram_test_index();
+        let ngram_field = index.schema().get_field(TITLE_NGRAM_FIELD).unwrap();
+        let searcher = reader.searcher();
+        let top_docs = ngram_candidates(
+            &searcher,
+            ngram_field,
+            &normalize_title(REORDERED_BERT),
+            None,
+            10,
+        )
+        .unwrap();
+        assert!(!top_docs.is_empty());
+
+        let title_field = index.schema().get_field("title").unwrap();
+        let top = searcher
+            .doc::<tantivy::TantivyDocument>(top_docs[0].1)
+            .unwrap();
+        assert!(
+            top.get_first(title_field)
+                .and_then(|v| v.as_str())
+                .unwrap()
+                .starts_with("BERT")
+        );
+    }
+
+    #[test]
+    fn test_query_ngram_fallback() {
+        let guard = QueryGuard {
+            loose_fallback: true,
+            ..QueryGuard::default()
+        };
+
+        // Word-only index: candidate retrieval misses entirely.
+        let (index, reader) = build_test_index();
+        let result = query_index(&index, &reader, REORDERED_BERT, 0.5, &guard).unwrap();
+        assert!(result.is_none());
+
+        // With trigrams the paper is retrieved and re-ranked by rapidfuzz.
+        let (index, reader) = build_ngram_test_index();
+        let result = query_index(&index, &reader, REORDERED_BERT, 0.5, &guard).unwrap();
+        assert!(result.unwrap().record.title.starts_with("BERT"));
+    }
+
+    #[test]
+    fn test_query_ngram_fallback_skips_short_queries() {
+        let (index, reader) = build_ngram_test_index();
+        // Two misspelled words: only trigrams can retrieve "Attention is All
+        // you Need", and at this threshold they would accept it.
+        let noisy = "Atention Nead";
+        let lenient = QueryGuard {
+            short_query_threshold: 0.0,
+            loose_fallback: true,
+            ..QueryGuard::default()
+        };
+        let result = query_index(&index, &reader, noisy, 0.3, &lenient).unwrap();
+        assert!(result.is_none());
+
+        let result = query_index(&index, &reader, noisy, 0.3, &QueryGuard::disabled()).unwrap();
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_query_token_set_rescues_reordered_title() {
+        let (index, reader) = build_test_index();
+        let guard = QueryGuard::default();
+        let reordered =
+            "Deep Bidirectional Transformers for Language Understanding: BERT Pre-training";
+        let query = |scorer| {
+            query_index_scored(
+                &index,
+                &reader,
+                reordered,
+                DEFAULT_THRESHOLD,
+                &guard,
+                None,
+                scorer,
+            )
+            .unwrap()
+        };
+
+        assert!(query(TitleScorer::Ratio).is_none());
+        let result = query(TitleScorer::TokenSet).unwrap();
+        assert!(result.record.title.starts_with("BERT"));
+        assert_eq!(result.score, 1.0);
+    }
+
+    #[test]
+    fn test_query_type_excludes_same_titled_article() {
+        let schema = crate::builder::build_schema(false);
+        let index = Index::create_in_ram(schema.clone());
+        let mut writer = index.writer(15_000_000).unwrap();
+        let title_field = schema.get_field("title").unwrap();
+        let authors_field = schema.get_field("authors").unwrap();
+        let id_field = schema.get_field("openalex_id").unwrap();
+        let type_field = schema.get_field(WORK_TYPE_FIELD).unwrap();
+
+        const TITLE: &str = "The Pile: An 800GB Dataset of Diverse Text for Language Modeling";
+        for (id, authors, work_type) in [(1u64, "Leo Gao", "article"), (2, "EleutherAI", "dataset")]
+        {
+            writer
+                .add_document(doc!(
+                    title_field => TITLE,
+                    authors_field => authors,
+                    id_field => id,
+                    type_field => work_type
+                ))
+                .unwrap();
+        }
+        writer.commit().unwrap();
+        let reader: IndexReader = index
+            .reader_builder()
+            .reload_policy(tantivy::ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+        let guard = QueryGuard::default();
+        let authors = |types: Option<&[&str]>| {
+            query_index_typed(&index, &reader, TITLE, DEFAULT_THRESHOLD, &guard, types)
+                .unwrap()
+                .map(|r| r.record.authors)
+        };
+
+        assert_eq!(
+            authors(Some(PRIMARY_TYPES)),
+            Some(vec!["Leo Gao".to_string()])
+        );
+        // Without types nothing is filtered out.
+        assert!(authors(None).is_some());
+        assert_eq!(
+            authors(Some(&["article"])),
+            Some(vec!["Leo Gao".to_string()])
+        );
+        assert_eq!(
+            authors(Some(&["dataset"])),
+            Some(vec!["EleutherAI".to_string()])
+        );
+        assert_eq!(authors(Some(&["component"])), None);
+
+        // An index without type tags can't answer typed queries.
+        let (index, reader) = build_test_index();
+        assert!(
+            query_index_typed(
+                &index,
+                &reader,
+                TITLE,
+                DEFAULT_THRESHOLD,
+                &guard,
+                Some(&["dataset"])
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_normalize_title() {
         assert_eq!(normalize_title("Hello, World! 123"), "helloworld123");
         assert_eq!(normalize_title("  A--B  "), "ab");
     }
 
+    #[test]
+    fn test_query_diacritics() {
+        let (index, reader) = build_test_index();
+        let result = query_index(
+            &index,
+            &reader,
+            "Áttention is All you Néed",
+            DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
+        )
+        .unwrap();
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().score, 1.0);
+    }
+
     #[test]
     fn test_get_query_words() {
         let words = get_query_words("Attention is All you Need");
@@ -291,6 +691,7 @@ mod tests {
             &reader,
             "Attention is All you Need",
             DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
         )
         .unwrap();
         assert!(result.is_some());
@@ -309,6 +710,7 @@ mod tests {
             &reader,
             "Completely Unrelated Paper About Marine Biology",
             DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
         )
         .unwrap();
         assert!(result.is_none());
@@ -317,7 +719,47 @@ mod tests {
     #[test]
     fn test_query_empty() {
         let (index, reader) = build_test_index();
-        let result = query_index(&index, &reader, "", DEFAULT_THRESHOLD).unwrap();
+        let result = query_index(
+            &index,
+            &reader,
+            "",
+            DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
+        )
+        .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_stopwords_only_returns_none() {
+        let (index, reader) = build_test_index();
+        let result = query_index(
+            &index,
+            &reader,
+            "About Their Other Through",
+            DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
+        )
+        .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_guard_min_words() {
+        let (index, reader) = build_test_index();
+        let guard = QueryGuard {
+            min_query_words: 3,
+            ..QueryGuard::default()
+        };
+        // Two distinctive words ("attention", "need") fall below the minimum.
+        let result = query_index(
+            &index,
+            &reader,
+            "Attention is All you Need",
+            DEFAULT_THRESHOLD,
+            &guard,
+        )
+        .unwrap();
         assert!(result.is_none());
     }
 
@@ -329,6 +771,7 @@ mod tests {
             &reader,
             "BERT: Pre-training of Deep Bidirectional Transformers for Language Understanding",
             DEFAULT_THRESHOLD,
+            &QueryGuard::default(),
         )
         .unwrap();
         assert!(result.is_some());
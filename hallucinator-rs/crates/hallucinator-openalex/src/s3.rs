@@ -179,28 +179,29 @@ fn parse_partition_list_xml(
                 let qname = e.name();
                 let name = std::str::from_utf8(qname.as_ref()).unwrap_or("");
                 match name {
-                    "Prefix" => {
-                        if in_prefix {
-                            if let Some(date) = extract_date_from_prefix(&current_text) {
-                                partitions.push(DatePartition {
-                                    prefix: current_text.clone(),
-                                    date,
-                                });
-                            }
-                            in_prefix = false;
+                    "Prefix" if in_prefix => {
+                        if let Some(date) = extract_date_from_prefix(&current_text) {
+                            partitions.push(DatePartition {
+                                prefix: current_text.clone(),
+                                date,
+                            });
                         }
+                        in_prefix = false;
                     }
-                    "NextContinuationToken" => {
-                        if in_next_token {
-                            next_token = Some(current_text.clone());
-                            in_next_token = false;
-                        }
+                    "NextContinuationToken" if in_next_token => {
+                        next_token = Some(current_text.clone());
+                        in_next_token = false;
                     }
                     _ => {}
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => return Err(OpenAlexError::Parse(format!("XML parse error: {}", e))),
+            Err(e) => {
+                return Err(OpenAlexError::Parse {
+                    message: format!("XML parse error: {}", e),
+                    record: None,
+                });
+            }
             _ => {}
         }
         buf.clear();
@@ -278,17 +279,20 @@ fn parse_file_list_xml(xml: &str) -> Result<(Vec<PartitionFile>, Option<String>)
                         }
                         in_contents = false;
                     }
-                    "NextContinuationToken" => {
-                        if in_next_token {
-                            next_token = Some(current_text.clone());
-                            in_next_token = false;
-                        }
+                    "NextContinuationToken" if in_next_token => {
+                        next_token = Some(current_text.clone());
+                        in_next_token = false;
                     }
                     _ => {}
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => return Err(OpenAlexError::Parse(format!("XML parse error: {}", e))),
+            Err(e) => {
+                return Err(OpenAlexError::Parse {
+                    message: format!("XML parse error: {}", e),
+                    record: None,
+                });
+            }
             _ => {}
         }
         buf.clear();
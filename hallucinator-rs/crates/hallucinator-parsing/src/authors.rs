@@ -83,6 +83,13 @@ pub(crate) fn extract_authors_from_reference_with_config(
         return vec![];
     }
 
+    let author_section = if config.strip_author_superscripts {
+        strip_citation_superscripts(author_section)
+    } else {
+        std::borrow::Cow::Borrowed(author_section)
+    };
+    let author_section = author_section.as_ref();
+
     // Check for ALL CAPS format: LASTNAME, I., LASTNAME, I., AND LASTNAME, I.
     // Must match pattern like "BACKES, M." (all-caps surname, comma, space, single uppercase initial, period)
     static ALL_CAPS_CHECK: Lazy<Regex> =
@@ -103,6 +110,25 @@ pub(crate) fn extract_authors_from_reference_with_config(
     parse_general_authors_with_max(author_section, config.max_authors)
 }
 
+/// Remove citation markers glued to the end of author names.
+///
+/// Some PDFs render affiliation/citation superscripts inline, producing
+/// "Smith1, Jones2,3 and Lee4*". A marker is stripped only when it directly
+/// follows a run of at least two letters and is itself followed by a
+/// separator or the end of the text: one or two ASCII digits (optionally a
+/// comma-joined list like `2,3`) or Unicode superscript digits, plus an
+/// optional `*`/`†`. Space-separated tokens ("Smith 3rd", "2020") and
+/// letter-led suffixes ("Jr.", "III") are left alone.
+fn strip_citation_superscripts(section: &str) -> std::borrow::Cow<'_, str> {
+    static MARKER_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"(\p{L}{2})(?:\d{1,2}(?:,\d{1,2})*|[\u{00B9}\u{00B2}\u{00B3}\u{2070}-\u{2079}]+)[*\u{2020}]?([\s,;.]|$)",
+        )
+        .unwrap()
+    });
+    MARKER_RE.replace_all(section, "$1$2")
+}
+
 /// Find the first "real" period — one that's not after an author initial like "M." or "J."
 fn find_first_real_period(text: &str) -> Option<usize> {
     static PERIOD_SPACE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.\s").unwrap());
@@ -322,4 +348,48 @@ mod tests {
             authors
         );
     }
+
+    #[test]
+    fn test_superscript_markers_stripped() {
+        let ref_text =
+            "John Smith1, Alice Jones2,3, and Bob Lee4*. 2022. Title of paper. In Proceedings.";
+        let authors = extract_authors_from_reference(ref_text);
+        assert_eq!(authors, vec!["John Smith", "Alice Jones", "Bob Lee"]);
+    }
+
+    #[test]
+    fn test_unicode_superscript_markers_stripped() {
+        let ref_text = "Smith\u{00B9}, J.; Jones\u{00B2}\u{00B3}, A.; and Williams\u{2074}\u{2020}, C. 2023. Title here.";
+        let authors = extract_authors_from_reference(ref_text);
+        // Trailing punctuation of the author section is trimmed as usual
+        assert_eq!(authors, vec!["Smith, J.", "Jones, A.", "Williams, C"]);
+    }
+
+    #[test]
+    fn test_all_caps_superscript_markers_stripped() {
+        let ref_text = "SMITH1, J., AND JONES2, A. Title here.";
+        let authors = extract_authors_from_reference(ref_text);
+        assert_eq!(authors, vec!["Smith, J.", "Jones, A."]);
+    }
+
+    #[test]
+    fn test_superscript_strip_keeps_name_suffixes() {
+        let stripped =
+            strip_citation_superscripts("John Smith Jr., Henry Ford III, Jane Doe 2nd, A. Li 2020");
+        assert_eq!(
+            stripped,
+            "John Smith Jr., Henry Ford III, Jane Doe 2nd, A. Li 2020"
+        );
+    }
+
+    #[test]
+    fn test_superscript_strip_can_be_disabled() {
+        let config = crate::ParsingConfigBuilder::new()
+            .strip_author_superscripts(false)
+            .build()
+            .unwrap();
+        let ref_text = "John Smith1 and Alice Jones. 2022. Title of paper. In Proceedings.";
+        let authors = extract_authors_from_reference_with_config(ref_text, &config);
+        assert_eq!(authors, vec!["Alice Jones"]);
+    }
 }
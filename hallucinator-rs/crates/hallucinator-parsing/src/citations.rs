@@ -0,0 +1,247 @@
+//! In-text citation detection: which listed references the document body
+//! actually cites.
+
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::Reference;
+
+/// How far past an author's surname to look for the year of an author–year
+/// citation, e.g. "Smith, Jones, and Lee (2019)".
+const YEAR_WINDOW: usize = 60;
+
+/// Widest numeric range (`[3–40]`) expanded; anything wider is more likely
+/// a page or year span than a citation.
+const MAX_RANGE: usize = 100;
+
+/// Record on each reference whether `body` (the document text before the
+/// reference list) cites it.
+///
+/// Numeric markers (`[3]`, `[2, 5–7]`) are matched against the number each
+/// reference is printed with, from `labels` (see [`printed_labels`]), or its
+/// `original_number` where it has none, author–year markers ("Smith et al. (2020)",
+/// "(Smith and Jones, 2019a)") against its first author's surname and
+/// year. When no reference is cited at all, the document's citation style
+/// wasn't recognized (superscript numbers, for one, come out of text
+/// extraction as plain digits) and `cited_in_text` is left `None` rather
+/// than reporting every reference as uncited.
+pub fn mark_in_text_citations(body: &str, references: &mut [Reference], labels: &[Option<usize>]) {
+    let numbers = numeric_citations(body);
+    let cited: Vec<bool> = references
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let number = labels
+                .get(i)
+                .copied()
+                .flatten()
+                .unwrap_or(r.original_number);
+            numbers.contains(&number) || has_author_year_citation(body, r)
+        })
+        .collect();
+    if !cited.contains(&true) {
+        return;
+    }
+    for (reference, cited) in references.iter_mut().zip(cited) {
+        reference.cited_in_text = Some(cited);
+    }
+}
+
+/// The number each of `references` (as segmented from `section`) is printed
+/// with: the `[12]` or `12.` label just before its first line. `None` for a
+/// reference without one, or whose first line can't be found in `section`.
+///
+/// A reference's position in the list is no substitute: one entry merged
+/// into another, or split in two, shifts the position of every later one.
+pub fn printed_labels(section: &str, references: &[String]) -> Vec<Option<usize>> {
+    static LABEL_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?:\[(\d{1,4})\]|(?:^|\n)[ \t]*(\d{1,3})\.)\s*$").unwrap());
+
+    let mut pos = 0;
+    references
+        .iter()
+        .map(|reference| {
+            let first_line = reference.lines().next().unwrap_or("").trim();
+            if first_line.is_empty() {
+                return None;
+            }
+            let at = pos + section[pos..].find(first_line)?;
+            let before = &section[pos..at];
+            pos = at + first_line.len();
+            let caps = LABEL_RE.captures(before)?;
+            caps.get(1).or(caps.get(2))?.as_str().parse().ok()
+        })
+        .collect()
+}
+
+/// Every reference number inside a bracketed numeric citation in `body`.
+fn numeric_citations(body: &str) -> HashSet<usize> {
+    static MARKER_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\[(\d{1,4}(?:\s*[,;–-]\s*\d{1,4})*)\]").unwrap());
+    static RANGE_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^(\d{1,4})\s*[–-]\s*(\d{1,4})$").unwrap());
+
+    let mut numbers = HashSet::new();
+    for caps in MARKER_RE.captures_iter(body) {
+        for part in caps[1].split([',', ';']).map(str::trim) {
+            if let Some(range) = RANGE_RE.captures(part) {
+                let (from, to): (usize, usize) =
+                    (range[1].parse().unwrap(), range[2].parse().unwrap());
+                if from <= to && to - from <= MAX_RANGE {
+                    numbers.extend(from..=to);
+                }
+            } else if let Ok(n) = part.parse() {
+                numbers.insert(n);
+            }
+        }
+    }
+    numbers
+}
+
+/// Whether `body` names the reference's first author followed shortly by
+/// one of the years in its citation.
+fn has_author_year_citation(body: &str, reference: &Reference) -> bool {
+    static YEAR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:19|20)\d{2}\b").unwrap());
+
+    let Some(surname) = reference.authors.first().map(|a| surname(a)) else {
+        return false;
+    };
+    if surname.chars().count() < 2 {
+        return false;
+    }
+    let years: Vec<&str> = YEAR_RE
+        .find_iter(&reference.raw_citation)
+        .map(|m| m.as_str())
+        .collect();
+    if years.is_empty() {
+        return false;
+    }
+
+    let Ok(surname_re) = Regex::new(&format!(r"\b{}\b", regex::escape(surname))) else {
+        return false;
+    };
+    surname_re.find_iter(body).any(|m| {
+        let mut end = (m.end() + YEAR_WINDOW).min(body.len());
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        let after = &body[m.end()..end];
+        years.iter().any(|year| after.contains(year))
+    })
+}
+
+/// The surname in "Last, First" or "First Last".
+fn surname(author: &str) -> &str {
+    let name = match author.split_once(',') {
+        Some((last, _)) => last,
+        None => author.split_whitespace().last().unwrap_or(""),
+    };
+    name.trim_matches(|c: char| !c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(n: usize, author: &str, raw: &str) -> Reference {
+        Reference {
+            raw_citation: raw.to_string(),
+            title: Some(raw.to_string()),
+            authors: vec![author.to_string()],
+            doi: None,
+            arxiv_id: None,
+            original_number: n,
+            skip_reason: None,
+            kind: Default::default(),
+            container_title: None,
+            cite_key: None,
+            cited_in_text: None,
+        }
+    }
+
+    #[test]
+    fn test_numeric_citations_expand_lists_and_ranges() {
+        let numbers = numeric_citations("as shown [1], [3, 5–7] and [9-10; 12]. See [2020-1].");
+        let mut numbers: Vec<_> = numbers.into_iter().collect();
+        numbers.sort();
+        assert_eq!(numbers, [1, 3, 5, 6, 7, 9, 10, 12]);
+    }
+
+    #[test]
+    fn test_author_year_citations() {
+        // Turing is named, but not next to the year of the listed paper.
+        let body = "Prior work (Vaswani et al., 2017; He and Sun 2016a) builds on Turing's \
+                    question, first posed long before 2016.";
+        let mut refs = vec![
+            reference(
+                1,
+                "Ashish Vaswani",
+                "A. Vaswani et al. Attention. NeurIPS, 2017.",
+            ),
+            reference(
+                2,
+                "He, Kaiming",
+                "K. He and J. Sun. Residual learning. CVPR 2016.",
+            ),
+            reference(
+                3,
+                "Alan Turing",
+                "A. Turing. Computing machinery. Mind, 1950.",
+            ),
+        ];
+        mark_in_text_citations(body, &mut refs, &[]);
+        let cited: Vec<_> = refs.iter().map(|r| r.cited_in_text).collect();
+        assert_eq!(cited, [Some(true), Some(true), Some(false)]);
+    }
+
+    #[test]
+    fn test_unrecognized_style_leaves_references_unchecked() {
+        let mut refs = vec![reference(1, "Alan Turing", "A. Turing. Computing. 1950.")];
+        mark_in_text_citations("No markers here.1 Just superscripts.", &mut refs, &[]);
+        assert_eq!(refs[0].cited_in_text, None);
+    }
+
+    #[test]
+    fn test_printed_labels_survive_a_merged_entry() {
+        // [2] was merged into [1] by segmentation, so position and label
+        // disagree from the second reference on.
+        let section = "[1] A. Turing. Computing machinery. Mind, 1950.\n\
+                       [2] C. Shannon. A mathematical theory. 1948.\n\
+                       [3] A. Vaswani et al. Attention. NeurIPS, 2017.\n\
+                       4. K. He et al. Residual learning. CVPR 2016.";
+        let segmented = [
+            "A. Turing. Computing machinery. Mind, 1950.\n[2] C. Shannon. A mathematical theory. 1948.",
+            "A. Vaswani et al. Attention. NeurIPS, 2017.",
+            "K. He et al. Residual learning. CVPR 2016.",
+            "Not in the section at all.",
+        ]
+        .map(String::from);
+        let labels = printed_labels(section, &segmented);
+        assert_eq!(labels, [Some(1), Some(3), Some(4), None]);
+
+        let mut refs = vec![
+            reference(
+                1,
+                "Alan Turing",
+                "A. Turing. Computing machinery. Mind, 1950.",
+            ),
+            reference(
+                2,
+                "Ashish Vaswani",
+                "A. Vaswani et al. Attention. NeurIPS, 2017.",
+            ),
+            reference(
+                3,
+                "Kaiming He",
+                "K. He et al. Residual learning. CVPR 2016.",
+            ),
+            reference(4, "Nobody", "Not in the section at all."),
+        ];
+        mark_in_text_citations("As in [3] and [4].", &mut refs, &labels);
+        let cited: Vec<_> = refs.iter().map(|r| r.cited_in_text).collect();
+        // The last reference falls back to its position, 4.
+        assert_eq!(cited, [Some(false), Some(true), Some(true), Some(true)]);
+    }
+}
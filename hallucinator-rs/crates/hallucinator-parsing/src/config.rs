@@ -62,10 +62,21 @@ pub struct ParsingConfig {
     // ── authors.rs ──
     /// Maximum number of authors to retain per reference (default: 15).
     pub(crate) max_authors: usize,
+    /// Strip citation-marker digits glued to author names, e.g. the
+    /// superscript in "Smith1" (default: true).
+    pub(crate) strip_author_superscripts: bool,
 
     // ── text_processing.rs ──
     /// Compound-word suffixes that should preserve the hyphen.
     pub(crate) compound_suffixes: ListOverride<String>,
+    /// Keep a line-break hyphen unless the split is clearly a syllable break
+    /// (default: false, i.e. join when unsure).
+    pub(crate) keep_uncertain_hyphens: bool,
+
+    // ── citations.rs ──
+    /// Scan the text before the reference list for in-text citations and
+    /// fill `Reference::cited_in_text` (default: false).
+    pub(crate) check_in_text_citations: bool,
 
     // ── scoring.rs ──
     /// Weights for the segmentation scoring function.
@@ -85,7 +96,10 @@ impl Default for ParsingConfig {
             quote_patterns: ListOverride::Default,
             min_title_words: 4,
             max_authors: 15,
+            strip_author_superscripts: true,
             compound_suffixes: ListOverride::Default,
+            keep_uncertain_hyphens: false,
+            check_in_text_citations: false,
             scoring_weights: None,
         }
     }
@@ -119,7 +133,10 @@ pub struct ParsingConfigBuilder {
     quote_patterns: ListOverrideBuilder,
     min_title_words: Option<usize>,
     max_authors: Option<usize>,
+    strip_author_superscripts: Option<bool>,
     compound_suffixes: ListOverridePlainBuilder,
+    keep_uncertain_hyphens: Option<bool>,
+    check_in_text_citations: Option<bool>,
     scoring_weights: Option<ScoringWeights>,
 }
 
@@ -222,6 +239,11 @@ impl ParsingConfigBuilder {
         self
     }
 
+    pub fn strip_author_superscripts(mut self, enabled: bool) -> Self {
+        self.strip_author_superscripts = Some(enabled);
+        self
+    }
+
     // ── Compound suffixes ──
 
     pub fn set_compound_suffixes(mut self, suffixes: Vec<String>) -> Self {
@@ -237,6 +259,19 @@ impl ParsingConfigBuilder {
         self
     }
 
+    pub fn keep_uncertain_hyphens(mut self, enabled: bool) -> Self {
+        self.keep_uncertain_hyphens = Some(enabled);
+        self
+    }
+
+    // ── In-text citations ──
+
+    /// Record on each reference whether the document body cites it.
+    pub fn check_in_text_citations(mut self, enabled: bool) -> Self {
+        self.check_in_text_citations = Some(enabled);
+        self
+    }
+
     // ── Scoring weights ──
 
     /// Set custom scoring weights for segmentation strategy selection.
@@ -287,7 +322,10 @@ impl ParsingConfigBuilder {
             quote_patterns: compile_list(self.quote_patterns)?,
             min_title_words: self.min_title_words.unwrap_or(4),
             max_authors: self.max_authors.unwrap_or(15),
+            strip_author_superscripts: self.strip_author_superscripts.unwrap_or(true),
             compound_suffixes: compile_plain(self.compound_suffixes),
+            keep_uncertain_hyphens: self.keep_uncertain_hyphens.unwrap_or(false),
+            check_in_text_citations: self.check_in_text_citations.unwrap_or(false),
             scoring_weights: self.scoring_weights,
         })
     }
@@ -302,6 +340,7 @@ mod tests {
         let config = ParsingConfig::default();
         assert_eq!(config.min_title_words, 4);
         assert_eq!(config.max_authors, 15);
+        assert!(config.strip_author_superscripts);
         assert!((config.fallback_fraction - 0.7).abs() < f64::EPSILON);
     }
 
@@ -3,8 +3,10 @@ use regex::Regex;
 use std::path::Path;
 
 use crate::config::ParsingConfig;
-use crate::{ExtractionResult, ParsingError, PdfBackend, Reference, SkipStats};
-use crate::{authors, identifiers, section, text_processing, title};
+use crate::section::HeadedSection;
+use crate::{ExtractionResult, ParsingError, PdfBackend, Reference, SectionMissing, SkipStats};
+use crate::{authors, citations, identifiers, section, text_processing, title};
+use hallucinator_core::classify_citation;
 
 /// A configurable reference extraction pipeline.
 ///
@@ -59,6 +61,12 @@ impl ReferenceExtractor {
         section::segment_references_with_config(text, &self.config)
     }
 
+    /// Like [`segment_references`](Self::segment_references), but returns each
+    /// reference's exact input lines, labels and line breaks included.
+    pub fn segment_references_raw(&self, text: &str) -> Vec<String> {
+        section::segment_references_raw_with_config(text, &self.config)
+    }
+
     /// Parse a single reference string into a [`Reference`] (step 4).
     ///
     /// `prev_authors` is used for em-dash "same authors" handling.
@@ -74,11 +82,24 @@ impl ReferenceExtractor {
         // Expand typographic ligatures (ﬁ → fi, ﬂ → fl, etc.) early in the pipeline
         // so all downstream steps see clean ASCII text.
         let text = text_processing::expand_ligatures(text);
-        let ref_section = self
-            .find_references_section(&text)
-            .ok_or(ParsingError::NoReferencesSection)?;
+        let headed = section::find_headed_section(&text, &self.config);
+        let ref_section = match &headed {
+            HeadedSection::Found(section) => section.clone(),
+            HeadedSection::Empty | HeadedSection::Missing => {
+                section::unheaded_section(&text, &self.config)
+            }
+        };
 
-        let raw_refs = self.segment_references(&ref_section);
+        let raw_refs = self.segment_references(&ref_section.text);
+        // Without a heading, the fallback tail of the document is only a
+        // reference list if something in it reads like a citation.
+        let fallback_is_prose = !matches!(headed, HeadedSection::Found(_))
+            && !raw_refs.iter().any(|r| looks_like_citation(r));
+        if raw_refs.is_empty() || fallback_is_prose {
+            return Err(ParsingError::NoReferencesSection(missing_section_cause(
+                &text, &headed,
+            )));
+        }
 
         let mut stats = SkipStats {
             total_raw: raw_refs.len(),
@@ -92,9 +113,11 @@ impl ReferenceExtractor {
             let parsed = parse_single_reference(ref_text, &previous_authors, &self.config);
             match parsed {
                 ParsedRef::Skip(reason, raw_citation, title) => {
+                    let kind = classify_citation(&raw_citation);
                     match reason {
                         SkipReason::UrlOnly => stats.url_only += 1,
                         SkipReason::ShortTitle => stats.short_title += 1,
+                        SkipReason::Placeholder => stats.placeholder += 1,
                     }
                     references.push(Reference {
                         raw_citation,
@@ -106,7 +129,12 @@ impl ReferenceExtractor {
                         skip_reason: Some(match reason {
                             SkipReason::UrlOnly => "url_only".to_string(),
                             SkipReason::ShortTitle => "short_title".to_string(),
+                            SkipReason::Placeholder => "placeholder".to_string(),
                         }),
+                        kind,
+                        container_title: None,
+                        cite_key: None,
+                        cited_in_text: None,
                     });
                 }
                 ParsedRef::Ref(mut r) => {
@@ -121,6 +149,11 @@ impl ReferenceExtractor {
             }
         }
 
+        if self.config.check_in_text_citations {
+            let labels = citations::printed_labels(&ref_section.text, &raw_refs);
+            citations::mark_in_text_citations(&text[..ref_section.start], &mut references, &labels);
+        }
+
         Ok(ExtractionResult {
             references,
             skip_stats: stats,
@@ -128,6 +161,23 @@ impl ReferenceExtractor {
     }
 }
 
+/// Minimum number of alphabetic characters for a document to count as having
+/// a text layer. Scanned PDFs typically yield nothing, or only stray page
+/// numbers and headers.
+const MIN_TEXT_LAYER_CHARS: usize = 200;
+
+/// Explain why no references could be segmented from `text`.
+fn missing_section_cause(text: &str, headed: &HeadedSection) -> SectionMissing {
+    let letters = text.chars().filter(|c| c.is_alphabetic()).count();
+    if letters < MIN_TEXT_LAYER_CHARS {
+        SectionMissing::NoTextLayer
+    } else if matches!(headed, HeadedSection::Missing) {
+        SectionMissing::HeadingNotFound
+    } else {
+        SectionMissing::EmptySection
+    }
+}
+
 /// Result of parsing a single reference.
 pub enum ParsedRef {
     Ref(Reference),
@@ -139,6 +189,9 @@ pub enum ParsedRef {
 pub enum SkipReason {
     UrlOnly,
     ShortTitle,
+    /// Unfilled placeholder left in a draft ("[CITATION NEEDED]",
+    /// "TODO: add ref", "Author et al., Year").
+    Placeholder,
 }
 
 /// Parse a single reference string, applying config overrides.
@@ -158,6 +211,13 @@ fn parse_single_reference(
     // Fix hyphenation (config-aware for custom compound suffixes)
     let ref_text = text_processing::fix_hyphenation_with_config(&ref_text, config);
 
+    // Skip unfilled placeholders before they are parsed as bogus references
+    if is_placeholder_citation(&ref_text) {
+        static WS_SKIP_RE0: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+        let raw = WS_SKIP_RE0.replace_all(&ref_text, " ").trim().to_string();
+        return ParsedRef::Skip(SkipReason::Placeholder, raw, None);
+    }
+
     // Skip entries with non-academic URLs
     static URL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"https?\s*:\s*//").unwrap());
     static BROKEN_URL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"ht\s*tps?\s*:\s*//").unwrap());
@@ -228,6 +288,8 @@ fn parse_single_reference(
     let raw_citation = IEEE_PREFIX.replace(&raw_citation, "").to_string();
     static NUM_PREFIX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+\.\s*").unwrap());
     let raw_citation = NUM_PREFIX.replace(&raw_citation, "").to_string();
+    let kind = classify_citation(&raw_citation);
+    let container_title = title::extract_container_title(&raw_citation);
 
     ParsedRef::Ref(Reference {
         raw_citation,
@@ -237,9 +299,36 @@ fn parse_single_reference(
         arxiv_id,
         original_number: 0, // placeholder; overwritten by caller
         skip_reason: None,
+        kind,
+        container_title,
+        cite_key: None,
+        cited_in_text: None,
     })
 }
 
+/// Check whether a reference is a literal placeholder rather than a citation:
+/// bracketed "citation needed" markers, TODO/FIXME/TBD notes, "reference to
+/// be added", or template text such as "Author et al., Year".
+///
+/// Unbracketed markers only count when they are the whole citation, so a
+/// real title like "Citation Needed: A Taxonomy ..." or a volume numbered
+/// XXX is not mistaken for one.
+fn is_placeholder_citation(ref_text: &str) -> bool {
+    static MARKER_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)\[\s*(?:citation|reference|ref|cite)s?(?:\s+needed)?\s*\??\s*\]|^\W*(?:citation|reference|ref)s?\s+(?:needed|to\s+be\s+added|missing)\W*$").unwrap()
+    });
+    // Case-sensitive: uppercase notes and capitalized template words only, so
+    // ordinary prose ("todo lists", "year") does not trigger. XXX is also a
+    // Roman numeral, so it only counts bracketed or on its own.
+    static NOTE_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\b(?:TODO|FIXME|TBD)\b|\[\s*XXX+\s*\]|^\W*XXX+\W*$").unwrap());
+    static TEMPLATE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"\b(?:Author|AUTHOR)s?(?:,?\s+[A-Z]\.)*(?:\s+(?:et\s+al\.?|and\s+Author))?\s*[,.]?\s*\(?(?:Year|YEAR|YYYY|XXXX)\b").unwrap()
+    });
+
+    MARKER_RE.is_match(ref_text) || NOTE_RE.is_match(ref_text) || TEMPLATE_RE.is_match(ref_text)
+}
+
 /// Check whether raw citation text has structural signals of a real reference
 /// (venue markers, author-year patterns, journal metadata) even when the
 /// extracted title is very short.
@@ -268,6 +357,47 @@ mod tests {
 
     // ── ReferenceExtractor with default config ──
 
+    #[test]
+    fn test_extract_no_text_layer() {
+        let ext = ReferenceExtractor::new();
+        // What a scanned PDF typically yields: page numbers and whitespace
+        let text = "\n\n1\n\n\x0c\n2\n\n\x0c\n3\n";
+        assert!(matches!(
+            ext.extract_references_from_text(text),
+            Err(ParsingError::NoReferencesSection(
+                SectionMissing::NoTextLayer
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_extract_heading_not_found() {
+        let ext = ReferenceExtractor::new();
+        let text = "Introduction\n\n".to_string()
+            + &"We study the problem of reference checking in detail and find it hard. ".repeat(20)
+            + "\n\nConclusion\n\nThis is the end of the paper with no bibliography at all.\n";
+        assert!(matches!(
+            ext.extract_references_from_text(&text),
+            Err(ParsingError::NoReferencesSection(
+                SectionMissing::HeadingNotFound
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_extract_empty_section() {
+        let ext = ReferenceExtractor::new();
+        let text = "Introduction\n\n".to_string()
+            + &"We study the problem of reference checking in detail and find it hard. ".repeat(20)
+            + "\n\nReferences\n\n";
+        assert!(matches!(
+            ext.extract_references_from_text(&text),
+            Err(ParsingError::NoReferencesSection(
+                SectionMissing::EmptySection
+            ))
+        ));
+    }
+
     #[test]
     fn test_extractor_default_find_section() {
         let ext = ReferenceExtractor::new();
@@ -330,6 +460,9 @@ mod tests {
         match parsed {
             ParsedRef::Skip(SkipReason::UrlOnly, _, _) => {} // expected
             ParsedRef::Skip(SkipReason::ShortTitle, _, _) => {} // also acceptable
+            ParsedRef::Skip(SkipReason::Placeholder, _, _) => {
+                panic!("URL-only ref should not be a placeholder")
+            }
             ParsedRef::Ref(r) => panic!("URL-only ref should be skipped, got: {:?}", r.title),
         }
 
@@ -345,12 +478,17 @@ mod tests {
     #[test]
     fn test_extractor_no_references_section() {
         let ext = ReferenceExtractor::new();
-        // Very short text with no references header — fallback will kick in but
-        // there won't be meaningful references to parse
+        // Very short text with no references header — fallback kicks in but
+        // there are no meaningful references to parse
         let text = "Short.";
         let result = ext.extract_references_from_text(text);
-        // Fallback returns empty section text, which is still Ok but with 0 refs
-        assert!(result.is_ok());
+        // Too little text to be anything but an image-only document
+        assert!(matches!(
+            result,
+            Err(ParsingError::NoReferencesSection(
+                SectionMissing::NoTextLayer
+            ))
+        ));
     }
 
     // ── Custom config actually takes effect ──
@@ -624,12 +762,87 @@ mod tests {
                 );
             }
             ParsedRef::Ref(_) => panic!("Non-academic URL should be skipped"),
-            ParsedRef::Skip(SkipReason::ShortTitle, _, _) => {
-                panic!("Should be UrlOnly skip, not ShortTitle")
+            ParsedRef::Skip(SkipReason::ShortTitle | SkipReason::Placeholder, _, _) => {
+                panic!("Should be UrlOnly skip")
             }
         }
     }
 
+    // ── Placeholder citations ──
+
+    #[test]
+    fn test_placeholder_forms_skipped() {
+        let ext = ReferenceExtractor::new();
+        for text in [
+            "[CITATION NEEDED]",
+            "[citation needed]",
+            "TODO: add ref for the transformer paper",
+            "Author et al., Year. Title of the paper. Venue.",
+            "Author, A. (YYYY). A study of something important. Journal Name.",
+            "Reference to be added.",
+            "[ref?]",
+            "[XXX]",
+            "XXX.",
+        ] {
+            match ext.parse_reference(text, &[]) {
+                ParsedRef::Skip(SkipReason::Placeholder, raw, title) => {
+                    assert_eq!(raw, text);
+                    assert!(title.is_none());
+                }
+                _ => panic!("{:?} should be skipped as a placeholder", text),
+            }
+        }
+    }
+
+    #[test]
+    fn test_placeholder_does_not_match_real_refs() {
+        let ext = ReferenceExtractor::new();
+        for text in [
+            "A. Vaswani, N. Shazeer, and N. Parmar. Attention is all you need. In Advances in Neural Information Processing Systems, 2017.",
+            "J. Author and K. Smith. Reference counting garbage collection in practice. In Proceedings of PLDI, 2019.",
+            "M. Year. Learning the todo lists of software developers at scale. In ICSE, 2020.",
+            "M. Redi, B. Fetahu, J. Morgan, and D. Taraborelli. Citation Needed: A Taxonomy and Algorithmic Assessment of Wikipedia's Verifiability. In Proceedings of WWW, 2019.",
+            "R. Smith and T. Jones. Scaling sparse attention to long documents. In Proceedings of the XXX International Conference on Machine Learning, 2013.",
+            "P. Brown. The missing references problem in digital libraries. Journal of Documentation, vol. XXX, pp. 1-20, 2004.",
+        ] {
+            assert!(
+                matches!(ext.parse_reference(text, &[]), ParsedRef::Ref(_)),
+                "{:?} should not be a placeholder",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_placeholder_counted_in_skip_stats() {
+        let ext = ReferenceExtractor::new();
+        let text = "Body text.\n\nReferences\n42\n\
+            [1] A. Vaswani, N. Shazeer, and N. Parmar. Attention is all you need. In Advances in Neural Information Processing Systems, 2017.\n\
+            [2] [CITATION NEEDED]\n\
+            [3] Author et al., Year. Title goes here. Venue.\n\
+            [4] K. He, X. Zhang, S. Ren, and J. Sun. Deep residual learning for image recognition. In Proc. CVPR, 2016.\n\
+            [5] J. Devlin, M. Chang, K. Lee, and K. Toutanova. BERT: Pre-training of deep bidirectional transformers for language understanding. In Proc. NAACL, 2019.\n\
+            [6] D. P. Kingma and J. Ba. Adam: A method for stochastic optimization. In Proc. ICLR, 2015.\n";
+        let result = ext.extract_references_from_text(text).unwrap();
+        assert_eq!(result.skip_stats.placeholder, 2);
+        let reasons: Vec<_> = result
+            .references
+            .iter()
+            .map(|r| r.skip_reason.as_deref())
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                None,
+                Some("placeholder"),
+                Some("placeholder"),
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
     #[test]
     fn test_two_word_title_rescued_by_venue() {
         // "Translation-based Recommendation" is 2 words — below min_title_words=4.
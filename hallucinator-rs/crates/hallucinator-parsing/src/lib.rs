@@ -3,29 +3,48 @@ use std::path::Path;
 use thiserror::Error;
 
 pub mod authors;
+pub mod citations;
 pub mod config;
 pub mod extractor;
 pub mod identifiers;
 pub mod scoring;
 pub mod section;
 pub mod text_processing;
+pub mod timeout;
 pub mod title;
 
 pub use config::{ListOverride, ParsingConfig, ParsingConfigBuilder};
 pub use extractor::ReferenceExtractor;
 pub use scoring::{ScoringWeights, score_segmentation, select_best_segmentation};
 pub use section::{SegmentationResult, SegmentationStrategy};
+pub use timeout::TimeoutBackend;
 // Re-export domain types from core (canonical definitions live there)
 pub use hallucinator_core::{BackendError, ExtractionResult, PdfBackend, Reference, SkipStats};
 
 #[derive(Error, Debug)]
 pub enum ParsingError {
-    #[error("no references section found")]
-    NoReferencesSection,
+    #[error("no references found: {0}")]
+    NoReferencesSection(SectionMissing),
     #[error("backend error: {0}")]
     Backend(#[from] hallucinator_core::BackendError),
 }
 
+/// Why no references could be extracted from a document.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionMissing {
+    /// The document has (almost) no extractable text — typically a scanned or
+    /// image-only PDF.
+    #[error("document has no text layer (scanned or image-only PDF?)")]
+    NoTextLayer,
+    /// Text is present, but there is no References/Bibliography heading and the
+    /// end of the document doesn't look like a reference list.
+    #[error("no References/Bibliography heading found")]
+    HeadingNotFound,
+    /// A references heading was found, but no references follow it.
+    #[error("references section is empty")]
+    EmptySection,
+}
+
 /// Extract references from a PDF file using the given backend for text extraction.
 ///
 /// Pipeline:
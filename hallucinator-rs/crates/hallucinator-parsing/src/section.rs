@@ -8,6 +8,10 @@ use crate::config::ParsingConfig;
 pub enum SegmentationStrategy {
     Ieee,
     Numbered,
+    /// `[i]`, `[ii]`, `[iii]`, ...
+    RomanNumeral,
+    /// `[a]`, `[b]`, `[c]`, ...
+    Lettered,
     Aaai,
     Neurips,
     MlFullName,
@@ -21,6 +25,8 @@ impl SegmentationStrategy {
         match self {
             Self::Ieee => 1.0,
             Self::Numbered => 0.95,
+            Self::RomanNumeral => 0.95,
+            Self::Lettered => 0.95,
             Self::Aaai => 0.8,
             Self::Neurips => 0.8,
             Self::MlFullName => 0.8,
@@ -41,7 +47,8 @@ pub struct SegmentationResult {
 ///
 /// Searches for common reference section headers (References, Bibliography, Works Cited)
 /// and returns the text between the header and any end markers (Appendix, Acknowledgments, etc.).
-/// Falls back to the last 30% of the document if no header is found.
+/// If no header is found, looks for the stretch of text densest in
+/// citation-like lines, then falls back to the last 30% of the document.
 pub fn find_references_section(text: &str) -> Option<String> {
     find_references_section_with_config(text, &ParsingConfig::default())
 }
@@ -51,6 +58,34 @@ pub(crate) fn find_references_section_with_config(
     text: &str,
     config: &ParsingConfig,
 ) -> Option<String> {
+    match find_headed_section(text, config) {
+        HeadedSection::Found(section) => Some(section.text),
+        HeadedSection::Empty | HeadedSection::Missing => Some(unheaded_section(text, config).text),
+    }
+}
+
+/// A references section located in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Section {
+    pub(crate) text: String,
+    /// Byte offset in the document where the section, heading included,
+    /// begins; the text before it is the document body.
+    pub(crate) start: usize,
+}
+
+/// Outcome of looking for an explicit references heading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HeadedSection {
+    /// A heading matched and text follows it.
+    Found(Section),
+    /// A heading matched but nothing follows it before an end marker.
+    Empty,
+    /// No heading matched.
+    Missing,
+}
+
+/// Find the section under the last references heading, without falling back.
+pub(crate) fn find_headed_section(text: &str, config: &ParsingConfig) -> HeadedSection {
     static HEADER_RE: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"(?i)\n\s*(?:References|Bibliography|Works\s+Cited)\s*\n").unwrap()
     });
@@ -60,10 +95,24 @@ pub(crate) fn find_references_section_with_config(
     // Use the LAST "References" header, not the first.
     // Some papers have multiple "References" headers (e.g., table headers like
     // "Table 2: References to related work") before the actual reference list.
+    // A header repeated as a running header on continuation pages is the
+    // exception: while the text before the last header is itself a run of
+    // citations, the list started at an earlier one.
     let matches: Vec<_> = header_re.find_iter(text).collect();
-    if let Some(m) = matches.last() {
-        let ref_start = m.end();
-        let rest = &text[ref_start..];
+    if let Some(last) = matches.last() {
+        let mut first = matches.len() - 1;
+        while first > 0 && is_citation_run(&text[matches[first - 1].end()..matches[first].start()])
+        {
+            first -= 1;
+        }
+        let ref_start = matches[first].end();
+        // Drop the repeated headers so they don't end up inside a reference.
+        let rest = if first + 1 < matches.len() {
+            header_re.replace_all(&text[ref_start..], "\n").into_owned()
+        } else {
+            text[last.end()..].to_string()
+        };
+        let rest = rest.as_str();
 
         static END_RE: Lazy<Regex> = Lazy::new(|| {
             // Match common end-of-references markers:
@@ -87,11 +136,116 @@ pub(crate) fn find_references_section_with_config(
         };
 
         let section = &rest[..ref_end];
-        if !section.trim().is_empty() {
-            return Some(section.to_string());
+        if section.trim().is_empty() {
+            return HeadedSection::Empty;
+        }
+        return HeadedSection::Found(Section {
+            text: section.to_string(),
+            start: matches[first].start(),
+        });
+    }
+
+    HeadedSection::Missing
+}
+
+/// Whether a line carries the marks of a bibliography entry: a `[n]` label,
+/// or a year alongside a venue, page range, initials or "et al.".
+fn is_citation_line(line: &str) -> bool {
+    static LABEL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*\[\d{1,3}\]").unwrap());
+    static YEAR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:19|20)\d{2}[a-z]?\b").unwrap());
+    static VENUE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)\b(?:In\s+Proceedings|Proc\.|Conference|Workshop|Symposium|Journal|Transactions|arXiv|preprint|Press|doi)\b").unwrap()
+    });
+    static PAGES_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)\b(?:pp?\.|pages)\s*\d+|\d+\s*[–-]\s*\d+").unwrap());
+    static NAME_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\b[A-Z]\.\s*(?:[A-Z]\.\s*)?[A-Z][a-z]+|\bet\s+al\.").unwrap());
+
+    if LABEL_RE.is_match(line) {
+        return true;
+    }
+    YEAR_RE.is_match(line)
+        && (VENUE_RE.is_match(line) || PAGES_RE.is_match(line) || NAME_RE.is_match(line))
+}
+
+/// Whether `text` (e.g. a page between two running "References" headers)
+/// reads as part of a reference list: at least a third of its lines, and at
+/// least two, look like citations.
+fn is_citation_run(text: &str) -> bool {
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    let citations = lines.iter().filter(|l| is_citation_line(l)).count();
+    citations >= 2 && citations * 3 >= lines.len()
+}
+
+/// Fewest citation-like lines for [`find_dense_section`] to call a stretch
+/// of text a reference list.
+const MIN_DENSE_CITATIONS: usize = 3;
+
+/// Locate an unheaded reference list (e.g. one whose heading was lost with
+/// the page headers) as the stretch of lines densest in citations.
+///
+/// Citation-like lines score +2 and other non-empty lines -1; the section is
+/// the highest-scoring run of lines, widened to the paragraphs its first and
+/// last lines belong to.
+pub(crate) fn find_dense_section(text: &str) -> Option<Section> {
+    let lines: Vec<&str> = text.split('\n').collect();
+
+    let (mut best, mut best_range, mut citations) = (0i64, None, 0);
+    let (mut score, mut start, mut run_citations) = (0i64, 0, 0);
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let cited = is_citation_line(line);
+        if score <= 0 {
+            if !cited {
+                continue;
+            }
+            (score, start, run_citations) = (0, i, 0);
+        }
+        if cited {
+            score += 2;
+            run_citations += 1;
+        } else {
+            score -= 1;
+        }
+        if score > best {
+            (best, best_range, citations) = (score, Some((start, i)), run_citations);
+        }
+    }
+
+    let (mut first, mut last) = best_range.filter(|_| citations >= MIN_DENSE_CITATIONS)?;
+    // A reference's author line often has no year: take in the lines of the
+    // paragraph above the first citation line (and below the last one).
+    for _ in 0..3 {
+        if first > 0 && !lines[first - 1].trim().is_empty() && !is_heading_like(lines[first - 1]) {
+            first -= 1;
+        }
+        if last + 1 < lines.len() && !lines[last + 1].trim().is_empty() {
+            last += 1;
         }
     }
+    Some(Section {
+        text: lines[first..=last].join("\n"),
+        start: lines[..first].iter().map(|line| line.len() + 1).sum(),
+    })
+}
+
+/// A short line without sentence punctuation, like a section heading.
+fn is_heading_like(line: &str) -> bool {
+    let line = line.trim();
+    line.split_whitespace().count() <= 4 && !line.ends_with(['.', ',', ';'])
+}
+
+/// The references section of a document without a usable heading: the
+/// densest run of citations, else the [`fallback_section`].
+pub(crate) fn unheaded_section(text: &str, config: &ParsingConfig) -> Section {
+    find_dense_section(text).unwrap_or_else(|| fallback_section(text, config))
+}
 
+/// The last `1 - fallback_fraction` of the document, used when no heading
+/// delimits a non-empty references section.
+pub(crate) fn fallback_section(text: &str, config: &ParsingConfig) -> Section {
     // Fallback: last N% of document (default 30%, i.e. fraction = 0.7)
     let cutoff = (text.len() as f64 * config.fallback_fraction) as usize;
     // Don't split in the middle of a UTF-8 codepoint
@@ -100,7 +254,10 @@ pub(crate) fn find_references_section_with_config(
         .map(|(i, _)| i)
         .find(|&i| i >= cutoff)
         .unwrap_or(cutoff);
-    Some(text[cutoff..].to_string())
+    Section {
+        text: text[cutoff..].to_string(),
+        start: cutoff,
+    }
 }
 
 /// Strip conference page headers/footers that get embedded in PDF text extraction.
@@ -179,9 +336,10 @@ fn strip_page_headers(text: &str) -> String {
 /// Tries multiple segmentation strategies in order:
 /// 1. IEEE style: `[1]`, `[2]`, etc.
 /// 2. Numbered list: `1.`, `2.`, etc. (sequential starting from 1)
-/// 3. AAAI/ACM author-year with semicolons
-/// 4. Springer/Nature: lines starting with uppercase + `(YYYY)` pattern
-/// 5. Fallback: double-newline splitting
+/// 3. Roman-numeral or lettered brackets: `[i]`, `[ii]` or `[a]`, `[b]`
+/// 4. AAAI/ACM author-year with semicolons
+/// 5. Springer/Nature: lines starting with uppercase + `(YYYY)` pattern
+/// 6. Fallback: double-newline splitting
 pub fn segment_references(ref_text: &str) -> Vec<String> {
     segment_references_with_config(ref_text, &ParsingConfig::default())
 }
@@ -214,6 +372,22 @@ pub fn segment_references_all_strategies(
         });
     }
 
+    // Strategy 2b: Roman-numeral labels
+    if let Some(refs) = try_roman_numeral(ref_text) {
+        results.push(SegmentationResult {
+            strategy: SegmentationStrategy::RomanNumeral,
+            references: refs,
+        });
+    }
+
+    // Strategy 2c: Lettered labels
+    if let Some(refs) = try_lettered(ref_text) {
+        results.push(SegmentationResult {
+            strategy: SegmentationStrategy::Lettered,
+            references: refs,
+        });
+    }
+
     // Strategy 3a: AAAI
     if let Some(refs) = try_aaai(ref_text) {
         results.push(SegmentationResult {
@@ -287,6 +461,63 @@ pub(crate) fn segment_references_with_config(
         .unwrap_or_default()
 }
 
+/// Like [`segment_references`], but each reference is returned as the exact
+/// input lines it came from: labels, line breaks, page headers and trailing
+/// whitespace included. Joined back together the spans reproduce the input
+/// from the first reference onward, which shows exactly where the segmenter
+/// cut.
+///
+/// Spans start at the beginning of the line holding the reference's first
+/// text; references that share a line are cut where the next one's text
+/// starts instead. A reference whose first line can't be found in the input
+/// (e.g. one the segmenter rewrote) stays part of the previous span, and a
+/// warning is logged.
+pub fn segment_references_raw(ref_text: &str) -> Vec<String> {
+    segment_references_raw_with_config(ref_text, &ParsingConfig::default())
+}
+
+/// Config-aware version of [`segment_references_raw`].
+pub(crate) fn segment_references_raw_with_config(
+    ref_text: &str,
+    config: &ParsingConfig,
+) -> Vec<String> {
+    let refs = segment_references_with_config(ref_text, config);
+
+    // Locate each reference's first line in the input, in order
+    let mut starts: Vec<usize> = Vec::with_capacity(refs.len());
+    let mut pos = 0;
+    for reference in &refs {
+        let first_line = reference.lines().next().unwrap_or("").trim();
+        let Some(offset) = (!first_line.is_empty())
+            .then(|| ref_text[pos..].find(first_line))
+            .flatten()
+        else {
+            tracing::warn!(
+                "raw segmentation: reference {:?} not found in the input, merged into the previous span",
+                first_line
+            );
+            continue;
+        };
+        let at = pos + offset;
+        let line_start = ref_text[..at].rfind('\n').map_or(0, |i| i + 1);
+        let start = match starts.last() {
+            Some(&prev) if line_start <= prev => at,
+            _ => line_start,
+        };
+        starts.push(start);
+        pos = at + first_line.len();
+    }
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(ref_text.len());
+            ref_text[start..end].to_string()
+        })
+        .collect()
+}
+
 fn try_ieee_with_config(ref_text: &str, config: &ParsingConfig) -> Option<Vec<String>> {
     // Match [1], [2], etc. at start of string, after newline, period, or closing bracket
     // - Start/newline: standard IEEE format
@@ -384,6 +615,100 @@ fn try_numbered_with_config(ref_text: &str, config: &ParsingConfig) -> Option<Ve
     Some(refs)
 }
 
+fn try_roman_numeral(ref_text: &str) -> Option<Vec<String>> {
+    // Same anchoring as IEEE; one case per label so "[Iv]" isn't accepted
+    static RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?m)(?:^|\n|[.\]0-9])\s*\[([ivxlc]{1,8}|[IVXLC]{1,8})\]\s*").unwrap()
+    });
+    split_on_sequential_labels(ref_text, &RE, roman_to_int)
+}
+
+fn try_lettered(ref_text: &str) -> Option<Vec<String>> {
+    static RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?m)(?:^|\n|[.\]0-9])\s*\[([a-z]|[A-Z])\]\s*").unwrap());
+    split_on_sequential_labels(ref_text, &RE, |label| {
+        let c = label.chars().next()?.to_ascii_lowercase();
+        Some(c as usize - 'a' as usize + 1)
+    })
+}
+
+/// Value of a Roman numeral (`iv` → 4); `None` for malformed numerals like `iiii`.
+fn roman_to_int(label: &str) -> Option<usize> {
+    let digit = |c: char| match c.to_ascii_lowercase() {
+        'i' => Some(1),
+        'v' => Some(5),
+        'x' => Some(10),
+        'l' => Some(50),
+        'c' => Some(100),
+        _ => None,
+    };
+    let digits: Vec<usize> = label.chars().map(digit).collect::<Option<_>>()?;
+    let mut total = 0;
+    for (i, &d) in digits.iter().enumerate() {
+        match digits.get(i + 1) {
+            Some(&next) if next > d => total -= d as isize,
+            _ => total += d as isize,
+        }
+    }
+    let total = usize::try_from(total).ok().filter(|&n| n > 0)?;
+    // Only accept the canonical spelling, so "iiii" or "vx" don't count
+    (int_to_roman(total) == label.to_ascii_lowercase()).then_some(total)
+}
+
+fn int_to_roman(mut n: usize) -> String {
+    const NUMERALS: [(usize, &str); 9] = [
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Split at bracketed labels whose values run 1, 2, 3, ...
+///
+/// Unlike the IEEE strategy, labels that break the sequence are treated as
+/// reference text rather than boundaries: a stray `[c]` or `[v]` inside a
+/// citation would otherwise split it.
+fn split_on_sequential_labels(
+    ref_text: &str,
+    re: &Regex,
+    value: impl Fn(&str) -> Option<usize>,
+) -> Option<Vec<String>> {
+    let mut bounds = Vec::new();
+    for caps in re.captures_iter(ref_text) {
+        let m = caps.get(0)?;
+        if value(&caps[1]) == Some(bounds.len() + 1) {
+            bounds.push((m.start(), m.end()));
+        }
+    }
+    if bounds.len() < 3 {
+        return None;
+    }
+
+    let mut refs = Vec::new();
+    for (i, &(_, start)) in bounds.iter().enumerate() {
+        let end = bounds.get(i + 1).map_or(ref_text.len(), |&(next, _)| next);
+        let content = ref_text[start..end].trim();
+        if !content.is_empty() {
+            refs.push(content.to_string());
+        }
+    }
+    Some(refs)
+}
+
 fn try_aaai(ref_text: &str) -> Option<Vec<String>> {
     // Surname chars: ASCII letters + common diacritics (Latin Extended)
     let sc = r"[a-zA-Z\u{00C0}-\u{024F}\u{00E4}\u{00F6}\u{00FC}\u{00DF}\u{00E8}\u{00E9}]";
@@ -623,13 +948,56 @@ fn fallback_double_newline_with_config(ref_text: &str, config: &ParsingConfig) -
     static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n\s*\n").unwrap());
 
     let re = config.fallback_segment_re.as_ref().unwrap_or(&RE);
-    re.split(ref_text)
+    let paragraphs = re
+        .split(ref_text)
         .map(|p| p.trim())
-        .filter(|p| !p.is_empty() && p.len() > 20)
+        .filter(|p| !p.is_empty())
         .map(|p| p.to_string())
+        .collect();
+    // Merge before the length filter: the tail of a reference carried over to
+    // the next page is often shorter than a reference.
+    merge_page_break_fragments(paragraphs)
+        .into_iter()
+        .filter(|p| p.len() > 20)
         .collect()
 }
 
+/// Rejoin references that a page break cut in two.
+///
+/// Backends join pages with a newline, so a reference running off the bottom
+/// of a page continues after a blank line (often with the page number in
+/// between), and paragraph splitting breaks it there. A fragment is merged
+/// into the next one when it can't be a whole reference on its own (no
+/// terminal period, no year, not ending in a URL) and the next one doesn't
+/// open with a reference label.
+fn merge_page_break_fragments(paragraphs: Vec<String>) -> Vec<String> {
+    static YEAR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:19|20)\d{2}[a-z]?\b").unwrap());
+    static LABEL_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^(?:\[[^\]\s]{1,8}\]|\d{1,3}\.\s)").unwrap());
+    static PAGE_NUMBER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n\s*\d{1,3}\s*$").unwrap());
+
+    let is_fragment = |p: &str| {
+        let last_word = p.split_whitespace().next_back().unwrap_or("");
+        !p.ends_with('.')
+            && !YEAR_RE.is_match(p)
+            && !last_word.starts_with("http")
+            && !last_word.contains("doi.org")
+    };
+
+    let mut merged: Vec<String> = Vec::with_capacity(paragraphs.len());
+    for paragraph in paragraphs {
+        if let Some(prev) = merged.last_mut() {
+            let body = PAGE_NUMBER_RE.replace(prev, "");
+            if is_fragment(&body) && !LABEL_RE.is_match(&paragraph) {
+                *prev = format!("{}\n{}", body, paragraph);
+                continue;
+            }
+        }
+        merged.push(paragraph);
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -657,17 +1025,24 @@ mod tests {
         let section = find_references_section(text).unwrap();
         assert!(section.contains("[1] Ref one."));
         assert!(section.contains("[2] Ref two."));
-        assert!(!section.contains("ANALYSIS"), "Should truncate at A\\nANALYSIS boundary");
+        assert!(
+            !section.contains("ANALYSIS"),
+            "Should truncate at A\\nANALYSIS boundary"
+        );
         assert!(!section.contains("Math stuff"));
     }
 
     #[test]
     fn test_find_references_section_with_detailed_appendix() {
         // Test "A\nDetailed" pattern
-        let text = "Body.\n\nReferences\n\n[1] Ref one.\n\nA\nDetailed Benchmark Results\n\nTables here.";
+        let text =
+            "Body.\n\nReferences\n\n[1] Ref one.\n\nA\nDetailed Benchmark Results\n\nTables here.";
         let section = find_references_section(text).unwrap();
         assert!(section.contains("[1] Ref one."));
-        assert!(!section.contains("Detailed"), "Should truncate at A\\nDetailed boundary");
+        assert!(
+            !section.contains("Detailed"),
+            "Should truncate at A\\nDetailed boundary"
+        );
         assert!(!section.contains("Tables here"));
     }
 
@@ -688,6 +1063,49 @@ mod tests {
         assert!(refs[0].starts_with("First"));
     }
 
+    #[test]
+    fn test_roman_to_int() {
+        assert_eq!(roman_to_int("i"), Some(1));
+        assert_eq!(roman_to_int("iv"), Some(4));
+        assert_eq!(roman_to_int("XIV"), Some(14));
+        assert_eq!(roman_to_int("xcix"), Some(99));
+        assert_eq!(roman_to_int("iiii"), None);
+        assert_eq!(roman_to_int("vx"), None);
+    }
+
+    #[test]
+    fn test_segment_lettered_needs_sequence_from_a() {
+        // Starting at [b] is more likely stray markers than a bibliography
+        let text = "[b] First reference text here.\n[c] Second reference text here.\n[d] Third reference.\n";
+        assert!(try_lettered(text).is_none());
+    }
+
+    #[test]
+    fn test_segment_raw_round_trips_input_lines() {
+        let text = "[1] A. Author, \"First paper title here,\" in\n    Proc. Conf., 2020.\n\
+                    [2] B. Author, \"Second paper title,\" Journal,\n    vol. 3, 2021.\n\
+                    [3] C. Author, \"Third paper title here,\" 2022.\n";
+        let raw = segment_references_raw(text);
+        assert_eq!(raw.len(), 3);
+        assert!(raw[0].starts_with("[1] A. Author"));
+        assert!(raw[1].contains("Journal,\n    vol. 3"));
+        assert_eq!(
+            raw.concat().lines().collect::<Vec<_>>(),
+            text.lines().collect::<Vec<_>>()
+        );
+        // The default variant still strips labels
+        assert!(segment_references(text)[0].starts_with("A. Author"));
+    }
+
+    #[test]
+    fn test_segment_raw_same_line_references() {
+        let text =
+            "[1] First reference text here. [2] Second reference text here. [3] Third reference.";
+        let raw = segment_references_raw(text);
+        assert_eq!(raw.len(), 3);
+        assert_eq!(raw.concat(), text);
+    }
+
     #[test]
     fn test_segment_fallback() {
         let text = "This is a long enough reference paragraph one.\n\nThis is a long enough reference paragraph two.\n\nShort.\n\nThis is a long enough reference paragraph three.";
@@ -695,6 +1113,25 @@ mod tests {
         assert_eq!(refs.len(), 3); // "Short." is filtered out (len <= 20)
     }
 
+    #[test]
+    fn test_merge_page_break_fragments() {
+        let paragraphs = vec![
+            "A. Author. A complete reference title. In Proc. Conf., 2020.".to_string(),
+            "B. Author. A reference cut off at the bottom of the\n7".to_string(),
+            "page, in Proc. Conf., 2021.".to_string(),
+            "C. Author. Ends without period or year".to_string(),
+            "[4] D. Author. A labeled reference. 2022.".to_string(),
+        ];
+        let merged = merge_page_break_fragments(paragraphs);
+        assert_eq!(merged.len(), 4);
+        assert_eq!(
+            merged[1],
+            "B. Author. A reference cut off at the bottom of the\npage, in Proc. Conf., 2021."
+        );
+        // A following label always starts a new reference
+        assert!(merged[3].starts_with("[4]"));
+    }
+
     #[test]
     fn test_find_references_bibliography() {
         let text = "Body.\n\nBibliography\n\nSome refs here.\n";
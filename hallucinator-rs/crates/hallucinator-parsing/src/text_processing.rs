@@ -50,6 +50,15 @@ pub(crate) static COMPOUND_SUFFIXES: Lazy<HashSet<&'static str>> = Lazy::new(||
     .collect()
 });
 
+/// Word endings that only occur as the tail of a syllable break, never as
+/// the second half of a compound ("informa-tion", "Mod-els").
+const SYLLABLE_SUFFIXES: &[&str] = &[
+    "tion", "tions", "sion", "sions", "cient", "cients", "curity", "rity", "lity", "nity", "els",
+    "ness", "ment", "ments", "ance", "ence", "ency", "ity", "ing", "ings", "ism", "isms", "ist",
+    "ists", "ble", "able", "ible", "ure", "ures", "age", "ages", "ous", "ive", "ical", "ally",
+    "ular", "ology", "ization", "ised", "ized", "ises", "izes", "uous",
+];
+
 /// Expand common typographic ligatures found in PDFs.
 pub fn expand_ligatures(text: &str) -> String {
     text.replace('\u{FB00}', "ff")
@@ -64,6 +73,12 @@ pub fn expand_ligatures(text: &str) -> String {
 ///
 /// - `"detec- tion"` or `"detec-\ntion"` → `"detection"` (syllable break)
 /// - `"human- centered"` → `"human-centered"` (compound word)
+/// - `"state-\nof-the-art"` → `"state-of-the-art"` (multi-part compound)
+///
+/// Splits that are neither a known compound nor clearly a syllable break are
+/// joined unless [`ParsingConfigBuilder::keep_uncertain_hyphens`] is set.
+///
+/// [`ParsingConfigBuilder::keep_uncertain_hyphens`]: crate::ParsingConfigBuilder::keep_uncertain_hyphens
 pub fn fix_hyphenation(text: &str) -> String {
     fix_hyphenation_with_config(text, &ParsingConfig::default())
 }
@@ -71,8 +86,8 @@ pub fn fix_hyphenation(text: &str) -> String {
 /// Config-aware version of [`fix_hyphenation`].
 pub(crate) fn fix_hyphenation_with_config(text: &str, config: &ParsingConfig) -> String {
     static RE: Lazy<Regex> = Lazy::new(|| {
-        // Match: word-char, hyphen, whitespace (including newlines), then word chars
-        Regex::new(r"(\w)-\s+(\w)(\w*)").unwrap()
+        // Match: word, hyphen, whitespace (including newlines), then word chars
+        Regex::new(r"(\w+)-(\s+)(\w)(\w*)").unwrap()
     });
 
     // Second pattern: handle hyphenation without space (PDF extraction artifact)
@@ -81,7 +96,11 @@ pub(crate) fn fix_hyphenation_with_config(text: &str, config: &ParsingConfig) ->
         // Match: lowercase letter, hyphen (no space), then common syllable suffixes,
         // followed by punctuation, space, or end of string
         // NOTE: rust regex doesn't support look-ahead, so we capture the trailing char too
-        Regex::new(r"(?i)([a-z])-(tion|tions|sion|sions|cient|cients|curity|rity|lity|nity|els|ness|ment|ments|ance|ence|ency|ity|ing|ings|ism|isms|ist|ists|ble|able|ible|ure|ures|age|ages|ous|ive|ical|ally|ular|ology|ization|ised|ized|ises|izes|uous)([.\s,;:?!]|$)").unwrap()
+        Regex::new(&format!(
+            r"(?i)([a-z])-({})([.\s,;:?!]|$)",
+            SYLLABLE_SUFFIXES.join("|")
+        ))
+        .unwrap()
     });
 
     // Resolve compound suffixes: convert defaults to owned Strings for uniform handling
@@ -92,8 +111,10 @@ pub(crate) fn fix_hyphenation_with_config(text: &str, config: &ParsingConfig) ->
     let result = RE
         .replace_all(text, |caps: &regex::Captures| {
             let before = &caps[1];
-            let after_char = &caps[2];
-            let after_rest = &caps[3];
+            let gap = &caps[2];
+            let after_char = &caps[3];
+            let after_rest = &caps[4];
+            let whole = caps.get(0).unwrap();
 
             let after_word = format!("{}{}", after_char, after_rest);
             let after_lower = after_word.to_lowercase();
@@ -121,6 +142,21 @@ pub(crate) fn fix_hyphenation_with_config(text: &str, config: &ParsingConfig) ->
                 return format!("{}-{}", before, after_word);
             }
 
+            // Part of a multi-part compound: "state-\nof-the-art" (the right side
+            // continues with another hyphen) or "state-of-the-\nart" (the left
+            // side follows one, and the right side is too short for a break).
+            let continues_compound = text[whole.end()..].starts_with('-');
+            let follows_compound = text[..whole.start()].ends_with('-');
+            if continues_compound || (follows_compound && after_word.chars().count() <= 3) {
+                return format!("{}-{}", before, after_word);
+            }
+
+            // TeX never leaves fewer than three letters after a break, so a
+            // one- or two-letter right side is a real word: "state- of".
+            if after_word.chars().count() < 3 {
+                return format!("{}-{}", before, after_word);
+            }
+
             // If the word after the hyphen is a small connector word starting with uppercase,
             // it's likely a compound proper noun (e.g., "Over-The-Air", "Up-To-Date").
             // But if it's a longer word starting with uppercase (like "Bridge" in "Base-Bridge"),
@@ -137,7 +173,15 @@ pub(crate) fn fix_hyphenation_with_config(text: &str, config: &ParsingConfig) ->
                 return format!("{}-{}", before, after_word);
             }
 
-            // Otherwise, it's likely a syllable break — remove hyphen
+            // Otherwise, it's likely a syllable break. It is certain when a
+            // lowercase tail follows a line-end hyphen or is a suffix no
+            // compound ends with; anything else may be a compound we don't know.
+            let lowercase_tail = after_char.chars().all(char::is_lowercase);
+            let syllable_break = lowercase_tail
+                && (gap.contains('\n') || SYLLABLE_SUFFIXES.contains(&after_lower.as_str()));
+            if config.keep_uncertain_hyphens && !syllable_break {
+                return format!("{}-{}", before, after_word);
+            }
             format!("{}{}", before, after_word)
         })
         .into_owned();
@@ -243,6 +287,54 @@ mod tests {
         assert_eq!(fix_hyphenation("classi-\nfication"), "classification");
     }
 
+    #[test]
+    fn test_fix_hyphenation_multi_part_compound() {
+        assert_eq!(fix_hyphenation("informa-\ntion"), "information");
+        assert_eq!(fix_hyphenation("state-\nof-the-art"), "state-of-the-art");
+        assert_eq!(fix_hyphenation("state-of-\nthe-art"), "state-of-the-art");
+        assert_eq!(fix_hyphenation("state-of-the-\nart"), "state-of-the-art");
+        assert_eq!(fix_hyphenation("end-\nto-end"), "end-to-end");
+        // A break inside an already-hyphenated compound is still a break
+        assert_eq!(fix_hyphenation("self-super-\nvised"), "self-supervised");
+    }
+
+    #[test]
+    fn test_fix_hyphenation_short_right_side_is_a_word() {
+        assert_eq!(fix_hyphenation("state- of"), "state-of");
+        assert_eq!(fix_hyphenation("follow-\nup work"), "follow-up work");
+    }
+
+    #[test]
+    fn test_fix_hyphenation_keep_uncertain() {
+        use crate::ParsingConfigBuilder;
+        let config = ParsingConfigBuilder::new()
+            .keep_uncertain_hyphens(true)
+            .build()
+            .unwrap();
+        // Clear syllable breaks are still joined
+        assert_eq!(
+            fix_hyphenation_with_config("informa-\ntion", &config),
+            "information"
+        );
+        assert_eq!(
+            fix_hyphenation_with_config("detec- tion", &config),
+            "detection"
+        );
+        // An unknown compound on one line keeps its hyphen...
+        assert_eq!(
+            fix_hyphenation_with_config("privacy- preserving", &config),
+            "privacy-preserving"
+        );
+        // ...and so does a capitalized right side
+        assert_eq!(
+            fix_hyphenation_with_config("Base-\nBridge", &config),
+            "Base-Bridge"
+        );
+        // Default config joins both
+        assert_eq!(fix_hyphenation("privacy- preserving"), "privacypreserving");
+        assert_eq!(fix_hyphenation("Base-\nBridge"), "BaseBridge");
+    }
+
     #[test]
     fn test_fix_hyphenation_camelcase() {
         // Issue #169: CamelCase words broken across lines should have hyphen removed
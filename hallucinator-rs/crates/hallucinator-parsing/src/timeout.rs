@@ -0,0 +1,52 @@
+//! Per-file deadline for PDF text extraction.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use crate::{BackendError, PdfBackend};
+
+/// A [`PdfBackend`] that gives up on a file after `timeout`.
+///
+/// Extraction runs on its own thread; if it misses the deadline,
+/// [`BackendError::Timeout`] is returned so the caller can move on. The thread
+/// cannot be interrupted and is left to finish (or spin) in the background.
+pub struct TimeoutBackend<B> {
+    inner: Arc<B>,
+    timeout: Duration,
+}
+
+impl<B: PdfBackend + 'static> TimeoutBackend<B> {
+    pub fn new(inner: B, timeout: Duration) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            timeout,
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<B: PdfBackend + 'static> PdfBackend for TimeoutBackend<B> {
+    fn extract_text(&self, path: &Path) -> Result<String, BackendError> {
+        let (tx, rx) = mpsc::channel();
+        let inner = self.inner.clone();
+        let path = path.to_path_buf();
+        std::thread::Builder::new()
+            .name("pdf-extract".into())
+            .spawn(move || {
+                let _ = tx.send(inner.extract_text(&path));
+            })?;
+
+        match rx.recv_timeout(self.timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(BackendError::Timeout(self.timeout)),
+            Err(RecvTimeoutError::Disconnected) => Err(BackendError::ExtractionError(
+                "extraction thread panicked".into(),
+            )),
+        }
+    }
+}
@@ -228,6 +228,10 @@ pub(crate) fn clean_title_with_config(
         title = title[..=qmark_pos].to_string();
     }
 
+    // Handle an accidental trailing "In Proceedings ..." / "In Proc. ..." / "In <venue>"
+    // clause after a sentence-final boundary (covers "!" and quoted titles too)
+    title = strip_trailing_in_venue(&title);
+
     // Handle "? JournalName, vol(issue)" — journal name bleeding after question mark
     static QMARK_JOURNAL_RE: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"[?!]\s+[A-Z][a-zA-Z\s&+\u{00AE}\u{2013}\u{2014}\-]+,\s*(?:vol\.?\s*)?\d+")
@@ -430,6 +434,34 @@ pub(crate) fn clean_title_with_config(
 
 // ───────────────── Format-specific extractors ─────────────────
 
+/// Extract the book title of a chapter in an edited collection:
+/// `Author. Chapter. In: A. Editor (ed.), Book Title. Publisher, 2020.` → `Book Title`.
+///
+/// Only "In" clauses that name editors count, so a plain "In: Proceedings of
+/// ..." venue is not mistaken for a container.
+pub fn extract_container_title(ref_text: &str) -> Option<String> {
+    static WS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+    static IN_CLAUSE_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"(?:^|[\s.,;'"\u{2019}\u{201d}])In:?\s"#).unwrap());
+    static EDITOR_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"\((?:[Ee]ds?|[Ee]ditors?|Hrsg)\.?\)|\b[Ee]ds?\.(?:,|\s)|\b[Ee]ditors?\b|\bHrsg\.",
+        )
+        .unwrap()
+    });
+    static BOOK_END_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"\.\s|\.$|,\s*(?:pp?\.|[Vv]ol\.|[Cc]h(?:ap)?\.|(?:19|20)\d{2})|\s\(").unwrap()
+    });
+
+    let text = WS_RE.replace_all(ref_text, " ");
+    let clause = &text[IN_CLAUSE_RE.find(&text)?.end()..];
+    let rest = &clause[EDITOR_RE.find(clause)?.end()..];
+    let rest = rest.trim_start_matches([',', ':', ' ']);
+    let end = BOOK_END_RE.find(rest).map_or(rest.len(), |m| m.start());
+    let book = rest[..end].trim().trim_end_matches([',', ';']);
+    (book.split_whitespace().count() >= 2).then(|| book.to_string())
+}
+
 fn try_quoted_title_with_config(ref_text: &str, config: &ParsingConfig) -> Option<(String, bool)> {
     // First, try greedy IEEE pattern for titles with nested/inner quotes.
     // Matches from first " to last ," (IEEE convention: title ends with comma inside quotes)
@@ -452,7 +484,7 @@ fn try_quoted_title_with_config(ref_text: &str, config: &ParsingConfig) -> Optio
             Regex::new(r"[\u{2018}]([^\u{2018}\u{2019}]{10,})[\u{2019}]").unwrap(),
             // Plain single quotes (Harvard/APA style): require ') ' or similar delimiter
             // to avoid matching possessive apostrophes
-            Regex::new(r"(?:^|[\s(])'([^']{10,})'(?:\s*[,.]|\s*$)").unwrap(),
+            Regex::new(r"(?:^|[\s(])'([^']{10,})'(?:\s*[,.]|\s*$|\s+In:)").unwrap(),
         ]
     });
 
@@ -471,6 +503,18 @@ fn try_quoted_title_with_config(ref_text: &str, config: &ParsingConfig) -> Optio
                 continue;
             }
 
+            // Chapter in an edited collection: "Chapter." In: Editor (ed.), Book.
+            // The quoted part is the chapter; the book title is not a subtitle.
+            static IN_COLLECTION: Lazy<Regex> =
+                Lazy::new(|| Regex::new(r"^[.,;]?\s*In:\s").unwrap());
+            // (the plain single-quote pattern consumes the "In:" itself)
+            let in_collection = IN_COLLECTION.is_match(after_quote)
+                || caps.get(0).unwrap().as_str().ends_with("In:");
+            if in_collection && quoted_part.split_whitespace().count() >= 2 {
+                let chapter = quoted_part.trim_end_matches(['.', ',']);
+                return Some((chapter.to_string(), true));
+            }
+
             // IEEE: comma inside quotes means title is complete
             // Accept 2+ words for quoted titles (quotes are a strong indicator)
             if quoted_part.ends_with(',') {
@@ -2137,6 +2181,33 @@ pub(crate) static DEFAULT_CUTOFF_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
     ]
 });
 
+/// Strip a trailing "In Proceedings/In Proc./In <venue>" clause that follows a
+/// sentence-final `.`, `?` or `!` (optionally closed by a quote).
+///
+/// Only recognizable venue starts are stripped ("In Search of ..." is kept), and
+/// at least two words must remain so a short real title is never truncated.
+fn strip_trailing_in_venue(title: &str) -> String {
+    static TRAILING_IN_VENUE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r#"([.?!])["'\u{201D}]?\s+[Ii]n:?\s+(?:Proceedings\b|Proc\.|Advances\s+in\b|(?:the\s+)?(?:\d+(?:st|nd|rd|th)\s+)?(?:(?:Annual|International)\s+)*(?:Conference|Workshop|Symposium)\b|(?:IEEE|ACM|USENIX|AAAI|IJCAI|NeurIPS|NIPS|ICML|ICLR|CVPR|ICCV|ECCV|ACL|EMNLP|NAACL)\b|(?:19|20)\d{2}\b).*$"#,
+        )
+        .unwrap()
+    });
+    let Some(caps) = TRAILING_IN_VENUE_RE.captures(title) else {
+        return title.to_string();
+    };
+    let boundary = caps.get(1).unwrap();
+    let kept = if boundary.as_str() == "." {
+        &title[..boundary.start()]
+    } else {
+        &title[..boundary.end()]
+    };
+    if kept.split_whitespace().count() < 2 {
+        return title.to_string();
+    }
+    kept.trim_end().to_string()
+}
+
 fn apply_cutoff_patterns_with_config(title: &str, config: &ParsingConfig) -> String {
     let patterns = config
         .venue_cutoff_patterns
@@ -3334,6 +3405,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trailing_in_venue_after_exclamation() {
+        let cleaned = clean_title(
+            "Do Androids Dream of Electric Sheep! In Proceedings of the 2021 ACM Conference on Fiction",
+            true,
+        );
+        assert_eq!(cleaned, "Do Androids Dream of Electric Sheep!");
+    }
+
+    #[test]
+    fn test_trailing_in_venue_after_quoted_period() {
+        let cleaned = clean_title(
+            "Scaling Laws for Neural Language Models.\u{201D} In 34th Annual Conference on Neural Information Processing Systems",
+            true,
+        );
+        assert_eq!(cleaned, "Scaling Laws for Neural Language Models");
+    }
+
+    #[test]
+    fn test_trailing_in_venue_keeps_real_title() {
+        let title = "Attention! In Search of Robust Features for Tracking";
+        assert_eq!(clean_title(title, true), title);
+        assert_eq!(
+            strip_trailing_in_venue("Wow! In Proceedings of Something"),
+            "Wow! In Proceedings of Something"
+        );
+    }
+
     #[test]
     fn test_title_not_in_proceedings() {
         // Issue: "In Proceedings..." becomes the title instead of the actual title
@@ -3504,6 +3603,52 @@ mod tests {
         let cleaned = clean_title(title, false);
         assert_eq!(cleaned, "", "DOI URL should be rejected as title");
     }
+
+    #[test]
+    fn test_in_collection_prefers_quoted_chapter() {
+        let cases = [
+            "Smith, J. 'Reference Checking at Scale.' In: Brown, A. (ed.), Handbook of Scholarly Integrity. Springer, 2020.",
+            "Smith, J. \"Reference Checking at Scale.\" In: Brown, A. (ed.), Handbook of Scholarly Integrity. Springer, 2020.",
+            "Smith, J. (2020). \u{2018}Reference Checking at Scale\u{2019}. In: A. Brown (ed.), Handbook of Scholarly Integrity. London: Routledge, pp. 12\u{2013}30.",
+        ];
+        for ref_text in cases {
+            let (title, from_quotes) = extract_title_from_reference(ref_text);
+            assert_eq!(title, "Reference Checking at Scale", "{}", ref_text);
+            assert!(from_quotes);
+            assert_eq!(
+                extract_container_title(ref_text).as_deref(),
+                Some("Handbook of Scholarly Integrity"),
+                "{}",
+                ref_text
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_collection_unquoted_springer() {
+        let ref_text = "Smith, J.: Reference checking at scale. In: Brown, A. (ed.) Handbook of Scholarly Integrity, pp. 1\u{2013}20. Springer, Cham (2020)";
+        let (title, _) = extract_title_from_reference(ref_text);
+        assert_eq!(title, "Reference checking at scale");
+        assert_eq!(
+            extract_container_title(ref_text).as_deref(),
+            Some("Handbook of Scholarly Integrity")
+        );
+    }
+
+    #[test]
+    fn test_container_title_requires_editors() {
+        // A proceedings venue is not an edited-collection container
+        let ref_text = "Smith, J.: Reference checking at scale. In: Proceedings of the Web Conference, pp. 1\u{2013}10 (2020)";
+        assert_eq!(extract_container_title(ref_text), None);
+        let ref_text = "J. Smith, \"Reference checking at scale,\" in Proc. IEEE S&P, 2020.";
+        assert_eq!(extract_container_title(ref_text), None);
+        // Multiple editors, "(eds.)"
+        let ref_text = "Smith, J.: Reference checking. In: Brown, A., Green, B. (eds.) Advances in Integrity Research. LNCS, vol. 1234. Springer (2021)";
+        assert_eq!(
+            extract_container_title(ref_text).as_deref(),
+            Some("Advances in Integrity Research")
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,48 @@
+//! `TimeoutBackend` abandons a backend that runs past its deadline.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use hallucinator_parsing::{
+    BackendError, ParsingError, PdfBackend, TimeoutBackend, extract_references,
+};
+
+/// Stands in for MuPDF spinning on a malformed PDF.
+struct SlowBackend(Duration);
+
+impl PdfBackend for SlowBackend {
+    fn extract_text(&self, _path: &Path) -> Result<String, BackendError> {
+        std::thread::sleep(self.0);
+        Ok("References\n[1] A. Author. A title. 2020.\n".into())
+    }
+}
+
+#[test]
+fn slow_backend_times_out() {
+    let backend = TimeoutBackend::new(
+        SlowBackend(Duration::from_secs(5)),
+        Duration::from_millis(50),
+    );
+
+    let start = Instant::now();
+    let result = extract_references(Path::new("hang.pdf"), &backend);
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(
+        matches!(
+            result,
+            Err(ParsingError::Backend(BackendError::Timeout(t))) if t == Duration::from_millis(50)
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn fast_backend_is_unaffected() {
+    let backend = TimeoutBackend::new(SlowBackend(Duration::ZERO), Duration::from_secs(5));
+    assert!(
+        backend
+            .extract_text(Path::new("ok.pdf"))
+            .unwrap()
+            .starts_with("References")
+    );
+}
@@ -0,0 +1,48 @@
+//! With `check_in_text_citations`, each reference records whether the body
+//! of the document cites it, so one that is listed but never cited stands
+//! out.
+
+use hallucinator_parsing::{ParsingConfigBuilder, ReferenceExtractor};
+
+const PAPER: &str = include_str!("fixtures/uncited_reference.txt");
+
+fn extractor(check_in_text_citations: bool) -> ReferenceExtractor {
+    let config = ParsingConfigBuilder::new()
+        .check_in_text_citations(check_in_text_citations)
+        .build()
+        .unwrap();
+    ReferenceExtractor::with_config(config)
+}
+
+#[test]
+fn listed_but_never_cited_reference_is_flagged() {
+    let result = extractor(true).extract_references_from_text(PAPER).unwrap();
+    let cited: Vec<_> = result
+        .references
+        .iter()
+        .map(|r| (r.original_number, r.cited_in_text))
+        .collect();
+    assert_eq!(
+        cited,
+        [
+            (1, Some(true)),
+            (2, Some(false)),
+            (3, Some(true)),
+            (4, Some(true)),
+            (5, Some(true)),
+        ]
+    );
+    assert_eq!(
+        result.references[1].title.as_deref(),
+        Some("Towards evaluating the robustness of neural networks")
+    );
+}
+
+#[test]
+fn citations_are_not_checked_by_default() {
+    let result = extractor(false)
+        .extract_references_from_text(PAPER)
+        .unwrap();
+    assert_eq!(result.references.len(), 5);
+    assert!(result.references.iter().all(|r| r.cited_in_text.is_none()));
+}
@@ -0,0 +1,61 @@
+//! Segmentation of bibliographies labeled with Roman numerals (`[i]`, `[ii]`)
+//! or letters (`[a]`, `[b]`) instead of `[1]`, `[2]`.
+
+use hallucinator_parsing::section::{
+    SegmentationStrategy, segment_references, segment_references_all_strategies,
+};
+use hallucinator_parsing::{ParsingConfig, ScoringWeights, select_best_segmentation};
+
+const ROMAN: &str = include_str!("fixtures/roman_numeral_refs.txt");
+const LETTERED: &str = include_str!("fixtures/lettered_refs.txt");
+
+fn best_strategy(text: &str) -> SegmentationStrategy {
+    let config = ParsingConfig::default();
+    let results = segment_references_all_strategies(text, &config);
+    select_best_segmentation(results, text, &config, &ScoringWeights::default())
+        .unwrap()
+        .strategy
+}
+
+#[test]
+fn roman_numeral_labels_split_into_separate_references() {
+    let refs = segment_references(ROMAN);
+    assert_eq!(refs.len(), 10);
+    assert!(refs[0].starts_with("A. Krizhevsky"));
+    assert!(refs[3].starts_with("J. Devlin"));
+    assert!(refs[9].starts_with("O. Ronneberger"));
+    // Labels are stripped, not left at the start of the next reference
+    assert!(refs.iter().all(|r| !r.starts_with('[')));
+    assert_eq!(best_strategy(ROMAN), SegmentationStrategy::RomanNumeral);
+}
+
+#[test]
+fn roman_numeral_labels_without_line_breaks() {
+    // Some PDF extractions lose newlines; labels then follow the previous
+    // reference's closing period.
+    let flattened = ROMAN.replace('\n', " ");
+    let refs = segment_references(&flattened);
+    assert_eq!(refs.len(), 10);
+    assert!(refs[6].starts_with("S. Hochreiter"));
+}
+
+#[test]
+fn lettered_labels_split_into_separate_references() {
+    let refs = segment_references(LETTERED);
+    assert_eq!(refs.len(), 5);
+    assert!(refs[0].starts_with("M. Abadi"));
+    assert!(refs[2].starts_with("N. Papernot"));
+    assert!(refs[4].starts_with("C. Dwork"));
+    assert_eq!(best_strategy(LETTERED), SegmentationStrategy::Lettered);
+}
+
+#[test]
+fn out_of_sequence_labels_are_not_boundaries() {
+    // "[c]" inside a reference is not the next label after "[ii]"
+    let text = "[i] A. Author, \"A first paper title here,\" in Proc. Conf., 2020.\n\
+                [ii] B. Author, \"A second paper with a [c] marker,\" in Proc. Conf., 2021.\n\
+                [iii] C. Author, \"A third paper title here,\" in Proc. Conf., 2022.\n";
+    let refs = segment_references(text);
+    assert_eq!(refs.len(), 3);
+    assert!(refs[1].contains("[c] marker"));
+}
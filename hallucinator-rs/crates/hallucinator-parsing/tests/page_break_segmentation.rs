@@ -0,0 +1,28 @@
+//! A reference that runs off the bottom of one page and continues at the top
+//! of the next must come out as one reference, not two fragments.
+
+use hallucinator_parsing::ReferenceExtractor;
+
+const PAGE_BREAK: &str = include_str!("fixtures/page_break_refs.txt");
+
+#[test]
+fn reference_crossing_page_break_is_rejoined() {
+    let result = ReferenceExtractor::new()
+        .extract_references_from_text(PAGE_BREAK)
+        .unwrap();
+    assert_eq!(result.skip_stats.total_raw, 5);
+
+    let split = result
+        .references
+        .iter()
+        .find(|r| r.raw_citation.contains("Deep residual learning"))
+        .unwrap();
+    assert!(split.raw_citation.contains("Pattern Recognition"));
+    // The page number between the two halves is dropped
+    assert!(!split.raw_citation.contains("14"));
+    assert_eq!(
+        split.title.as_deref(),
+        Some("Deep residual learning for image recognition")
+    );
+    assert_eq!(split.authors.len(), 4);
+}
@@ -0,0 +1,57 @@
+//! A reference list whose "References" heading was lost (e.g. stripped along
+//! with the page header it sat in) is still found, even when a long appendix
+//! follows it and the last-30% fallback would miss it.
+
+use hallucinator_parsing::ReferenceExtractor;
+
+const STRIPPED: &str = include_str!("fixtures/stripped_heading_refs.txt");
+
+#[test]
+fn reference_list_without_heading_is_found_by_density() {
+    let result = ReferenceExtractor::new()
+        .extract_references_from_text(STRIPPED)
+        .unwrap();
+    assert_eq!(result.skip_stats.total_raw, 5);
+
+    let titles: Vec<_> = result
+        .references
+        .iter()
+        .map(|r| r.title.as_deref().unwrap_or_default())
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            "Deep learning with differential privacy",
+            "Towards evaluating the robustness of neural networks",
+            "Deep residual learning for image recognition",
+            "Membership inference attacks against machine learning models",
+            "Stealing machine learning models via prediction APIs",
+        ]
+    );
+    assert!(
+        result
+            .references
+            .iter()
+            .all(|r| !r.raw_citation.contains("Proof"))
+    );
+}
+
+#[test]
+fn repeated_running_heading_does_not_cut_the_list() {
+    // The heading survives as a running header on the continuation page; the
+    // list still starts at the first one.
+    let text =
+        STRIPPED
+            .replacen("[1] ", "References\n\n[1] ", 1)
+            .replacen("14\n", "14\nReferences\n", 1);
+    let result = ReferenceExtractor::new()
+        .extract_references_from_text(&text)
+        .unwrap();
+    assert_eq!(result.skip_stats.total_raw, 5);
+    assert!(
+        result
+            .references
+            .iter()
+            .all(|r| !r.raw_citation.contains("References"))
+    );
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use hallucinator_core::{BackendError, PdfBackend};
+
+/// [`PdfBackend`] built on the MIT-licensed `pdf-extract` crate.
+///
+/// An alternative to the MuPDF backend for builds that can't ship AGPL code.
+/// Its layout analysis is simpler than MuPDF's, so column-heavy documents may
+/// segment less cleanly, but single-column reference lists come out the same.
+#[derive(Debug, Clone, Default)]
+pub struct PdfExtractBackend;
+
+impl PdfBackend for PdfExtractBackend {
+    fn extract_text(&self, path: &Path) -> Result<String, BackendError> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| BackendError::OpenError(format!("{}: {}", path.display(), e)))?;
+
+        // pdf-extract panics on some malformed inputs instead of erroring.
+        let result = std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem(&bytes))
+            .map_err(|_| BackendError::ExtractionError("pdf-extract panicked".into()))
+            .and_then(|r| r.map_err(|e| BackendError::ExtractionError(e.to_string())));
+
+        // pdf-extract takes no password, so a failure on a document with an
+        // encryption dictionary is most likely that; those encrypted with an
+        // empty user password still decode above.
+        match result {
+            Err(BackendError::ExtractionError(_)) if has_encrypt_dictionary(&bytes) => {
+                Err(BackendError::Encrypted(path.display().to_string()))
+            }
+            other => other,
+        }
+    }
+}
+
+/// Whether the PDF's trailer (or cross-reference stream) names an `/Encrypt`
+/// dictionary. Both are stored uncompressed, so a byte search finds it.
+fn has_encrypt_dictionary(bytes: &[u8]) -> bool {
+    bytes.windows(b"/Encrypt".len()).any(|w| w == b"/Encrypt")
+}
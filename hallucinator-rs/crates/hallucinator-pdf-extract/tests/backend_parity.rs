@@ -0,0 +1,45 @@
+//! The pdf-extract backend finds the same references as MuPDF.
+
+use std::path::{Path, PathBuf};
+
+use hallucinator_core::PdfBackend;
+use hallucinator_pdf_extract::PdfExtractBackend;
+use hallucinator_pdf_mupdf::MupdfBackend;
+
+fn fixture() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/references.pdf")
+}
+
+fn titles(backend: &dyn PdfBackend) -> Vec<String> {
+    hallucinator_parsing::extract_references(&fixture(), backend)
+        .unwrap()
+        .references
+        .into_iter()
+        .filter_map(|r| r.title)
+        .collect()
+}
+
+#[test]
+fn pdf_extract_backend_finds_references() {
+    assert_eq!(
+        titles(&PdfExtractBackend),
+        [
+            "Attention is all you need",
+            "BERT: Pre-training of deep bidirectional transformers for language understanding",
+            "Deep residual learning for image recognition",
+        ]
+    );
+}
+
+#[test]
+fn backends_agree_on_fixture() {
+    assert_eq!(titles(&PdfExtractBackend), titles(&MupdfBackend::default()));
+}
+
+#[test]
+fn missing_file_is_open_error() {
+    assert!(matches!(
+        PdfExtractBackend.extract_text(Path::new("missing.pdf")),
+        Err(hallucinator_core::BackendError::OpenError(_))
+    ));
+}
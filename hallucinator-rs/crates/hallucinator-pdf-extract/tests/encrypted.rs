@@ -0,0 +1,34 @@
+//! PDFs that pdf-extract can't decrypt fail with `BackendError::Encrypted`,
+//! not a generic extraction error.
+
+use hallucinator_core::{BackendError, PdfBackend};
+use hallucinator_pdf_extract::PdfExtractBackend;
+
+fn extract(name: &str, contents: &[u8]) -> Result<String, BackendError> {
+    let path = std::env::temp_dir().join(format!("{}-{}.pdf", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    let result = PdfExtractBackend.extract_text(&path);
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+#[test]
+fn undecodable_encrypted_pdf_is_reported() {
+    let result = extract(
+        "encrypted",
+        b"%PDF-1.4\ntrailer\n<< /Root 1 0 R /Encrypt 2 0 R >>\n%%EOF\n",
+    );
+    assert!(
+        matches!(result, Err(BackendError::Encrypted(ref p)) if p.ends_with(".pdf")),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn undecodable_plain_pdf_is_an_extraction_error() {
+    let result = extract("broken", b"%PDF-1.4\ntrailer\n<< /Root 1 0 R >>\n%%EOF\n");
+    assert!(
+        matches!(result, Err(BackendError::ExtractionError(_))),
+        "{result:?}"
+    );
+}
@@ -0,0 +1,131 @@
+//! Two-up reference grids that MuPDF returns as a single block.
+//!
+//! Short references are sometimes laid out side by side; MuPDF then emits one
+//! block whose lines alternate between the left and right entry. Clustering
+//! the lines' starting x-coordinates recovers the two columns, which are then
+//! read column-major.
+
+/// Smallest gap between line starts, as a fraction of the block width, that
+/// counts as a column break. Hanging indents are far narrower.
+const MIN_COLUMN_GAP: f32 = 0.25;
+
+/// A text line with the x-coordinate of its left edge.
+pub(crate) struct PositionedLine {
+    pub x0: f32,
+    pub text: String,
+}
+
+/// Reorder a block's lines column-major if they interleave two columns;
+/// otherwise return them unchanged. `block_width` is the block's bbox width.
+pub(crate) fn reorder_grid_lines(lines: Vec<PositionedLine>, block_width: f32) -> Vec<String> {
+    match column_split(&lines, block_width) {
+        Some(split) => {
+            let (left, right): (Vec<_>, Vec<_>) =
+                lines.into_iter().partition(|line| line.x0 < split);
+            left.into_iter()
+                .chain(right)
+                .map(|line| line.text)
+                .collect()
+        }
+        None => lines.into_iter().map(|line| line.text).collect(),
+    }
+}
+
+/// The x-coordinate dividing two interleaved columns, if there are two.
+fn column_split(lines: &[PositionedLine], block_width: f32) -> Option<f32> {
+    if lines.len() < 4 || block_width <= 0.0 {
+        return None;
+    }
+
+    let mut starts: Vec<f32> = lines.iter().map(|line| line.x0).collect();
+    starts.sort_by(f32::total_cmp);
+    let (gap, split) = starts
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0], (pair[0] + pair[1]) / 2.0))
+        .max_by(|a, b| a.0.total_cmp(&b.0))?;
+    if gap < MIN_COLUMN_GAP * block_width {
+        return None;
+    }
+
+    // Side by side means both columns are populated and reading order keeps
+    // switching between them; a block that merely ends in an indented run
+    // switches once.
+    let left = lines.iter().filter(|line| line.x0 < split).count();
+    let right = lines.len() - left;
+    let switches = lines
+        .windows(2)
+        .filter(|pair| (pair[0].x0 < split) != (pair[1].x0 < split))
+        .count();
+    (left >= 2 && right >= 2 && switches >= left.min(right)).then_some(split)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(x0: f32, text: &str) -> PositionedLine {
+        PositionedLine {
+            x0,
+            text: text.to_string(),
+        }
+    }
+
+    fn texts(lines: &[PositionedLine]) -> Vec<String> {
+        lines.iter().map(|line| line.text.clone()).collect()
+    }
+
+    #[test]
+    fn interleaved_grid_is_read_column_major() {
+        // Row by row, as MuPDF emits them: [1] beside [3], [2] beside [4].
+        let lines = vec![
+            line(50.0, "[1] A. Smith. First paper title."),
+            line(310.0, "[3] C. Brown. Third paper title."),
+            line(60.0, "In CCS, 2019."),
+            line(320.0, "In NDSS, 2021."),
+            line(50.0, "[2] B. Jones. Second paper title."),
+            line(310.0, "[4] D. White. Fourth paper title."),
+            line(60.0, "In USENIX Security, 2020."),
+            line(320.0, "In S&P, 2022."),
+        ];
+        assert_eq!(
+            reorder_grid_lines(lines, 500.0),
+            [
+                "[1] A. Smith. First paper title.",
+                "In CCS, 2019.",
+                "[2] B. Jones. Second paper title.",
+                "In USENIX Security, 2020.",
+                "[3] C. Brown. Third paper title.",
+                "In NDSS, 2021.",
+                "[4] D. White. Fourth paper title.",
+                "In S&P, 2022.",
+            ]
+        );
+    }
+
+    #[test]
+    fn hanging_indents_are_not_columns() {
+        let lines = vec![
+            line(50.0, "[1] A. Smith. A long paper title that"),
+            line(62.0, "wraps onto a second line. In CCS, 2019."),
+            line(50.0, "[2] B. Jones. Another title. In NDSS,"),
+            line(62.0, "2020."),
+        ];
+        let expected = texts(&lines);
+        assert_eq!(reorder_grid_lines(lines, 500.0), expected);
+    }
+
+    #[test]
+    fn single_switch_is_not_a_grid() {
+        // A block whose tail sits far to the right (e.g. a right-aligned
+        // run) switches once and must keep its order.
+        let lines = vec![
+            line(50.0, "first"),
+            line(50.0, "second"),
+            line(50.0, "third"),
+            line(400.0, "fourth"),
+            line(400.0, "fifth"),
+        ];
+        let expected = texts(&lines);
+        assert_eq!(reorder_grid_lines(lines, 500.0), expected);
+    }
+}
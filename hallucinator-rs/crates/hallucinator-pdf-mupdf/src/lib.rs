@@ -1,24 +1,100 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use mupdf::{Document, TextPageFlags};
+use mupdf::{Document, MetadataName, TextPageFlags};
 
 use hallucinator_core::{BackendError, PdfBackend};
 
+mod grid;
+mod title;
+
+use grid::PositionedLine;
+use title::SizedLine;
+
 /// MuPDF-based implementation of [`PdfBackend`].
 ///
 /// This crate is the sole AGPL island — it isolates the mupdf dependency
 /// (which is AGPL-3.0) so that non-PDF code paths do not transitively
 /// depend on it.
-pub struct MupdfBackend;
+///
+/// Encrypted PDFs that need a user password fail with
+/// [`BackendError::Encrypted`] unless one is set via
+/// [`with_password`](Self::with_password). PDFs encrypted with an empty user
+/// password (owner-password-only restrictions) open without one.
+///
+/// With [`column_detection`](Self::column_detection) on, a block whose lines
+/// alternate between two columns (short references set two-up) is read
+/// column-major instead of row by row.
+#[derive(Debug, Clone, Default)]
+pub struct MupdfBackend {
+    password: Option<String>,
+    column_detection: bool,
+}
+
+impl MupdfBackend {
+    /// Try `password` on PDFs that need one.
+    pub fn with_password(password: impl Into<String>) -> Self {
+        Self {
+            password: Some(password.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Detect two-column line grids within a block and reorder them.
+    /// Default: off.
+    pub fn column_detection(mut self, enabled: bool) -> Self {
+        self.column_detection = enabled;
+        self
+    }
+
+    /// The document's own title, for labelling it in reports: the metadata
+    /// title when it looks like one, otherwise the largest text on the first
+    /// page. `None` if neither yields a plausible title or the PDF can't be
+    /// read.
+    pub fn extract_document_title(&self, path: &Path) -> Option<String> {
+        let document = self.open_unlocked(path).ok()?;
+        if let Some(title) = document
+            .metadata(MetadataName::Title)
+            .ok()
+            .and_then(|title| title::plausible_metadata_title(&title))
+        {
+            return Some(title);
+        }
+
+        let page = document.load_page(0).ok()?;
+        let text_page = page.to_text_page(TextPageFlags::empty()).ok()?;
+        let lines: Vec<SizedLine> = text_page
+            .blocks()
+            .flat_map(|block| block.lines().collect::<Vec<_>>())
+            .map(|line| SizedLine {
+                size: line.chars().map(|c| c.size()).fold(0.0, f32::max),
+                text: line.chars().filter_map(|c| c.char()).collect(),
+            })
+            .collect();
+        title::title_from_lines(&lines)
+    }
+
+    /// Open `path`, authenticating with the password if it needs one.
+    fn open_unlocked(&self, path: &Path) -> Result<Document, BackendError> {
+        let mut document = open_document(path)?;
+        let locked = document.needs_password().map_err(|e| open_error(path, e))?;
+        if locked {
+            let unlocked = match &self.password {
+                Some(password) => document
+                    .authenticate(password)
+                    .map_err(|e| open_error(path, e))?,
+                None => false,
+            };
+            if !unlocked {
+                return Err(BackendError::Encrypted(path.display().to_string()));
+            }
+        }
+        Ok(document)
+    }
+}
 
 impl PdfBackend for MupdfBackend {
     fn extract_text(&self, path: &Path) -> Result<String, BackendError> {
-        let path_str = path
-            .to_str()
-            .ok_or_else(|| BackendError::OpenError("invalid path encoding".into()))?;
-
-        let document =
-            Document::open(path_str).map_err(|e| BackendError::OpenError(e.to_string()))?;
+        let document = self.open_unlocked(path)?;
 
         let mut pages_text = Vec::new();
 
@@ -34,11 +110,23 @@ impl PdfBackend for MupdfBackend {
             // Use block/line iteration to match PyMuPDF's get_text() behavior
             let mut page_text = String::new();
             for block in text_page.blocks() {
-                for line in block.lines() {
-                    let line_text: String = line
-                        .chars()
-                        .map(|c| c.char().unwrap_or('\u{FFFD}'))
-                        .collect();
+                let lines: Vec<PositionedLine> = block
+                    .lines()
+                    .map(|line| PositionedLine {
+                        x0: line.bounds().x0,
+                        text: line
+                            .chars()
+                            .map(|c| c.char().unwrap_or('\u{FFFD}'))
+                            .collect(),
+                    })
+                    .collect();
+                let lines = if self.column_detection {
+                    let bounds = block.bounds();
+                    grid::reorder_grid_lines(lines, bounds.x1 - bounds.x0)
+                } else {
+                    lines.into_iter().map(|line| line.text).collect()
+                };
+                for line_text in lines {
                     page_text.push_str(&line_text);
                     page_text.push('\n');
                 }
@@ -49,3 +137,53 @@ impl PdfBackend for MupdfBackend {
         Ok(pages_text.join("\n"))
     }
 }
+
+/// Open `path` in a form MuPDF accepts on every platform.
+///
+/// MuPDF takes a UTF-8 path (converted to UTF-16 on Windows), so the path is
+/// canonicalized and, if it still isn't valid UTF-8, the file is read here and
+/// opened from memory instead. Errors name the path as given.
+fn open_document(path: &Path) -> Result<Document, BackendError> {
+    let resolved = std::fs::canonicalize(path).map_err(|e| open_error(path, e))?;
+    let resolved = simplify_verbatim(resolved);
+    match resolved.to_str() {
+        Some(path_str) => Document::open(path_str).map_err(|e| open_error(path, e)),
+        None => {
+            let bytes = std::fs::read(&resolved).map_err(|e| open_error(path, e))?;
+            Document::from_bytes(&bytes, "application/pdf").map_err(|e| open_error(path, e))
+        }
+    }
+}
+
+fn open_error(path: &Path, e: impl std::fmt::Display) -> BackendError {
+    BackendError::OpenError(format!("{}: {}", path.display(), e))
+}
+
+/// Undo the `\\?\` prefix `canonicalize` adds on Windows when the plain form
+/// fits in `MAX_PATH`; longer paths keep it, which MuPDF passes through to
+/// `_wfopen` unchanged.
+#[cfg(windows)]
+fn simplify_verbatim(path: PathBuf) -> PathBuf {
+    const MAX_PATH: usize = 260;
+
+    let Some(s) = path.to_str() else {
+        return path;
+    };
+    let plain = if let Some(rest) = s.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{}", rest)
+    } else if let Some(rest) = s.strip_prefix(r"\\?\") {
+        rest.to_string()
+    } else {
+        return path;
+    };
+    if plain.len() < MAX_PATH {
+        PathBuf::from(plain)
+    } else {
+        path
+    }
+}
+
+#[cfg(not(windows))]
+fn simplify_verbatim(path: PathBuf) -> PathBuf {
+    path
+}
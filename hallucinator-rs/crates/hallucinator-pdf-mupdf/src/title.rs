@@ -0,0 +1,163 @@
+//! Picking a document's own title, for labelling papers in reports.
+//!
+//! The PDF metadata title is used when it looks like a real title (many
+//! producers leave a filename or "Untitled" there); otherwise the largest
+//! text on the first page is taken, which is how papers set their title.
+
+/// Titles shorter than this are rejected: a word or two is more likely a
+/// venue banner or running header than a paper title.
+const MIN_TITLE_LEN: usize = 8;
+
+/// Titles longer than this are rejected: the "largest text" was probably a
+/// whole paragraph set in one size.
+const MAX_TITLE_LEN: usize = 300;
+
+/// How much larger than the body text the title must be set.
+const MIN_TITLE_SCALE: f32 = 1.2;
+
+/// A first-page text line with the font size of its largest character.
+pub(crate) struct SizedLine {
+    pub size: f32,
+    pub text: String,
+}
+
+/// `title` from the PDF metadata, unless it looks like a placeholder.
+pub(crate) fn plausible_metadata_title(title: &str) -> Option<String> {
+    let title = collapse_whitespace(title);
+    let title = title
+        .strip_prefix("Microsoft Word - ")
+        .unwrap_or(&title)
+        .to_string();
+    let lower = title.to_lowercase();
+    let placeholder = lower.starts_with("untitled")
+        || [".pdf", ".doc", ".docx", ".dvi", ".tex", ".ps"]
+            .iter()
+            .any(|ext| lower.ends_with(ext))
+        || !title.contains(' ');
+    (!placeholder && title_len_ok(&title)).then_some(title)
+}
+
+/// The first run of lines set in the page's largest font, joined, if that
+/// font stands out from the body text.
+pub(crate) fn title_from_lines(lines: &[SizedLine]) -> Option<String> {
+    let lines: Vec<&SizedLine> = lines
+        .iter()
+        .filter(|line| line.text.chars().any(char::is_alphabetic))
+        // arXiv's sidebar stamp is set larger than most titles.
+        .filter(|line| !line.text.trim_start().starts_with("arXiv:"))
+        .collect();
+    let max_size = lines.iter().map(|line| line.size).fold(0.0, f32::max);
+    if max_size <= 0.0 || max_size < MIN_TITLE_SCALE * body_size(&lines)? {
+        return None;
+    }
+
+    let is_title = |line: &&&SizedLine| line.size >= max_size - 0.5;
+    let title = lines
+        .iter()
+        .skip_while(|line| !is_title(line))
+        .take_while(is_title)
+        .map(|line| line.text.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let title = collapse_whitespace(&title);
+    title_len_ok(&title).then_some(title)
+}
+
+/// The font size covering the most characters on the page.
+fn body_size(lines: &[&SizedLine]) -> Option<f32> {
+    let mut sizes: Vec<(f32, usize)> = Vec::new();
+    for line in lines {
+        let chars = line.text.chars().count();
+        match sizes
+            .iter_mut()
+            .find(|(size, _)| (size - line.size).abs() < 0.5)
+        {
+            Some((_, count)) => *count += chars,
+            None => sizes.push((line.size, chars)),
+        }
+    }
+    sizes
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(size, _)| size)
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn title_len_ok(title: &str) -> bool {
+    (MIN_TITLE_LEN..=MAX_TITLE_LEN).contains(&title.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(size: f32, text: &str) -> SizedLine {
+        SizedLine {
+            size,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn placeholder_metadata_titles_are_rejected() {
+        assert_eq!(plausible_metadata_title(""), None);
+        assert_eq!(plausible_metadata_title("Untitled"), None);
+        assert_eq!(plausible_metadata_title("main.pdf"), None);
+        assert_eq!(plausible_metadata_title("paper_final_v3"), None);
+        assert_eq!(
+            plausible_metadata_title("Microsoft Word - A Study of  Things"),
+            Some("A Study of Things".into())
+        );
+    }
+
+    #[test]
+    fn largest_lines_form_the_title() {
+        let lines = [
+            line(9.0, "Proceedings of a Workshop"),
+            line(17.2, "Deep Residual Learning"),
+            line(17.2, "for Image Recognition"),
+            line(11.0, "Kaiming He   Xiangyu Zhang"),
+            line(
+                10.0,
+                "Deeper neural networks are more difficult to train. We present",
+            ),
+            line(
+                10.0,
+                "a residual learning framework to ease the training of networks",
+            ),
+            line(17.2, "1. Introduction"),
+        ];
+        assert_eq!(
+            title_from_lines(&lines).as_deref(),
+            Some("Deep Residual Learning for Image Recognition")
+        );
+    }
+
+    #[test]
+    fn arxiv_stamp_is_not_the_title() {
+        let lines = [
+            line(20.0, "arXiv:2403.10573v1  [cs.CR]  15 Mar 2024"),
+            line(14.0, "A Clear Paper Title"),
+            line(
+                10.0,
+                "Body text that goes on for quite a while in the normal size.",
+            ),
+        ];
+        assert_eq!(
+            title_from_lines(&lines).as_deref(),
+            Some("A Clear Paper Title")
+        );
+    }
+
+    #[test]
+    fn uniform_text_has_no_title() {
+        let lines = [
+            line(10.0, "Just a page of text set in one size."),
+            line(10.0, "Nothing here stands out as a title."),
+        ];
+        assert_eq!(title_from_lines(&lines), None);
+    }
+}
@@ -0,0 +1,107 @@
+//! A paper's own title is read from its metadata or, failing that, from the
+//! largest text on its first page.
+
+use hallucinator_pdf_mupdf::MupdfBackend;
+
+const TITLE: &str = "Deep Residual Learning for Image Recognition";
+
+/// A one-page PDF with `content` as its content stream and, if given,
+/// `info_title` as the metadata title.
+fn pdf(content: &str, info_title: Option<&str>) -> Vec<u8> {
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+         /Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>"
+            .to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            content.len(),
+            content
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+    if let Some(title) = info_title {
+        objects.push(format!("<< /Title ({}) >>", title));
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+    let xref_start = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    let info = match info_title {
+        Some(_) => format!(" /Info {} 0 R", objects.len()),
+        None => String::new(),
+    };
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R{} >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            info,
+            xref_start
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+/// A first page with the title in 20pt above an author line and body text
+/// in 10pt.
+fn first_page() -> String {
+    [
+        format!("BT /F1 20 Tf 72 700 Td ({}) Tj ET", TITLE),
+        "BT /F1 11 Tf 72 670 Td (Kaiming He, Xiangyu Zhang) Tj ET".to_string(),
+        "BT /F1 10 Tf 72 640 Td (Deeper neural networks are more difficult to train.) Tj ET"
+            .to_string(),
+        "BT /F1 10 Tf 72 628 Td (We present a residual learning framework to ease training.) Tj ET"
+            .to_string(),
+    ]
+    .join("\n")
+}
+
+fn title_of(pdf: Vec<u8>) -> Option<String> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("2403.10573v1.pdf");
+    std::fs::write(&path, pdf).unwrap();
+    MupdfBackend::default().extract_document_title(&path)
+}
+
+#[test]
+fn title_is_the_largest_first_page_text() {
+    assert_eq!(title_of(pdf(&first_page(), None)).as_deref(), Some(TITLE));
+}
+
+#[test]
+fn metadata_title_is_preferred() {
+    let title = title_of(pdf(&first_page(), Some("Residual Networks, Revisited")));
+    assert_eq!(title.as_deref(), Some("Residual Networks, Revisited"));
+}
+
+#[test]
+fn placeholder_metadata_falls_back_to_page_text() {
+    let title = title_of(pdf(&first_page(), Some("main.pdf")));
+    assert_eq!(title.as_deref(), Some(TITLE));
+}
+
+#[test]
+fn page_without_a_title_has_none() {
+    let content = "BT /F1 10 Tf 72 700 Td (Only body text on this page, nothing larger.) Tj ET";
+    assert_eq!(title_of(pdf(content, None)), None);
+}
+
+#[test]
+fn unreadable_file_has_no_title() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.pdf");
+    std::fs::write(&path, b"not a pdf").unwrap();
+    assert_eq!(MupdfBackend::default().extract_document_title(&path), None);
+}
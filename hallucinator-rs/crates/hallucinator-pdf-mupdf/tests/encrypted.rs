@@ -0,0 +1,45 @@
+//! Password-protected PDFs fail with `BackendError::Encrypted` unless the
+//! backend has the password.
+
+use std::path::{Path, PathBuf};
+
+use hallucinator_core::{BackendError, PdfBackend};
+use hallucinator_pdf_mupdf::MupdfBackend;
+
+/// RC4-encrypted with user password `secret`.
+fn fixture() -> Option<PathBuf> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/encrypted.pdf");
+    if path.exists() {
+        Some(path)
+    } else {
+        eprintln!("skipping: {} not found", path.display());
+        None
+    }
+}
+
+#[test]
+fn encrypted_pdf_without_password_is_reported() {
+    let Some(path) = fixture() else { return };
+    match MupdfBackend::default().extract_text(&path) {
+        Err(BackendError::Encrypted(p)) => assert!(p.ends_with("encrypted.pdf"), "{p}"),
+        other => panic!("expected Encrypted, got {other:?}"),
+    }
+}
+
+#[test]
+fn wrong_password_is_still_encrypted() {
+    let Some(path) = fixture() else { return };
+    assert!(matches!(
+        MupdfBackend::with_password("wrong").extract_text(&path),
+        Err(BackendError::Encrypted(_))
+    ));
+}
+
+#[test]
+fn password_unlocks_encrypted_pdf() {
+    let Some(path) = fixture() else { return };
+    let text = MupdfBackend::with_password("secret")
+        .extract_text(&path)
+        .unwrap();
+    assert!(text.contains("Encrypted fixture text"), "{text:?}");
+}
@@ -0,0 +1,68 @@
+//! PDFs open from paths with non-ASCII names, and open failures name the path.
+
+use std::path::Path;
+
+use hallucinator_core::{BackendError, PdfBackend};
+use hallucinator_pdf_mupdf::MupdfBackend;
+
+/// A one-page PDF showing `text`, with a correct xref table.
+fn minimal_pdf(text: &str) -> Vec<u8> {
+    let content = format!("BT /F1 12 Tf 72 720 Td ({}) Tj ET", text);
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+         /Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>"
+            .to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            content.len(),
+            content
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+    let xref_start = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_start
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+#[test]
+fn extracts_text_from_unicode_filename() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("Référence — 論文.pdf");
+    std::fs::write(&path, minimal_pdf("Hello references")).unwrap();
+
+    let text = MupdfBackend::default().extract_text(&path).unwrap();
+    assert!(text.contains("Hello references"), "{text:?}");
+}
+
+#[test]
+fn open_error_names_the_path() {
+    let path = Path::new("missing-Référence.pdf");
+    match MupdfBackend::default().extract_text(path) {
+        Err(BackendError::OpenError(msg)) => {
+            assert!(msg.contains("missing-Référence.pdf"), "{msg}")
+        }
+        other => panic!("expected OpenError, got {other:?}"),
+    }
+}
@@ -26,14 +26,20 @@ pub struct PyValidatorConfig {
     pub(crate) cache_path: Option<String>,
     pub(crate) cache_positive_ttl_secs: u64,
     pub(crate) cache_negative_ttl_secs: u64,
+    pub(crate) cache_max_entries: Option<usize>,
     pub(crate) searxng_url: Option<String>,
     pub(crate) num_workers: usize,
     pub(crate) max_rate_limit_retries: u32,
+    pub(crate) retry_passes: u32,
+    pub(crate) min_databases_queried: usize,
     pub(crate) db_timeout_secs: u64,
     pub(crate) db_timeout_short_secs: u64,
     pub(crate) disabled_dbs: Vec<String>,
     pub(crate) check_openalex_authors: bool,
+    pub(crate) existence_only: bool,
     pub(crate) crossref_mailto: Option<String>,
+    pub(crate) http_proxy: Option<String>,
+    pub(crate) extra_ca_cert: Option<String>,
 }
 
 impl PyValidatorConfig {
@@ -97,6 +103,8 @@ impl PyValidatorConfig {
             check_openalex_authors: self.check_openalex_authors,
             crossref_mailto: self.crossref_mailto.clone(),
             max_rate_limit_retries: self.max_rate_limit_retries,
+            retry_passes: self.retry_passes,
+            min_databases_queried: self.min_databases_queried,
             rate_limiters,
             cache_path: self.cache_path.as_ref().map(PathBuf::from),
             cache_positive_ttl_secs: self.cache_positive_ttl_secs,
@@ -106,7 +114,32 @@ impl PyValidatorConfig {
                 self.cache_path.as_ref().map(std::path::Path::new),
                 self.cache_positive_ttl_secs,
                 self.cache_negative_ttl_secs,
+                self.cache_max_entries,
             )),
+            near_miss_margin: hallucinator_core::DEFAULT_NEAR_MISS_MARGIN,
+            match_threshold: None,
+            thresholds: std::collections::HashMap::new(),
+            title_scorer: hallucinator_core::TitleScorer::default(),
+            query_guard: hallucinator_core::QueryGuard::default(),
+            http_client: None,
+            http_proxy: self.http_proxy.clone(),
+            extra_ca_cert: self.extra_ca_cert.as_ref().map(PathBuf::from),
+            max_references: Some(hallucinator_core::DEFAULT_MAX_REFERENCES),
+            max_references_action: hallucinator_core::ReferenceLimitAction::Truncate,
+            extraction_timeout: Some(hallucinator_core::DEFAULT_EXTRACTION_TIMEOUT),
+            check_in_text_citations: false,
+            pdf_column_detection: false,
+            overrides_path: None,
+            overrides: None,
+            trust_resolved_doi: false,
+            cross_validate_doi: false,
+            accept_preprint_versions: false,
+            trusted_verifiers: vec![],
+            mode: if self.existence_only {
+                hallucinator_core::VerificationMode::ExistenceOnly
+            } else {
+                hallucinator_core::VerificationMode::Full
+            },
         })
     }
 }
@@ -124,14 +157,20 @@ impl PyValidatorConfig {
             cache_path: None,
             cache_positive_ttl_secs: hallucinator_core::DEFAULT_POSITIVE_TTL.as_secs(),
             cache_negative_ttl_secs: hallucinator_core::DEFAULT_NEGATIVE_TTL.as_secs(),
+            cache_max_entries: None,
             searxng_url: None,
             num_workers: 4,
             max_rate_limit_retries: 3,
+            retry_passes: 0,
+            min_databases_queried: hallucinator_core::DEFAULT_MIN_DATABASES_QUERIED,
             db_timeout_secs: 10,
             db_timeout_short_secs: 5,
             disabled_dbs: vec![],
             check_openalex_authors: false,
+            existence_only: false,
             crossref_mailto: None,
+            http_proxy: None,
+            extra_ca_cert: None,
         }
     }
 
@@ -223,6 +262,18 @@ impl PyValidatorConfig {
         self.cache_negative_ttl_secs = value;
     }
 
+    /// Most query results kept in the cache, evicting the least recently
+    /// used beyond it (default: None = unbounded).
+    #[getter]
+    fn get_cache_max_entries(&self) -> Option<usize> {
+        self.cache_max_entries
+    }
+
+    #[setter]
+    fn set_cache_max_entries(&mut self, value: Option<usize>) {
+        self.cache_max_entries = value;
+    }
+
     /// SearxNG instance base URL for web search fallback (optional).
     #[getter]
     fn get_searxng_url(&self) -> Option<&str> {
@@ -256,6 +307,31 @@ impl PyValidatorConfig {
         self.max_rate_limit_retries = value;
     }
 
+    /// Passes re-checking references left unverified by a database timeout
+    /// or error (default: 0).
+    #[getter]
+    fn get_retry_passes(&self) -> u32 {
+        self.retry_passes
+    }
+
+    #[setter]
+    fn set_retry_passes(&mut self, value: u32) {
+        self.retry_passes = value;
+    }
+
+    /// Fewest online databases that must answer for a not-found result to be
+    /// a confident negative; offline databases don't count. Below it the
+    /// result's ``insufficient_coverage`` is set (default: 1).
+    #[getter]
+    fn get_min_databases_queried(&self) -> usize {
+        self.min_databases_queried
+    }
+
+    #[setter]
+    fn set_min_databases_queried(&mut self, value: usize) {
+        self.min_databases_queried = value;
+    }
+
     /// Timeout in seconds for database queries (default: 10).
     #[getter]
     fn get_db_timeout_secs(&self) -> u64 {
@@ -300,6 +376,18 @@ impl PyValidatorConfig {
         self.check_openalex_authors = value;
     }
 
+    /// Only check that each cited paper exists: a title match is verified
+    /// whatever its authors, so no author mismatch is reported (default: False).
+    #[getter]
+    fn get_existence_only(&self) -> bool {
+        self.existence_only
+    }
+
+    #[setter]
+    fn set_existence_only(&mut self, value: bool) {
+        self.existence_only = value;
+    }
+
     /// CrossRef mailto address for polite pool (optional).
     #[getter]
     fn get_crossref_mailto(&self) -> Option<&str> {
@@ -311,6 +399,30 @@ impl PyValidatorConfig {
         self.crossref_mailto = value;
     }
 
+    /// Proxy URL for all database queries (optional). An invalid URL makes
+    /// ``Validator.check`` raise ``RuntimeError``.
+    #[getter]
+    fn get_http_proxy(&self) -> Option<&str> {
+        self.http_proxy.as_deref()
+    }
+
+    #[setter]
+    fn set_http_proxy(&mut self, value: Option<String>) {
+        self.http_proxy = value;
+    }
+
+    /// Path to a PEM file of extra trusted CA certificates (optional), e.g.
+    /// for a TLS-intercepting proxy.
+    #[getter]
+    fn get_extra_ca_cert(&self) -> Option<&str> {
+        self.extra_ca_cert.as_deref()
+    }
+
+    #[setter]
+    fn set_extra_ca_cert(&mut self, value: Option<String>) {
+        self.extra_ca_cert = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ValidatorConfig(num_workers={}, db_timeout={}s, disabled_dbs={:?})",
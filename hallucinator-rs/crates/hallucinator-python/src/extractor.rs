@@ -118,6 +118,27 @@ impl PyPdfExtractor {
         self.invalidate();
     }
 
+    /// Strip citation-marker digits glued to author names ("Smith1").
+    #[setter]
+    fn set_strip_author_superscripts(&mut self, enabled: bool) {
+        self.builder = self.builder.clone().strip_author_superscripts(enabled);
+        self.invalidate();
+    }
+
+    /// Keep line-break hyphens unless the split is clearly a syllable break.
+    #[setter]
+    fn set_keep_uncertain_hyphens(&mut self, enabled: bool) {
+        self.builder = self.builder.clone().keep_uncertain_hyphens(enabled);
+        self.invalidate();
+    }
+
+    /// Record on each reference whether the document body cites it.
+    #[setter]
+    fn set_check_in_text_citations(&mut self, enabled: bool) {
+        self.builder = self.builder.clone().check_in_text_citations(enabled);
+        self.invalidate();
+    }
+
     /// Add an extra venue cutoff pattern (appended to defaults).
     fn add_venue_cutoff_pattern(&mut self, pattern: &str) {
         self.builder = self
@@ -207,7 +228,7 @@ impl PyPdfExtractor {
     /// Extract raw text from a PDF file (step 1).
     #[cfg(feature = "pdf")]
     fn extract_text(&mut self, path: &str) -> PyResult<String> {
-        let backend = hallucinator_pdf_mupdf::MupdfBackend;
+        let backend = hallucinator_pdf_mupdf::MupdfBackend::default();
         backend
             .extract_text(&PathBuf::from(path))
             .map_err(backend_error_to_py)
@@ -225,6 +246,13 @@ impl PyPdfExtractor {
         Ok(ext.segment_references(text))
     }
 
+    /// Like `segment`, but returns each reference's exact input lines,
+    /// labels and line breaks included.
+    fn segment_raw(&mut self, text: &str) -> PyResult<Vec<String>> {
+        let ext = self.extractor()?;
+        Ok(ext.segment_references_raw(text))
+    }
+
     /// Parse a single reference string (step 4).
     ///
     /// Returns a `Reference` or `None` if the reference was skipped.
@@ -247,7 +275,7 @@ impl PyPdfExtractor {
     /// Parse a single reference string, returning skip reason if skipped.
     ///
     /// Returns `(Reference, None)` on success or `(None, reason)` on skip.
-    /// `reason` is `"url_only"` or `"short_title"`.
+    /// `reason` is `"url_only"`, `"short_title"`, or `"placeholder"`.
     #[pyo3(signature = (text, prev_authors=None))]
     fn parse_reference_detailed(
         &mut self,
@@ -265,6 +293,7 @@ impl PyPdfExtractor {
                 let reason_str = match reason {
                     hallucinator_parsing::extractor::SkipReason::UrlOnly => "url_only",
                     hallucinator_parsing::extractor::SkipReason::ShortTitle => "short_title",
+                    hallucinator_parsing::extractor::SkipReason::Placeholder => "placeholder",
                 };
                 Ok((None, Some(reason_str.to_string())))
             }
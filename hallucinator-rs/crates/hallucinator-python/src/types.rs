@@ -1,6 +1,6 @@
 use pyo3::prelude::*;
 
-use hallucinator_core::{ExtractionResult, Reference, SkipStats};
+use hallucinator_core::{ExtractionResult, Reference, SkipStats, classify_citation};
 
 /// A parsed reference extracted from a document.
 #[pyclass(name = "Reference")]
@@ -40,15 +40,21 @@ impl PyReference {
         arxiv_id: Option<String>,
         raw_citation: Option<String>,
     ) -> Self {
+        let raw_citation = raw_citation.unwrap_or_else(|| title.clone());
+        let kind = classify_citation(&raw_citation);
         Self {
             inner: Reference {
-                raw_citation: raw_citation.unwrap_or_else(|| title.clone()),
+                raw_citation,
                 title: Some(title),
                 authors,
                 doi,
                 arxiv_id,
                 original_number: 0,
                 skip_reason: None,
+                kind,
+                container_title: None,
+                cite_key: None,
+                cited_in_text: None,
             },
         }
     }
@@ -95,6 +101,32 @@ impl PyReference {
         self.inner.skip_reason.as_deref()
     }
 
+    /// Publication kind inferred from the citation text: ``"journal"``,
+    /// ``"conference"``, ``"preprint"``, ``"book"``, ``"web"``, or ``"unknown"``.
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.inner.kind.as_str()
+    }
+
+    /// Book title for a chapter in an edited collection, or `None`.
+    #[getter]
+    fn container_title(&self) -> Option<&str> {
+        self.inner.container_title.as_deref()
+    }
+
+    /// LaTeX cite key (`\bibitem{key}` / `@article{key,`), or `None`.
+    #[getter]
+    fn cite_key(&self) -> Option<&str> {
+        self.inner.cite_key.as_deref()
+    }
+
+    /// Whether the document body cites this reference; `None` unless the
+    /// extractor's `check_in_text_citations` was set.
+    #[getter]
+    fn cited_in_text(&self) -> Option<bool> {
+        self.inner.cited_in_text
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Reference(title={:?}, authors={}, doi={:?})",
@@ -132,6 +164,12 @@ impl PySkipStats {
         self.inner.short_title
     }
 
+    /// Number of references skipped as unfilled placeholders ("[CITATION NEEDED]").
+    #[getter]
+    fn placeholder(&self) -> usize {
+        self.inner.placeholder
+    }
+
     /// Number of references where no title could be extracted.
     #[getter]
     fn no_title(&self) -> usize {
@@ -152,10 +190,11 @@ impl PySkipStats {
 
     fn __repr__(&self) -> String {
         format!(
-            "SkipStats(total_raw={}, url_only={}, short_title={}, no_title={}, no_authors={})",
+            "SkipStats(total_raw={}, url_only={}, short_title={}, placeholder={}, no_title={}, no_authors={})",
             self.inner.total_raw,
             self.inner.url_only,
             self.inner.short_title,
+            self.inner.placeholder,
             self.inner.no_title,
             self.inner.no_authors,
         )
@@ -208,6 +247,7 @@ impl PyExtractionResult {
         total_raw: usize,
         url_only: usize,
         short_title: usize,
+        placeholder: usize,
         no_title: usize,
         no_authors: usize,
     ) -> Self {
@@ -216,8 +256,10 @@ impl PyExtractionResult {
             total_raw,
             url_only,
             short_title,
+            placeholder,
             no_title,
             no_authors,
+            ..Default::default()
         };
         Self {
             inner: ExtractionResult {
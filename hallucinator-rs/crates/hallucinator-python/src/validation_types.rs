@@ -1,5 +1,7 @@
 use pyo3::prelude::*;
 
+use crate::types::PyReference;
+
 use hallucinator_core::{
     ArxivInfo, CheckStats, DbResult, DbStatus, DoiInfo, ProgressEvent, RetractionInfo, Status,
     ValidationResult,
@@ -40,6 +42,12 @@ impl PyValidationResult {
         &self.inner.raw_citation
     }
 
+    /// LaTeX cite key of the reference, if it came from a BBL/BIB file.
+    #[getter]
+    fn cite_key(&self) -> Option<&str> {
+        self.inner.cite_key.as_deref()
+    }
+
     /// Authors from the parsed reference.
     #[getter]
     fn ref_authors(&self) -> Vec<String> {
@@ -80,6 +88,20 @@ impl PyValidationResult {
         self.inner.failed_dbs.clone()
     }
 
+    /// True for a not-found result that too few online databases answered to
+    /// be a confident negative (see ``ValidatorConfig.min_databases_queried``).
+    #[getter]
+    fn insufficient_coverage(&self) -> bool {
+        self.inner.insufficient_coverage
+    }
+
+    /// Whether the document body cites this reference; ``None`` unless the
+    /// extractor's ``check_in_text_citations`` was set.
+    #[getter]
+    fn cited_in_text(&self) -> Option<bool> {
+        self.inner.cited_in_text
+    }
+
     /// Per-database query results.
     #[getter]
     fn db_results(&self) -> Vec<PyDbResult> {
@@ -112,6 +134,18 @@ impl PyValidationResult {
             .map(PyRetractionInfo::from)
     }
 
+    /// Closest offline candidate `(title, score)` for a narrowly missed reference.
+    #[getter]
+    fn near_miss(&self) -> Option<(String, f64)> {
+        self.inner.near_miss.clone()
+    }
+
+    /// Each reference author paired with whether the matched record lists them.
+    #[getter]
+    fn author_match_detail(&self) -> Vec<(String, bool)> {
+        self.inner.author_match_detail.clone()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ValidationResult(title={:?}, status={:?}, source={:?})",
@@ -232,10 +266,28 @@ impl PyDoiInfo {
         self.inner.title.as_deref()
     }
 
+    /// Whether the DOI resolves to a paper with a different title than cited.
+    #[getter]
+    fn title_mismatch(&self) -> bool {
+        self.inner.title_mismatch
+    }
+
+    /// Whether the DOI has the ``10.<registrant>/<suffix>`` shape.
+    #[getter]
+    fn well_formed(&self) -> bool {
+        self.inner.well_formed
+    }
+
+    /// False when the resolver has no record of the DOI (likely fabricated).
+    #[getter]
+    fn registered(&self) -> bool {
+        self.inner.registered
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "DoiInfo(doi={:?}, valid={})",
-            self.inner.doi, self.inner.valid
+            "DoiInfo(doi={:?}, valid={}, title_mismatch={})",
+            self.inner.doi, self.inner.valid, self.inner.title_mismatch
         )
     }
 }
@@ -275,10 +327,16 @@ impl PyArxivInfo {
         self.inner.title.as_deref()
     }
 
+    /// DOI of the published version of the preprint, if arXiv lists one.
+    #[getter]
+    fn published_doi(&self) -> Option<&str> {
+        self.inner.published_doi.as_deref()
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "ArxivInfo(arxiv_id={:?}, valid={})",
-            self.inner.arxiv_id, self.inner.valid
+            "ArxivInfo(arxiv_id={:?}, valid={}, published_doi={:?})",
+            self.inner.arxiv_id, self.inner.valid, self.inner.published_doi
         )
     }
 }
@@ -332,10 +390,12 @@ impl PyRetractionInfo {
 ///
 /// Properties vary by ``event_type``:
 ///
+/// - ``"extracted"`` — ``references`` (the ``Reference`` list about to be checked), ``total``; sent first
 /// - ``"checking"`` — ``index``, ``total``, ``title``
 /// - ``"result"`` — ``index``, ``total``, ``result`` (a ``ValidationResult``)
 /// - ``"warning"`` — ``index``, ``total``, ``title``, ``failed_dbs``, ``message``
 /// - ``"retry_pass"`` — ``count``
+/// - ``"db_query_started"`` — ``paper_index``, ``ref_index``, ``db_name``, sent just before the query
 /// - ``"db_query_complete"`` — ``paper_index``, ``ref_index``, ``db_name``, ``status``, ``elapsed_ms``
 /// - ``"rate_limit_wait"`` — ``db_name``, waiting for rate limiter
 /// - ``"rate_limit_retry"`` — ``ref_index``, ``db_name``, ``attempt``, retrying after 429
@@ -357,11 +417,13 @@ impl PyProgressEvent {
     #[getter]
     fn event_type(&self) -> &str {
         match &self.inner {
+            ProgressEvent::Extracted { .. } => "extracted",
             ProgressEvent::Checking { .. } => "checking",
             ProgressEvent::Result { .. } => "result",
             ProgressEvent::Warning { .. } => "warning",
             ProgressEvent::Retrying { .. } => "retrying",
             ProgressEvent::RetryPass { .. } => "retry_pass",
+            ProgressEvent::DatabaseQueryStarted { .. } => "db_query_started",
             ProgressEvent::DatabaseQueryComplete { .. } => "db_query_complete",
             ProgressEvent::RateLimitWait { .. } => "rate_limit_wait",
             ProgressEvent::RateLimitRetry { .. } => "rate_limit_retry",
@@ -380,10 +442,11 @@ impl PyProgressEvent {
         }
     }
 
-    /// Total number of references (for checking/result/warning/retrying events).
+    /// Total number of references (for extracted/checking/result/warning/retrying events).
     #[getter]
     fn total(&self) -> Option<usize> {
         match &self.inner {
+            ProgressEvent::Extracted { references } => Some(references.len()),
             ProgressEvent::Checking { total, .. }
             | ProgressEvent::Result { total, .. }
             | ProgressEvent::Warning { total, .. }
@@ -403,6 +466,17 @@ impl PyProgressEvent {
         }
     }
 
+    /// The references about to be checked (for extracted events).
+    #[getter]
+    fn references(&self) -> Option<Vec<PyReference>> {
+        match &self.inner {
+            ProgressEvent::Extracted { references } => {
+                Some(references.iter().cloned().map(PyReference::from).collect())
+            }
+            _ => None,
+        }
+    }
+
     /// The validation result (for result events).
     #[getter]
     fn result(&self) -> Option<PyValidationResult> {
@@ -440,30 +514,35 @@ impl PyProgressEvent {
         }
     }
 
-    /// Paper index (for db_query_complete events).
+    /// Paper index (for db_query_started, db_query_complete events).
     #[getter]
     fn paper_index(&self) -> Option<usize> {
         match &self.inner {
-            ProgressEvent::DatabaseQueryComplete { paper_index, .. } => Some(*paper_index),
+            ProgressEvent::DatabaseQueryStarted { paper_index, .. }
+            | ProgressEvent::DatabaseQueryComplete { paper_index, .. } => Some(*paper_index),
             _ => None,
         }
     }
 
-    /// Reference index within the paper (for db_query_complete, rate_limit_retry events).
+    /// Reference index within the paper (for db_query_started, db_query_complete,
+    /// rate_limit_retry events).
     #[getter]
     fn ref_index(&self) -> Option<usize> {
         match &self.inner {
-            ProgressEvent::DatabaseQueryComplete { ref_index, .. }
+            ProgressEvent::DatabaseQueryStarted { ref_index, .. }
+            | ProgressEvent::DatabaseQueryComplete { ref_index, .. }
             | ProgressEvent::RateLimitRetry { ref_index, .. } => Some(*ref_index),
             _ => None,
         }
     }
 
-    /// Database name (for db_query_complete, rate_limit_wait, rate_limit_retry events).
+    /// Database name (for db_query_started, db_query_complete, rate_limit_wait,
+    /// rate_limit_retry events).
     #[getter]
     fn db_name(&self) -> Option<&str> {
         match &self.inner {
-            ProgressEvent::DatabaseQueryComplete { db_name, .. }
+            ProgressEvent::DatabaseQueryStarted { db_name, .. }
+            | ProgressEvent::DatabaseQueryComplete { db_name, .. }
             | ProgressEvent::RateLimitWait { db_name, .. }
             | ProgressEvent::RateLimitRetry { db_name, .. } => Some(db_name),
             _ => None,
@@ -523,6 +602,10 @@ impl PyProgressEvent {
 
     fn __repr__(&self) -> String {
         match &self.inner {
+            ProgressEvent::Extracted { references } => format!(
+                "ProgressEvent(type='extracted', total={})",
+                references.len()
+            ),
             ProgressEvent::Checking {
                 index,
                 total,
@@ -566,6 +649,12 @@ impl PyProgressEvent {
             ProgressEvent::RetryPass { count } => {
                 format!("ProgressEvent(type='retry_pass', count={})", count)
             }
+            ProgressEvent::DatabaseQueryStarted {
+                ref_index, db_name, ..
+            } => format!(
+                "ProgressEvent(type='db_query_started', ref={}, db={:?})",
+                ref_index, db_name,
+            ),
             ProgressEvent::DatabaseQueryComplete {
                 db_name, status, ..
             } => format!(
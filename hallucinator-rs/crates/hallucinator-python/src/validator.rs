@@ -5,6 +5,7 @@ use tokio_util::sync::CancellationToken;
 use hallucinator_core::Reference;
 
 use crate::config::PyValidatorConfig;
+use crate::errors::core_error_to_py;
 use crate::types::PyReference;
 use crate::validation_types::{PyCheckStats, PyProgressEvent, PyValidationResult};
 
@@ -99,6 +100,7 @@ impl PyValidator {
             })
         });
 
+        let results = results.map_err(core_error_to_py)?;
         Ok(results.into_iter().map(PyValidationResult::from).collect())
     }
 
@@ -1,7 +1,7 @@
 use std::io::Write;
 use std::path::Path;
 
-use hallucinator_core::{CheckStats, DbStatus, Status, ValidationResult};
+use hallucinator_core::{CheckStats, Status, ValidationResult};
 
 use crate::types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef};
 
@@ -9,6 +9,8 @@ use crate::types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef}
 ///
 /// `ref_states` is a parallel slice to `papers` — `ref_states[i]` are the ReportRefs
 /// for `papers[i]`. This is used to include FP reason overrides in the output.
+/// [`ExportFormat::Bibtex`] always holds only unverified references, so it
+/// ignores `problematic_only`.
 pub fn export_results(
     papers: &[ReportPaper<'_>],
     ref_states: &[&[ReportRef]],
@@ -22,6 +24,7 @@ pub fn export_results(
         ExportFormat::Markdown => export_markdown(papers, ref_states, problematic_only),
         ExportFormat::Text => export_text(papers, ref_states, problematic_only),
         ExportFormat::Html => export_html(papers, ref_states, problematic_only),
+        ExportFormat::Bibtex => export_bibtex(papers, ref_states),
     };
 
     let mut file =
@@ -51,10 +54,15 @@ fn is_retracted(r: &ValidationResult) -> bool {
     r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted)
 }
 
-/// Whether a verified reference has an invalid DOI or arXiv ID.
+/// Whether a verified reference has an invalid (or mismatched) DOI or arXiv
+/// ID, or a DOI that names a different paper than its title search found.
 fn has_doi_arxiv_issue(r: &ValidationResult) -> bool {
     r.status == Status::Verified
-        && (r.doi_info.as_ref().is_some_and(|d| !d.valid)
+        && (r
+            .doi_info
+            .as_ref()
+            .is_some_and(|d| !d.valid || d.title_mismatch)
+            || r.doi_conflict.is_some()
             || r.arxiv_info.as_ref().is_some_and(|a| !a.valid))
 }
 
@@ -113,6 +121,24 @@ fn build_sorted_refs<'a>(paper: &ReportPaper<'a>, paper_refs: &[ReportRef]) -> V
     entries
 }
 
+/// Checked references the document lists but never cites in its body, as
+/// `(ref_num, result)` in reference order.
+fn uncited_refs<'a>(
+    paper: &ReportPaper<'a>,
+    paper_refs: &[ReportRef],
+) -> Vec<(usize, &'a ValidationResult)> {
+    paper
+        .results
+        .iter()
+        .enumerate()
+        .filter_map(|(ri, result)| {
+            let r = result.as_ref().filter(|r| r.cited_in_text == Some(false))?;
+            let ref_num = paper_refs.get(ri).map(|rs| rs.index + 1).unwrap_or(ri + 1);
+            Some((ref_num, r))
+        })
+        .collect()
+}
+
 fn problematic_pct(stats: &CheckStats) -> f64 {
     let checked = stats.total.saturating_sub(stats.skipped);
     if checked == 0 {
@@ -171,7 +197,7 @@ fn json_escape(s: &str) -> String {
     out
 }
 
-fn json_str(s: &str) -> String {
+pub(crate) fn json_str(s: &str) -> String {
     format!("\"{}\"", json_escape(s))
 }
 
@@ -201,8 +227,9 @@ pub fn export_json(
             None => "null".to_string(),
         };
         out.push_str(&format!(
-            "  {{\n    \"filename\": {},\n    \"verdict\": {},\n    \"stats\": {{\n      \"total\": {},\n      \"verified\": {},\n      \"not_found\": {},\n      \"author_mismatch\": {},\n      \"retracted\": {},\n      \"skipped\": {},\n      \"problematic_pct\": {:.1}\n    }},\n    \"references\": [\n",
+            "  {{\n    \"filename\": {},\n    \"title\": {},\n    \"verdict\": {},\n    \"stats\": {{\n      \"total\": {},\n      \"verified\": {},\n      \"not_found\": {},\n      \"author_mismatch\": {},\n      \"retracted\": {},\n      \"skipped\": {},\n      \"problematic_pct\": {:.1}\n    }},\n    \"references\": [\n",
             json_str(paper.filename),
+            paper.title.map_or_else(|| "null".to_string(), json_str),
             verdict_json,
             s.total, s.verified, s.not_found, s.author_mismatch, s.retracted, s.skipped,
             problematic_pct(&s),
@@ -236,6 +263,14 @@ pub fn export_json(
             entry.push_str("      {\n");
             entry.push_str(&format!("        \"index\": {},\n", ri));
             entry.push_str(&format!("        \"original_number\": {},\n", orig_num));
+            entry.push_str(&format!(
+                "        \"cite_key\": {},\n",
+                json_opt_str(&r.cite_key)
+            ));
+            entry.push_str(&format!(
+                "        \"fingerprint\": {},\n",
+                json_str(&r.fingerprint())
+            ));
             entry.push_str(&format!("        \"title\": {},\n", json_str(&r.title)));
             entry.push_str(&format!(
                 "        \"raw_citation\": {},\n",
@@ -251,6 +286,10 @@ pub fn export_json(
                 "        \"source\": {},\n",
                 json_opt_str(&r.source)
             ));
+            entry.push_str(&format!(
+                "        \"verified_by_count\": {},\n",
+                r.verified_by_count
+            ));
             entry.push_str(&format!(
                 "        \"ref_authors\": {},\n",
                 json_str_array(&r.ref_authors)
@@ -259,6 +298,21 @@ pub fn export_json(
                 "        \"found_authors\": {},\n",
                 json_str_array(&r.found_authors)
             ));
+            let detail: Vec<String> = r
+                .author_match_detail
+                .iter()
+                .map(|(author, matched)| {
+                    format!(
+                        "{{\"author\": {}, \"matched\": {}}}",
+                        json_str(author),
+                        matched
+                    )
+                })
+                .collect();
+            entry.push_str(&format!(
+                "        \"author_match_detail\": [{}],\n",
+                detail.join(", ")
+            ));
             entry.push_str(&format!(
                 "        \"paper_url\": {},\n",
                 json_opt_str(&r.paper_url)
@@ -271,10 +325,13 @@ pub fn export_json(
             // DOI info
             if let Some(doi) = &r.doi_info {
                 entry.push_str(&format!(
-                    "        \"doi_info\": {{\"doi\": {}, \"valid\": {}, \"title\": {}}},\n",
+                    "        \"doi_info\": {{\"doi\": {}, \"valid\": {}, \"title\": {}, \"title_mismatch\": {}, \"well_formed\": {}, \"registered\": {}}},\n",
                     json_str(&doi.doi),
                     doi.valid,
-                    json_opt_str(&doi.title)
+                    json_opt_str(&doi.title),
+                    doi.title_mismatch,
+                    doi.well_formed,
+                    doi.registered
                 ));
             } else {
                 entry.push_str("        \"doi_info\": null,\n");
@@ -283,10 +340,11 @@ pub fn export_json(
             // arXiv info
             if let Some(ax) = &r.arxiv_info {
                 entry.push_str(&format!(
-                    "        \"arxiv_info\": {{\"arxiv_id\": {}, \"valid\": {}, \"title\": {}}},\n",
+                    "        \"arxiv_info\": {{\"arxiv_id\": {}, \"valid\": {}, \"title\": {}, \"published_doi\": {}}},\n",
                     json_str(&ax.arxiv_id),
                     ax.valid,
-                    json_opt_str(&ax.title)
+                    json_opt_str(&ax.title),
+                    json_opt_str(&ax.published_doi)
                 ));
             } else {
                 entry.push_str("        \"arxiv_info\": null,\n");
@@ -304,18 +362,48 @@ pub fn export_json(
                 entry.push_str("        \"retraction_info\": null,\n");
             }
 
+            // Near-miss suggestion
+            if let Some((nm_title, nm_score)) = &r.near_miss {
+                entry.push_str(&format!(
+                    "        \"near_miss\": {{\"title\": {}, \"score\": {:.3}}},\n",
+                    json_str(nm_title),
+                    nm_score
+                ));
+            } else {
+                entry.push_str("        \"near_miss\": null,\n");
+            }
+
+            // DOI and title search disagreement
+            if let Some(conflict) = &r.doi_conflict {
+                entry.push_str(&format!(
+                    "        \"doi_conflict\": {{\"doi\": {}, \"doi_title\": {}, \"matched_source\": {}, \"matched_title\": {}}},\n",
+                    json_str(&conflict.doi),
+                    json_str(&conflict.doi_title),
+                    json_str(&conflict.matched_source),
+                    json_str(&conflict.matched_title)
+                ));
+            } else {
+                entry.push_str("        \"doi_conflict\": null,\n");
+            }
+            entry.push_str(&format!(
+                "        \"version_relation\": {},\n",
+                json_opt_str(&r.version_relation.map(|v| v.as_str().to_string()))
+            ));
+            entry.push_str(&format!(
+                "        \"insufficient_coverage\": {},\n",
+                r.insufficient_coverage
+            ));
+            entry.push_str(&format!(
+                "        \"cited_in_text\": {},\n",
+                r.cited_in_text
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "null".to_string())
+            ));
+
             // Per-DB results
             entry.push_str("        \"db_results\": [");
             for (di, db) in r.db_results.iter().enumerate() {
-                let db_status = match db.status {
-                    DbStatus::Match => "match",
-                    DbStatus::NoMatch => "no_match",
-                    DbStatus::AuthorMismatch => "author_mismatch",
-                    DbStatus::Timeout => "timeout",
-                    DbStatus::RateLimited => "rate_limited",
-                    DbStatus::Error => "error",
-                    DbStatus::Skipped => "skipped",
-                };
+                let db_status = hallucinator_core::run::db_status_str(&db.status);
                 let elapsed_ms = db.elapsed.map(|d| d.as_millis()).unwrap_or(0);
                 entry.push_str(&format!(
                     "{{\"db\": {}, \"status\": {}, \"elapsed_ms\": {}, \"authors\": {}, \"url\": {}}}",
@@ -354,11 +442,17 @@ pub fn export_json(
                     entry.push_str("        \"source\": null,\n");
                     entry.push_str("        \"ref_authors\": [],\n");
                     entry.push_str("        \"found_authors\": [],\n");
+                    entry.push_str("        \"author_match_detail\": [],\n");
                     entry.push_str("        \"paper_url\": null,\n");
                     entry.push_str("        \"failed_dbs\": [],\n");
                     entry.push_str("        \"doi_info\": null,\n");
                     entry.push_str("        \"arxiv_info\": null,\n");
                     entry.push_str("        \"retraction_info\": null,\n");
+                    entry.push_str("        \"near_miss\": null,\n");
+                    entry.push_str("        \"doi_conflict\": null,\n");
+                    entry.push_str("        \"version_relation\": null,\n");
+                    entry.push_str("        \"insufficient_coverage\": false,\n");
+                    entry.push_str("        \"cited_in_text\": null,\n");
                     entry.push_str("        \"db_results\": []\n");
                     entry.push_str("      }");
                     entries.push(entry);
@@ -397,7 +491,7 @@ fn export_csv(
     problematic_only: bool,
 ) -> String {
     let mut out = String::from(
-        "Filename,Verdict,Ref#,Title,Status,EffectiveStatus,FpReason,Source,Retracted,Authors,FoundAuthors,PaperURL,DOI,ArxivID,FailedDBs\n",
+        "Filename,Verdict,Ref#,Title,Status,EffectiveStatus,FpReason,Source,Retracted,Authors,FoundAuthors,PaperURL,DOI,ArxivID,FailedDBs,CiteKey,DoiConflict,VersionRelation\n",
     );
     for (pi, paper) in papers.iter().enumerate() {
         let verdict = verdict_str(paper.verdict);
@@ -425,8 +519,13 @@ fn export_csv(
                 .map(|a| a.arxiv_id.as_str())
                 .unwrap_or("");
             let failed = r.failed_dbs.join("; ");
+            let doi_conflict = r
+                .doi_conflict
+                .as_ref()
+                .map(|c| c.doi_title.as_str())
+                .unwrap_or("");
             out.push_str(&format!(
-                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
                 csv_escape(paper.filename),
                 csv_escape(verdict),
                 sref.ref_num,
@@ -442,6 +541,9 @@ fn export_csv(
                 csv_escape(doi),
                 csv_escape(arxiv),
                 csv_escape(&failed),
+                csv_escape(r.cite_key.as_deref().unwrap_or("")),
+                csv_escape(doi_conflict),
+                r.version_relation.map(|v| v.as_str()).unwrap_or(""),
             ));
         }
         // Add skipped refs (excluded in problematic-only mode)
@@ -449,7 +551,7 @@ fn export_csv(
             for rs in paper_refs {
                 if let Some(skip) = &rs.skip_info {
                     out.push_str(&format!(
-                        "{},{},{},{},skipped,skipped,{},,,,,,,,\n",
+                        "{},{},{},{},skipped,skipped,{},,,,,,,,,,,\n",
                         csv_escape(paper.filename),
                         csv_escape(verdict),
                         rs.index + 1,
@@ -467,6 +569,20 @@ fn md_escape(s: &str) -> String {
     s.replace('|', "\\|")
 }
 
+/// Why an [`insufficient_coverage`](ValidationResult::insufficient_coverage)
+/// not-found result is not a confident negative, naming the backends that
+/// failed.
+pub fn coverage_caveat(r: &ValidationResult) -> String {
+    if r.failed_dbs.is_empty() {
+        "too few online databases were queried, so this may be a false alarm".to_string()
+    } else {
+        format!(
+            "too few online databases answered ({} timed out or failed), so this may be a false alarm",
+            r.failed_dbs.join(", ")
+        )
+    }
+}
+
 fn scholar_url(title: &str) -> String {
     format!(
         "https://scholar.google.com/scholar?q={}",
@@ -489,7 +605,7 @@ fn export_markdown(
             Some(PaperVerdict::Questionable) => " **[?!]**",
             None => "",
         };
-        out.push_str(&format!("## {}{}\n\n", paper.filename, verdict_badge));
+        out.push_str(&format!("## {}{}\n\n", paper.label(), verdict_badge));
 
         // Stats summary
         out.push_str(&format!(
@@ -556,7 +672,13 @@ fn export_markdown(
             out.push_str("|---|-------|--------|-----|\n");
             for sref in &verified {
                 let r = sref.result;
-                let source = r.source.as_deref().unwrap_or("\u{2014}");
+                let mut source = r.source.as_deref().unwrap_or("\u{2014}").to_string();
+                if r.verified_by_count > 1 {
+                    source.push_str(&format!(" (verified by {} sources)", r.verified_by_count));
+                }
+                if let Some(relation) = r.version_relation {
+                    source.push_str(&format!(" ({})", relation.description()));
+                }
                 let url = r
                     .paper_url
                     .as_ref()
@@ -587,6 +709,7 @@ fn export_markdown(
                     let reason = match rs.skip_info.as_ref().map(|s| s.reason.as_str()) {
                         Some("url_only") => "URL-only",
                         Some("short_title") => "Short title",
+                        Some("placeholder") => "Placeholder",
                         Some("no_title") => "No title",
                         Some(other) => other,
                         None => "",
@@ -607,6 +730,17 @@ fn export_markdown(
             }
         } // end if !problematic_only
 
+        let uncited = uncited_refs(paper, paper_refs);
+        if !uncited.is_empty() {
+            out.push_str("### Listed but Never Cited\n\n");
+            out.push_str("| # | Title |\n");
+            out.push_str("|---|-------|\n");
+            for (ref_num, r) in &uncited {
+                out.push_str(&format!("| {} | {} |\n", ref_num, md_escape(&r.title)));
+            }
+            out.push('\n');
+        }
+
         out.push_str("---\n\n");
     }
     out
@@ -630,6 +764,10 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
         status_icon,
     ));
 
+    if let Some(key) = &r.cite_key {
+        out.push_str(&format!("- **Cite key:** `{}`\n", key));
+    }
+
     // Author comparison for mismatches
     if r.status == Status::AuthorMismatch {
         if !r.ref_authors.is_empty() {
@@ -653,17 +791,56 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
 
     // DOI/arXiv issues
     if let Some(doi) = &r.doi_info
+        && !doi.registered
+    {
+        out.push_str(&format!(
+            "- **DOI** `{}` \u{2014} not registered (likely fabricated)\n",
+            doi.doi
+        ));
+    } else if let Some(doi) = &r.doi_info
         && !doi.valid
     {
         out.push_str(&format!(
             "- **DOI** `{}` \u{2014} invalid/unresolvable\n",
             doi.doi
         ));
+    } else if let Some(doi) = &r.doi_info
+        && doi.title_mismatch
+    {
+        out.push_str(&format!(
+            "- **DOI** `{}` \u{2014} resolves to a different title: {}\n",
+            doi.doi,
+            md_escape(doi.title.as_deref().unwrap_or(""))
+        ));
+    }
+    if let Some(conflict) = &r.doi_conflict {
+        out.push_str(&format!(
+            "- **DOI conflict:** `{}` resolves to {}, but {} matched {}\n",
+            conflict.doi,
+            md_escape(&conflict.doi_title),
+            conflict.matched_source,
+            md_escape(&conflict.matched_title)
+        ));
     }
     if let Some(ax) = &r.arxiv_info
         && !ax.valid
     {
         out.push_str(&format!("- **arXiv** `{}` \u{2014} invalid\n", ax.arxiv_id));
+    } else if let Some(ax) = &r.arxiv_info
+        && let Some(published) = &ax.published_doi
+    {
+        out.push_str(&format!(
+            "- **arXiv** `{}` \u{2014} published as: {}\n",
+            ax.arxiv_id, published
+        ));
+    }
+
+    if let Some(relation) = r.version_relation {
+        out.push_str(&format!(
+            "- **Matched version:** {} ({})\n",
+            relation.description(),
+            r.source.as_deref().unwrap_or("unknown")
+        ));
     }
 
     // Retraction details
@@ -681,6 +858,17 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
         }
     }
 
+    // Near-miss suggestion
+    if let Some((nm_title, nm_score)) = &r.near_miss {
+        out.push_str(&format!(
+            "- **Closest match:** {} ({:.2})\n",
+            nm_title, nm_score
+        ));
+    }
+    if r.insufficient_coverage {
+        out.push_str(&format!("- **Low coverage:** {}\n", coverage_caveat(r)));
+    }
+
     // Links
     if let Some(url) = &r.paper_url {
         out.push_str(&format!("- [Paper URL]({})\n", url));
@@ -719,9 +907,9 @@ fn export_text(
             Some(PaperVerdict::Questionable) => " [?!]",
             None => "",
         };
-        let title = format!("{}{}", paper.filename, verdict_badge);
+        let title = format!("{}{}", paper.label(), verdict_badge);
         out.push_str(&format!("\n{}\n", title));
-        out.push_str(&"-".repeat(title.len()));
+        out.push_str(&"-".repeat(title.chars().count()));
         out.push('\n');
         out.push_str(&format!(
             "  {} total | {} verified | {} not found | {} mismatch | {} retracted | {} skipped | {:.1}% problematic\n\n",
@@ -759,6 +947,10 @@ fn export_text(
                 sref.ref_num, r.title, status, source, retracted_tag,
             ));
 
+            if let Some(key) = &r.cite_key {
+                out.push_str(&format!("       Cite key: {}\n", key));
+            }
+
             // Authors
             if !r.ref_authors.is_empty() {
                 out.push_str(&format!(
@@ -779,12 +971,42 @@ fn export_text(
 
             // DOI / arXiv
             if let Some(doi) = &r.doi_info {
-                let valid = if doi.valid { "valid" } else { "INVALID" };
+                let valid = if !doi.registered {
+                    "NOT REGISTERED - likely fabricated".to_string()
+                } else if !doi.valid {
+                    "INVALID".to_string()
+                } else if doi.title_mismatch {
+                    format!(
+                        "TITLE MISMATCH: resolves to \"{}\"",
+                        doi.title.as_deref().unwrap_or("")
+                    )
+                } else {
+                    "valid".to_string()
+                };
                 out.push_str(&format!("       DOI: {} ({})\n", doi.doi, valid));
             }
+            if let Some(conflict) = &r.doi_conflict {
+                out.push_str(&format!(
+                    "       DOI conflict: {} resolves to \"{}\", but {} matched \"{}\"\n",
+                    conflict.doi,
+                    conflict.doi_title,
+                    conflict.matched_source,
+                    conflict.matched_title
+                ));
+            }
             if let Some(ax) = &r.arxiv_info {
                 let valid = if ax.valid { "valid" } else { "INVALID" };
                 out.push_str(&format!("       arXiv: {} ({})\n", ax.arxiv_id, valid));
+                if let Some(published) = &ax.published_doi {
+                    out.push_str(&format!("       Published as: {}\n", published));
+                }
+            }
+            if let Some(relation) = r.version_relation {
+                out.push_str(&format!(
+                    "       Matched version: {} ({})\n",
+                    relation.description(),
+                    r.source.as_deref().unwrap_or("unknown")
+                ));
             }
 
             // Retraction details
@@ -799,6 +1021,17 @@ fn export_text(
                 }
             }
 
+            // Near-miss suggestion
+            if let Some((nm_title, nm_score)) = &r.near_miss {
+                out.push_str(&format!(
+                    "       Closest match: {} ({:.2})\n",
+                    nm_title, nm_score
+                ));
+            }
+            if r.insufficient_coverage {
+                out.push_str(&format!("       Low coverage: {}\n", coverage_caveat(r)));
+            }
+
             // Paper URL
             if let Some(url) = &r.paper_url {
                 out.push_str(&format!("       URL: {}\n", url));
@@ -827,6 +1060,7 @@ fn export_text(
                     let reason = match rs.skip_info.as_ref().map(|s| s.reason.as_str()) {
                         Some("url_only") => "URL-only",
                         Some("short_title") => "Short title",
+                        Some("placeholder") => "Placeholder",
                         Some("no_title") => "No title",
                         Some(other) => other,
                         None => "",
@@ -840,6 +1074,14 @@ fn export_text(
                 }
             }
         }
+
+        let uncited = uncited_refs(paper, paper_refs);
+        if !uncited.is_empty() {
+            out.push_str("\n  Listed but never cited:\n");
+            for (ref_num, r) in &uncited {
+                out.push_str(&format!("  [{}] {}\n", ref_num, r.title));
+            }
+        }
     }
     out
 }
@@ -851,6 +1093,88 @@ fn html_escape(s: &str) -> String {
         .replace('"', "&quot;")
 }
 
+/// Escape a BibTeX field value: braces and backslashes are dropped (they
+/// would unbalance the `{...}` delimiters), LaTeX specials are escaped.
+fn bib_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '{' | '}' | '\\' => {}
+            '&' | '%' | '$' | '#' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Last plausible publication year (19xx/20xx, not part of a longer number)
+/// in a raw citation; styles that put the year up front rarely end in one.
+fn citation_year(raw: &str) -> Option<&str> {
+    let bytes = raw.as_bytes();
+    (0..bytes.len().saturating_sub(3))
+        .rev()
+        .find(|&i| {
+            let window = &bytes[i..i + 4];
+            window.iter().all(u8::is_ascii_digit)
+                && (window.starts_with(b"19") || window.starts_with(b"20"))
+                && (i == 0 || !bytes[i - 1].is_ascii_digit())
+                && bytes.get(i + 4).is_none_or(|b| !b.is_ascii_digit())
+        })
+        .map(|i| &raw[i..i + 4])
+}
+
+/// NotFound and AuthorMismatch references as `@misc` entries (title, authors,
+/// year, DOI), ready to hand back to authors for correction or to re-check.
+/// References the user marked as false positives are left out.
+fn export_bibtex(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
+    let mut out = String::from("% Unverified references exported by hallucinator\n");
+    let mut n = 0;
+    for (pi, paper) in papers.iter().enumerate() {
+        let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
+        for sref in build_sorted_refs(paper, paper_refs) {
+            let r = sref.result;
+            if sref.fp.is_some() || r.status == Status::Verified {
+                continue;
+            }
+            n += 1;
+            out.push_str(&format!("\n@misc{{unverified{},\n", n));
+            out.push_str(&format!("  title = {{{}}},\n", bib_escape(&r.title)));
+            let authors: Vec<String> = r
+                .ref_authors
+                .iter()
+                .filter(|a| !a.to_lowercase().starts_with("et al"))
+                .map(|a| bib_escape(a))
+                .collect();
+            if !authors.is_empty() {
+                out.push_str(&format!("  author = {{{}}},\n", authors.join(" and ")));
+            }
+            if let Some(year) = citation_year(&r.raw_citation) {
+                out.push_str(&format!("  year = {{{}}},\n", year));
+            }
+            if let Some(doi) = &r.doi_info {
+                out.push_str(&format!("  doi = {{{}}},\n", bib_escape(&doi.doi)));
+            }
+            let cite_key = r
+                .cite_key
+                .as_ref()
+                .map(|k| format!(" ({})", bib_escape(k)))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "  note = {{{}, reference {}{}: {}}},\n",
+                bib_escape(paper.filename),
+                sref.ref_num,
+                cite_key,
+                status_str(&r.status).replace('_', " ")
+            ));
+            out.push_str("}\n");
+        }
+    }
+    out
+}
+
 fn export_html(
     papers: &[ReportPaper<'_>],
     ref_states: &[&[ReportRef]],
@@ -1112,7 +1436,7 @@ body.hide-verified .ref-card[data-status="verified"] { display: none; }
         out.push_str(&format!(
             "<details data-order=\"{}\" data-not-found=\"{}\" data-mismatch=\"{}\" data-retracted=\"{}\" data-pct=\"{:.2}\">\n<summary>{}{} <span class=\"paper-stats\">{} total &middot; {} verified &middot; {} not found &middot; {} mismatch &middot; {} retracted &middot; {} skipped &middot; {:.1}% problematic</span></summary>\n<div class=\"paper-content\">\n",
             pi, s.not_found, s.author_mismatch, s.retracted, pp,
-            html_escape(paper.filename),
+            html_escape(&paper.label()),
             verdict_html,
             s.total, s.verified, s.not_found, s.author_mismatch, s.retracted, s.skipped, pp,
         ));
@@ -1142,6 +1466,7 @@ body.hide-verified .ref-card[data-status="verified"] { display: none; }
                 let reason = match rs.skip_info.as_ref().map(|s| s.reason.as_str()) {
                     Some("url_only") => "URL-only",
                     Some("short_title") => "Short title",
+                    Some("placeholder") => "Placeholder",
                     Some("no_title") => "No title",
                     Some(other) => other,
                     None => "",
@@ -1160,6 +1485,20 @@ body.hide-verified .ref-card[data-status="verified"] { display: none; }
             }
         }
 
+        let uncited = uncited_refs(paper, paper_refs);
+        if !uncited.is_empty() {
+            out.push_str(
+                "<h3 style=\"color:var(--dim);margin-top:1.5rem\">Listed but Never Cited</h3>\n",
+            );
+            for (ref_num, r) in &uncited {
+                out.push_str(&format!(
+                    "<div class=\"ref-card\"><div class=\"ref-header\"><span class=\"ref-num\">[{}]</span><span class=\"ref-title\">{}</span><span class=\"badge\" style=\"background:var(--dim);color:#fff\">Uncited</span></div></div>\n",
+                    ref_num,
+                    html_escape(&r.title),
+                ));
+            }
+        }
+
         out.push_str("</div>\n</details>\n");
     }
     out.push_str("</div>\n"); // close #papers
@@ -1248,11 +1587,24 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
     }
     out.push_str("</div>\n");
 
+    if let Some(key) = &r.cite_key {
+        out.push_str(&format!(
+            "<div class=\"ref-detail\">Cite key: <code>{}</code></div>\n",
+            html_escape(key)
+        ));
+    }
+
     // Source
     if let Some(src) = &r.source {
+        let others = if r.verified_by_count > 1 {
+            format!(" (verified by {} sources)", r.verified_by_count)
+        } else {
+            String::new()
+        };
         out.push_str(&format!(
-            "<div class=\"ref-detail\">Verified via <strong>{}</strong></div>\n",
-            html_escape(src)
+            "<div class=\"ref-detail\">Verified via <strong>{}</strong>{}</div>\n",
+            html_escape(src),
+            others
         ));
     }
 
@@ -1277,19 +1629,41 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
 
     // DOI / arXiv
     if let Some(doi) = &r.doi_info {
-        if doi.valid {
+        if doi.valid && doi.title_mismatch {
+            out.push_str(&format!(
+                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: <a href=\"https://doi.org/{}\">{}</a> (resolves to a different title: {})</div>\n",
+                html_escape(&doi.doi),
+                html_escape(&doi.doi),
+                html_escape(doi.title.as_deref().unwrap_or("")),
+            ));
+        } else if doi.valid {
             out.push_str(&format!(
                 "<div class=\"ref-detail\">DOI: <a href=\"https://doi.org/{}\">{}</a></div>\n",
                 html_escape(&doi.doi),
                 html_escape(&doi.doi),
             ));
         } else {
+            let issue = if doi.registered {
+                "invalid"
+            } else {
+                "not registered \u{2014} likely fabricated"
+            };
             out.push_str(&format!(
-                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: {} (invalid)</div>\n",
+                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: {} ({})</div>\n",
                 html_escape(&doi.doi),
+                issue,
             ));
         }
     }
+    if let Some(conflict) = &r.doi_conflict {
+        out.push_str(&format!(
+            "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI conflict: {} resolves to {}, but {} matched {}</div>\n",
+            html_escape(&conflict.doi),
+            html_escape(&conflict.doi_title),
+            html_escape(&conflict.matched_source),
+            html_escape(&conflict.matched_title),
+        ));
+    }
     if let Some(ax) = &r.arxiv_info {
         if ax.valid {
             out.push_str(&format!(
@@ -1297,6 +1671,13 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
                 html_escape(&ax.arxiv_id),
                 html_escape(&ax.arxiv_id),
             ));
+            if let Some(published) = &ax.published_doi {
+                out.push_str(&format!(
+                    "<div class=\"ref-detail\">Published as: <a href=\"https://doi.org/{}\">{}</a></div>\n",
+                    html_escape(published),
+                    html_escape(published),
+                ));
+            }
         } else {
             out.push_str(&format!(
                 "<div class=\"ref-detail\" style=\"color:var(--red)\">arXiv: {} (invalid)</div>\n",
@@ -1305,6 +1686,14 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
         }
     }
 
+    if let Some(relation) = r.version_relation {
+        out.push_str(&format!(
+            "<div class=\"ref-detail\">Matched version: {} ({})</div>\n",
+            relation.description(),
+            html_escape(r.source.as_deref().unwrap_or("unknown")),
+        ));
+    }
+
     // Retraction warning
     if let Some(ret) = &r.retraction_info
         && ret.is_retracted
@@ -1322,6 +1711,21 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
         out.push_str("</div>\n");
     }
 
+    // Near-miss suggestion
+    if let Some((nm_title, nm_score)) = &r.near_miss {
+        out.push_str(&format!(
+            "<div class=\"ref-detail\">Closest match: {} ({:.2})</div>\n",
+            html_escape(nm_title),
+            nm_score
+        ));
+    }
+    if r.insufficient_coverage {
+        out.push_str(&format!(
+            "<div class=\"ref-detail\">Low coverage: {}</div>\n",
+            html_escape(&coverage_caveat(r))
+        ));
+    }
+
     // Links
     out.push_str("<div class=\"links\">");
     if let Some(url) = &r.paper_url {
@@ -1384,6 +1788,7 @@ mod tests {
         ValidationResult {
             title: title.to_string(),
             raw_citation: String::new(),
+            cite_key: None,
             ref_authors: vec![],
             status,
             source: None,
@@ -1394,6 +1799,13 @@ mod tests {
             doi_info: None,
             arxiv_info: None,
             retraction_info: None,
+            near_miss: None,
+            author_match_detail: vec![],
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 0,
+            insufficient_coverage: false,
+            cited_in_text: None,
         }
     }
 
@@ -1404,6 +1816,7 @@ mod tests {
     ) -> ReportPaper<'a> {
         ReportPaper {
             filename,
+            title: None,
             stats,
             results,
             verdict: None,
@@ -1653,6 +2066,9 @@ mod tests {
             doi: "10.bad".into(),
             valid: false,
             title: None,
+            title_mismatch: false,
+            well_formed: false,
+            registered: true,
         });
 
         let results: Vec<Option<ValidationResult>> = vec![
@@ -1769,13 +2185,82 @@ mod tests {
     #[test]
     fn test_export_format_all() {
         let all = ExportFormat::all();
-        assert_eq!(all.len(), 5);
+        assert_eq!(all.len(), 6);
         for fmt in all {
             assert!(!fmt.label().is_empty());
             assert!(!fmt.extension().is_empty());
         }
     }
 
+    #[test]
+    fn test_citation_year() {
+        assert_eq!(
+            citation_year("A. Doe. Title. In Proc. X, 2019."),
+            Some("2019")
+        );
+        assert_eq!(
+            citation_year("Doe (2001). Title. pp. 12019-12030."),
+            Some("2001")
+        );
+        assert_eq!(citation_year("Doe. Title. Tech report 1234."), None);
+    }
+
+    #[test]
+    fn test_bibtex_roundtrips_through_bib_parser() {
+        let stats = CheckStats::default();
+        let mut not_found = make_result(
+            "A Study of Imaginary Neural Architectures",
+            Status::NotFound,
+        );
+        not_found.ref_authors = vec!["Jane Doe".into(), "John Smith".into(), "et al.".into()];
+        not_found.raw_citation =
+            "J. Doe, J. Smith, et al. A Study of Imaginary Neural Architectures. NeurIPS, 2023."
+                .into();
+        not_found.doi_info = Some(DoiInfo {
+            doi: "10.1234/fake.2023.001".into(),
+            valid: false,
+            title: None,
+            title_mismatch: false,
+            well_formed: true,
+            registered: false,
+        });
+        let mut mismatch = make_result(
+            "Robust Learning & Control for Quadruped Robots",
+            Status::AuthorMismatch,
+        );
+        mismatch.ref_authors = vec!["Alice Wong".into()];
+        let fp = make_result("Another Missing Paper About Graphs", Status::NotFound);
+        let verified = make_result("A Perfectly Real Verified Paper", Status::Verified);
+        let results = vec![Some(not_found), Some(mismatch), Some(fp), Some(verified)];
+        let paper = make_paper("paper.pdf", &stats, &results);
+        let refs = vec![
+            make_ref(0, "A Study of Imaginary Neural Architectures"),
+            make_ref(1, "Robust Learning & Control for Quadruped Robots"),
+            make_ref_fp(2, "Another Missing Paper About Graphs", FpReason::KnownGood),
+            make_ref(3, "A Perfectly Real Verified Paper"),
+        ];
+
+        let bib = export_bibtex(&[paper], &[&refs]);
+        assert_eq!(bib.matches("@misc{").count(), 2);
+        assert!(bib.contains("year = {2023}"));
+
+        let parsed = hallucinator_bbl::extract_references_from_bib_str(&bib).unwrap();
+        assert_eq!(parsed.references.len(), 2);
+        let first = &parsed.references[0];
+        assert_eq!(
+            first.title.as_deref(),
+            Some("A Study of Imaginary Neural Architectures")
+        );
+        assert_eq!(first.authors.len(), 2);
+        assert_eq!(first.doi.as_deref(), Some("10.1234/fake.2023.001"));
+        let second = &parsed.references[1];
+        assert_eq!(
+            second.title.as_deref(),
+            Some("Robust Learning & Control for Quadruped Robots")
+        );
+        assert_eq!(second.authors.len(), 1);
+    }
+
     // ── P4: format integration (smoke tests) ────────────────────────
 
     #[test]
@@ -1808,6 +2293,181 @@ mod tests {
         assert!(out.contains("\"status\": \"verified\""));
     }
 
+    #[test]
+    fn test_json_near_miss() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 0,
+            not_found: 1,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Atention Is All You Ned", Status::NotFound);
+        r.near_miss = Some(("Attention Is All You Need".into(), 0.82));
+        let results = vec![Some(r)];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Atention Is All You Ned")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices, false);
+        assert!(out.contains(
+            "\"near_miss\": {\"title\": \"Attention Is All You Need\", \"score\": 0.820}"
+        ));
+    }
+
+    #[test]
+    fn test_doi_conflict_in_exports() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 1,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Attention Is All You Need", Status::Verified);
+        r.doi_conflict = Some(hallucinator_core::DoiConflict {
+            doi: "10.1000/xyz".into(),
+            doi_title: "Deep Residual Learning".into(),
+            matched_source: "DBLP".into(),
+            matched_title: "Attention Is All You Need".into(),
+        });
+        let results = vec![Some(r)];
+        let paper = || make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Attention Is All You Need")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let json = export_json(&[paper()], ref_slices, false);
+        assert!(json.contains(
+            "\"doi_conflict\": {\"doi\": \"10.1000/xyz\", \"doi_title\": \"Deep Residual Learning\", \"matched_source\": \"DBLP\", \"matched_title\": \"Attention Is All You Need\"}"
+        ));
+        assert!(export_csv(&[paper()], ref_slices, false).contains(",Deep Residual Learning,\n"));
+        let md = export_markdown(&[paper()], ref_slices, false);
+        assert!(
+            md.contains("- **DOI conflict:** `10.1000/xyz` resolves to Deep Residual Learning")
+        );
+        let text = export_text(&[paper()], ref_slices, false);
+        assert!(text.contains("DOI conflict: 10.1000/xyz resolves to \"Deep Residual Learning\""));
+        assert!(export_html(&[paper()], ref_slices, false).contains("DOI conflict: 10.1000/xyz"));
+    }
+
+    #[test]
+    fn test_version_relation_in_exports() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 1,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Attention Is All You Need", Status::Verified);
+        r.source = Some("DBLP".into());
+        r.version_relation = Some(hallucinator_core::VersionRelation::PublishedVersion);
+        let results = vec![Some(r)];
+        let paper = || make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Attention Is All You Need")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let json = export_json(&[paper()], ref_slices, false);
+        assert!(json.contains("\"version_relation\": \"published_version\""));
+        let csv = export_csv(&[paper()], ref_slices, false);
+        assert!(csv.lines().nth(1).unwrap().ends_with(",published_version"));
+        let md = export_markdown(&[paper()], ref_slices, false);
+        assert!(md.contains("| DBLP (published version of the cited preprint) |"));
+        let expected = "Matched version: published version of the cited preprint (DBLP)";
+        assert!(export_text(&[paper()], ref_slices, false).contains(expected));
+        assert!(export_html(&[paper()], ref_slices, false).contains(expected));
+    }
+
+    #[test]
+    fn test_json_author_match_detail() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 1,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Some Paper", Status::Verified);
+        r.author_match_detail = vec![("A. Smith".into(), true), ("B. Jones".into(), false)];
+        let results = vec![Some(r)];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Some Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices, false);
+        assert!(out.contains(
+            "\"author_match_detail\": [{\"author\": \"A. Smith\", \"matched\": true}, {\"author\": \"B. Jones\", \"matched\": false}]"
+        ));
+    }
+
+    #[test]
+    fn test_insufficient_coverage_caveat() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 0,
+            not_found: 1,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Obscure Paper", Status::NotFound);
+        r.insufficient_coverage = true;
+        assert_eq!(
+            coverage_caveat(&r),
+            "too few online databases were queried, so this may be a false alarm"
+        );
+        r.failed_dbs = vec!["CrossRef".into(), "arXiv".into()];
+        let results = vec![Some(r)];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Obscure Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let json = export_json(std::slice::from_ref(&paper), ref_slices, false);
+        assert!(json.contains("\"insufficient_coverage\": true"));
+        let text = export_text(std::slice::from_ref(&paper), ref_slices, false);
+        let caveat = "too few online databases answered (CrossRef, arXiv timed out or failed)";
+        assert!(text.contains(&format!("Low coverage: {caveat}")));
+        let md = export_markdown(std::slice::from_ref(&paper), ref_slices, false);
+        assert!(md.contains(&format!("**Low coverage:** {caveat}")));
+        let html = export_html(&[paper], ref_slices, false);
+        assert!(html.contains(&format!("Low coverage: {caveat}")));
+    }
+
+    #[test]
+    fn test_listed_but_never_cited() {
+        let stats = CheckStats {
+            total: 2,
+            verified: 2,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut cited = make_result("Cited Paper", Status::Verified);
+        cited.cited_in_text = Some(true);
+        let mut uncited = make_result("Padding Paper", Status::Verified);
+        uncited.cited_in_text = Some(false);
+        let results = vec![Some(cited), Some(uncited)];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Cited Paper"), make_ref(1, "Padding Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let json = export_json(std::slice::from_ref(&paper), ref_slices, false);
+        assert!(json.contains("\"cited_in_text\": true"));
+        assert!(json.contains("\"cited_in_text\": false"));
+        let text = export_text(std::slice::from_ref(&paper), ref_slices, false);
+        assert!(text.contains("Listed but never cited:\n  [2] Padding Paper\n"));
+        let md = export_markdown(std::slice::from_ref(&paper), ref_slices, true);
+        assert!(md.contains(
+            "### Listed but Never Cited\n\n| # | Title |\n|---|-------|\n| 2 | Padding Paper |\n"
+        ));
+        assert!(!md.contains("| 1 | Cited Paper |"));
+        let html = export_html(&[paper], ref_slices, false);
+        assert!(html.contains("Listed but Never Cited"));
+    }
+
     #[test]
     fn test_json_skipped_ref() {
         let stats = CheckStats {
@@ -1853,7 +2513,7 @@ mod tests {
         let first_line = out.lines().next().unwrap();
         assert_eq!(
             first_line,
-            "Filename,Verdict,Ref#,Title,Status,EffectiveStatus,FpReason,Source,Retracted,Authors,FoundAuthors,PaperURL,DOI,ArxivID,FailedDBs",
+            "Filename,Verdict,Ref#,Title,Status,EffectiveStatus,FpReason,Source,Retracted,Authors,FoundAuthors,PaperURL,DOI,ArxivID,FailedDBs,CiteKey,DoiConflict,VersionRelation",
         );
     }
 
@@ -1878,6 +2538,37 @@ mod tests {
         assert!(lines[1].contains("My Paper"));
     }
 
+    #[test]
+    fn test_cite_key_in_all_formats() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 0,
+            not_found: 1,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut result = make_result("Made Up Paper", Status::NotFound);
+        result.cite_key = Some("smith2023made".to_string());
+        let results = vec![Some(result)];
+        let refs = vec![make_ref(0, "Made Up Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let paper = || make_paper("paper.bbl", &stats, &results);
+
+        let json = export_json(&[paper()], ref_slices, false);
+        assert!(json.contains("\"cite_key\": \"smith2023made\""));
+        let csv = export_csv(&[paper()], ref_slices, false);
+        assert!(csv.lines().nth(1).unwrap().ends_with(",smith2023made,,"));
+        let md = export_markdown(&[paper()], ref_slices, false);
+        assert!(md.contains("- **Cite key:** `smith2023made`"));
+        let text = export_text(&[paper()], ref_slices, false);
+        assert!(text.contains("Cite key: smith2023made"));
+        let html = export_html(&[paper()], ref_slices, false);
+        assert!(html.contains("<code>smith2023made</code>"));
+        let bib = export_bibtex(&[paper()], ref_slices);
+        assert!(bib.contains("reference 1 (smith2023made): not found"));
+    }
+
     #[test]
     fn test_markdown_structure() {
         let stats = CheckStats {
@@ -1944,6 +2635,60 @@ mod tests {
         assert!(out.contains("</html>"));
     }
 
+    #[test]
+    fn test_verified_by_count_in_json_and_html() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 1,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+        };
+        let mut r = make_result("Paper", Status::Verified);
+        r.source = Some("DBLP".into());
+        r.verified_by_count = 3;
+        let results = vec![Some(r)];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let json = export_json(&[paper], ref_slices, false);
+        assert!(json.contains("\"verified_by_count\": 3"));
+        let paper = make_paper("f.pdf", &stats, &results);
+        let html = export_html(&[paper], ref_slices, false);
+        assert!(html.contains("Verified via <strong>DBLP</strong> (verified by 3 sources)"));
+    }
+
+    #[test]
+    fn test_document_title_labels_paper() {
+        let stats = CheckStats::default();
+        let results: Vec<Option<ValidationResult>> = vec![];
+        let empty_refs: &[ReportRef] = &[];
+        let ref_slices: &[&[ReportRef]] = &[empty_refs];
+        let titled = || ReportPaper {
+            title: Some("Deep Residual Learning"),
+            ..make_paper("2403.10573v1.pdf", &stats, &results)
+        };
+
+        let md = export_markdown(&[titled()], ref_slices, false);
+        assert!(md.contains("## Deep Residual Learning (2403.10573v1.pdf)\n"));
+        let text = export_text(&[titled()], ref_slices, false);
+        assert!(text.contains("\nDeep Residual Learning (2403.10573v1.pdf)\n"));
+        let json = export_json(&[titled()], ref_slices, false);
+        assert!(json.contains("\"title\": \"Deep Residual Learning\""));
+        let run: Vec<hallucinator_core::PaperRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(run[0].title.as_deref(), Some("Deep Residual Learning"));
+        assert_eq!(run[0].filename, "2403.10573v1.pdf");
+
+        // Without a title the filename alone labels the paper.
+        let untitled = make_paper("2403.10573v1.pdf", &stats, &results);
+        let md = export_markdown(&[untitled], ref_slices, false);
+        assert!(md.contains("## 2403.10573v1.pdf\n"));
+        let untitled = make_paper("2403.10573v1.pdf", &stats, &results);
+        assert!(export_json(&[untitled], ref_slices, false).contains("\"title\": null"));
+    }
+
     #[test]
     fn test_html_verdict_badges() {
         let stats = CheckStats::default();
@@ -1963,6 +2708,42 @@ mod tests {
 
     // ── P5: problematic_only filtering ────────────────────────────────
 
+    #[test]
+    fn test_json_matches_run_schema() {
+        let stats = CheckStats {
+            total: 2,
+            verified: 0,
+            not_found: 1,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 1,
+        };
+        let mut r = make_result("Atention Is All You Ned", Status::NotFound);
+        r.near_miss = Some(("Attention Is All You Need".into(), 0.82));
+        let results = vec![Some(r), None];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![
+            make_ref_fp(0, "Atention Is All You Ned", FpReason::KnownGood),
+            make_ref_skipped(1, "Short", "short_title"),
+        ];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices, false);
+
+        let run = hallucinator_core::parse_run(&out).unwrap();
+        assert_eq!(run.len(), 1);
+        assert_eq!(run[0].filename, "test.pdf");
+        let refs = &run[0].references;
+        assert_eq!(refs[0].status, hallucinator_core::RecordStatus::NotFound);
+        assert_eq!(refs[0].fp_reason.as_deref(), Some("known_good"));
+        assert_eq!(refs[0].near_miss.as_ref().unwrap().score, 0.82);
+        assert_eq!(
+            refs[0].fingerprint.as_deref(),
+            Some(results[0].as_ref().unwrap().fingerprint().as_str())
+        );
+        assert_eq!(refs[1].status, hallucinator_core::RecordStatus::Skipped);
+        assert_eq!(refs[1].skip_reason.as_deref(), Some("short_title"));
+    }
+
     #[test]
     fn test_json_problematic_only_excludes_verified() {
         let stats = CheckStats {
@@ -1979,6 +2760,7 @@ mod tests {
             arxiv_id: "2511.12345".to_string(),
             valid: false,
             title: None,
+            published_doi: None,
         });
         let results = vec![
             Some(make_result("Good Paper", Status::Verified)),
@@ -2094,6 +2876,7 @@ mod tests {
             arxiv_id: "2511.99999".to_string(),
             valid: false,
             title: None,
+            published_doi: None,
         });
         let results = vec![
             Some(make_result("Good Paper", Status::Verified)),
@@ -1,5 +1,9 @@
 pub mod export;
+pub mod metrics;
 pub mod types;
 
-pub use export::{export_json, export_results};
-pub use types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef, SkipInfo};
+pub use export::{coverage_caveat, export_json, export_results};
+pub use metrics::append_metrics;
+pub use types::{
+    ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef, SkipInfo, paper_label,
+};
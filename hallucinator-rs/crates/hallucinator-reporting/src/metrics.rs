@@ -0,0 +1,109 @@
+//! Per-run summary metrics, appended one JSON object per line to a
+//! user-chosen file so results can be tracked over time.
+
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hallucinator_core::CheckStats;
+
+use crate::export::json_str;
+
+/// Append a metrics record for one checked file to `path`, creating the
+/// file if needed. The record is stamped with the current time.
+pub fn append_metrics(
+    path: &Path,
+    file: &str,
+    stats: &CheckStats,
+    elapsed: Duration,
+) -> Result<(), String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let line = metrics_line(timestamp, file, stats, elapsed);
+    let mut out = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open metrics file: {}", e))?;
+    // One write per record, so concurrent appenders don't interleave lines.
+    out.write_all(line.as_bytes())
+        .map_err(|e| format!("Failed to write metrics: {}", e))
+}
+
+/// A metrics record as a single JSON line (with trailing newline).
+/// `timestamp` is in seconds since the Unix epoch.
+pub fn metrics_line(timestamp: u64, file: &str, stats: &CheckStats, elapsed: Duration) -> String {
+    format!(
+        "{{\"timestamp\": {}, \"file\": {}, \"total\": {}, \"verified\": {}, \"not_found\": {}, \"author_mismatch\": {}, \"retracted\": {}, \"skipped\": {}, \"elapsed_secs\": {:.3}}}\n",
+        timestamp,
+        json_str(file),
+        stats.total,
+        stats.verified,
+        stats.not_found,
+        stats.author_mismatch,
+        stats.retracted,
+        stats.skipped,
+        elapsed.as_secs_f64(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> CheckStats {
+        CheckStats {
+            total: 12,
+            verified: 8,
+            not_found: 2,
+            author_mismatch: 1,
+            retracted: 1,
+            skipped: 1,
+        }
+    }
+
+    #[test]
+    fn test_append_metrics_adds_one_record_per_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.jsonl");
+
+        append_metrics(
+            &path,
+            "paper \"a\".pdf",
+            &stats(),
+            Duration::from_millis(4250),
+        )
+        .unwrap();
+        append_metrics(&path, "b.pdf", &CheckStats::default(), Duration::ZERO).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<serde_json::Value> = content
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+
+        let first = &records[0];
+        assert!(first["timestamp"].as_u64().unwrap() > 0);
+        assert_eq!(first["file"], "paper \"a\".pdf");
+        assert_eq!(first["total"], 12);
+        assert_eq!(first["verified"], 8);
+        assert_eq!(first["not_found"], 2);
+        assert_eq!(first["author_mismatch"], 1);
+        assert_eq!(first["retracted"], 1);
+        assert_eq!(first["skipped"], 1);
+        assert_eq!(first["elapsed_secs"], 4.25);
+        assert_eq!(records[1]["file"], "b.pdf");
+        assert_eq!(records[1]["verified"], 0);
+    }
+
+    #[test]
+    fn test_metrics_line_is_a_single_line() {
+        let line = metrics_line(1_700_000_000, "multi\nline.pdf", &stats(), Duration::ZERO);
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1);
+        assert!(line.starts_with("{\"timestamp\": 1700000000, \"file\": \"multi\\nline.pdf\""));
+    }
+}
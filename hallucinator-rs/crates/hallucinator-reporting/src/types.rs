@@ -110,6 +110,8 @@ pub enum ExportFormat {
     Markdown,
     Text,
     Html,
+    /// NotFound / AuthorMismatch references only, as minimal BibTeX entries.
+    Bibtex,
 }
 
 impl ExportFormat {
@@ -120,6 +122,7 @@ impl ExportFormat {
             ExportFormat::Markdown,
             ExportFormat::Text,
             ExportFormat::Html,
+            ExportFormat::Bibtex,
         ]
     }
 
@@ -130,6 +133,7 @@ impl ExportFormat {
             Self::Markdown => "Markdown",
             Self::Text => "Plain Text",
             Self::Html => "HTML",
+            Self::Bibtex => "BibTeX (unverified)",
         }
     }
 
@@ -140,6 +144,7 @@ impl ExportFormat {
             Self::Markdown => "md",
             Self::Text => "txt",
             Self::Html => "html",
+            Self::Bibtex => "bib",
         }
     }
 }
@@ -148,11 +153,29 @@ impl ExportFormat {
 /// Consumers (TUI, CLI) build this from their internal state types.
 pub struct ReportPaper<'a> {
     pub filename: &'a str,
+    /// The document's own title, when one could be extracted.
+    pub title: Option<&'a str>,
     pub stats: &'a CheckStats,
     pub results: &'a [Option<ValidationResult>],
     pub verdict: Option<PaperVerdict>,
 }
 
+impl ReportPaper<'_> {
+    /// How the paper is headed in reports; see [`paper_label`].
+    pub fn label(&self) -> String {
+        paper_label(self.filename, self.title)
+    }
+}
+
+/// How a paper is named in reports and the TUI: its title followed by the
+/// filename, or just the filename when no title was extracted.
+pub fn paper_label(filename: &str, title: Option<&str>) -> String {
+    match title {
+        Some(title) => format!("{} ({})", title, filename),
+        None => filename.to_string(),
+    }
+}
+
 /// Lightweight input struct for a single reference, used by the export module.
 pub struct ReportRef {
     pub index: usize,
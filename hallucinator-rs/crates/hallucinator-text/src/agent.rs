@@ -0,0 +1,16 @@
+//! The `User-Agent` every hallucinator HTTP client sends.
+
+/// Where to reach the maintainers when no contact address is configured.
+pub const PROJECT_URL: &str = "https://github.com/gianlucasb/hallucinator";
+
+/// `User-Agent` value: `hallucinator/<version> (+<contact>)`.
+///
+/// `contact` is an email address (sent as `mailto:`); without one, the
+/// project URL is used.
+pub fn user_agent(contact: Option<&str>) -> String {
+    let contact = match contact.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(email) => format!("mailto:{}", email),
+        None => PROJECT_URL.to_string(),
+    };
+    format!("hallucinator/{} (+{})", env!("CARGO_PKG_VERSION"), contact)
+}
@@ -0,0 +1,186 @@
+//! Retry handling shared by the offline database builders' downloads.
+
+use std::time::Duration;
+
+/// A failed download attempt, split by whether trying again could help.
+/// `E` is the builder's own error type.
+#[derive(Debug)]
+pub enum AttemptError<E> {
+    /// Connection failure, 5xx/429 response or interrupted stream.
+    Transient(String),
+    Fatal(E),
+}
+
+impl<E> AttemptError<E> {
+    /// Classify a non-success HTTP status: 5xx and 429 are transient,
+    /// anything else is wrapped by `fatal`.
+    pub fn from_status(
+        status: reqwest::StatusCode,
+        message: String,
+        fatal: impl FnOnce(String) -> E,
+    ) -> Self {
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            AttemptError::Transient(message)
+        } else {
+            AttemptError::Fatal(fatal(message))
+        }
+    }
+}
+
+impl<E: From<std::io::Error>> From<std::io::Error> for AttemptError<E> {
+    fn from(e: std::io::Error) -> Self {
+        AttemptError::Fatal(e.into())
+    }
+}
+
+/// The client a builder downloads with when its options supply none: it
+/// identifies the tool and otherwise keeps reqwest's defaults, including the
+/// `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY` environment variables.
+pub fn default_client() -> reqwest::Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .user_agent(crate::user_agent(None))
+        .build()
+}
+
+/// Handle a failed attempt: if it was transient and retries remain, bump
+/// `attempt`, report it through `on_retry(attempt, error)` and sleep out the
+/// exponential backoff (`retry_delay` doubled per retry); otherwise return the
+/// error that ends the build, with transient messages wrapped by
+/// `download_error`.
+pub async fn retry_or_fail<E>(
+    err: AttemptError<E>,
+    attempt: &mut u32,
+    max_retries: u32,
+    retry_delay: Duration,
+    on_retry: impl FnOnce(u32, String),
+    download_error: impl FnOnce(String) -> E,
+) -> Result<(), E> {
+    match err {
+        AttemptError::Transient(error) if *attempt < max_retries => {
+            *attempt += 1;
+            on_retry(*attempt, error);
+            let backoff = 2u32.saturating_pow(*attempt - 1);
+            tokio::time::sleep(retry_delay.saturating_mul(backoff)).await;
+            Ok(())
+        }
+        AttemptError::Transient(error) if *attempt > 0 => Err(download_error(format!(
+            "{} (gave up after {} retries)",
+            error, attempt
+        ))),
+        AttemptError::Transient(error) => Err(download_error(error)),
+        AttemptError::Fatal(e) => Err(e),
+    }
+}
+
+/// A local HTTP server for the builders' download tests.
+#[cfg(feature = "test-util")]
+pub mod stub {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve `path` on a local port, answering the n-th connection with the
+    /// n-th `(status line, body)` and closing it, repeating the last response
+    /// once the list runs out. Returns the URL and the (lowercased) head of
+    /// each request served.
+    pub async fn spawn_http_stub(
+        path: &str,
+        responses: Vec<(&'static str, Vec<u8>)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}{}", listener.local_addr().unwrap(), path);
+        let requests: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 1024];
+                while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match socket.read(&mut chunk).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                    }
+                }
+                let n = {
+                    let mut seen = seen.lock().unwrap();
+                    seen.push(String::from_utf8_lossy(&buf).to_lowercase());
+                    seen.len() - 1
+                };
+                let (status, body) = &responses[n.min(responses.len() - 1)];
+                let head = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                let _ = socket.write_all(head.as_bytes()).await;
+                let _ = socket.write_all(body).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+        (url, requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Download(String),
+    }
+
+    #[tokio::test]
+    async fn test_retry_or_fail_backs_off_then_gives_up() {
+        let mut attempt = 0;
+        let mut retries = Vec::new();
+        for _ in 0..2 {
+            retry_or_fail(
+                AttemptError::Transient("503".into()),
+                &mut attempt,
+                2,
+                Duration::ZERO,
+                |n, error| retries.push((n, error)),
+                TestError::Download,
+            )
+            .await
+            .unwrap();
+        }
+        assert_eq!(retries, [(1, "503".into()), (2, "503".into())]);
+
+        let err = retry_or_fail(
+            AttemptError::Transient("503".into()),
+            &mut attempt,
+            2,
+            Duration::ZERO,
+            |_, _| unreachable!(),
+            TestError::Download,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err,
+            TestError::Download("503 (gave up after 2 retries)".into())
+        );
+    }
+
+    #[test]
+    fn test_status_classification() {
+        let transient = AttemptError::from_status(
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+            "429".into(),
+            TestError::Download,
+        );
+        assert!(matches!(transient, AttemptError::Transient(_)));
+        let fatal = AttemptError::from_status(
+            reqwest::StatusCode::NOT_FOUND,
+            "404".into(),
+            TestError::Download,
+        );
+        assert!(matches!(fatal, AttemptError::Fatal(TestError::Download(_))));
+    }
+}
@@ -0,0 +1,110 @@
+//! FTS5 candidate lookup shared by the SQLite backends (DBLP, ACL).
+//!
+//! Both index titles in a `publications_fts` table whose rowids are
+//! `publications.id`; they differ only in the columns they read back.
+
+use rusqlite::{Connection, Row, params};
+
+/// How [`fts_candidates`] matches the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FtsLookup {
+    /// Keyword query: every word must appear (FTS5's implicit AND).
+    Keywords,
+    /// Loose [`prefix_query`]. OR queries match far more rows, so they are
+    /// ordered by bm25 to keep the titles sharing the most prefixes.
+    Ranked,
+}
+
+/// Build a loose FTS5 query from query words: each word is cut to its first
+/// half (at least 3 characters) and the prefixes are OR-ed together, so a
+/// typo past the start of a word no longer rules the title out.
+pub fn prefix_query(words: &[String]) -> String {
+    words
+        .iter()
+        .filter_map(|w| {
+            let alnum: String = w
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            let len = (alnum.len() / 2).max(3);
+            (alnum.len() >= 3).then(|| format!("{}*", &alnum[..len]))
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// Run `fts_query` against `publications_fts` and return up to `limit`
+/// candidates, selecting `columns` (e.g. `"p.id, p.title"`) from
+/// `publications p` and converting each row with `row`.
+pub fn fts_candidates<T>(
+    conn: &Connection,
+    columns: &str,
+    lookup: FtsLookup,
+    fts_query: &str,
+    limit: usize,
+    row: impl FnMut(&Row<'_>) -> rusqlite::Result<T>,
+) -> rusqlite::Result<Vec<T>> {
+    let sql = match lookup {
+        FtsLookup::Keywords => format!(
+            "SELECT {columns} FROM publications p \
+             WHERE p.id IN (SELECT rowid FROM publications_fts WHERE title MATCH ?1) \
+             LIMIT ?2"
+        ),
+        FtsLookup::Ranked => format!(
+            "SELECT {columns} FROM publications_fts f \
+             JOIN publications p ON p.id = f.rowid \
+             WHERE publications_fts MATCH ?1 ORDER BY f.rank LIMIT ?2"
+        ),
+    };
+    let mut stmt = conn.prepare_cached(&sql)?;
+    let candidates = stmt
+        .query_map(params![fts_query, limit as i64], row)?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_query() {
+        let words = vec![
+            "bret".to_string(),
+            "bidirectional".to_string(),
+            "what's".to_string(),
+            "l2".to_string(),
+        ];
+        assert_eq!(prefix_query(&words), "bre* OR bidire* OR wha*");
+    }
+
+    #[test]
+    fn test_fts_candidates() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE publications (id INTEGER PRIMARY KEY, title TEXT);
+             CREATE VIRTUAL TABLE publications_fts USING fts5(title);
+             INSERT INTO publications VALUES (1, 'Attention Is All You Need'),
+                                             (2, 'Attention Patterns in Transformers');
+             INSERT INTO publications_fts (rowid, title) SELECT id, title FROM publications;",
+        )
+        .unwrap();
+        let title = |row: &Row<'_>| row.get::<_, String>(0);
+
+        let hits = fts_candidates(
+            &conn,
+            "p.title",
+            FtsLookup::Keywords,
+            "attention need",
+            10,
+            title,
+        )
+        .unwrap();
+        assert_eq!(hits, ["Attention Is All You Need"]);
+
+        let query = prefix_query(&["atention".into(), "patterns".into()]);
+        let hits = fts_candidates(&conn, "p.title", FtsLookup::Ranked, &query, 10, title).unwrap();
+        assert_eq!(hits, ["Attention Patterns in Transformers"]);
+    }
+}
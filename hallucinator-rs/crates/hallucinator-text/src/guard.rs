@@ -0,0 +1,121 @@
+//! The short/generic query guard shared by the offline backends.
+
+/// Guard against short or generic queries that pull huge candidate sets
+/// out of an offline index (e.g. "Deep Learning").
+///
+/// Distinctiveness is measured as the number of query words each backend
+/// keeps for its index lookup (4+ chars, not a stop word).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QueryGuard {
+    /// Queries with fewer distinctive words than this return no match
+    /// without touching the index.
+    pub min_query_words: usize,
+    /// Queries with at most this many distinctive words count as "short".
+    pub short_query_words: usize,
+    /// Threshold floor applied to short queries: a two-word title has to be
+    /// a near-exact match, since the FTS lookup for it matches thousands of
+    /// unrelated records.
+    pub short_query_threshold: f64,
+    /// Candidate limit applied to short queries.
+    pub short_query_candidates: usize,
+    /// When the keyword queries return no candidates at all (typically OCR
+    /// errors or typos in the cited title), retry once with a looser lookup:
+    /// an OR of word prefixes in the SQLite (DBLP, ACL) backends, title
+    /// trigrams in OpenAlex. Never used for short queries, whose loose
+    /// candidates are mostly noise. Off by default: a loose lookup matches
+    /// far more of the index than the keyword queries, so every not-found
+    /// title pays for a much slower query.
+    pub loose_fallback: bool,
+}
+
+impl Default for QueryGuard {
+    fn default() -> Self {
+        Self {
+            min_query_words: 1,
+            short_query_words: 2,
+            short_query_threshold: 0.97,
+            short_query_candidates: 10,
+            loose_fallback: false,
+        }
+    }
+}
+
+impl QueryGuard {
+    /// A guard that never skips or tightens a query.
+    pub fn disabled() -> Self {
+        Self {
+            min_query_words: 0,
+            short_query_words: 0,
+            short_query_threshold: 0.0,
+            short_query_candidates: usize::MAX,
+            loose_fallback: true,
+        }
+    }
+
+    /// Effective `(threshold, candidate_limit)` for a query with `word_count`
+    /// distinctive words against a backend fetching at most `max_candidates`,
+    /// or `None` if the query should be skipped.
+    pub fn limits(
+        &self,
+        word_count: usize,
+        threshold: f64,
+        max_candidates: usize,
+    ) -> Option<(f64, usize)> {
+        if word_count == 0 || word_count < self.min_query_words {
+            return None;
+        }
+        if word_count <= self.short_query_words {
+            Some((
+                threshold.max(self.short_query_threshold),
+                self.short_query_candidates.min(max_candidates),
+            ))
+        } else {
+            Some((threshold, max_candidates))
+        }
+    }
+
+    /// Whether a query with `word_count` distinctive words may retry with
+    /// the loose fallback lookup.
+    pub fn allows_loose_fallback(&self, word_count: usize) -> bool {
+        self.loose_fallback && word_count > self.short_query_words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_queries_are_tightened() {
+        let guard = QueryGuard::default();
+        assert_eq!(guard.limits(2, 0.95, 50), Some((0.97, 10)));
+        assert_eq!(guard.limits(4, 0.95, 50), Some((0.95, 50)));
+        assert_eq!(guard.limits(0, 0.95, 50), None);
+
+        let lenient = QueryGuard {
+            short_query_threshold: 0.9,
+            ..QueryGuard::default()
+        };
+        assert_eq!(lenient.limits(2, 0.85, 50), Some((0.9, 10)));
+    }
+
+    #[test]
+    fn test_disabled_guard_never_tightens() {
+        let guard = QueryGuard::disabled();
+        assert_eq!(guard.limits(1, 0.9, 50), Some((0.9, 50)));
+        assert_eq!(guard.limits(0, 0.9, 50), None);
+        assert!(guard.allows_loose_fallback(1));
+    }
+
+    #[test]
+    fn test_short_queries_skip_loose_fallback() {
+        let guard = QueryGuard {
+            loose_fallback: true,
+            ..QueryGuard::default()
+        };
+        assert!(!guard.allows_loose_fallback(2));
+        assert!(guard.allows_loose_fallback(3));
+
+        assert!(!QueryGuard::default().allows_loose_fallback(5));
+    }
+}
@@ -0,0 +1,329 @@
+//! Shared title normalization and scoring for the offline database crates.
+//!
+//! `hallucinator-dblp`, `hallucinator-acl` and `hallucinator-openalex` cannot
+//! depend on `hallucinator-core` (core depends on them), so the normalization
+//! applied to both query and candidate titles before rapidfuzz scoring, and
+//! the [`TitleScorer`] choice itself, live here. Keeping them in one place
+//! guarantees all three backends score a given title pair identically.
+//!
+//! The FTS5 candidate lookup of the SQLite backends ([`fts`]), and the
+//! [`user_agent`], retry handling ([`download`]) and database file
+//! replacement ([`sqlite`]) of their database builders, live here for the
+//! same reason.
+
+use std::collections::BTreeSet;
+
+mod agent;
+pub mod download;
+pub mod fts;
+mod guard;
+pub mod sqlite;
+
+pub use agent::{PROJECT_URL, user_agent};
+pub use guard::QueryGuard;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Fold diacritics to their base letters, preserving case and punctuation.
+///
+/// Applies NFKD and drops combining marks (`"Rényi"` → `"Renyi"`), then maps
+/// the handful of Latin letters NFKD leaves intact (`ß`, `æ`, `ø`, `ł`, ...)
+/// to ASCII spellings. Characters without a decomposition (CJK, Greek, ...)
+/// pass through unchanged.
+pub fn fold_diacritics(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.nfkd() {
+        if is_combining_mark(c) {
+            continue;
+        }
+        match c {
+            'ß' => out.push_str("ss"),
+            'æ' => out.push_str("ae"),
+            'Æ' => out.push_str("AE"),
+            'œ' => out.push_str("oe"),
+            'Œ' => out.push_str("OE"),
+            'ø' => out.push('o'),
+            'Ø' => out.push('O'),
+            'ł' => out.push('l'),
+            'Ł' => out.push('L'),
+            'đ' | 'ð' => out.push('d'),
+            'Đ' | 'Ð' => out.push('D'),
+            'þ' => out.push_str("th"),
+            'Þ' => out.push_str("TH"),
+            'ı' => out.push('i'),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Normalize a title for fuzzy scoring.
+///
+/// Folds diacritics, lowercases, and strips everything that is not a letter
+/// or digit. Whitespace and punctuation are removed entirely rather than
+/// collapsed, matching the "lowercase alphanumeric only" form the offline
+/// thresholds were calibrated against. Non-Latin letters are kept so titles
+/// in other scripts still produce a comparable key.
+pub fn normalize_title(title: &str) -> String {
+    fold_diacritics(title)
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Split a title into words for the token-based scorers: diacritics
+/// folded, lowercased, split at anything that is not a letter or digit.
+pub fn title_tokens(title: &str) -> Vec<String> {
+    fold_diacritics(title)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// How a cited title is scored against a candidate title, from 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleScorer {
+    /// rapidfuzz `ratio` of the [`normalize_title`] forms. Strict and word
+    /// order sensitive; the databases' default thresholds were calibrated
+    /// against it.
+    #[default]
+    Ratio,
+    /// `ratio` of the titles' [words](title_tokens) in sorted order, so
+    /// reordered words cost nothing.
+    TokenSort,
+    /// Compares the words the titles share with each title's leftover words,
+    /// as rapidfuzz's `token_set_ratio` does. A title whose words all appear
+    /// in the other (e.g. a citation with venue text appended) scores 1.0,
+    /// which makes this the most lenient scorer.
+    TokenSet,
+}
+
+impl TitleScorer {
+    /// Score `query` against `candidate`.
+    pub fn score(self, query: &str, candidate: &str) -> f64 {
+        self.prepare(query).score(candidate)
+    }
+
+    /// Normalize `query` once for scoring against many candidates.
+    pub fn prepare(self, query: &str) -> PreparedTitle {
+        PreparedTitle(match self {
+            Self::Ratio => Prepared::Ratio(normalize_title(query)),
+            Self::TokenSort => Prepared::TokenSort(sorted_words(query)),
+            Self::TokenSet => Prepared::TokenSet(word_set(query)),
+        })
+    }
+
+    /// The scorer's name as used in configuration: `ratio`, `token-sort` or
+    /// `token-set`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ratio => "ratio",
+            Self::TokenSort => "token-sort",
+            Self::TokenSet => "token-set",
+        }
+    }
+}
+
+impl std::fmt::Display for TitleScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TitleScorer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "ratio" => Ok(Self::Ratio),
+            "token-sort" => Ok(Self::TokenSort),
+            "token-set" => Ok(Self::TokenSet),
+            _ => Err(format!(
+                "unknown title scorer '{s}' (expected ratio, token-sort or token-set)"
+            )),
+        }
+    }
+}
+
+/// A query title in the form its [`TitleScorer`] compares, from
+/// [`TitleScorer::prepare`].
+#[derive(Debug, Clone)]
+pub struct PreparedTitle(Prepared);
+
+#[derive(Debug, Clone)]
+enum Prepared {
+    Ratio(String),
+    TokenSort(String),
+    TokenSet(BTreeSet<String>),
+}
+
+impl PreparedTitle {
+    /// Score the prepared query against `candidate`.
+    pub fn score(&self, candidate: &str) -> f64 {
+        match &self.0 {
+            Prepared::Ratio(query) => ratio(query, &normalize_title(candidate)),
+            Prepared::TokenSort(query) => ratio(query, &sorted_words(candidate)),
+            Prepared::TokenSet(query) => token_set_ratio(query, &word_set(candidate)),
+        }
+    }
+}
+
+fn sorted_words(title: &str) -> String {
+    let mut words = title_tokens(title);
+    words.sort_unstable();
+    words.join(" ")
+}
+
+fn word_set(title: &str) -> BTreeSet<String> {
+    title_tokens(title).into_iter().collect()
+}
+
+fn ratio(a: &str, b: &str) -> f64 {
+    rapidfuzz::fuzz::ratio(a.chars(), b.chars())
+}
+
+fn token_set_ratio(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    let join = |words: Vec<&String>| {
+        words
+            .into_iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let shared = join(a.intersection(b).collect());
+    let only_a = join(a.difference(b).collect());
+    let only_b = join(b.difference(a).collect());
+    if !shared.is_empty() && (only_a.is_empty() || only_b.is_empty()) {
+        return 1.0;
+    }
+
+    let with_shared = |rest: &str| {
+        if shared.is_empty() {
+            rest.to_string()
+        } else {
+            format!("{shared} {rest}")
+        }
+    };
+    let (all_a, all_b) = (with_shared(&only_a), with_shared(&only_b));
+    let mut best = ratio(&all_a, &all_b);
+    if !shared.is_empty() {
+        best = best.max(ratio(&shared, &all_a)).max(ratio(&shared, &all_b));
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_title_ascii() {
+        assert_eq!(normalize_title("Hello, World! 123"), "helloworld123");
+        assert_eq!(normalize_title("  A--B  "), "ab");
+    }
+
+    #[test]
+    fn test_normalize_title_folds_diacritics() {
+        assert_eq!(
+            normalize_title("Über die Lösung: Rényi-Entropie"),
+            normalize_title("Uber die Losung: Renyi-Entropie"),
+        );
+        assert_eq!(normalize_title("Straße"), "strasse");
+        assert_eq!(normalize_title("Łukasz Øre"), "lukaszore");
+    }
+
+    #[test]
+    fn test_normalize_title_whitespace_and_case() {
+        assert_eq!(
+            normalize_title("Deep\tLearning\n  for   EVERYONE"),
+            "deeplearningforeveryone"
+        );
+    }
+
+    #[test]
+    fn test_normalize_title_keeps_non_latin_letters() {
+        assert_eq!(normalize_title("深度学习 2024"), "深度学习2024");
+    }
+
+    #[test]
+    fn test_title_tokens() {
+        assert_eq!(
+            title_tokens("Self-Attention: Über Alles"),
+            ["self", "attention", "uber", "alles"]
+        );
+    }
+
+    #[test]
+    fn test_token_scorers_forgive_reordering() {
+        let cited = "Image Recognition with Deep Residual Learning";
+        let indexed = "Deep Residual Learning for Image Recognition";
+        assert!(TitleScorer::Ratio.score(cited, indexed) < 0.9);
+        assert!(TitleScorer::TokenSort.score(cited, indexed) > 0.85);
+        assert!(TitleScorer::TokenSet.score(cited, indexed) >= 0.9);
+    }
+
+    #[test]
+    fn test_token_set_ignores_appended_words() {
+        let cited = "Attention Is All You Need. NeurIPS 2017";
+        let indexed = "Attention is All you Need";
+        assert_eq!(TitleScorer::TokenSet.score(cited, indexed), 1.0);
+        assert!(TitleScorer::Ratio.score(cited, indexed) < 0.9);
+        assert!(TitleScorer::TokenSet.score("Deep Learning", "Graph Theory") < 0.5);
+    }
+
+    #[test]
+    fn test_scorers_agree_on_identical_titles() {
+        for scorer in [
+            TitleScorer::Ratio,
+            TitleScorer::TokenSort,
+            TitleScorer::TokenSet,
+        ] {
+            assert_eq!(scorer.score("Hello, World", "hello world"), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_prepared_title_scores_like_the_scorer() {
+        let cited = "Image Recognition with Deep Residual Learning";
+        let candidates = [
+            "Deep Residual Learning for Image Recognition",
+            "Residual Networks",
+            "",
+        ];
+        for scorer in [
+            TitleScorer::Ratio,
+            TitleScorer::TokenSort,
+            TitleScorer::TokenSet,
+        ] {
+            let prepared = scorer.prepare(cited);
+            for candidate in candidates {
+                assert_eq!(
+                    prepared.score(candidate),
+                    scorer.score(cited, candidate),
+                    "{scorer} {candidate:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_title_scorer_from_str() {
+        for scorer in [
+            TitleScorer::Ratio,
+            TitleScorer::TokenSort,
+            TitleScorer::TokenSet,
+        ] {
+            assert_eq!(scorer.as_str().parse::<TitleScorer>(), Ok(scorer));
+        }
+        assert_eq!("token_set".parse(), Ok(TitleScorer::TokenSet));
+        assert!("jaro".parse::<TitleScorer>().is_err());
+    }
+
+    #[test]
+    fn test_fold_diacritics_preserves_case_and_punctuation() {
+        assert_eq!(fold_diacritics("Érdős–Rényi"), "Erdos–Renyi");
+    }
+}
@@ -0,0 +1,134 @@
+//! Database file handling shared by the SQLite (DBLP, ACL) builders.
+//!
+//! A rebuild is written to a fresh file next to the database and only
+//! replaces it once finished, so no rows from the previous build survive it
+//! (e.g. records a new `min_year` excludes) and a failed build leaves the old
+//! database intact.
+
+use std::ffi::OsString;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rusqlite::Connection;
+
+/// Make a finished build durable before the database is handed off.
+///
+/// Restores `synchronous = NORMAL` (bulk loading may have lowered it), folds
+/// the WAL back into the main file with a truncating checkpoint, and fsyncs
+/// the database and whatever remains of its WAL. If another connection still
+/// reads an older snapshot the checkpoint stops short, and the synced WAL
+/// carries the rest.
+pub fn finalize(conn: &Connection, db_path: &Path) -> io::Result<()> {
+    conn.pragma_update(None, "synchronous", "NORMAL")
+        .and_then(|()| conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(())))
+        .map_err(io::Error::other)?;
+
+    for path in [db_path, Path::new(&with_suffix(db_path, "-wal"))] {
+        // Windows only flushes handles opened for writing.
+        match OpenOptions::new().write(true).open(path) {
+            Ok(file) => file.sync_all()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Where a rebuild of `db_path` is written before it replaces the database:
+/// `<db>.building`.
+pub fn staging_path(db_path: &Path) -> PathBuf {
+    with_suffix(db_path, ".building").into()
+}
+
+/// Delete the database at `path` with its `-wal` and `-shm` files, ignoring
+/// any that don't exist.
+pub fn remove_database(path: &Path) -> io::Result<()> {
+    remove_if_exists(path)?;
+    remove_companions(path)
+}
+
+/// Move the finished database at `staged`, whose connections are closed,
+/// over `db_path`.
+///
+/// The WAL and shared-memory files of the replaced database are deleted
+/// first: SQLite would otherwise apply the old WAL to the new file.
+pub fn replace_database(staged: &Path, db_path: &Path) -> io::Result<()> {
+    remove_companions(db_path)?;
+    remove_companions(staged)?;
+    std::fs::rename(staged, db_path)
+}
+
+fn remove_companions(path: &Path) -> io::Result<()> {
+    for suffix in ["-wal", "-shm"] {
+        remove_if_exists(Path::new(&with_suffix(path, suffix)))?;
+    }
+    Ok(())
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> OsString {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finalize_hands_off_complete_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.db");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL; PRAGMA synchronous = OFF; \
+             CREATE TABLE papers (id INTEGER PRIMARY KEY);",
+        )
+        .unwrap();
+        for id in 0..500 {
+            conn.execute("INSERT INTO papers (id) VALUES (?1)", [id])
+                .unwrap();
+        }
+        finalize(&conn, &path).unwrap();
+
+        // The builder's connection is still open, as it is when a build hands
+        // the path to a reader.
+        let wal_len = std::fs::metadata(dir.path().join("test.db-wal")).map_or(0, |m| m.len());
+        assert_eq!(wal_len, 0);
+        let reader = Connection::open(&path).unwrap();
+        let count: i64 = reader
+            .query_row("SELECT COUNT(*) FROM papers", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 500);
+        drop(conn);
+    }
+
+    #[test]
+    fn test_replace_database_drops_the_old_wal() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let db_path = dir.join("test.db");
+        let staged = staging_path(&db_path);
+        assert_eq!(staged, dir.join("test.db.building"));
+
+        std::fs::write(&db_path, "old").unwrap();
+        std::fs::write(dir.join("test.db-wal"), "old wal").unwrap();
+        std::fs::write(&staged, "new").unwrap();
+        replace_database(&staged, &db_path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&db_path).unwrap(), "new");
+        assert!(!dir.join("test.db-wal").exists());
+        assert!(!staged.exists());
+
+        remove_database(&db_path).unwrap();
+        assert!(!db_path.exists());
+    }
+}
@@ -6,6 +6,9 @@ pub enum Action {
     DrillIn,
     MoveUp,
     MoveDown,
+    /// Mouse wheel (scrolls the detail view, otherwise moves like `MoveUp`/`MoveDown`).
+    ScrollUp,
+    ScrollDown,
     PageUp,
     PageDown,
     GoTop,
@@ -18,10 +21,13 @@ pub enum Action {
     SearchInput(char),
     SearchConfirm,
     SearchCancel,
-    NextMatch,
-    PrevMatch,
+    /// Jump to the next/previous problematic reference in the paper view.
+    NextProblem,
+    PrevProblem,
     Retry,
     RetryAll,
+    /// Retry transient DB failures across every paper in the queue.
+    RetryAllPapers,
     Export,
     AddFiles,
     #[allow(dead_code)] // planned feature: remove paper from queue
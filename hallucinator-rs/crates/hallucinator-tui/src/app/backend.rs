@@ -5,7 +5,6 @@ use hallucinator_core::{DbStatus, ProgressEvent};
 use hallucinator_reporting::FpReason;
 
 use super::App;
-use crate::model::activity::ActiveQuery;
 use crate::model::paper::{RefPhase, RefState};
 use crate::model::queue::PaperPhase;
 use crate::tui_event::BackendEvent;
@@ -17,59 +16,20 @@ impl App {
             BackendEvent::ExtractionStarted { paper_index } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
                     paper.phase = PaperPhase::Extracting;
+                    paper.started = Some(Instant::now());
                 }
             }
             BackendEvent::ExtractionComplete {
                 paper_index,
                 ref_count,
-                references,
                 skip_stats: _,
+                document_title,
             } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
                     paper.total_refs = ref_count;
-                    let skipped = references
-                        .iter()
-                        .filter(|r| r.skip_reason.is_some())
-                        .count();
-                    paper.stats.total = references.len();
-                    paper.stats.skipped = skipped;
-                    // Allocate result slots for ALL refs (including skipped) so
-                    // that remapped indices from the backend fit.
-                    paper.init_results(references.len());
+                    paper.title = document_title;
                     paper.phase = PaperPhase::Checking;
                 }
-                if paper_index < self.ref_states.len() {
-                    self.ref_states[paper_index] = references
-                        .into_iter()
-                        .map(|r| {
-                            let phase = if let Some(reason) = &r.skip_reason {
-                                RefPhase::Skipped(reason.clone())
-                            } else {
-                                RefPhase::Pending
-                            };
-                            RefState {
-                                index: r.original_number.saturating_sub(1),
-                                title: r.title.clone().unwrap_or_default(),
-                                phase,
-                                result: None,
-                                fp_reason: None,
-                                raw_citation: r.raw_citation,
-                                authors: r.authors,
-                                doi: r.doi,
-                                arxiv_id: r.arxiv_id,
-                            }
-                        })
-                        .collect();
-
-                    // Restore persisted FP overrides from cache
-                    if let Some(cache) = &self.current_query_cache {
-                        for rs in &mut self.ref_states[paper_index] {
-                            if let Some(reason_str) = cache.get_fp_override(&rs.title) {
-                                rs.fp_reason = reason_str.parse::<FpReason>().ok();
-                            }
-                        }
-                    }
-                }
             }
             BackendEvent::ExtractionFailed { paper_index, error } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
@@ -85,6 +45,7 @@ impl App {
                     && paper.phase != PaperPhase::ExtractionFailed
                 {
                     paper.phase = PaperPhase::Complete;
+                    self.append_metrics(paper_index);
                 }
             }
             BackendEvent::BatchComplete => {
@@ -107,6 +68,9 @@ impl App {
                 {
                     self.config_state.dblp_parse_started = Some(Instant::now());
                 }
+                if let hallucinator_dblp::BuildProgress::Warning { message } = &event {
+                    self.activity.log_warn(format!("DBLP build: {}", message));
+                }
                 self.config_state.dblp_build_status = Some(super::format_dblp_progress(
                     &event,
                     self.config_state.dblp_build_started,
@@ -144,6 +108,9 @@ impl App {
                 {
                     self.config_state.acl_parse_started = Some(Instant::now());
                 }
+                if let hallucinator_acl::BuildProgress::Warning { message } = &event {
+                    self.activity.log_warn(format!("ACL build: {}", message));
+                }
                 self.config_state.acl_build_status = Some(super::format_acl_progress(
                     &event,
                     self.config_state.acl_build_started,
@@ -217,11 +184,8 @@ impl App {
                     rs.phase = RefPhase::Checking;
                 }
                 // Track active query
-                self.activity.active_queries.push(ActiveQuery {
-                    db_name: format!("ref #{}", index + 1),
-                    ref_title: title,
-                    is_retry: false,
-                });
+                self.activity
+                    .start_query(paper_index, index, title, format!("ref #{}", index + 1));
                 // Increment in-flight for all enabled DBs
                 let enabled: Vec<String> = self
                     .config_state
@@ -249,13 +213,9 @@ impl App {
                     && let Some(rs) = refs.get_mut(index)
                 {
                     rs.phase = RefPhase::Done;
-                    // Remove matching active query
-                    let title = rs.title.clone();
-                    self.activity
-                        .active_queries
-                        .retain(|q| q.ref_title != title);
                     rs.result = Some(result);
                 }
+                self.activity.finish_query(paper_index, index);
                 self.activity.total_completed += 1;
                 self.throughput_since_last += 1;
             }
@@ -264,18 +224,18 @@ impl App {
                 // Aggregate DB-level warnings are emitted below in DatabaseQueryComplete.
             }
             ProgressEvent::Retrying {
-                title, failed_dbs, ..
+                index,
+                title,
+                failed_dbs,
+                ..
             } => {
-                // Mark the existing active query as a retry
-                if let Some(q) = self
-                    .activity
-                    .active_queries
-                    .iter_mut()
-                    .find(|q| q.ref_title == title)
-                {
-                    q.is_retry = true;
-                    q.db_name = format!("retry ({})", failed_dbs.len());
-                }
+                // The first attempt's result already ended its entry
+                self.activity.start_query(
+                    paper_index,
+                    index,
+                    title,
+                    format!("retry ({})", failed_dbs.len()),
+                );
             }
             ProgressEvent::RetryPass { count, .. } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
@@ -284,13 +244,22 @@ impl App {
                     paper.retry_done = 0;
                 }
             }
+            ProgressEvent::DatabaseQueryStarted {
+                ref_index, db_name, ..
+            } => {
+                // Show which DBs the ref is waiting on in the active-query list
+                self.activity
+                    .update_query_db(paper_index, ref_index, &db_name, true);
+            }
             ProgressEvent::DatabaseQueryComplete {
-                ref_index: _,
+                ref_index,
                 db_name,
                 status,
                 elapsed,
                 ..
             } => {
+                self.activity
+                    .update_query_db(paper_index, ref_index, &db_name, false);
                 if status == DbStatus::Skipped {
                     // Early-exit artifact — just decrement in-flight
                     self.activity.decrement_in_flight(&db_name);
@@ -326,10 +295,93 @@ impl App {
                     }
                 }
             }
-            ProgressEvent::RateLimitWait { .. } | ProgressEvent::RateLimitRetry { .. } => {
-                // Rate limit events are handled internally by the pool;
-                // no TUI action needed (activity panel could log these in the future).
+            ProgressEvent::RateLimitWait {
+                db_name,
+                wait_duration,
+            } => {
+                self.activity
+                    .record_rate_limit_wait(&db_name, wait_duration, Instant::now());
+            }
+            ProgressEvent::RateLimitRetry { .. } => {
+                // Retries are handled internally by the pool; no TUI action needed.
             }
+            ProgressEvent::Extracted { references } => {
+                if let Some(paper) = self.papers.get_mut(paper_index) {
+                    let skipped = references
+                        .iter()
+                        .filter(|r| r.skip_reason.is_some())
+                        .count();
+                    paper.stats.total = references.len();
+                    paper.stats.skipped = skipped;
+                    // Allocate result slots for ALL refs (including skipped) so
+                    // that remapped indices from the backend fit.
+                    paper.init_results(references.len());
+                }
+                if paper_index < self.ref_states.len() {
+                    self.ref_states[paper_index] = references
+                        .into_iter()
+                        .map(|r| {
+                            let phase = if let Some(reason) = &r.skip_reason {
+                                RefPhase::Skipped(reason.clone())
+                            } else {
+                                RefPhase::Pending
+                            };
+                            RefState {
+                                index: r.original_number.saturating_sub(1),
+                                title: r.title.clone().unwrap_or_default(),
+                                phase,
+                                result: None,
+                                fp_reason: None,
+                                raw_citation: r.raw_citation,
+                                authors: r.authors,
+                                doi: r.doi,
+                                arxiv_id: r.arxiv_id,
+                                cite_key: r.cite_key,
+                            }
+                        })
+                        .collect();
+
+                    // Restore persisted FP overrides from cache
+                    if let Some(cache) = &self.current_query_cache {
+                        for rs in &mut self.ref_states[paper_index] {
+                            if let Some(reason_str) = cache.get_fp_override(&rs.title) {
+                                rs.fp_reason = reason_str.parse::<FpReason>().ok();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Refresh [`rate_limit_pause`](App::rate_limit_pause) from the rate
+    /// limiters' backoff state.
+    pub(super) fn update_rate_limit_pause(&mut self, now: Instant) {
+        if !self.processing_started || self.batch_complete {
+            self.rate_limit_pause = None;
+            return;
+        }
+        if let Some(limiters) = &self.current_rate_limiters {
+            self.activity.sync_backoffs(limiters, now);
+        }
+        self.rate_limit_pause = self.activity.rate_limit_pause(now);
+    }
+
+    /// Append a `--metrics-file` record for a paper that finished checking,
+    /// once per paper.
+    fn append_metrics(&mut self, paper_index: usize) {
+        let (Some(path), Some(paper)) = (&self.metrics_file, self.papers.get_mut(paper_index))
+        else {
+            return;
+        };
+        if std::mem::replace(&mut paper.metrics_written, true) {
+            return;
+        }
+        let elapsed = paper.started.map(|s| s.elapsed()).unwrap_or_default();
+        if let Err(e) =
+            hallucinator_reporting::append_metrics(path, &paper.filename, &paper.stats, elapsed)
+        {
+            self.activity.log_warn(format!("{}: {}", path.display(), e));
         }
     }
 }
@@ -233,6 +233,9 @@ pub struct App {
     pub batch_complete: bool,
     pub show_help: bool,
     pub detail_scroll: u16,
+    /// Largest useful `detail_scroll` for the last rendered detail view
+    /// (content rows minus visible rows). `u16::MAX` until first render.
+    pub detail_max_scroll: u16,
     /// Height of the visible table area (set on resize, used for page up/down).
     pub visible_rows: usize,
 
@@ -299,6 +302,11 @@ pub struct App {
     pub(super) inflight_batches: usize,
     /// Rate limiters for the current run (shared with backend for backoff state).
     pub current_rate_limiters: Option<std::sync::Arc<hallucinator_core::RateLimiters>>,
+    /// `--metrics-file`: where a summary line is appended per completed paper.
+    pub metrics_file: Option<PathBuf>,
+    /// Set while every backend with pending queries is in 429 backoff: time
+    /// until the soonest retry. Refreshed each tick.
+    pub rate_limit_pause: Option<std::time::Duration>,
     /// Query cache for the current run (shared with backend for cache stats).
     pub current_query_cache: Option<std::sync::Arc<hallucinator_core::QueryCache>>,
     /// Cache path corresponding to the current_query_cache (for change detection).
@@ -336,6 +344,7 @@ impl App {
             batch_complete: false,
             show_help: false,
             detail_scroll: 0,
+            detail_max_scroll: u16::MAX,
             visible_rows: 20,
             input_mode: InputMode::Normal,
             search_query: String::new(),
@@ -370,6 +379,8 @@ impl App {
             extracted_count: 0,
             inflight_batches: 0,
             current_rate_limiters: None,
+            metrics_file: None,
+            rate_limit_pause: None,
             current_query_cache: None,
             current_query_cache_path: None,
             frame_count: 0,
@@ -624,12 +635,22 @@ impl App {
         self.config_state.dirty = true;
     }
 
+    /// Skip the startup banner when `--no-banner` / `[display] no_banner` is set.
+    pub fn skip_banner_if_configured(&mut self) {
+        if self.config_state.no_banner && self.screen == Screen::Banner {
+            self.dismiss_banner();
+        }
+    }
+
     /// Dismiss the banner and navigate to the appropriate first screen.
     fn dismiss_banner(&mut self) {
         self.banner_start = None;
         if self.single_paper_mode {
             self.screen = Screen::Paper(0);
-        } else if self.papers.is_empty() {
+        } else if self.papers.is_empty()
+            && self.extracting_archive.is_none()
+            && self.pending_archive_extractions.is_empty()
+        {
             self.screen = Screen::FilePicker;
         } else {
             self.screen = Screen::Queue;
@@ -722,7 +743,15 @@ impl App {
                 crate::view::paper::render_in(f, self, idx, main_area, footer_area)
             }
             Screen::RefDetail(paper_idx, ref_idx) => {
-                crate::view::detail::render_in(f, self, paper_idx, ref_idx, main_area, footer_area)
+                self.detail_max_scroll = crate::view::detail::render_in(
+                    f,
+                    self,
+                    paper_idx,
+                    ref_idx,
+                    main_area,
+                    footer_area,
+                );
+                self.detail_scroll = self.detail_scroll.min(self.detail_max_scroll);
             }
             Screen::Config => crate::view::config::render_in(f, self, main_area, footer_area),
             Screen::Banner | Screen::FilePicker => unreachable!(),
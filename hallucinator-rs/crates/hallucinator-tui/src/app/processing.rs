@@ -94,6 +94,7 @@ impl App {
             current_path.as_deref(),
             hallucinator_core::DEFAULT_POSITIVE_TTL.as_secs(),
             hallucinator_core::DEFAULT_NEGATIVE_TTL.as_secs(),
+            self.config_state.cache_max_entries,
         );
 
         // Log cache info
@@ -166,6 +167,8 @@ impl App {
             openalex_offline_db: None, // Populated from main.rs
             num_workers: self.config_state.num_workers,
             max_rate_limit_retries: self.config_state.max_rate_limit_retries,
+            retry_passes: self.config_state.retry_passes,
+            min_databases_queried: self.config_state.min_databases_queried,
             rate_limiters: std::sync::Arc::new(hallucinator_core::RateLimiters::new(
                 !self.config_state.crossref_mailto.is_empty(),
                 !self.config_state.s2_api_key.is_empty(),
@@ -188,6 +191,30 @@ impl App {
             cache_positive_ttl_secs: hallucinator_core::DEFAULT_POSITIVE_TTL.as_secs(),
             cache_negative_ttl_secs: hallucinator_core::DEFAULT_NEGATIVE_TTL.as_secs(),
             query_cache: Some(self.get_or_build_query_cache()),
+            near_miss_margin: hallucinator_core::DEFAULT_NEAR_MISS_MARGIN,
+            match_threshold: self.config_state.match_threshold,
+            thresholds: self.config_state.thresholds.clone(),
+            title_scorer: hallucinator_core::TitleScorer::default(),
+            query_guard: hallucinator_core::QueryGuard::default(),
+            http_client: None,
+            http_proxy: self.config_state.http_proxy.clone(),
+            extra_ca_cert: self.config_state.extra_ca_cert.as_ref().map(PathBuf::from),
+            max_references: Some(hallucinator_core::DEFAULT_MAX_REFERENCES),
+            max_references_action: hallucinator_core::ReferenceLimitAction::Truncate,
+            extraction_timeout: Some(hallucinator_core::DEFAULT_EXTRACTION_TIMEOUT),
+            check_in_text_citations: self.config_state.check_citations,
+            pdf_column_detection: false,
+            overrides_path: None,
+            overrides: None,
+            trust_resolved_doi: false,
+            cross_validate_doi: false,
+            accept_preprint_versions: false,
+            trusted_verifiers: vec![],
+            mode: if self.config_state.existence_only {
+                hallucinator_core::VerificationMode::ExistenceOnly
+            } else {
+                hallucinator_core::VerificationMode::Full
+            },
         }
     }
 
@@ -480,6 +507,60 @@ impl App {
         }
     }
 
+    /// Handle F in the queue: retry transient DB failures across all papers,
+    /// one `RetryReferences` command per affected paper.
+    pub(super) fn handle_retry_all_papers(&mut self) {
+        if self.processing_started && !self.batch_complete {
+            self.activity
+                .log("Batch still running \u{2014} retry after it completes".to_string());
+            return;
+        }
+        let in_flight = self
+            .ref_states
+            .iter()
+            .flatten()
+            .any(|rs| rs.phase == RefPhase::Retrying);
+        if in_flight {
+            self.activity.log("Retry already in progress".to_string());
+            return;
+        }
+
+        let batches = crate::model::paper::collect_transient_retries(&self.ref_states);
+        if batches.is_empty() {
+            self.activity
+                .log("No references with database failures to retry".to_string());
+            return;
+        }
+
+        let ref_count: usize = batches.iter().map(|(_, batch)| batch.len()).sum();
+        let paper_count = batches.len();
+        let config = self.build_config();
+        for (paper_idx, to_retry) in batches {
+            if let Some(refs) = self.ref_states.get_mut(paper_idx) {
+                for &(ref_idx, _, _) in &to_retry {
+                    if let Some(rs) = refs.get_mut(ref_idx) {
+                        rs.phase = RefPhase::Retrying;
+                    }
+                }
+            }
+            if let Some(tx) = &self.backend_cmd_tx {
+                let _ = tx.send(BackendCommand::RetryReferences {
+                    paper_index: paper_idx,
+                    refs_to_retry: to_retry,
+                    config: Box::new(config.clone()),
+                });
+            }
+        }
+
+        self.activity.log(format!(
+            "Requeued {} reference{} across {} paper{} for retry",
+            ref_count,
+            if ref_count == 1 { "" } else { "s" },
+            paper_count,
+            if paper_count == 1 { "" } else { "s" },
+        ));
+    }
+
     /// Handle R: retry all failed/not-found references for the current paper.
     pub(super) fn handle_retry_all(&mut self) {
         let paper_idx = match &self.screen {
@@ -1,6 +1,7 @@
 use super::*;
 use crate::action::Action;
 use crate::model::config::ConfigSection;
+use crate::model::paper::{FpReason, RefPhase};
 
 /// Create a minimal App for testing (no backend, no files).
 fn test_app() -> App {
@@ -12,6 +13,85 @@ fn dismiss_banner(app: &mut App) {
     app.screen = Screen::Queue;
 }
 
+// ── Fast start (--no-banner) ────────────────────────────────────
+
+#[test]
+fn no_banner_skips_banner_to_queue() {
+    let mut app = App::new(
+        vec!["a.pdf".to_string(), "b.pdf".to_string()],
+        Theme::hacker(),
+    );
+    app.config_state.no_banner = true;
+    app.banner_start = Some(Instant::now());
+    app.skip_banner_if_configured();
+    assert_ne!(app.screen, Screen::Banner);
+    assert_eq!(app.screen, Screen::Queue);
+    assert!(app.banner_start.is_none());
+}
+
+#[test]
+fn no_banner_goes_to_file_picker_or_single_paper() {
+    let mut app = test_app();
+    app.config_state.no_banner = true;
+    app.skip_banner_if_configured();
+    assert_eq!(app.screen, Screen::FilePicker);
+
+    let mut app = App::new(vec!["a.pdf".to_string()], Theme::hacker());
+    app.single_paper_mode = true;
+    app.config_state.no_banner = true;
+    app.skip_banner_if_configured();
+    assert_eq!(app.screen, Screen::Paper(0));
+}
+
+#[test]
+fn banner_shown_by_default() {
+    let mut app = test_app();
+    app.skip_banner_if_configured();
+    assert_eq!(app.screen, Screen::Banner);
+}
+
+#[test]
+fn display_config_toggles_no_banner() {
+    let mut app = test_app();
+    dismiss_banner(&mut app);
+    app.screen = Screen::Config;
+    app.config_state.section = ConfigSection::Display;
+    app.config_state.item_cursor = 2;
+    app.handle_config_enter();
+    assert!(app.config_state.no_banner);
+    assert!(app.config_state.dirty);
+    app.handle_config_enter();
+    assert!(!app.config_state.no_banner);
+}
+
+#[test]
+fn display_config_toggles_check_citations() {
+    let mut app = test_app();
+    dismiss_banner(&mut app);
+    app.screen = Screen::Config;
+    app.config_state.section = ConfigSection::Display;
+    app.config_state.item_cursor = 3;
+    app.handle_config_space();
+    assert!(app.config_state.check_citations);
+    assert!(app.config_state.dirty);
+    let file = crate::config_file::from_config_state(&app.config_state);
+    assert_eq!(file.databases.unwrap().check_citations, Some(true));
+}
+
+#[test]
+fn display_config_toggles_existence_only() {
+    let mut app = test_app();
+    dismiss_banner(&mut app);
+    app.screen = Screen::Config;
+    app.config_state.section = ConfigSection::Display;
+    app.config_state.item_cursor = 4;
+    app.handle_config_space();
+    assert!(app.config_state.existence_only);
+    assert!(app.config_state.dirty);
+    let file = crate::config_file::from_config_state(&app.config_state);
+    assert_eq!(file.databases.unwrap().existence_only, Some(true));
+}
+
 // ── FilePickerContext defaults ──────────────────────────────────
 
 #[test]
@@ -292,6 +372,90 @@ fn enter_on_dir_in_db_picker_navigates() {
     assert!(app.file_picker_context == FilePickerContext::SelectDatabase { config_item: 0 });
 }
 
+// ── AddFiles from Config > Databases item 2 opens OpenAlex picker ──
+
+#[test]
+fn add_files_from_config_databases_item2_opens_db_picker() {
+    let mut app = test_app();
+    app.screen = Screen::Config;
+    app.config_state.section = ConfigSection::Databases;
+    app.config_state.item_cursor = 2;
+
+    app.update(Action::AddFiles);
+
+    assert_eq!(app.screen, Screen::FilePicker);
+    assert_eq!(
+        app.file_picker_context,
+        FilePickerContext::SelectDatabase { config_item: 2 }
+    );
+}
+
+// ── Enter on a Tantivy index dir in OpenAlex picker selects it ──
+
+#[test]
+fn enter_on_index_dir_in_openalex_picker_confirms() {
+    let mut app = test_app();
+    app.screen = Screen::FilePicker;
+    app.file_picker_context = FilePickerContext::SelectDatabase { config_item: 2 };
+
+    let dir = tempfile::tempdir().unwrap();
+    let index = dir.path().join("openalex.idx");
+    std::fs::create_dir(&index).unwrap();
+    std::fs::write(index.join("meta.json"), "{}").unwrap();
+    app.file_picker.entries = vec![FileEntry {
+        name: "openalex.idx".to_string(),
+        path: index,
+        is_dir: true,
+        is_pdf: false,
+        is_bbl: false,
+        is_bib: false,
+        is_archive: false,
+        is_json: false,
+        is_db: false,
+    }];
+    app.file_picker.cursor = 0;
+
+    app.update(Action::DrillIn);
+
+    assert_eq!(app.screen, Screen::Config);
+    assert_eq!(app.file_picker_context, FilePickerContext::AddFiles);
+    assert!(
+        app.config_state
+            .openalex_offline_path
+            .ends_with("openalex.idx")
+    );
+    assert!(app.config_state.dblp_offline_path.is_empty());
+    assert!(app.config_state.dirty);
+}
+
+// ── Enter on a plain dir in OpenAlex picker navigates into it ───
+
+#[test]
+fn enter_on_plain_dir_in_openalex_picker_navigates() {
+    let mut app = test_app();
+    app.screen = Screen::FilePicker;
+    app.file_picker_context = FilePickerContext::SelectDatabase { config_item: 2 };
+
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    app.file_picker.entries = vec![FileEntry {
+        name: "src".to_string(),
+        path: manifest.join("src"),
+        is_dir: true,
+        is_pdf: false,
+        is_bbl: false,
+        is_bib: false,
+        is_archive: false,
+        is_json: false,
+        is_db: false,
+    }];
+    app.file_picker.cursor = 0;
+
+    app.update(Action::DrillIn);
+
+    assert_eq!(app.screen, Screen::FilePicker);
+    assert!(app.config_state.openalex_offline_path.is_empty());
+}
+
 // ── Enter on non-db file in db picker is a no-op ────────────────
 
 #[test]
@@ -528,8 +692,8 @@ fn confirm_prompt_n_discards_and_exits() {
     app.config_state.dirty = true;
     app.config_state.confirm_exit = true;
 
-    // n = NextMatch in normal mode
-    app.update(Action::NextMatch);
+    // n = NextProblem in normal mode
+    app.update(Action::NextProblem);
 
     assert_eq!(app.screen, Screen::Queue);
     assert!(!app.config_state.confirm_exit);
@@ -600,7 +764,7 @@ fn discard_from_config_opened_twice_exits_to_queue() {
     assert!(app.config_state.confirm_exit);
 
     // Press 'n' to discard
-    app.update(Action::NextMatch);
+    app.update(Action::NextProblem);
     assert_eq!(app.screen, Screen::Queue);
     assert!(!app.config_state.confirm_exit);
     assert!(!app.config_state.dirty);
@@ -632,3 +796,588 @@ fn db_picker_enter_on_db_sets_dirty() {
     assert_eq!(app.screen, Screen::Config);
     assert!(app.config_state.dirty);
 }
+
+// ── OpenAlex build from Config > Databases ──────────────────────
+
+fn openalex_build_app() -> (App, mpsc::UnboundedReceiver<BackendCommand>) {
+    let mut app = test_app();
+    let (tx, rx) = mpsc::unbounded_channel();
+    app.backend_cmd_tx = Some(tx);
+    app.screen = Screen::Config;
+    app.config_state.section = ConfigSection::Databases;
+    app.config_state.item_cursor = 2;
+    app.config_state.openalex_offline_path = "/tmp/openalex.idx".to_string();
+    (app, rx)
+}
+
+#[test]
+fn build_openalex_prompts_before_starting() {
+    let (mut app, mut rx) = openalex_build_app();
+
+    app.update(Action::BuildDatabase);
+
+    assert!(app.config_state.openalex_build_prompt);
+    assert!(app.config_state.editing);
+    assert_eq!(app.input_mode, InputMode::TextInput);
+    assert!(!app.config_state.openalex_building);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn build_openalex_prompt_sends_filters() {
+    let (mut app, mut rx) = openalex_build_app();
+    app.update(Action::BuildDatabase);
+    app.config_state.edit_buffer = "2024-01-01 2020".to_string();
+
+    app.update(Action::SearchConfirm);
+
+    assert!(!app.config_state.openalex_build_prompt);
+    assert!(app.config_state.openalex_building);
+    assert!(!app.config_state.dirty);
+    match rx.try_recv() {
+        Ok(BackendCommand::BuildOpenalex {
+            db_path,
+            since,
+            min_year,
+            ..
+        }) => {
+            assert_eq!(db_path, PathBuf::from("/tmp/openalex.idx"));
+            assert_eq!(since.as_deref(), Some("2024-01-01"));
+            assert_eq!(min_year, Some(2020));
+        }
+        _ => panic!("expected BuildOpenalex command"),
+    }
+}
+
+#[test]
+fn build_openalex_prompt_rejects_bad_filter() {
+    let (mut app, mut rx) = openalex_build_app();
+    app.update(Action::BuildDatabase);
+    app.config_state.edit_buffer = "last-week".to_string();
+
+    app.update(Action::SearchConfirm);
+
+    assert!(!app.config_state.openalex_building);
+    assert!(rx.try_recv().is_err());
+    assert!(
+        app.config_state
+            .openalex_build_status
+            .as_deref()
+            .is_some_and(|s| s.starts_with("Failed"))
+    );
+}
+
+#[test]
+fn build_openalex_prompt_cancel() {
+    let (mut app, mut rx) = openalex_build_app();
+    app.update(Action::BuildDatabase);
+
+    app.update(Action::SearchCancel);
+
+    assert!(!app.config_state.openalex_build_prompt);
+    assert!(!app.config_state.editing);
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn parse_openalex_build_filter_tokens() {
+    assert_eq!(parse_openalex_build_filter("").unwrap(), (None, None));
+    assert_eq!(
+        parse_openalex_build_filter("2020").unwrap(),
+        (None, Some(2020))
+    );
+    assert_eq!(
+        parse_openalex_build_filter(" 2020  2024-06-30 ").unwrap(),
+        (Some("2024-06-30".to_string()), Some(2020))
+    );
+    assert!(parse_openalex_build_filter("2024/06/30").is_err());
+    assert!(parse_openalex_build_filter("2024-13-45").is_err());
+    assert!(parse_openalex_build_filter("2023-02-29").is_err());
+    assert!(parse_openalex_build_filter("2020 2021").is_err());
+}
+
+#[test]
+fn openalex_build_progress_updates_status() {
+    let mut app = test_app();
+    app.config_state.openalex_building = true;
+
+    app.handle_backend_event(crate::tui_event::BackendEvent::OpenAlexBuildProgress {
+        event: hallucinator_openalex::BuildProgress::ListingPartitions {
+            message: "Listing S3 partitions...".to_string(),
+        },
+    });
+    assert_eq!(
+        app.config_state.openalex_build_status.as_deref(),
+        Some("Listing S3 partitions...")
+    );
+
+    // Per-file events keep the previous status
+    app.handle_backend_event(crate::tui_event::BackendEvent::OpenAlexBuildProgress {
+        event: hallucinator_openalex::BuildProgress::FileStarted {
+            filename: "part_000.gz".to_string(),
+        },
+    });
+    assert_eq!(
+        app.config_state.openalex_build_status.as_deref(),
+        Some("Listing S3 partitions...")
+    );
+
+    app.handle_backend_event(crate::tui_event::BackendEvent::OpenAlexBuildProgress {
+        event: hallucinator_openalex::BuildProgress::Complete {
+            publications: 1234,
+            skipped: false,
+            failed_files: vec![],
+        },
+    });
+    assert_eq!(
+        app.config_state.openalex_build_status.as_deref(),
+        Some("Complete: 1,234 publications indexed")
+    );
+}
+
+// ── Mouse wheel ─────────────────────────────────────────────────
+
+#[test]
+fn mouse_scroll_in_detail_clamps_to_content() {
+    let mut app = test_app();
+    app.screen = Screen::RefDetail(0, 0);
+    app.detail_max_scroll = 5;
+
+    app.update(Action::ScrollDown);
+    assert_eq!(app.detail_scroll, 3);
+    app.update(Action::ScrollDown);
+    assert_eq!(app.detail_scroll, 5);
+
+    app.update(Action::ScrollUp);
+    assert_eq!(app.detail_scroll, 2);
+    app.update(Action::ScrollUp);
+    assert_eq!(app.detail_scroll, 0);
+}
+
+#[test]
+fn go_bottom_in_detail_stops_at_content_end() {
+    let mut app = test_app();
+    app.screen = Screen::RefDetail(0, 0);
+    app.detail_max_scroll = 12;
+
+    app.update(Action::GoBottom);
+    assert_eq!(app.detail_scroll, 12);
+    app.update(Action::MoveDown);
+    assert_eq!(app.detail_scroll, 12);
+    app.update(Action::MoveUp);
+    assert_eq!(app.detail_scroll, 11);
+}
+
+#[test]
+fn mouse_scroll_in_queue_moves_cursor() {
+    let mut app = App::new(
+        vec!["a.pdf".to_string(), "b.pdf".to_string()],
+        Theme::hacker(),
+    );
+    dismiss_banner(&mut app);
+
+    app.update(Action::ScrollDown);
+    assert_eq!(app.queue_cursor, 1);
+    app.update(Action::ScrollUp);
+    assert_eq!(app.queue_cursor, 0);
+    assert_eq!(app.detail_scroll, 0);
+}
+
+// ── Next/previous problem navigation ────────────────────────────
+
+fn ref_with_status(index: usize, status: Option<hallucinator_core::Status>) -> RefState {
+    RefState {
+        index,
+        title: format!("Reference {index}"),
+        phase: RefPhase::Done,
+        result: status.map(|status| hallucinator_core::ValidationResult {
+            title: format!("Reference {index}"),
+            raw_citation: String::new(),
+            cite_key: None,
+            ref_authors: vec![],
+            status,
+            source: None,
+            found_authors: vec![],
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            near_miss: None,
+            author_match_detail: vec![],
+            doi_conflict: None,
+            version_relation: None,
+            verified_by_count: 0,
+            insufficient_coverage: false,
+            cited_in_text: None,
+        }),
+        fp_reason: None,
+        raw_citation: String::new(),
+        authors: vec![],
+        doi: None,
+        arxiv_id: None,
+        cite_key: None,
+    }
+}
+
+#[test]
+fn next_problem_position_wraps_and_skips_safe() {
+    use hallucinator_core::Status;
+    let mut refs = vec![
+        ref_with_status(0, Some(Status::Verified)),
+        ref_with_status(1, Some(Status::NotFound)),
+        ref_with_status(2, None),
+        ref_with_status(3, Some(Status::AuthorMismatch)),
+        ref_with_status(4, Some(Status::NotFound)),
+    ];
+    refs[4].fp_reason = Some(FpReason::ExistsElsewhere);
+    let indices: Vec<usize> = (0..refs.len()).collect();
+
+    assert_eq!(next_problem_position(&refs, &indices, 0, true), Some(1));
+    assert_eq!(next_problem_position(&refs, &indices, 1, true), Some(3));
+    // Wraps past the safe-marked ref back to the start
+    assert_eq!(next_problem_position(&refs, &indices, 3, true), Some(1));
+    assert_eq!(next_problem_position(&refs, &indices, 1, false), Some(3));
+    assert_eq!(next_problem_position(&refs, &indices, 2, false), Some(1));
+
+    // Follows the sorted view order, returning view positions
+    let sorted = vec![3, 0, 1];
+    assert_eq!(next_problem_position(&refs, &sorted, 0, true), Some(2));
+    assert_eq!(next_problem_position(&refs, &sorted, 2, true), Some(0));
+
+    // A single problem is found again from itself; none → None
+    assert_eq!(next_problem_position(&refs, &[1], 0, true), Some(0));
+    assert_eq!(next_problem_position(&refs, &[0, 2], 0, true), None);
+    assert_eq!(next_problem_position(&refs, &[], 0, true), None);
+}
+
+#[test]
+fn next_problem_moves_paper_cursor() {
+    use hallucinator_core::Status;
+    let mut app = App::new(vec!["a.pdf".to_string()], Theme::hacker());
+    app.ref_states[0] = vec![
+        ref_with_status(0, Some(Status::Verified)),
+        ref_with_status(1, Some(Status::Verified)),
+        ref_with_status(2, Some(Status::NotFound)),
+    ];
+    app.paper_sort = PaperSortOrder::RefNumber;
+    app.screen = Screen::Paper(0);
+
+    app.update(Action::NextProblem);
+    assert_eq!(app.paper_cursor, 2);
+    app.update(Action::PrevProblem);
+    assert_eq!(app.paper_cursor, 2);
+}
+
+// ── Retry DB failures across all papers ─────────────────────────
+
+fn ref_with_failures(
+    index: usize,
+    status: hallucinator_core::Status,
+    failed_dbs: &[&str],
+) -> RefState {
+    let mut rs = ref_with_status(index, Some(status));
+    if let Some(r) = rs.result.as_mut() {
+        r.failed_dbs = failed_dbs.iter().map(|s| s.to_string()).collect();
+    }
+    rs
+}
+
+#[test]
+fn collect_transient_retries_across_papers() {
+    use crate::model::paper::collect_transient_retries;
+    use hallucinator_core::Status;
+
+    let mut safe = ref_with_failures(2, Status::NotFound, &["DBLP"]);
+    safe.fp_reason = Some(FpReason::KnownGood);
+    let mut retrying = ref_with_failures(3, Status::NotFound, &["arXiv"]);
+    retrying.phase = RefPhase::Retrying;
+    let ref_states = vec![
+        vec![
+            ref_with_failures(0, Status::NotFound, &["CrossRef", "OpenAlex"]),
+            ref_with_failures(1, Status::Verified, &["CrossRef"]),
+            safe,
+            retrying,
+            ref_with_failures(4, Status::NotFound, &[]),
+        ],
+        vec![ref_with_status(0, Some(Status::NotFound))],
+        vec![
+            ref_with_status(0, None),
+            ref_with_failures(1, Status::AuthorMismatch, &["Semantic Scholar"]),
+        ],
+    ];
+
+    let batches = collect_transient_retries(&ref_states);
+
+    assert_eq!(batches.len(), 2);
+    let (paper, batch) = &batches[0];
+    assert_eq!(*paper, 0);
+    assert_eq!(batch.len(), 1);
+    assert_eq!(batch[0].0, 0);
+    assert_eq!(batch[0].2, vec!["CrossRef", "OpenAlex"]);
+    let (paper, batch) = &batches[1];
+    assert_eq!(*paper, 2);
+    assert_eq!(batch.len(), 1);
+    assert_eq!(batch[0].0, 1);
+    assert_eq!(batch[0].1.title.as_deref(), Some("Reference 1"));
+}
+
+#[test]
+fn retry_all_papers_sends_one_command_per_paper_and_guards_reentry() {
+    use hallucinator_core::Status;
+
+    let mut app = App::new(
+        vec!["a.pdf".to_string(), "b.pdf".to_string()],
+        Theme::hacker(),
+    );
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    app.backend_cmd_tx = Some(tx);
+    app.screen = Screen::Queue;
+    app.ref_states[0] = vec![ref_with_failures(0, Status::NotFound, &["DBLP"])];
+    app.ref_states[1] = vec![
+        ref_with_failures(0, Status::NotFound, &["CrossRef"]),
+        ref_with_failures(1, Status::NotFound, &["arXiv"]),
+    ];
+
+    app.update(Action::RetryAllPapers);
+
+    let mut sent = Vec::new();
+    while let Ok(cmd) = rx.try_recv() {
+        match cmd {
+            BackendCommand::RetryReferences {
+                paper_index,
+                refs_to_retry,
+                ..
+            } => sent.push((paper_index, refs_to_retry.len())),
+            _ => panic!("expected RetryReferences"),
+        }
+    }
+    assert_eq!(sent, vec![(0, 1), (1, 2)]);
+    assert_eq!(app.ref_states[1][1].phase, RefPhase::Retrying);
+
+    // A second press while the retry is in flight is ignored
+    app.update(Action::RetryAllPapers);
+    assert!(rx.try_recv().is_err());
+}
+
+// ── Rate-limit pause ────────────────────────────────────────────
+
+#[test]
+fn rate_limit_pause_needs_every_busy_db_backing_off() {
+    use std::time::Duration;
+
+    let mut activity = ActivityState::default();
+    let now = Instant::now();
+    activity.increment_in_flight(&["CrossRef".into(), "arXiv".into()]);
+    // An idle backend doesn't count, backing off or not.
+    activity.record_db_complete("DOI", true, false, false, 10.0);
+    assert_eq!(activity.rate_limit_pause(now), None);
+
+    activity.record_rate_limit_wait("CrossRef", Duration::from_secs(20), now);
+    assert_eq!(activity.rate_limit_pause(now), None, "arXiv still working");
+
+    activity.record_rate_limit_wait("arXiv", Duration::from_secs(5), now);
+    assert_eq!(
+        activity.rate_limit_pause(now),
+        Some(Duration::from_secs(5)),
+        "soonest retry wins"
+    );
+    // A shorter wait reported later doesn't pull a deadline forward.
+    activity.record_rate_limit_wait("CrossRef", Duration::from_secs(1), now);
+    assert_eq!(
+        activity.rate_limit_pause(now + Duration::from_secs(2)),
+        Some(Duration::from_secs(3))
+    );
+    // Once arXiv's backoff is over it is making progress again.
+    assert_eq!(
+        activity.rate_limit_pause(now + Duration::from_secs(6)),
+        None
+    );
+
+    // A backend that has finished its queries no longer holds the pause back.
+    activity.decrement_in_flight("arXiv");
+    assert_eq!(
+        activity.rate_limit_pause(now + Duration::from_secs(6)),
+        Some(Duration::from_secs(14))
+    );
+}
+
+#[test]
+fn rate_limit_pause_shown_only_while_processing() {
+    use std::time::Duration;
+
+    let mut app = test_app();
+    let now = Instant::now();
+    app.activity.increment_in_flight(&["CrossRef".into()]);
+    app.handle_progress(
+        0,
+        hallucinator_core::ProgressEvent::RateLimitWait {
+            db_name: "CrossRef".into(),
+            wait_duration: Duration::from_secs(10),
+        },
+    );
+
+    app.update_rate_limit_pause(now);
+    assert_eq!(app.rate_limit_pause, None);
+
+    app.processing_started = true;
+    app.update_rate_limit_pause(now);
+    let wait = app.rate_limit_pause.unwrap();
+    // The wait was recorded a moment after `now`.
+    assert!(wait >= Duration::from_secs(10) && wait < Duration::from_secs(11));
+
+    app.batch_complete = true;
+    app.update_rate_limit_pause(now);
+    assert_eq!(app.rate_limit_pause, None);
+}
+
+#[test]
+fn completed_paper_appends_metrics_record() {
+    use crate::tui_event::BackendEvent;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("metrics.jsonl");
+    let mut app = App::new(vec!["a.pdf".into(), "b.pdf".into()], Theme::hacker());
+    app.metrics_file = Some(path.clone());
+
+    app.handle_backend_event(BackendEvent::ExtractionStarted { paper_index: 0 });
+    app.papers[0].stats = hallucinator_core::CheckStats {
+        total: 5,
+        verified: 3,
+        not_found: 1,
+        author_mismatch: 0,
+        retracted: 1,
+        skipped: 0,
+    };
+    app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 0 });
+    // A retry pass completes the paper again without a second record.
+    app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 0 });
+
+    // A paper whose extraction failed isn't a completed run.
+    app.handle_backend_event(BackendEvent::ExtractionFailed {
+        paper_index: 1,
+        error: "broken".into(),
+    });
+    app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 1 });
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(record["file"], "a.pdf");
+    assert_eq!(record["total"], 5);
+    assert_eq!(record["verified"], 3);
+    assert_eq!(record["not_found"], 1);
+    assert_eq!(record["retracted"], 1);
+    assert!(record["timestamp"].as_u64().unwrap() > 0);
+    assert!(record["elapsed_secs"].as_f64().unwrap() >= 0.0);
+}
+
+#[test]
+fn extracted_document_title_labels_paper() {
+    use crate::tui_event::BackendEvent;
+
+    let mut app = App::new(vec!["2403.10573v1.pdf".into()], Theme::hacker());
+    assert_eq!(app.papers[0].display_name(), "2403.10573v1.pdf");
+
+    app.handle_backend_event(BackendEvent::ExtractionComplete {
+        paper_index: 0,
+        ref_count: 0,
+        skip_stats: Default::default(),
+        document_title: Some("Deep Residual Learning".into()),
+    });
+    assert_eq!(
+        app.papers[0].display_name(),
+        "Deep Residual Learning (2403.10573v1.pdf)"
+    );
+}
+
+#[test]
+fn extracted_references_are_listed_as_pending() {
+    use hallucinator_core::{ProgressEvent, Reference};
+
+    let mut app = App::new(vec!["a.pdf".into()], Theme::hacker());
+    let checkable = Reference::new("Attention Is All You Need");
+    let mut skipped = Reference::new("https://example.com");
+    skipped.original_number = 2;
+    skipped.skip_reason = Some("url_only".into());
+    app.handle_progress(
+        0,
+        ProgressEvent::Extracted {
+            references: vec![checkable, skipped],
+        },
+    );
+
+    let refs = &app.ref_states[0];
+    assert_eq!(refs.len(), 2);
+    assert_eq!(refs[0].title, "Attention Is All You Need");
+    assert_eq!(refs[0].phase, RefPhase::Pending);
+    assert_eq!(refs[1].index, 1);
+    assert_eq!(refs[1].phase, RefPhase::Skipped("url_only".into()));
+    assert_eq!(app.papers[0].stats.total, 2);
+    assert_eq!(app.papers[0].stats.skipped, 1);
+    assert_eq!(app.papers[0].results.len(), 2);
+}
+
+#[test]
+fn active_queries_track_pending_backends_per_reference() {
+    use hallucinator_core::ProgressEvent;
+    use std::time::Duration;
+
+    let mut app = App::new(vec!["a.pdf".into(), "b.pdf".into()], Theme::hacker());
+    // The same title cited in both papers is tracked separately.
+    for paper_index in 0..2 {
+        app.handle_progress(
+            paper_index,
+            ProgressEvent::Checking {
+                index: 0,
+                total: 1,
+                title: "Shared Title".into(),
+            },
+        );
+    }
+    let started = |db: &str| ProgressEvent::DatabaseQueryStarted {
+        paper_index: 0,
+        ref_index: 0,
+        db_name: db.into(),
+    };
+    let complete = |db: &str| ProgressEvent::DatabaseQueryComplete {
+        paper_index: 0,
+        ref_index: 0,
+        db_name: db.into(),
+        status: hallucinator_core::DbStatus::NoMatch,
+        elapsed: Duration::from_millis(5),
+    };
+    app.handle_progress(0, started("CrossRef"));
+    app.handle_progress(0, started("arXiv"));
+    let statuses = |app: &App| -> Vec<String> {
+        app.activity
+            .active_queries
+            .iter()
+            .map(|q| q.status())
+            .collect()
+    };
+    assert_eq!(statuses(&app), ["CrossRef +1", "ref #1"]);
+
+    app.handle_progress(0, complete("CrossRef"));
+    assert_eq!(statuses(&app), ["arXiv", "ref #1"]);
+    app.handle_progress(0, complete("arXiv"));
+    assert_eq!(statuses(&app), ["ref #1", "ref #1"]);
+
+    app.ref_states[0] = vec![ref_with_status(0, None)];
+    app.handle_progress(
+        0,
+        ProgressEvent::Result {
+            index: 0,
+            total: 1,
+            result: Box::new(
+                ref_with_status(0, Some(hallucinator_core::Status::NotFound))
+                    .result
+                    .unwrap(),
+            ),
+        },
+    );
+    assert_eq!(app.activity.active_queries.len(), 1);
+    assert_eq!(app.activity.active_queries[0].paper_index, 1);
+}
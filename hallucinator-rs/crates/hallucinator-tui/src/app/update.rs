@@ -7,9 +7,27 @@ use crate::model::paper::{FpReason, RefPhase};
 use crate::model::queue::PaperVerdict;
 use crate::tui_event::BackendCommand;
 
+/// Lines scrolled per mouse-wheel notch in the reference detail view.
+const MOUSE_SCROLL_LINES: u16 = 3;
+
 impl App {
+    /// Scroll the reference detail view down, clamped to the rendered content.
+    fn scroll_detail_down(&mut self, lines: u16) {
+        self.detail_scroll = self
+            .detail_scroll
+            .saturating_add(lines)
+            .min(self.detail_max_scroll);
+    }
+
     /// Process a user action and update state. Returns true if the app should quit.
     pub fn update(&mut self, action: Action) -> bool {
+        // The mouse wheel scrolls the detail view by a few lines; everywhere
+        // else it moves the cursor like j/k.
+        let action = match action {
+            Action::ScrollDown if !matches!(self.screen, Screen::RefDetail(..)) => Action::MoveDown,
+            Action::ScrollUp if !matches!(self.screen, Screen::RefDetail(..)) => Action::MoveUp,
+            other => other,
+        };
         // Quit confirmation modal — q confirms, Esc cancels
         if self.confirm_quit {
             match action {
@@ -211,6 +229,7 @@ impl App {
                                     let paper = self.papers.get(i)?;
                                     Some(hallucinator_reporting::ReportPaper {
                                         filename: &paper.filename,
+                                        title: paper.title.as_deref(),
                                         stats: &paper.stats,
                                         results,
                                         verdict: paper.verdict,
@@ -308,8 +327,8 @@ impl App {
                         self.screen = Screen::Queue;
                     }
                 }
-                // n key (mapped to NextMatch in normal mode) = discard & exit
-                Action::NextMatch => {
+                // n key (mapped to NextProblem in normal mode) = discard & exit
+                Action::NextProblem => {
                     self.config_state.confirm_exit = false;
                     self.config_state.dirty = false;
                     if let Some(prev) = self.config_state.prev_screen.clone() {
@@ -419,6 +438,7 @@ impl App {
                 Screen::Config => {
                     // Clean up any in-progress editing
                     self.config_state.editing = false;
+                    self.config_state.openalex_build_prompt = false;
                     self.config_state.edit_buffer.clear();
                     self.config_state.edit_cursor = 0;
                     self.input_mode = InputMode::Normal;
@@ -473,7 +493,7 @@ impl App {
                     }
                 }
                 Screen::RefDetail(..) => {
-                    self.detail_scroll = self.detail_scroll.saturating_add(1);
+                    self.scroll_detail_down(1);
                 }
                 Screen::Config => {
                     let max = self.config_section_item_count().saturating_sub(1);
@@ -510,7 +530,7 @@ impl App {
                         self.paper_cursor = (self.paper_cursor + page).min(max);
                     }
                     Screen::RefDetail(..) => {
-                        self.detail_scroll = self.detail_scroll.saturating_add(page as u16);
+                        self.scroll_detail_down(page as u16);
                     }
                     Screen::Config | Screen::Banner | Screen::FilePicker => {}
                 }
@@ -545,7 +565,7 @@ impl App {
                     self.paper_cursor = self.paper_ref_indices(*idx).len().saturating_sub(1);
                 }
                 Screen::RefDetail(..) => {
-                    self.detail_scroll = u16::MAX;
+                    self.detail_scroll = self.detail_max_scroll;
                 }
                 Screen::Config => {
                     self.config_state.item_cursor =
@@ -710,6 +730,7 @@ impl App {
             Action::SearchCancel => {
                 if self.config_state.editing {
                     self.config_state.editing = false;
+                    self.config_state.openalex_build_prompt = false;
                     self.config_state.edit_buffer.clear();
                     self.config_state.edit_cursor = 0;
                     self.input_mode = InputMode::Normal;
@@ -721,7 +742,19 @@ impl App {
                     }
                 }
             }
-            Action::NextMatch | Action::PrevMatch => {}
+            Action::NextProblem | Action::PrevProblem => {
+                if let Screen::Paper(idx) = self.screen {
+                    let indices = self.paper_ref_indices(idx);
+                    if let Some(pos) = super::next_problem_position(
+                        &self.ref_states[idx],
+                        &indices,
+                        self.paper_cursor,
+                        action == Action::NextProblem,
+                    ) {
+                        self.paper_cursor = pos;
+                    }
+                }
+            }
             Action::ToggleActivityPanel => {
                 self.activity_panel_visible = !self.activity_panel_visible;
             }
@@ -807,6 +840,12 @@ impl App {
                     _ => {}
                 }
             }
+            Action::ScrollDown => {
+                self.scroll_detail_down(MOUSE_SCROLL_LINES);
+            }
+            Action::ScrollUp => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(MOUSE_SCROLL_LINES);
+            }
             Action::ClickAt(x, y) => {
                 self.handle_click(x, y);
             }
@@ -901,6 +940,11 @@ impl App {
             Action::RetryAll => {
                 self.handle_retry_all();
             }
+            Action::RetryAllPapers => {
+                if self.screen == Screen::Queue {
+                    self.handle_retry_all_papers();
+                }
+            }
             Action::RemovePaper => {
                 // Placeholder for future implementation
             }
@@ -927,6 +971,7 @@ impl App {
                     self.throughput_since_last = 0;
                     self.last_throughput_tick = self.tick;
                 }
+                self.update_rate_limit_pause(Instant::now());
             }
             Action::Resize(_w, h) => {
                 self.visible_rows = (h as usize).saturating_sub(11);
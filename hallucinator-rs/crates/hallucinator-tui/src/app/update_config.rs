@@ -40,7 +40,7 @@ impl App {
             ConfigSection::ApiKeys => 3,
             ConfigSection::Databases => 7 + self.config_state.disabled_dbs.len(), // DBLP + ACL + OpenAlex + cache_path + clear_cache + clear_not_found + searxng_url + toggles
             ConfigSection::Concurrency => 5,
-            ConfigSection::Display => 2, // theme + fps
+            ConfigSection::Display => 5, // theme + fps + banner + check citations + existence only
         }
     }
 
@@ -85,6 +85,18 @@ impl App {
                     self.config_state.edit_cursor = self.config_state.edit_buffer.len();
                     self.input_mode = InputMode::TextInput;
                 }
+                2 => {
+                    self.config_state.no_banner = !self.config_state.no_banner;
+                    self.config_state.dirty = true;
+                }
+                3 => {
+                    self.config_state.check_citations = !self.config_state.check_citations;
+                    self.config_state.dirty = true;
+                }
+                4 => {
+                    self.config_state.existence_only = !self.config_state.existence_only;
+                    self.config_state.dirty = true;
+                }
                 _ => {}
             },
             ConfigSection::Databases => {
@@ -146,17 +158,32 @@ impl App {
                     }
                 }
             }
-            ConfigSection::Display => {
-                if self.config_state.item_cursor == 0 {
-                    self.cycle_theme();
+            ConfigSection::Display => match self.config_state.item_cursor {
+                0 => self.cycle_theme(),
+                2 => {
+                    self.config_state.no_banner = !self.config_state.no_banner;
+                    self.config_state.dirty = true;
                 }
-            }
+                3 => {
+                    self.config_state.check_citations = !self.config_state.check_citations;
+                    self.config_state.dirty = true;
+                }
+                4 => {
+                    self.config_state.existence_only = !self.config_state.existence_only;
+                    self.config_state.dirty = true;
+                }
+                _ => {}
+            },
             _ => {}
         }
     }
 
     /// Confirm a config text edit.
     pub(super) fn confirm_config_edit(&mut self) {
+        if self.config_state.openalex_build_prompt {
+            self.confirm_openalex_build_prompt();
+            return;
+        }
         let buf = self.config_state.edit_buffer.clone();
         match self.config_state.section {
             ConfigSection::ApiKeys => match self.config_state.item_cursor {
@@ -361,6 +388,15 @@ impl App {
             if self.config_state.dblp_building {
                 return; // already building
             }
+            let client = match self.download_client() {
+                Ok(client) => client,
+                Err(e) => {
+                    self.config_state.dblp_build_status = Some(format!("Failed: {}", e));
+                    self.activity
+                        .log_warn(format!("DBLP build not started: {}", e));
+                    return;
+                }
+            };
             let db_path = if self.config_state.dblp_offline_path.is_empty() {
                 super::default_db_path("dblp.db")
             } else {
@@ -375,13 +411,22 @@ impl App {
                 db_path.display()
             ));
             if let Some(tx) = &self.backend_cmd_tx {
-                let _ = tx.send(BackendCommand::BuildDblp { db_path });
+                let _ = tx.send(BackendCommand::BuildDblp { db_path, client });
             }
         } else if item == 1 {
             // ACL
             if self.config_state.acl_building {
                 return;
             }
+            let client = match self.download_client() {
+                Ok(client) => client,
+                Err(e) => {
+                    self.config_state.acl_build_status = Some(format!("Failed: {}", e));
+                    self.activity
+                        .log_warn(format!("ACL build not started: {}", e));
+                    return;
+                }
+            };
             let db_path = if self.config_state.acl_offline_path.is_empty() {
                 super::default_db_path("acl.db")
             } else {
@@ -394,28 +439,81 @@ impl App {
             self.activity
                 .log(format!("Building ACL database at {}...", db_path.display()));
             if let Some(tx) = &self.backend_cmd_tx {
-                let _ = tx.send(BackendCommand::BuildAcl { db_path });
+                let _ = tx.send(BackendCommand::BuildAcl { db_path, client });
             }
         } else if item == 2 {
-            // OpenAlex
+            // OpenAlex: a full snapshot is tens of GB, so ask for the
+            // since/min-year filter before starting.
             if self.config_state.openalex_building {
                 return;
             }
-            let db_path = if self.config_state.openalex_offline_path.is_empty() {
-                super::default_db_path("openalex.idx")
+            self.config_state.openalex_build_prompt = true;
+            self.config_state.editing = true;
+            self.config_state.edit_buffer.clear();
+            self.config_state.edit_cursor = 0;
+            self.input_mode = InputMode::TextInput;
+        }
+    }
+
+    /// The client for offline database downloads, with the configured proxy
+    /// and CA certificate.
+    fn download_client(&self) -> Result<reqwest::Client, hallucinator_core::CoreError> {
+        hallucinator_core::http::build_client_for(&self.config_state.network_config())
+    }
+
+    /// Confirm the OpenAlex build filter prompt and start the build.
+    fn confirm_openalex_build_prompt(&mut self) {
+        let buf = std::mem::take(&mut self.config_state.edit_buffer);
+        self.config_state.openalex_build_prompt = false;
+        self.config_state.editing = false;
+        self.config_state.edit_cursor = 0;
+        self.input_mode = InputMode::Normal;
+
+        let started = super::parse_openalex_build_filter(&buf).and_then(|filter| {
+            let client = self.download_client().map_err(|e| e.to_string())?;
+            Ok((filter, client))
+        });
+        let ((since, min_year), client) = match started {
+            Ok(started) => started,
+            Err(e) => {
+                self.config_state.openalex_build_status = Some(format!("Failed: {}", e));
+                self.activity
+                    .log_warn(format!("OpenAlex build not started: {}", e));
+                return;
+            }
+        };
+
+        let db_path = if self.config_state.openalex_offline_path.is_empty() {
+            super::default_db_path("openalex.idx")
+        } else {
+            PathBuf::from(&self.config_state.openalex_offline_path)
+        };
+        self.config_state.openalex_building = true;
+        self.config_state.openalex_build_status = Some("Starting...".to_string());
+        self.config_state.openalex_build_started = Some(Instant::now());
+        let mut filters = Vec::new();
+        if let Some(ref since) = since {
+            filters.push(format!("since {}", since));
+        }
+        if let Some(min_year) = min_year {
+            filters.push(format!("from {}", min_year));
+        }
+        self.activity.log(format!(
+            "Building OpenAlex index at {}{}...",
+            db_path.display(),
+            if filters.is_empty() {
+                " (full snapshot)".to_string()
             } else {
-                PathBuf::from(&self.config_state.openalex_offline_path)
-            };
-            self.config_state.openalex_building = true;
-            self.config_state.openalex_build_status = Some("Starting...".to_string());
-            self.config_state.openalex_build_started = Some(Instant::now());
-            self.activity.log(format!(
-                "Building OpenAlex index at {}...",
-                db_path.display()
-            ));
-            if let Some(tx) = &self.backend_cmd_tx {
-                let _ = tx.send(BackendCommand::BuildOpenalex { db_path });
+                format!(" ({})", filters.join(", "))
             }
+        ));
+        if let Some(tx) = &self.backend_cmd_tx {
+            let _ = tx.send(BackendCommand::BuildOpenalex {
+                db_path,
+                since,
+                min_year,
+                client,
+            });
         }
     }
 }
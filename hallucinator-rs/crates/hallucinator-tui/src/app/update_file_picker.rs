@@ -87,16 +87,21 @@ impl App {
                     self.file_picker_context,
                     FilePickerContext::SelectDatabase { .. }
                 ) {
-                    // In db mode: Enter on .db → select & return to config
+                    // In db mode: Enter on .db (or, for OpenAlex, on a Tantivy
+                    // index directory) → select & return to config
                     if let Some(entry) = self
                         .file_picker
                         .entries
                         .get(self.file_picker.cursor)
                         .cloned()
                     {
-                        if entry.is_dir {
+                        let is_openalex_index = entry.is_dir
+                            && self.file_picker_context
+                                == FilePickerContext::SelectDatabase { config_item: 2 }
+                            && entry.path.join("meta.json").is_file();
+                        if entry.is_dir && !is_openalex_index {
                             self.file_picker.enter_directory();
-                        } else if entry.is_db {
+                        } else if entry.is_db || is_openalex_index {
                             let canonical = super::clean_canonicalize(&entry.path);
                             if let FilePickerContext::SelectDatabase { config_item } =
                                 self.file_picker_context
@@ -70,6 +70,7 @@ pub(super) fn format_dblp_progress(
             records_inserted,
             bytes_read,
             bytes_total,
+            ..
         } => {
             let pct = if *bytes_total > 0 {
                 (*bytes_read as f64 / *bytes_total as f64 * 100.0) as u32
@@ -98,6 +99,18 @@ pub(super) fn format_dblp_progress(
                 eta
             )
         }
+        hallucinator_dblp::BuildProgress::ReusingDownload { bytes } => {
+            format!("Reusing previous download ({})", format_bytes(*bytes))
+        }
+        hallucinator_dblp::BuildProgress::Retrying {
+            attempt,
+            max_retries,
+            error,
+        } => format!(
+            "Download failed, retrying ({}/{}): {}",
+            attempt, max_retries, error
+        ),
+        hallucinator_dblp::BuildProgress::Warning { message } => format!("Warning: {}", message),
         hallucinator_dblp::BuildProgress::RebuildingIndex => "Rebuilding FTS index...".to_string(),
         hallucinator_dblp::BuildProgress::Compacting => {
             "Compacting database (VACUUM)...".to_string()
@@ -105,10 +118,18 @@ pub(super) fn format_dblp_progress(
         hallucinator_dblp::BuildProgress::Complete {
             publications,
             authors,
+            issues,
             skipped,
         } => {
             if *skipped {
                 "Already up to date (304 Not Modified)".to_string()
+            } else if *issues > 0 {
+                format!(
+                    "Complete: {} publications, {} authors ({} records had issues)",
+                    format_number(*publications),
+                    format_number(*authors),
+                    format_number(*issues)
+                )
             } else {
                 format!(
                     "Complete: {} publications, {} authors",
@@ -196,14 +217,31 @@ pub(super) fn format_acl_progress(
                 eta
             )
         }
+        hallucinator_acl::BuildProgress::Retrying {
+            attempt,
+            max_retries,
+            error,
+        } => format!(
+            "Download failed, retrying ({}/{}): {}",
+            attempt, max_retries, error
+        ),
+        hallucinator_acl::BuildProgress::Warning { message } => format!("Warning: {}", message),
         hallucinator_acl::BuildProgress::RebuildingIndex => "Rebuilding FTS index...".to_string(),
         hallucinator_acl::BuildProgress::Complete {
             publications,
             authors,
+            issues,
             skipped,
         } => {
             if *skipped {
                 "Already up to date (same commit SHA)".to_string()
+            } else if *issues > 0 {
+                format!(
+                    "Complete: {} publications, {} authors ({} records had issues)",
+                    format_number(*publications),
+                    format_number(*authors),
+                    format_number(*issues)
+                )
             } else {
                 format!(
                     "Complete: {} publications, {} authors",
@@ -279,7 +317,16 @@ pub(super) fn format_openalex_progress(
                 format_number(*records_indexed)
             )
         }
-        hallucinator_openalex::BuildProgress::Merging => "Merging index segments...".to_string(),
+        hallucinator_openalex::BuildProgress::Merging { segments_before } => {
+            format!("Merging {} index segments...", segments_before)
+        }
+        hallucinator_openalex::BuildProgress::Merged {
+            segments_before,
+            segments_after,
+        } => format!(
+            "Merged index segments ({} -> {})",
+            segments_before, segments_after
+        ),
         hallucinator_openalex::BuildProgress::FileSkipped { .. } => return None,
         hallucinator_openalex::BuildProgress::Complete {
             publications,
@@ -298,6 +345,42 @@ pub(super) fn format_openalex_progress(
     })
 }
 
+/// Parse the OpenAlex build filter prompt into `(since, min_year)`.
+///
+/// Accepts whitespace-separated tokens: a `YYYY-MM-DD` date (only download
+/// partitions newer than it) and/or a four-digit year (skip older works).
+/// An empty input means a full build.
+pub(super) fn parse_openalex_build_filter(
+    input: &str,
+) -> Result<(Option<String>, Option<u32>), String> {
+    let mut since = None;
+    let mut min_year = None;
+    for token in input.split_whitespace() {
+        let is_date = token.len() == 10
+            && token.char_indices().all(|(i, c)| match i {
+                4 | 7 => c == '-',
+                _ => c.is_ascii_digit(),
+            });
+        if is_date && since.is_none() {
+            if !hallucinator_openalex::is_valid_date(token) {
+                return Err(format!("invalid date '{}'", token));
+            }
+            since = Some(token.to_string());
+        } else if token.len() == 4 && min_year.is_none() {
+            match token.parse::<u32>() {
+                Ok(year) => min_year = Some(year),
+                Err(_) => return Err(format!("invalid year '{}'", token)),
+            }
+        } else {
+            return Err(format!(
+                "unrecognized filter '{}' (expected YYYY-MM-DD and/or YYYY)",
+                token
+            ));
+        }
+    }
+    Ok((since, min_year))
+}
+
 /// Format an ETA string from progress and elapsed time.
 pub(super) fn format_eta(done: u64, total: u64, started: Option<Instant>) -> String {
     if done == 0 || total == 0 {
@@ -354,6 +437,28 @@ pub(super) fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Find the cursor position of the next (or previous) problematic reference
+/// in the paper view, wrapping around. `indices` is the sorted/filtered view
+/// order from `paper_ref_indices`; `cursor` is a position within it.
+/// Returns `None` if no other reference in the view is problematic.
+pub(super) fn next_problem_position(
+    refs: &[RefState],
+    indices: &[usize],
+    cursor: usize,
+    forward: bool,
+) -> Option<usize> {
+    let n = indices.len();
+    (1..=n)
+        .map(|step| {
+            if forward {
+                (cursor + step) % n
+            } else {
+                (cursor + n - step % n) % n
+            }
+        })
+        .find(|&pos| refs.get(indices[pos]).is_some_and(|rs| rs.is_problematic()))
+}
+
 pub(super) fn verdict_sort_key(rs: &RefState) -> u8 {
     if matches!(rs.phase, RefPhase::Skipped(_)) {
         return 5; // sort skipped refs last
@@ -12,16 +12,27 @@ use crate::tui_event::BackendEvent;
 /// A job submitted to the extraction worker pool.
 struct ExtractionJob {
     path: PathBuf,
-    result_tx: tokio::sync::oneshot::Sender<Result<ExtractionResult, String>>,
+    /// Receives the extracted references and the document's own title.
+    result_tx: tokio::sync::oneshot::Sender<Result<(ExtractionResult, Option<String>), String>>,
 }
 
 /// Worker that pulls extraction jobs from the channel and runs them on
 /// the blocking thread pool. N workers = N concurrent extractions.
-async fn extraction_worker(rx: async_channel::Receiver<ExtractionJob>) {
+/// Documents over `config.max_references` are truncated or failed here, and
+/// PDFs whose text takes longer than `config.extraction_timeout` fail.
+async fn extraction_worker(rx: async_channel::Receiver<ExtractionJob>, config: Arc<Config>) {
     while let Ok(job) = rx.recv().await {
+        let config = Arc::clone(&config);
         let result = tokio::task::spawn_blocking(move || {
-            hallucinator_ingest::extract_references(&job.path)
-                .map_err(|e| format!("Extraction failed: {}", e))
+            let (mut extraction, title) = hallucinator_ingest::extract_references_and_title(
+                &job.path,
+                &hallucinator_ingest::ExtractOptions::from(config.as_ref()),
+            )
+            .map_err(|e| format!("Extraction failed: {}", e))?;
+            config
+                .enforce_reference_limit(&mut extraction)
+                .map_err(|e| format!("Extraction failed: {}", e))?;
+            Ok((extraction, title))
         })
         .await
         .unwrap_or_else(|e| Err(format!("Task join error: {}", e)));
@@ -66,6 +77,26 @@ fn remap_progress_index(event: ProgressEvent, index_map: &[usize]) -> ProgressEv
             failed_dbs,
             message,
         },
+        ProgressEvent::Retrying {
+            index,
+            total,
+            title,
+            failed_dbs,
+        } => ProgressEvent::Retrying {
+            index: index_map.get(index).copied().unwrap_or(index),
+            total,
+            title,
+            failed_dbs,
+        },
+        ProgressEvent::DatabaseQueryStarted {
+            paper_index,
+            ref_index,
+            db_name,
+        } => ProgressEvent::DatabaseQueryStarted {
+            paper_index,
+            ref_index: index_map.get(ref_index).copied().unwrap_or(ref_index),
+            db_name,
+        },
         ProgressEvent::DatabaseQueryComplete {
             paper_index,
             ref_index,
@@ -97,10 +128,28 @@ pub async fn run_batch_with_offset(
     offset: usize,
 ) {
     let num_workers = config.num_workers.max(1);
+    // Build the client once so every paper, worker and backend shares its pool.
+    let mut config = config;
+    let client = match config.http_client() {
+        Ok(client) => client,
+        Err(e) => {
+            for i in 0..pdfs.len() {
+                let _ = tx.send(BackendEvent::ExtractionFailed {
+                    paper_index: offset + i,
+                    error: e.to_string(),
+                });
+            }
+            let _ = tx.send(BackendEvent::BatchComplete);
+            return;
+        }
+    };
+    config.http_client = Some(client);
     let config = Arc::new(config);
 
-    // Create ONE global validation pool for all papers
-    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers);
+    // Create ONE global validation pool for all papers; the client is already
+    // built, so this cannot fail
+    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers)
+        .expect("HTTP client already built");
     let pool_tx = pool.sender();
 
     // Bounded extraction: N worker tasks pull from a channel, so at most N
@@ -111,7 +160,7 @@ pub async fn run_batch_with_offset(
     let (extract_tx, extract_rx) = async_channel::unbounded::<ExtractionJob>();
     for _ in 0..max_extractors {
         let rx = extract_rx.clone();
-        tokio::spawn(extraction_worker(rx));
+        tokio::spawn(extraction_worker(rx, Arc::clone(&config)));
     }
     // Drop our clone so the channel closes once the sender side is dropped
     drop(extract_rx);
@@ -125,12 +174,22 @@ pub async fn run_batch_with_offset(
         let tx = tx.clone();
         let cancel = cancel.clone();
         let extract_tx = extract_tx.clone();
+        let config = Arc::clone(&config);
 
         handles.push(tokio::spawn(async move {
             if cancel.is_cancelled() {
                 return;
             }
-            process_single_paper(paper_index, &pdf_path, &pool_tx, &extract_tx, &tx, &cancel).await;
+            process_single_paper(
+                paper_index,
+                &pdf_path,
+                &config,
+                &pool_tx,
+                &extract_tx,
+                &tx,
+                &cancel,
+            )
+            .await;
         }));
     }
 
@@ -153,6 +212,7 @@ pub async fn run_batch_with_offset(
 async fn process_single_paper(
     paper_index: usize,
     pdf_path: &std::path::Path,
+    config: &Config,
     pool_tx: &async_channel::Sender<RefJob>,
     extract_tx: &async_channel::Sender<ExtractionJob>,
     tx: &mpsc::UnboundedSender<BackendEvent>,
@@ -174,11 +234,11 @@ async fn process_single_paper(
         });
         return;
     }
-    let extraction: Result<ExtractionResult, String> = result_rx
+    let extraction: Result<(ExtractionResult, Option<String>), String> = result_rx
         .await
         .unwrap_or_else(|_| Err("Extraction worker dropped".to_string()));
 
-    let extraction = match extraction {
+    let (extraction, document_title) = match extraction {
         Ok(ext) => ext,
         Err(error) => {
             let _ = tx.send(BackendEvent::ExtractionFailed { paper_index, error });
@@ -192,8 +252,15 @@ async fn process_single_paper(
     let _ = tx.send(BackendEvent::ExtractionComplete {
         paper_index,
         ref_count: all_refs.len(),
-        references: all_refs.clone(),
         skip_stats,
+        document_title,
+    });
+    // List every reference as pending before the first query goes out
+    let _ = tx.send(BackendEvent::Progress {
+        paper_index,
+        event: Box::new(ProgressEvent::Extracted {
+            references: all_refs.clone(),
+        }),
     });
 
     // Build a mapping from filtered (checkable) index → original all_refs index,
@@ -220,6 +287,12 @@ async fn process_single_paper(
 
     let total = refs.len();
 
+    // Resolve refs with a DOI or arXiv ID in bulk where a backend supports it;
+    // the pool's per-ref queries then hit the cache
+    if let Some(client) = &config.http_client {
+        hallucinator_core::prefetch_batched(&refs, config, client).await;
+    }
+
     // Submit all refs to the shared pool and collect oneshot receivers
     let mut receivers = Vec::with_capacity(total);
     for (i, reference) in refs.iter().enumerate() {
@@ -253,10 +326,30 @@ async fn process_single_paper(
     }
 
     // Await all receivers (results are already sent via Progress events)
-    for (_i, rx) in receivers {
-        let _ = rx.await;
+    let mut results = vec![None; total];
+    for (i, rx) in receivers {
+        results[i] = rx.await.ok();
     }
 
+    // Re-check refs left unverified by a database failure, if configured
+    let tx_progress = tx.clone();
+    let progress_cb = move |event: ProgressEvent| {
+        let event = remap_progress_index(event, &index_map);
+        let _ = tx_progress.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(event),
+        });
+    };
+    // Only fails building the HTTP client, which the batch already did
+    let _ = hallucinator_core::checker::retry_failed_references(
+        &refs,
+        &mut results,
+        config,
+        &progress_cb,
+        cancel,
+    )
+    .await;
+
     let _ = tx.send(BackendEvent::PaperComplete { paper_index });
 }
 
@@ -267,11 +360,10 @@ pub async fn retry_references(
     config: Config,
     tx: mpsc::UnboundedSender<BackendEvent>,
 ) {
-    let client = reqwest::Client::builder()
-        .pool_max_idle_per_host(2)
-        .pool_idle_timeout(std::time::Duration::from_secs(30))
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new());
+    // The network settings were validated when the TUI started
+    let Ok(client) = config.http_client() else {
+        return;
+    };
     let config = Arc::new(config);
     let semaphore = Arc::new(tokio::sync::Semaphore::new(config.num_workers.max(1)));
     let total = refs_to_retry.len();
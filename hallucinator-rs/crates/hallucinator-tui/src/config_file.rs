@@ -43,6 +43,9 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         {
             state.cache_path = path.clone();
         }
+        if let Some(v) = db.cache_max_entries {
+            state.cache_max_entries = Some(v);
+        }
         if let Some(ref url) = db.searxng_url
             && !url.is_empty()
         {
@@ -55,6 +58,27 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
                 }
             }
         }
+        if let Some(v) = db.min_databases_queried {
+            state.min_databases_queried = v;
+        }
+        if let Some(v) = db.match_threshold
+            && (0.0..=1.0).contains(&v)
+        {
+            state.match_threshold = Some(v);
+        }
+        if let Some(ref thresholds) = db.thresholds {
+            state.thresholds = thresholds
+                .iter()
+                .filter(|(_, t)| (0.0..=1.0).contains(*t))
+                .map(|(name, t)| (name.clone(), *t))
+                .collect();
+        }
+        if let Some(v) = db.check_citations {
+            state.check_citations = v;
+        }
+        if let Some(v) = db.existence_only {
+            state.existence_only = v;
+        }
     }
     if let Some(conc) = &file_cfg.concurrency {
         if let Some(v) = conc.num_workers {
@@ -69,10 +93,25 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         if let Some(v) = conc.max_rate_limit_retries {
             state.max_rate_limit_retries = v;
         }
+        if let Some(v) = conc.retry_passes {
+            state.retry_passes = v;
+        }
         if let Some(v) = conc.max_archive_size_mb {
             state.max_archive_size_mb = v;
         }
     }
+    if let Some(net) = &file_cfg.network {
+        if let Some(ref proxy) = net.http_proxy
+            && !proxy.is_empty()
+        {
+            state.http_proxy = Some(proxy.clone());
+        }
+        if let Some(ref path) = net.extra_ca_cert
+            && !path.is_empty()
+        {
+            state.extra_ca_cert = Some(path.clone());
+        }
+    }
     if let Some(disp) = &file_cfg.display {
         if let Some(ref theme) = disp.theme
             && !theme.is_empty()
@@ -82,6 +121,9 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         if let Some(fps) = disp.fps {
             state.fps = fps.clamp(1, 120);
         }
+        if let Some(no_banner) = disp.no_banner {
+            state.no_banner = no_banner;
+        }
     }
 }
 
@@ -133,23 +175,39 @@ pub fn from_config_state(state: &ConfigState) -> ConfigFile {
             } else {
                 Some(state.cache_path.clone())
             },
+            cache_max_entries: state.cache_max_entries,
             searxng_url: state.searxng_url.clone(),
             disabled: if disabled.is_empty() {
                 None
             } else {
                 Some(disabled)
             },
+            min_databases_queried: Some(state.min_databases_queried),
+            match_threshold: state.match_threshold,
+            thresholds: if state.thresholds.is_empty() {
+                None
+            } else {
+                Some(state.thresholds.clone())
+            },
+            check_citations: Some(state.check_citations),
+            existence_only: Some(state.existence_only),
         }),
         concurrency: Some(ConcurrencyConfig {
             num_workers: Some(state.num_workers),
             db_timeout_secs: Some(state.db_timeout_secs),
             db_timeout_short_secs: Some(state.db_timeout_short_secs),
             max_rate_limit_retries: Some(state.max_rate_limit_retries),
+            retry_passes: Some(state.retry_passes),
             max_archive_size_mb: Some(state.max_archive_size_mb),
         }),
+        network: Some(NetworkConfig {
+            http_proxy: state.http_proxy.clone(),
+            extra_ca_cert: state.extra_ca_cert.clone(),
+        }),
         display: Some(DisplayConfig {
             theme: Some(state.theme_name.clone()),
             fps: Some(state.fps),
+            no_banner: Some(state.no_banner),
         }),
     }
 }
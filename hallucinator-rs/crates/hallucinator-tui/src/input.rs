@@ -28,8 +28,8 @@ pub fn map_event(event: &Event, input_mode: &InputMode) -> Action {
 
 fn map_mouse(mouse: &MouseEvent) -> Action {
     match mouse.kind {
-        MouseEventKind::ScrollDown => Action::MoveDown,
-        MouseEventKind::ScrollUp => Action::MoveUp,
+        MouseEventKind::ScrollDown => Action::ScrollDown,
+        MouseEventKind::ScrollUp => Action::ScrollUp,
         MouseEventKind::Down(MouseButton::Left) => Action::ClickAt(mouse.column, mouse.row),
         _ => Action::None,
     }
@@ -49,11 +49,12 @@ fn map_key_normal(key: &KeyEvent) -> Action {
         KeyCode::Char('s') => Action::CycleSort,
         KeyCode::Char('f') => Action::CycleFilter,
         KeyCode::Char('/') => Action::StartSearch,
-        KeyCode::Char('n') => Action::NextMatch,
-        KeyCode::Char('N') => Action::PrevMatch,
+        KeyCode::Char('n') => Action::NextProblem,
+        KeyCode::Char('N') => Action::PrevProblem,
         KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Retry,
         KeyCode::Char('r') => Action::StartProcessing,
         KeyCode::Char('R') => Action::RetryAll,
+        KeyCode::Char('F') => Action::RetryAllPapers,
         KeyCode::Char('e') => Action::Export,
         KeyCode::Char('o') | KeyCode::Char('a') => Action::AddFiles,
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::PageDown,
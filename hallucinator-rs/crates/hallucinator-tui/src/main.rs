@@ -71,7 +71,7 @@ struct Cli {
     #[arg(long)]
     check_openalex_authors: bool,
 
-    /// Color theme: hacker (default), modern, or gnr
+    /// Color theme: hacker (default), modern, gnr, or a path to a theme .toml file
     #[arg(long)]
     theme: Option<String>,
 
@@ -79,10 +79,19 @@ struct Cli {
     #[arg(long)]
     load: Option<PathBuf>,
 
+    /// Append a one-line JSON summary (counts and elapsed time) per
+    /// completed paper to this path, for tracking results over time
+    #[arg(long, value_name = "PATH")]
+    metrics_file: Option<PathBuf>,
+
     /// Enable mouse support (click to select rows, scroll)
     #[arg(long)]
     mouse: bool,
 
+    /// Skip the startup banner
+    #[arg(long)]
+    no_banner: bool,
+
     /// Target frames per second (default: 30)
     #[arg(long)]
     fps: Option<u32>,
@@ -100,11 +109,19 @@ enum Command {
     UpdateDblp {
         /// Path to store the DBLP SQLite database (default: ./dblp.db)
         path: Option<PathBuf>,
+
+        /// Only index publications from this year or later (e.g. 2015)
+        #[arg(long)]
+        min_year: Option<u32>,
     },
     /// Download and build the offline ACL Anthology database
     UpdateAcl {
         /// Path to store the ACL SQLite database (default: ./acl.db)
         path: Option<PathBuf>,
+
+        /// Only index papers from this year or later (e.g. 2015)
+        #[arg(long)]
+        min_year: Option<u32>,
     },
     /// Download and build the offline OpenAlex Tantivy index
     UpdateOpenalex {
@@ -118,6 +135,11 @@ enum Command {
         /// Only index works published in this year or later (e.g. 2020)
         #[arg(long)]
         min_year: Option<u32>,
+
+        /// Also index title trigrams for fuzzier candidate retrieval
+        /// (roughly doubles index size; only applies to a new index)
+        #[arg(long)]
+        title_ngrams: bool,
     },
 }
 
@@ -129,21 +151,22 @@ async fn main() -> anyhow::Result<()> {
     // Handle subcommands
     if let Some(command) = cli.command {
         return match command {
-            Command::UpdateDblp { path } => {
+            Command::UpdateDblp { path, min_year } => {
                 let db_path = path.unwrap_or_else(|| PathBuf::from("dblp.db"));
-                update_dblp(&db_path).await
+                update_dblp(&db_path, min_year).await
             }
-            Command::UpdateAcl { path } => {
+            Command::UpdateAcl { path, min_year } => {
                 let db_path = path.unwrap_or_else(|| PathBuf::from("acl.db"));
-                update_acl(&db_path).await
+                update_acl(&db_path, min_year).await
             }
             Command::UpdateOpenalex {
                 path,
                 since,
                 min_year,
+                title_ngrams,
             } => {
                 let db_path = path.unwrap_or_else(|| PathBuf::from("openalex.idx"));
-                update_openalex(&db_path, since.as_deref(), min_year).await
+                update_openalex(&db_path, since.as_deref(), min_year, title_ngrams).await
             }
         };
     }
@@ -223,6 +246,9 @@ async fn main() -> anyhow::Result<()> {
     if let Some(fps) = cli.fps {
         config_state.fps = fps.clamp(1, 120);
     }
+    if cli.no_banner {
+        config_state.no_banner = true;
+    }
 
     // SearxNG URL: only enabled if --searxng flag is set
     if cli.searxng {
@@ -234,6 +260,9 @@ async fn main() -> anyhow::Result<()> {
         );
     }
 
+    // An invalid [network] proxy or CA certificate would fail every query
+    hallucinator_core::http::build_client_for(&config_state.network_config())?;
+
     // Mark disabled DBs from CLI args
     for (name, enabled) in &mut config_state.disabled_dbs {
         if cli.disable_dbs.iter().any(|d| d.eq_ignore_ascii_case(name)) {
@@ -376,11 +405,13 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    // Select theme
-    let theme = match config_state.theme_name.as_str() {
-        "modern" => theme::Theme::modern(),
-        "gnr" | "t800" => theme::Theme::t800(),
-        _ => theme::Theme::hacker(),
+    // Select theme: built-in name or custom theme file
+    let theme = match theme::Theme::resolve(&config_state.theme_name) {
+        Ok(theme) => theme,
+        Err(e) => {
+            startup_warnings.push(format!("{:#}; using hacker theme", e));
+            theme::Theme::hacker()
+        }
     };
 
     // Separate archives from regular files — archives need deferred extraction
@@ -465,6 +496,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Apply the fully-resolved config state
     app.config_state = config_state;
+    app.metrics_file = cli.metrics_file.clone();
 
     // Record banner start time for Instant-based auto-dismiss
     app.banner_start = Some(std::time::Instant::now());
@@ -566,6 +598,9 @@ async fn main() -> anyhow::Result<()> {
         app.single_paper_mode = true;
     }
 
+    // Fast start: go straight to the queue/paper/file picker
+    app.skip_banner_if_configured();
+
     // Set up backend command channel for deferred processing
     let (event_tx, mut event_rx) = mpsc::unbounded_channel();
     let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel::<tui_event::BackendCommand>();
@@ -664,7 +699,7 @@ async fn main() -> anyhow::Result<()> {
                 tui_event::BackendCommand::CancelProcessing => {
                     batch_cancel.cancel();
                 }
-                tui_event::BackendCommand::BuildDblp { db_path } => {
+                tui_event::BackendCommand::BuildDblp { db_path, client } => {
                     // Invalidate cached handle so the next ProcessFiles re-opens
                     // the DB even if the path hasn't changed (e.g. rebuilding
                     // an existing dblp.db that previously failed to open).
@@ -672,10 +707,19 @@ async fn main() -> anyhow::Result<()> {
                     cached_dblp_path = None;
                     let tx = event_tx_for_backend.clone();
                     tokio::spawn(async move {
-                        let result = hallucinator_dblp::build_database(&db_path, |evt| {
-                            let _ =
-                                tx.send(tui_event::BackendEvent::DblpBuildProgress { event: evt });
-                        })
+                        let options = hallucinator_dblp::BuildOptions {
+                            client: Some(client),
+                            ..Default::default()
+                        };
+                        let result = hallucinator_dblp::build_database_with_options(
+                            &db_path,
+                            options,
+                            |evt| {
+                                let _ = tx.send(tui_event::BackendEvent::DblpBuildProgress {
+                                    event: evt,
+                                });
+                            },
+                        )
                         .await;
                         match result {
                             Ok(_) => {
@@ -695,16 +739,24 @@ async fn main() -> anyhow::Result<()> {
                         }
                     });
                 }
-                tui_event::BackendCommand::BuildAcl { db_path } => {
+                tui_event::BackendCommand::BuildAcl { db_path, client } => {
                     // Invalidate cached handle (same reason as DBLP above).
                     cached_acl_db = None;
                     cached_acl_path = None;
                     let tx = event_tx_for_backend.clone();
                     tokio::spawn(async move {
-                        let result = hallucinator_acl::build_database(&db_path, |evt| {
-                            let _ =
-                                tx.send(tui_event::BackendEvent::AclBuildProgress { event: evt });
-                        })
+                        let options = hallucinator_acl::BuildOptions {
+                            client: Some(client),
+                            ..Default::default()
+                        };
+                        let result = hallucinator_acl::build_database_with_options(
+                            &db_path,
+                            options,
+                            |evt| {
+                                let _ = tx
+                                    .send(tui_event::BackendEvent::AclBuildProgress { event: evt });
+                            },
+                        )
                         .await;
                         match result {
                             Ok(_) => {
@@ -724,16 +776,31 @@ async fn main() -> anyhow::Result<()> {
                         }
                     });
                 }
-                tui_event::BackendCommand::BuildOpenalex { db_path } => {
+                tui_event::BackendCommand::BuildOpenalex {
+                    db_path,
+                    since,
+                    min_year,
+                    client,
+                } => {
                     cached_openalex_db = None;
                     cached_openalex_path = None;
                     let tx = event_tx_for_backend.clone();
                     tokio::spawn(async move {
-                        let result = hallucinator_openalex::build_database(&db_path, |evt| {
-                            let _ = tx.send(tui_event::BackendEvent::OpenAlexBuildProgress {
-                                event: evt,
-                            });
-                        })
+                        let options = hallucinator_openalex::BuildOptions {
+                            since,
+                            min_year,
+                            client: Some(client),
+                            ..Default::default()
+                        };
+                        let result = hallucinator_openalex::build_database_with_options(
+                            &db_path,
+                            options,
+                            |evt| {
+                                let _ = tx.send(tui_event::BackendEvent::OpenAlexBuildProgress {
+                                    event: evt,
+                                });
+                            },
+                        )
                         .await;
                         match result {
                             Ok(_) => {
@@ -893,7 +960,17 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
+/// The client for the `update-*` downloads, with the config file's
+/// `[network]` proxy and CA certificate.
+fn download_client() -> anyhow::Result<reqwest::Client> {
+    let mut config_state = model::config::ConfigState::default();
+    config_file::apply_to_config_state(&config_file::load_config(), &mut config_state);
+    Ok(hallucinator_core::http::build_client_for(
+        &config_state.network_config(),
+    )?)
+}
+
+async fn update_dblp(db_path: &PathBuf, min_year: Option<u32>) -> anyhow::Result<()> {
     use indicatif::{HumanBytes, HumanCount, MultiProgress, ProgressBar, ProgressStyle};
     use std::time::Instant;
 
@@ -940,111 +1017,149 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
     let build_start = Instant::now();
     let parse_start = std::cell::Cell::new(None::<Instant>);
 
-    let updated = hallucinator_dblp::build_database(db_path, |event| match event {
-        hallucinator_dblp::BuildProgress::Downloading {
-            bytes_downloaded,
-            total_bytes,
-            ..
-        } => {
-            if let Some(total) = total_bytes {
-                if dl_bar.length() == Some(0) {
-                    dl_bar.set_length(total);
-                    dl_bar.set_style(dl_bar_style.clone());
-                }
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading dblp.xml.gz");
-                if bytes_downloaded >= total && !dl_bar.is_finished() {
-                    dl_bar.finish_with_message(format!(
-                        "Downloaded {} in {:.0?}",
-                        HumanBytes(total),
-                        dl_bar.elapsed()
-                    ));
+    if let Some(min_year) = min_year {
+        println!("Only indexing publications from {min_year} or later");
+    }
+
+    let options = hallucinator_dblp::BuildOptions {
+        min_year,
+        client: Some(download_client()?),
+        ..Default::default()
+    };
+    let updated =
+        hallucinator_dblp::build_database_with_options(db_path, options, |event| match event {
+            hallucinator_dblp::BuildProgress::Downloading {
+                bytes_downloaded,
+                total_bytes,
+                ..
+            } => {
+                if let Some(total) = total_bytes {
+                    if dl_bar.length() == Some(0) {
+                        dl_bar.set_length(total);
+                        dl_bar.set_style(dl_bar_style.clone());
+                    }
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading dblp.xml.gz");
+                    if bytes_downloaded >= total && !dl_bar.is_finished() {
+                        dl_bar.finish_with_message(format!(
+                            "Downloaded {} in {:.0?}",
+                            HumanBytes(total),
+                            dl_bar.elapsed()
+                        ));
+                    }
+                } else {
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading dblp.xml.gz");
                 }
-            } else {
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading dblp.xml.gz");
             }
-        }
-        hallucinator_dblp::BuildProgress::Parsing {
-            records_inserted,
-            bytes_read,
-            bytes_total,
-        } => {
-            if !dl_bar.is_finished() {
+            hallucinator_dblp::BuildProgress::ReusingDownload { bytes } => {
                 dl_bar.finish_with_message(format!(
-                    "Downloaded {} in {:.0?}",
-                    HumanBytes(dl_bar.position()),
-                    dl_bar.elapsed()
+                    "Reusing downloaded {} (ETag unchanged)",
+                    HumanBytes(bytes)
                 ));
             }
-            if parse_start.get().is_none() {
-                parse_start.set(Some(Instant::now()));
-                parse_bar.reset_elapsed();
-                parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-                parse_bar.enable_steady_tick(Duration::from_millis(120));
+            hallucinator_dblp::BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            } => {
+                dl_bar.println(format!(
+                    "Download failed ({}); retrying ({}/{})...",
+                    error, attempt, max_retries
+                ));
+                dl_bar.set_message("Reconnecting to dblp.org...");
             }
-            if bytes_total > 0 && parse_bar.length() == Some(0) {
-                parse_bar.set_length(bytes_total);
-                parse_bar.set_style(parse_bar_style.clone());
+            hallucinator_dblp::BuildProgress::Warning { message } => {
+                parse_bar.println(format!("Warning: {}", message));
             }
-            parse_bar.set_position(bytes_read);
-            let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
-            let per_sec = if elapsed > 0.0 {
-                records_inserted as f64 / elapsed
-            } else {
-                0.0
-            };
-            parse_bar.set_message(format!(
-                "{} publications ({}/s)",
-                HumanCount(records_inserted),
-                HumanCount(per_sec as u64),
-            ));
-        }
-        hallucinator_dblp::BuildProgress::RebuildingIndex => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!(
-                    "Downloaded {} in {:.0?}",
-                    HumanBytes(dl_bar.position()),
-                    dl_bar.elapsed()
+            hallucinator_dblp::BuildProgress::Parsing {
+                records_inserted,
+                queue_depth,
+                bytes_read,
+                bytes_total,
+                ..
+            } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!(
+                        "Downloaded {} in {:.0?}",
+                        HumanBytes(dl_bar.position()),
+                        dl_bar.elapsed()
+                    ));
+                }
+                if parse_start.get().is_none() {
+                    parse_start.set(Some(Instant::now()));
+                    parse_bar.reset_elapsed();
+                    parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                    parse_bar.enable_steady_tick(Duration::from_millis(120));
+                }
+                if bytes_total > 0 && parse_bar.length() == Some(0) {
+                    parse_bar.set_length(bytes_total);
+                    parse_bar.set_style(parse_bar_style.clone());
+                }
+                parse_bar.set_position(bytes_read);
+                let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
+                let per_sec = if elapsed > 0.0 {
+                    records_inserted as f64 / elapsed
+                } else {
+                    0.0
+                };
+                parse_bar.set_message(format!(
+                    "{} publications ({}/s, {} queued)",
+                    HumanCount(records_inserted),
+                    HumanCount(per_sec as u64),
+                    HumanCount(queue_depth),
                 ));
             }
-            if !parse_bar.is_finished() {
-                let elapsed = parse_start.get().map(|s| s.elapsed());
-                parse_bar.finish_with_message(format!(
-                    "Inserted publications in {:.0?}",
-                    elapsed.unwrap_or_default()
-                ));
+            hallucinator_dblp::BuildProgress::RebuildingIndex => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!(
+                        "Downloaded {} in {:.0?}",
+                        HumanBytes(dl_bar.position()),
+                        dl_bar.elapsed()
+                    ));
+                }
+                if !parse_bar.is_finished() {
+                    let elapsed = parse_start.get().map(|s| s.elapsed());
+                    parse_bar.finish_with_message(format!(
+                        "Inserted publications in {:.0?}",
+                        elapsed.unwrap_or_default()
+                    ));
+                }
+                finalize_bar.reset_elapsed();
+                finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                finalize_bar.enable_steady_tick(Duration::from_millis(120));
+                finalize_bar.set_message("Rebuilding FTS search index...");
             }
-            finalize_bar.reset_elapsed();
-            finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-            finalize_bar.enable_steady_tick(Duration::from_millis(120));
-            finalize_bar.set_message("Rebuilding FTS search index...");
-        }
-        hallucinator_dblp::BuildProgress::Compacting => {
-            finalize_bar.set_message("Compacting database (VACUUM)...");
-        }
-        hallucinator_dblp::BuildProgress::Complete {
-            publications,
-            authors,
-            skipped,
-        } => {
-            if !parse_bar.is_finished() {
-                parse_bar.finish_and_clear();
+            hallucinator_dblp::BuildProgress::Compacting => {
+                finalize_bar.set_message("Compacting database (VACUUM)...");
             }
-            if skipped {
-                finalize_bar
-                    .finish_with_message("Database is already up to date (304 Not Modified)");
-            } else {
-                finalize_bar.finish_with_message(format!(
-                    "Indexed {} publications, {} authors (total {:.0?})",
-                    HumanCount(publications),
-                    HumanCount(authors),
-                    build_start.elapsed()
-                ));
+            hallucinator_dblp::BuildProgress::Complete {
+                publications,
+                authors,
+                issues,
+                skipped,
+            } => {
+                if !parse_bar.is_finished() {
+                    parse_bar.finish_and_clear();
+                }
+                if skipped {
+                    finalize_bar
+                        .finish_with_message("Database is already up to date (304 Not Modified)");
+                } else {
+                    let mut summary = format!(
+                        "Indexed {} publications, {} authors (total {:.0?})",
+                        HumanCount(publications),
+                        HumanCount(authors),
+                        build_start.elapsed()
+                    );
+                    if issues > 0 {
+                        summary.push_str(&format!("; {} records had issues", HumanCount(issues)));
+                    }
+                    finalize_bar.finish_with_message(summary);
+                }
             }
-        }
-    })
-    .await?;
+        })
+        .await?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1056,7 +1171,7 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn update_acl(db_path: &PathBuf) -> anyhow::Result<()> {
+async fn update_acl(db_path: &PathBuf, min_year: Option<u32>) -> anyhow::Result<()> {
     use indicatif::{HumanCount, MultiProgress, ProgressBar, ProgressStyle};
     use std::time::Instant;
 
@@ -1106,105 +1221,134 @@ async fn update_acl(db_path: &PathBuf) -> anyhow::Result<()> {
     let build_start = Instant::now();
     let parse_start = std::cell::Cell::new(None::<Instant>);
 
-    let updated = hallucinator_acl::build_database(db_path, |event| match event {
-        hallucinator_acl::BuildProgress::Downloading {
-            bytes_downloaded,
-            total_bytes,
-        } => {
-            if let Some(total) = total_bytes {
-                if dl_bar.length() == Some(0) {
-                    dl_bar.set_length(total);
-                    dl_bar.set_style(dl_bar_style.clone());
+    if let Some(min_year) = min_year {
+        println!("Only indexing papers from {min_year} or later");
+    }
+
+    let options = hallucinator_acl::BuildOptions {
+        min_year,
+        client: Some(download_client()?),
+        ..Default::default()
+    };
+    let updated =
+        hallucinator_acl::build_database_with_options(db_path, options, |event| match event {
+            hallucinator_acl::BuildProgress::Downloading {
+                bytes_downloaded,
+                total_bytes,
+            } => {
+                if let Some(total) = total_bytes {
+                    if dl_bar.length() == Some(0) {
+                        dl_bar.set_length(total);
+                        dl_bar.set_style(dl_bar_style.clone());
+                    }
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading acl-anthology.tar.gz");
+                } else {
+                    dl_bar.set_position(bytes_downloaded);
+                    dl_bar.set_message("Downloading acl-anthology.tar.gz");
                 }
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading acl-anthology.tar.gz");
-            } else {
-                dl_bar.set_position(bytes_downloaded);
-                dl_bar.set_message("Downloading acl-anthology.tar.gz");
-            }
-        }
-        hallucinator_acl::BuildProgress::Extracting { files_extracted } => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
-            }
-            if parse_bar.is_hidden() {
-                parse_bar.reset_elapsed();
-                parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-                parse_bar.enable_steady_tick(Duration::from_millis(120));
-            }
-            parse_bar.set_message(format!("Extracting XML files... ({})", files_extracted));
-        }
-        hallucinator_acl::BuildProgress::Parsing {
-            records_parsed,
-            records_inserted,
-            files_processed,
-            files_total,
-        } => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
             }
-            if parse_start.get().is_none() {
-                parse_start.set(Some(Instant::now()));
-                parse_bar.reset_elapsed();
-                parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-                parse_bar.enable_steady_tick(Duration::from_millis(120));
+            hallucinator_acl::BuildProgress::Retrying {
+                attempt,
+                max_retries,
+                error,
+            } => {
+                dl_bar.println(format!(
+                    "Download failed ({}); retrying ({}/{})...",
+                    error, attempt, max_retries
+                ));
+                dl_bar.set_message("Reconnecting to GitHub...");
             }
-            if files_total > 0 && parse_bar.length() == Some(0) {
-                parse_bar.set_length(files_total);
-                parse_bar.set_style(parse_bar_style.clone());
+            hallucinator_acl::BuildProgress::Warning { message } => {
+                parse_bar.println(format!("Warning: {}", message));
             }
-            parse_bar.set_position(files_processed);
-            let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
-            let per_sec = if elapsed > 0.0 {
-                records_inserted as f64 / elapsed
-            } else {
-                0.0
-            };
-            parse_bar.set_message(format!(
-                "{} parsed, {} inserted ({}/s)",
-                HumanCount(records_parsed),
-                HumanCount(records_inserted),
-                HumanCount(per_sec as u64),
-            ));
-        }
-        hallucinator_acl::BuildProgress::RebuildingIndex => {
-            if !dl_bar.is_finished() {
-                dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+            hallucinator_acl::BuildProgress::Extracting { files_extracted } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
+                if parse_bar.is_hidden() {
+                    parse_bar.reset_elapsed();
+                    parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                    parse_bar.enable_steady_tick(Duration::from_millis(120));
+                }
+                parse_bar.set_message(format!("Extracting XML files... ({})", files_extracted));
             }
-            if !parse_bar.is_finished() {
-                let elapsed = parse_start.get().map(|s| s.elapsed());
-                parse_bar.finish_with_message(format!(
-                    "Inserted publications in {:.0?}",
-                    elapsed.unwrap_or_default()
+            hallucinator_acl::BuildProgress::Parsing {
+                records_parsed,
+                records_inserted,
+                files_processed,
+                files_total,
+            } => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
+                if parse_start.get().is_none() {
+                    parse_start.set(Some(Instant::now()));
+                    parse_bar.reset_elapsed();
+                    parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                    parse_bar.enable_steady_tick(Duration::from_millis(120));
+                }
+                if files_total > 0 && parse_bar.length() == Some(0) {
+                    parse_bar.set_length(files_total);
+                    parse_bar.set_style(parse_bar_style.clone());
+                }
+                parse_bar.set_position(files_processed);
+                let elapsed = parse_start.get().unwrap().elapsed().as_secs_f64();
+                let per_sec = if elapsed > 0.0 {
+                    records_inserted as f64 / elapsed
+                } else {
+                    0.0
+                };
+                parse_bar.set_message(format!(
+                    "{} parsed, {} inserted ({}/s)",
+                    HumanCount(records_parsed),
+                    HumanCount(records_inserted),
+                    HumanCount(per_sec as u64),
                 ));
             }
-            finalize_bar.reset_elapsed();
-            finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
-            finalize_bar.enable_steady_tick(Duration::from_millis(120));
-            finalize_bar.set_message("Rebuilding FTS search index...");
-        }
-        hallucinator_acl::BuildProgress::Complete {
-            publications,
-            authors,
-            skipped,
-        } => {
-            if !parse_bar.is_finished() {
-                parse_bar.finish_and_clear();
+            hallucinator_acl::BuildProgress::RebuildingIndex => {
+                if !dl_bar.is_finished() {
+                    dl_bar.finish_with_message(format!("Downloaded in {:.0?}", dl_bar.elapsed()));
+                }
+                if !parse_bar.is_finished() {
+                    let elapsed = parse_start.get().map(|s| s.elapsed());
+                    parse_bar.finish_with_message(format!(
+                        "Inserted publications in {:.0?}",
+                        elapsed.unwrap_or_default()
+                    ));
+                }
+                finalize_bar.reset_elapsed();
+                finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+                finalize_bar.enable_steady_tick(Duration::from_millis(120));
+                finalize_bar.set_message("Rebuilding FTS search index...");
             }
-            if skipped {
-                finalize_bar
-                    .finish_with_message("Database is already up to date (same commit SHA)");
-            } else {
-                finalize_bar.finish_with_message(format!(
-                    "Indexed {} publications, {} authors (total {:.0?})",
-                    HumanCount(publications),
-                    HumanCount(authors),
-                    build_start.elapsed()
-                ));
+            hallucinator_acl::BuildProgress::Complete {
+                publications,
+                authors,
+                issues,
+                skipped,
+            } => {
+                if !parse_bar.is_finished() {
+                    parse_bar.finish_and_clear();
+                }
+                if skipped {
+                    finalize_bar
+                        .finish_with_message("Database is already up to date (same commit SHA)");
+                } else {
+                    let mut summary = format!(
+                        "Indexed {} publications, {} authors (total {:.0?})",
+                        HumanCount(publications),
+                        HumanCount(authors),
+                        build_start.elapsed()
+                    );
+                    if issues > 0 {
+                        summary.push_str(&format!("; {} records had issues", HumanCount(issues)));
+                    }
+                    finalize_bar.finish_with_message(summary);
+                }
             }
-        }
-    })
-    .await?;
+        })
+        .await?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1220,6 +1364,7 @@ async fn update_openalex(
     db_path: &PathBuf,
     since: Option<&str>,
     min_year: Option<u32>,
+    title_ngrams: bool,
 ) -> anyhow::Result<()> {
     use indicatif::{HumanCount, MultiProgress, ProgressBar, ProgressStyle};
     use std::time::Instant;
@@ -1239,6 +1384,9 @@ async fn update_openalex(
     if let Some(min_year) = min_year {
         println!("Only indexing works published in {min_year} or later");
     }
+    if title_ngrams {
+        println!("Indexing title trigrams (larger index, fuzzier candidate retrieval)");
+    }
 
     let multi = MultiProgress::new();
 
@@ -1272,11 +1420,15 @@ async fn update_openalex(
 
     let build_start = Instant::now();
 
-    let updated = hallucinator_openalex::build_database_filtered(
-        db_path,
-        since,
+    let options = hallucinator_openalex::BuildOptions {
+        since: since.map(String::from),
         min_year,
-        |event| match event {
+        title_ngrams,
+        secondary_types: Vec::new(),
+        client: Some(download_client()?),
+    };
+    let updated =
+        hallucinator_openalex::build_database_with_options(db_path, options, |event| match event {
             hallucinator_openalex::BuildProgress::ListingPartitions { message } => {
                 dl_bar.set_message(message);
             }
@@ -1360,7 +1512,7 @@ async fn update_openalex(
                     eprintln!("Warning: skipped {} ({error})", filename);
                 });
             }
-            hallucinator_openalex::BuildProgress::Merging => {
+            hallucinator_openalex::BuildProgress::Merging { segments_before } => {
                 for (_, s) in file_spinners.drain() {
                     s.finish_and_clear();
                 }
@@ -1374,7 +1526,16 @@ async fn update_openalex(
                 finalize_bar.reset_elapsed();
                 finalize_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
                 finalize_bar.enable_steady_tick(Duration::from_millis(120));
-                finalize_bar.set_message("Merging index segments...");
+                finalize_bar.set_message(format!("Merging {} index segments...", segments_before));
+            }
+            hallucinator_openalex::BuildProgress::Merged {
+                segments_before,
+                segments_after,
+            } => {
+                finalize_bar.set_message(format!(
+                    "Merged index segments ({} -> {})",
+                    segments_before, segments_after
+                ));
             }
             hallucinator_openalex::BuildProgress::Complete {
                 publications,
@@ -1394,9 +1555,8 @@ async fn update_openalex(
                     ));
                 }
             }
-        },
-    )
-    .await?;
+        })
+        .await?;
 
     let canonical = std::fs::canonicalize(db_path).unwrap_or_else(|_| db_path.clone());
     if !updated {
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
 
 /// Health status of a database backend.
 #[derive(Debug, Clone)]
@@ -69,12 +70,28 @@ impl DbHealth {
     }
 }
 
-/// An active (in-flight) query.
+/// A reference being checked, with the backends it is waiting on.
 #[derive(Debug, Clone)]
 pub struct ActiveQuery {
-    pub db_name: String,
+    pub paper_index: usize,
+    pub ref_index: usize,
     pub ref_title: String,
-    pub is_retry: bool,
+    /// Shown while no backend query is in flight: `ref #N` or `retry (N)`.
+    pub label: String,
+    /// Backends queried for this reference that haven't answered yet, oldest first.
+    pub pending_dbs: Vec<String>,
+}
+
+impl ActiveQuery {
+    /// What the reference is waiting on: its oldest pending backend (with a
+    /// count of the others), or its label.
+    pub fn status(&self) -> String {
+        match self.pending_dbs.as_slice() {
+            [] => self.label.clone(),
+            [db] => db.clone(),
+            [db, rest @ ..] => format!("{} +{}", db, rest.len()),
+        }
+    }
 }
 
 /// State for the activity panel.
@@ -90,6 +107,8 @@ pub struct ActivityState {
     pub messages: VecDeque<(String, bool)>,
     /// DBs that have already triggered a failure warning (to avoid spam).
     pub warned_dbs: HashSet<String>,
+    /// When each DB's latest 429 backoff ends.
+    pub backoff_until: HashMap<String, Instant>,
 }
 
 impl Default for ActivityState {
@@ -101,6 +120,7 @@ impl Default for ActivityState {
             total_completed: 0,
             messages: VecDeque::new(),
             warned_dbs: HashSet::new(),
+            backoff_until: HashMap::new(),
         }
     }
 }
@@ -120,6 +140,53 @@ impl ActivityState {
         self.messages.push_back((msg, true));
     }
 
+    /// Start tracking a reference check, replacing any earlier entry for it.
+    pub fn start_query(
+        &mut self,
+        paper_index: usize,
+        ref_index: usize,
+        title: String,
+        label: String,
+    ) {
+        self.finish_query(paper_index, ref_index);
+        self.active_queries.push(ActiveQuery {
+            paper_index,
+            ref_index,
+            ref_title: title,
+            label,
+            pending_dbs: Vec::new(),
+        });
+    }
+
+    /// Stop tracking a reference check (called on its result).
+    pub fn finish_query(&mut self, paper_index: usize, ref_index: usize) {
+        self.active_queries
+            .retain(|q| (q.paper_index, q.ref_index) != (paper_index, ref_index));
+    }
+
+    /// Note that a backend query for a tracked reference started (`started`)
+    /// or answered.
+    pub fn update_query_db(
+        &mut self,
+        paper_index: usize,
+        ref_index: usize,
+        db_name: &str,
+        started: bool,
+    ) {
+        let Some(q) = self
+            .active_queries
+            .iter_mut()
+            .find(|q| (q.paper_index, q.ref_index) == (paper_index, ref_index))
+        else {
+            return;
+        };
+        if started {
+            q.pending_dbs.push(db_name.to_string());
+        } else if let Some(pos) = q.pending_dbs.iter().position(|db| db == db_name) {
+            q.pending_dbs.remove(pos);
+        }
+    }
+
     /// Increment in-flight count for all given DBs (called on Checking).
     pub fn increment_in_flight(&mut self, db_names: &[String]) {
         for name in db_names {
@@ -153,6 +220,49 @@ impl ActivityState {
         health.record(success, is_rate_limited, is_match, elapsed_ms);
     }
 
+    /// Note that `db_name` is waiting `wait` (from `now`) before retrying a 429.
+    pub fn record_rate_limit_wait(&mut self, db_name: &str, wait: Duration, now: Instant) {
+        let until = now + wait;
+        let entry = self
+            .backoff_until
+            .entry(db_name.to_string())
+            .or_insert(until);
+        if *entry < until {
+            *entry = until;
+        }
+    }
+
+    /// Pick up the backoffs the rate limiters currently report for the
+    /// known DBs.
+    pub fn sync_backoffs(&mut self, limiters: &hallucinator_core::RateLimiters, now: Instant) {
+        let waits: Vec<(String, Duration)> = self
+            .db_health
+            .keys()
+            .filter_map(|name| Some((name.clone(), limiters.backoff_remaining(name)?)))
+            .collect();
+        for (name, wait) in waits {
+            self.record_rate_limit_wait(&name, wait, now);
+        }
+    }
+
+    /// If every DB with queries in flight is waiting out a 429, the time
+    /// until the soonest of them retries. `None` while any of them is
+    /// still making progress.
+    pub fn rate_limit_pause(&self, now: Instant) -> Option<Duration> {
+        let mut soonest: Option<Duration> = None;
+        for (name, health) in &self.db_health {
+            if health.in_flight == 0 {
+                continue;
+            }
+            let left = self.backoff_until.get(name)?.saturating_duration_since(now);
+            if left.is_zero() {
+                return None;
+            }
+            soonest = Some(soonest.map_or(left, |s| s.min(left)));
+        }
+        soonest
+    }
+
     pub fn push_throughput(&mut self, count: u16) {
         if self.throughput_buckets.len() >= 60 {
             self.throughput_buckets.pop_front();
@@ -64,6 +64,9 @@ pub struct ConfigState {
     pub openalex_build_status: Option<String>,
     /// When the OpenAlex build started (for ETA/speed calculations).
     pub openalex_build_started: Option<std::time::Instant>,
+    /// Whether the edit buffer holds the OpenAlex build filter prompt
+    /// (`since`/`min_year`) rather than a config value.
+    pub openalex_build_prompt: bool,
 
     // Editable fields
     pub openalex_key: String,
@@ -74,17 +77,42 @@ pub struct ConfigState {
     pub acl_offline_path: String,
     pub openalex_offline_path: String,
     pub cache_path: String,
+    /// Query cache cap from the config file (None = unbounded). Not
+    /// editable in the TUI.
+    pub cache_max_entries: Option<usize>,
     /// Inline status message for cache clear operation.
     pub cache_clear_status: Option<String>,
     pub num_workers: usize,
     pub max_rate_limit_retries: u32,
+    /// Retry passes over references left unverified by a database failure.
+    pub retry_passes: u32,
+    /// Fewest databases that must answer before a not-found result is a
+    /// confident negative.
+    pub min_databases_queried: usize,
+    /// Offline DB similarity threshold from the config file (None = each
+    /// database's default). Not editable in the TUI.
+    pub match_threshold: Option<f64>,
+    /// Per-database offline thresholds from the config file.
+    pub thresholds: std::collections::HashMap<String, f64>,
+    /// Flag references the document lists but never cites in its body.
+    pub check_citations: bool,
+    /// Only check that cited papers exist; never report author mismatches.
+    pub existence_only: bool,
     pub db_timeout_secs: u64,
     pub db_timeout_short_secs: u64,
     pub max_archive_size_mb: u32, // 0 = unlimited
     pub theme_name: String,
     pub fps: u32,
+    /// Skip the startup banner and go straight to the first working screen.
+    pub no_banner: bool,
     /// SearxNG URL for web search fallback (None = disabled)
     pub searxng_url: Option<String>,
+    /// Proxy for database queries and downloads, from the config file's
+    /// `[network]` section. Not editable in the TUI.
+    pub http_proxy: Option<String>,
+    /// PEM file of extra CA certificates, from the config file's
+    /// `[network]` section. Not editable in the TUI.
+    pub extra_ca_cert: Option<String>,
 }
 
 impl Default for ConfigState {
@@ -121,6 +149,7 @@ impl Default for ConfigState {
             openalex_building: false,
             openalex_build_status: None,
             openalex_build_started: None,
+            openalex_build_prompt: false,
             openalex_key: String::new(),
             s2_api_key: String::new(),
             crossref_mailto: String::new(),
@@ -129,20 +158,41 @@ impl Default for ConfigState {
             acl_offline_path: String::new(),
             openalex_offline_path: String::new(),
             cache_path: String::new(),
+            cache_max_entries: None,
             cache_clear_status: None,
             num_workers: 4,
             max_rate_limit_retries: 3,
+            retry_passes: 0,
+            min_databases_queried: hallucinator_core::DEFAULT_MIN_DATABASES_QUERIED,
+            match_threshold: None,
+            thresholds: std::collections::HashMap::new(),
+            check_citations: false,
+            existence_only: false,
             db_timeout_secs: 10,
             db_timeout_short_secs: 5,
             max_archive_size_mb: 0, // unlimited
             theme_name: "hacker".to_string(),
             fps: 30,
+            no_banner: false,
             searxng_url: None,
+            http_proxy: None,
+            extra_ca_cert: None,
         }
     }
 }
 
 impl ConfigState {
+    /// A `Config` with only what the HTTP client is built from: the contact
+    /// address, proxy and extra CA certificate.
+    pub fn network_config(&self) -> hallucinator_core::Config {
+        hallucinator_core::Config {
+            crossref_mailto: Some(self.crossref_mailto.clone()).filter(|m| !m.is_empty()),
+            http_proxy: self.http_proxy.clone(),
+            extra_ca_cert: self.extra_ca_cert.as_ref().map(std::path::PathBuf::from),
+            ..Default::default()
+        }
+    }
+
     /// Mask a key for display: show first 4 chars then asterisks.
     pub fn mask_key(key: &str) -> String {
         if key.is_empty() {
@@ -1,4 +1,4 @@
-use hallucinator_core::{Reference, Status, ValidationResult};
+use hallucinator_core::{Reference, Status, ValidationResult, classify_citation};
 
 pub use hallucinator_reporting::FpReason;
 
@@ -31,6 +31,8 @@ pub struct RefState {
     pub doi: Option<String>,
     /// arXiv ID extracted during parsing.
     pub arxiv_id: Option<String>,
+    /// LaTeX cite key, for references read from a `.bbl`/`.bib` file.
+    pub cite_key: Option<String>,
 }
 
 impl RefState {
@@ -54,6 +56,10 @@ impl RefState {
             arxiv_id: self.arxiv_id.clone(),
             original_number: self.index + 1,
             skip_reason,
+            kind: classify_citation(&self.raw_citation),
+            container_title: None,
+            cite_key: self.cite_key.clone(),
+            cited_in_text: None,
         }
     }
 
@@ -62,6 +68,18 @@ impl RefState {
         self.fp_reason.is_some()
     }
 
+    /// Whether this reference needs review: not found, author mismatch, or
+    /// retracted, and not already marked safe.
+    pub fn is_problematic(&self) -> bool {
+        if self.is_marked_safe() {
+            return false;
+        }
+        self.result.as_ref().is_some_and(|r| {
+            r.status != Status::Verified
+                || r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted)
+        })
+    }
+
     pub fn verdict_label(&self) -> String {
         if let Some(reason) = self.fp_reason {
             return format!("\u{2713} Safe ({})", reason.short_label());
@@ -70,6 +88,7 @@ impl RefState {
             return match reason.as_str() {
                 "url_only" => "(skipped: URL-only)".to_string(),
                 "short_title" => "(skipped: short title)".to_string(),
+                "placeholder" => "(skipped: placeholder)".to_string(),
                 "no_title" => "(skipped: no title)".to_string(),
                 other => format!("(skipped: {})", other),
             };
@@ -107,6 +126,36 @@ impl RefState {
     }
 }
 
+/// A paper's references to re-check: `(ref_index, reference, failed_dbs)`.
+pub type RetryBatch = Vec<(usize, Reference, Vec<String>)>;
+
+/// Collect references whose result is incomplete because of transient
+/// database failures (timeouts, rate limits, errors), grouped by paper index.
+///
+/// Verified references, references marked safe, and references already being
+/// retried are left alone. Papers without any such reference are omitted.
+pub fn collect_transient_retries(ref_states: &[Vec<RefState>]) -> Vec<(usize, RetryBatch)> {
+    ref_states
+        .iter()
+        .enumerate()
+        .filter_map(|(paper_idx, refs)| {
+            let batch: RetryBatch = refs
+                .iter()
+                .enumerate()
+                .filter(|(_, rs)| !rs.is_marked_safe() && rs.phase != RefPhase::Retrying)
+                .filter_map(|(i, rs)| {
+                    let result = rs.result.as_ref()?;
+                    if result.status == Status::Verified || result.failed_dbs.is_empty() {
+                        return None;
+                    }
+                    Some((i, rs.to_reference(), result.failed_dbs.clone()))
+                })
+                .collect();
+            (!batch.is_empty()).then_some((paper_idx, batch))
+        })
+        .collect()
+}
+
 /// Sort order for references in the paper view.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PaperSortOrder {
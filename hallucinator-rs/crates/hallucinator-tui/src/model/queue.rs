@@ -42,6 +42,8 @@ impl PaperPhase {
 #[derive(Debug, Clone)]
 pub struct PaperState {
     pub filename: String,
+    /// The document's own title, once extraction has read it.
+    pub title: Option<String>,
     pub phase: PaperPhase,
     pub total_refs: usize,
     pub stats: CheckStats,
@@ -54,12 +56,18 @@ pub struct PaperState {
     pub retry_done: usize,
     /// User-assigned verdict for the entire paper.
     pub verdict: Option<PaperVerdict>,
+    /// When extraction of this paper started.
+    pub started: Option<std::time::Instant>,
+    /// Whether the paper's `--metrics-file` record was written; a retry
+    /// completes the paper again without adding another.
+    pub metrics_written: bool,
 }
 
 impl PaperState {
     pub fn new(filename: String) -> Self {
         Self {
             filename,
+            title: None,
             phase: PaperPhase::Queued,
             total_refs: 0,
             stats: CheckStats::default(),
@@ -68,9 +76,17 @@ impl PaperState {
             retry_total: 0,
             retry_done: 0,
             verdict: None,
+            started: None,
+            metrics_written: false,
         }
     }
 
+    /// Label shown for the paper: its title followed by the filename, or
+    /// just the filename when no title was found.
+    pub fn display_name(&self) -> String {
+        hallucinator_reporting::paper_label(&self.filename, self.title.as_deref())
+    }
+
     /// Pre-allocate result slots once the reference count is known.
     pub fn init_results(&mut self, count: usize) {
         self.results = vec![None; count];
@@ -247,7 +263,8 @@ pub fn filtered_indices(
         .enumerate()
         .filter(|(_, p)| {
             filter.matches(p)
-                && (search_query.is_empty() || p.filename.to_lowercase().contains(&query_lower))
+                && (search_query.is_empty()
+                    || p.display_name().to_lowercase().contains(&query_lower))
         })
         .map(|(i, _)| i)
         .collect()
@@ -1,8 +1,10 @@
 use std::io::Write;
 use std::path::PathBuf;
 
+use hallucinator_core::run::{PaperRecord, RecordStatus, ReferenceRecord, RunStats, run_to_json};
+
 use crate::model::paper::{RefPhase, RefState};
-use crate::model::queue::PaperState;
+use crate::model::queue::{PaperState, PaperVerdict};
 
 /// Get the run directory for persisting results.
 /// Creates `~/.cache/hallucinator/runs/<timestamp>/` if it doesn't exist.
@@ -19,8 +21,8 @@ pub fn run_dir() -> Option<PathBuf> {
 
 /// Persist results for a single paper to the run directory.
 ///
-/// Uses the same rich JSON format as the export module so that saved results
-/// can be loaded back via `--load` or the file picker.
+/// Writes the canonical run schema (`hallucinator_core::run`) so that saved
+/// results can be loaded back via `--load` or the file picker.
 pub fn save_paper_results(
     run_dir: &std::path::Path,
     paper_index: usize,
@@ -28,32 +30,66 @@ pub fn save_paper_results(
     ref_states: &[RefState],
 ) {
     let out_path = run_dir.join(format!("paper_{}.json", paper_index));
-    let results_from_refs: Vec<Option<hallucinator_core::ValidationResult>> =
-        ref_states.iter().map(|rs| rs.result.clone()).collect();
-    let report_paper = hallucinator_reporting::ReportPaper {
-        filename: &paper.filename,
-        stats: &paper.stats,
-        results: &results_from_refs,
-        verdict: paper.verdict,
-    };
-    let report_refs: Vec<hallucinator_reporting::ReportRef> = ref_states
-        .iter()
-        .map(|rs| hallucinator_reporting::ReportRef {
-            index: rs.index,
-            title: rs.title.clone(),
-            skip_info: if let RefPhase::Skipped(reason) = &rs.phase {
-                Some(hallucinator_reporting::SkipInfo {
-                    reason: reason.clone(),
-                })
-            } else {
-                None
-            },
-            fp_reason: rs.fp_reason,
-        })
-        .collect();
-    let json = hallucinator_reporting::export_json(&[report_paper], &[&report_refs], false);
+    let json = run_to_json(&[paper_record(paper, ref_states)]);
 
     if let Ok(mut file) = std::fs::File::create(&out_path) {
         let _ = file.write_all(json.as_bytes());
     }
 }
+
+/// Build the canonical record for a paper from TUI state.
+fn paper_record(paper: &PaperState, ref_states: &[RefState]) -> PaperRecord {
+    let references = ref_states
+        .iter()
+        .enumerate()
+        .map(|(i, rs)| {
+            let mut record = match (&rs.phase, &rs.result) {
+                (RefPhase::Skipped(reason), _) => ReferenceRecord {
+                    skip_reason: Some(reason.clone()),
+                    ..ReferenceRecord::unchecked(
+                        i,
+                        rs.index + 1,
+                        &rs.title,
+                        &rs.raw_citation,
+                        RecordStatus::Skipped,
+                    )
+                },
+                (_, Some(result)) => ReferenceRecord::from_result(i, rs.index + 1, result),
+                (_, None) => ReferenceRecord::unchecked(
+                    i,
+                    rs.index + 1,
+                    &rs.title,
+                    &rs.raw_citation,
+                    RecordStatus::Pending,
+                ),
+            };
+            record.cite_key = rs.cite_key.clone();
+            if let Some(fp) = rs.fp_reason {
+                record.fp_reason = Some(fp.as_str().to_string());
+                if record.status.to_status().is_some() {
+                    record.effective_status = Some(RecordStatus::Verified);
+                }
+            }
+            if record.ref_authors.is_empty() {
+                record.ref_authors = rs.authors.clone();
+            }
+            record.fingerprint.get_or_insert_with(|| {
+                hallucinator_core::matching::reference_fingerprint(&rs.title, &rs.authors)
+            });
+            record
+        })
+        .collect();
+    PaperRecord {
+        filename: paper.filename.clone(),
+        title: paper.title.clone(),
+        verdict: paper.verdict.map(|v| {
+            match v {
+                PaperVerdict::Safe => "safe",
+                PaperVerdict::Questionable => "questionable",
+            }
+            .to_string()
+        }),
+        stats: Some(RunStats::from_check_stats(&paper.stats)),
+        references,
+    }
+}
@@ -1,4 +1,9 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Context;
 use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
 
 use crate::model::paper::RefPhase;
 use crate::model::queue::PaperPhase;
@@ -27,7 +32,108 @@ pub struct Theme {
     is_t800: bool,
 }
 
+/// Custom color definitions, read from the `[theme]` table of a theme file.
+///
+/// Colors accept names (`red`, `dark-gray`), `#rrggbb`, or a 256-color index.
+/// Unset fields keep the color from `base` (a built-in theme name, default
+/// `hacker`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeConfig {
+    pub base: Option<String>,
+    pub verified: Option<String>,
+    pub not_found: Option<String>,
+    pub author_mismatch: Option<String>,
+    pub retracted: Option<String>,
+    pub header_fg: Option<String>,
+    pub header_bg: Option<String>,
+    pub border: Option<String>,
+    pub text: Option<String>,
+    pub dim: Option<String>,
+    pub highlight_bg: Option<String>,
+    pub active: Option<String>,
+    pub queued: Option<String>,
+    pub spinner: Option<String>,
+    pub footer_fg: Option<String>,
+    pub footer_bg: Option<String>,
+}
+
+/// On-disk layout of a theme file: colors live under `[theme]`.
+#[derive(Debug, Deserialize)]
+struct ThemeFile {
+    theme: ThemeConfig,
+}
+
 impl Theme {
+    /// Look up a built-in theme by name (`hacker`, `modern`, `gnr`/`t800`).
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "hacker" => Some(Self::hacker()),
+            "modern" => Some(Self::modern()),
+            "gnr" | "t800" => Some(Self::t800()),
+            _ => None,
+        }
+    }
+
+    /// Resolve `--theme`/`[display] theme`: a built-in theme name, or a path
+    /// to a TOML file with a `[theme]` table.
+    pub fn resolve(name_or_path: &str) -> anyhow::Result<Self> {
+        if let Some(theme) = Self::builtin(name_or_path) {
+            return Ok(theme);
+        }
+        let path = Path::new(name_or_path);
+        if !path.is_file() {
+            anyhow::bail!(
+                "unknown theme '{}' (expected hacker, modern, gnr, or a theme .toml file)",
+                name_or_path
+            );
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read theme file {}", path.display()))?;
+        let file: ThemeFile = toml::from_str(&content)
+            .with_context(|| format!("invalid theme file {}", path.display()))?;
+        Self::from_config(&file.theme)
+    }
+
+    /// Build a theme from custom color definitions layered over a built-in base.
+    pub fn from_config(config: &ThemeConfig) -> anyhow::Result<Self> {
+        let base = config.base.as_deref().unwrap_or("hacker");
+        let mut theme =
+            Self::builtin(base).with_context(|| format!("unknown base theme '{}'", base))?;
+        let fields: [(&mut Color, &Option<String>, &str); 15] = [
+            (&mut theme.verified, &config.verified, "verified"),
+            (&mut theme.not_found, &config.not_found, "not_found"),
+            (
+                &mut theme.author_mismatch,
+                &config.author_mismatch,
+                "author_mismatch",
+            ),
+            (&mut theme.retracted, &config.retracted, "retracted"),
+            (&mut theme.header_fg, &config.header_fg, "header_fg"),
+            (&mut theme.header_bg, &config.header_bg, "header_bg"),
+            (&mut theme.border, &config.border, "border"),
+            (&mut theme.text, &config.text, "text"),
+            (&mut theme.dim, &config.dim, "dim"),
+            (
+                &mut theme.highlight_bg,
+                &config.highlight_bg,
+                "highlight_bg",
+            ),
+            (&mut theme.active, &config.active, "active"),
+            (&mut theme.queued, &config.queued, "queued"),
+            (&mut theme.spinner, &config.spinner, "spinner"),
+            (&mut theme.footer_fg, &config.footer_fg, "footer_fg"),
+            (&mut theme.footer_bg, &config.footer_bg, "footer_bg"),
+        ];
+        for (slot, value, field) in fields {
+            if let Some(value) = value {
+                *slot = Color::from_str(value)
+                    .map_err(|_| anyhow::anyhow!("invalid color '{}' for {}", value, field))?;
+            }
+        }
+        Ok(theme)
+    }
+
     /// Hacker-green terminal theme.
     pub fn hacker() -> Self {
         Self {
@@ -156,3 +262,52 @@ impl Theme {
         Style::default().fg(self.footer_fg).bg(self.footer_bg)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_theme_from_toml() {
+        let file: ThemeFile = toml::from_str(
+            r##"
+            [theme]
+            base = "modern"
+            verified = "#112233"
+            not_found = "light-red"
+            dim = "244"
+            "##,
+        )
+        .unwrap();
+        let theme = Theme::from_config(&file.theme).unwrap();
+        assert_eq!(theme.verified, Color::Rgb(0x11, 0x22, 0x33));
+        assert_eq!(theme.not_found, Color::LightRed);
+        assert_eq!(theme.dim, Color::Indexed(244));
+        // Unset fields come from the base theme
+        assert_eq!(theme.header_bg, Theme::modern().header_bg);
+        assert!(!theme.is_t800());
+    }
+
+    #[test]
+    fn custom_theme_rejects_bad_color_and_unknown_field() {
+        let bad_color = ThemeConfig {
+            active: Some("not-a-color".to_string()),
+            ..Default::default()
+        };
+        assert!(Theme::from_config(&bad_color).is_err());
+        assert!(toml::from_str::<ThemeFile>("[theme]\nverifed = \"red\"\n").is_err());
+    }
+
+    #[test]
+    fn resolve_builtin_name_and_file() {
+        assert!(Theme::resolve("gnr").unwrap().is_t800());
+        assert!(Theme::resolve("no-such-theme").is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.toml");
+        std::fs::write(&path, "[theme]\nbase = \"gnr\"\nactive = \"cyan\"\n").unwrap();
+        let theme = Theme::resolve(path.to_str().unwrap()).unwrap();
+        assert_eq!(theme.active, Color::Cyan);
+        assert!(theme.is_t800());
+    }
+}
@@ -20,12 +20,25 @@ pub enum BackendCommand {
     },
     /// Cancel the current batch.
     CancelProcessing,
-    /// Build/update the offline DBLP database.
-    BuildDblp { db_path: PathBuf },
-    /// Build/update the offline ACL Anthology database.
-    BuildAcl { db_path: PathBuf },
-    /// Build/update the offline OpenAlex Tantivy index.
-    BuildOpenalex { db_path: PathBuf },
+    /// Build/update the offline DBLP database, downloading with `client`.
+    BuildDblp {
+        db_path: PathBuf,
+        client: reqwest::Client,
+    },
+    /// Build/update the offline ACL Anthology database, downloading with
+    /// `client`.
+    BuildAcl {
+        db_path: PathBuf,
+        client: reqwest::Client,
+    },
+    /// Build/update the offline OpenAlex Tantivy index, optionally limited to
+    /// S3 partitions newer than `since` and works published from `min_year`.
+    BuildOpenalex {
+        db_path: PathBuf,
+        since: Option<String>,
+        min_year: Option<u32>,
+        client: reqwest::Client,
+    },
 }
 
 /// Events flowing from the backend processing task to the TUI.
@@ -34,12 +47,14 @@ pub enum BackendCommand {
 pub enum BackendEvent {
     /// PDF text extraction started for paper at queue index.
     ExtractionStarted { paper_index: usize },
-    /// PDF extraction completed — references parsed.
+    /// PDF extraction completed — references parsed. The references
+    /// themselves follow as a [`ProgressEvent::Extracted`].
     ExtractionComplete {
         paper_index: usize,
         ref_count: usize,
-        references: Vec<Reference>,
         skip_stats: SkipStats,
+        /// The paper's own title, if one could be read from the document.
+        document_title: Option<String>,
     },
     /// PDF extraction failed.
     ExtractionFailed { paper_index: usize, error: String },
@@ -259,7 +259,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             };
             lines.push(Line::from(vec![
                 Span::styled(
-                    format!(" {:<12}", q.db_name),
+                    format!(" {:<12}", q.status()),
                     Style::default().fg(theme.spinner),
                 ),
                 Span::styled(title_short, Style::default().fg(theme.dim)),
@@ -107,14 +107,16 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect, footer_area: Rect) {
     f.render_widget(content, chunks[2]);
 
     // Footer — context-aware per section (confirm_exit is now a modal overlay)
-    let footer_text = if config.editing {
+    let footer_text = if config.openalex_build_prompt {
+        " Type filter (e.g. 2024-01-01 2020), Enter:start build, Esc:cancel".to_string()
+    } else if config.editing {
         " Type value, Enter:confirm, Esc:cancel".to_string()
     } else {
         let section_hint = match config.section {
             ConfigSection::ApiKeys => "Enter:edit value",
             ConfigSection::Databases => "Enter:edit/toggle  Space:toggle",
             ConfigSection::Concurrency => "Enter:edit value",
-            ConfigSection::Display => "Space/Enter:cycle theme",
+            ConfigSection::Display => "Space/Enter:cycle theme/toggle",
         };
         let active_note = if app.processing_started && !app.batch_complete {
             "  \u{26A0} changes apply to next batch"
@@ -275,14 +277,15 @@ fn render_databases(lines: &mut Vec<Line>, config: &ConfigState, theme: &Theme,
 
     // Item 2: OpenAlex offline path (editable)
     let cursor = if config.item_cursor == 2 { "> " } else { "  " };
-    let display_val = if config.editing && config.item_cursor == 2 {
+    let editing_path = config.editing && config.item_cursor == 2 && !config.openalex_build_prompt;
+    let display_val = if editing_path {
         render_edit_field(&config.edit_buffer, config.edit_cursor)
     } else if config.openalex_offline_path.is_empty() {
         "(not set)".to_string()
     } else {
         truncate_path(&config.openalex_offline_path, max_path_len)
     };
-    let val_style = if config.editing && config.item_cursor == 2 {
+    let val_style = if editing_path {
         Style::default().fg(theme.active)
     } else {
         Style::default().fg(theme.dim)
@@ -302,6 +305,20 @@ fn render_databases(lines: &mut Vec<Line>, config: &ConfigState, theme: &Theme,
     }
     lines.push(Line::from(spans));
 
+    // OpenAlex build filter prompt (shown after pressing b)
+    if config.openalex_build_prompt {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "      Build filter [since YYYY-MM-DD] [min year YYYY], blank = full: ",
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                render_edit_field(&config.edit_buffer, config.edit_cursor),
+                Style::default().fg(theme.active),
+            ),
+        ]));
+    }
+
     // Show OpenAlex build status inline
     if let Some(ref status) = config.openalex_build_status {
         let style = if config.openalex_building {
@@ -494,6 +511,56 @@ fn render_display(lines: &mut Vec<Line>, config: &ConfigState, theme: &Theme) {
         ),
         Span::styled(display_val, val_style),
     ]));
+
+    // Item 2: Startup banner
+    let cursor = if config.item_cursor == 2 { "> " } else { "  " };
+    let (label, color) = if config.no_banner {
+        ("[off]", theme.dim)
+    } else {
+        ("[on]", theme.verified)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {}{:<22}", cursor, "Startup banner"),
+            Style::default().fg(theme.text),
+        ),
+        Span::styled(label, Style::default().fg(color)),
+    ]));
+
+    // Item 3: In-text citation check
+    let cursor = if config.item_cursor == 3 { "> " } else { "  " };
+    let (label, color) = if config.check_citations {
+        ("[on]", theme.verified)
+    } else {
+        ("[off]", theme.dim)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {}{:<22}", cursor, "Check citations"),
+            Style::default().fg(theme.text),
+        ),
+        Span::styled(label, Style::default().fg(color)),
+        Span::styled(
+            "  (flag references never cited)",
+            Style::default().fg(theme.dim),
+        ),
+    ]));
+
+    // Item 4: Existence-only verification
+    let cursor = if config.item_cursor == 4 { "> " } else { "  " };
+    let (label, color) = if config.existence_only {
+        ("[on]", theme.verified)
+    } else {
+        ("[off]", theme.dim)
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {}{:<22}", cursor, "Existence only"),
+            Style::default().fg(theme.text),
+        ),
+        Span::styled(label, Style::default().fg(color)),
+        Span::styled("  (skip author comparison)", Style::default().fg(theme.dim)),
+    ]));
 }
 
 /// Truncate a path string for display. If longer than `max_len`, show `...` + the tail.
@@ -18,6 +18,7 @@ struct LinkEntry {
 
 /// Render the Reference Detail screen into the given area.
 /// `footer_area` is a full-width row below the main content + activity panel.
+/// Returns the maximum scroll offset for the rendered content.
 pub fn render_in(
     f: &mut Frame,
     app: &App,
@@ -25,7 +26,7 @@ pub fn render_in(
     ref_index: usize,
     area: Rect,
     footer_area: Rect,
-) {
+) -> u16 {
     let theme = &app.theme;
     let paper = &app.papers[paper_index];
     let refs = &app.ref_states[paper_index];
@@ -56,6 +57,13 @@ pub fn render_in(
                 label: format!("arXiv: {url}"),
                 url,
             });
+            if let Some(published) = &arxiv.published_doi {
+                let url = format!("https://doi.org/{published}");
+                links.push(LinkEntry {
+                    label: format!("Published as: {url}"),
+                    url,
+                });
+            }
         }
     } else if matches!(rs.phase, RefPhase::Skipped(_)) && !rs.title.is_empty() {
         let scholar_query = encode_url_param(&rs.title);
@@ -81,7 +89,7 @@ pub fn render_in(
         Span::styled(" Reference ", theme.header_style()),
         Span::styled(" > ", Style::default().fg(theme.dim)),
         Span::styled(
-            &paper.filename,
+            paper.display_name(),
             Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ),
         Span::styled(" > ", Style::default().fg(theme.dim)),
@@ -114,6 +122,7 @@ pub fn render_in(
         let reason_desc = match reason.as_str() {
             "url_only" => "URL-only (non-academic URL)",
             "short_title" => "Short title (fewer than minimum words)",
+            "placeholder" => "Placeholder or incomplete citation",
             "no_title" => "No title could be extracted",
             other => other,
         };
@@ -141,6 +150,9 @@ pub fn render_in(
     if let Some(arxiv) = &rs.arxiv_id {
         labeled_line(&mut lines, "arXiv ID", arxiv, theme);
     }
+    if let Some(key) = &rs.cite_key {
+        labeled_line(&mut lines, "Cite Key", key, theme);
+    }
 
     if let Some(result) = &rs.result {
         lines.push(Line::from(""));
@@ -175,6 +187,9 @@ pub fn render_in(
         if let Some(source) = &result.source {
             labeled_line(&mut lines, "Source", source, theme);
         }
+        if let Some(relation) = result.version_relation {
+            labeled_line(&mut lines, "Matched", relation.description(), theme);
+        }
         // Author comparison for mismatches: always show both rows
         if result.status == Status::AuthorMismatch {
             // PDF Authors (what was extracted from the paper)
@@ -189,21 +204,15 @@ pub fn render_in(
 
             // DB Authors (what the database returned) — always show, even if empty
             if !result.found_authors.is_empty() {
-                let overlap = if !result.ref_authors.is_empty() {
-                    let ref_set: std::collections::HashSet<_> = result
-                        .ref_authors
-                        .iter()
-                        .map(|a| a.to_lowercase())
-                        .collect();
-                    let found_set: std::collections::HashSet<_> = result
-                        .found_authors
-                        .iter()
-                        .map(|a| a.to_lowercase())
-                        .collect();
-                    let overlap_count = ref_set.intersection(&found_set).count();
-                    format!(" ({}/{})", overlap_count, result.ref_authors.len())
-                } else {
+                let overlap = if result.author_match_detail.is_empty() {
                     String::new()
+                } else {
+                    let matched = result
+                        .author_match_detail
+                        .iter()
+                        .filter(|(_, matched)| *matched)
+                        .count();
+                    format!(" ({}/{})", matched, result.author_match_detail.len())
                 };
                 labeled_line(
                     &mut lines,
@@ -230,6 +239,16 @@ pub fn render_in(
                 &result.found_authors.join(", "),
                 theme,
             );
+            // ...and any cited authors the record doesn't list
+            let unmatched: Vec<&str> = result
+                .author_match_detail
+                .iter()
+                .filter(|(_, matched)| !matched)
+                .map(|(author, _)| author.as_str())
+                .collect();
+            if !unmatched.is_empty() {
+                labeled_line(&mut lines, "Not in record", &unmatched.join(", "), theme);
+            }
         }
 
         // DATABASE RESULTS section (per-DB table)
@@ -317,13 +336,38 @@ pub fn render_in(
             section_header(&mut lines, "IDENTIFIERS", theme);
 
             if let Some(doi) = &result.doi_info {
-                let validity = if doi.valid { "valid" } else { "invalid" };
+                let validity = if !doi.registered {
+                    "not registered, likely fabricated"
+                } else if !doi.valid {
+                    "invalid"
+                } else if doi.title_mismatch {
+                    "resolves to a different title"
+                } else {
+                    "valid"
+                };
                 labeled_line(
                     &mut lines,
                     "DOI",
                     &format!("{} ({})", doi.doi, validity),
                     theme,
                 );
+                if doi.title_mismatch
+                    && let Some(doi_title) = &doi.title
+                {
+                    lines.push(Line::from(Span::styled(
+                        format!("  \u{26A0} DOI title: {}", doi_title),
+                        Style::default().fg(theme.not_found),
+                    )));
+                }
+            }
+            if let Some(conflict) = &result.doi_conflict {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "  \u{26A0} DOI conflict: the DOI names \"{}\", but {} matched \"{}\"",
+                        conflict.doi_title, conflict.matched_source, conflict.matched_title
+                    ),
+                    Style::default().fg(theme.not_found),
+                )));
             }
             if let Some(arxiv) = &result.arxiv_info {
                 let validity = if arxiv.valid { "valid" } else { "invalid" };
@@ -333,6 +377,9 @@ pub fn render_in(
                     &format!("{} ({})", arxiv.arxiv_id, validity),
                     theme,
                 );
+                if let Some(published) = &arxiv.published_doi {
+                    labeled_line(&mut lines, "Published as", published, theme);
+                }
             }
             if let Some(url) = &result.paper_url {
                 labeled_line(&mut lines, "Paper URL", url, theme);
@@ -384,6 +431,21 @@ pub fn render_in(
                 )));
             }
         }
+        if result.insufficient_coverage {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  Low coverage: {}.",
+                    hallucinator_reporting::coverage_caveat(result)
+                ),
+                Style::default().fg(theme.author_mismatch),
+            )));
+        }
+        if result.cited_in_text == Some(false) {
+            lines.push(Line::from(Span::styled(
+                "  Listed but never cited in the text.",
+                Style::default().fg(theme.author_mismatch),
+            )));
+        }
     } else if matches!(rs.phase, RefPhase::Skipped(_)) {
         // No extra content for skipped refs (links added below)
     } else {
@@ -412,8 +474,13 @@ pub fn render_in(
                 .borders(Borders::ALL)
                 .border_style(theme.border_style()),
         )
-        .wrap(Wrap { trim: false })
-        .scroll((app.detail_scroll, 0));
+        .wrap(Wrap { trim: false });
+    // line_count includes the block borders; the width excludes them
+    let content_rows = content.line_count(chunks[1].width.saturating_sub(2));
+    let max_scroll = content_rows
+        .saturating_sub(chunks[1].height as usize)
+        .min(u16::MAX as usize) as u16;
+    let content = content.scroll((app.detail_scroll.min(max_scroll), 0));
 
     f.render_widget(content, chunks[1]);
 
@@ -422,6 +489,8 @@ pub fn render_in(
 
     // --- Footer ---
     render_footer(f, footer_area, theme);
+
+    max_scroll
 }
 
 fn section_header<'a>(lines: &mut Vec<Line<'a>>, title: &'a str, theme: &Theme) {
@@ -161,7 +161,7 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
     let summary_lines = if is_db_mode {
         if selected_count == 0 {
             let hint = if is_dir_mode {
-                "  Navigate to the index directory and press Space to select"
+                "  Navigate to the index directory and press Space (or Enter) to select"
             } else {
                 "  Navigate to a .db or .sqlite file and press Enter to select"
             };
@@ -284,7 +284,7 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
 
     // Footer — context-aware
     let footer_text = if is_dir_mode {
-        " j/k:navigate  Enter:open dir  Space:select dir  Esc:confirm  ?:help  q:quit"
+        " j/k:navigate  Enter:open dir/select index  Space:select dir  Esc:confirm  ?:help  q:quit"
     } else if is_db_mode {
         " j/k:navigate  Enter:select & confirm  Esc:cancel  ?:help  q:quit"
     } else {
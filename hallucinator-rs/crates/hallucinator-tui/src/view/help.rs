@@ -37,7 +37,7 @@ pub fn render(f: &mut Frame, theme: &Theme) {
         key_line("S", "Reverse sort direction", theme),
         key_line("f", "Cycle filter", theme),
         key_line("/", "Start search", theme),
-        key_line("n / N", "Next / previous match", theme),
+        key_line("n / N", "Next / previous problem (paper view)", theme),
         Line::from(""),
         // Actions
         section_header("Actions", theme),
@@ -45,6 +45,7 @@ pub fn render(f: &mut Frame, theme: &Theme) {
         key_line("Space", "Paper verdict / cycle FP reason", theme),
         key_line("Ctrl+r", "Retry failed reference", theme),
         key_line("R", "Retry all failed references", theme),
+        key_line("F", "Retry DB failures across all papers (queue)", theme),
         key_line("e", "Export results", theme),
         key_line("p", "Open source PDF in default viewer", theme),
         key_line("o / a", "Open file picker (add files)", theme),
@@ -61,6 +62,7 @@ pub fn render(f: &mut Frame, theme: &Theme) {
         Line::from(""),
         section_header("Mouse", theme),
         key_line("Shift+click", "Select text / click links (terminal)", theme),
+        key_line("Wheel", "Move cursor / scroll detail (--mouse)", theme),
         Line::from(""),
         section_header("Loading Results", theme),
         key_line("--load FILE", "Load saved results JSON at startup", theme),
@@ -21,6 +21,14 @@ pub fn spinner_char(tick: usize) -> char {
     SPINNER_FRAMES[tick % SPINNER_FRAMES.len()]
 }
 
+/// Status text shown while every backend with pending queries waits out a 429.
+pub fn rate_limit_label(wait: std::time::Duration) -> String {
+    format!(
+        "\u{23F8} Rate-limited \u{2014} waiting {}s",
+        wait.as_secs_f64().ceil() as u64
+    )
+}
+
 /// Truncate a string to fit in `max_width` columns, appending "\u{2026}" if truncated.
 pub fn truncate(s: &str, max_width: usize) -> String {
     if max_width == 0 {
@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Table
 use crate::app::{App, InputMode};
 use crate::model::paper::{PaperFilter, RefPhase};
 use crate::theme::Theme;
-use crate::view::{spinner_char, truncate};
+use crate::view::{rate_limit_label, spinner_char, truncate};
 
 /// Render the Paper detail screen into the given area.
 /// `footer_area` is a full-width row below the main content + activity panel.
@@ -32,9 +32,9 @@ pub fn render_in(f: &mut Frame, app: &mut App, paper_index: usize, area: Rect, f
     let chunks = Layout::vertical(constraints).split(area);
     let mut ci = 0;
 
-    render_breadcrumb(f, chunks[ci], &paper.filename, theme);
+    render_breadcrumb(f, chunks[ci], &paper.display_name(), theme);
     ci += 1;
-    render_progress(f, chunks[ci], paper, app.tick, theme);
+    render_progress(f, chunks[ci], paper, app.tick, app.rate_limit_pause, theme);
     ci += 1;
 
     if has_search {
@@ -71,6 +71,7 @@ fn render_progress(
     area: Rect,
     paper: &crate::model::queue::PaperState,
     tick: usize,
+    rate_limit_pause: Option<std::time::Duration>,
     theme: &Theme,
 ) {
     let done = paper.completed_count();
@@ -83,6 +84,13 @@ fn render_progress(
 
     let label = if done >= checkable && checkable > 0 {
         format!("\u{2713} {} / {} refs", done, checkable)
+    } else if let Some(wait) = rate_limit_pause {
+        format!(
+            "{} \u{00B7} {} / {} refs",
+            rate_limit_label(wait),
+            done,
+            checkable
+        )
     } else {
         format!("{} {} / {} refs", spinner_char(tick), done, checkable)
     };
@@ -268,7 +276,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
     }
 
     spans.push(Span::styled(
-        " Space:FP reason  Enter:detail  p:pdf  Ctrl+r:retry  R:retry all  s:sort  f:filter  c:config  e:export  Esc:back",
+        " Space:FP reason  Enter:detail  n/N:next/prev problem  p:pdf  Ctrl+r:retry  R:retry all  s:sort  f:filter  c:config  e:export  Esc:back",
         theme.footer_style(),
     ));
 
@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
 use crate::app::{App, InputMode};
 use crate::model::queue::{PaperPhase, PaperVerdict};
 use crate::theme::Theme;
-use crate::view::{spinner_char, truncate};
+use crate::view::{rate_limit_label, spinner_char, truncate};
 
 /// Render the Queue screen into the given area.
 /// `footer_area` is a full-width row below the main content + activity panel.
@@ -114,6 +114,15 @@ fn render_progress_bar(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
         ));
     }
 
+    if let Some(wait) = app.rate_limit_pause {
+        spans.push(Span::styled(
+            format!(" {}", rate_limit_label(wait)),
+            Style::default()
+                .fg(theme.author_mismatch)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
@@ -168,7 +177,7 @@ fn render_table(f: &mut Frame, area: Rect, app: &App) {
                 Some(PaperVerdict::Questionable) => "[?!] ",
                 None => "",
             };
-            let raw_name = format!("{}{}", verdict_badge, paper.filename);
+            let raw_name = format!("{}{}", verdict_badge, paper.display_name());
             let name = truncate(&raw_name, (area.width as usize).saturating_sub(40));
 
             let name_style = match paper.verdict {
@@ -360,7 +369,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ));
         spans.push(Span::styled(
-            " Space:mark  Enter:open  p:pdf  s/S:sort  f:filter  o:add  c:config  e:export  ?:help  q:quit",
+            " Space:mark  Enter:open  p:pdf  F:retry failed  s/S:sort  f:filter  o:add  c:config  e:export  ?:help  q:quit",
             theme.footer_style(),
         ));
     } else {
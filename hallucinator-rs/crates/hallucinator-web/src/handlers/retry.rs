@@ -38,11 +38,21 @@ pub async fn retry(
         ..Config::default()
     };
 
-    let client = reqwest::Client::new();
+    let client = match config.http_client() {
+        Ok(client) => client,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+                .into_response();
+        }
+    };
 
     let result = hallucinator_core::query_all_databases(
         &req.title,
         &req.ref_authors,
+        hallucinator_core::RefKind::Unknown,
         &config,
         &client,
         true, // longer timeout for retries
@@ -7,7 +7,9 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::sync::CancellationToken;
 
-use hallucinator_core::{Config, ExtractionResult, ProgressEvent, SkipStats, ValidationResult};
+use hallucinator_core::{
+    Config, ExtractionResult, PaperRecord, ProgressEvent, SkipStats, ValidationResult,
+};
 
 use crate::models::*;
 use crate::state::AppState;
@@ -66,9 +68,13 @@ async fn handle_single_pdf(
         .map_err(|e| format!("Failed to write temp file: {}", e))?;
 
     // Extract references (blocking I/O via MuPDF)
-    let extraction = extract_pdf_blocking(&pdf_path)
+    let config = build_config(&state, &fields);
+    let mut extraction = extract_pdf_blocking(&pdf_path)
         .await
         .map_err(|e| format!("{}: {}", filename, e))?;
+    config
+        .enforce_reference_limit(&mut extraction)
+        .map_err(|e| format!("{}: {}", filename, e))?;
 
     // Temp dir no longer needed after extraction
     drop(temp_dir);
@@ -86,14 +92,15 @@ async fn handle_single_pdf(
                 total_raw: skip_stats.total_raw,
                 skipped_url: skip_stats.url_only,
                 skipped_short_title: skip_stats.short_title,
+                skipped_placeholder: skip_stats.placeholder,
                 skipped_no_authors: skip_stats.no_authors,
+                truncated: skip_stats.truncated,
             },
         },
     )
     .await?;
 
     // Run validation in a separate task so we can detect client disconnect
-    let config = build_config(&state, &fields);
     let cancel = CancellationToken::new();
     let cancel_for_disconnect = cancel.clone();
     let tx_progress = tx.clone();
@@ -113,7 +120,9 @@ async fn handle_single_pdf(
     // Race between validation completing and client disconnecting
     let results = tokio::select! {
         result = validation_handle => {
-            result.map_err(|e| format!("Validation task error: {}", e))?
+            result
+                .map_err(|e| format!("Validation task error: {}", e))?
+                .map_err(|e| e.to_string())?
         }
         _ = tx.closed() => {
             cancel_for_disconnect.cancel();
@@ -124,7 +133,13 @@ async fn handle_single_pdf(
     // Send complete event
     let summary = SummaryJson::from_results(&results, &skip_stats);
     let result_jsons: Vec<ResultJson> = results.iter().map(ResultJson::from).collect();
+    let run = vec![PaperRecord::from_results(
+        filename,
+        &results,
+        skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder,
+    )];
 
+    let passed = passed(&result_jsons, &fields.fail_on);
     send(
         &tx,
         "complete",
@@ -133,6 +148,8 @@ async fn handle_single_pdf(
             results: result_jsons,
             file_count: None,
             files: None,
+            run,
+            passed,
         },
     )
     .await?;
@@ -153,6 +170,7 @@ async fn handle_archive(
 
     let mut all_results: Vec<ResultJson> = Vec::new();
     let mut file_results: Vec<FileResultJson> = Vec::new();
+    let mut run: Vec<PaperRecord> = Vec::new();
     let mut aggregate_skip_stats = SkipStats::default();
 
     let cancel = CancellationToken::new();
@@ -187,10 +205,17 @@ async fn handle_archive(
                 aggregate_skip_stats.total_raw += skip_stats.total_raw;
                 aggregate_skip_stats.url_only += skip_stats.url_only;
                 aggregate_skip_stats.short_title += skip_stats.short_title;
+                aggregate_skip_stats.placeholder += skip_stats.placeholder;
                 aggregate_skip_stats.no_authors += skip_stats.no_authors;
+                aggregate_skip_stats.truncated += skip_stats.truncated;
 
                 let summary = SummaryJson::from_results(&results, &skip_stats);
                 let result_jsons: Vec<ResultJson> = results.iter().map(ResultJson::from).collect();
+                run.push(PaperRecord::from_results(
+                    &pdf.filename,
+                    &results,
+                    skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder,
+                ));
 
                 send(
                     &tx,
@@ -248,6 +273,7 @@ async fn handle_archive(
     // Compute aggregate summary from all_results
     let aggregate_summary = compute_aggregate_summary(&all_results, &aggregate_skip_stats);
 
+    let passed = passed(&all_results, &fields.fail_on);
     send(
         &tx,
         "complete",
@@ -256,6 +282,8 @@ async fn handle_archive(
             results: all_results,
             file_count: Some(file_count),
             files: Some(file_results),
+            run,
+            passed,
         },
     )
     .await?;
@@ -272,9 +300,13 @@ async fn process_archive_file(
     cancel: &CancellationToken,
     cancel_for_disconnect: &CancellationToken,
 ) -> Result<(Vec<ValidationResult>, SkipStats), String> {
-    let extraction = extract_pdf_blocking(&pdf.path)
+    let config = build_config(state, fields);
+    let mut extraction = extract_pdf_blocking(&pdf.path)
         .await
         .map_err(|e| format!("{}: {}", pdf.filename, e))?;
+    config
+        .enforce_reference_limit(&mut extraction)
+        .map_err(|e| format!("{}: {}", pdf.filename, e))?;
 
     let skip_stats = extraction.skip_stats.clone();
     let refs = extraction.references;
@@ -288,13 +320,14 @@ async fn process_archive_file(
                 total_raw: skip_stats.total_raw,
                 skipped_url: skip_stats.url_only,
                 skipped_short_title: skip_stats.short_title,
+                skipped_placeholder: skip_stats.placeholder,
                 skipped_no_authors: skip_stats.no_authors,
+                truncated: skip_stats.truncated,
             },
         },
     )
     .await?;
 
-    let config = build_config(state, fields);
     let cancel_clone = cancel.clone();
     let cancel_disconnect = cancel_for_disconnect.clone();
     let tx_progress = tx.clone();
@@ -316,7 +349,9 @@ async fn process_archive_file(
     // Race between validation completing and client disconnecting
     let results = tokio::select! {
         result = validation_handle => {
-            result.map_err(|e| format!("Validation task error: {}", e))?
+            result
+                .map_err(|e| format!("Validation task error: {}", e))?
+                .map_err(|e| e.to_string())?
         }
         _ = tx_closed.closed() => {
             cancel_disconnect.cancel();
@@ -358,6 +393,20 @@ fn send_progress_event(
     filename: Option<&str>,
 ) {
     let sse = match event {
+        ProgressEvent::Extracted { references } => sse_event(
+            "extracted",
+            &ExtractedEvent {
+                references: references
+                    .iter()
+                    .map(|r| ExtractedRefJson {
+                        title: r.title.clone().unwrap_or_default(),
+                        raw_citation: r.raw_citation.clone(),
+                        ref_authors: r.authors.clone(),
+                    })
+                    .collect(),
+                filename: filename.map(String::from),
+            },
+        ),
         ProgressEvent::Checking {
             index,
             total,
@@ -405,6 +454,7 @@ fn send_progress_event(
             sse_event("retry_pass", &RetryPassEvent { count: *count })
         }
         ProgressEvent::Retrying { .. }
+        | ProgressEvent::DatabaseQueryStarted { .. }
         | ProgressEvent::DatabaseQueryComplete { .. } => {
             // Not sent via SSE (detail only needed in TUI)
             return;
@@ -442,9 +492,10 @@ fn compute_aggregate_summary(results: &[ResultJson], skip_stats: &SkipStats) ->
         verified,
         not_found,
         mismatched,
-        skipped: skip_stats.url_only + skip_stats.short_title,
+        skipped: skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder,
         skipped_url: skip_stats.url_only,
         skipped_short_title: skip_stats.short_title,
+        skipped_placeholder: skip_stats.placeholder,
         title_only: skip_stats.no_authors,
     }
 }
@@ -1,5 +1,5 @@
 use axum::response::sse::Event;
-use hallucinator_core::{SkipStats, Status, ValidationResult};
+use hallucinator_core::{PaperRecord, SkipStats, Status, ValidationResult};
 use serde::{Deserialize, Serialize};
 
 // ── Result JSON (matches Python's per-reference JSON shape) ─────────────
@@ -37,6 +37,8 @@ pub struct ArxivInfoJson {
     pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arxiv_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_doi: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -64,7 +66,16 @@ impl From<&ValidationResult> for ResultJson {
 
         let doi_info = r.doi_info.as_ref().map(|d| DoiInfoJson {
             doi: d.doi.clone(),
-            status: if d.valid { "verified" } else { "invalid" }.to_string(),
+            status: if !d.registered {
+                "unregistered"
+            } else if !d.valid {
+                "invalid"
+            } else if d.title_mismatch {
+                "title_mismatch"
+            } else {
+                "verified"
+            }
+            .to_string(),
             doi_title: d.title.clone(),
         });
 
@@ -72,6 +83,7 @@ impl From<&ValidationResult> for ResultJson {
             arxiv_id: a.arxiv_id.clone(),
             status: if a.valid { "verified" } else { "invalid" }.to_string(),
             arxiv_title: a.title.clone(),
+            published_doi: a.published_doi.clone(),
         });
 
         let retraction_info = r.retraction_info.as_ref().map(|ri| RetractionInfoJson {
@@ -109,6 +121,7 @@ pub struct SummaryJson {
     pub skipped: usize,
     pub skipped_url: usize,
     pub skipped_short_title: usize,
+    pub skipped_placeholder: usize,
     pub title_only: usize,
 }
 
@@ -133,14 +146,49 @@ impl SummaryJson {
             verified,
             not_found,
             mismatched,
-            skipped: skip_stats.url_only + skip_stats.short_title,
+            skipped: skip_stats.url_only + skip_stats.short_title + skip_stats.placeholder,
             skipped_url: skip_stats.url_only,
             skipped_short_title: skip_stats.short_title,
+            skipped_placeholder: skip_stats.placeholder,
             title_only: skip_stats.no_authors,
         }
     }
 }
 
+// ── Failure policy ──────────────────────────────────────────────────────
+
+/// A reference outcome that can make a run fail, as named in the `fail_on`
+/// form field of `/analyze/stream`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailOn {
+    NotFound,
+    AuthorMismatch,
+    Retracted,
+}
+
+/// Used when the client doesn't send `fail_on`: every problem fails the run.
+pub const DEFAULT_FAIL_ON: &[FailOn] =
+    &[FailOn::NotFound, FailOn::AuthorMismatch, FailOn::Retracted];
+
+impl FailOn {
+    fn matches(self, result: &ResultJson) -> bool {
+        match self {
+            FailOn::NotFound => result.status == "not_found",
+            FailOn::AuthorMismatch => result.status == "author_mismatch",
+            FailOn::Retracted => result
+                .retraction_info
+                .as_ref()
+                .is_some_and(|ri| ri.retracted),
+        }
+    }
+}
+
+/// Whether no result has an outcome listed in `fail_on`.
+pub fn passed(results: &[ResultJson], fail_on: &[FailOn]) -> bool {
+    !results.iter().any(|r| fail_on.iter().any(|f| f.matches(r)))
+}
+
 // ── SSE Event Structs ───────────────────────────────────────────────────
 
 #[derive(Serialize)]
@@ -154,7 +202,26 @@ pub struct SkipStatsJson {
     pub total_raw: usize,
     pub skipped_url: usize,
     pub skipped_short_title: usize,
+    pub skipped_placeholder: usize,
     pub skipped_no_authors: usize,
+    /// References dropped for exceeding the per-document limit.
+    pub truncated: usize,
+}
+
+/// The references about to be checked, sent before any `checking` event so
+/// the page can list them as pending.
+#[derive(Serialize)]
+pub struct ExtractedEvent {
+    pub references: Vec<ExtractedRefJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ExtractedRefJson {
+    pub title: String,
+    pub raw_citation: String,
+    pub ref_authors: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -223,6 +290,11 @@ pub struct CompleteEvent {
     pub file_count: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<FileResultJson>>,
+    /// The completed run in the canonical schema (`hallucinator_core::run`),
+    /// loadable by the TUI with `--load`.
+    pub run: Vec<PaperRecord>,
+    /// False if any result has an outcome the request listed in `fail_on`.
+    pub passed: bool,
 }
 
 #[derive(Serialize)]
@@ -285,3 +357,54 @@ pub fn sse_event<T: Serialize>(event_type: &str, data: &T) -> Event {
         .event(event_type)
         .data(serde_json::to_string(data).unwrap_or_default())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(status: &str, retracted: bool) -> ResultJson {
+        ResultJson {
+            title: "A Paper".into(),
+            raw_citation: String::new(),
+            ref_authors: vec![],
+            status: status.into(),
+            source: None,
+            found_authors: vec![],
+            paper_url: None,
+            error_type: None,
+            failed_dbs: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: retracted.then(|| RetractionInfoJson {
+                retracted: true,
+                retraction_doi: None,
+                retraction_type: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_passed_reflects_configured_failures() {
+        let results = vec![result("verified", false), result("not_found", false)];
+        assert!(!passed(&results, DEFAULT_FAIL_ON));
+        assert!(!passed(&results, &[FailOn::NotFound]));
+        // Not found treated as a warning only.
+        assert!(passed(
+            &results,
+            &[FailOn::AuthorMismatch, FailOn::Retracted]
+        ));
+        assert!(passed(&results, &[]));
+
+        let retracted = vec![result("verified", true)];
+        assert!(!passed(&retracted, &[FailOn::Retracted]));
+        assert!(passed(&retracted, &[FailOn::NotFound]));
+    }
+
+    #[test]
+    fn test_fail_on_parses_snake_case_names() {
+        let parsed: Vec<FailOn> =
+            serde_json::from_str(r#"["not_found", "author_mismatch", "retracted"]"#).unwrap();
+        assert_eq!(parsed, DEFAULT_FAIL_ON);
+        assert!(serde_json::from_str::<Vec<FailOn>>(r#"["verified"]"#).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use axum::extract::Multipart;
 
+use crate::models::{DEFAULT_FAIL_ON, FailOn};
+
 /// The type of uploaded file.
 #[derive(Debug)]
 pub enum FileType {
@@ -22,6 +24,8 @@ pub struct FormFields {
     pub s2_api_key: Option<String>,
     pub check_openalex_authors: bool,
     pub disabled_dbs: Vec<String>,
+    /// Outcomes that make the run fail (`passed: false` in the final event).
+    pub fail_on: Vec<FailOn>,
 }
 
 /// Parse a multipart form upload into structured form fields.
@@ -31,6 +35,7 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
     let mut s2_api_key: Option<String> = None;
     let mut check_openalex_authors = false;
     let mut disabled_dbs: Vec<String> = Vec::new();
+    let mut fail_on: Vec<FailOn> = DEFAULT_FAIL_ON.to_vec();
 
     while let Some(field) = multipart
         .next_field()
@@ -92,6 +97,20 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
                     disabled_dbs = dbs;
                 }
             }
+            "fail_on" => {
+                let val = field
+                    .text()
+                    .await
+                    .map_err(|e| format!("Failed to read fail_on: {}", e))?;
+                if !val.is_empty() {
+                    fail_on = serde_json::from_str(&val).map_err(|e| {
+                        format!(
+                            "Invalid fail_on (expected a JSON list of \"not_found\", \"author_mismatch\", \"retracted\"): {}",
+                            e
+                        )
+                    })?;
+                }
+            }
             _ => {
                 // Ignore unknown fields
                 let _ = field.bytes().await;
@@ -107,6 +126,7 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
         s2_api_key,
         check_openalex_authors,
         disabled_dbs,
+        fail_on,
     })
 }
 
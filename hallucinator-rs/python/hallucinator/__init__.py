@@ -86,6 +86,8 @@ class PdfExtractor:
             "fallback_segment_regex",
             "min_title_words",
             "max_authors",
+            "strip_author_superscripts",
+            "keep_uncertain_hyphens",
         }
     )
 
@@ -154,6 +156,15 @@ class PdfExtractor:
                 return result
         return self._native.segment(text)
 
+    def segment_raw(self, text):
+        """Segment references text, keeping each reference's original lines.
+
+        Labels, line breaks and page headers are left in place, so the
+        result shows exactly where the built-in segmenter cut. Custom
+        strategies are not consulted.
+        """
+        return self._native.segment_raw(text)
+
     def parse_reference(self, text, prev_authors=None):
         """Parse a single reference string.
 
@@ -173,7 +184,7 @@ class PdfExtractor:
 
         section = self.find_section(text)
         if section is None:
-            return ExtractionResult._from_parts([], 0, 0, 0, 0, 0)
+            return ExtractionResult._from_parts([], 0, 0, 0, 0, 0, 0)
 
         segments = self.segment(section)
         return self._parse_segments(segments)
@@ -209,6 +220,7 @@ class PdfExtractor:
         total_raw = len(segments)
         url_only = 0
         short_title = 0
+        placeholder = 0
         no_title = 0
         no_authors = 0
 
@@ -221,6 +233,8 @@ class PdfExtractor:
                     url_only += 1
                 elif skip_reason == "short_title":
                     short_title += 1
+                elif skip_reason == "placeholder":
+                    placeholder += 1
             elif ref is not None:
                 if ref.title is None:
                     no_title += 1
@@ -231,7 +245,7 @@ class PdfExtractor:
                 refs.append(ref)
 
         return ExtractionResult._from_parts(
-            refs, total_raw, url_only, short_title, no_title, no_authors
+            refs, total_raw, url_only, short_title, placeholder, no_title, no_authors
         )
 
     def __repr__(self):
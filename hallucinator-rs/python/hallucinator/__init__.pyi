@@ -46,6 +46,10 @@ class Reference:
     def original_number(self) -> int: ...
     @property
     def skip_reason(self) -> Optional[str]: ...
+    @property
+    def container_title(self) -> Optional[str]: ...
+    @property
+    def cite_key(self) -> Optional[str]: ...
 
 class PdfExtractor:
     """A configurable PDF reference extractor with custom strategy support.
@@ -65,6 +69,8 @@ class PdfExtractor:
     fallback_segment_regex: str
     min_title_words: int
     max_authors: int
+    strip_author_superscripts: bool
+    keep_uncertain_hyphens: bool
 
     # Config methods
     def add_venue_cutoff_pattern(self, pattern: str) -> None: ...
@@ -94,6 +100,7 @@ class PdfExtractor:
     # Pipeline methods
     def find_section(self, text: str) -> Optional[str]: ...
     def segment(self, text: str) -> list[str]: ...
+    def segment_raw(self, text: str) -> list[str]: ...
     def parse_reference(
         self, text: str, prev_authors: Optional[list[str]] = None
     ) -> Optional[Reference]: ...
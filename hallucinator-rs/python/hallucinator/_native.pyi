@@ -15,6 +15,8 @@ class Reference:
     def doi(self) -> Optional[str]: ...
     @property
     def arxiv_id(self) -> Optional[str]: ...
+    @property
+    def cited_in_text(self) -> Optional[bool]: ...
 
 class SkipStats:
     """Statistics about references that were skipped during extraction."""
@@ -24,6 +26,8 @@ class SkipStats:
     @property
     def short_title(self) -> int: ...
     @property
+    def placeholder(self) -> int: ...
+    @property
     def no_title(self) -> int: ...
     @property
     def no_authors(self) -> int: ...
@@ -44,6 +48,7 @@ class ExtractionResult:
         total_raw: int,
         url_only: int,
         short_title: int,
+        placeholder: int,
         no_title: int,
         no_authors: int,
     ) -> "ExtractionResult": ...
@@ -66,6 +71,9 @@ class NativePdfExtractor:
     fallback_segment_regex: str
     min_title_words: int
     max_authors: int
+    strip_author_superscripts: bool
+    keep_uncertain_hyphens: bool
+    check_in_text_citations: bool
 
     def add_venue_cutoff_pattern(self, pattern: str) -> None: ...
     def set_venue_cutoff_patterns(self, patterns: list[str]) -> None: ...
@@ -79,6 +87,7 @@ class NativePdfExtractor:
     def extract_text(self, path: str) -> str: ...
     def find_section(self, text: str) -> Optional[str]: ...
     def segment(self, text: str) -> list[str]: ...
+    def segment_raw(self, text: str) -> list[str]: ...
     def parse_reference(
         self, text: str, prev_authors: Optional[list[str]] = None
     ) -> Optional[Reference]: ...
@@ -103,7 +112,10 @@ class ValidatorConfig:
     db_timeout_short_secs: int
     disabled_dbs: list[str]
     check_openalex_authors: bool
+    existence_only: bool
     crossref_mailto: Optional[str]
+    http_proxy: Optional[str]
+    extra_ca_cert: Optional[str]
 
 class Validator:
     """Validates references against academic databases."""
@@ -138,6 +150,10 @@ class ValidationResult:
     @property
     def failed_dbs(self) -> list[str]: ...
     @property
+    def insufficient_coverage(self) -> bool: ...
+    @property
+    def cited_in_text(self) -> Optional[bool]: ...
+    @property
     def db_results(self) -> list["DbResult"]: ...
     @property
     def doi_info(self) -> Optional["DoiInfo"]: ...
@@ -179,6 +195,8 @@ class ArxivInfo:
     def valid(self) -> bool: ...
     @property
     def title(self) -> Optional[str]: ...
+    @property
+    def published_doi(self) -> Optional[str]: ...
 
 class RetractionInfo:
     """Information about a retraction check."""